@@ -4,9 +4,10 @@ use clap::{Parser, Subcommand};
 
 use crate::{
     commands::{
-        agents, audio_isolation, audio_native, dubbing, forced_alignment, history, models, music,
-        pvc_voices, single_use_token, sound_generation, speech_to_speech, speech_to_text, studio,
-        text_to_dialogue, text_to_voice, tts, user, voice_generation, voices, workspace, ws,
+        agents, audio_isolation, audio_native, completions, config, dubbing, forced_alignment,
+        history, manpages, models, music, pvc_voices, single_use_token, sound_generation,
+        speech_to_speech, speech_to_text, studio, text_to_dialogue, text_to_voice, tts, user,
+        voice_generation, voices, workspace, ws,
     },
     output::OutputFormat,
 };
@@ -23,6 +24,10 @@ pub(crate) struct Cli {
     #[arg(long, env = "ELEVENLABS_BASE_URL", global = true)]
     pub base_url: Option<String>,
 
+    /// Profile from the config file to use (see `elevenlabs config`).
+    #[arg(long, env = "ELEVENLABS_PROFILE", global = true)]
+    pub profile: Option<String>,
+
     /// Output format.
     #[arg(long, default_value = "pretty", global = true)]
     pub format: OutputFormat,
@@ -57,6 +62,9 @@ pub(crate) enum Commands {
     /// Conversational AI agents.
     Agents(agents::AgentsArgs),
 
+    /// Manage configuration profiles.
+    Config(config::ConfigArgs),
+
     /// Isolate audio from background noise.
     AudioIsolation(audio_isolation::AudioIsolationArgs),
 
@@ -104,4 +112,10 @@ pub(crate) enum Commands {
 
     /// WebSocket operations (TTS streaming, Conversational AI).
     Ws(ws::WsArgs),
+
+    /// Print a shell completion script.
+    Completions(completions::CompletionsArgs),
+
+    /// Write man pages for every command.
+    Manpages(manpages::ManpagesArgs),
 }
@@ -50,6 +50,16 @@ pub(crate) enum AgentsCommands {
         #[arg(long)]
         conversation_id: String,
     },
+
+    /// Report tool usage across the workspace, least-used first.
+    ToolUsage {
+        /// Only show tools that have never been called.
+        #[arg(long)]
+        unused: bool,
+    },
+
+    /// Roll out a branch by shifting live traffic to it in stages.
+    Deploy(crate::commands::agents_deploy::DeployArgs),
 }
 
 /// Execute an agents subcommand.
@@ -78,13 +88,25 @@ pub(crate) async fn execute(args: &AgentsArgs, cli: &Cli) -> eyre::Result<()> {
             eprintln!("Agent {agent_id} deleted");
         }
         AgentsCommands::ListConversations { agent_id } => {
-            let response = client.agents().list_conversations(Some(agent_id), None).await?;
+            let response = client.agents().list_conversations(Some(agent_id), None, None).await?;
             print_json(&response, cli.format)?;
         }
         AgentsCommands::GetConversation { conversation_id } => {
             let response = client.agents().get_conversation(conversation_id).await?;
             print_json(&response, cli.format)?;
         }
+        AgentsCommands::Deploy(deploy) => {
+            crate::commands::agents_deploy::execute(deploy, &client, cli).await?;
+        }
+        AgentsCommands::ToolUsage { unused } => {
+            let report = client.agents().tool_usage_report().await?;
+            if *unused {
+                let entries: Vec<_> = report.unused().collect();
+                print_json(&entries, cli.format)?;
+            } else {
+                print_json(&report, cli.format)?;
+            }
+        }
     }
     Ok(())
 }
@@ -0,0 +1,215 @@
+//! `agents deploy` — shift live traffic from an agent's main branch to
+//! another branch in stages.
+//!
+//! The branch first receives `--percent` of traffic. Each `--ramp` step then
+//! waits its delay, measured from the previous step, and raises the branch
+//! to its percentage; the main branch always receives the rest. After every
+//! step the live split reported by the API is printed to stderr.
+//!
+//! If a step fails, traffic is returned to the main branch before the error
+//! is reported. `--rollback` does the same on demand, for example after
+//! interrupting a ramp.
+
+use std::{str::FromStr, time::Duration};
+
+use clap::Args;
+use elevenlabs_sdk::{
+    ElevenLabsClient,
+    types::{AgentDeploymentResponse, CreateDeploymentRequest},
+};
+
+use crate::{cli::Cli, output::print_json};
+
+/// Roll out an agent branch by shifting traffic in stages.
+#[derive(Debug, Args)]
+pub(crate) struct DeployArgs {
+    /// Agent whose traffic is split.
+    #[arg(long)]
+    agent: String,
+
+    /// Branch to roll out.
+    #[arg(long)]
+    branch: String,
+
+    /// Share of traffic (0–100) the branch receives first.
+    #[arg(long, value_parser = parse_percent, required_unless_present = "rollback")]
+    percent: Option<f64>,
+
+    /// Later steps as `DELAY:PERCENT` pairs, e.g. `10m:50,30m:100`. Each
+    /// delay (`s`, `m` or `h`) counts from the previous step.
+    #[arg(long, conflicts_with = "rollback")]
+    ramp: Option<Ramp>,
+
+    /// Branch that receives the remaining traffic. Defaults to the agent's
+    /// main branch.
+    #[arg(long)]
+    main_branch: Option<String>,
+
+    /// Return all traffic to the main branch instead of rolling out.
+    #[arg(long, conflicts_with = "percent")]
+    rollback: bool,
+}
+
+/// One ramp step: wait `delay`, then route `percent` to the branch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Step {
+    delay: Duration,
+    percent: f64,
+}
+
+/// Steps parsed from `--ramp`.
+#[derive(Debug, Clone)]
+struct Ramp(Vec<Step>);
+
+impl FromStr for Ramp {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = s
+            .split(',')
+            .map(|step| {
+                let (delay, percent) = step
+                    .split_once(':')
+                    .ok_or_else(|| format!("invalid step {step:?}: expected DELAY:PERCENT"))?;
+                Ok(Step { delay: parse_delay(delay)?, percent: parse_percent(percent)? })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(Self(steps))
+    }
+}
+
+/// Parses a delay such as `90s`, `10m` or `2h`.
+fn parse_delay(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let (number, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let value: u64 = number.parse().map_err(|_| format!("invalid delay {s:?}"))?;
+    let seconds = match unit {
+        "s" => value,
+        "m" => value * 60,
+        "h" => value * 3600,
+        _ => return Err(format!("invalid delay {s:?}: expected a number followed by s, m or h")),
+    };
+    Ok(Duration::from_secs(seconds))
+}
+
+/// Parses a traffic percentage between 0 and 100.
+fn parse_percent(s: &str) -> Result<f64, String> {
+    let value: f64 = s.trim().parse().map_err(|_| format!("invalid percentage {s:?}"))?;
+    if !(0.0..=100.0).contains(&value) {
+        return Err(format!("percentage {value} is not between 0 and 100"));
+    }
+    Ok(value)
+}
+
+/// Formats a delay for progress messages.
+fn format_delay(delay: Duration) -> String {
+    let secs = delay.as_secs();
+    if secs.is_multiple_of(3600) && secs > 0 {
+        format!("{}h", secs / 3600)
+    } else if secs.is_multiple_of(60) && secs > 0 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
+/// Execute `agents deploy`.
+pub(crate) async fn execute(
+    args: &DeployArgs,
+    client: &ElevenLabsClient,
+    cli: &Cli,
+) -> eyre::Result<()> {
+    let main_branch = match &args.main_branch {
+        Some(branch) => branch.clone(),
+        None => client.agents().get_agent(&args.agent).await?.main_branch_id.ok_or_else(|| {
+            eyre::eyre!("agent {} reports no main branch; pass --main-branch", args.agent)
+        })?,
+    };
+    if main_branch == args.branch {
+        return Err(eyre::eyre!("branch {} is the main branch", args.branch));
+    }
+
+    if args.rollback {
+        let response = route(client, args, &main_branch, 0.0).await?;
+        report(client, args, &main_branch).await;
+        print_json(&response, cli.format)?;
+        return Ok(());
+    }
+
+    let first = Step { delay: Duration::ZERO, percent: args.percent.unwrap_or_default() };
+    let mut steps = vec![first];
+    steps.extend(args.ramp.iter().flat_map(|ramp| ramp.0.iter().copied()));
+    if let Some(pair) = steps.windows(2).find(|pair| pair[1].percent <= pair[0].percent) {
+        return Err(eyre::eyre!(
+            "ramp percentages must increase, but {}% follows {}%; use --rollback to reduce traffic",
+            pair[1].percent,
+            pair[0].percent
+        ));
+    }
+
+    let total = steps.len();
+    let mut response = None;
+    for (i, step) in steps.iter().enumerate() {
+        if !step.delay.is_zero() {
+            eprintln!(
+                "[{}/{total}] waiting {} before routing {}% to {}",
+                i + 1,
+                format_delay(step.delay),
+                step.percent,
+                args.branch
+            );
+            tokio::time::sleep(step.delay).await;
+        }
+        match route(client, args, &main_branch, step.percent).await {
+            Ok(deployed) => response = Some(deployed),
+            Err(e) => {
+                eprintln!("[{}/{total}] deployment failed: {e}; rolling back", i + 1);
+                if let Err(rollback) = route(client, args, &main_branch, 0.0).await {
+                    eprintln!("rollback failed: {rollback}; rerun with --rollback");
+                }
+                return Err(e.into());
+            }
+        }
+        eprintln!("[{}/{total}] {} now receives {}%", i + 1, args.branch, step.percent);
+        report(client, args, &main_branch).await;
+    }
+
+    if let Some(response) = response {
+        print_json(&response, cli.format)?;
+    }
+    Ok(())
+}
+
+/// Routes `percent` of traffic to the rolled-out branch and the rest to the
+/// main branch.
+async fn route(
+    client: &ElevenLabsClient,
+    args: &DeployArgs,
+    main_branch: &str,
+    percent: f64,
+) -> elevenlabs_sdk::Result<AgentDeploymentResponse> {
+    let share = percent / 100.0;
+    let request = CreateDeploymentRequest::traffic_split([
+        (main_branch, 1.0 - share),
+        (args.branch.as_str(), share),
+    ]);
+    client.agents().create_deployment(&args.agent, &request).await
+}
+
+/// Prints the live traffic split. A failure to fetch it is reported but
+/// does not stop the rollout.
+async fn report(client: &ElevenLabsClient, args: &DeployArgs, main_branch: &str) {
+    match client.agents().list_branches(&args.agent).await {
+        Ok(branches) => {
+            for branch in branches.live() {
+                let role = if branch.id == main_branch { " (main)" } else { "" };
+                eprintln!(
+                    "    {}{role}: {:.1}% live",
+                    branch.id,
+                    branch.current_live_percentage * 100.0
+                );
+            }
+        }
+        Err(e) => eprintln!("    could not fetch live traffic: {e}"),
+    }
+}
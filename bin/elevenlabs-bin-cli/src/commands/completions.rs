@@ -0,0 +1,79 @@
+//! Shell completion CLI subcommand.
+//!
+//! Also holds [`ModelIdParser`], which offers the known model IDs to the
+//! generated scripts. Voice IDs differ per account and cannot be listed in a
+//! static script; `elevenlabs voices list` prints them.
+
+use std::ffi::OsStr;
+
+use clap::{
+    Arg, Args, Command, CommandFactory,
+    builder::{PossibleValue, StringValueParser, TypedValueParser},
+};
+use clap_complete::Shell;
+
+use crate::cli::Cli;
+
+/// Shell completion script generation.
+#[derive(Debug, Args)]
+pub(crate) struct CompletionsArgs {
+    /// Shell to generate the completion script for.
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+/// Execute the completions subcommand, printing the script to stdout.
+pub(crate) fn execute(args: &CompletionsArgs) {
+    let mut cmd = Cli::command();
+    let name = cmd.get_name().to_owned();
+    clap_complete::generate(args.shell, &mut cmd, name, &mut std::io::stdout());
+}
+
+/// Parses a `--model-id` value, accepting any ID but offering the known
+/// ones to shell completion.
+///
+/// Use with `hide_possible_values = true` so `--help` does not present the
+/// list as exhaustive.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ModelIdParser {
+    candidates: &'static [&'static str],
+}
+
+impl ModelIdParser {
+    /// Text-to-speech models.
+    pub(crate) const TTS: Self = Self {
+        candidates: &[
+            "eleven_v3",
+            "eleven_multilingual_v2",
+            "eleven_flash_v2_5",
+            "eleven_flash_v2",
+            "eleven_turbo_v2_5",
+            "eleven_turbo_v2",
+            "eleven_monolingual_v1",
+        ],
+    };
+
+    /// Speech-to-speech models.
+    pub(crate) const STS: Self =
+        Self { candidates: &["eleven_multilingual_sts_v2", "eleven_english_sts_v2"] };
+
+    /// Speech-to-text models.
+    pub(crate) const STT: Self = Self { candidates: &["scribe_v1", "scribe_v2"] };
+}
+
+impl TypedValueParser for ModelIdParser {
+    type Value = String;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(self.candidates.iter().copied().map(PossibleValue::new)))
+    }
+}
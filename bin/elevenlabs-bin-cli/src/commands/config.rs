@@ -0,0 +1,95 @@
+//! Configuration profile CLI subcommands.
+
+use clap::{Args, Subcommand};
+use elevenlabs_sdk::{Profile, ProfileFile};
+
+use crate::{cli::Cli, output::print_json};
+
+/// Configuration profile operations.
+#[derive(Debug, Args)]
+pub(crate) struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum ConfigCommands {
+    /// Set a setting in the selected profile, creating the profile if needed.
+    Set {
+        /// Setting name (api_key, base_url, voice_id, model_id, output_format).
+        key: String,
+        /// New value.
+        value: String,
+    },
+    /// Print a setting from the selected profile.
+    Get {
+        /// Setting name.
+        key: String,
+    },
+    /// Remove a setting from the selected profile.
+    Unset {
+        /// Setting name.
+        key: String,
+    },
+    /// List all profiles, with API keys masked.
+    List,
+    /// Make the selected profile the default.
+    Use,
+}
+
+/// Execute a config subcommand.
+pub(crate) fn execute(args: &ConfigArgs, cli: &Cli) -> eyre::Result<()> {
+    let path = ProfileFile::default_path()
+        .ok_or_else(|| eyre::eyre!("cannot locate the config file — set ELEVENLABS_CONFIG"))?;
+    let mut file = ProfileFile::load(&path)?;
+    let name = file.resolve_name(cli.profile.as_deref()).to_owned();
+
+    match &args.command {
+        ConfigCommands::Set { key, value } => {
+            file.profiles.entry(name).or_default().set(key, Some(value))?;
+            file.save(&path)?;
+        }
+        ConfigCommands::Get { key } => {
+            if let Some(value) = file.profile(&name)?.get(key)? {
+                println!("{value}");
+            }
+        }
+        ConfigCommands::Unset { key } => {
+            let Some(profile) = file.profiles.get_mut(&name) else {
+                return Ok(());
+            };
+            profile.set(key, None)?;
+            file.save(&path)?;
+        }
+        ConfigCommands::List => {
+            let profiles: serde_json::Map<String, serde_json::Value> = file
+                .profiles
+                .iter()
+                .map(|(name, profile)| Ok((name.clone(), serde_json::to_value(masked(profile))?)))
+                .collect::<Result<_, serde_json::Error>>()?;
+            let listing = serde_json::json!({
+                "path": path,
+                "default_profile": file.resolve_name(None),
+                "profiles": profiles,
+            });
+            print_json(&listing, cli.format)?;
+        }
+        ConfigCommands::Use => {
+            file.profile(&name)?;
+            file.default_profile = Some(name);
+            file.save(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns `profile` with all but the last four characters of its API key
+/// masked.
+fn masked(profile: &Profile) -> Profile {
+    let api_key = profile.api_key.as_deref().map(|key| {
+        let tail: String =
+            key.chars().rev().take(4).collect::<Vec<_>>().into_iter().rev().collect();
+        format!("****{tail}")
+    });
+    Profile { api_key, ..profile.clone() }
+}
@@ -73,22 +73,9 @@ pub(crate) async fn execute(args: &DubbingArgs, cli: &crate::cli::Cli) -> eyre::
     match &args.command {
         DubbingCommands::Create { source_lang, target_lang } => {
             let request = elevenlabs_sdk::types::CreateDubbingRequest {
-                name: None,
-                source_url: None,
                 source_lang: source_lang.clone(),
                 target_lang: Some(target_lang.clone()),
-                target_accent: None,
-                num_speakers: None,
-                watermark: None,
-                start_time: None,
-                end_time: None,
-                highest_resolution: None,
-                drop_background_audio: None,
-                use_profanity_filter: None,
-                dubbing_studio: None,
-                disable_voice_cloning: None,
-                mode: None,
-                csv_fps: None,
+                ..Default::default()
             };
             let response = client.dubbing().create(&request, None).await?;
             crate::output::print_json(&response, cli.format)?;
@@ -1,6 +1,10 @@
 //! Forced alignment CLI subcommands.
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
+use elevenlabs_sdk::{
+    FileInput,
+    types::{CaptionOptions, ForcedAlignmentRequest},
+};
 
 /// Forced alignment operations.
 #[derive(Debug, Args)]
@@ -20,22 +24,66 @@ pub(crate) enum ForcedAlignmentCommands {
         /// Transcript text to align.
         #[arg(long)]
         text: String,
+
+        /// Have the server spool the upload to disk (for long recordings).
+        #[arg(long)]
+        spooled: bool,
+
+        /// Print captions in this format instead of the JSON response.
+        #[arg(long, value_enum)]
+        captions: Option<CaptionFormat>,
+
+        /// Maximum characters per caption line.
+        #[arg(long, default_value_t = 42)]
+        max_line_length: usize,
+
+        /// Speaker label to attach to every caption.
+        #[arg(long)]
+        speaker: Option<String>,
     },
 }
 
+/// Caption output format.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum CaptionFormat {
+    /// SubRip (`.srt`).
+    Srt,
+    /// WebVTT (`.vtt`).
+    Vtt,
+    /// Advanced SubStation Alpha (`.ass`).
+    Ass,
+}
+
 /// Execute a forced-alignment subcommand.
 pub(crate) async fn execute(args: &ForcedAlignmentArgs, cli: &crate::cli::Cli) -> eyre::Result<()> {
     let client = crate::context::build_client(cli)?;
 
     match &args.command {
-        ForcedAlignmentCommands::Create { audio, text } => {
-            let audio_data = tokio::fs::read(audio).await?;
-            let filename = std::path::Path::new(audio)
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("audio.mp3");
-            let response = client.forced_alignment().create(&audio_data, filename, text).await?;
-            crate::output::print_json(&response, cli.format)?;
+        ForcedAlignmentCommands::Create {
+            audio,
+            text,
+            spooled,
+            captions,
+            max_line_length,
+            speaker,
+        } => {
+            let request = ForcedAlignmentRequest {
+                enabled_spooled_file: *spooled,
+                ..ForcedAlignmentRequest::new(text.as_str())
+            };
+            let response =
+                client.forced_alignment().align(FileInput::from_path(audio), &request).await?;
+            let options = CaptionOptions {
+                max_line_length: *max_line_length,
+                speaker: speaker.clone(),
+                ..Default::default()
+            };
+            match captions {
+                Some(CaptionFormat::Srt) => print!("{}", response.to_srt(&options)),
+                Some(CaptionFormat::Vtt) => print!("{}", response.to_vtt(&options)),
+                Some(CaptionFormat::Ass) => print!("{}", response.to_ass(&options)),
+                None => crate::output::print_json(&response, cli.format)?,
+            }
         }
     }
     Ok(())
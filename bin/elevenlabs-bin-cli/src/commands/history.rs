@@ -1,6 +1,7 @@
 //! History CLI subcommands.
 
 use clap::{Args, Subcommand};
+use elevenlabs_sdk::types::{DateRange, Timestamp};
 
 /// History operations.
 #[derive(Debug, Args)]
@@ -12,7 +13,15 @@ pub(crate) struct HistoryArgs {
 #[derive(Debug, Subcommand)]
 pub(crate) enum HistoryCommands {
     /// List history items.
-    List,
+    List {
+        /// Only items created at or after this Unix time (seconds).
+        #[arg(long)]
+        after: Option<i64>,
+
+        /// Only items created at or before this Unix time (seconds).
+        #[arg(long)]
+        before: Option<i64>,
+    },
 
     /// Get a specific history item.
     Get {
@@ -45,8 +54,12 @@ pub(crate) async fn execute(args: &HistoryArgs, cli: &crate::cli::Cli) -> eyre::
     let client = crate::context::build_client(cli)?;
 
     match &args.command {
-        HistoryCommands::List => {
-            let response = client.history().list(None, None, None).await?;
+        HistoryCommands::List { after, before } => {
+            let range = DateRange {
+                after: after.map(Timestamp::from),
+                before: before.map(Timestamp::from),
+            };
+            let response = client.history().list(None, None, None, Some(range)).await?;
             crate::output::print_json(&response, cli.format)?;
         }
         HistoryCommands::Get { history_item_id } => {
@@ -0,0 +1,25 @@
+//! Man page generation CLI subcommand.
+
+use std::path::PathBuf;
+
+use clap::{Args, CommandFactory};
+
+use crate::cli::Cli;
+
+/// Man page generation.
+#[derive(Debug, Args)]
+pub(crate) struct ManpagesArgs {
+    /// Directory to write the pages into, created if missing. One page is
+    /// written per command and subcommand (`elevenlabs.1`,
+    /// `elevenlabs-tts.1`, ...).
+    #[arg(long)]
+    pub out_dir: PathBuf,
+}
+
+/// Execute the manpages subcommand.
+pub(crate) fn execute(args: &ManpagesArgs) -> eyre::Result<()> {
+    std::fs::create_dir_all(&args.out_dir)?;
+    clap_mangen::generate_to(Cli::command(), &args.out_dir)?;
+    eprintln!("Man pages written to {}", args.out_dir.display());
+    Ok(())
+}
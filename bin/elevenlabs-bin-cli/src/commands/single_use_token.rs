@@ -1,6 +1,7 @@
 //! Single-use token CLI subcommands.
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
+use elevenlabs_sdk::types::TokenPurpose;
 
 use crate::{cli::Cli, context::build_client, output::print_json};
 
@@ -14,7 +15,29 @@ pub(crate) struct SingleUseTokenArgs {
 #[derive(Debug, Subcommand)]
 pub(crate) enum SingleUseTokenCommands {
     /// Create a single-use token.
-    Create,
+    Create {
+        /// What the token will be used for.
+        #[arg(long, value_enum, default_value_t = Purpose::TtsWebsocket)]
+        purpose: Purpose,
+    },
+}
+
+/// Single-use token purpose.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum Purpose {
+    /// Realtime speech-to-text sessions.
+    RealtimeScribe,
+    /// Text-to-speech input-streaming WebSocket sessions.
+    TtsWebsocket,
+}
+
+impl From<Purpose> for TokenPurpose {
+    fn from(purpose: Purpose) -> Self {
+        match purpose {
+            Purpose::RealtimeScribe => Self::RealtimeScribe,
+            Purpose::TtsWebsocket => Self::TtsWebsocket,
+        }
+    }
 }
 
 /// Execute a single-use-token subcommand.
@@ -22,8 +45,8 @@ pub(crate) async fn execute(args: &SingleUseTokenArgs, cli: &Cli) -> eyre::Resul
     let client = build_client(cli)?;
 
     match &args.command {
-        SingleUseTokenCommands::Create => {
-            let response = client.single_use_token().create("tts").await?;
+        SingleUseTokenCommands::Create { purpose } => {
+            let response = client.single_use_token().create((*purpose).into()).await?;
             print_json(&response, cli.format)?;
         }
     }
@@ -25,6 +25,10 @@ pub(crate) enum SoundGenerationCommands {
         #[arg(short, long)]
         output: Option<String>,
     },
+
+    /// Generate every prompt in a file with every seed in a range, writing
+    /// the sounds and a manifest into a directory.
+    Sweep(crate::commands::sound_generation_sweep::SweepArgs),
 }
 
 /// Execute a sound-generation subcommand.
@@ -48,6 +52,9 @@ pub(crate) async fn execute(args: &SoundGenerationArgs, cli: &crate::cli::Cli) -
                 stdout.write_all(&audio).await?;
             }
         }
+        SoundGenerationCommands::Sweep(sweep) => {
+            crate::commands::sound_generation_sweep::execute(sweep, &client).await?;
+        }
     }
     Ok(())
 }
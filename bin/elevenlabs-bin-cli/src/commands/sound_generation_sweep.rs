@@ -0,0 +1,232 @@
+//! `sound-generation sweep` — generate every prompt in a file with every seed
+//! in a range, for comparing variations side by side.
+//!
+//! Each clip is written as `<prompt>-seed<seed>.mp3`, where `<prompt>` is the
+//! 1-based line number of the prompt among the non-empty lines, and a
+//! `manifest.json` describing the whole matrix is written alongside.
+
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Mutex, PoisonError},
+};
+
+use clap::Args;
+use elevenlabs_sdk::{ElevenLabsClient, types::SoundGenerationRequest};
+use futures_util::{StreamExt, stream};
+use serde::Serialize;
+
+/// Name of the manifest written into the output directory.
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Generate a matrix of sound effects over prompts and seeds.
+#[derive(Debug, Args)]
+pub(crate) struct SweepArgs {
+    /// Text file with one prompt per line. Blank lines and lines starting
+    /// with `#` are ignored.
+    #[arg(long)]
+    prompt_file: PathBuf,
+
+    /// Seeds to generate each prompt with: a range such as `1..10`
+    /// (exclusive) or `1..=10` (inclusive), or a list such as `3,7,42`.
+    #[arg(long)]
+    seeds: Seeds,
+
+    /// Directory to write audio files and the manifest into.
+    #[arg(long)]
+    out_dir: PathBuf,
+
+    /// Duration of each sound in seconds.
+    #[arg(long)]
+    duration_seconds: Option<f64>,
+
+    /// How closely generation follows the prompt (0.0–1.0).
+    #[arg(long)]
+    prompt_influence: Option<f64>,
+
+    /// Generate sounds that loop smoothly.
+    #[arg(long = "loop")]
+    looping: bool,
+
+    /// Model ID to use.
+    #[arg(long)]
+    model_id: Option<String>,
+
+    /// Number of sounds generated at once.
+    #[arg(long, default_value_t = 4)]
+    concurrency: usize,
+}
+
+/// Seeds parsed from `--seeds`.
+#[derive(Debug, Clone)]
+struct Seeds(Vec<u32>);
+
+impl FromStr for Seeds {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number =
+            |v: &str| v.trim().parse::<u32>().map_err(|e| format!("invalid seed {v:?}: {e}"));
+        let seeds: Vec<u32> = if let Some((start, end)) = s.split_once("..=") {
+            (number(start)?..=number(end)?).collect()
+        } else if let Some((start, end)) = s.split_once("..") {
+            (number(start)?..number(end)?).collect()
+        } else {
+            s.split(',').map(number).collect::<Result<_, _>>()?
+        };
+        if seeds.is_empty() {
+            return Err(format!("{s:?} contains no seeds"));
+        }
+        Ok(Self(seeds))
+    }
+}
+
+/// One sound to generate.
+#[derive(Debug)]
+struct Job {
+    prompt_index: usize,
+    prompt: String,
+    seed: u32,
+    filename: String,
+}
+
+/// One manifest entry.
+#[derive(Debug, Serialize)]
+struct Entry {
+    prompt_index: usize,
+    prompt: String,
+    seed: u32,
+    file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// The manifest written next to the generated files.
+#[derive(Debug, Serialize)]
+struct Manifest<'a> {
+    model_id: &'a str,
+    duration_seconds: Option<f64>,
+    prompt_influence: f64,
+    r#loop: bool,
+    entries: Vec<Entry>,
+}
+
+/// Execute `sound-generation sweep`.
+pub(crate) async fn execute(args: &SweepArgs, client: &ElevenLabsClient) -> eyre::Result<()> {
+    let prompts = read_prompts(&args.prompt_file)?;
+    if prompts.is_empty() {
+        return Err(eyre::eyre!("{} contains no prompts", args.prompt_file.display()));
+    }
+    let template = request_template(args);
+
+    let jobs: Vec<Job> = prompts
+        .iter()
+        .enumerate()
+        .flat_map(|(i, prompt)| {
+            args.seeds.0.iter().map(move |&seed| Job {
+                prompt_index: i + 1,
+                prompt: prompt.clone(),
+                seed,
+                filename: format!("{:03}-seed{seed}.mp3", i + 1),
+            })
+        })
+        .collect();
+    let total = jobs.len();
+    eprintln!("{} prompts x {} seeds = {total} sounds", prompts.len(), args.seeds.0.len());
+
+    tokio::fs::create_dir_all(&args.out_dir).await?;
+    let completed = Mutex::new(0_usize);
+    let mut entries: Vec<Entry> = stream::iter(jobs)
+        .map(|job| {
+            let template = &template;
+            let completed = &completed;
+            async move {
+                let result = generate(client, template, &job, &args.out_dir).await;
+                let n = {
+                    let mut completed = completed.lock().unwrap_or_else(PoisonError::into_inner);
+                    *completed += 1;
+                    *completed
+                };
+                let status = if result.is_ok() { "ok" } else { "failed" };
+                eprintln!("[{n}/{total}] {status} {}", job.filename);
+                let (bytes, error) = match result {
+                    Ok(bytes) => (Some(bytes), None),
+                    Err(e) => (None, Some(e.to_string())),
+                };
+                Entry {
+                    prompt_index: job.prompt_index,
+                    prompt: job.prompt,
+                    seed: job.seed,
+                    file: job.filename,
+                    bytes,
+                    error,
+                }
+            }
+        })
+        .buffer_unordered(args.concurrency.max(1))
+        .collect()
+        .await;
+    entries.sort_by_key(|e| (e.prompt_index, e.seed));
+
+    let failed = entries.iter().filter(|e| e.error.is_some()).count();
+    let manifest = Manifest {
+        model_id: &template.model_id,
+        duration_seconds: template.duration_seconds,
+        prompt_influence: template.prompt_influence,
+        r#loop: template.r#loop,
+        entries,
+    };
+    let manifest_path = args.out_dir.join(MANIFEST_FILE_NAME);
+    tokio::fs::write(&manifest_path, serde_json::to_vec_pretty(&manifest)?).await?;
+    eprintln!("Manifest written to {}", manifest_path.display());
+
+    if failed > 0 {
+        return Err(eyre::eyre!("{failed} of {total} sounds failed"));
+    }
+    Ok(())
+}
+
+/// Builds the request shared by every sound, minus text and seed.
+fn request_template(args: &SweepArgs) -> SoundGenerationRequest {
+    let mut request = SoundGenerationRequest {
+        r#loop: args.looping,
+        duration_seconds: args.duration_seconds,
+        ..Default::default()
+    };
+    if let Some(influence) = args.prompt_influence {
+        request.prompt_influence = influence;
+    }
+    if let Some(model_id) = &args.model_id {
+        request.model_id.clone_from(model_id);
+    }
+    request
+}
+
+/// Generates one sound and writes it into `out_dir`.
+async fn generate(
+    client: &ElevenLabsClient,
+    template: &SoundGenerationRequest,
+    job: &Job,
+    out_dir: &Path,
+) -> eyre::Result<usize> {
+    let request = SoundGenerationRequest {
+        text: job.prompt.clone(),
+        seed: Some(job.seed),
+        ..template.clone()
+    };
+    let audio = client.sound_generation().generate(&request).await?;
+    tokio::fs::write(out_dir.join(&job.filename), &audio).await?;
+    Ok(audio.len())
+}
+
+/// Reads the non-empty, non-comment lines of the prompt file.
+fn read_prompts(path: &Path) -> eyre::Result<Vec<String>> {
+    Ok(std::fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_owned)
+        .collect())
+}
@@ -2,6 +2,8 @@
 
 use clap::{Args, Subcommand};
 
+use crate::commands::completions::ModelIdParser;
+
 /// Speech-to-speech conversion operations.
 #[derive(Debug, Args)]
 pub(crate) struct SpeechToSpeechArgs {
@@ -22,7 +24,7 @@ pub(crate) enum SpeechToSpeechCommands {
         input: String,
 
         /// Model ID to use.
-        #[arg(long)]
+        #[arg(long, value_parser = ModelIdParser::STS, hide_possible_values = true)]
         model_id: Option<String>,
 
         /// Output file path for the converted audio.
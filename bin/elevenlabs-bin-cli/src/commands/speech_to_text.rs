@@ -2,6 +2,8 @@
 
 use clap::{Args, Subcommand};
 
+use crate::commands::completions::ModelIdParser;
+
 /// Speech-to-text transcription operations.
 #[derive(Debug, Args)]
 pub(crate) struct SpeechToTextArgs {
@@ -18,7 +20,7 @@ pub(crate) enum SpeechToTextCommands {
         input: String,
 
         /// Model ID to use for transcription.
-        #[arg(long)]
+        #[arg(long, value_parser = ModelIdParser::STT, hide_possible_values = true)]
         model_id: Option<String>,
     },
 
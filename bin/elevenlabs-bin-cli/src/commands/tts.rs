@@ -1,6 +1,11 @@
 //! Text-to-speech CLI subcommands.
 
-use clap::{Args, Subcommand};
+use std::time::Duration;
+
+use clap::{Args, Subcommand, ValueEnum};
+use elevenlabs_sdk::{Profile, audio, types::OutputFormat};
+
+use crate::commands::completions::ModelIdParser;
 
 /// Text-to-speech operations.
 #[derive(Debug, Args)]
@@ -13,67 +18,155 @@ pub(crate) struct TtsArgs {
 pub(crate) enum TtsCommands {
     /// Convert text to speech audio.
     Convert {
-        /// Voice ID to use for synthesis.
+        /// Voice ID to use for synthesis [default: the profile's voice_id].
         #[arg(long)]
-        voice_id: String,
+        voice_id: Option<String>,
 
         /// Text to convert to speech.
         #[arg(long)]
         text: String,
 
-        /// Model ID to use.
-        #[arg(long)]
+        /// Model ID to use [default: the profile's model_id].
+        #[arg(long, value_parser = ModelIdParser::TTS, hide_possible_values = true)]
         model_id: Option<String>,
 
         /// Output file path for the audio.
         #[arg(short, long)]
         output: Option<String>,
+
+        /// Audio format to write [default: the profile's output_format, else
+        /// mp3].
+        #[arg(long, value_enum)]
+        audio_format: Option<AudioFormat>,
+
+        /// Sample rate in Hz for PCM and WAV output.
+        #[arg(long, default_value_t = 24_000)]
+        sample_rate: u32,
     },
 
     /// Convert text to speech and stream the audio.
     ConvertStream {
-        /// Voice ID to use for synthesis.
+        /// Voice ID to use for synthesis [default: the profile's voice_id].
         #[arg(long)]
-        voice_id: String,
+        voice_id: Option<String>,
 
         /// Text to convert to speech.
         #[arg(long)]
         text: String,
 
-        /// Model ID to use.
-        #[arg(long)]
+        /// Model ID to use [default: the profile's model_id].
+        #[arg(long, value_parser = ModelIdParser::TTS, hide_possible_values = true)]
         model_id: Option<String>,
 
         /// Output file path for the audio.
         #[arg(short, long)]
         output: Option<String>,
+
+        /// Audio format to write [default: the profile's output_format, else
+        /// mp3].
+        #[arg(long, value_enum)]
+        audio_format: Option<AudioFormat>,
+
+        /// Sample rate in Hz for PCM and WAV output.
+        #[arg(long, default_value_t = 24_000)]
+        sample_rate: u32,
     },
 
     /// Convert text to speech with timestamps.
     ConvertWithTimestamps {
-        /// Voice ID to use for synthesis.
+        /// Voice ID to use for synthesis [default: the profile's voice_id].
         #[arg(long)]
-        voice_id: String,
+        voice_id: Option<String>,
 
         /// Text to convert to speech.
         #[arg(long)]
         text: String,
 
-        /// Model ID to use.
-        #[arg(long)]
+        /// Model ID to use [default: the profile's model_id].
+        #[arg(long, value_parser = ModelIdParser::TTS, hide_possible_values = true)]
         model_id: Option<String>,
 
         /// Output file path for the audio.
         #[arg(short, long)]
         output: Option<String>,
     },
+
+    /// Synthesize many clips from a CSV manifest or a directory of text
+    /// files, resuming where an earlier run stopped.
+    Batch(crate::commands::tts_batch::BatchArgs),
+}
+
+/// Audio format written by the convert subcommands.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum AudioFormat {
+    /// MP3 at 44.1 kHz, 128 kbps.
+    Mp3,
+    /// Raw 16-bit little-endian PCM without a header.
+    Pcm,
+    /// 16-bit PCM in a WAV container, wrapped locally.
+    Wav,
+}
+
+impl AudioFormat {
+    /// Returns the format to request from the API.
+    fn output_format(self, sample_rate: u32) -> eyre::Result<OutputFormat> {
+        match self {
+            Self::Mp3 => Ok(OutputFormat::default()),
+            Self::Pcm | Self::Wav => OutputFormat::pcm(sample_rate)
+                .ok_or_else(|| eyre::eyre!("unsupported PCM sample rate {sample_rate} Hz")),
+        }
+    }
+
+    /// Converts audio returned in [`output_format`](Self::output_format) to
+    /// this format.
+    fn finish(self, audio: &[u8], sample_rate: u32) -> eyre::Result<Vec<u8>> {
+        match self {
+            Self::Mp3 | Self::Pcm => Ok(audio.to_vec()),
+            Self::Wav => Ok(audio::pcm_to_wav(audio, sample_rate, 1)?.to_vec()),
+        }
+    }
+}
+
+/// Returns the format to request from the API and the format to write: the
+/// `--audio-format` if given, else the profile's `output_format` written as
+/// returned.
+fn resolve_format(
+    audio_format: Option<AudioFormat>,
+    sample_rate: u32,
+    profile: &Profile,
+) -> eyre::Result<(OutputFormat, Option<AudioFormat>)> {
+    match audio_format {
+        Some(audio_format) => Ok((audio_format.output_format(sample_rate)?, Some(audio_format))),
+        None => Ok((profile.output_format.unwrap_or_default(), None)),
+    }
+}
+
+/// Returns `--voice-id` if given, else the profile's `voice_id`.
+fn resolve_voice_id<'a>(
+    voice_id: &'a Option<String>,
+    profile: &'a Profile,
+) -> eyre::Result<&'a str> {
+    voice_id
+        .as_deref()
+        .or(profile.voice_id.as_deref())
+        .ok_or_else(|| eyre::eyre!("voice ID required — set --voice-id or a profile voice_id"))
 }
 
-/// Write audio bytes to file or stdout.
-async fn write_audio(data: &[u8], output: &Option<String>) -> eyre::Result<()> {
+/// Write audio bytes to file or stdout, reporting their playing time if
+/// known.
+async fn write_audio(
+    data: &[u8],
+    duration: Option<Duration>,
+    output: &Option<String>,
+) -> eyre::Result<()> {
     if let Some(path) = output {
         tokio::fs::write(path, data).await?;
-        eprintln!("Audio written to {path}");
+        match duration {
+            Some(duration) => {
+                eprintln!("Audio written to {path} ({:.1} s)", duration.as_secs_f64());
+            }
+            None => eprintln!("Audio written to {path}"),
+        }
     } else {
         use tokio::io::AsyncWriteExt;
         let mut stdout = tokio::io::stdout();
@@ -85,35 +178,61 @@ async fn write_audio(data: &[u8], output: &Option<String>) -> eyre::Result<()> {
 /// Execute a text-to-speech subcommand.
 pub(crate) async fn execute(args: &TtsArgs, cli: &crate::cli::Cli) -> eyre::Result<()> {
     let client = crate::context::build_client(cli)?;
+    let profile = crate::context::active_profile(cli)?;
 
     match &args.command {
-        TtsCommands::Convert { voice_id, text, model_id, output } => {
+        TtsCommands::Convert { voice_id, text, model_id, output, audio_format, sample_rate } => {
+            let voice_id = resolve_voice_id(voice_id, &profile)?;
             let mut request = elevenlabs_sdk::types::TextToSpeechRequest::new(text);
-            request.model_id = model_id.clone();
-            let audio = client.text_to_speech().convert(voice_id, &request, None, None).await?;
-            write_audio(&audio, output).await?;
+            request.model_id = model_id.clone().or_else(|| profile.model_id.clone());
+            let (format, audio_format) = resolve_format(*audio_format, *sample_rate, &profile)?;
+            let audio =
+                client.text_to_speech().convert(voice_id, &request, Some(format), None).await?;
+            let duration = audio::estimate_duration(&audio, format);
+            let audio = match audio_format {
+                Some(audio_format) => audio_format.finish(&audio, *sample_rate)?,
+                None => audio.to_vec(),
+            };
+            write_audio(&audio, duration, output).await?;
         }
-        TtsCommands::ConvertStream { voice_id, text, model_id, output } => {
+        TtsCommands::ConvertStream {
+            voice_id,
+            text,
+            model_id,
+            output,
+            audio_format,
+            sample_rate,
+        } => {
             use futures_util::StreamExt;
+            let voice_id = resolve_voice_id(voice_id, &profile)?;
             let mut request = elevenlabs_sdk::types::TextToSpeechRequest::new(text);
-            request.model_id = model_id.clone();
+            request.model_id = model_id.clone().or_else(|| profile.model_id.clone());
+            let (format, audio_format) = resolve_format(*audio_format, *sample_rate, &profile)?;
             let tts = client.text_to_speech();
-            let mut stream = tts.convert_stream(voice_id, &request, None, None).await?;
+            let mut stream = tts.convert_stream(voice_id, &request, Some(format), None).await?;
             let mut buf = Vec::new();
             while let Some(chunk) = stream.next().await {
                 buf.extend_from_slice(&chunk?);
             }
-            write_audio(&buf, output).await?;
+            let duration = audio::estimate_duration(&buf, format);
+            if let Some(audio_format) = audio_format {
+                buf = audio_format.finish(&buf, *sample_rate)?;
+            }
+            write_audio(&buf, duration, output).await?;
         }
         TtsCommands::ConvertWithTimestamps { voice_id, text, model_id, output: _ } => {
+            let voice_id = resolve_voice_id(voice_id, &profile)?;
             let mut request = elevenlabs_sdk::types::TextToSpeechRequest::new(text);
-            request.model_id = model_id.clone();
+            request.model_id = model_id.clone().or_else(|| profile.model_id.clone());
             let response = client
                 .text_to_speech()
                 .convert_with_timestamps(voice_id, &request, None, None)
                 .await?;
             crate::output::print_json(&response, cli.format)?;
         }
+        TtsCommands::Batch(batch) => {
+            crate::commands::tts_batch::execute(batch, &client).await?;
+        }
     }
     Ok(())
 }
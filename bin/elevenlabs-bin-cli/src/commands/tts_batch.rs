@@ -0,0 +1,301 @@
+//! `tts batch` — synthesize many clips from a CSV manifest or a directory of
+//! text files.
+//!
+//! Completed clips are recorded in a state file inside the output directory,
+//! so an interrupted run can be resumed by running the same command again.
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    path::{Component, Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use clap::Args;
+use elevenlabs_sdk::{ElevenLabsClient, types::TextToSpeechRequest};
+use futures_util::{StreamExt, stream};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::completions::ModelIdParser;
+
+/// Name of the resume state file written into the output directory.
+const STATE_FILE_NAME: &str = ".tts-batch-state.json";
+
+/// Synthesize clips in bulk from a manifest.
+#[derive(Debug, Args)]
+pub(crate) struct BatchArgs {
+    /// CSV manifest with `text`, `voice` and `filename` columns, or a
+    /// directory of `.txt` files (one clip per file).
+    #[arg(long)]
+    input: PathBuf,
+
+    /// Directory to write audio files and the resume state into.
+    #[arg(long)]
+    out_dir: PathBuf,
+
+    /// Voice ID for directory input and for CSV rows with an empty `voice`.
+    #[arg(long)]
+    voice_id: Option<String>,
+
+    /// Model ID to use.
+    #[arg(long, value_parser = ModelIdParser::TTS, hide_possible_values = true)]
+    model_id: Option<String>,
+
+    /// Number of clips synthesized at once.
+    #[arg(long, default_value_t = 4)]
+    concurrency: usize,
+
+    /// Ignore the state file and synthesize every clip again.
+    #[arg(long)]
+    restart: bool,
+}
+
+/// One clip to synthesize.
+#[derive(Debug)]
+struct Job {
+    text: String,
+    voice_id: String,
+    filename: String,
+}
+
+/// Clips completed by earlier runs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BatchState {
+    completed: BTreeSet<String>,
+}
+
+/// Result of one clip, for the summary table.
+#[derive(Debug)]
+enum Outcome {
+    Written(usize),
+    Skipped,
+    Failed(String),
+}
+
+/// Execute `tts batch`.
+pub(crate) async fn execute(args: &BatchArgs, client: &ElevenLabsClient) -> eyre::Result<()> {
+    let jobs = if args.input.is_dir() {
+        jobs_from_directory(&args.input, args.voice_id.as_deref())?
+    } else {
+        let manifest = tokio::fs::read_to_string(&args.input).await?;
+        jobs_from_csv(&manifest, args.voice_id.as_deref())?
+    };
+
+    tokio::fs::create_dir_all(&args.out_dir).await?;
+    let state_path = args.out_dir.join(STATE_FILE_NAME);
+    let state = if args.restart || !state_path.exists() {
+        BatchState::default()
+    } else {
+        serde_json::from_str(&tokio::fs::read_to_string(&state_path).await?)?
+    };
+
+    let (done, pending): (Vec<Job>, Vec<Job>) = jobs.into_iter().partition(|job| {
+        state.completed.contains(&job.filename) && args.out_dir.join(&job.filename).exists()
+    });
+    let total = pending.len();
+    eprintln!("{} clips to synthesize, {} already done", total, done.len());
+
+    let state = Mutex::new(state);
+    let completed = Mutex::new(0_usize);
+    let mut results: Vec<(Job, Outcome)> = stream::iter(pending)
+        .map(|job| {
+            let state = &state;
+            let state_path = &state_path;
+            let completed = &completed;
+            async move {
+                let outcome = match synthesize(client, &job, args).await {
+                    Ok(bytes) => match record_completed(state, state_path, &job.filename) {
+                        Ok(()) => Outcome::Written(bytes),
+                        Err(e) => Outcome::Failed(format!("saving state: {e}")),
+                    },
+                    Err(e) => Outcome::Failed(e.to_string()),
+                };
+                let n = {
+                    let mut completed = completed.lock().unwrap_or_else(PoisonError::into_inner);
+                    *completed += 1;
+                    *completed
+                };
+                let status = if matches!(outcome, Outcome::Failed(_)) { "failed" } else { "ok" };
+                eprintln!("[{n}/{total}] {status} {}", job.filename);
+                (job, outcome)
+            }
+        })
+        .buffer_unordered(args.concurrency.max(1))
+        .collect()
+        .await;
+    results.extend(done.into_iter().map(|job| (job, Outcome::Skipped)));
+    results.sort_by(|a, b| a.0.filename.cmp(&b.0.filename));
+
+    print_summary(&results);
+    let failed = results.iter().filter(|(_, o)| matches!(o, Outcome::Failed(_))).count();
+    if failed > 0 {
+        return Err(eyre::eyre!("{failed} of {} clips failed; rerun to retry them", results.len()));
+    }
+    Ok(())
+}
+
+/// Synthesizes one clip and writes it into the output directory.
+async fn synthesize(client: &ElevenLabsClient, job: &Job, args: &BatchArgs) -> eyre::Result<usize> {
+    let mut request = TextToSpeechRequest::new(&job.text);
+    request.model_id.clone_from(&args.model_id);
+    let audio = client.text_to_speech().convert(&job.voice_id, &request, None, None).await?;
+
+    let path = args.out_dir.join(&job.filename);
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(&path, &audio).await?;
+    Ok(audio.len())
+}
+
+/// Marks `filename` as done and persists the state file.
+///
+/// The file is replaced atomically so an interrupted run never leaves it
+/// half-written.
+fn record_completed(state: &Mutex<BatchState>, path: &Path, filename: &str) -> eyre::Result<()> {
+    let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+    state.completed.insert(filename.to_owned());
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(&*state)?)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Prints one row per clip followed by totals.
+fn print_summary(results: &[(Job, Outcome)]) {
+    let width = results.iter().map(|(job, _)| job.filename.len()).max().unwrap_or(0).max(4);
+    println!("{:<8}  {:<width$}  DETAIL", "STATUS", "FILE");
+    let (mut written, mut skipped, mut failed) = (0, 0, 0);
+    for (job, outcome) in results {
+        let (status, detail) = match outcome {
+            Outcome::Written(bytes) => {
+                written += 1;
+                ("ok", format!("{bytes} bytes"))
+            }
+            Outcome::Skipped => {
+                skipped += 1;
+                ("skipped", "already done".to_owned())
+            }
+            Outcome::Failed(error) => {
+                failed += 1;
+                ("failed", error.clone())
+            }
+        };
+        println!("{status:<8}  {:<width$}  {detail}", job.filename);
+    }
+    println!("\n{written} written, {skipped} skipped, {failed} failed");
+}
+
+/// Builds one job per `.txt` file in `dir`, named after the file stem.
+fn jobs_from_directory(dir: &Path, voice_id: Option<&str>) -> eyre::Result<Vec<Job>> {
+    let voice_id =
+        voice_id.ok_or_else(|| eyre::eyre!("--voice-id is required for directory input"))?;
+    let mut jobs = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let text = std::fs::read_to_string(&path)?;
+        if text.trim().is_empty() {
+            continue;
+        }
+        jobs.push(Job {
+            text: text.trim().to_owned(),
+            voice_id: voice_id.to_owned(),
+            filename: format!("{stem}.mp3"),
+        });
+    }
+    jobs.sort_by(|a, b| a.filename.cmp(&b.filename));
+    Ok(jobs)
+}
+
+/// Builds jobs from a CSV manifest with a header row.
+///
+/// Recognised columns are `text`, `voice` (or `voice_id`) and `filename`
+/// (or `file`); other columns are ignored. Filenames without an extension
+/// get `.mp3`.
+fn jobs_from_csv(manifest: &str, default_voice: Option<&str>) -> eyre::Result<Vec<Job>> {
+    let mut rows = parse_csv(manifest)?.into_iter();
+    let header = rows.next().ok_or_else(|| eyre::eyre!("manifest is empty"))?;
+    let column = |names: &[&str]| {
+        header.iter().position(|h| names.iter().any(|n| h.trim().eq_ignore_ascii_case(n)))
+    };
+    let text_col = column(&["text"]).ok_or_else(|| eyre::eyre!("manifest has no `text` column"))?;
+    let file_col = column(&["filename", "file"])
+        .ok_or_else(|| eyre::eyre!("manifest has no `filename` column"))?;
+    let voice_col = column(&["voice", "voice_id"]);
+
+    let mut seen = HashSet::new();
+    let mut jobs = Vec::new();
+    for (i, row) in rows.enumerate() {
+        let line = i + 2;
+        let field = |col: usize| row.get(col).map_or("", |v| v.trim());
+        if row.iter().all(|v| v.trim().is_empty()) {
+            continue;
+        }
+
+        let text = field(text_col);
+        if text.is_empty() {
+            return Err(eyre::eyre!("row {line}: `text` is empty"));
+        }
+        let voice_id = voice_col
+            .map(field)
+            .filter(|v| !v.is_empty())
+            .or(default_voice)
+            .ok_or_else(|| eyre::eyre!("row {line}: no voice and no --voice-id given"))?;
+
+        let mut filename = field(file_col).to_owned();
+        let relative = Path::new(&filename).components().all(|c| matches!(c, Component::Normal(_)));
+        if filename.is_empty() || !relative {
+            return Err(eyre::eyre!("row {line}: invalid filename {filename:?}"));
+        }
+        if Path::new(&filename).extension().is_none() {
+            filename.push_str(".mp3");
+        }
+        if !seen.insert(filename.clone()) {
+            return Err(eyre::eyre!("row {line}: duplicate filename {filename:?}"));
+        }
+
+        jobs.push(Job { text: text.to_owned(), voice_id: voice_id.to_owned(), filename });
+    }
+    Ok(jobs)
+}
+
+/// Parses RFC 4180 CSV: comma-separated fields, optionally double-quoted,
+/// with `""` escaping a quote inside a quoted field.
+fn parse_csv(input: &str) -> eyre::Result<Vec<Vec<String>>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(eyre::eyre!("manifest has an unterminated quoted field"));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
@@ -1,9 +1,30 @@
 //! Voices CLI subcommands.
 
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
 use clap::{Args, Subcommand};
+use elevenlabs_sdk::{FileInput, types::AddVoiceRequest};
+use futures_util::StreamExt;
+use tokio::io::AsyncWriteExt;
 
 use crate::{cli::Cli, context::build_client, output::print_json};
 
+/// Largest sample accepted for instant voice cloning.
+const MAX_SAMPLE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Audio extensions accepted as clone samples, with their MIME types.
+const SAMPLE_FORMATS: &[(&str, &str)] = &[
+    ("mp3", "audio/mpeg"),
+    ("wav", "audio/wav"),
+    ("m4a", "audio/mp4"),
+    ("flac", "audio/flac"),
+    ("ogg", "audio/ogg"),
+    ("webm", "audio/webm"),
+];
+
 /// Voice management operations.
 #[derive(Debug, Args)]
 pub(crate) struct VoicesArgs {
@@ -14,7 +35,12 @@ pub(crate) struct VoicesArgs {
 #[derive(Debug, Subcommand)]
 pub(crate) enum VoicesCommands {
     /// List all voices.
-    List,
+    List {
+        /// Only list voices that speak this language (e.g. `es` or
+        /// `pt-BR`), verified voices first.
+        #[arg(long)]
+        language: Option<String>,
+    },
 
     /// Get details about a voice.
     Get {
@@ -54,6 +80,86 @@ pub(crate) enum VoicesCommands {
 
     /// Browse shared/library voices.
     GetShared,
+
+    /// Find library voices that sound like an audio sample.
+    Similar {
+        /// Path to the reference audio sample.
+        #[arg(long)]
+        audio: PathBuf,
+
+        /// How far a match may be from the sample (0.0 to 2.0); lower
+        /// values return only closer matches.
+        #[arg(long)]
+        threshold: Option<f64>,
+
+        /// Maximum number of matches.
+        #[arg(long)]
+        top_k: Option<u32>,
+    },
+
+    /// List the samples of a voice.
+    ListSamples {
+        /// Voice ID to list samples for.
+        #[arg(long)]
+        voice_id: String,
+    },
+
+    /// Upload another sample to an instant voice clone.
+    AddSample {
+        /// Voice ID to add the sample to.
+        #[arg(long)]
+        voice_id: String,
+
+        /// Path to the audio sample.
+        #[arg(long)]
+        file: PathBuf,
+    },
+
+    /// Download the audio of a voice sample.
+    GetSampleAudio {
+        /// Voice ID the sample belongs to.
+        #[arg(long)]
+        voice_id: String,
+
+        /// Sample ID to download.
+        #[arg(long)]
+        sample_id: String,
+
+        /// Output file path for the audio.
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Delete a voice sample.
+    DeleteSample {
+        /// Voice ID the sample belongs to.
+        #[arg(long)]
+        voice_id: String,
+
+        /// Sample ID to delete.
+        #[arg(long)]
+        sample_id: String,
+    },
+
+    /// Clone a voice from a directory of audio samples.
+    Clone {
+        /// Name of the new voice.
+        #[arg(long)]
+        name: String,
+
+        /// Directory containing the audio samples (mp3, wav, m4a, flac, ogg
+        /// or webm, up to 10 MB each).
+        #[arg(long)]
+        samples: PathBuf,
+
+        /// Description of the voice.
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Label to attach, as `key=value`. May be repeated.
+        #[arg(long, value_name = "KEY=VALUE")]
+        labels: Vec<String>,
+    },
 }
 
 /// Execute a voices subcommand.
@@ -61,10 +167,14 @@ pub(crate) async fn execute(args: &VoicesArgs, cli: &Cli) -> eyre::Result<()> {
     let client = build_client(cli)?;
 
     match &args.command {
-        VoicesCommands::List => {
+        VoicesCommands::List { language: None } => {
             let response = client.voices().list(None).await?;
             print_json(&response, cli.format)?;
         }
+        VoicesCommands::List { language: Some(language) } => {
+            let voices = client.voices().list_by_language(language).await?;
+            print_json(&voices, cli.format)?;
+        }
         VoicesCommands::Get { voice_id } => {
             let response = client.voices().get(voice_id, None).await?;
             print_json(&response, cli.format)?;
@@ -95,6 +205,133 @@ pub(crate) async fn execute(args: &VoicesArgs, cli: &Cli) -> eyre::Result<()> {
                 .await?;
             print_json(&response, cli.format)?;
         }
+        VoicesCommands::Similar { audio, threshold, top_k } => {
+            let matches = client
+                .voices()
+                .find_similar(FileInput::from_path(audio), *threshold, *top_k)
+                .await?;
+            print_json(&matches, cli.format)?;
+        }
+        VoicesCommands::ListSamples { voice_id } => {
+            let samples = client.voices().list_samples(voice_id).await?;
+            print_json(&samples, cli.format)?;
+        }
+        VoicesCommands::AddSample { voice_id, file } => {
+            let sample = client.voices().add_sample(voice_id, FileInput::from_path(file)).await?;
+            print_json(&sample, cli.format)?;
+        }
+        VoicesCommands::GetSampleAudio { voice_id, sample_id, output } => {
+            let mut stream = client.voices().get_sample_audio_stream(voice_id, sample_id).await?;
+            let mut writer: Box<dyn tokio::io::AsyncWrite + Unpin> = match output {
+                Some(path) => Box::new(tokio::fs::File::create(path).await?),
+                None => Box::new(tokio::io::stdout()),
+            };
+            while let Some(chunk) = stream.next().await {
+                writer.write_all(&chunk?).await?;
+            }
+            writer.flush().await?;
+            if let Some(path) = output {
+                eprintln!("Audio written to {path}");
+            }
+        }
+        VoicesCommands::DeleteSample { voice_id, sample_id } => {
+            let response = client.voices().delete_sample(voice_id, sample_id).await?;
+            print_json(&response, cli.format)?;
+        }
+        VoicesCommands::Clone { name, samples, description, labels } => {
+            let labels = parse_labels(labels)?;
+            let files = read_samples(samples)?;
+            eprintln!("Uploading {} sample(s)...", files.len());
+
+            let request = AddVoiceRequest {
+                name: name.clone(),
+                description: description.clone(),
+                labels: (!labels.is_empty()).then_some(labels),
+            };
+            let parts: Vec<(&str, &str, &[u8])> = files
+                .iter()
+                .map(|s| (s.filename.as_str(), s.content_type, s.data.as_slice()))
+                .collect();
+            let response = client.voices().add(&request, &parts).await?;
+            println!("{}", response.voice_id);
+        }
     }
     Ok(())
 }
+
+/// An audio sample read from disk, ready for upload.
+#[derive(Debug)]
+struct Sample {
+    filename: String,
+    content_type: &'static str,
+    data: Vec<u8>,
+}
+
+/// Parses `key=value` labels into a map.
+fn parse_labels(labels: &[String]) -> eyre::Result<HashMap<String, String>> {
+    labels
+        .iter()
+        .map(|label| {
+            let (key, value) = label
+                .split_once('=')
+                .ok_or_else(|| eyre::eyre!("invalid label `{label}`: expected key=value"))?;
+            if key.trim().is_empty() {
+                return Err(eyre::eyre!("invalid label `{label}`: key is empty"));
+            }
+            Ok((key.trim().to_owned(), value.trim().to_owned()))
+        })
+        .collect()
+}
+
+/// Reads every audio sample in `dir`, sorted by file name.
+///
+/// Files that are not audio are skipped; audio files over the size limit
+/// are rejected before anything is uploaded.
+fn read_samples(dir: &Path) -> eyre::Result<Vec<Sample>> {
+    if !dir.is_dir() {
+        return Err(eyre::eyre!("{} is not a directory", dir.display()));
+    }
+
+    let mut samples = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Some(content_type) = sample_content_type(&path) else {
+            eprintln!("Skipping {}: not a supported audio format", path.display());
+            continue;
+        };
+        let size = std::fs::metadata(&path)?.len();
+        if size > MAX_SAMPLE_BYTES {
+            return Err(eyre::eyre!(
+                "{} is {size} bytes; samples must be at most {MAX_SAMPLE_BYTES} bytes",
+                path.display()
+            ));
+        }
+        if size == 0 {
+            return Err(eyre::eyre!("{} is empty", path.display()));
+        }
+        let filename = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| eyre::eyre!("{} has a non-UTF-8 file name", path.display()))?
+            .to_owned();
+        samples.push(Sample { filename, content_type, data: std::fs::read(&path)? });
+    }
+
+    if samples.is_empty() {
+        return Err(eyre::eyre!("no audio samples found in {}", dir.display()));
+    }
+    samples.sort_by(|a, b| a.filename.cmp(&b.filename));
+    Ok(samples)
+}
+
+/// Returns the MIME type for a supported sample, based on its extension.
+fn sample_content_type(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?;
+    SAMPLE_FORMATS
+        .iter()
+        .find(|(known, _)| ext.eq_ignore_ascii_case(known))
+        .map(|(_, content_type)| *content_type)
+}
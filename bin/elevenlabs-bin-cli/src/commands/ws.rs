@@ -2,6 +2,8 @@
 
 use clap::{Args, Subcommand};
 
+use crate::commands::completions::ModelIdParser;
+
 /// WebSocket operations (TTS streaming, Conversational AI).
 #[derive(Debug, Args)]
 pub(crate) struct WsArgs {
@@ -12,22 +14,46 @@ pub(crate) struct WsArgs {
 #[derive(Debug, Subcommand)]
 pub(crate) enum WsCommands {
     /// Stream text-to-speech over WebSocket.
+    ///
+    /// Without `--text`, reads lines from stdin and sends each one as it is
+    /// entered. Audio is written as it arrives, so stdout can be piped into a
+    /// player such as `ffplay -nodisp -`. The delay from sending a line to
+    /// its first audio chunk is reported on stderr.
     Tts {
         /// Voice ID to use for synthesis.
         #[arg(long)]
         voice_id: String,
 
-        /// Text to convert to speech.
+        /// Text to convert to speech. Read line by line from stdin if
+        /// omitted.
         #[arg(long)]
-        text: String,
+        text: Option<String>,
 
         /// Model ID to use.
-        #[arg(long)]
+        #[arg(long, value_parser = ModelIdParser::TTS, hide_possible_values = true)]
         model_id: Option<String>,
 
         /// Output file path for the audio.
-        #[arg(short, long)]
+        #[arg(short, long, alias = "out")]
         output: Option<String>,
+
+        /// Send lines without `try_trigger_generation`, leaving it to the
+        /// server's chunk schedule when audio is generated.
+        #[arg(long)]
+        no_trigger: bool,
+
+        /// Flush after every line, so each line is spoken immediately.
+        #[arg(long)]
+        flush: bool,
+
+        /// Generate audio as soon as each sentence is complete instead of
+        /// following the chunk schedule.
+        #[arg(long)]
+        auto_mode: bool,
+
+        /// Send text no faster than this many characters per second.
+        #[arg(long)]
+        chars_per_second: Option<u32>,
     },
 
     /// Start a conversational AI WebSocket session.
@@ -53,39 +79,31 @@ pub(crate) async fn execute(args: &WsArgs, cli: &crate::cli::Cli) -> eyre::Resul
     };
 
     match &args.command {
-        WsCommands::Tts { voice_id, text, model_id, output } => {
-            let ws_config = elevenlabs_sdk::TtsWsConfig {
-                voice_id: voice_id.clone(),
-                model_id: model_id.clone().unwrap_or_else(|| "eleven_turbo_v2".into()),
-                voice_settings: None,
-                generation_config: None,
-                output_format: None,
+        WsCommands::Tts {
+            voice_id,
+            text,
+            model_id,
+            output,
+            no_trigger,
+            flush,
+            auto_mode,
+            chars_per_second,
+        } => {
+            let mut ws_config = elevenlabs_sdk::TtsWsConfig::new(
+                voice_id.clone(),
+                model_id.clone().unwrap_or_else(|| "eleven_turbo_v2".into()),
+            );
+            ws_config.auto_mode = auto_mode.then_some(true);
+            ws_config.pacing = chars_per_second.map(elevenlabs_sdk::TtsWsPacing::new);
+            let ws = elevenlabs_sdk::TtsWebSocket::connect(&client_config, &ws_config).await?;
+            let mut out: Box<dyn tokio::io::AsyncWrite + Unpin> = match output {
+                Some(path) => Box::new(tokio::fs::File::create(path).await?),
+                None => Box::new(tokio::io::stdout()),
             };
-            let mut ws = elevenlabs_sdk::TtsWebSocket::connect(&client_config, &ws_config).await?;
-            ws.send_text(text).await?;
-            ws.flush().await?;
-
-            let mut audio_buf = Vec::new();
-            while let Some(resp) = ws.recv().await? {
-                if let Some(ref b64) = resp.audio {
-                    use base64::Engine;
-                    if let Ok(decoded) = base64::engine::general_purpose::STANDARD.decode(b64) {
-                        audio_buf.extend_from_slice(&decoded);
-                    }
-                }
-                if resp.is_final == Some(true) {
-                    break;
-                }
-            }
-            ws.close().await?;
-
+            let options = LineOptions { trigger: !no_trigger, flush: *flush };
+            stream_tts(ws, text.as_deref(), options, &mut out).await?;
             if let Some(path) = output {
-                tokio::fs::write(path, &audio_buf).await?;
                 eprintln!("Audio written to {path}");
-            } else {
-                use tokio::io::AsyncWriteExt;
-                let mut stdout = tokio::io::stdout();
-                stdout.write_all(&audio_buf).await?;
             }
         }
         WsCommands::Conversation { agent_id } => {
@@ -97,3 +115,78 @@ pub(crate) async fn execute(args: &WsArgs, cli: &crate::cli::Cli) -> eyre::Resul
     }
     Ok(())
 }
+
+/// How `ws tts` sends each line of text.
+#[derive(Debug, Clone, Copy)]
+struct LineOptions {
+    trigger: bool,
+    flush: bool,
+}
+
+/// Sends `text`, or stdin line by line, over `ws` and writes audio to `out`
+/// as it arrives.
+async fn stream_tts(
+    mut ws: elevenlabs_sdk::TtsWebSocket,
+    text: Option<&str>,
+    options: LineOptions,
+    out: &mut (dyn tokio::io::AsyncWrite + Unpin),
+) -> eyre::Result<()> {
+    use std::time::Instant;
+
+    use elevenlabs_sdk::TtsWsResponse;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+    let mut lines = tokio::io::BufReader::new(tokio::io::stdin()).lines();
+    let mut input_open = true;
+    let mut waiting_since = if let Some(text) = text {
+        ws.send_text_with_trigger(text, options.trigger).await?;
+        ws.end_input().await?;
+        input_open = false;
+        Some(Instant::now())
+    } else {
+        None
+    };
+
+    loop {
+        tokio::select! {
+            line = lines.next_line(), if input_open => {
+                let Some(line) = line? else {
+                    ws.end_input().await?;
+                    input_open = false;
+                    continue;
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                // Lines are sent as separate chunks; the trailing space keeps
+                // words from running together across them.
+                ws.send_text_with_trigger(&format!("{line} "), options.trigger).await?;
+                if options.flush {
+                    ws.flush().await?;
+                }
+                waiting_since.get_or_insert_with(Instant::now);
+            }
+            response = ws.recv() => match response? {
+                Some(TtsWsResponse::Audio(chunk)) => {
+                    if let Some(audio) = chunk.audio.as_ref().filter(|audio| !audio.is_empty()) {
+                        if let Some(sent) = waiting_since.take() {
+                            eprintln!("first audio after {} ms", sent.elapsed().as_millis());
+                        }
+                        out.write_all(audio).await?;
+                        out.flush().await?;
+                    }
+                    if chunk.is_final == Some(true) && !input_open {
+                        break;
+                    }
+                }
+                Some(TtsWsResponse::TimedOut { message }) => {
+                    eyre::bail!("stream timed out: {message}");
+                }
+                None => break,
+            },
+        }
+    }
+
+    ws.close().await?;
+    Ok(())
+}
@@ -1,26 +1,50 @@
 //! Shared context helpers for CLI command execution.
 
-use elevenlabs_sdk::{ClientConfig, ElevenLabsClient};
+use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, Profile, ProfileFile};
 
 use crate::cli::Cli;
 
+/// Load the profile selected by `--profile` / `ELEVENLABS_PROFILE`, or the
+/// file's default profile.
+///
+/// An unnamed profile that does not exist reads as empty, so the CLI works
+/// without a profile file.
+///
+/// # Errors
+///
+/// Returns an error if the profile file cannot be read or a profile named
+/// with `--profile` does not exist.
+pub(crate) fn active_profile(cli: &Cli) -> eyre::Result<Profile> {
+    let Some(path) = ProfileFile::default_path() else {
+        return Ok(Profile::default());
+    };
+    let file = ProfileFile::load(&path)?;
+    let name = file.resolve_name(cli.profile.as_deref());
+    match file.profile(name) {
+        Ok(profile) => Ok(profile.clone()),
+        Err(_) if cli.profile.is_none() => Ok(Profile::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
 /// Build an [`ElevenLabsClient`] from CLI global options.
 ///
 /// Uses `--api-key` / `ELEVENLABS_API_KEY` and optionally `--base-url` /
-/// `ELEVENLABS_BASE_URL` to construct the SDK client.
+/// `ELEVENLABS_BASE_URL` to construct the SDK client, falling back to the
+/// active profile for either.
 ///
 /// # Errors
 ///
 /// Returns an error if the API key is not provided or client construction fails.
 pub(crate) fn build_client(cli: &Cli) -> eyre::Result<ElevenLabsClient> {
-    let api_key = cli
-        .api_key
-        .as_deref()
-        .ok_or_else(|| eyre::eyre!("API key required — set --api-key or ELEVENLABS_API_KEY"))?;
+    let profile = active_profile(cli)?;
+    let api_key = cli.api_key.as_deref().or(profile.api_key.as_deref()).ok_or_else(|| {
+        eyre::eyre!("API key required — set --api-key, ELEVENLABS_API_KEY or a profile api_key")
+    })?;
 
     let mut builder = ClientConfig::builder(api_key);
 
-    if let Some(ref base_url) = cli.base_url {
+    if let Some(base_url) = cli.base_url.as_ref().or(profile.base_url.as_ref()) {
         builder = builder.base_url(base_url);
     }
 
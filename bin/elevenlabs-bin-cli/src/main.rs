@@ -30,6 +30,7 @@ async fn main() -> eyre::Result<()> {
             cli::Commands::User(args) => commands::user::execute(args, &cli).await?,
             cli::Commands::Workspace(args) => commands::workspace::execute(args, &cli).await?,
             cli::Commands::Agents(args) => commands::agents::execute(args, &cli).await?,
+            cli::Commands::Config(args) => commands::config::execute(args, &cli)?,
             cli::Commands::AudioIsolation(args) => {
                 commands::audio_isolation::execute(args, &cli).await?;
             }
@@ -66,6 +67,8 @@ async fn main() -> eyre::Result<()> {
                 commands::voice_generation::execute(args, &cli).await?;
             }
             cli::Commands::Ws(args) => commands::ws::execute(args, &cli).await?,
+            cli::Commands::Completions(args) => commands::completions::execute(args),
+            cli::Commands::Manpages(args) => commands::manpages::execute(args)?,
         },
         None => {
             eprintln!("elevenlabs-bin-cli — use --help for usage information");
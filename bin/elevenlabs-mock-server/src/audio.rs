@@ -0,0 +1,56 @@
+//! Synthetic audio used in place of real speech.
+
+use std::time::Duration;
+
+/// Sample rate of generated audio, in Hz.
+const SAMPLE_RATE: u32 = 22_050;
+
+/// Approximate speaking time per character of input text.
+const SECS_PER_CHAR: f64 = 0.06;
+
+/// Shortest clip returned for non-empty text.
+const MIN_DURATION: Duration = Duration::from_millis(500);
+
+/// Longest clip returned, regardless of text length.
+const MAX_DURATION: Duration = Duration::from_secs(30);
+
+/// Returns a plausible speech duration for `text`.
+pub(crate) fn duration_for_text(text: &str) -> Duration {
+    let secs = text.chars().count() as f64 * SECS_PER_CHAR;
+    Duration::from_secs_f64(secs).clamp(MIN_DURATION, MAX_DURATION)
+}
+
+/// Picks a stable tone frequency for a voice so different voices are
+/// audibly distinguishable.
+pub(crate) fn frequency_for_voice(voice_id: &str) -> f64 {
+    let hash =
+        voice_id.bytes().fold(0_u32, |acc, b| acc.wrapping_mul(31).wrapping_add(u32::from(b)));
+    220.0 + f64::from(hash % 12) * 20.0
+}
+
+/// Renders a sine tone as a 16-bit mono PCM WAV file.
+pub(crate) fn tone_wav(duration: Duration, frequency_hz: f64) -> Vec<u8> {
+    let samples = (duration.as_secs_f64() * f64::from(SAMPLE_RATE)) as u32;
+    let data_len = samples * 2;
+
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16_u32.to_le_bytes()); // fmt chunk size
+    wav.extend_from_slice(&1_u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1_u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    wav.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes()); // byte rate
+    wav.extend_from_slice(&2_u16.to_le_bytes()); // block align
+    wav.extend_from_slice(&16_u16.to_le_bytes()); // bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+
+    let step = std::f64::consts::TAU * frequency_hz / f64::from(SAMPLE_RATE);
+    for n in 0..samples {
+        let sample = (f64::from(n) * step).sin() * f64::from(i16::MAX) * 0.3;
+        wav.extend_from_slice(&(sample as i16).to_le_bytes());
+    }
+    wav
+}
@@ -0,0 +1,70 @@
+//! Mock ElevenLabs API server for local development and demos.
+//!
+//! Emulates text-to-speech (returning a WAV tone), the voices list, models,
+//! and speech history without consuming credits. Point the SDK at it with
+//! `ClientConfig::builder("any-key").base_url("http://127.0.0.1:4011")`.
+
+mod audio;
+mod routes;
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::http::StatusCode;
+use clap::Parser;
+
+/// Command-line options for the mock server.
+#[derive(Debug, Parser)]
+#[command(name = "elevenlabs-mock-server", version, about)]
+struct Args {
+    /// Address to listen on.
+    #[arg(long, env = "ELEVENLABS_MOCK_ADDR", default_value = "127.0.0.1:4011")]
+    addr: SocketAddr,
+
+    /// Extra latency added to every request, in milliseconds.
+    #[arg(long, default_value_t = 0)]
+    latency_ms: u64,
+
+    /// Fail every N-th request with `--fail-status`.
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    fail_every: Option<u64>,
+
+    /// HTTP status returned for injected failures.
+    #[arg(long, default_value_t = 500)]
+    fail_status: u16,
+
+    /// Enable debug logging.
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    let filter = if args.verbose {
+        tracing::level_filters::LevelFilter::DEBUG
+    } else {
+        tracing::level_filters::LevelFilter::INFO
+    };
+    tracing_subscriber::fmt().with_max_level(filter).with_writer(std::io::stderr).init();
+
+    let fail_status = StatusCode::from_u16(args.fail_status)?;
+    let state = Arc::new(routes::AppState::new(
+        Duration::from_millis(args.latency_ms),
+        args.fail_every,
+        fail_status,
+    ));
+
+    let listener = tokio::net::TcpListener::bind(args.addr).await?;
+    tracing::info!(addr = %listener.local_addr()?, "mock ElevenLabs API listening");
+
+    axum::serve(listener, routes::router(state))
+        .with_graceful_shutdown(async {
+            // If the signal handler cannot be installed, run until killed.
+            if tokio::signal::ctrl_c().await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        })
+        .await?;
+    Ok(())
+}
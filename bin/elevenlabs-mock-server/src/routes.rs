@@ -0,0 +1,329 @@
+//! HTTP routes emulating a subset of the ElevenLabs API.
+//!
+//! | Route | Behaviour |
+//! |-------|-----------|
+//! | `POST /v1/text-to-speech/{voice_id}[/stream]` | Returns a WAV tone and records a history item |
+//! | `GET /v1/voices`, `GET /v1/voices/{voice_id}` | Fixed set of premade voices |
+//! | `GET /v1/models` | A single multilingual model |
+//! | `GET /v1/history`, `GET/DELETE /v1/history/{id}`, `GET /v1/history/{id}/audio` | Items generated since startup |
+
+use std::{
+    sync::{
+        Arc, Mutex, PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::{Path, Request, State},
+    http::{StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::audio;
+
+/// Header carrying the API key; any non-empty value is accepted.
+const API_KEY_HEADER: &str = "xi-api-key";
+
+/// Model reported by `GET /v1/models` and used when a request omits one.
+const DEFAULT_MODEL_ID: &str = "eleven_multilingual_v2";
+
+/// A premade voice served by the mock.
+#[derive(Debug)]
+struct MockVoice {
+    id: &'static str,
+    name: &'static str,
+    gender: &'static str,
+    accent: &'static str,
+}
+
+/// Premade voices served by the mock.
+const VOICES: &[MockVoice] = &[
+    MockVoice { id: "21m00Tcm4TlvDq8ikWAM", name: "Rachel", gender: "female", accent: "american" },
+    MockVoice { id: "29vD33N1CtxCmqQRPOHJ", name: "Drew", gender: "male", accent: "american" },
+    MockVoice { id: "EXAVITQu4vr4xnSDxMaL", name: "Sarah", gender: "female", accent: "american" },
+    MockVoice { id: "JBFqnCBsd6RMkjVDRZzb", name: "George", gender: "male", accent: "british" },
+];
+
+impl MockVoice {
+    fn find(voice_id: &str) -> Option<&'static Self> {
+        VOICES.iter().find(|voice| voice.id == voice_id)
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "voice_id": self.id,
+            "name": self.name,
+            "category": "premade",
+            "labels": {"gender": self.gender, "accent": self.accent},
+            "available_for_tiers": [],
+            "high_quality_base_model_ids": [DEFAULT_MODEL_ID],
+            "description": format!("Mock voice {}", self.name),
+            "is_legacy": false,
+            "is_mixed": false,
+        })
+    }
+}
+
+/// Fault-injection settings and in-memory history shared by all handlers.
+#[derive(Debug)]
+pub(crate) struct AppState {
+    latency: Duration,
+    fail_every: Option<u64>,
+    fail_status: StatusCode,
+    requests: AtomicU64,
+    generated: AtomicU64,
+    history: Mutex<Vec<HistoryEntry>>,
+}
+
+impl AppState {
+    /// Creates state that delays every request by `latency` and fails every
+    /// `fail_every`-th request with `fail_status`.
+    pub(crate) const fn new(
+        latency: Duration,
+        fail_every: Option<u64>,
+        fail_status: StatusCode,
+    ) -> Self {
+        Self {
+            latency,
+            fail_every,
+            fail_status,
+            requests: AtomicU64::new(0),
+            generated: AtomicU64::new(0),
+            history: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+/// A generated clip kept for the history endpoints.
+#[derive(Debug, Clone)]
+struct HistoryEntry {
+    id: String,
+    voice_id: String,
+    voice_name: String,
+    model_id: String,
+    text: String,
+    date_unix: i64,
+    character_count_from: i64,
+    character_count_to: i64,
+    audio: Bytes,
+}
+
+impl HistoryEntry {
+    fn to_json(&self) -> Value {
+        json!({
+            "history_item_id": self.id,
+            "voice_id": self.voice_id,
+            "voice_name": self.voice_name,
+            "voice_category": "premade",
+            "model_id": self.model_id,
+            "text": self.text,
+            "date_unix": self.date_unix,
+            "character_count_change_from": self.character_count_from,
+            "character_count_change_to": self.character_count_to,
+            "content_type": "audio/wav",
+            "state": "created",
+            "source": "TTS",
+        })
+    }
+}
+
+/// Builds the router with fault injection and API-key checks applied to
+/// every route.
+pub(crate) fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/v1/text-to-speech/{voice_id}", post(text_to_speech))
+        .route("/v1/text-to-speech/{voice_id}/stream", post(text_to_speech))
+        .route("/v1/voices", get(list_voices))
+        .route("/v1/voices/{voice_id}", get(get_voice))
+        .route("/v1/models", get(list_models))
+        .route("/v1/history", get(list_history))
+        .route("/v1/history/{history_item_id}", get(get_history_item).delete(delete_history_item))
+        .route("/v1/history/{history_item_id}/audio", get(get_history_audio))
+        .layer(middleware::from_fn(require_api_key))
+        .layer(middleware::from_fn_with_state(Arc::clone(&state), inject_faults))
+        .with_state(state)
+}
+
+/// Returns an error body shaped like the real API's `detail` object.
+fn error(status: StatusCode, code: &str, message: &str) -> Response {
+    (status, Json(json!({"detail": {"status": code, "message": message}}))).into_response()
+}
+
+/// Applies the configured latency and fails every N-th request.
+async fn inject_faults(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !state.latency.is_zero() {
+        tokio::time::sleep(state.latency).await;
+    }
+    let count = state.requests.fetch_add(1, Ordering::Relaxed) + 1;
+    if let Some(every) = state.fail_every &&
+        count.is_multiple_of(every)
+    {
+        tracing::info!(count, status = %state.fail_status, "injecting failure");
+        return error(state.fail_status, "injected_failure", "Failure injected by mock server");
+    }
+    next.run(request).await
+}
+
+/// Rejects requests without an `xi-api-key` header, like the real API.
+async fn require_api_key(request: Request, next: Next) -> Response {
+    let has_key =
+        request.headers().get(API_KEY_HEADER).is_some_and(|value| !value.as_bytes().is_empty());
+    if !has_key {
+        return error(StatusCode::UNAUTHORIZED, "invalid_api_key", "Missing xi-api-key header");
+    }
+    next.run(request).await
+}
+
+fn voice_not_found(voice_id: &str) -> Response {
+    error(StatusCode::NOT_FOUND, "voice_not_found", &format!("Voice {voice_id} not found"))
+}
+
+fn history_not_found(history_item_id: &str) -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "history_item_not_found",
+        &format!("History item {history_item_id} not found"),
+    )
+}
+
+/// Subset of the text-to-speech request body the mock understands.
+#[derive(Debug, Deserialize)]
+struct TextToSpeechBody {
+    text: String,
+    model_id: Option<String>,
+}
+
+async fn text_to_speech(
+    State(state): State<Arc<AppState>>,
+    Path(voice_id): Path<String>,
+    Json(body): Json<TextToSpeechBody>,
+) -> Response {
+    let Some(voice) = MockVoice::find(&voice_id) else {
+        return voice_not_found(&voice_id);
+    };
+
+    let duration = audio::duration_for_text(&body.text);
+    let wav = Bytes::from(audio::tone_wav(duration, audio::frequency_for_voice(&voice_id)));
+
+    let date_unix =
+        SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs() as i64);
+    let characters = body.text.chars().count() as i64;
+    {
+        let mut history = state.history.lock().unwrap_or_else(PoisonError::into_inner);
+        let character_count_from = history.last().map_or(0, |entry| entry.character_count_to);
+        history.push(HistoryEntry {
+            id: format!("mock_{:08}", state.generated.fetch_add(1, Ordering::Relaxed) + 1),
+            voice_id: voice_id.clone(),
+            voice_name: voice.name.to_owned(),
+            model_id: body.model_id.unwrap_or_else(|| DEFAULT_MODEL_ID.to_owned()),
+            text: body.text,
+            date_unix,
+            character_count_from,
+            character_count_to: character_count_from + characters,
+            audio: wav.clone(),
+        });
+    }
+
+    tracing::debug!(%voice_id, duration_ms = duration.as_millis() as u64, "generated tone");
+    ([(header::CONTENT_TYPE, "audio/wav")], wav).into_response()
+}
+
+async fn list_voices() -> Json<Value> {
+    let voices: Vec<Value> = VOICES.iter().map(MockVoice::to_json).collect();
+    Json(json!({ "voices": voices }))
+}
+
+async fn get_voice(Path(voice_id): Path<String>) -> Response {
+    match MockVoice::find(&voice_id) {
+        Some(voice) => Json(voice.to_json()).into_response(),
+        None => voice_not_found(&voice_id),
+    }
+}
+
+async fn list_models() -> Json<Value> {
+    Json(json!([{
+        "model_id": DEFAULT_MODEL_ID,
+        "name": "Eleven Multilingual v2 (mock)",
+        "can_be_finetuned": true,
+        "can_do_text_to_speech": true,
+        "can_do_voice_conversion": false,
+        "can_use_style": true,
+        "can_use_speaker_boost": true,
+        "serves_pro_voices": false,
+        "token_cost_factor": 1.0,
+        "description": "Mock model that renders text as a sine tone.",
+        "requires_alpha_access": false,
+        "max_characters_request_free_user": 2500,
+        "max_characters_request_subscribed_user": 5000,
+        "maximum_text_length_per_request": 10000,
+        "languages": [{"language_id": "en", "name": "English"}],
+        "model_rates": {"character_cost_multiplier": 1.0},
+        "concurrency_group": "standard",
+    }]))
+}
+
+async fn list_history(State(state): State<Arc<AppState>>) -> Json<Value> {
+    let history = state.history.lock().unwrap_or_else(PoisonError::into_inner);
+    let items: Vec<Value> = history.iter().rev().map(HistoryEntry::to_json).collect();
+    let last_id = history.first().map(|entry| entry.id.clone());
+    Json(json!({
+        "history": items,
+        "last_history_item_id": last_id,
+        "has_more": false,
+    }))
+}
+
+fn find_history(state: &AppState, history_item_id: &str) -> Option<HistoryEntry> {
+    state
+        .history
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .find(|entry| entry.id == history_item_id)
+        .cloned()
+}
+
+async fn get_history_item(
+    State(state): State<Arc<AppState>>,
+    Path(history_item_id): Path<String>,
+) -> Response {
+    match find_history(&state, &history_item_id) {
+        Some(entry) => Json(entry.to_json()).into_response(),
+        None => history_not_found(&history_item_id),
+    }
+}
+
+async fn get_history_audio(
+    State(state): State<Arc<AppState>>,
+    Path(history_item_id): Path<String>,
+) -> Response {
+    match find_history(&state, &history_item_id) {
+        Some(entry) => ([(header::CONTENT_TYPE, "audio/wav")], entry.audio).into_response(),
+        None => history_not_found(&history_item_id),
+    }
+}
+
+async fn delete_history_item(
+    State(state): State<Arc<AppState>>,
+    Path(history_item_id): Path<String>,
+) -> Response {
+    let mut history = state.history.lock().unwrap_or_else(PoisonError::into_inner);
+    let before = history.len();
+    history.retain(|entry| entry.id != history_item_id);
+    if history.len() == before {
+        return history_not_found(&history_item_id);
+    }
+    Json(json!({"status": "ok"})).into_response()
+}
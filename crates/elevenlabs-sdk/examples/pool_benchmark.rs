@@ -0,0 +1,110 @@
+//! Connection pool benchmark for concurrent streaming TTS.
+//!
+//! Streams the same request `CONCURRENCY` times in parallel under each
+//! connection profile and prints the wall time, time-to-first-byte
+//! percentiles and [`PoolStats`](elevenlabs_sdk::PoolStats) for each, so the
+//! effect of HTTP/2 multiplexing and pool limits can be compared on your own
+//! network.
+//!
+//! Usage (against the bundled mock server, which costs no credits):
+//!
+//! ```sh
+//! cargo run -p elevenlabs-mock-server -- --latency-ms 200 &
+//! ELEVENLABS_API_KEY=any ELEVENLABS_BASE_URL=http://127.0.0.1:4011 \
+//!     cargo run -p elevenlabs-sdk --example pool_benchmark --release
+//! ```
+//!
+//! Set `CONCURRENCY` (default 32) and `ROUNDS` (default 3) to change the
+//! load.
+
+#![expect(clippy::print_stdout, reason = "examples print their progress")]
+
+use std::time::{Duration, Instant};
+
+use elevenlabs_sdk::{
+    ClientConfig, ClientConfigBuilder, ElevenLabsClient, HttpVersionPreference,
+    types::TextToSpeechRequest,
+};
+use futures_util::{StreamExt, future::join_all};
+
+const VOICE_ID: &str = "21m00Tcm4TlvDq8ikWAM";
+
+/// A profile name and the builder settings it applies.
+type Profile = (&'static str, fn(ClientConfigBuilder) -> ClientConfigBuilder);
+
+/// Reads a positive integer from the environment.
+fn env_usize(name: &str, default: usize) -> usize {
+    std::env::var(name).ok().and_then(|v| v.parse().ok()).filter(|&n| n > 0).unwrap_or(default)
+}
+
+/// Streams one request and returns its time to first byte.
+async fn stream_once(client: &ElevenLabsClient, request: &TextToSpeechRequest) -> Option<Duration> {
+    let started = Instant::now();
+    let tts = client.text_to_speech();
+    let mut stream = tts.convert_stream(VOICE_ID, request, None, None).await.ok()?;
+    let mut first_byte = None;
+    while let Some(chunk) = stream.next().await {
+        chunk.ok()?;
+        first_byte.get_or_insert_with(|| started.elapsed());
+    }
+    first_byte
+}
+
+/// Returns the `p`-th percentile of sorted `samples`.
+fn percentile(samples: &[Duration], p: f64) -> Duration {
+    let index = ((samples.len() as f64 - 1.0) * p).round() as usize;
+    samples.get(index).copied().unwrap_or_default()
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let base = ClientConfig::from_env()?;
+    let concurrency = env_usize("CONCURRENCY", 32);
+    let rounds = env_usize("ROUNDS", 3);
+    let request = TextToSpeechRequest::new("Measuring connection reuse under concurrent load.");
+
+    let profiles: [Profile; 4] = [
+        ("auto", |b| b),
+        ("http1", |b| b.http_version(HttpVersionPreference::Http1)),
+        ("http1, 1 idle/host", |b| {
+            b.http_version(HttpVersionPreference::Http1).pool_max_idle_per_host(1)
+        }),
+        ("http2", |b| b.http_version(HttpVersionPreference::Http2)),
+    ];
+
+    println!("{concurrency} concurrent streams x {rounds} rounds against {}\n", base.base_url);
+    println!(
+        "{:<20} {:>9} {:>9} {:>9} {:>6} {:>6} {:>6} {:>6}",
+        "profile", "wall", "ttfb p50", "ttfb p95", "ok", "peak", "h1", "h2"
+    );
+
+    for (name, configure) in profiles {
+        let builder = ClientConfig::builder(base.api_key.clone())
+            .base_url(base.base_url.clone())
+            .max_retries(0);
+        let client = ElevenLabsClient::new(configure(builder).build())?;
+
+        let started = Instant::now();
+        let mut ttfb = Vec::new();
+        for _ in 0..rounds {
+            let streams = (0..concurrency).map(|_| stream_once(&client, &request));
+            ttfb.extend(join_all(streams).await.into_iter().flatten());
+        }
+        let wall = started.elapsed();
+        ttfb.sort();
+
+        let stats = client.pool_stats();
+        println!(
+            "{name:<20} {:>8.0?} {:>8.0?} {:>8.0?} {:>6} {:>6} {:>6} {:>6}",
+            wall,
+            percentile(&ttfb, 0.5),
+            percentile(&ttfb, 0.95),
+            ttfb.len(),
+            stats.peak_in_flight,
+            stats.http1_responses,
+            stats.http2_responses,
+        );
+    }
+
+    Ok(())
+}
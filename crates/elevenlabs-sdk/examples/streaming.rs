@@ -9,6 +9,8 @@
 //! ELEVENLABS_API_KEY=... cargo run -p elevenlabs-sdk --example streaming
 //! ```
 
+#![expect(clippy::print_stdout, reason = "examples print their progress")]
+
 use std::{fs::File, io::Write};
 
 use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, types::TextToSpeechRequest};
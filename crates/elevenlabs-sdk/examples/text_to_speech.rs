@@ -8,6 +8,8 @@
 //! ELEVENLABS_API_KEY=... cargo run -p elevenlabs-sdk --example text_to_speech
 //! ```
 
+#![expect(clippy::print_stdout, reason = "examples print their progress")]
+
 use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, types::TextToSpeechRequest};
 
 #[tokio::main]
@@ -8,6 +8,8 @@
 //! ELEVENLABS_API_KEY=... cargo run -p elevenlabs-sdk --example voices
 //! ```
 
+#![expect(clippy::print_stdout, reason = "examples print their progress")]
+
 use elevenlabs_sdk::{ClientConfig, ElevenLabsClient};
 
 #[tokio::main]
@@ -1,7 +1,7 @@
 //! Real-time TTS via WebSocket.
 //!
 //! Sends text chunks through the input-streaming WebSocket endpoint and
-//! decodes the base64 audio responses into a file.
+//! writes the streamed audio into a file.
 //!
 //! Usage:
 //!
@@ -9,9 +9,12 @@
 //! ELEVENLABS_API_KEY=... cargo run -p elevenlabs-sdk --example websocket_tts
 //! ```
 
+#![expect(clippy::print_stdout, reason = "examples print their progress")]
+
 use std::{fs::File, io::Write};
 
 use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, TtsWebSocket, TtsWsConfig};
+use futures_util::StreamExt;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -21,13 +24,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // required for WebSocket, but good practice).
     let _client = ElevenLabsClient::new(config.clone())?;
 
-    let ws_config = TtsWsConfig {
-        voice_id: "21m00Tcm4TlvDq8ikWAM".into(),
-        model_id: "eleven_turbo_v2".into(),
-        voice_settings: None,
-        generation_config: None,
-        output_format: None,
-    };
+    let ws_config = TtsWsConfig::new("21m00Tcm4TlvDq8ikWAM", "eleven_turbo_v2");
 
     println!("Connecting to TTS WebSocket...");
     let mut ws = TtsWebSocket::connect(&config, &ws_config).await?;
@@ -40,27 +37,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         ws.send_text(chunk).await?;
     }
 
-    // Flush to ensure all buffered text is synthesised.
-    println!("Flushing...");
-    ws.flush().await?;
+    // Signal the end of the text so the server synthesises what is left.
+    println!("Ending input...");
+    ws.end_input().await?;
 
     let output_path = "output_ws.mp3";
     let mut file = File::create(output_path)?;
     let mut total_bytes: usize = 0;
 
-    // Receive audio responses until the final marker.
-    while let Some(resp) = ws.recv().await? {
-        if let Some(ref audio_b64) = resp.audio {
-            use base64::Engine;
-            let decoded = base64::engine::general_purpose::STANDARD.decode(audio_b64)?;
-            file.write_all(&decoded)?;
-            total_bytes += decoded.len();
-            println!("  Received audio chunk: {} bytes", decoded.len());
-        }
-
-        if resp.is_final == Some(true) {
-            println!("Received final marker.");
-            break;
+    // Receive decoded audio until the final marker.
+    {
+        let mut audio = std::pin::pin!(ws.audio_stream());
+        while let Some(chunk) = audio.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk)?;
+            total_bytes += chunk.len();
+            println!("  Received audio chunk: {} bytes", chunk.len());
         }
     }
 
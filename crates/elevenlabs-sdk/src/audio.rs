@@ -0,0 +1,329 @@
+//! Local conversions between the audio formats the API returns.
+//!
+//! `pcm_*` [`OutputFormat`]s are raw 16-bit little-endian samples without a
+//! header, and `ulaw_8000` is 8-bit G.711 μ-law as used by telephony
+//! providers. Neither can be played by most tools as-is:
+//!
+//! - [`pcm_to_wav`] prepends a WAV header to PCM audio.
+//! - [`ulaw_to_pcm`] decodes μ-law audio to 16-bit PCM.
+//! - [`estimate_duration`] estimates the playing time of audio in any output format.
+//! - [`sniff_content_type`] identifies audio of unknown format from its first bytes.
+//!
+//! With the `audio-processing` feature, [`processing`] prepares long
+//! recordings for speech-to-speech by resampling and splitting them.
+//!
+//! # Example
+//!
+//! ```
+//! use elevenlabs_sdk::{audio, types::OutputFormat};
+//!
+//! // One second of silence at 16 kHz, as returned for `pcm_16000`.
+//! let pcm = vec![0_u8; 32_000];
+//! let wav = audio::pcm_to_wav(&pcm, 16_000, 1).unwrap();
+//! assert_eq!(&wav[..4], b"RIFF");
+//! assert_eq!(wav.len(), audio::WAV_HEADER_LEN + pcm.len());
+//!
+//! let duration = audio::estimate_duration(&wav, OutputFormat::Wav_16000).unwrap();
+//! assert_eq!(duration.as_secs(), 1);
+//! ```
+
+#[cfg(feature = "audio-processing")]
+pub mod processing;
+
+use std::time::Duration;
+
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::{
+    error::{ElevenLabsError, Result},
+    types::OutputFormat,
+};
+
+/// Length of the header written by [`pcm_to_wav`].
+pub const WAV_HEADER_LEN: usize = 44;
+
+/// Bytes per sample of the PCM audio returned by the API.
+const PCM_SAMPLE_BYTES: u16 = 2;
+
+/// Wraps 16-bit little-endian PCM samples in a WAV container.
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::Validation`] if `sample_rate` or `channels` is
+/// zero, `pcm` does not hold a whole number of frames, or it is too large
+/// for a WAV file.
+pub fn pcm_to_wav(pcm: &[u8], sample_rate: u32, channels: u16) -> Result<Bytes> {
+    if sample_rate == 0 || channels == 0 {
+        return Err(ElevenLabsError::Validation(
+            "sample rate and channel count must be positive".to_owned(),
+        ));
+    }
+    let block_align = channels
+        .checked_mul(PCM_SAMPLE_BYTES)
+        .ok_or_else(|| ElevenLabsError::Validation(format!("too many channels: {channels}")))?;
+    if !pcm.len().is_multiple_of(usize::from(block_align)) {
+        return Err(ElevenLabsError::Validation(format!(
+            "PCM length {} is not a multiple of the {block_align}-byte frame size",
+            pcm.len()
+        )));
+    }
+    let data_len = u32::try_from(pcm.len())
+        .ok()
+        .filter(|&len| len <= u32::MAX - (WAV_HEADER_LEN as u32 - 8))
+        .ok_or_else(|| {
+            ElevenLabsError::Validation(format!(
+                "{} bytes of PCM do not fit in a WAV file",
+                pcm.len()
+            ))
+        })?;
+    let byte_rate = u64::from(sample_rate) * u64::from(block_align);
+    let byte_rate = u32::try_from(byte_rate).map_err(|_| {
+        ElevenLabsError::Validation(format!("sample rate {sample_rate} Hz is too high"))
+    })?;
+
+    let mut wav = BytesMut::with_capacity(WAV_HEADER_LEN + pcm.len());
+    wav.put_slice(b"RIFF");
+    wav.put_u32_le(WAV_HEADER_LEN as u32 - 8 + data_len);
+    wav.put_slice(b"WAVE");
+    wav.put_slice(b"fmt ");
+    wav.put_u32_le(16);
+    wav.put_u16_le(1); // integer PCM
+    wav.put_u16_le(channels);
+    wav.put_u32_le(sample_rate);
+    wav.put_u32_le(byte_rate);
+    wav.put_u16_le(block_align);
+    wav.put_u16_le(PCM_SAMPLE_BYTES * 8);
+    wav.put_slice(b"data");
+    wav.put_u32_le(data_len);
+    wav.put_slice(pcm);
+    Ok(wav.freeze())
+}
+
+/// Decodes G.711 μ-law audio to 16-bit little-endian PCM at the same
+/// sample rate.
+pub fn ulaw_to_pcm(ulaw: &[u8]) -> Bytes {
+    let mut pcm = BytesMut::with_capacity(ulaw.len() * usize::from(PCM_SAMPLE_BYTES));
+    for &byte in ulaw {
+        pcm.put_i16_le(ulaw_sample(byte));
+    }
+    pcm.freeze()
+}
+
+/// Decodes one μ-law byte.
+const fn ulaw_sample(byte: u8) -> i16 {
+    let byte = !byte;
+    let exponent = (byte >> 4) & 0x07;
+    let mantissa = (byte & 0x0f) as i16;
+    let magnitude = (((mantissa << 3) + 0x84) << exponent) - 0x84;
+    if byte & 0x80 == 0 { magnitude } else { -magnitude }
+}
+
+/// Returns the playing time of `len` bytes of 16-bit PCM.
+pub fn pcm_duration(len: usize, sample_rate: u32, channels: u16) -> Duration {
+    let bytes_per_sec = u64::from(sample_rate) * u64::from(channels) * u64::from(PCM_SAMPLE_BYTES);
+    bytes_duration(len, bytes_per_sec)
+}
+
+/// Returns the playing time of a WAV file, read from its header.
+///
+/// Returns `None` if `wav` does not start with a valid RIFF/WAVE header. A
+/// data chunk cut short, as when a streamed file is still being written,
+/// counts the bytes present.
+pub fn wav_duration(wav: &[u8]) -> Option<Duration> {
+    if wav.get(..4)? != b"RIFF" || wav.get(8..12)? != b"WAVE" {
+        return None;
+    }
+    let mut byte_rate = None;
+    let mut offset = 12;
+    while let Some(header) = wav.get(offset..offset + 8) {
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let body = offset + 8;
+        match &header[..4] {
+            b"fmt " => {
+                let rate = wav.get(body + 8..body + 12)?;
+                byte_rate = Some(u32::from_le_bytes([rate[0], rate[1], rate[2], rate[3]]));
+            }
+            b"data" => {
+                let len = size.min(wav.len() - body);
+                return Some(bytes_duration(len, u64::from(byte_rate?)));
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even length.
+        offset = body.checked_add(size)?.checked_add(size % 2)?;
+    }
+    None
+}
+
+/// Estimates the playing time of `audio` returned in `format`.
+///
+/// PCM, μ-law, A-law and WAV durations are exact. MP3 and Opus durations
+/// assume a constant bitrate and may be off by a few frames. Returns `None`
+/// if a WAV header cannot be read.
+pub fn estimate_duration(audio: &[u8], format: OutputFormat) -> Option<Duration> {
+    let name = format.to_string();
+    if name.starts_with("wav_") {
+        return wav_duration(audio);
+    }
+    if let Some(kbps) = format.bitrate_kbps() {
+        let body = if name.starts_with("mp3_") { strip_id3_tag(audio) } else { audio };
+        return Some(bytes_duration(body.len(), u64::from(kbps) * 1000 / 8));
+    }
+    if name.starts_with("pcm_") {
+        return Some(pcm_duration(audio.len(), format.sample_rate(), 1));
+    }
+    // μ-law and A-law use one byte per sample.
+    Some(bytes_duration(audio.len(), u64::from(format.sample_rate())))
+}
+
+/// Returns the playing time of an MP3 file, assuming every frame has the
+/// bitrate of the first.
+///
+/// Returns `None` if `mp3`, after any ID3v2 tag, does not start with an MPEG
+/// Layer III frame header.
+pub fn mp3_duration(mp3: &[u8]) -> Option<Duration> {
+    const MPEG1_KBPS: [u16; 15] =
+        [0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320];
+    const MPEG2_KBPS: [u16; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+
+    let body = strip_id3_tag(mp3);
+    let header = body.get(..3)?;
+    let is_layer3 = header[1] & 0x06 == 0x02;
+    if header[0] != 0xff || header[1] & 0xe0 != 0xe0 || !is_layer3 {
+        return None;
+    }
+    let table = match (header[1] >> 3) & 0x03 {
+        0b11 => &MPEG1_KBPS,
+        0b10 | 0b00 => &MPEG2_KBPS,
+        _ => return None,
+    };
+    let kbps = *table.get(usize::from(header[2] >> 4)).filter(|&&kbps| kbps != 0)?;
+    Some(bytes_duration(body.len(), u64::from(kbps) * 1000 / 8))
+}
+
+/// Identifies audio from its leading bytes and returns its MIME type.
+///
+/// Recognizes MP3, WAV, FLAC, Ogg, AAC (ADTS), MP4/M4A and WebM. Returns
+/// `None` for anything else.
+pub fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    let content_type = match data {
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => "audio/wav",
+        [b'I', b'D', b'3', ..] => "audio/mpeg",
+        [b'f', b'L', b'a', b'C', ..] => "audio/flac",
+        [b'O', b'g', b'g', b'S', ..] => "audio/ogg",
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => "audio/mp4",
+        [0x1a, 0x45, 0xdf, 0xa3, ..] => "audio/webm",
+        // ADTS frames use the MPEG sync word with the layer bits cleared.
+        [0xff, b, ..] if b & 0xf6 == 0xf0 => "audio/aac",
+        [0xff, b, ..] if b & 0xe0 == 0xe0 && b & 0x06 != 0 => "audio/mpeg",
+        _ => return None,
+    };
+    Some(content_type)
+}
+
+/// Returns the time it takes to play `len` bytes at `bytes_per_sec`.
+fn bytes_duration(len: usize, bytes_per_sec: u64) -> Duration {
+    if bytes_per_sec == 0 {
+        return Duration::ZERO;
+    }
+    let nanos = len as u128 * 1_000_000_000 / u128::from(bytes_per_sec);
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+/// Returns `data` without a leading ID3v2 tag.
+pub(crate) fn strip_id3_tag(data: &[u8]) -> &[u8] {
+    let Some(header) = data.get(..10).filter(|h| h.starts_with(b"ID3")) else {
+        return data;
+    };
+    // The tag size is a 28-bit "syncsafe" integer: 7 bits per byte.
+    let size = header[6..].iter().fold(0_usize, |acc, &b| (acc << 7) | usize::from(b & 0x7f));
+    let footer = if header[5] & 0x10 == 0 { 0 } else { 10 };
+    data.get(10 + size + footer..).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pcm_to_wav_writes_header() {
+        let pcm = [0x01, 0x00, 0xff, 0x7f];
+        let wav = pcm_to_wav(&pcm, 24_000, 1).unwrap();
+        assert_eq!(wav.len(), WAV_HEADER_LEN + 4);
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(wav[4..8].try_into().unwrap()), 40);
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        assert_eq!(u16::from_le_bytes([wav[22], wav[23]]), 1);
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 24_000);
+        assert_eq!(u32::from_le_bytes(wav[28..32].try_into().unwrap()), 48_000);
+        assert_eq!(&wav[36..40], b"data");
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 4);
+        assert_eq!(&wav[44..], pcm);
+    }
+
+    #[test]
+    fn pcm_to_wav_rejects_partial_frames() {
+        assert!(matches!(pcm_to_wav(&[0; 3], 16_000, 1), Err(ElevenLabsError::Validation(_))));
+        assert!(matches!(pcm_to_wav(&[0; 6], 16_000, 2), Err(ElevenLabsError::Validation(_))));
+        assert!(matches!(pcm_to_wav(&[0; 4], 0, 1), Err(ElevenLabsError::Validation(_))));
+        assert!(pcm_to_wav(&[0; 8], 16_000, 2).is_ok());
+    }
+
+    #[test]
+    fn ulaw_to_pcm_decodes_g711() {
+        let pcm = ulaw_to_pcm(&[0xff, 0x7f, 0x00, 0x80, 0xe7]);
+        let samples: Vec<i16> =
+            pcm.chunks_exact(2).map(|s| i16::from_le_bytes([s[0], s[1]])).collect();
+        assert_eq!(samples, [0, 0, -32_124, 32_124, 260]);
+    }
+
+    #[test]
+    fn estimate_duration_per_format() {
+        let second = Duration::from_secs(1);
+        assert_eq!(estimate_duration(&vec![0; 48_000], OutputFormat::Pcm_24000), Some(second));
+        assert_eq!(estimate_duration(&[0; 8000], OutputFormat::Ulaw_8000), Some(second));
+        assert_eq!(estimate_duration(&[0; 16_000], OutputFormat::Mp3_44100_128), Some(second));
+
+        let tagged = [b"ID3\x04\x00\x00\x00\x00\x00\x02xx".as_slice(), &[0; 4000]].concat();
+        assert_eq!(
+            estimate_duration(&tagged, OutputFormat::Mp3_22050_32),
+            Some(Duration::from_secs(1))
+        );
+
+        let wav = pcm_to_wav(&vec![0; 32_000], 16_000, 1).unwrap();
+        assert_eq!(estimate_duration(&wav, OutputFormat::Wav_16000), Some(second));
+        assert_eq!(wav_duration(&wav[..WAV_HEADER_LEN + 16_000]), Some(second / 2));
+        assert_eq!(estimate_duration(b"not a wav file", OutputFormat::Wav_16000), None);
+    }
+
+    #[test]
+    fn mp3_duration_reads_first_frame_bitrate() {
+        // MPEG-1 Layer III, 128 kbps, 44.1 kHz.
+        let mp3 = [[0xff, 0xfb, 0x90, 0x64].as_slice(), &[0; 15_996]].concat();
+        assert_eq!(mp3_duration(&mp3), Some(Duration::from_secs(1)));
+        // MPEG-2 Layer III, 64 kbps.
+        let mp3 = [[0xff, 0xf3, 0x80, 0x64].as_slice(), &[0; 7996]].concat();
+        assert_eq!(mp3_duration(&mp3), Some(Duration::from_secs(1)));
+        assert_eq!(mp3_duration(b"RIFF"), None);
+    }
+
+    #[test]
+    fn sniff_content_type_recognizes_containers() {
+        let wav = pcm_to_wav(&[0; 4], 16_000, 1).unwrap();
+        assert_eq!(sniff_content_type(&wav), Some("audio/wav"));
+        assert_eq!(sniff_content_type(b"ID3\x04\x00"), Some("audio/mpeg"));
+        assert_eq!(sniff_content_type(&[0xff, 0xfb, 0x90]), Some("audio/mpeg"));
+        assert_eq!(sniff_content_type(&[0xff, 0xf1, 0x50]), Some("audio/aac"));
+        assert_eq!(sniff_content_type(b"fLaC\0\0"), Some("audio/flac"));
+        assert_eq!(sniff_content_type(b"\0\0\0\x20ftypM4A "), Some("audio/mp4"));
+        assert_eq!(sniff_content_type(b"<!DOCTYPE html>"), None);
+    }
+
+    #[test]
+    fn strip_id3_tag_removes_leading_tag() {
+        let tagged = [b"ID3\x04\x00\x00\x00\x00\x00\x02xx".as_slice(), b"\xff\xfbframe"].concat();
+        assert_eq!(strip_id3_tag(&tagged), b"\xff\xfbframe");
+        assert_eq!(strip_id3_tag(b"\xff\xfbframe"), b"\xff\xfbframe");
+    }
+}
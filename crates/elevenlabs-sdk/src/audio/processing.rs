@@ -0,0 +1,433 @@
+//! Client-side preparation of long recordings for speech-to-speech.
+//!
+//! Speech-to-speech rejects inputs that are too long, and decodes anything
+//! other than 16 kHz mono PCM on the server. [`prepare`] turns a WAV file
+//! into chunks of [`SpeechToSpeechFileFormat::PcmS16le16`] audio:
+//!
+//! 1. [`probe`] reads the sample rate, channel count and duration.
+//! 2. Channels are mixed down to mono and resampled to [`STS_SAMPLE_RATE`].
+//! 3. Audio longer than [`PreprocessOptions::max_chunk`] is cut at the quietest point shortly
+//!    before each chunk would end, so cuts fall in pauses rather than words.
+//!
+//! [`SpeechToSpeechService::convert_long`] converts each chunk and joins the
+//! results with [`concat`].
+//!
+//! Only 16-bit PCM WAV input is decoded; convert other formats to WAV first.
+//!
+//! [`SpeechToSpeechFileFormat::PcmS16le16`]: crate::types::SpeechToSpeechFileFormat::PcmS16le16
+//! [`SpeechToSpeechService::convert_long`]: crate::services::SpeechToSpeechService::convert_long
+//!
+//! # Example
+//!
+//! ```
+//! use elevenlabs_sdk::audio::{
+//!     self,
+//!     processing::{self, PreprocessOptions},
+//! };
+//!
+//! // Three seconds of 44.1 kHz stereo silence.
+//! let wav = audio::pcm_to_wav(&vec![0_u8; 44_100 * 4 * 3], 44_100, 2).unwrap();
+//! assert_eq!(processing::probe(&wav).unwrap().channels, 2);
+//!
+//! let chunks = processing::prepare(&wav, &PreprocessOptions::default()).unwrap();
+//! assert_eq!(chunks.len(), 1);
+//! assert_eq!(chunks[0].len(), 16_000 * 2 * 3);
+//! ```
+
+use std::{f64::consts::PI, time::Duration};
+
+use bytes::{BufMut, Bytes, BytesMut};
+
+use super::{pcm_to_wav, strip_id3_tag};
+use crate::{
+    error::{ElevenLabsError, Result},
+    types::OutputFormat,
+};
+
+/// Sample rate of [`PcmS16le16`](crate::types::SpeechToSpeechFileFormat::PcmS16le16)
+/// input, which [`prepare`] resamples to.
+pub const STS_SAMPLE_RATE: u32 = 16_000;
+
+/// Zero crossings of the resampling kernel on each side of an output
+/// sample. More crossings give a sharper cutoff at the cost of more taps.
+const RESAMPLE_ZERO_CROSSINGS: f64 = 16.0;
+
+/// Length of the windows compared when looking for a quiet cut point.
+const SILENCE_FRAME: Duration = Duration::from_millis(20);
+
+/// `WAVE_FORMAT_PCM` and `WAVE_FORMAT_EXTENSIBLE` format tags.
+const PCM_FORMAT_TAGS: [u16; 2] = [0x0001, 0xfffe];
+
+/// Format of a WAV file, as read by [`probe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioProbe {
+    /// Samples per second.
+    pub sample_rate: u32,
+    /// Number of channels.
+    pub channels: u16,
+    /// Bits per sample.
+    pub bits_per_sample: u16,
+    /// Playing time.
+    pub duration: Duration,
+}
+
+/// Options for [`prepare`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreprocessOptions {
+    /// Longest chunk sent in one request.
+    pub max_chunk: Duration,
+    /// How far before [`max_chunk`](Self::max_chunk) a cut may be placed.
+    /// A wider window finds quieter cut points at the cost of shorter
+    /// chunks.
+    pub search_window: Duration,
+}
+
+impl Default for PreprocessOptions {
+    fn default() -> Self {
+        Self { max_chunk: Duration::from_mins(4), search_window: Duration::from_secs(20) }
+    }
+}
+
+/// A parsed WAV file.
+struct Wav<'a> {
+    format_tag: u16,
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+    data: &'a [u8],
+}
+
+/// Reads the format and duration of a WAV file.
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::Validation`] if `wav` is not a WAV file or
+/// has no `fmt ` or `data` chunk.
+pub fn probe(wav: &[u8]) -> Result<AudioProbe> {
+    let wav = parse_wav(wav)?;
+    let frame_bytes = u64::from(wav.channels) * u64::from(wav.bits_per_sample.div_ceil(8));
+    let frames = wav.data.len() as u64 / frame_bytes.max(1);
+    let nanos = u128::from(frames) * 1_000_000_000 / u128::from(wav.sample_rate);
+    Ok(AudioProbe {
+        sample_rate: wav.sample_rate,
+        channels: wav.channels,
+        bits_per_sample: wav.bits_per_sample,
+        duration: Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX)),
+    })
+}
+
+/// Converts a 16-bit PCM WAV file into chunks of 16 kHz mono 16-bit
+/// little-endian PCM, each at most `options.max_chunk` long.
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::Validation`] if `wav` is not a 16-bit PCM WAV
+/// file, or `options.max_chunk` is shorter than the silence detection
+/// window.
+pub fn prepare(wav: &[u8], options: &PreprocessOptions) -> Result<Vec<Bytes>> {
+    let wav = parse_wav(wav)?;
+    if !PCM_FORMAT_TAGS.contains(&wav.format_tag) || wav.bits_per_sample != 16 {
+        return Err(ElevenLabsError::Validation(format!(
+            "only 16-bit PCM WAV can be preprocessed, got format {:#06x} with {} bits per sample",
+            wav.format_tag, wav.bits_per_sample
+        )));
+    }
+    let max_chunk = samples_in(options.max_chunk);
+    let frame = samples_in(SILENCE_FRAME);
+    if max_chunk < frame {
+        return Err(ElevenLabsError::Validation(format!(
+            "max_chunk must be at least {}ms",
+            SILENCE_FRAME.as_millis()
+        )));
+    }
+    let window = samples_in(options.search_window).min(max_chunk - frame);
+
+    let mono = downmix(wav.data, wav.channels);
+    let samples = resample(&mono, wav.sample_rate, STS_SAMPLE_RATE);
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < samples.len() {
+        let end = if samples.len() - start <= max_chunk {
+            samples.len()
+        } else {
+            quietest_cut(&samples, start + max_chunk - window, start + max_chunk, frame)
+        };
+        let mut chunk = BytesMut::with_capacity((end - start) * 2);
+        for &sample in &samples[start..end] {
+            chunk.put_i16_le(sample);
+        }
+        chunks.push(chunk.freeze());
+        start = end;
+    }
+    Ok(chunks)
+}
+
+/// Joins audio converted chunk by chunk into one file in `format`.
+///
+/// MP3 parts lose their ID3 tags after the first, WAV parts are merged
+/// under one header, and other formats are concatenated as-is.
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::Validation`] if a WAV part cannot be parsed or
+/// the parts do not share one WAV format.
+pub fn concat(parts: &[Bytes], format: OutputFormat) -> Result<Bytes> {
+    let name = format.to_string();
+    if name.starts_with("wav_") {
+        let mut pcm = BytesMut::new();
+        let mut layout = None;
+        for part in parts {
+            let wav = parse_wav(part)?;
+            let part_layout = (wav.sample_rate, wav.channels);
+            if *layout.get_or_insert(part_layout) != part_layout {
+                return Err(ElevenLabsError::Validation(
+                    "WAV parts differ in sample rate or channel count".to_owned(),
+                ));
+            }
+            pcm.put_slice(wav.data);
+        }
+        let (sample_rate, channels) = layout.unwrap_or_else(|| (format.sample_rate(), 1));
+        return pcm_to_wav(&pcm, sample_rate, channels);
+    }
+    let mut joined = BytesMut::with_capacity(parts.iter().map(Bytes::len).sum());
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 && name.starts_with("mp3_") {
+            joined.put_slice(strip_id3_tag(part));
+        } else {
+            joined.put_slice(part);
+        }
+    }
+    Ok(joined.freeze())
+}
+
+/// Reads the `fmt ` and `data` chunks of a WAV file.
+fn parse_wav(wav: &[u8]) -> Result<Wav<'_>> {
+    let invalid = |reason: &str| ElevenLabsError::Validation(format!("invalid WAV file: {reason}"));
+    if wav.get(..4) != Some(b"RIFF") || wav.get(8..12) != Some(b"WAVE") {
+        return Err(invalid("missing RIFF/WAVE header"));
+    }
+    let mut format = None;
+    let mut offset = 12;
+    while let Some(header) = wav.get(offset..offset + 8) {
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let body = offset + 8;
+        match &header[..4] {
+            b"fmt " => {
+                let fmt = wav.get(body..body + 16).ok_or_else(|| invalid("short fmt chunk"))?;
+                let field = |at: usize| u16::from_le_bytes([fmt[at], fmt[at + 1]]);
+                format = Some((
+                    field(0),
+                    field(2),
+                    u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]),
+                    field(14),
+                ));
+            }
+            b"data" => {
+                let (format_tag, channels, sample_rate, bits_per_sample) =
+                    format.ok_or_else(|| invalid("data chunk before fmt chunk"))?;
+                if channels == 0 || sample_rate == 0 {
+                    return Err(invalid("zero channels or sample rate"));
+                }
+                let end = body.saturating_add(size).min(wav.len());
+                return Ok(Wav {
+                    format_tag,
+                    channels,
+                    sample_rate,
+                    bits_per_sample,
+                    data: &wav[body..end],
+                });
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even length.
+        offset = body.saturating_add(size).saturating_add(size % 2);
+    }
+    Err(invalid("no data chunk"))
+}
+
+/// Returns the number of samples at [`STS_SAMPLE_RATE`] in `duration`.
+fn samples_in(duration: Duration) -> usize {
+    (duration.as_millis() * u128::from(STS_SAMPLE_RATE) / 1000) as usize
+}
+
+/// Averages interleaved 16-bit little-endian channels into mono samples.
+fn downmix(data: &[u8], channels: u16) -> Vec<i16> {
+    let channels = usize::from(channels);
+    data.chunks_exact(2 * channels)
+        .map(|frame| {
+            let sum: i32 = frame
+                .chunks_exact(2)
+                .map(|sample| i32::from(i16::from_le_bytes([sample[0], sample[1]])))
+                .sum();
+            (sum / channels as i32) as i16
+        })
+        .collect()
+}
+
+/// Resamples mono audio from `from` to `to` Hz with a Blackman-windowed
+/// sinc filter.
+///
+/// When downsampling, the kernel is widened so its cutoff falls at the
+/// output's Nyquist frequency, removing content that would otherwise alias
+/// into the speech band.
+fn resample(samples: &[i16], from: u32, to: u32) -> Vec<i16> {
+    if from == to || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let cutoff = (f64::from(to) / f64::from(from)).min(1.0);
+    let half_width = RESAMPLE_ZERO_CROSSINGS / cutoff;
+    let step = f64::from(from) / f64::from(to);
+    let len = (samples.len() as u64 * u64::from(to) / u64::from(from)) as usize;
+    (0..len)
+        .map(|i| {
+            let center = i as f64 * step;
+            let first = (center - half_width).ceil().max(0.0) as usize;
+            let last = ((center + half_width).floor() as usize).min(samples.len() - 1);
+            let (mut sum, mut weight) = (0.0, 0.0);
+            for (k, &sample) in samples.iter().enumerate().take(last + 1).skip(first) {
+                let x = (k as f64 - center) * cutoff;
+                let tap = sinc(x) * blackman(x / RESAMPLE_ZERO_CROSSINGS);
+                sum = f64::from(sample).mul_add(tap, sum);
+                weight += tap;
+            }
+            // Dividing by the tap sum keeps a constant signal unchanged,
+            // including near the edges where the kernel is cut short.
+            (sum / weight).round().clamp(f64::from(i16::MIN), f64::from(i16::MAX)) as i16
+        })
+        .collect()
+}
+
+/// Normalized sinc, `sin(πx) / πx`.
+fn sinc(x: f64) -> f64 {
+    if x.abs() < f64::EPSILON { 1.0 } else { (PI * x).sin() / (PI * x) }
+}
+
+/// Blackman window over `[-1, 1]`, zero outside it.
+fn blackman(t: f64) -> f64 {
+    if t.abs() >= 1.0 {
+        return 0.0;
+    }
+    0.08f64.mul_add((2.0 * PI * t).cos(), 0.5f64.mul_add((PI * t).cos(), 0.42))
+}
+
+/// Returns the middle of the quietest `frame`-long window between `from`
+/// and `to`, preferring later windows on ties.
+fn quietest_cut(samples: &[i16], from: usize, to: usize, frame: usize) -> usize {
+    let energy = |start: usize| -> i64 {
+        samples[start..start + frame].iter().map(|&s| i64::from(s) * i64::from(s)).sum()
+    };
+    (from..=to - frame)
+        .rev()
+        .step_by(frame / 2)
+        .min_by_key(|&start| energy(start))
+        .map_or(to, |start| start + frame / 2)
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    /// Encodes mono samples as a WAV file at `sample_rate`.
+    fn wav(samples: &[i16], sample_rate: u32, channels: u16) -> Bytes {
+        let mut pcm = BytesMut::new();
+        for &sample in samples {
+            for _ in 0..channels {
+                pcm.put_i16_le(sample);
+            }
+        }
+        pcm_to_wav(&pcm, sample_rate, channels).unwrap()
+    }
+
+    /// Returns `seconds` of a sine tone at `frequency` Hz.
+    fn tone(frequency: f64, sample_rate: u32, seconds: u32) -> Vec<i16> {
+        (0..sample_rate * seconds)
+            .map(|i| {
+                let t = f64::from(i) / f64::from(sample_rate);
+                ((2.0 * PI * frequency * t).sin() * 10_000.0) as i16
+            })
+            .collect()
+    }
+
+    /// Root mean square of `samples`, ignoring the first and last 100 ms
+    /// where the filter kernel is cut short.
+    fn rms(samples: &[i16], sample_rate: u32) -> f64 {
+        let edge = sample_rate as usize / 10;
+        let inner = &samples[edge..samples.len() - edge];
+        let power: f64 = inner.iter().map(|&s| f64::from(s) * f64::from(s)).sum();
+        (power / inner.len() as f64).sqrt()
+    }
+
+    #[test]
+    fn resample_removes_content_above_the_output_nyquist() {
+        let high = tone(10_000.0, 48_000, 1);
+        let low = tone(1_000.0, 44_100, 1);
+
+        let high_out = resample(&high, 48_000, STS_SAMPLE_RATE);
+        let low_out = resample(&low, 44_100, STS_SAMPLE_RATE);
+
+        // 10 kHz folds back to 6 kHz without a filter; require 40 dB of
+        // attenuation, while a 1 kHz tone passes through.
+        assert!(rms(&high_out, STS_SAMPLE_RATE) < rms(&high, 48_000) / 100.0);
+        let ratio = rms(&low_out, STS_SAMPLE_RATE) / rms(&low, 44_100);
+        assert!((0.98..1.02).contains(&ratio), "{ratio}");
+    }
+
+    #[test]
+    fn prepare_downmixes_and_resamples() {
+        let samples = vec![1_000_i16; 48_000];
+        let input = wav(&samples, 48_000, 2);
+
+        let probe = probe(&input).unwrap();
+        assert_eq!((probe.sample_rate, probe.channels), (48_000, 2));
+        assert_eq!(probe.duration, Duration::from_secs(1));
+
+        let chunks = prepare(&input, &PreprocessOptions::default()).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), 16_000 * 2);
+        // A constant signal comes through the 48 kHz -> 16 kHz resample intact.
+        assert!(chunks[0].chunks_exact(2).all(|s| i16::from_le_bytes([s[0], s[1]]) == 1_000));
+    }
+
+    #[test]
+    fn prepare_cuts_long_audio_in_pauses() {
+        // 3 s of tone with a 100 ms pause starting at 1.5 s.
+        let samples: Vec<i16> =
+            (0..48_000).map(|i| if (24_000..25_600).contains(&i) { 0 } else { 8_000 }).collect();
+        let options = PreprocessOptions {
+            max_chunk: Duration::from_secs(2),
+            search_window: Duration::from_secs(2),
+        };
+
+        let chunks = prepare(&wav(&samples, 16_000, 1), &options).unwrap();
+
+        let lens: Vec<usize> = chunks.iter().map(|chunk| chunk.len() / 2).collect();
+        assert_eq!(lens.iter().sum::<usize>(), 48_000);
+        assert!(lens.iter().all(|&len| len <= 32_000), "{lens:?}");
+        let first_cut = lens[0];
+        assert!(samples[first_cut] == 0 || samples[first_cut - 1] == 0, "cut at {first_cut}");
+    }
+
+    #[test]
+    fn prepare_rejects_non_pcm_wav() {
+        let mut input = wav(&[0; 16], 16_000, 1).to_vec();
+        input[20] = 3; // IEEE float
+        assert!(matches!(
+            prepare(&input, &PreprocessOptions::default()),
+            Err(ElevenLabsError::Validation(_))
+        ));
+        assert!(probe(b"not a wav file").is_err());
+    }
+
+    #[test]
+    fn concat_merges_wav_and_strips_mp3_tags() {
+        let parts = [wav(&[1, 2], 22_050, 1), wav(&[3], 22_050, 1)];
+        let joined = concat(&parts, OutputFormat::Wav_22050).unwrap();
+        assert_eq!(joined, wav(&[1, 2, 3], 22_050, 1));
+
+        let tagged = Bytes::from_static(b"ID3\x04\x00\x00\x00\x00\x00\x00\xff\xfb");
+        let joined = concat(&[tagged.clone(), tagged], OutputFormat::Mp3_44100_128).unwrap();
+        assert_eq!(&joined[..], b"ID3\x04\x00\x00\x00\x00\x00\x00\xff\xfb\xff\xfb");
+    }
+}
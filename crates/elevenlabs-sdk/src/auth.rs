@@ -1,10 +1,24 @@
-//! API key authentication for the ElevenLabs API.
+//! Authentication for the ElevenLabs API.
 //!
 //! Provides the [`ApiKey`] newtype for securely handling API keys with
-//! redacted [`Debug`] output, and the [`API_KEY_HEADER`] constant used
-//! for authenticating all API requests.
+//! redacted [`Debug`] output, the [`API_KEY_HEADER`] constant used
+//! for authenticating API requests, [`KeyPool`] for spreading
+//! requests across several keys, and [`AuthStrategy`] for authenticating
+//! with bearer or single-use tokens instead of an API key.
 
-use std::fmt;
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+#[cfg(feature = "ws")]
+use crate::types::SINGLE_USE_TOKEN_PARAM;
+use crate::{config::ConfigError, error::ElevenLabsError};
 
 /// HTTP header name used to send the API key to ElevenLabs.
 ///
@@ -60,6 +74,224 @@ impl AsRef<str> for ApiKey {
     }
 }
 
+/// How a [`KeyPool`] picks the key for each request.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyRotation {
+    /// Cycle through the keys, one request attempt at a time.
+    #[default]
+    RoundRobin,
+    /// Use one key until the API rejects it with `401` or `429`, then move
+    /// on to the next one.
+    Failover,
+}
+
+/// A set of API keys that requests are spread across.
+///
+/// Attach a pool with
+/// [`ElevenLabsClient::with_key_pool`](crate::ElevenLabsClient::with_key_pool).
+/// Scoped clients created from that client share the pool, including its
+/// rotation position. A key set with
+/// [`ElevenLabsClient::with_api_key`](crate::ElevenLabsClient::with_api_key)
+/// takes precedence over the pool.
+///
+/// # Examples
+///
+/// ```
+/// use elevenlabs_sdk::{KeyPool, KeyRotation};
+///
+/// let pool = KeyPool::new(["key-a", "key-b"])?.rotation(KeyRotation::Failover);
+/// assert_eq!(pool.len(), 2);
+/// # Ok::<(), elevenlabs_sdk::ConfigError>(())
+/// ```
+#[derive(Debug)]
+pub struct KeyPool {
+    keys: Vec<ApiKey>,
+    rotation: KeyRotation,
+    cursor: AtomicUsize,
+}
+
+impl KeyPool {
+    /// Creates a round-robin pool over `keys`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::EmptyKeyPool`] if `keys` is empty.
+    pub fn new<I, K>(keys: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<ApiKey>,
+    {
+        let keys: Vec<ApiKey> = keys.into_iter().map(Into::into).collect();
+        if keys.is_empty() {
+            return Err(ConfigError::EmptyKeyPool);
+        }
+        Ok(Self { keys, rotation: KeyRotation::default(), cursor: AtomicUsize::new(0) })
+    }
+
+    /// Sets the rotation strategy.
+    pub const fn rotation(mut self, rotation: KeyRotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns the keys in the pool.
+    pub fn keys(&self) -> &[ApiKey] {
+        &self.keys
+    }
+
+    /// Returns the number of keys in the pool.
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Always `false`: a pool holds at least one key.
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Picks the key for the next request attempt, returning its index.
+    pub(crate) fn pick(&self) -> (usize, &ApiKey) {
+        let position = match self.rotation {
+            KeyRotation::RoundRobin => self.cursor.fetch_add(1, Ordering::Relaxed),
+            KeyRotation::Failover => self.cursor.load(Ordering::Relaxed),
+        };
+        let index = position % self.keys.len();
+        (index, &self.keys[index])
+    }
+
+    /// Records that the key at `index` was rejected. Under
+    /// [`KeyRotation::Failover`] this moves the pool on to the next key,
+    /// unless another request already did.
+    pub(crate) fn report_rejected(&self, index: usize) {
+        if self.rotation != KeyRotation::Failover {
+            return;
+        }
+        let current = self.cursor.load(Ordering::Relaxed);
+        if current % self.keys.len() == index {
+            let _ = self.cursor.compare_exchange(
+                current,
+                current.wrapping_add(1),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
+    }
+}
+
+/// Future returned by a [`TokenProvider`].
+pub type TokenFuture = Pin<Box<dyn Future<Output = Result<String, ElevenLabsError>> + Send>>;
+
+/// Callback that fetches a fresh single-use token, typically from your own
+/// backend calling
+/// [`SingleUseTokenService::create`](crate::services::SingleUseTokenService::create).
+///
+/// # Examples
+///
+/// ```
+/// use elevenlabs_sdk::auth::TokenProvider;
+///
+/// let provider = TokenProvider::new(|| async {
+///     // Ask your backend for a token here.
+///     Ok("sutkn_abc123".to_owned())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct TokenProvider(Arc<dyn Fn() -> TokenFuture + Send + Sync>);
+
+impl TokenProvider {
+    /// Wraps an async callback that returns a fresh token on each call.
+    pub fn new<F, Fut>(fetch: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, ElevenLabsError>> + Send + 'static,
+    {
+        Self(Arc::new(move || Box::pin(fetch())))
+    }
+
+    /// Fetches a new token.
+    pub(crate) async fn fetch(&self) -> Result<ApiKey, ElevenLabsError> {
+        (self.0)().await.map(ApiKey)
+    }
+}
+
+impl fmt::Debug for TokenProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenProvider(..)")
+    }
+}
+
+impl PartialEq for TokenProvider {
+    /// Providers compare equal only if they are the same instance.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for TokenProvider {}
+
+/// How the client authenticates its HTTP requests and WebSocket
+/// connections.
+///
+/// Set via [`ClientConfigBuilder::auth`](crate::ClientConfigBuilder::auth).
+/// A key set with
+/// [`ElevenLabsClient::with_api_key`](crate::ElevenLabsClient::with_api_key)
+/// or drawn from a [`KeyPool`] still takes precedence on HTTP requests.
+///
+/// # Examples
+///
+/// ```
+/// use elevenlabs_sdk::{AuthStrategy, ClientConfig};
+///
+/// // The API key passed to the builder is not sent under a token strategy.
+/// let config =
+///     ClientConfig::builder("").auth(AuthStrategy::Bearer("short-lived-token".into())).build();
+/// assert!(matches!(config.auth, AuthStrategy::Bearer(_)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AuthStrategy {
+    /// Send [`ClientConfig::api_key`](crate::ClientConfig::api_key) in the
+    /// `xi-api-key` header, and in the first frame of TTS WebSockets.
+    #[default]
+    ApiKey,
+    /// Send a fixed token as `Authorization: Bearer <token>`, and as the
+    /// `authorization` query parameter of WebSocket URLs.
+    Bearer(ApiKey),
+    /// Fetch a new token from the provider for every HTTP attempt and
+    /// WebSocket connection, since each token can be used only once. It is
+    /// sent as `Authorization: Bearer <token>` on HTTP requests and as the
+    /// `single_use_token` query parameter of WebSocket URLs.
+    SingleUseToken(TokenProvider),
+}
+
+impl AuthStrategy {
+    /// Returns the token to send as a bearer token, fetching a fresh one
+    /// for [`SingleUseToken`](Self::SingleUseToken), or `None` when the API
+    /// key is used.
+    pub(crate) async fn token(&self) -> Result<Option<ApiKey>, ElevenLabsError> {
+        match self {
+            Self::ApiKey => Ok(None),
+            Self::Bearer(token) => Ok(Some(token.clone())),
+            Self::SingleUseToken(provider) => provider.fetch().await.map(Some),
+        }
+    }
+
+    /// Returns the WebSocket query parameter carrying the token, or `None`
+    /// when the API key is used.
+    #[cfg(feature = "ws")]
+    pub(crate) async fn ws_query_param(
+        &self,
+    ) -> Result<Option<(&'static str, String)>, ElevenLabsError> {
+        Ok(match self {
+            Self::ApiKey => None,
+            Self::Bearer(token) => Some(("authorization", format!("Bearer {}", token.as_str()))),
+            Self::SingleUseToken(provider) => {
+                Some((SINGLE_USE_TOKEN_PARAM, provider.fetch().await?.0))
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 #[expect(clippy::unwrap_used, reason = "tests use unwrap for concise assertions")]
 mod tests {
@@ -121,4 +353,75 @@ mod tests {
     fn api_key_header_constant() {
         assert_eq!(API_KEY_HEADER, "xi-api-key");
     }
+
+    #[test]
+    fn key_pool_rejects_empty_key_list() {
+        let result = KeyPool::new(Vec::<ApiKey>::new());
+        assert_eq!(result.unwrap_err(), ConfigError::EmptyKeyPool);
+    }
+
+    #[test]
+    fn key_pool_round_robin_cycles_keys() {
+        let pool = KeyPool::new(["a", "b", "c"]).unwrap();
+        let picked: Vec<&str> = (0..5).map(|_| pool.pick().1.as_str()).collect();
+        assert_eq!(picked, ["a", "b", "c", "a", "b"]);
+
+        pool.report_rejected(2);
+        assert_eq!(pool.pick().1.as_str(), "c");
+    }
+
+    #[test]
+    fn key_pool_failover_moves_on_after_rejection() {
+        let pool = KeyPool::new(["a", "b"]).unwrap().rotation(KeyRotation::Failover);
+        assert_eq!(pool.pick(), (0, &ApiKey::from("a")));
+        assert_eq!(pool.pick(), (0, &ApiKey::from("a")));
+
+        pool.report_rejected(0);
+        assert_eq!(pool.pick(), (1, &ApiKey::from("b")));
+
+        // A stale report for a key that is no longer current is ignored.
+        pool.report_rejected(0);
+        assert_eq!(pool.pick(), (1, &ApiKey::from("b")));
+
+        pool.report_rejected(1);
+        assert_eq!(pool.pick(), (0, &ApiKey::from("a")));
+    }
+
+    #[tokio::test]
+    async fn auth_strategy_tokens() {
+        assert_eq!(AuthStrategy::ApiKey.token().await.unwrap(), None);
+        #[cfg(feature = "ws")]
+        assert_eq!(AuthStrategy::ApiKey.ws_query_param().await.unwrap(), None);
+
+        let bearer = AuthStrategy::Bearer(ApiKey::from("tok"));
+        assert_eq!(bearer.token().await.unwrap(), Some(ApiKey::from("tok")));
+        #[cfg(feature = "ws")]
+        assert_eq!(
+            bearer.ws_query_param().await.unwrap(),
+            Some(("authorization", "Bearer tok".to_owned()))
+        );
+    }
+
+    #[tokio::test]
+    async fn single_use_token_is_fetched_each_time() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let calls = Arc::clone(&counter);
+        let provider = TokenProvider::new(move || {
+            let n = calls.fetch_add(1, Ordering::Relaxed);
+            async move { Ok(format!("sut-{n}")) }
+        });
+        let strategy = AuthStrategy::SingleUseToken(provider.clone());
+
+        assert_eq!(strategy.token().await.unwrap(), Some(ApiKey::from("sut-0")));
+        #[cfg(feature = "ws")]
+        assert_eq!(
+            strategy.ws_query_param().await.unwrap(),
+            Some(("single_use_token", "sut-1".to_owned()))
+        );
+        assert_eq!(strategy, AuthStrategy::SingleUseToken(provider));
+        assert_ne!(
+            strategy,
+            AuthStrategy::SingleUseToken(TokenProvider::new(|| async { Ok(String::new()) }))
+        );
+    }
 }
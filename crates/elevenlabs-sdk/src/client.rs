@@ -4,21 +4,49 @@
 //! URL construction, API key header injection, JSON (de)serialization,
 //! error response parsing, and tracing instrumentation.
 
+use std::{
+    collections::HashMap,
+    io::Write as _,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
 use bytes::Bytes;
+use flate2::{Compression, write::GzEncoder};
 use futures_core::Stream;
 use hpx::{
     Method, StatusCode,
-    header::{HeaderMap, HeaderValue},
+    header::{HeaderMap, HeaderName, HeaderValue},
 };
 use serde::{Serialize, de::DeserializeOwned};
+use tracing::Instrument;
 
 use crate::{
-    auth::API_KEY_HEADER,
-    config::ClientConfig,
-    error::{ElevenLabsError, Result},
-    middleware,
+    auth::{API_KEY_HEADER, ApiKey, AuthStrategy, KeyPool},
+    config::{
+        ClientConfig, HttpVersionPreference, NetworkConfig, PoolConfig, RequestOptions,
+        TimeoutCategory,
+    },
+    deadline,
+    download::{self, DownloadOptions, DownloadSummary},
+    error::{self, ElevenLabsError, Result},
+    middleware::{
+        self,
+        metrics::{PoolCounters, PoolStats, RequestMetrics},
+        quota::QuotaGuard,
+    },
+    types::VoiceSettings,
+    upload::UploadBody,
 };
 
+/// HTTP header used to send [`RequestOptions::idempotency_key`], or the key
+/// generated for a [`RequestOptions::retry_unsafe`] call.
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// HTTP header carrying the ID ElevenLabs assigns to each request.
+pub(crate) const REQUEST_ID_HEADER: &str = "request-id";
+
 /// The main ElevenLabs API client.
 ///
 /// Wraps an [`hpx::Client`] with ElevenLabs-specific configuration, including
@@ -26,6 +54,12 @@ use crate::{
 ///
 /// Created via [`ElevenLabsClient::new`] with a [`ClientConfig`].
 ///
+/// Cloning is cheap: the configuration, connection pool and caches live
+/// behind an [`Arc`] and are shared by every clone, as they are with scoped
+/// clients from [`with_options`](Self::with_options). Services borrow the
+/// client, so to use one from a spawned task or an axum handler, move a
+/// clone into it and create the service there.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -34,86 +68,302 @@ use crate::{
 /// # async fn example() -> elevenlabs_sdk::Result<()> {
 /// let config = ClientConfig::builder("your-api-key").build();
 /// let client = ElevenLabsClient::new(config)?;
+///
+/// let tasks: Vec<_> = ["voice_a", "voice_b"]
+///     .into_iter()
+///     .map(|voice_id| {
+///         let client = client.clone();
+///         tokio::spawn(async move { client.voices().get(voice_id, None).await })
+///     })
+///     .collect();
+/// for task in tasks {
+///     let voice = task.await.expect("task panicked")?;
+///     println!("{}", voice.name);
+/// }
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub struct ElevenLabsClient {
+    shared: Arc<Shared>,
+    options: Arc<RequestOptions>,
+    key_pool: Option<Arc<KeyPool>>,
+    quota_guard: Option<Arc<QuotaGuard>>,
+    #[cfg(feature = "cache")]
+    cache: Option<Arc<middleware::cache::CacheConfig>>,
+    #[cfg(feature = "record-replay")]
+    cassette: Option<Arc<middleware::recorder::Cassette>>,
+}
+
+/// State shared by a client, its clones and its scoped clients.
+struct Shared {
     config: ClientConfig,
     http: hpx::Client,
     base_url: url::Url,
+    /// Per-voice settings cached by
+    /// [`VoicesService::default_settings_for`](crate::services::VoicesService::default_settings_for).
+    voice_settings: scc::HashMap<String, VoiceSettings>,
+    /// Voice and model names cached by
+    /// [`HistoryService::list_enriched`](crate::services::HistoryService::list_enriched).
+    display_names: scc::HashMap<NameKind, HashMap<String, String>>,
+    /// Connection usage reported by [`pool_stats`](ElevenLabsClient::pool_stats).
+    pool_counters: PoolCounters,
+}
+
+/// Kind of resource in the display name cache. Each kind maps IDs to
+/// display names and is absent until it has been fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum NameKind {
+    Voice,
+    Model,
 }
 
 impl std::fmt::Debug for ElevenLabsClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ElevenLabsClient")
-            .field("config", &self.config)
-            .field("base_url", &self.base_url)
-            .finish_non_exhaustive()
+        let mut debug = f.debug_struct("ElevenLabsClient");
+        debug
+            .field("config", &self.shared.config)
+            .field("base_url", &self.shared.base_url)
+            .field("options", &self.options)
+            .field("key_pool", &self.key_pool)
+            .field("quota_guard", &self.quota_guard);
+        #[cfg(feature = "cache")]
+        debug.field("cache", &self.cache);
+        #[cfg(feature = "record-replay")]
+        debug.field("cassette", &self.cassette);
+        debug.finish_non_exhaustive()
     }
 }
 
-/// Shape of error responses returned by the ElevenLabs API.
-#[derive(serde::Deserialize)]
-struct ApiErrorBody {
-    /// Top-level detail field.
-    detail: Option<ApiErrorDetail>,
-}
-
-/// Inner detail of an API error — can be a plain string or structured object.
-#[derive(serde::Deserialize)]
-#[serde(untagged)]
-enum ApiErrorDetail {
-    /// A plain error message string.
-    Message(String),
-    /// A structured error object with a message field.
-    Structured {
-        /// The error message.
-        message: String,
-    },
-}
-
 impl ElevenLabsClient {
     /// Creates a new [`ElevenLabsClient`] from the given configuration.
     ///
-    /// Builds an internal HTTP client with default headers (including the
-    /// `xi-api-key` authentication header) and the configured timeout.
+    /// Builds an internal HTTP client with default headers (the
+    /// `User-Agent`, [`ClientConfig::default_headers`] and the `xi-api-key`
+    /// authentication header), the configured timeout, the
+    /// proxy, TLS and DNS settings from [`ClientConfig::network`], the
+    /// connection pool settings from [`ClientConfig::pool`] and response
+    /// decompression from [`ClientConfig::compression`].
     ///
     /// # Errors
     ///
     /// Returns [`ElevenLabsError::InvalidUrl`] if `config.base_url` cannot be parsed,
-    /// or [`ElevenLabsError::Transport`] if the HTTP client fails to build.
+    /// [`ElevenLabsError::Validation`] if the user agent or a default header
+    /// is not a valid header, or [`ElevenLabsError::Transport`] if the proxy
+    /// URL or a root certificate is invalid or the HTTP client fails to
+    /// build.
     pub fn new(config: ClientConfig) -> Result<Self> {
+        crypto_provider();
         let base_url = url::Url::parse(&config.base_url)?;
 
         let mut default_headers = HeaderMap::new();
-        let mut api_key_value = HeaderValue::from_str(config.api_key.as_str()).map_err(|e| {
-            ElevenLabsError::Validation(format!("invalid API key header value: {e}"))
-        })?;
-        api_key_value.set_sensitive(true);
-        default_headers.insert(API_KEY_HEADER, api_key_value);
+        let user_agent = HeaderValue::from_str(&config.user_agent_header())
+            .map_err(|e| ElevenLabsError::Validation(format!("invalid user agent: {e}")))?;
+        default_headers.insert(hpx::header::USER_AGENT, user_agent);
+        for (name, value) in &config.default_headers {
+            let (name, value) = default_header(name, value)?;
+            default_headers.insert(name, value);
+        }
+        if config.auth == AuthStrategy::ApiKey {
+            default_headers.insert(API_KEY_HEADER, api_key_header(&config.api_key)?);
+        }
 
-        let http = hpx::Client::builder()
+        let decompress = config.compression.decompress_responses;
+        let builder = hpx::Client::builder()
             .default_headers(default_headers)
             .timeout(config.timeout)
-            .build()
-            .map_err(ElevenLabsError::Transport)?;
-
-        Ok(Self { config, http, base_url })
+            .gzip(decompress)
+            .brotli(decompress);
+        let builder = apply_pool(apply_network(builder, &config.network)?, &config.pool);
+        let http = builder.build().map_err(ElevenLabsError::Transport)?;
+
+        Ok(Self {
+            shared: Arc::new(Shared {
+                config,
+                http,
+                base_url,
+                voice_settings: scc::HashMap::default(),
+                display_names: scc::HashMap::default(),
+                pool_counters: PoolCounters::default(),
+            }),
+            options: Arc::default(),
+            key_pool: None,
+            quota_guard: None,
+            #[cfg(feature = "cache")]
+            cache: None,
+            #[cfg(feature = "record-replay")]
+            cassette: None,
+        })
     }
 
     /// Returns a reference to the underlying [`ClientConfig`].
-    pub const fn config(&self) -> &ClientConfig {
-        &self.config
+    pub fn config(&self) -> &ClientConfig {
+        &self.shared.config
+    }
+
+    /// Returns a scoped client that applies `options` to every request.
+    ///
+    /// The scoped client shares the underlying connection pool with `self`,
+    /// so creating one per call is cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use elevenlabs_sdk::{
+    ///     CancellationToken, ClientConfig, ElevenLabsClient, RequestOptions,
+    ///     types::TextToSpeechRequest,
+    /// };
+    ///
+    /// # async fn example() -> elevenlabs_sdk::Result<()> {
+    /// let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+    /// let token = CancellationToken::new();
+    ///
+    /// let scoped = client.with_options(
+    ///     RequestOptions::new().timeout(Duration::from_secs(120)).cancellation_token(token.clone()),
+    /// );
+    /// // Call `token.cancel()` from UI code to abort the conversion.
+    /// let request = TextToSpeechRequest::new("A very long chapter...");
+    /// let audio = scoped.text_to_speech().convert("voice_id", &request, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self { options: Arc::new(options), ..self.clone() }
+    }
+
+    /// Returns a scoped client that authenticates every request with `key`
+    /// instead of the configured key.
+    ///
+    /// Like [`with_options`](Self::with_options), the scoped client shares
+    /// the connection pool with `self` and keeps its other per-call options,
+    /// so a multi-tenant service can create one per tenant request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use elevenlabs_sdk::{ApiKey, ClientConfig, ElevenLabsClient};
+    ///
+    /// # async fn example() -> elevenlabs_sdk::Result<()> {
+    /// let client = ElevenLabsClient::new(ClientConfig::builder("service-key").build())?;
+    ///
+    /// let tenant_key = ApiKey::from("tenant-key");
+    /// let voices = client.with_api_key(&tenant_key).voices().list(None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_api_key(&self, key: &ApiKey) -> Self {
+        self.with_options(RequestOptions::clone(&self.options).api_key(key.clone()))
+    }
+
+    /// Spreads requests across the keys in `pool`.
+    ///
+    /// Each attempt, including retries, takes its key from the pool. Keys
+    /// answered with `401` or `429` are reported back so that
+    /// [`KeyRotation::Failover`](crate::KeyRotation::Failover) can move on.
+    /// A key set with [`with_api_key`](Self::with_api_key) still wins.
+    pub fn with_key_pool(mut self, pool: KeyPool) -> Self {
+        self.key_pool = Some(Arc::new(pool));
+        self
+    }
+
+    /// Refuses text-to-speech and speech-to-speech requests that `guard`
+    /// estimates would exceed the character quota.
+    ///
+    /// Scoped clients share the guard. See [`QuotaGuard`] for how the
+    /// estimate is kept.
+    pub fn with_quota_guard(mut self, guard: QuotaGuard) -> Self {
+        self.quota_guard = Some(Arc::new(guard));
+        self
+    }
+
+    /// Returns the quota guard set with
+    /// [`with_quota_guard`](Self::with_quota_guard), if any.
+    pub fn quota_guard(&self) -> Option<&QuotaGuard> {
+        self.quota_guard.as_deref()
+    }
+
+    /// Checks `characters` against the quota guard, fetching the remaining
+    /// quota first if the estimate is stale.
+    ///
+    /// Does nothing without a guard. With
+    /// [`RequestOptions::skip_quota_guard`] the characters are counted but
+    /// not checked.
+    pub(crate) async fn guard_quota(&self, characters: usize) -> Result<()> {
+        let Some(guard) = &self.quota_guard else {
+            return Ok(());
+        };
+        let characters = i64::try_from(characters).unwrap_or(i64::MAX);
+        if self.options.skip_quota_guard {
+            guard.record(characters);
+            return Ok(());
+        }
+        if guard.needs_refresh(Instant::now()) {
+            let quota = self.user().remaining_quota().await?;
+            guard.update(&quota, Instant::now());
+        }
+        guard.reserve(characters)
+    }
+
+    /// Returns how this client has been using its connections.
+    ///
+    /// Scoped clients from [`with_options`](Self::with_options) share the
+    /// connection pool and these counters with `self`. Compare snapshots
+    /// taken before and after a load test to tune
+    /// [`ClientConfig::pool`].
+    pub fn pool_stats(&self) -> PoolStats {
+        self.shared.pool_counters.snapshot()
+    }
+
+    /// Returns the per-voice settings cache, shared with scoped clients.
+    pub(crate) fn voice_settings_cache(&self) -> &scc::HashMap<String, VoiceSettings> {
+        &self.shared.voice_settings
+    }
+
+    /// Returns the voice and model name cache, shared with scoped clients.
+    pub(crate) fn display_names(&self) -> &scc::HashMap<NameKind, HashMap<String, String>> {
+        &self.shared.display_names
+    }
+
+    /// Enables response caching for GET requests matching `cache`.
+    ///
+    /// Requires the `cache` feature. See
+    /// [`CacheConfig`](crate::CacheConfig) for how entries are stored and
+    /// revalidated.
+    #[cfg(feature = "cache")]
+    pub fn with_cache(mut self, cache: middleware::cache::CacheConfig) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Records responses to, or replays them from, `cassette`.
+    ///
+    /// Requires the `record-replay` feature. See
+    /// [`Cassette`](crate::Cassette) for how interactions are sanitized and
+    /// matched. While recording, response bodies are read in full before
+    /// being returned, so streaming endpoints deliver everything at once.
+    #[cfg(feature = "record-replay")]
+    pub fn with_cassette(mut self, cassette: Arc<middleware::recorder::Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Returns the per-call options applied by this client.
+    pub fn options(&self) -> &RequestOptions {
+        &self.options
     }
 
     /// Returns an [`AgentsService`](crate::services::AgentsService) scoped to
     /// this client.
+    #[cfg(feature = "convai")]
     pub const fn agents(&self) -> crate::services::AgentsService<'_> {
         crate::services::AgentsService::new(self)
     }
 
     /// Returns a [`TextToSpeechService`](crate::services::TextToSpeechService)
     /// scoped to this client.
+    #[cfg(feature = "tts")]
     pub const fn text_to_speech(&self) -> crate::services::TextToSpeechService<'_> {
         crate::services::TextToSpeechService::new(self)
     }
@@ -156,6 +406,7 @@ impl ElevenLabsClient {
 
     /// Returns a [`TextToDialogueService`](crate::services::TextToDialogueService)
     /// scoped to this client.
+    #[cfg(feature = "tts")]
     pub const fn text_to_dialogue(&self) -> crate::services::TextToDialogueService<'_> {
         crate::services::TextToDialogueService::new(self)
     }
@@ -174,18 +425,21 @@ impl ElevenLabsClient {
 
     /// Returns a [`DubbingService`](crate::services::DubbingService) scoped to
     /// this client.
+    #[cfg(feature = "dubbing")]
     pub const fn dubbing(&self) -> crate::services::DubbingService<'_> {
         crate::services::DubbingService::new(self)
     }
 
     /// Returns a [`StudioService`](crate::services::StudioService) scoped to
     /// this client.
+    #[cfg(feature = "studio")]
     pub const fn studio(&self) -> crate::services::StudioService<'_> {
         crate::services::StudioService::new(self)
     }
 
     /// Returns a [`MusicService`](crate::services::MusicService) scoped to
     /// this client.
+    #[cfg(feature = "music")]
     pub const fn music(&self) -> crate::services::MusicService<'_> {
         crate::services::MusicService::new(self)
     }
@@ -202,6 +456,12 @@ impl ElevenLabsClient {
         crate::services::HistoryService::new(self)
     }
 
+    /// Returns a [`UsageService`](crate::services::UsageService) scoped to
+    /// this client.
+    pub const fn usage(&self) -> crate::services::UsageService<'_> {
+        crate::services::UsageService::new(self)
+    }
+
     /// Returns a [`UserService`](crate::services::UserService) scoped to
     /// this client.
     pub const fn user(&self) -> crate::services::UserService<'_> {
@@ -237,70 +497,274 @@ impl ElevenLabsClient {
     /// Constructs the full URL by joining `path` onto the base URL,
     /// optionally attaches a pre-serialized JSON body, and maps
     /// transport/timeout errors.
-    #[tracing::instrument(
-        skip(self, body),
-        fields(method = %method, path = %path)
-    )]
     async fn request(
         &self,
         method: Method,
         path: &str,
         body: Option<serde_json::Value>,
     ) -> Result<hpx::Response> {
-        let url = self.base_url.join(path)?;
-
-        let mut last_error: Option<ElevenLabsError> = None;
+        self.request_with_headers(method, path, body, &HeaderMap::new(), TimeoutCategory::Default)
+            .await
+    }
 
-        for attempt in 0..=self.config.max_retries {
-            let mut builder = self.http.request(method.clone(), url.as_str());
-            if let Some(ref json_body) = body {
-                builder = builder.json(json_body);
+    /// Like [`request`](Self::request), but also sends `headers`, and times
+    /// each attempt out after the timeout for `category`, unless the path is
+    /// a long-running operation.
+    ///
+    /// All attempts run inside one request span; the outcome is recorded on
+    /// the span and reported to the configured [`MetricsSink`](crate::MetricsSink).
+    async fn request_with_headers(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+        headers: &HeaderMap,
+        category: TimeoutCategory,
+    ) -> Result<hpx::Response> {
+        let url = self.endpoint_url(path)?;
+        #[cfg(feature = "record-replay")]
+        if let Some(replayed) = self.replay(&method, &url) {
+            return replayed;
+        }
+        let body = body.map(|value| serde_json::to_vec(&value)).transpose()?;
+        let (body, gzipped) = match body {
+            Some(json) => {
+                let (json, gzipped) =
+                    compress_body(json, self.shared.config.compression.compress_requests_above)?;
+                (Some(Bytes::from(json)), gzipped)
             }
+            None => (None, false),
+        };
+        let idempotency_key = self
+            .options
+            .idempotency_key
+            .clone()
+            .or_else(|| self.options.retry_unsafe.then(middleware::idempotency::generate_key));
+        let retry_ambiguous = middleware::idempotency::is_idempotent_method(&method) ||
+            idempotency_key.is_some() ||
+            self.options.retry_unsafe;
+        let attempt_timeout =
+            self.attempt_timeout(middleware::timeouts::classify(&method, url.path(), category));
+        let span = self.request_span(&method, &url);
+        let started = Instant::now();
+        let deadline_at = self.call_deadline();
+        let mut retries = 0;
+
+        let result = async {
+            let mut last_error: Option<ElevenLabsError> = None;
+
+            for attempt in 0..=self.shared.config.max_retries {
+                retries = attempt;
+                let (mut builder, pooled_key) = self
+                    .apply_options(
+                        self.shared
+                            .http
+                            .request(method.clone(), url.as_str())
+                            .timeout(attempt_timeout),
+                        idempotency_key.as_deref(),
+                    )
+                    .await?;
+                for (name, value) in headers {
+                    builder = builder.header(name, value);
+                }
+                if let Some(ref json_body) = body {
+                    builder = builder
+                        .header(hpx::header::CONTENT_TYPE, "application/json")
+                        .body(json_body.clone());
+                    if gzipped {
+                        builder = builder.header(hpx::header::CONTENT_ENCODING, "gzip");
+                    }
+                }
 
-            match builder.send().await {
-                Ok(response) => {
-                    let status = response.status();
-
-                    if middleware::should_retry(status) && attempt < self.config.max_retries {
-                        let retry_after = middleware::parse_retry_after(&response);
+                let in_flight = self.shared.pool_counters.start();
+                let sent = builder.send().await;
+                drop(in_flight);
+                self.shared.pool_counters.finish(sent.as_ref().ok().map(hpx::Response::version));
+                match sent {
+                    Ok(response) => {
+                        let status = response.status();
+                        self.report_key_status(pooled_key, status);
+
+                        if middleware::should_retry(status) &&
+                            (retry_ambiguous || status == StatusCode::TOO_MANY_REQUESTS) &&
+                            attempt < self.shared.config.max_retries
+                        {
+                            let retry_after = middleware::parse_retry_after(&response);
+                            let delay = middleware::compute_delay(
+                                attempt,
+                                self.shared.config.retry_backoff,
+                                retry_after,
+                            );
+                            if deadline::leaves_time(deadline_at, delay) {
+                                tracing::warn!(
+                                    attempt,
+                                    status = %status,
+                                    delay_ms = delay.as_millis() as u64,
+                                    "retrying request"
+                                );
+                                tokio::time::sleep(delay).await;
+                                continue;
+                            }
+                            tracing::warn!(
+                                attempt,
+                                status = %status,
+                                delay_ms = delay.as_millis() as u64,
+                                "not retrying, deadline would pass during backoff"
+                            );
+                        }
+
+                        tracing::debug!(status = %status, "received API response");
+                        return Ok(response);
+                    }
+                    Err(e)
+                        if e.is_timeout() &&
+                            retry_ambiguous &&
+                            attempt < self.shared.config.max_retries =>
+                    {
                         let delay = middleware::compute_delay(
                             attempt,
-                            self.config.retry_backoff,
-                            retry_after,
+                            self.shared.config.retry_backoff,
+                            None,
                         );
+                        if !deadline::leaves_time(deadline_at, delay) {
+                            return Err(ElevenLabsError::Timeout);
+                        }
                         tracing::warn!(
                             attempt,
-                            status = %status,
                             delay_ms = delay.as_millis() as u64,
-                            "retrying request"
+                            "request timed out, retrying"
                         );
                         tokio::time::sleep(delay).await;
-                        continue;
+                        last_error = Some(ElevenLabsError::Timeout);
+                    }
+                    Err(e) if e.is_timeout() => {
+                        return Err(ElevenLabsError::Timeout);
+                    }
+                    Err(e) => {
+                        return Err(ElevenLabsError::Transport(e));
                     }
-
-                    tracing::debug!(status = %status, "received API response");
-                    return Ok(response);
-                }
-                Err(e) if e.is_timeout() && attempt < self.config.max_retries => {
-                    let delay = middleware::compute_delay(attempt, self.config.retry_backoff, None);
-                    tracing::warn!(
-                        attempt,
-                        delay_ms = delay.as_millis() as u64,
-                        "request timed out, retrying"
-                    );
-                    tokio::time::sleep(delay).await;
-                    last_error = Some(ElevenLabsError::Timeout);
-                }
-                Err(e) if e.is_timeout() => {
-                    return Err(ElevenLabsError::Timeout);
-                }
-                Err(e) => {
-                    return Err(ElevenLabsError::Transport(e));
                 }
             }
+
+            Err(last_error.unwrap_or(ElevenLabsError::Timeout))
+        }
+        .instrument(span.clone())
+        .await;
+
+        let bytes_sent = body.as_ref().map_or(0, |b| b.len() as u64);
+        #[cfg(feature = "record-replay")]
+        let result = self.record(&method, &url, result).await;
+        self.observe(
+            &span,
+            &request_metrics(&method, &url, started, retries, bytes_sent, result.as_ref()),
+        );
+        #[cfg(feature = "cache")]
+        self.invalidate_cache(&method, path, &result).await;
+        result
+    }
+
+    /// Answers a request from the cassette, if one is attached in replay
+    /// mode.
+    #[cfg(feature = "record-replay")]
+    fn replay(&self, method: &Method, url: &url::Url) -> Option<Result<hpx::Response>> {
+        use middleware::recorder::CassetteMode;
+
+        let cassette = self.cassette.as_ref().filter(|c| c.mode() == CassetteMode::Replay)?;
+        let replayed =
+            cassette.next(method.as_str(), &cassette_path(url)).and_then(|interaction| {
+                let status = StatusCode::from_u16(interaction.status).map_err(|_| {
+                    ElevenLabsError::Validation(format!(
+                        "invalid status {} in cassette",
+                        interaction.status
+                    ))
+                })?;
+                let headers = interaction
+                    .headers
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((
+                            hpx::header::HeaderName::from_bytes(name.as_bytes()).ok()?,
+                            HeaderValue::from_str(value).ok()?,
+                        ))
+                    })
+                    .collect();
+                Ok(build_response(status, headers, interaction.body.to_bytes()?))
+            });
+        Some(replayed)
+    }
+
+    /// Stores a successful exchange in the cassette, if one is attached in
+    /// record mode, and returns an equivalent response.
+    #[cfg(feature = "record-replay")]
+    async fn record(
+        &self,
+        method: &Method,
+        url: &url::Url,
+        result: Result<hpx::Response>,
+    ) -> Result<hpx::Response> {
+        use middleware::recorder::CassetteMode;
+
+        let Some(cassette) = self.cassette.as_ref().filter(|c| c.mode() == CassetteMode::Record)
+        else {
+            return result;
+        };
+        let response = result?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+
+        let header_pairs: Vec<(&str, &str)> = headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+            .collect();
+        let mut secrets = vec![self.shared.config.api_key.as_str()];
+        secrets.extend(self.options.api_key.as_ref().map(ApiKey::as_str));
+        if let Some(pool) = &self.key_pool {
+            secrets.extend(pool.keys().iter().map(ApiKey::as_str));
+        }
+        cassette.push(
+            method.as_str(),
+            &cassette_path(url),
+            status.as_u16(),
+            &header_pairs,
+            &body,
+            &secrets,
+        );
+        Ok(build_response(status, headers, body))
+    }
+
+    /// Opens the span for one HTTP call, or a disabled span if
+    /// [`ClientConfig::tracing_spans`] is off.
+    fn request_span(&self, method: &Method, url: &url::Url) -> tracing::Span {
+        if !self.shared.config.tracing_spans {
+            return tracing::Span::none();
         }
+        tracing::info_span!(
+            "request",
+            method = %method,
+            endpoint = url.path(),
+            status = tracing::field::Empty,
+            retries = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            bytes_sent = tracing::field::Empty,
+            bytes_received = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+        )
+    }
 
-        Err(last_error.unwrap_or(ElevenLabsError::Timeout))
+    /// Records a finished call on `span` and reports it to the metrics sink.
+    fn observe(&self, span: &tracing::Span, metrics: &RequestMetrics) {
+        span.record("status", metrics.status);
+        span.record("retries", metrics.retries);
+        span.record("latency_ms", metrics.latency.as_millis() as u64);
+        span.record("bytes_sent", metrics.bytes_sent);
+        span.record("bytes_received", metrics.bytes_received);
+        if let Some(request_id) = &metrics.request_id {
+            span.record("request_id", request_id.as_str());
+        }
+        if let Some(sink) = &self.shared.config.metrics_sink {
+            sink.record_request(metrics);
+        }
     }
 
     /// Checks an HTTP response for errors and maps them to [`ElevenLabsError`]
@@ -312,13 +776,7 @@ impl ElevenLabsClient {
             return Ok(response);
         }
 
-        // 401 Unauthorized
-        if status == StatusCode::UNAUTHORIZED {
-            let body = response.text().await.unwrap_or_default();
-            let message = Self::extract_error_message(&body)
-                .unwrap_or_else(|| "invalid or missing API key".to_owned());
-            return Err(ElevenLabsError::Auth(message));
-        }
+        let request_id = request_id(&response).map(str::to_owned);
 
         // 429 Rate Limited
         if status == StatusCode::TOO_MANY_REQUESTS {
@@ -327,47 +785,275 @@ impl ElevenLabsClient {
                 .get(hpx::header::RETRY_AFTER)
                 .and_then(|v| v.to_str().ok())
                 .and_then(|v| v.parse::<u64>().ok());
-            return Err(ElevenLabsError::RateLimited { retry_after });
+            return Err(ElevenLabsError::RateLimited { retry_after, request_id });
         }
 
-        // Other 4xx / 5xx
-        let status_code = status.as_u16();
         let body = response.text().await.unwrap_or_default();
-        let message = Self::extract_error_message(&body)
-            .unwrap_or_else(|| status.canonical_reason().unwrap_or("Unknown error").to_owned());
+        let reason = status.canonical_reason().unwrap_or("Unknown error");
+        Err(ElevenLabsError::from_response(status.as_u16(), reason, body, request_id))
+    }
 
-        Err(ElevenLabsError::Api {
-            status: status_code,
-            message,
-            body: if body.is_empty() { None } else { Some(body) },
-        })
+    /// Runs `fut` under the scoped [`RequestOptions`] timeout, deadline and
+    /// cancellation token, and the caller's [`deadline::scope`], if any.
+    async fn with_call_options<T>(&self, fut: impl Future<Output = Result<T>>) -> Result<T> {
+        let timed = deadline::within(self.call_deadline(), fut);
+
+        match &self.options.cancellation_token {
+            Some(token) => tokio::select! {
+                biased;
+                () = token.cancelled() => Err(ElevenLabsError::Cancelled),
+                result = timed => result,
+            },
+            None => timed.await,
+        }
+    }
+
+    /// Resolves `path` against the base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the configured
+    /// [`Region`](crate::Region) does not offer the endpoint.
+    fn endpoint_url(&self, path: &str) -> Result<url::Url> {
+        let region = &self.shared.config.region;
+        if !region.supports(path) {
+            return Err(ElevenLabsError::Validation(format!(
+                "{path} is not available in the {region} region"
+            )));
+        }
+        Ok(self.shared.base_url.join(path)?)
+    }
+
+    /// Returns the per-attempt timeout for a request in `category`: the
+    /// scoped [`RequestOptions::attempt_timeout`], else the profile's entry
+    /// for the category, else [`ClientConfig::timeout`].
+    fn attempt_timeout(&self, category: TimeoutCategory) -> Duration {
+        self.options
+            .attempt_timeout
+            .or_else(|| self.shared.config.timeouts.get(category))
+            .unwrap_or(self.shared.config.timeout)
+    }
+
+    /// Returns the earliest of the scoped [`RequestOptions`] timeout, counted
+    /// from now, the scoped deadline and the caller's [`deadline::scope`].
+    fn call_deadline(&self) -> Option<tokio::time::Instant> {
+        let timeout = self.options.timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+        deadline::earliest(deadline::earliest(timeout, self.options.deadline), deadline::current())
+    }
+
+    /// Attaches the call's idempotency key, if any, and picks the API key or
+    /// token for this attempt.
+    ///
+    /// Returns the pool index of the key used, if it came from the
+    /// [`KeyPool`].
+    async fn apply_options(
+        &self,
+        mut builder: hpx::RequestBuilder,
+        idempotency_key: Option<&str>,
+    ) -> Result<(hpx::RequestBuilder, Option<usize>)> {
+        if let Some(key) = idempotency_key {
+            builder = builder.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+
+        let (key, pooled_key) = match (&self.options.api_key, &self.key_pool) {
+            (Some(key), _) => (Some(key), None),
+            (None, Some(pool)) => {
+                let (index, key) = pool.pick();
+                (Some(key), Some(index))
+            }
+            (None, None) => (None, None),
+        };
+        if let Some(key) = key {
+            builder = builder.header(API_KEY_HEADER, api_key_header(key)?);
+        } else if let Some(token) = self.shared.config.auth.token().await? {
+            builder = builder.header(hpx::header::AUTHORIZATION, bearer_header(&token)?);
+        }
+        Ok((builder, pooled_key))
+    }
+
+    /// Tells the key pool when the key used for an attempt was rejected.
+    fn report_key_status(&self, pooled_key: Option<usize>, status: StatusCode) {
+        if let (Some(index), Some(pool)) = (pooled_key, &self.key_pool) &&
+            matches!(status, StatusCode::UNAUTHORIZED | StatusCode::TOO_MANY_REQUESTS)
+        {
+            pool.report_rejected(index);
+        }
+    }
+
+    /// Returns the cache configuration, rule scope and TTL to use for a GET
+    /// of `path`, or `None` if it is not cached or the credential to send
+    /// is not known up front.
+    #[cfg(feature = "cache")]
+    fn cache_rule(
+        &self,
+        path: &str,
+    ) -> Option<(&middleware::cache::CacheConfig, &str, std::time::Duration)> {
+        let cache = self.cache.as_deref()?;
+        self.cache_credential()?;
+        cache.scope_and_ttl(path).map(|(scope, ttl)| (cache, scope, ttl))
+    }
+
+    /// Returns the credential every request from this client is sent with,
+    /// or `None` if it changes between requests, as with a [`KeyPool`] or
+    /// single-use tokens.
+    #[cfg(feature = "cache")]
+    fn cache_credential(&self) -> Option<&str> {
+        if let Some(key) = &self.options.api_key {
+            return Some(key.as_str());
+        }
+        if self.key_pool.is_some() {
+            return None;
+        }
+        match &self.shared.config.auth {
+            AuthStrategy::ApiKey => Some(self.shared.config.api_key.as_str()),
+            AuthStrategy::Bearer(token) => Some(token.as_str()),
+            AuthStrategy::SingleUseToken(_) => None,
+        }
+    }
+
+    /// Drops cached GETs made stale by a successful non-GET request to
+    /// `path`.
+    #[cfg(feature = "cache")]
+    async fn invalidate_cache(&self, method: &Method, path: &str, result: &Result<hpx::Response>) {
+        if let Some(cache) = self.cache.as_deref() &&
+            *method != Method::GET &&
+            result.as_ref().is_ok_and(|response| response.status().is_success())
+        {
+            cache.invalidate(path).await;
+        }
     }
 
-    /// Attempts to extract a human-readable error message from a JSON body.
-    fn extract_error_message(body: &str) -> Option<String> {
-        let parsed: ApiErrorBody = serde_json::from_str(body).ok()?;
-        match parsed.detail? {
-            ApiErrorDetail::Message(msg) => Some(msg),
-            ApiErrorDetail::Structured { message } => Some(message),
+    /// Sends a GET request through the response cache.
+    ///
+    /// Fresh entries are returned without a request. Stale entries with an
+    /// `ETag` are revalidated with `If-None-Match`; a `304` refreshes the
+    /// entry and returns the cached body.
+    #[cfg(feature = "cache")]
+    async fn cached_get(
+        &self,
+        cache: &middleware::cache::CacheConfig,
+        scope: &str,
+        path: &str,
+        ttl: std::time::Duration,
+    ) -> Result<Bytes> {
+        let url = self.endpoint_url(path)?;
+        let credential = self.cache_credential().unwrap_or_default();
+        let key = middleware::cache::cache_key(scope, credential, url.as_str());
+        let cached = cache.load(&key).await;
+
+        let mut headers = HeaderMap::new();
+        if let Some(entry) = &cached {
+            if entry.is_fresh(ttl) {
+                tracing::debug!(path, "serving GET from cache");
+                return Ok(entry.body.clone());
+            }
+            if let Some(value) = entry.etag.as_deref().and_then(|e| HeaderValue::from_str(e).ok()) {
+                headers.insert(hpx::header::IF_NONE_MATCH, value);
+            }
+        }
+
+        let response = self
+            .request_with_headers(Method::GET, path, None, &headers, TimeoutCategory::FastRead)
+            .await?;
+        if response.status() == StatusCode::NOT_MODIFIED &&
+            let Some(entry) = cached
+        {
+            tracing::debug!(path, "cached GET revalidated");
+            let refreshed = middleware::cache::CachedResponse::new(entry.body, entry.etag);
+            cache.save(&key, refreshed.clone()).await;
+            return Ok(refreshed.body);
         }
+
+        let response = Self::handle_error_response(response).await?;
+        let etag = response
+            .headers()
+            .get(hpx::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned);
+        let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+        cache.save(&key, middleware::cache::CachedResponse::new(body.clone(), etag)).await;
+        Ok(body)
     }
 
     // ─── Convenience request methods ───────────────────────────────────
 
     /// Sends a GET request and deserializes the JSON response body.
     pub(crate) async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let response = self.request(Method::GET, path, None).await?;
-        let response = Self::handle_error_response(response).await?;
-        let parsed = response.json::<T>().await.map_err(ElevenLabsError::Transport)?;
-        Ok(parsed)
+        #[cfg(feature = "cache")]
+        if let Some((cache, scope, ttl)) = self.cache_rule(path) {
+            let bytes =
+                self.with_call_options(Box::pin(self.cached_get(cache, scope, path, ttl))).await?;
+            return error::decode_json(&bytes, self.shared.config.capture_raw_bodies);
+        }
+
+        self.with_call_options(async {
+            let response = self
+                .request_with_headers(
+                    Method::GET,
+                    path,
+                    None,
+                    &HeaderMap::new(),
+                    TimeoutCategory::FastRead,
+                )
+                .await?;
+            let response = Self::handle_error_response(response).await?;
+            let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            error::decode_json(&body, self.shared.config.capture_raw_bodies)
+        })
+        .await
     }
 
     /// Sends a GET request and returns the response as raw bytes.
     pub(crate) async fn get_bytes(&self, path: &str) -> Result<Bytes> {
-        let response = self.request(Method::GET, path, None).await?;
-        let response = Self::handle_error_response(response).await?;
-        let bytes = response.bytes().await.map_err(ElevenLabsError::Transport)?;
-        Ok(bytes)
+        #[cfg(feature = "cache")]
+        if let Some((cache, scope, ttl)) = self.cache_rule(path) {
+            return self.with_call_options(Box::pin(self.cached_get(cache, scope, path, ttl))).await;
+        }
+
+        self.with_call_options(async {
+            let response = self.request(Method::GET, path, None).await?;
+            let response = Self::handle_error_response(response).await?;
+            let bytes = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            Ok(bytes)
+        })
+        .await
+    }
+
+    /// Sends a GET request and returns the raw bytes together with the
+    /// response `Content-Type`, if any.
+    pub(crate) async fn get_bytes_with_content_type(
+        &self,
+        path: &str,
+    ) -> Result<(Bytes, Option<String>)> {
+        self.with_call_options(async {
+            let response = self.request(Method::GET, path, None).await?;
+            let response = Self::handle_error_response(response).await?;
+            let content_type = response
+                .headers()
+                .get(hpx::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_owned);
+            let bytes = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            Ok((bytes, content_type))
+        })
+        .await
+    }
+
+    /// Sends a GET request and returns a streaming response of byte chunks.
+    ///
+    /// Like [`post_stream`](Self::post_stream), the scoped timeout and
+    /// cancellation token only cover the initial response.
+    pub(crate) async fn get_stream(
+        &self,
+        path: &str,
+    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<>> {
+        let response = self
+            .with_call_options(async {
+                let response = self.request(Method::GET, path, None).await?;
+                Self::handle_error_response(response).await
+            })
+            .await?;
+        Ok(response.bytes_stream())
     }
 
     /// Sends a POST request with a JSON body and deserializes the JSON
@@ -378,10 +1064,13 @@ impl ElevenLabsClient {
         body: &B,
     ) -> Result<T> {
         let json_value = serde_json::to_value(body)?;
-        let response = self.request(Method::POST, path, Some(json_value)).await?;
-        let response = Self::handle_error_response(response).await?;
-        let parsed = response.json::<T>().await.map_err(ElevenLabsError::Transport)?;
-        Ok(parsed)
+        self.with_call_options(async {
+            let response = self.request(Method::POST, path, Some(json_value)).await?;
+            let response = Self::handle_error_response(response).await?;
+            let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            error::decode_json(&body, self.shared.config.capture_raw_bodies)
+        })
+        .await
     }
 
     /// Sends a POST request with a JSON body and returns raw bytes (for
@@ -392,10 +1081,31 @@ impl ElevenLabsClient {
         body: &B,
     ) -> Result<Bytes> {
         let json_value = serde_json::to_value(body)?;
-        let response = self.request(Method::POST, path, Some(json_value)).await?;
-        let response = Self::handle_error_response(response).await?;
-        let bytes = response.bytes().await.map_err(ElevenLabsError::Transport)?;
-        Ok(bytes)
+        self.with_call_options(async {
+            let response = self.request(Method::POST, path, Some(json_value)).await?;
+            let response = Self::handle_error_response(response).await?;
+            let bytes = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            Ok(bytes)
+        })
+        .await
+    }
+
+    /// Sends a POST request with a JSON body and returns raw bytes together
+    /// with the response headers.
+    pub(crate) async fn post_bytes_with_headers<B: Serialize + Sync>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<(Bytes, HeaderMap)> {
+        let json_value = serde_json::to_value(body)?;
+        self.with_call_options(async {
+            let response = self.request(Method::POST, path, Some(json_value)).await?;
+            let response = Self::handle_error_response(response).await?;
+            let headers = response.headers().clone();
+            let bytes = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            Ok((bytes, headers))
+        })
+        .await
     }
 
     /// Sends a POST request and returns a streaming response of byte chunks.
@@ -403,30 +1113,103 @@ impl ElevenLabsClient {
     /// Stream items contain [`hpx::Error`] rather than [`ElevenLabsError`] to
     /// avoid requiring additional stream-mapping dependencies. Callers should
     /// convert errors at the service layer.
+    ///
+    /// The scoped timeout and cancellation token only cover the initial
+    /// response; the caller drives (and may drop) the returned stream.
+    #[cfg(any(feature = "tts", feature = "studio", feature = "convai", feature = "music"))]
     pub(crate) async fn post_stream<B: Serialize + Sync>(
         &self,
         path: &str,
         body: &B,
     ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<B>> {
         let json_value = serde_json::to_value(body)?;
-        let response = self.request(Method::POST, path, Some(json_value)).await?;
-        let response = Self::handle_error_response(response).await?;
+        let response = self
+            .with_call_options(async {
+                let response = self.request(Method::POST, path, Some(json_value)).await?;
+                Self::handle_error_response(response).await
+            })
+            .await?;
         Ok(response.bytes_stream())
     }
 
+    /// Streams the response of `GET path` to the file at `dest`.
+    ///
+    /// The body is written to `dest` with `.part` appended and renamed to
+    /// `dest` once complete, so `dest` is never left half-written. See
+    /// [`DownloadOptions`] for checksum verification and progress
+    /// reporting. The scoped timeout and cancellation token only cover the
+    /// initial response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the body cannot be written,
+    /// or the checksum does not match.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use elevenlabs_sdk::{ClientConfig, DownloadOptions, ElevenLabsClient};
+    ///
+    /// # async fn example() -> elevenlabs_sdk::Result<()> {
+    /// let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+    /// let options = DownloadOptions::new().on_progress(|p| {
+    ///     eprintln!("{} / {:?} bytes", p.bytes_written, p.total_bytes);
+    /// });
+    /// client.download_to_file("/v1/dubbing/dub_123/audio/es", "dubbed_es.mp4", options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_to_file(
+        &self,
+        path: &str,
+        dest: impl AsRef<Path>,
+        options: DownloadOptions<'_>,
+    ) -> Result<DownloadSummary> {
+        self.download(Method::GET, path, None, dest.as_ref(), &options).await
+    }
+
+    /// Sends a request and streams the response body to `dest`.
+    pub(crate) async fn download(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+        dest: &Path,
+        options: &DownloadOptions<'_>,
+    ) -> Result<DownloadSummary> {
+        let response = self
+            .with_call_options(async {
+                let response = self.request(method, path, body).await?;
+                Self::handle_error_response(response).await
+            })
+            .await?;
+        let total_bytes = response
+            .headers()
+            .get(hpx::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+        download::spool_to_file(response.bytes_stream(), total_bytes, dest, options).await
+    }
+
     /// Sends a DELETE request (expects no response body).
     pub(crate) async fn delete(&self, path: &str) -> Result<()> {
-        let response = self.request(Method::DELETE, path, None).await?;
-        let _response = Self::handle_error_response(response).await?;
-        Ok(())
+        self.with_call_options(async {
+            let response = self.request(Method::DELETE, path, None).await?;
+            let _response = Self::handle_error_response(response).await?;
+            Ok(())
+        })
+        .await
     }
 
     /// Sends a DELETE request and deserializes the JSON response body.
     pub(crate) async fn delete_json<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let response = self.request(Method::DELETE, path, None).await?;
-        let response = Self::handle_error_response(response).await?;
-        let parsed = response.json::<T>().await.map_err(ElevenLabsError::Transport)?;
-        Ok(parsed)
+        self.with_call_options(async {
+            let response = self.request(Method::DELETE, path, None).await?;
+            let response = Self::handle_error_response(response).await?;
+            let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            error::decode_json(&body, self.shared.config.capture_raw_bodies)
+        })
+        .await
     }
 
     /// Sends a DELETE request with a JSON body and deserializes the JSON
@@ -437,10 +1220,81 @@ impl ElevenLabsClient {
         body: &B,
     ) -> Result<T> {
         let json_value = serde_json::to_value(body)?;
-        let response = self.request(Method::DELETE, path, Some(json_value)).await?;
-        let response = Self::handle_error_response(response).await?;
-        let parsed = response.json::<T>().await.map_err(ElevenLabsError::Transport)?;
-        Ok(parsed)
+        self.with_call_options(async {
+            let response = self.request(Method::DELETE, path, Some(json_value)).await?;
+            let response = Self::handle_error_response(response).await?;
+            let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            error::decode_json(&body, self.shared.config.capture_raw_bodies)
+        })
+        .await
+    }
+
+    /// Sends an unauthenticated GET request to `url`, outside the API.
+    ///
+    /// Uses a separate HTTP client without the `xi-api-key` header, so the
+    /// key never reaches third-party hosts. Proxy, TLS and DNS settings
+    /// still apply.
+    pub(crate) async fn fetch_remote(&self, url: &url::Url) -> Result<hpx::Response> {
+        let builder = hpx::Client::builder().timeout(self.shared.config.timeout);
+        let http = apply_network(builder, &self.shared.config.network)?
+            .build()
+            .map_err(ElevenLabsError::Transport)?;
+        let response = http.get(url.as_str()).send().await.map_err(ElevenLabsError::Transport)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ElevenLabsError::Validation(format!(
+                "fetching {url} failed: HTTP {status}"
+            )));
+        }
+        Ok(response)
+    }
+
+    /// Sends a POST request with a raw multipart body and returns the raw
+    /// response after error mapping.
+    async fn send_multipart(
+        &self,
+        path: &str,
+        body: impl Into<UploadBody>,
+        content_type: &str,
+    ) -> Result<hpx::Response> {
+        let url = self.endpoint_url(path)?;
+        #[cfg(feature = "record-replay")]
+        if let Some(replayed) = self.replay(&Method::POST, &url) {
+            return Self::handle_error_response(replayed?).await;
+        }
+        let span = self.request_span(&Method::POST, &url);
+        let started = Instant::now();
+        let UploadBody { body, len } = body.into();
+        let bytes_sent = len.unwrap_or(0);
+        let category =
+            middleware::timeouts::classify(&Method::POST, url.path(), TimeoutCategory::Upload);
+
+        let builder = self
+            .shared
+            .http
+            .post(url.as_str())
+            .timeout(self.attempt_timeout(category))
+            .header(hpx::header::CONTENT_TYPE, content_type)
+            .body(body);
+        let (builder, pooled_key) =
+            self.apply_options(builder, self.options.idempotency_key.as_deref()).await?;
+        let in_flight = self.shared.pool_counters.start();
+        let result =
+            builder.send().instrument(span.clone()).await.map_err(ElevenLabsError::Transport);
+        drop(in_flight);
+        self.shared.pool_counters.finish(result.as_ref().ok().map(hpx::Response::version));
+        if let Ok(response) = &result {
+            self.report_key_status(pooled_key, response.status());
+        }
+        #[cfg(feature = "record-replay")]
+        let result = self.record(&Method::POST, &url, result).await;
+        self.observe(
+            &span,
+            &request_metrics(&Method::POST, &url, started, 0, bytes_sent, result.as_ref()),
+        );
+        #[cfg(feature = "cache")]
+        self.invalidate_cache(&Method::POST, path, &result).await;
+        Self::handle_error_response(result?).await
     }
 
     /// Sends a POST request with a raw body and custom content-type, then
@@ -451,46 +1305,35 @@ impl ElevenLabsClient {
     pub(crate) async fn post_multipart<T: DeserializeOwned>(
         &self,
         path: &str,
-        body: Vec<u8>,
+        body: impl Into<UploadBody>,
         content_type: &str,
     ) -> Result<T> {
-        let url = self.base_url.join(path)?;
-        let response = self
-            .http
-            .post(url.as_str())
-            .header(hpx::header::CONTENT_TYPE, content_type)
-            .body(body)
-            .send()
-            .await
-            .map_err(ElevenLabsError::Transport)?;
-        let response = Self::handle_error_response(response).await?;
-        let parsed = response.json::<T>().await.map_err(ElevenLabsError::Transport)?;
-        Ok(parsed)
+        self.with_call_options(async {
+            let response = self.send_multipart(path, body, content_type).await?;
+            let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            error::decode_json(&body, self.shared.config.capture_raw_bodies)
+        })
+        .await
     }
 
     /// Sends a POST request with a raw multipart body and returns the
     /// response as raw bytes (for audio endpoints).
     ///
     /// Used for speech-to-speech endpoints that accept `multipart/form-data`
-    /// and return audio bytes rather than JSON.
+    /// and return audio bytes rather than JSON. The body may be a buffer or a
+    /// streaming [`UploadBody`].
     pub(crate) async fn post_multipart_bytes(
         &self,
         path: &str,
-        body: Vec<u8>,
+        body: impl Into<UploadBody>,
         content_type: &str,
     ) -> Result<Bytes> {
-        let url = self.base_url.join(path)?;
-        let response = self
-            .http
-            .post(url.as_str())
-            .header(hpx::header::CONTENT_TYPE, content_type)
-            .body(body)
-            .send()
-            .await
-            .map_err(ElevenLabsError::Transport)?;
-        let response = Self::handle_error_response(response).await?;
-        let bytes = response.bytes().await.map_err(ElevenLabsError::Transport)?;
-        Ok(bytes)
+        self.with_call_options(async {
+            let response = self.send_multipart(path, body, content_type).await?;
+            let bytes = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            Ok(bytes)
+        })
+        .await
     }
 
     /// Sends a POST request with a raw multipart body and returns a streaming
@@ -498,22 +1341,14 @@ impl ElevenLabsClient {
     ///
     /// Used for speech-to-speech streaming endpoints that accept
     /// `multipart/form-data` and return chunked audio.
-    pub(crate) async fn post_multipart_stream(
+    pub(crate) async fn post_multipart_stream<UB: Into<UploadBody>>(
         &self,
         path: &str,
-        body: Vec<u8>,
+        body: UB,
         content_type: &str,
-    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<'_>> {
-        let url = self.base_url.join(path)?;
-        let response = self
-            .http
-            .post(url.as_str())
-            .header(hpx::header::CONTENT_TYPE, content_type)
-            .body(body)
-            .send()
-            .await
-            .map_err(ElevenLabsError::Transport)?;
-        let response = Self::handle_error_response(response).await?;
+    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<UB>> {
+        let response =
+            self.with_call_options(self.send_multipart(path, body, content_type)).await?;
         Ok(response.bytes_stream())
     }
 
@@ -525,33 +1360,184 @@ impl ElevenLabsClient {
         body: &B,
     ) -> Result<T> {
         let json_value = serde_json::to_value(body)?;
-        let response = self.request(Method::PATCH, path, Some(json_value)).await?;
-        let response = Self::handle_error_response(response).await?;
-        let parsed = response.json::<T>().await.map_err(ElevenLabsError::Transport)?;
-        Ok(parsed)
+        self.with_call_options(async {
+            let response = self.request(Method::PATCH, path, Some(json_value)).await?;
+            let response = Self::handle_error_response(response).await?;
+            let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            error::decode_json(&body, self.shared.config.capture_raw_bodies)
+        })
+        .await
     }
 
     /// Sends a PUT request with a JSON body and deserializes the JSON
     /// response.
+    #[cfg(feature = "convai")]
     pub(crate) async fn put<T: DeserializeOwned, B: Serialize + Sync>(
         &self,
         path: &str,
         body: &B,
     ) -> Result<T> {
         let json_value = serde_json::to_value(body)?;
-        let response = self.request(Method::PUT, path, Some(json_value)).await?;
-        let response = Self::handle_error_response(response).await?;
-        let parsed = response.json::<T>().await.map_err(ElevenLabsError::Transport)?;
-        Ok(parsed)
+        self.with_call_options(async {
+            let response = self.request(Method::PUT, path, Some(json_value)).await?;
+            let response = Self::handle_error_response(response).await?;
+            let body = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+            error::decode_json(&body, self.shared.config.capture_raw_bodies)
+        })
+        .await
+    }
+}
+
+/// Builds the [`RequestMetrics`] for a call that started at `started`.
+fn request_metrics(
+    method: &Method,
+    url: &url::Url,
+    started: Instant,
+    retries: u32,
+    bytes_sent: u64,
+    result: std::result::Result<&hpx::Response, &ElevenLabsError>,
+) -> RequestMetrics {
+    let response = result.ok();
+    RequestMetrics {
+        method: method.to_string(),
+        endpoint: url.path().to_owned(),
+        status: response.map(|r| r.status().as_u16()),
+        retries,
+        latency: started.elapsed(),
+        bytes_sent,
+        bytes_received: response
+            .and_then(|r| r.headers().get(hpx::header::CONTENT_LENGTH))
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok()),
+        request_id: response.and_then(request_id).map(str::to_owned),
+    }
+}
+
+/// Returns the `request-id` header of `response`, if present.
+fn request_id(response: &hpx::Response) -> Option<&str> {
+    response.headers().get(REQUEST_ID_HEADER).and_then(|v| v.to_str().ok())
+}
+
+/// Returns the process-level rustls crypto provider, installing `ring` as
+/// the default first if none is set.
+///
+/// hpx builds its TLS config with [`rustls::ClientConfig::builder`], which
+/// panics when the dependency graph enables more than one provider and none
+/// has been installed, as happens once `aws-lc-rs` comes in alongside `ring`.
+pub(crate) fn crypto_provider() -> Arc<rustls::crypto::CryptoProvider> {
+    if let Some(provider) = rustls::crypto::CryptoProvider::get_default() {
+        return Arc::clone(provider);
+    }
+    // Another thread may install a provider first; whichever won is used.
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    rustls::crypto::CryptoProvider::get_default()
+        .cloned()
+        .unwrap_or_else(|| Arc::new(rustls::crypto::ring::default_provider()))
+}
+
+/// Applies the proxy, TLS and DNS settings from `network` to `builder`.
+fn apply_network(
+    mut builder: hpx::ClientBuilder,
+    network: &NetworkConfig,
+) -> Result<hpx::ClientBuilder> {
+    if let Some(proxy) = &network.proxy {
+        builder = builder.proxy(hpx::Proxy::all(proxy).map_err(ElevenLabsError::Transport)?);
+    }
+    if !network.root_certificates.is_empty() {
+        let store = network
+            .root_certificates
+            .iter()
+            .fold(hpx::tls::CertStore::builder().set_default_paths(), |store, pem| {
+                store.add_pem_cert(pem)
+            })
+            .build()
+            .map_err(ElevenLabsError::Transport)?;
+        builder = builder.cert_store(store);
+    }
+    for (host, addr) in &network.resolve_overrides {
+        builder = builder.resolve(host.clone(), *addr);
+    }
+    Ok(builder.cert_verification(!network.danger_accept_invalid_certs))
+}
+
+/// Applies the connection pool and HTTP version settings from `pool` to
+/// `builder`.
+fn apply_pool(mut builder: hpx::ClientBuilder, pool: &PoolConfig) -> hpx::ClientBuilder {
+    if let Some(max) = pool.max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max);
+    }
+    if let Some(timeout) = pool.idle_timeout {
+        builder = builder.pool_idle_timeout(timeout);
+    }
+    match pool.http_version {
+        HttpVersionPreference::Auto => builder,
+        HttpVersionPreference::Http1 => builder.http1_only(),
+        HttpVersionPreference::Http2 => builder.http2_only(),
+    }
+}
+
+/// Gzips `json` if it is at least `min_bytes` long, returning the body to
+/// send and whether it was compressed.
+fn compress_body(json: Vec<u8>, min_bytes: Option<usize>) -> Result<(Vec<u8>, bool)> {
+    if min_bytes.is_none_or(|min| json.len() < min) {
+        return Ok((json, false));
+    }
+    let mut encoder = GzEncoder::new(Vec::with_capacity(json.len() / 4), Compression::default());
+    encoder.write_all(&json)?;
+    Ok((encoder.finish()?, true))
+}
+
+/// Converts `token` into a sensitive `Authorization: Bearer` header value.
+fn bearer_header(token: &ApiKey) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {}", token.as_str()))
+        .map_err(|e| ElevenLabsError::Validation(format!("invalid bearer token: {e}")))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Returns the path and query of `url`, as stored in a cassette.
+#[cfg(feature = "record-replay")]
+fn cassette_path(url: &url::Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_owned(),
     }
 }
 
+/// Builds a response that was recorded or replayed rather than received.
+#[cfg(feature = "record-replay")]
+fn build_response(status: StatusCode, headers: HeaderMap, body: Bytes) -> hpx::Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    hpx::Response::from(response)
+}
+
+/// Parses a header from [`ClientConfig::default_headers`].
+fn default_header(name: &str, value: &str) -> Result<(HeaderName, HeaderValue)> {
+    let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+        ElevenLabsError::Validation(format!("invalid default header name {name:?}: {e}"))
+    })?;
+    let header_value = HeaderValue::from_str(value).map_err(|e| {
+        ElevenLabsError::Validation(format!("invalid value for default header {name}: {e}"))
+    })?;
+    Ok((header_name, header_value))
+}
+
+/// Converts `key` into a sensitive `xi-api-key` header value.
+fn api_key_header(key: &ApiKey) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(key.as_str())
+        .map_err(|e| ElevenLabsError::Validation(format!("invalid API key header value: {e}")))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
 #[cfg(test)]
-#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+#[expect(clippy::unwrap_used, clippy::panic, reason = "tests use unwrap and panic")]
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path},
+        matchers::{header, header_exists, method, path},
     };
 
     use super::*;
@@ -585,6 +1571,39 @@ mod tests {
         assert_eq!(result, TestResponse { message: "success".to_owned(), count: 42 });
     }
 
+    #[tokio::test]
+    async fn sends_user_agent_and_default_headers() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .and(header("user-agent", format!("{} docs-site/3.0", crate::config::SDK_USER_AGENT)))
+            .and(header("xi-source", "docs-site"))
+            .and(header("baggage", "team=voice"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"message": "ok", "count": 1})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .user_agent("docs-site/3.0")
+            .default_headers([("xi-source", "docs-site"), ("baggage", "team=voice")])
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let _: TestResponse = client.get("/v1/voices").await.unwrap();
+    }
+
+    #[test]
+    fn rejects_invalid_default_headers() {
+        let config =
+            ClientConfig::builder("test-key").default_headers([("bad header", "x")]).build();
+        assert!(matches!(ElevenLabsClient::new(config), Err(ElevenLabsError::Validation(_))));
+    }
+
     #[tokio::test]
     async fn get_handles_401_unauthorized() {
         let mock_server = MockServer::start().await;
@@ -605,8 +1624,8 @@ mod tests {
         let result: Result<TestResponse> = client.get("/v1/voices").await;
 
         match result {
-            Err(ElevenLabsError::Auth(msg)) => {
-                assert_eq!(msg, "Invalid API key");
+            Err(ElevenLabsError::Auth { message, .. }) => {
+                assert_eq!(message, "Invalid API key");
             }
             other => panic!("expected Auth error, got {other:?}"),
         }
@@ -629,7 +1648,7 @@ mod tests {
         let result: Result<TestResponse> = client.get("/v1/voices").await;
 
         match result {
-            Err(ElevenLabsError::RateLimited { retry_after }) => {
+            Err(ElevenLabsError::RateLimited { retry_after, .. }) => {
                 assert_eq!(retry_after, Some(30));
             }
             other => panic!("expected RateLimited error, got {other:?}"),
@@ -655,7 +1674,7 @@ mod tests {
         let result: Result<TestResponse> = client.get("/v1/voices").await;
 
         match result {
-            Err(ElevenLabsError::Api { status, message, body }) => {
+            Err(ElevenLabsError::Api { status, message, body, .. }) => {
                 assert_eq!(status, 500);
                 assert_eq!(message, "Internal server error");
                 assert!(body.is_some());
@@ -683,6 +1702,64 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn pool_stats_count_attempts_shared_with_scoped_clients() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"message": "ok", "count": 1})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let scoped = client.with_options(RequestOptions::new().idempotency_key("k"));
+        let _: TestResponse = client.get("/v1/test").await.unwrap();
+        let _: TestResponse = scoped.get("/v1/test").await.unwrap();
+
+        let stats = client.pool_stats();
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.in_flight, 0);
+        assert_eq!(stats.peak_in_flight, 1);
+        assert_eq!(stats.http1_responses, 2);
+        assert_eq!(stats.transport_errors, 0);
+    }
+
+    #[tokio::test]
+    async fn clones_share_state_across_spawned_tasks() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<ElevenLabsClient>();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"message": "ok", "count": 1})),
+            )
+            .expect(4)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        assert!(Arc::ptr_eq(&client.shared, &client.clone().shared));
+
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.get::<TestResponse>("/v1/test").await })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap().count, 1);
+        }
+        assert_eq!(client.pool_stats().requests, 4);
+    }
+
     #[tokio::test]
     async fn retry_on_429_then_succeeds() {
         use std::time::Duration;
@@ -843,4 +1920,858 @@ mod tests {
 
         assert_eq!(result, TestResponse { message: "created".to_owned(), count: 1 });
     }
+
+    #[tokio::test]
+    async fn large_json_bodies_are_gzipped_when_enabled() {
+        use std::io::Read as _;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/agents/create"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "created",
+                "count": 1
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .compress_requests_above(1024)
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let small = serde_json::json!({"name": "agent"});
+        let large = serde_json::json!({"prompt": "You are a helpful agent. ".repeat(200)});
+        let _: TestResponse = client.post("/v1/convai/agents/create", &small).await.unwrap();
+        let _: TestResponse = client.post("/v1/convai/agents/create", &large).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key("content-encoding"));
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&requests[0].body).unwrap(), small);
+        assert_eq!(requests[1].headers["content-encoding"], "gzip");
+        assert!(requests[1].body.len() < 1024);
+        let mut json = String::new();
+        flate2::read::GzDecoder::new(requests[1].body.as_slice())
+            .read_to_string(&mut json)
+            .unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap(), large);
+    }
+
+    #[tokio::test]
+    async fn with_options_sends_idempotency_key() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .and(header("idempotency-key", "job-42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "ok",
+                "count": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let scoped = client.with_options(RequestOptions::new().idempotency_key("job-42"));
+
+        let result: TestResponse = scoped.get("/v1/test").await.unwrap();
+        assert_eq!(result.message, "ok");
+    }
+
+    #[tokio::test]
+    async fn post_is_not_retried_after_server_error_unless_opted_in() {
+        use std::time::Duration;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/test"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "ok",
+                "count": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .retry_backoff(Duration::from_millis(1))
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let body = serde_json::json!({"text": "hi"});
+
+        let result: Result<TestResponse> = client.post("/v1/test", &body).await;
+        assert!(matches!(result, Err(ElevenLabsError::Api { status: 500, .. })));
+
+        let scoped = client.with_options(RequestOptions::new().retry_unsafe());
+        let result: TestResponse = scoped.post("/v1/test", &body).await.unwrap();
+        assert_eq!(result.message, "ok");
+    }
+
+    #[tokio::test]
+    async fn retry_unsafe_resends_generated_idempotency_key_on_retry() {
+        use std::time::Duration;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/test"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "ok",
+                "count": 1
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .retry_backoff(Duration::from_millis(1))
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let scoped = client.with_options(RequestOptions::new().retry_unsafe());
+        let body = serde_json::json!({"text": "hi"});
+
+        let _: TestResponse = scoped.post("/v1/test", &body).await.unwrap();
+        let _: TestResponse = scoped.post("/v1/test", &body).await.unwrap();
+
+        let keys: Vec<String> = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| r.headers.get("idempotency-key").unwrap().to_str().unwrap().to_owned())
+            .collect();
+        assert_eq!(keys.len(), 3);
+        assert_eq!(keys[0], keys[1], "a retry must re-send the same key");
+        assert_ne!(keys[1], keys[2], "each call gets its own key");
+    }
+
+    #[cfg(feature = "convai")]
+    #[tokio::test]
+    async fn keyless_batch_call_submission_is_not_retried_after_server_error() {
+        use std::time::Duration;
+
+        use crate::types::{BatchCallRecipient, SubmitBatchCallRequest};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/batch-calling/submit"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .retry_backoff(Duration::from_millis(1))
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let request = SubmitBatchCallRequest::new(
+            "spring",
+            "agent-1",
+            [BatchCallRecipient::new("+14155550100")],
+        );
+
+        let result = client.agents().submit_batch_call(&request).await;
+        assert!(matches!(result, Err(ElevenLabsError::Api { status: 503, .. })));
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests[0].headers.get("idempotency-key").is_none());
+    }
+
+    #[tokio::test]
+    async fn with_options_timeout_returns_timeout_error() {
+        use std::time::Duration;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/slow"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"message": "late", "count": 0}))
+                    .set_delay(Duration::from_secs(5)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config =
+            ClientConfig::builder("test-key").base_url(mock_server.uri()).max_retries(0).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let scoped = client.with_options(RequestOptions::new().timeout(Duration::from_millis(50)));
+
+        let result: Result<TestResponse> = scoped.get("/v1/slow").await;
+        assert!(matches!(result, Err(ElevenLabsError::Timeout)));
+    }
+
+    #[tokio::test]
+    async fn timeout_profile_applies_per_category_and_per_call() {
+        use std::time::Duration;
+
+        use crate::config::TimeoutProfile;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/slow"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"message": "late", "count": 0}))
+                    .set_delay(Duration::from_millis(300)),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/slow"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"message": "late", "count": 0}))
+                    .set_delay(Duration::from_millis(300)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(0)
+            .timeout_profile(TimeoutProfile::new().fast_read(Duration::from_millis(50)))
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        // JSON GETs are fast reads; other requests keep the 30 s default.
+        let result: Result<TestResponse> = client.get("/v1/slow").await;
+        assert!(matches!(result, Err(ElevenLabsError::Timeout)));
+        let result: Result<TestResponse> = client.post("/v1/slow", &serde_json::json!({})).await;
+        assert_eq!(result.unwrap().message, "late");
+
+        // A per-call attempt timeout replaces the profile.
+        let scoped =
+            client.with_options(RequestOptions::new().attempt_timeout(Duration::from_secs(5)));
+        let result: Result<TestResponse> = scoped.get("/v1/slow").await;
+        assert_eq!(result.unwrap().message, "late");
+    }
+
+    #[tokio::test]
+    async fn region_rejects_unsupported_endpoints_locally() {
+        let config = ClientConfig::builder("test-key").region(crate::Region::EuResidency).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let err =
+            client.get::<serde_json::Value>("/v1/shared-voices?page_size=5").await.unwrap_err();
+
+        assert!(
+            matches!(err, ElevenLabsError::Validation(ref msg) if msg.contains("eu-residency"))
+        );
+    }
+
+    #[tokio::test]
+    async fn deadline_skips_retries_that_cannot_finish_in_time() {
+        use std::time::Duration;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .retry_backoff(Duration::from_secs(10))
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let scoped = client.with_options(
+            RequestOptions::new().deadline(tokio::time::Instant::now() + Duration::from_mins(1)),
+        );
+
+        let started = Instant::now();
+        let within = tokio::time::Instant::now() + Duration::from_secs(5);
+        let result: Result<TestResponse> = deadline::scope(within, scoped.get("/v1/test")).await;
+        assert!(matches!(result, Err(ElevenLabsError::Api { status: 503, .. })));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn with_options_cancelled_token_aborts_request() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "ok",
+                "count": 1
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let token = tokio_util::sync::CancellationToken::new();
+        token.cancel();
+        let scoped = client.with_options(RequestOptions::new().cancellation_token(token));
+
+        let result: Result<TestResponse> = scoped.get("/v1/test").await;
+        assert!(matches!(result, Err(ElevenLabsError::Cancelled)));
+    }
+
+    #[tokio::test]
+    async fn with_api_key_overrides_configured_key() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .and(header("xi-api-key", "tenant-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "tenant",
+                "count": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .and(header("xi-api-key", "service-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "service",
+                "count": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("service-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let scoped = client.with_api_key(&ApiKey::from("tenant-key"));
+
+        let result: TestResponse = scoped.get("/v1/test").await.unwrap();
+        assert_eq!(result.message, "tenant");
+        let result: TestResponse = client.get("/v1/test").await.unwrap();
+        assert_eq!(result.message, "service");
+    }
+
+    #[tokio::test]
+    async fn bearer_auth_replaces_api_key_header() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(header_exists("xi-api-key"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .and(header("authorization", "Bearer session-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "bearer",
+                "count": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("unused")
+            .base_url(mock_server.uri())
+            .auth(AuthStrategy::Bearer(ApiKey::from("session-token")))
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let result: TestResponse = client.get("/v1/test").await.unwrap();
+        assert_eq!(result.message, "bearer");
+    }
+
+    #[tokio::test]
+    async fn single_use_token_is_fetched_per_request() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        let mock_server = MockServer::start().await;
+
+        for n in 0..2 {
+            Mock::given(method("GET"))
+                .and(path("/v1/test"))
+                .and(header("authorization", format!("Bearer sut-{n}").as_str()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "message": "token",
+                    "count": n
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let provider = crate::auth::TokenProvider::new(move || {
+            let n = counter.fetch_add(1, Ordering::Relaxed);
+            async move { Ok(format!("sut-{n}")) }
+        });
+        let config = ClientConfig::builder("unused")
+            .base_url(mock_server.uri())
+            .auth(AuthStrategy::SingleUseToken(provider))
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let first: TestResponse = client.get("/v1/test").await.unwrap();
+        let second: TestResponse = client.get("/v1/test").await.unwrap();
+        assert_eq!((first.count, second.count), (0, 1));
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn key_pool_round_robin_spreads_requests() {
+        let mock_server = MockServer::start().await;
+
+        for key in ["key-a", "key-b"] {
+            Mock::given(method("GET"))
+                .and(path("/v1/test"))
+                .and(header("xi-api-key", key))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "message": key,
+                    "count": 1
+                })))
+                .expect(2)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let config = ClientConfig::builder("unused-key").base_url(mock_server.uri()).build();
+        let pool = KeyPool::new(["key-a", "key-b"]).unwrap();
+        let client = ElevenLabsClient::new(config).unwrap().with_key_pool(pool);
+
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            let result: TestResponse = client.get("/v1/test").await.unwrap();
+            seen.push(result.message);
+        }
+        assert_eq!(seen, ["key-a", "key-b", "key-a", "key-b"]);
+    }
+
+    #[tokio::test]
+    async fn key_pool_failover_retries_with_next_key() {
+        use std::time::Duration;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .and(header("xi-api-key", "key-a"))
+            .respond_with(ResponseTemplate::new(429))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .and(header("xi-api-key", "key-b"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "ok",
+                "count": 1
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("unused-key")
+            .base_url(mock_server.uri())
+            .retry_backoff(Duration::from_millis(1))
+            .build();
+        let pool = KeyPool::new(["key-a", "key-b"]).unwrap().rotation(crate::KeyRotation::Failover);
+        let client = ElevenLabsClient::new(config).unwrap().with_key_pool(pool);
+
+        let result: TestResponse = client.get("/v1/test").await.unwrap();
+        assert_eq!(result.message, "ok");
+        // The pool stays on the working key for later calls.
+        let result: TestResponse = client.get("/v1/test").await.unwrap();
+        assert_eq!(result.message, "ok");
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn cache_serves_fresh_get_without_request() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "cached",
+                "count": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap().with_cache(
+            crate::CacheConfig::new(crate::MemoryCacheStore::new()).with_default_rules(),
+        );
+
+        let first: TestResponse = client.get("/v1/models").await.unwrap();
+        let second: TestResponse = client.get("/v1/models").await.unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn cache_revalidates_stale_entry_with_etag() {
+        use std::time::Duration;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_json(serde_json::json!({"message": "voices", "count": 2})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let cache = crate::CacheConfig::new(crate::MemoryCacheStore::new())
+            .ttl("/v1/voices", Duration::ZERO);
+        let client = ElevenLabsClient::new(config).unwrap().with_cache(cache);
+
+        let first: TestResponse = client.get("/v1/voices").await.unwrap();
+        let second: TestResponse = client.get("/v1/voices").await.unwrap();
+        assert_eq!(second, first);
+        assert_eq!(second.count, 2);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn cache_drops_entries_after_successful_write() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "voices",
+                "count": 1
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/abc/edit"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "edited",
+                "count": 0
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap().with_cache(
+            crate::CacheConfig::new(crate::MemoryCacheStore::new()).with_default_rules(),
+        );
+
+        let _: TestResponse = client.get("/v1/voices").await.unwrap();
+        let _: TestResponse = client.get("/v1/voices").await.unwrap();
+        let _: TestResponse =
+            client.post("/v1/voices/abc/edit", &serde_json::json!({})).await.unwrap();
+        let _: TestResponse = client.get("/v1/voices").await.unwrap();
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn cache_keys_entries_by_bearer_token() {
+        let mock_server = MockServer::start().await;
+
+        for user in ["alice", "bob"] {
+            Mock::given(method("GET"))
+                .and(path("/v1/voices"))
+                .and(header("authorization", format!("Bearer {user}").as_str()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "message": user,
+                    "count": 1
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let cache = crate::CacheConfig::new(crate::MemoryCacheStore::new()).with_default_rules();
+        let client_for = |token: &str| {
+            let config = ClientConfig::builder("")
+                .base_url(mock_server.uri())
+                .auth(AuthStrategy::Bearer(ApiKey::from(token)))
+                .build();
+            ElevenLabsClient::new(config).unwrap().with_cache(cache.clone())
+        };
+        let (alice, bob) = (client_for("alice"), client_for("bob"));
+
+        for _ in 0..2 {
+            let result: TestResponse = alice.get("/v1/voices").await.unwrap();
+            assert_eq!(result.message, "alice");
+            let result: TestResponse = bob.get("/v1/voices").await.unwrap();
+            assert_eq!(result.message, "bob");
+        }
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn cache_is_bypassed_with_single_use_tokens() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "voices",
+                "count": 1
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let provider = crate::auth::TokenProvider::new(|| async { Ok(String::from("single-use")) });
+        let config = ClientConfig::builder("")
+            .base_url(mock_server.uri())
+            .auth(AuthStrategy::SingleUseToken(provider))
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap().with_cache(
+            crate::CacheConfig::new(crate::MemoryCacheStore::new()).with_default_rules(),
+        );
+
+        let _: TestResponse = client.get("/v1/voices").await.unwrap();
+        let _: TestResponse = client.get("/v1/voices").await.unwrap();
+    }
+
+    #[cfg(feature = "record-replay")]
+    #[tokio::test]
+    async fn cassette_records_sanitized_responses_and_replays_them() {
+        use std::sync::Arc;
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("request-id", "req_1")
+                    .set_body_json(serde_json::json!({"message": "sk-secret", "count": 3})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "detail": {"status": "not_found", "message": "gone"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fixture = std::env::temp_dir()
+            .join(format!("elevenlabs-replay-{}", std::process::id()))
+            .join("client.json");
+        let cassette = Arc::new(crate::Cassette::record(&fixture));
+        let config = ClientConfig::builder("sk-secret").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap().with_cassette(Arc::clone(&cassette));
+        let recorded: TestResponse = client.get("/v1/test").await.unwrap();
+        assert_eq!(recorded.message, "sk-secret");
+        assert!(client.get::<TestResponse>("/v1/missing").await.is_err());
+        cassette.save().unwrap();
+        assert!(!std::fs::read_to_string(&fixture).unwrap().contains("sk-secret"));
+
+        // Nothing listens on the replay client's base URL.
+        let cassette = Arc::new(crate::Cassette::replay(&fixture).unwrap());
+        let config = ClientConfig::builder("test-key").base_url("http://127.0.0.1:9").build();
+        let client = ElevenLabsClient::new(config).unwrap().with_cassette(Arc::clone(&cassette));
+        let replayed: TestResponse = client.get("/v1/test").await.unwrap();
+        assert_eq!(replayed, TestResponse { message: "REDACTED".into(), count: 3 });
+        assert!(matches!(
+            client.get::<TestResponse>("/v1/missing").await,
+            Err(ElevenLabsError::Api { status: 404, .. })
+        ));
+        assert!(cassette.is_exhausted());
+        assert!(matches!(
+            client.get::<TestResponse>("/v1/test").await,
+            Err(ElevenLabsError::Validation(_))
+        ));
+
+        std::fs::remove_dir_all(fixture.parent().unwrap()).unwrap();
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingSink(std::sync::Mutex<Vec<RequestMetrics>>);
+
+    impl crate::MetricsSink for RecordingSink {
+        fn record_request(&self, metrics: &RequestMetrics) {
+            self.0.lock().unwrap().push(metrics.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn metrics_sink_records_retries_status_and_bytes() {
+        use std::{sync::Arc, time::Duration};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/test"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/test"))
+            .and(header("content-type", "application/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "ok",
+                "count": 1
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let sink = Arc::new(RecordingSink::default());
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .retry_backoff(Duration::from_millis(1))
+            .metrics_sink(sink.clone())
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let body = serde_json::json!({"text": "hi"});
+        let _: TestResponse = client.post("/v1/test?page=2", &body).await.unwrap();
+
+        let recorded = sink.0.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        let metrics = &recorded[0];
+        assert_eq!(metrics.method, "POST");
+        assert_eq!(metrics.endpoint, "/v1/test");
+        assert_eq!(metrics.status, Some(200));
+        assert_eq!(metrics.retries, 1);
+        assert_eq!(metrics.bytes_sent, serde_json::to_vec(&body).unwrap().len() as u64);
+        assert_eq!(metrics.bytes_received, Some(r#"{"count":1,"message":"ok"}"#.len() as u64));
+        assert!(metrics.is_success());
+    }
+
+    #[tokio::test]
+    async fn metrics_sink_records_failed_requests_with_spans_disabled() {
+        use std::sync::Arc;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let sink = Arc::new(RecordingSink::default());
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .tracing_spans(false)
+            .metrics_sink(sink.clone())
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let result: Result<TestResponse> = client.get("/v1/missing").await;
+        assert!(result.is_err());
+
+        let recorded = sink.0.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].status, Some(404));
+        assert_eq!(recorded[0].retries, 0);
+        assert_eq!(recorded[0].bytes_sent, 0);
+        assert!(!recorded[0].is_success());
+    }
+
+    #[tokio::test]
+    async fn errors_and_metrics_carry_request_id() {
+        use std::sync::Arc;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(
+                ResponseTemplate::new(404).insert_header("request-id", "req_abc123").set_body_json(
+                    serde_json::json!({
+                        "detail": {"status": "voice_not_found", "message": "No such voice"}
+                    }),
+                ),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let sink = Arc::new(RecordingSink::default());
+        let config = ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .metrics_sink(sink.clone())
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let err = client.get::<TestResponse>("/v1/voices").await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::VoiceNotFound { .. }));
+        assert_eq!(err.request_id(), Some("req_abc123"));
+        assert_eq!(sink.0.lock().unwrap()[0].request_id.as_deref(), Some("req_abc123"));
+    }
+
+    #[tokio::test]
+    async fn schema_drift_reports_path_and_captured_body() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"message": "hi", "count": "many"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        for capture in [false, true] {
+            let config = ClientConfig::builder("test-key")
+                .base_url(mock_server.uri())
+                .capture_raw_bodies(capture)
+                .build();
+            let client = ElevenLabsClient::new(config).unwrap();
+
+            let err = client.get::<TestResponse>("/v1/test").await.unwrap_err();
+            let ElevenLabsError::DeserializationFailed { path_hint, raw_body_snippet, .. } = err
+            else {
+                panic!("expected DeserializationFailed, got {err:?}");
+            };
+            assert_eq!(path_hint, "count");
+            assert_eq!(raw_body_snippet.is_some_and(|s| s.contains(r#""count":"many""#)), capture);
+        }
+    }
 }
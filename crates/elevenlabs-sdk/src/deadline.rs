@@ -0,0 +1,183 @@
+//! Deadlines propagated from the caller's context.
+//!
+//! A service with a latency budget can hand its remaining time to the SDK
+//! instead of configuring a timeout on every call. Inside
+//! [`scope`], every call made through an
+//! [`ElevenLabsClient`](crate::ElevenLabsClient) ends with
+//! [`ElevenLabsError::Timeout`] once the deadline passes, a retry whose
+//! backoff would end after the deadline is not started, and WebSocket
+//! connects and reconnects give up at the deadline.
+//!
+//! A deadline can also be set on a scoped client with
+//! [`RequestOptions::deadline`](crate::RequestOptions::deadline). When
+//! several apply, the earliest wins.
+//!
+//! With the `tower` feature, [`DeadlineLayer`] opens a scope for every
+//! request handled by a tower service, such as an axum router.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, deadline};
+//! use tokio::time::Instant;
+//!
+//! # async fn example() -> elevenlabs_sdk::Result<()> {
+//! let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+//!
+//! let budget = Instant::now() + Duration::from_millis(800);
+//! let models = deadline::scope(budget, client.models().list()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{future::Future, time::Duration};
+
+use tokio::time::Instant;
+
+use crate::error::{ElevenLabsError, Result};
+
+tokio::task_local! {
+    static DEADLINE: Instant;
+}
+
+/// Runs `fut` with `deadline` as the caller's deadline.
+///
+/// Inside an outer scope with an earlier deadline, the outer deadline is
+/// kept.
+pub async fn scope<F: Future>(deadline: Instant, fut: F) -> F::Output {
+    DEADLINE.scope(earliest(current(), Some(deadline)).unwrap_or(deadline), fut).await
+}
+
+/// Returns the deadline of the enclosing [`scope`], if any.
+pub fn current() -> Option<Instant> {
+    DEADLINE.try_with(|deadline| *deadline).ok()
+}
+
+/// Returns the earlier of two optional deadlines.
+pub(crate) fn earliest(a: Option<Instant>, b: Option<Instant>) -> Option<Instant> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Returns `true` if waiting `delay` from now still ends before `deadline`.
+pub(crate) fn leaves_time(deadline: Option<Instant>, delay: Duration) -> bool {
+    deadline.is_none_or(|deadline| Instant::now() + delay < deadline)
+}
+
+/// Runs `fut`, failing with [`ElevenLabsError::Timeout`] if `deadline`
+/// passes first.
+pub(crate) async fn within<T>(
+    deadline: Option<Instant>,
+    fut: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    match deadline {
+        Some(deadline) => {
+            tokio::time::timeout_at(deadline, fut).await.map_err(|_| ElevenLabsError::Timeout)?
+        }
+        None => fut.await,
+    }
+}
+
+/// Tower layer that gives every request a deadline `budget` after it
+/// arrives, visible to SDK calls made while handling it.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use elevenlabs_sdk::deadline::DeadlineLayer;
+/// use tower::ServiceBuilder;
+///
+/// let middleware = ServiceBuilder::new().layer(DeadlineLayer::new(Duration::from_secs(2)));
+/// ```
+#[cfg(feature = "tower")]
+#[derive(Debug, Clone, Copy)]
+pub struct DeadlineLayer {
+    budget: Duration,
+}
+
+#[cfg(feature = "tower")]
+impl DeadlineLayer {
+    /// Creates a layer giving each request `budget` to complete.
+    pub const fn new(budget: Duration) -> Self {
+        Self { budget }
+    }
+}
+
+#[cfg(feature = "tower")]
+impl<S> tower::Layer<S> for DeadlineLayer {
+    type Service = Deadline<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Deadline { inner, budget: self.budget }
+    }
+}
+
+/// Service created by [`DeadlineLayer`].
+#[cfg(feature = "tower")]
+#[derive(Debug, Clone)]
+pub struct Deadline<S> {
+    inner: S,
+    budget: Duration,
+}
+
+#[cfg(feature = "tower")]
+impl<S, R> tower::Service<R> for Deadline<S>
+where
+    S: tower::Service<R>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = tokio::task::futures::TaskLocalFuture<Instant, S::Future>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::result::Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        let deadline = Instant::now() + self.budget;
+        let deadline = earliest(current(), Some(deadline)).unwrap_or(deadline);
+        DEADLINE.scope(deadline, self.inner.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn nested_scopes_keep_the_earliest_deadline() {
+        assert_eq!(current(), None);
+        let now = Instant::now();
+        let near = now + Duration::from_secs(1);
+        let far = now + Duration::from_secs(5);
+
+        let inner = scope(near, async { scope(far, async { current() }).await }).await;
+        assert_eq!(inner, Some(near));
+        let inner = scope(far, async { scope(near, async { current() }).await }).await;
+        assert_eq!(inner, Some(near));
+        assert_eq!(current(), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn within_times_out_at_the_deadline() {
+        assert!(leaves_time(None, Duration::from_mins(1)));
+        let deadline = Some(Instant::now() + Duration::from_secs(1));
+        assert!(leaves_time(deadline, Duration::from_millis(500)));
+        assert!(!leaves_time(deadline, Duration::from_secs(1)));
+
+        let result = within(deadline, async {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+            Ok(())
+        })
+        .await;
+        assert!(matches!(result, Err(ElevenLabsError::Timeout)));
+        assert!(matches!(within(deadline, async { Ok(1) }).await, Ok(1)));
+    }
+}
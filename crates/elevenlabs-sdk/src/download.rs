@@ -0,0 +1,272 @@
+//! Streaming downloads to disk.
+//!
+//! Large responses such as dubbed videos, history archives and Studio
+//! snapshot archives are written to a `.part` file next to the destination
+//! as they arrive, so they never have to fit in memory. Once the body is
+//! complete (and its checksum matches, if one was given) the file is renamed
+//! into place, so the destination either holds the whole file or is left
+//! untouched.
+//!
+//! Use [`ElevenLabsClient::download_to_file`](crate::ElevenLabsClient::download_to_file)
+//! or the `*_to_file` methods on the dubbing, history and Studio services.
+
+use std::{
+    fmt::{self, Write as _},
+    path::{Path, PathBuf},
+};
+
+use bytes::Bytes;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncWriteExt;
+
+use crate::error::{ElevenLabsError, Result};
+
+/// Callback invoked after each chunk is written.
+type ProgressCallback<'a> = Box<dyn Fn(DownloadProgress) + Send + Sync + 'a>;
+
+/// Progress of a running download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// Bytes written so far.
+    pub bytes_written: u64,
+    /// Size of the whole body from its `Content-Length` header, if known.
+    pub total_bytes: Option<u64>,
+}
+
+/// Options for a download to disk.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::DownloadOptions;
+///
+/// let options = DownloadOptions::new()
+///     .sha256("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08")
+///     .on_progress(|p| eprintln!("{} bytes", p.bytes_written));
+/// ```
+#[derive(Default)]
+pub struct DownloadOptions<'a> {
+    sha256: Option<String>,
+    on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl fmt::Debug for DownloadOptions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DownloadOptions")
+            .field("sha256", &self.sha256)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+impl<'a> DownloadOptions<'a> {
+    /// Creates options with no checksum and no progress callback.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the downloaded body to have this SHA-256 digest (hex, any
+    /// case). On a mismatch the partial file is removed and
+    /// [`ElevenLabsError::ChecksumMismatch`] is returned.
+    #[must_use]
+    pub fn sha256(mut self, hex: impl Into<String>) -> Self {
+        self.sha256 = Some(hex.into());
+        self
+    }
+
+    /// Registers a callback invoked after each chunk is written.
+    #[must_use]
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(DownloadProgress) + Send + Sync + 'a,
+    {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+}
+
+/// Result of a completed download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadSummary {
+    /// Where the file was written.
+    pub path: PathBuf,
+    /// Size of the file in bytes.
+    pub bytes_written: u64,
+    /// SHA-256 digest of the file as lowercase hex.
+    pub sha256: String,
+}
+
+/// Writes `stream` to `dest` via a `.part` file, verifying the checksum
+/// from `options` before renaming it into place.
+///
+/// The partial file is removed if the stream, the write or the checksum
+/// fails.
+pub(crate) async fn spool_to_file<S, E>(
+    stream: S,
+    total_bytes: Option<u64>,
+    dest: &Path,
+    options: &DownloadOptions<'_>,
+) -> Result<DownloadSummary>
+where
+    S: Stream<Item = std::result::Result<Bytes, E>>,
+    E: Into<ElevenLabsError>,
+{
+    let part = part_path(dest)?;
+    let result = write_part(stream, total_bytes, &part, options).await;
+    let (bytes_written, sha256) = match result {
+        Ok(written) => written,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&part).await;
+            return Err(e);
+        }
+    };
+
+    if let Some(expected) = &options.sha256 &&
+        !expected.eq_ignore_ascii_case(&sha256)
+    {
+        let _ = tokio::fs::remove_file(&part).await;
+        return Err(ElevenLabsError::ChecksumMismatch {
+            expected: expected.clone(),
+            actual: sha256,
+        });
+    }
+
+    tokio::fs::rename(&part, dest).await?;
+    tracing::debug!(path = %dest.display(), bytes_written, "download complete");
+    Ok(DownloadSummary { path: dest.to_owned(), bytes_written, sha256 })
+}
+
+/// Streams the body into `part`, returning its size and hex digest.
+async fn write_part<S, E>(
+    stream: S,
+    total_bytes: Option<u64>,
+    part: &Path,
+    options: &DownloadOptions<'_>,
+) -> Result<(u64, String)>
+where
+    S: Stream<Item = std::result::Result<Bytes, E>>,
+    E: Into<ElevenLabsError>,
+{
+    let mut file = tokio::fs::File::create(part).await?;
+    let mut hasher = Sha256::new();
+    let mut bytes_written = 0_u64;
+
+    let mut stream = std::pin::pin!(stream);
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(Into::into)?;
+        file.write_all(&chunk).await?;
+        hasher.update(&chunk);
+        bytes_written += chunk.len() as u64;
+        if let Some(callback) = &options.on_progress {
+            callback(DownloadProgress { bytes_written, total_bytes });
+        }
+    }
+    file.sync_all().await?;
+
+    let sha256 = hex(&hasher.finalize());
+    Ok((bytes_written, sha256))
+}
+
+/// Encodes `bytes` as lowercase hex, e.g. for SHA-256 digests.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+/// Returns `dest` with `.part` appended to its file name.
+fn part_path(dest: &Path) -> Result<PathBuf> {
+    let name = dest.file_name().ok_or_else(|| {
+        ElevenLabsError::Validation(format!(
+            "download destination has no file name: {}",
+            dest.display()
+        ))
+    })?;
+    let mut part = name.to_owned();
+    part.push(".part");
+    Ok(dest.with_file_name(part))
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures_util::stream;
+
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("elevenlabs-download-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn chunks(parts: &[&'static [u8]]) -> impl Stream<Item = Result<Bytes>> {
+        stream::iter(parts.iter().map(|p| Ok(Bytes::from_static(p))).collect::<Vec<_>>())
+    }
+
+    #[tokio::test]
+    async fn writes_file_and_reports_progress() {
+        let dir = test_dir("ok");
+        let dest = dir.join("out.bin");
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&seen);
+        let options = DownloadOptions::new()
+            .sha256("9F86D081884C7D659A2FEAA0C55AD015A3BF4F1B2B0B822CD15D6C15B0F00A08")
+            .on_progress(move |p| recorded.lock().unwrap().push(p.bytes_written));
+
+        let summary =
+            spool_to_file(chunks(&[b"te", b"st"]), Some(4), &dest, &options).await.unwrap();
+        assert_eq!(summary.bytes_written, 4);
+        assert_eq!(
+            summary.sha256,
+            "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+        );
+        assert_eq!(std::fs::read(&dest).unwrap(), b"test");
+        assert!(!dir.join("out.bin.part").exists());
+        assert_eq!(*seen.lock().unwrap(), [2, 4]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn checksum_mismatch_leaves_destination_untouched() {
+        let dir = test_dir("mismatch");
+        let dest = dir.join("out.bin");
+        std::fs::write(&dest, b"old").unwrap();
+        let options = DownloadOptions::new().sha256("00");
+
+        let err = spool_to_file(chunks(&[b"new"]), None, &dest, &options).await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::ChecksumMismatch { .. }));
+        assert_eq!(std::fs::read(&dest).unwrap(), b"old");
+        assert!(!dir.join("out.bin.part").exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn stream_error_removes_partial_file() {
+        let dir = test_dir("error");
+        let dest = dir.join("out.bin");
+        let body =
+            stream::iter(vec![Ok(Bytes::from_static(b"partial")), Err(ElevenLabsError::Timeout)]);
+
+        let err = spool_to_file(body, None, &dest, &DownloadOptions::new()).await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Timeout));
+        assert!(!dest.exists());
+        assert!(!dir.join("out.bin.part").exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn hex_encodes_lowercase_pairs() {
+        assert_eq!(hex(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+        assert_eq!(hex(&[]), "");
+    }
+}
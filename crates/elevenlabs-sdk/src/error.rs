@@ -2,6 +2,24 @@
 //!
 //! Provides [`ElevenLabsError`] as the primary error enum for all SDK
 //! operations, along with a convenient [`Result`] type alias.
+//!
+//! Error responses are classified by the `detail` object in their body.
+//! Known `detail.status` codes map to dedicated variants such as
+//! [`ElevenLabsError::QuotaExceeded`], and request validation failures
+//! (HTTP 422) carry their per-field [`FieldError`]s. Anything else falls
+//! back to [`ElevenLabsError::Api`].
+//!
+//! Errors built from a response keep its `request-id` header, available via
+//! [`ElevenLabsError::request_id`]. Quote it when contacting ElevenLabs
+//! support.
+
+use std::fmt;
+
+use serde::{Deserialize, de::DeserializeOwned};
+
+/// Characters of body kept on each side of a deserialization failure in
+/// [`ElevenLabsError::DeserializationFailed::raw_body_snippet`].
+const RAW_BODY_SNIPPET_CONTEXT: usize = 200;
 
 /// A convenient `Result` type alias that defaults to [`ElevenLabsError`].
 pub type Result<T> = std::result::Result<T, ElevenLabsError>;
@@ -23,23 +41,85 @@ pub enum ElevenLabsError {
         message: String,
         /// Optional raw response body for further inspection.
         body: Option<String>,
+        /// The response's `request-id` header, if present.
+        request_id: Option<String>,
     },
 
     /// Authentication failed (invalid or missing API key).
-    #[error("Authentication failed: {0}")]
-    Auth(String),
+    #[error("Authentication failed: {message}")]
+    Auth {
+        /// Human-readable error message from the API.
+        message: String,
+        /// The response's `request-id` header, if present.
+        request_id: Option<String>,
+    },
+
+    /// The API key was rejected (`detail.status` of `invalid_api_key`).
+    #[error("Invalid API key: {message}")]
+    InvalidApiKey {
+        /// Human-readable error message from the API.
+        message: String,
+        /// The response's `request-id` header, if present.
+        request_id: Option<String>,
+    },
+
+    /// The account has run out of credits for this request
+    /// (`detail.status` of `quota_exceeded`).
+    #[error("Quota exceeded: {message}")]
+    QuotaExceeded {
+        /// Human-readable error message from the API.
+        message: String,
+        /// The response's `request-id` header, if present.
+        request_id: Option<String>,
+    },
+
+    /// The request was refused before being sent because it would use more
+    /// characters than the [`QuotaGuard`](crate::QuotaGuard) estimates are
+    /// left.
+    #[error("Quota would be exceeded: {requested} characters requested, {remaining} remaining")]
+    QuotaWouldExceed {
+        /// Characters the request would use.
+        requested: i64,
+        /// Characters estimated to remain.
+        remaining: i64,
+    },
+
+    /// The requested voice does not exist or is not accessible
+    /// (`detail.status` of `voice_not_found`).
+    #[error("Voice not found: {message}")]
+    VoiceNotFound {
+        /// Human-readable error message from the API.
+        message: String,
+        /// The response's `request-id` header, if present.
+        request_id: Option<String>,
+    },
+
+    /// The API rejected the request body or parameters (HTTP 422).
+    #[error("Request validation failed: {}", FieldErrors(.errors))]
+    ValidationFailed {
+        /// One entry per rejected field.
+        errors: Vec<FieldError>,
+        /// The response's `request-id` header, if present.
+        request_id: Option<String>,
+    },
 
     /// The request was rate-limited by the API.
     #[error("Rate limited (retry after {retry_after:?}s)")]
     RateLimited {
         /// Optional number of seconds to wait before retrying.
         retry_after: Option<u64>,
+        /// The response's `request-id` header, if present.
+        request_id: Option<String>,
     },
 
     /// The request timed out before a response was received.
     #[error("Request timeout")]
     Timeout,
 
+    /// The request was cancelled via its cancellation token.
+    #[error("Request cancelled")]
+    Cancelled,
+
     /// An error occurred at the HTTP transport layer.
     #[error("Transport error: {0}")]
     Transport(#[from] hpx::Error),
@@ -48,6 +128,25 @@ pub enum ElevenLabsError {
     #[error("Deserialization error: {0}")]
     Deserialization(#[from] serde_json::Error),
 
+    /// A successful response body did not match the expected type, usually
+    /// because the API added or changed a field.
+    ///
+    /// The raw body is only kept when
+    /// [`ClientConfig::capture_raw_bodies`](crate::ClientConfig::capture_raw_bodies)
+    /// is enabled, since it may contain personal data.
+    #[error("Failed to deserialize response at `{path_hint}`: {serde_error}")]
+    DeserializationFailed {
+        /// Path to the value that failed, e.g. `voices[3].category`, or `.`
+        /// for the top level.
+        path_hint: String,
+        /// Excerpt of the body around the failure, if raw body capture is
+        /// enabled.
+        raw_body_snippet: Option<String>,
+        /// The underlying `serde_json` error.
+        #[source]
+        serde_error: serde_json::Error,
+    },
+
     /// A caller-provided input failed validation.
     #[error("Invalid input: {0}")]
     Validation(String),
@@ -59,10 +158,219 @@ pub enum ElevenLabsError {
     /// WebSocket communication error.
     #[error("WebSocket error: {0}")]
     WebSocket(String),
+
+    /// A webhook payload failed signature verification.
+    #[error("Webhook signature verification failed: {0}")]
+    WebhookSignature(String),
+
+    /// Reading or writing a local file failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A downloaded file did not match its expected SHA-256 digest.
+    #[error("Checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        /// The digest that was asked for.
+        expected: String,
+        /// The digest of the downloaded data.
+        actual: String,
+    },
+}
+
+impl ElevenLabsError {
+    /// Returns the `request-id` header of the response this error was built
+    /// from, or `None` if there was no response or it had no such header.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Api { request_id, .. } |
+            Self::Auth { request_id, .. } |
+            Self::InvalidApiKey { request_id, .. } |
+            Self::QuotaExceeded { request_id, .. } |
+            Self::VoiceNotFound { request_id, .. } |
+            Self::ValidationFailed { request_id, .. } |
+            Self::RateLimited { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Maps an unsuccessful response to the most specific variant.
+    ///
+    /// `429 Too Many Requests` is handled by the caller, since it depends on
+    /// the `Retry-After` header rather than the body.
+    pub(crate) fn from_response(
+        status: u16,
+        reason: &str,
+        body: String,
+        request_id: Option<String>,
+    ) -> Self {
+        let detail = serde_json::from_str::<ApiErrorBody>(&body).ok().and_then(|b| b.detail);
+        let (code, message) = match detail {
+            Some(ApiErrorDetail::Fields(fields)) if !fields.is_empty() => {
+                let errors = fields.into_iter().map(FieldError::from).collect();
+                return Self::ValidationFailed { errors, request_id };
+            }
+            Some(ApiErrorDetail::Structured { status, message }) => (status, Some(message)),
+            Some(ApiErrorDetail::Message(message)) => (None, Some(message)),
+            Some(ApiErrorDetail::Fields(_)) | None => (None, None),
+        };
+
+        match (code.as_deref(), message) {
+            (Some("invalid_api_key"), Some(message)) => Self::InvalidApiKey { message, request_id },
+            (Some("quota_exceeded"), Some(message)) => Self::QuotaExceeded { message, request_id },
+            (Some("voice_not_found"), Some(message)) => Self::VoiceNotFound { message, request_id },
+            (_, message) if status == 401 => Self::Auth {
+                message: message.unwrap_or_else(|| "invalid or missing API key".to_owned()),
+                request_id,
+            },
+            (_, message) => Self::Api {
+                status,
+                message: message.unwrap_or_else(|| reason.to_owned()),
+                body: if body.is_empty() { None } else { Some(body) },
+                request_id,
+            },
+        }
+    }
+}
+
+/// Deserializes a successful JSON response body.
+///
+/// On failure the body is parsed again to find the path to the offending
+/// value, and an excerpt around it is kept if `capture_raw_body` is set.
+pub(crate) fn decode_json<T: DeserializeOwned>(body: &[u8], capture_raw_body: bool) -> Result<T> {
+    serde_json::from_slice(body).map_err(|serde_error| {
+        let mut deserializer = serde_json::Deserializer::from_slice(body);
+        let path_hint = serde_path_to_error::deserialize::<_, T>(&mut deserializer)
+            .err()
+            .map_or_else(|| ".".to_owned(), |e| e.path().to_string());
+        let raw_body_snippet =
+            capture_raw_body.then(|| body_snippet(body, serde_error.line(), serde_error.column()));
+        ElevenLabsError::DeserializationFailed { path_hint, raw_body_snippet, serde_error }
+    })
+}
+
+/// Returns the text of `body` around the 1-based `line` and `column`, with
+/// `…` marking where it was cut.
+fn body_snippet(body: &[u8], line: usize, column: usize) -> String {
+    let text = String::from_utf8_lossy(body);
+    let line_start: usize =
+        text.split_inclusive('\n').take(line.saturating_sub(1)).map(str::len).sum();
+    let mut offset = (line_start + column.saturating_sub(1)).min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let (head, tail) = text.split_at(offset);
+    let before: Vec<char> = head.chars().rev().take(RAW_BODY_SNIPPET_CONTEXT + 1).collect();
+    let after: Vec<char> = tail.chars().take(RAW_BODY_SNIPPET_CONTEXT + 1).collect();
+
+    let mut snippet = String::new();
+    if before.len() > RAW_BODY_SNIPPET_CONTEXT {
+        snippet.push('…');
+    }
+    snippet.extend(before.iter().take(RAW_BODY_SNIPPET_CONTEXT).rev());
+    snippet.extend(after.iter().take(RAW_BODY_SNIPPET_CONTEXT));
+    if after.len() > RAW_BODY_SNIPPET_CONTEXT {
+        snippet.push('…');
+    }
+    snippet
+}
+
+/// A single problem reported in a request validation failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Path to the offending value, e.g. `["body", "voice_settings",
+    /// "stability"]`. Array indices are rendered as strings.
+    pub loc: Vec<String>,
+    /// Human-readable description of the problem.
+    pub msg: String,
+    /// Machine-readable error type, e.g. `"missing"` or `"value_error"`.
+    pub kind: String,
+}
+
+impl FieldError {
+    /// Returns [`loc`](Self::loc) joined with dots, e.g.
+    /// `body.voice_settings.stability`.
+    pub fn path(&self) -> String {
+        self.loc.join(".")
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.loc.is_empty() {
+            f.write_str(&self.msg)
+        } else {
+            write!(f, "{}: {}", self.path(), self.msg)
+        }
+    }
+}
+
+impl From<RawFieldError> for FieldError {
+    fn from(raw: RawFieldError) -> Self {
+        let loc = raw
+            .loc
+            .into_iter()
+            .map(|part| match part {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            })
+            .collect();
+        Self { loc, msg: raw.msg, kind: raw.kind }
+    }
+}
+
+/// Displays field errors separated by semicolons.
+struct FieldErrors<'a>(&'a [FieldError]);
+
+impl fmt::Display for FieldErrors<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Shape of error responses returned by the ElevenLabs API.
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    /// Top-level detail field.
+    detail: Option<ApiErrorDetail>,
+}
+
+/// Inner detail of an API error.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ApiErrorDetail {
+    /// A plain error message string.
+    Message(String),
+    /// A structured error object with an optional machine-readable code.
+    Structured {
+        /// Machine-readable error code, e.g. `"voice_not_found"`.
+        #[serde(default)]
+        status: Option<String>,
+        /// The error message.
+        message: String,
+    },
+    /// Per-field validation errors (HTTP 422).
+    Fields(Vec<RawFieldError>),
+}
+
+/// A validation error entry as it appears on the wire.
+#[derive(Deserialize)]
+struct RawFieldError {
+    #[serde(default)]
+    loc: Vec<serde_json::Value>,
+    msg: String,
+    #[serde(rename = "type", default)]
+    kind: String,
 }
 
 #[cfg(test)]
-#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+#[expect(clippy::unwrap_used, clippy::panic, reason = "tests use unwrap and panic")]
 mod tests {
     use super::*;
 
@@ -76,25 +384,26 @@ mod tests {
             status: 422,
             message: "invalid voice id".to_owned(),
             body: Some("{\"detail\":\"not found\"}".to_owned()),
+            request_id: None,
         };
         assert_eq!(err.to_string(), "API error (HTTP 422): invalid voice id");
     }
 
     #[test]
     fn display_auth_error() {
-        let err = ElevenLabsError::Auth("invalid api key".to_owned());
+        let err = ElevenLabsError::Auth { message: "invalid api key".to_owned(), request_id: None };
         assert_eq!(err.to_string(), "Authentication failed: invalid api key");
     }
 
     #[test]
     fn display_rate_limited_with_retry() {
-        let err = ElevenLabsError::RateLimited { retry_after: Some(30) };
+        let err = ElevenLabsError::RateLimited { retry_after: Some(30), request_id: None };
         assert_eq!(err.to_string(), "Rate limited (retry after Some(30)s)");
     }
 
     #[test]
     fn display_rate_limited_without_retry() {
-        let err = ElevenLabsError::RateLimited { retry_after: None };
+        let err = ElevenLabsError::RateLimited { retry_after: None, request_id: None };
         assert_eq!(err.to_string(), "Rate limited (retry after Nones)");
     }
 
@@ -104,6 +413,12 @@ mod tests {
         assert_eq!(err.to_string(), "Request timeout");
     }
 
+    #[test]
+    fn display_cancelled() {
+        let err = ElevenLabsError::Cancelled;
+        assert_eq!(err.to_string(), "Request cancelled");
+    }
+
     #[test]
     fn display_validation_error() {
         let err = ElevenLabsError::Validation("text is empty".to_owned());
@@ -136,4 +451,133 @@ mod tests {
         let err = ElevenLabsError::WebSocket("connection refused".to_owned());
         assert_eq!(err.to_string(), "WebSocket error: connection refused");
     }
+
+    #[test]
+    fn display_webhook_signature_error() {
+        let err = ElevenLabsError::WebhookSignature("signature mismatch".to_owned());
+        assert_eq!(err.to_string(), "Webhook signature verification failed: signature mismatch");
+    }
+
+    #[test]
+    fn from_response_maps_known_status_codes() {
+        let body = |code: &str| format!(r#"{{"detail":{{"status":"{code}","message":"nope"}}}}"#);
+        assert!(matches!(
+            ElevenLabsError::from_response(401, "Unauthorized", body("invalid_api_key"), None),
+            ElevenLabsError::InvalidApiKey { message, .. } if message == "nope"
+        ));
+        assert!(matches!(
+            ElevenLabsError::from_response(401, "Unauthorized", body("quota_exceeded"), None),
+            ElevenLabsError::QuotaExceeded { .. }
+        ));
+        assert!(matches!(
+            ElevenLabsError::from_response(404, "Not Found", body("voice_not_found"), None),
+            ElevenLabsError::VoiceNotFound { .. }
+        ));
+        assert!(matches!(
+            ElevenLabsError::from_response(400, "Bad Request", body("something_else"), None),
+            ElevenLabsError::Api { status: 400, message, body: Some(_), .. } if message == "nope"
+        ));
+    }
+
+    #[test]
+    fn from_response_falls_back_to_auth_and_reason() {
+        assert!(matches!(
+            ElevenLabsError::from_response(401, "Unauthorized", String::new(), None),
+            ElevenLabsError::Auth { message, .. } if message == "invalid or missing API key"
+        ));
+        assert!(matches!(
+            ElevenLabsError::from_response(502, "Bad Gateway", "<html>".to_owned(), None),
+            ElevenLabsError::Api { status: 502, message, .. } if message == "Bad Gateway"
+        ));
+    }
+
+    #[test]
+    fn from_response_parses_validation_errors() {
+        let body = r#"{"detail":[
+            {"loc":["body","voice_settings","stability"],"msg":"must be <= 1","type":"less_than_equal"},
+            {"loc":["body","samples",0],"msg":"field required","type":"missing"}
+        ]}"#;
+        let err =
+            ElevenLabsError::from_response(422, "Unprocessable Entity", body.to_owned(), None);
+        let ElevenLabsError::ValidationFailed { errors: fields, .. } = &err else {
+            panic!("expected ValidationFailed, got {err:?}");
+        };
+        assert_eq!(fields[0].path(), "body.voice_settings.stability");
+        assert_eq!(fields[0].kind, "less_than_equal");
+        assert_eq!(fields[1].loc, ["body", "samples", "0"]);
+        assert_eq!(
+            err.to_string(),
+            "Request validation failed: body.voice_settings.stability: must be <= 1; \
+             body.samples.0: field required"
+        );
+    }
+
+    #[test]
+    fn request_id_is_kept_on_response_errors() {
+        let request_id = || Some("req_123".to_owned());
+        let errors = [
+            ElevenLabsError::from_response(401, "Unauthorized", String::new(), request_id()),
+            ElevenLabsError::from_response(
+                500,
+                "Internal Server Error",
+                String::new(),
+                request_id(),
+            ),
+            ElevenLabsError::from_response(
+                422,
+                "Unprocessable Entity",
+                r#"{"detail":[{"loc":["body"],"msg":"bad","type":"x"}]}"#.to_owned(),
+                request_id(),
+            ),
+            ElevenLabsError::RateLimited { retry_after: None, request_id: request_id() },
+        ];
+        for err in &errors {
+            assert_eq!(err.request_id(), Some("req_123"), "{err:?}");
+        }
+        assert_eq!(ElevenLabsError::Timeout.request_id(), None);
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Voices {
+        #[expect(dead_code, reason = "only deserialized")]
+        voices: Vec<VoiceEntry>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct VoiceEntry {
+        #[expect(dead_code, reason = "only deserialized")]
+        category: u32,
+    }
+
+    #[test]
+    fn decode_json_reports_path_and_optional_snippet() {
+        let body = br#"{"voices": [{"category": 1}, {"category": "cloned"}]}"#;
+
+        let err = decode_json::<Voices>(body, false).unwrap_err();
+        let ElevenLabsError::DeserializationFailed { path_hint, raw_body_snippet, .. } = &err
+        else {
+            panic!("expected DeserializationFailed, got {err:?}");
+        };
+        assert_eq!(path_hint, "voices[1].category");
+        assert!(raw_body_snippet.is_none());
+        assert!(
+            err.to_string().starts_with("Failed to deserialize response at `voices[1].category`")
+        );
+
+        let err = decode_json::<Voices>(body, true).unwrap_err();
+        let ElevenLabsError::DeserializationFailed { raw_body_snippet: Some(snippet), .. } = err
+        else {
+            panic!("expected a raw body snippet");
+        };
+        assert_eq!(snippet, std::str::from_utf8(body).unwrap());
+    }
+
+    #[test]
+    fn body_snippet_is_cut_around_the_failure() {
+        let body = format!("{}é{}", "a".repeat(500), "b".repeat(500));
+        let snippet = body_snippet(body.as_bytes(), 1, 501);
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert_eq!(snippet.chars().count(), 2 * RAW_BODY_SNIPPET_CONTEXT + 2);
+        assert!(snippet.contains(&format!("{}é", "a".repeat(RAW_BODY_SNIPPET_CONTEXT))));
+    }
 }
@@ -0,0 +1,332 @@
+//! Podcast RSS feeds built from speech history (`podcast-feed` feature).
+//!
+//! [`HistoryService::podcast_feed`](crate::services::HistoryService::podcast_feed)
+//! turns history items into an RSS 2.0 feed with one episode per item:
+//!
+//! | Episode field | Taken from |
+//! |---------------|------------|
+//! | `title` | The start of the item's text, cut at a word boundary |
+//! | `description` | The item's full text |
+//! | `pubDate` | `date_unix` |
+//! | `guid` | `history_item_id` |
+//! | `enclosure` | `{audio_base_url}/{history_item_id}.{ext}` |
+//!
+//! The feed only links to the audio; history audio cannot be fetched
+//! without an API key, so it has to be hosted under `audio_base_url`. With
+//! [`PodcastFeed::download_to`] the audio of every episode is downloaded
+//! into a directory under the enclosure file names, ready to upload, and
+//! the enclosures carry the real length and duration.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, feed::PodcastFeed};
+//!
+//! # async fn example() -> elevenlabs_sdk::Result<()> {
+//! let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+//! let history = client.history().list(Some(50), None, Some("narrator_voice_id"), None).await?;
+//!
+//! let feed = PodcastFeed::new(
+//!     "Daily Briefing",
+//!     "https://example.com/briefing",
+//!     "Narrated news, every morning.",
+//!     "https://cdn.example.com/briefing",
+//! )
+//! .language("en")
+//! .download_to("public/briefing");
+//! let rss = client.history().podcast_feed(&history.history, &feed).await?;
+//! std::fs::write("public/briefing/feed.xml", rss)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{pls::escape, types::SpeechHistoryItem, upload::CONTENT_TYPES};
+
+/// Characters of an item's text used as the episode title unless
+/// overridden with [`PodcastFeed::title_chars`].
+pub const DEFAULT_TITLE_CHARS: usize = 80;
+
+/// Channel settings of a podcast feed.
+#[derive(Debug, Clone)]
+pub struct PodcastFeed {
+    title: String,
+    link: String,
+    description: String,
+    audio_base_url: String,
+    language: Option<String>,
+    author: Option<String>,
+    image_url: Option<String>,
+    title_chars: usize,
+    download_dir: Option<PathBuf>,
+}
+
+impl PodcastFeed {
+    /// Creates a feed whose episode audio is served from `audio_base_url`.
+    pub fn new(
+        title: impl Into<String>,
+        link: impl Into<String>,
+        description: impl Into<String>,
+        audio_base_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            link: link.into(),
+            description: description.into(),
+            audio_base_url: audio_base_url.into(),
+            language: None,
+            author: None,
+            image_url: None,
+            title_chars: DEFAULT_TITLE_CHARS,
+            download_dir: None,
+        }
+    }
+
+    /// Sets the feed language (e.g. `"en"`).
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Sets the author shown by podcast apps.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Sets the cover art URL.
+    pub fn image_url(mut self, url: impl Into<String>) -> Self {
+        self.image_url = Some(url.into());
+        self
+    }
+
+    /// Sets how many characters of an item's text make up its title.
+    pub const fn title_chars(mut self, chars: usize) -> Self {
+        self.title_chars = chars;
+        self
+    }
+
+    /// Downloads the audio of every episode into `dir`, named like the
+    /// enclosure URLs.
+    pub fn download_to(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.download_dir = Some(dir.into());
+        self
+    }
+
+    /// Returns the directory set with [`download_to`](Self::download_to).
+    pub fn download_dir(&self) -> Option<&Path> {
+        self.download_dir.as_deref()
+    }
+
+    /// Maps a history item to an episode, without audio length or
+    /// duration.
+    pub fn episode(&self, item: &SpeechHistoryItem) -> PodcastEpisode {
+        let text = item.text.as_deref().unwrap_or_default().trim();
+        let file_name = format!("{}.{}", item.history_item_id, extension(&item.content_type));
+        PodcastEpisode {
+            guid: item.history_item_id.clone(),
+            title: episode_title(text, self.title_chars)
+                .unwrap_or_else(|| item.history_item_id.clone()),
+            description: text.to_owned(),
+            pub_date_unix: item.date_unix.into(),
+            enclosure_url: format!("{}/{file_name}", self.audio_base_url.trim_end_matches('/')),
+            file_name,
+            content_type: item.content_type.clone(),
+            length: 0,
+            duration: None,
+        }
+    }
+
+    /// Renders the feed as RSS 2.0 with iTunes tags, listing `episodes` in
+    /// the given order.
+    pub fn to_rss(&self, episodes: &[PodcastEpisode]) -> String {
+        let mut rss = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\" \
+             xmlns:itunes=\"http://www.itunes.com/dtds/podcast-1.0.dtd\">\n<channel>\n",
+        );
+        push_element(&mut rss, 1, "title", &self.title);
+        push_element(&mut rss, 1, "link", &self.link);
+        push_element(&mut rss, 1, "description", &self.description);
+        if let Some(language) = &self.language {
+            push_element(&mut rss, 1, "language", language);
+        }
+        if let Some(author) = &self.author {
+            push_element(&mut rss, 1, "itunes:author", author);
+        }
+        if let Some(url) = &self.image_url {
+            let _ = writeln!(rss, "  <itunes:image href=\"{}\"/>", escape(url));
+        }
+        if let Some(latest) = episodes.iter().map(|e| e.pub_date_unix).max() {
+            push_element(&mut rss, 1, "lastBuildDate", &rfc2822(latest));
+        }
+        for episode in episodes {
+            rss.push_str("  <item>\n");
+            push_element(&mut rss, 2, "title", &episode.title);
+            push_element(&mut rss, 2, "description", &episode.description);
+            push_element(&mut rss, 2, "pubDate", &rfc2822(episode.pub_date_unix));
+            let _ =
+                writeln!(rss, "    <guid isPermaLink=\"false\">{}</guid>", escape(&episode.guid));
+            let _ = writeln!(
+                rss,
+                "    <enclosure url=\"{}\" length=\"{}\" type=\"{}\"/>",
+                escape(&episode.enclosure_url),
+                episode.length,
+                escape(&episode.content_type),
+            );
+            if let Some(duration) = episode.duration {
+                push_element(&mut rss, 2, "itunes:duration", &duration.as_secs().to_string());
+            }
+            rss.push_str("  </item>\n");
+        }
+        rss.push_str("</channel>\n</rss>\n");
+        rss
+    }
+}
+
+/// One feed entry, mapped from a history item by [`PodcastFeed::episode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PodcastEpisode {
+    /// Stable ID of the episode; the history item ID.
+    pub guid: String,
+    /// Episode title.
+    pub title: String,
+    /// Episode description.
+    pub description: String,
+    /// Publication time as a Unix timestamp.
+    pub pub_date_unix: i64,
+    /// File name of the audio under the feed's audio base URL.
+    pub file_name: String,
+    /// Public URL of the audio.
+    pub enclosure_url: String,
+    /// MIME type of the audio.
+    pub content_type: String,
+    /// Size of the audio in bytes, or `0` if unknown.
+    pub length: u64,
+    /// Length of the audio, if known.
+    pub duration: Option<Duration>,
+}
+
+/// Returns the first `max_chars` characters of `text`, cut at the last word
+/// boundary and marked with an ellipsis if shortened, or `None` if `text`
+/// is blank.
+fn episode_title(text: &str, max_chars: usize) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    let Some((cut, _)) = text.char_indices().nth(max_chars) else {
+        return Some(text);
+    };
+    let prefix = &text[..cut];
+    let prefix = prefix.rfind(' ').map_or(prefix, |space| &prefix[..space]);
+    Some(format!("{}…", prefix.trim_end()))
+}
+
+/// Returns the file extension for `content_type`, falling back to `"mp3"`.
+fn extension(content_type: &str) -> &'static str {
+    let mime = content_type.split(';').next().unwrap_or_default().trim();
+    CONTENT_TYPES
+        .iter()
+        .find(|(_, known)| known.eq_ignore_ascii_case(mime))
+        .map_or("mp3", |(ext, _)| ext)
+}
+
+/// Appends `<name>value</name>` indented by `depth` levels.
+fn push_element(rss: &mut String, depth: usize, name: &str, value: &str) {
+    let _ = writeln!(rss, "{:indent$}<{name}>{}</{name}>", "", escape(value), indent = depth * 2);
+}
+
+/// Formats a Unix timestamp as an RFC 2822 date in UTC, as RSS expects.
+fn rfc2822(unix: i64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    const MONTHS: [&str; 12] =
+        ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+    let days = unix.div_euclid(86_400);
+    let secs = unix.rem_euclid(86_400);
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{}, {day:02} {} {year} {:02}:{:02}:{:02} +0000",
+        WEEKDAYS[days.rem_euclid(7) as usize],
+        MONTHS[(month - 1) as usize],
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60,
+    )
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, text: &str, date_unix: i64) -> SpeechHistoryItem {
+        serde_json::from_value(serde_json::json!({
+            "history_item_id": id,
+            "text": text,
+            "date_unix": date_unix,
+            "character_count_change_from": 0,
+            "character_count_change_to": 10,
+            "content_type": "audio/mpeg",
+            "state": "created"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn formats_rfc2822_dates() {
+        assert_eq!(rfc2822(0), "Thu, 01 Jan 1970 00:00:00 +0000");
+        assert_eq!(rfc2822(1_714_650_306), "Thu, 02 May 2024 11:45:06 +0000");
+        assert_eq!(rfc2822(951_825_600), "Tue, 29 Feb 2000 12:00:00 +0000");
+    }
+
+    #[test]
+    fn titles_are_cut_at_word_boundaries() {
+        assert_eq!(episode_title("  Hello\n world ", 80).as_deref(), Some("Hello world"));
+        assert_eq!(episode_title("Good morning everyone", 15).as_deref(), Some("Good morning…"));
+        assert_eq!(episode_title("Grüße", 3).as_deref(), Some("Grü…"));
+        assert_eq!(episode_title("  ", 10), None);
+    }
+
+    #[test]
+    fn renders_escaped_feed_with_enclosures() {
+        let feed = PodcastFeed::new("News & Views", "https://example.com", "Daily", "https://cdn/")
+            .language("en")
+            .title_chars(12);
+        let mut episode =
+            feed.episode(&item("item1", "Markets <rally> today again", 1_714_650_306));
+        assert_eq!(episode.title, "Markets…");
+        assert_eq!(episode.enclosure_url, "https://cdn/item1.mp3");
+        episode.length = 1_234;
+        episode.duration = Some(Duration::from_secs(61));
+        let untitled = feed.episode(&item("item2", "", 1_714_000_000));
+        assert_eq!(untitled.title, "item2");
+
+        let rss = feed.to_rss(&[episode, untitled]);
+        assert!(rss.contains("<title>News &amp; Views</title>"));
+        assert!(rss.contains("<language>en</language>"));
+        assert!(rss.contains("<lastBuildDate>Thu, 02 May 2024 11:45:06 +0000</lastBuildDate>"));
+        assert!(rss.contains("<description>Markets &lt;rally&gt; today again</description>"));
+        assert!(rss.contains(
+            "<enclosure url=\"https://cdn/item1.mp3\" length=\"1234\" type=\"audio/mpeg\"/>"
+        ));
+        assert!(rss.contains("<itunes:duration>61</itunes:duration>"));
+        assert!(rss.contains("<guid isPermaLink=\"false\">item2</guid>"));
+        assert_eq!(rss.matches("<item>").count(), 2);
+    }
+}
@@ -44,40 +44,123 @@
 //! - **User & Workspace** — Account and workspace management.
 //! - **Retry & Error Handling** — Automatic retry with exponential backoff, structured error types
 //!   with status codes and rate-limit info.
+//! - **Response Caching** (`cache` feature) — Optional TTL cache with `ETag` revalidation for
+//!   rarely-changing GET endpoints such as models and voices, with in-memory and on-disk stores.
+//! - **Record & Replay** (`record-replay` feature) — Capture API responses to sanitized fixture
+//!   files and replay them in tests without a network.
+//! - **Podcast Feeds** (`podcast-feed` feature) — Publish speech history items as a podcast RSS
+//!   feed.
+//! - **Offline Queue** (`offline-queue` feature) — Queue TTS and sound-generation requests on disk
+//!   while offline and deliver them in order, without duplicates, once the network is back.
+//! - **Audio Processing** (`audio-processing` feature) — Resample long recordings and split them at
+//!   pauses for speech-to-speech.
 //!
 //! ## Module Organization
 //!
 //! | Module | Description |
 //! |--------|-------------|
+//! | [`audio`] | Local PCM/WAV/μ-law conversions, duration estimates and (`audio-processing` feature) STS preprocessing |
 //! | [`auth`] | API key authentication and secure key handling |
 //! | [`config`] | Client configuration builder with env-var support |
+//! | [`deadline`] | Caller deadlines propagated into retries and WebSocket connects |
 //! | [`error`] | Error types ([`ElevenLabsError`]) and `Result` alias |
+//! | `feed` | Podcast RSS feeds from speech history (`podcast-feed` feature) |
+//! | `offline` | Disk-backed queue for requests made while offline (`offline-queue` feature) |
 //! | [`client`] | HTTP client ([`ElevenLabsClient`]) with automatic auth |
 //! | [`types`] | Shared request/response types mirroring the OpenAPI spec |
+//! | [`pls`] | PLS pronunciation lexicon parsing, writing and diffing |
+//! | [`profile`] | Named profiles with API keys and defaults, stored in a TOML file |
 //! | [`services`] | Typed endpoint wrappers (TTS, voices, models, etc.) |
-//! | [`ws`] | WebSocket streaming (TTS input-streaming, conversational AI) |
+//! | [`webhooks`] | Webhook signature verification and typed webhook payloads |
+//! | `ws` | WebSocket streaming for TTS input-streaming and conversational AI (`ws` feature) |
+//!
+//! ## Service Features
+//!
+//! The larger service groups can be compiled out to cut build times. All are
+//! enabled by default; depend on the crate with `default-features = false`
+//! and list the ones you use:
+//!
+//! | Feature | Enables |
+//! |---------|---------|
+//! | `tts` | Text-to-speech, text-to-dialogue and bulk synthesis |
+//! | `convai` | Agents platform: agents, conversations, knowledge base, tools |
+//! | `studio` | Studio projects and chapters |
+//! | `dubbing` | Dubbing projects |
+//! | `music` | Music generation |
+//! | `ws` | WebSocket TTS streaming, plus conversational AI with `convai` |
+//!
+//! Voices, models, history, speech-to-text, speech-to-speech, sound
+//! generation and the other smaller services are always available.
 
+pub mod audio;
 pub mod auth;
 pub mod client;
 pub mod config;
+pub mod deadline;
+pub mod download;
 pub mod error;
+#[cfg(feature = "podcast-feed")]
+pub mod feed;
 mod middleware;
+#[cfg(feature = "offline-queue")]
+pub mod offline;
+pub mod pls;
+pub mod profile;
 pub mod services;
 pub mod types;
+pub mod upload;
+pub mod webhooks;
+#[cfg(feature = "ws")]
 pub mod ws;
 
-pub use auth::ApiKey;
+pub use auth::{ApiKey, AuthStrategy, KeyPool, KeyRotation, TokenProvider};
 pub use client::ElevenLabsClient;
-pub use config::{ClientConfig, ClientConfigBuilder, ConfigError};
-pub use error::{ElevenLabsError, Result};
+pub use config::{
+    ClientConfig, ClientConfigBuilder, CompressionConfig, ConfigError, HttpVersionPreference,
+    NetworkConfig, PoolConfig, Region, RequestOptions, TimeoutCategory, TimeoutProfile, TlsVersion,
+    WsTransportConfig,
+};
+pub use download::{DownloadOptions, DownloadProgress, DownloadSummary};
+pub use error::{ElevenLabsError, FieldError, Result};
+#[cfg(feature = "cache")]
+pub use middleware::cache::{
+    CacheConfig, CacheStore, CachedResponse, DEFAULT_CACHE_TTL, DiskCacheStore, MemoryCacheStore,
+};
+#[cfg(feature = "record-replay")]
+pub use middleware::recorder::{Cassette, CassetteMode, Interaction, RecordedBody};
+pub use middleware::{
+    metrics::{MetricsSink, PoolStats, RequestMetrics, WsEvent},
+    quota::{DEFAULT_QUOTA_REFRESH_INTERVAL, QuotaGuard},
+};
+pub use profile::{Profile, ProfileFile};
+#[cfg(feature = "dubbing")]
+pub use services::DubbingService;
+#[cfg(feature = "music")]
+pub use services::MusicService;
+#[cfg(feature = "studio")]
+pub use services::StudioService;
+#[cfg(feature = "convai")]
+pub use services::{AgentsService, KnowledgeBaseSync, KnowledgeBaseUploader};
 pub use services::{
-    AgentsService, AudioIsolationService, AudioNativeService, ForcedAlignmentService,
-    HistoryService, ModelsService, MusicService, PvcVoicesService, SingleUseTokenService,
-    SoundGenerationService, SpeechToSpeechService, SpeechToTextService, StudioService,
-    TextToDialogueService, TextToSpeechService, TextToVoiceService, UserService,
+    AudioIsolationService, AudioNativeService, ForcedAlignmentService, HistoryService,
+    ModelsService, PvcTrainer, PvcVoicesService, SingleUseTokenService, SoundGenerationService,
+    SpeechToSpeechService, SpeechToTextService, TextToVoiceService, UsageService, UserService,
     VoiceGenerationService, VoicesService, WorkspaceService,
 };
-pub use ws::{
-    conversation::{ConversationEvent, ConversationWebSocket},
-    tts::{TtsWebSocket, TtsWsConfig, TtsWsResponse},
+#[cfg(feature = "tts")]
+pub use services::{
+    BulkJob, BulkProgress, BulkSynthesizer, JobResult, TextChunker, TextToDialogueService,
+    TextToSpeechService,
+};
+pub use tokio_util::sync::CancellationToken;
+pub use upload::FileInput;
+#[cfg(all(feature = "ws", feature = "convai"))]
+pub use ws::conversation::{
+    ConversationEvent, ConversationReceiver, ConversationSender, ConversationWebSocket,
+    ReconnectPolicy,
+};
+#[cfg(feature = "ws")]
+pub use ws::tts::{
+    TtsWebSocket, TtsWsAudioChunk, TtsWsConfig, TtsWsPacing, TtsWsReceiver, TtsWsResponse,
+    TtsWsSender,
 };
@@ -0,0 +1,553 @@
+//! Optional response cache for rarely-changing GET endpoints.
+//!
+//! Enabled with the `cache` feature. See [`CacheConfig`] for usage.
+
+use std::{
+    fmt,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::download::hex;
+
+/// TTL used by [`CacheConfig::with_default_rules`] (5 minutes).
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_mins(5);
+
+/// Metadata endpoints cached by [`CacheConfig::with_default_rules`].
+const DEFAULT_CACHED_ENDPOINTS: &[&str] = &[
+    "/v1/models",
+    "/v1/voices",
+    "/v1/voices/*",
+    "/v1/voices/*/settings",
+    "/v1/voices/settings/default",
+    "/v1/pronunciation-dictionaries",
+    "/v1/pronunciation-dictionaries/*",
+];
+
+// ---------------------------------------------------------------------------
+// Entries & stores
+// ---------------------------------------------------------------------------
+
+/// A cached GET response body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// Raw response body.
+    pub body: Bytes,
+    /// `ETag` header returned with the response, if any.
+    pub etag: Option<String>,
+    /// When the response was stored or last revalidated.
+    pub stored_at: SystemTime,
+}
+
+impl CachedResponse {
+    /// Creates an entry stored now.
+    pub fn new(body: Bytes, etag: Option<String>) -> Self {
+        Self { body, etag, stored_at: SystemTime::now() }
+    }
+
+    /// Returns `true` if the entry is younger than `ttl`.
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        self.stored_at.elapsed().is_ok_and(|age| age < ttl)
+    }
+}
+
+/// Storage backend for cached responses.
+///
+/// Keys are opaque hex strings derived from the credential and request URL, so
+/// a single store can safely be shared between clients. Implementations
+/// should treat failures as cache misses rather than surfacing them.
+///
+/// The methods may block: the client calls them on Tokio's blocking thread
+/// pool, never on a runtime worker.
+pub trait CacheStore: Send + Sync + fmt::Debug {
+    /// Returns the entry stored under `key`, if any.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Stores `entry` under `key`, replacing any previous entry.
+    fn put(&self, key: &str, entry: CachedResponse);
+
+    /// Removes the entry stored under `key`, if any.
+    fn remove(&self, key: &str);
+
+    /// Removes every entry whose key starts with `prefix`.
+    fn remove_prefix(&self, prefix: &str);
+
+    /// Removes every entry.
+    fn clear(&self);
+}
+
+/// In-memory [`CacheStore`] backed by a concurrent hash map.
+#[derive(Debug, Default)]
+pub struct MemoryCacheStore {
+    entries: scc::HashMap<String, CachedResponse>,
+}
+
+impl MemoryCacheStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of stored entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the store holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheStore for MemoryCacheStore {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.entries.read_sync(key, |_, entry| entry.clone())
+    }
+
+    fn put(&self, key: &str, entry: CachedResponse) {
+        self.entries.upsert_sync(key.to_owned(), entry);
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.remove_sync(key);
+    }
+
+    fn remove_prefix(&self, prefix: &str) {
+        self.entries.retain_sync(|key, _| !key.starts_with(prefix));
+    }
+
+    fn clear(&self) {
+        self.entries.clear_sync();
+    }
+}
+
+/// On-disk [`CacheStore`] that keeps one JSON file per entry.
+///
+/// Entries are written to a temporary file and renamed into place, so a
+/// concurrent reader sees either the old entry or the new one. I/O errors
+/// are logged and treated as cache misses.
+#[derive(Debug, Clone)]
+pub struct DiskCacheStore {
+    dir: PathBuf,
+}
+
+/// On-disk representation of a [`CachedResponse`].
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    /// Base64-encoded response body.
+    body: String,
+    /// Response `ETag`, if any.
+    etag: Option<String>,
+    /// Storage time in Unix seconds.
+    stored_at: u64,
+}
+
+impl DiskCacheStore {
+    /// Creates a store rooted at `dir`. The directory is created on first
+    /// write if it does not exist.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the directory entries are stored in.
+    pub fn dir(&self) -> &std::path::Path {
+        &self.dir
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+
+    /// Returns a temporary path for a write of `key` that no other write in
+    /// this process uses.
+    fn temp_path(&self, key: &str) -> PathBuf {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        let n = NEXT.fetch_add(1, Ordering::Relaxed);
+        self.dir.join(format!("{key}.{}.{n}.tmp", std::process::id()))
+    }
+}
+
+impl CacheStore for DiskCacheStore {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let raw = std::fs::read(self.entry_path(key)).ok()?;
+        let entry: DiskEntry = serde_json::from_slice(&raw).ok()?;
+        let body = STANDARD.decode(entry.body).ok()?;
+        Some(CachedResponse {
+            body: Bytes::from(body),
+            etag: entry.etag,
+            stored_at: UNIX_EPOCH + Duration::from_secs(entry.stored_at),
+        })
+    }
+
+    fn put(&self, key: &str, entry: CachedResponse) {
+        let stored_at =
+            entry.stored_at.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs());
+        let disk = DiskEntry { body: STANDARD.encode(&entry.body), etag: entry.etag, stored_at };
+        let temp = self.temp_path(key);
+        let result = std::fs::create_dir_all(&self.dir).and_then(|()| {
+            let raw = serde_json::to_vec(&disk)?;
+            std::fs::write(&temp, raw)?;
+            std::fs::rename(&temp, self.entry_path(key))
+        });
+        if let Err(error) = result {
+            let _ = std::fs::remove_file(&temp);
+            tracing::debug!(%error, dir = %self.dir.display(), "failed to write cache entry");
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        // A missing file is already the desired state.
+        let _ = std::fs::remove_file(self.entry_path(key));
+    }
+
+    fn remove_prefix(&self, prefix: &str) {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            if path.extension().is_some_and(|ext| ext == "json") &&
+                name.to_string_lossy().starts_with(prefix)
+            {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    fn clear(&self) {
+        self.remove_prefix("");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Configuration
+// ---------------------------------------------------------------------------
+
+/// Which GET requests to cache, for how long, and where.
+///
+/// Attach a configuration to a client with
+/// [`ElevenLabsClient::with_cache`](crate::ElevenLabsClient::with_cache).
+/// A GET request is cached when its path starts with one of the prefixes
+/// added with [`ttl`](Self::ttl) or matches one of the endpoints added with
+/// [`ttl_endpoint`](Self::ttl_endpoint); the longest matching rule decides
+/// the TTL. Fresh entries are served without contacting the API. Expired
+/// entries that carried an `ETag` are revalidated with `If-None-Match`, so
+/// an unchanged resource costs a `304 Not Modified` instead of a full
+/// response.
+///
+/// Only successful responses are cached. A successful non-GET request
+/// through the client drops the entries of every rule whose path, up to
+/// its first `*` segment, starts the request path: editing a voice clears
+/// the cached voice list, voices and voice settings. Writes made elsewhere,
+/// such as in the web UI or another process, are only picked up once the
+/// TTL runs out.
+///
+/// Entries are keyed by the credential sent with the request. Requests
+/// whose credential is not known up front, those of clients using a
+/// [`KeyPool`](crate::KeyPool) or
+/// [`AuthStrategy::SingleUseToken`](crate::AuthStrategy::SingleUseToken),
+/// bypass the cache.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use elevenlabs_sdk::{CacheConfig, ClientConfig, ElevenLabsClient, MemoryCacheStore};
+///
+/// # async fn example() -> elevenlabs_sdk::Result<()> {
+/// let cache = CacheConfig::new(MemoryCacheStore::new())
+///     .with_default_rules()
+///     .ttl("/v1/shared-voices", Duration::from_secs(60));
+/// let client =
+///     ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?.with_cache(cache);
+///
+/// // Fetched from the API once, then served from the cache for five minutes.
+/// let models = client.models().list().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    store: Arc<dyn CacheStore>,
+    rules: Vec<CacheRule>,
+}
+
+/// A path prefix or endpoint pattern and the TTL of the GETs it matches.
+#[derive(Debug, Clone)]
+struct CacheRule {
+    path: String,
+    /// Whether `path` is an endpoint pattern rather than a prefix.
+    exact: bool,
+    ttl: Duration,
+}
+
+impl CacheRule {
+    /// Returns `true` if the rule covers a GET of `path`.
+    fn matches(&self, path: &str) -> bool {
+        if !self.exact {
+            return path.starts_with(self.path.as_str());
+        }
+        let path = path.split_once('?').map_or(path, |(path, _)| path);
+        path.split('/').count() == self.path.split('/').count() &&
+            path.split('/')
+                .zip(self.path.split('/'))
+                .all(|(segment, pattern)| pattern == "*" || pattern == segment)
+    }
+
+    /// Returns the literal part of the rule before its first `*` segment.
+    /// Entries are grouped by scope, and writes under a scope drop its
+    /// group.
+    fn scope(&self) -> &str {
+        self.path.split_once("/*").map_or(self.path.as_str(), |(scope, _)| scope)
+    }
+}
+
+impl CacheConfig {
+    /// Creates a configuration with no cached paths.
+    ///
+    /// Add paths with [`ttl`](Self::ttl) or
+    /// [`with_default_rules`](Self::with_default_rules).
+    pub fn new(store: impl CacheStore + 'static) -> Self {
+        Self::with_store(Arc::new(store))
+    }
+
+    /// Creates a configuration backed by a shared store.
+    pub fn with_store(store: Arc<dyn CacheStore>) -> Self {
+        Self { store, rules: Vec::new() }
+    }
+
+    /// Caches model lists, voice lists, voices, voice settings and
+    /// pronunciation dictionary metadata for [`DEFAULT_CACHE_TTL`].
+    ///
+    /// Binary endpoints under the same paths, such as sample audio or
+    /// dictionary downloads, are not cached.
+    pub fn with_default_rules(mut self) -> Self {
+        for endpoint in DEFAULT_CACHED_ENDPOINTS {
+            self = self.ttl_endpoint(*endpoint, DEFAULT_CACHE_TTL);
+        }
+        self
+    }
+
+    /// Caches GET requests under `path_prefix` for `ttl`, replacing any
+    /// existing rule for the same path.
+    pub fn ttl(self, path_prefix: impl Into<String>, ttl: Duration) -> Self {
+        self.rule(path_prefix.into(), false, ttl)
+    }
+
+    /// Caches GET requests to `endpoint` for `ttl`, replacing any existing
+    /// rule for the same path.
+    ///
+    /// The request path, without its query, must match `endpoint` exactly,
+    /// except that a `*` segment matches any single segment:
+    /// `/v1/voices/*` matches `/v1/voices/abc` but not
+    /// `/v1/voices/abc/samples/def/audio`.
+    pub fn ttl_endpoint(self, endpoint: impl Into<String>, ttl: Duration) -> Self {
+        self.rule(endpoint.into(), true, ttl)
+    }
+
+    fn rule(mut self, path: String, exact: bool, ttl: Duration) -> Self {
+        self.rules.retain(|rule| rule.path != path);
+        self.rules.push(CacheRule { path, exact, ttl });
+        self
+    }
+
+    /// Returns the underlying store.
+    pub fn store(&self) -> &dyn CacheStore {
+        self.store.as_ref()
+    }
+
+    /// Reads the entry stored under `key` on the blocking thread pool.
+    pub(crate) async fn load(&self, key: &str) -> Option<CachedResponse> {
+        let store = Arc::clone(&self.store);
+        let key = key.to_owned();
+        tokio::task::spawn_blocking(move || store.get(&key)).await.ok().flatten()
+    }
+
+    /// Stores `entry` under `key` on the blocking thread pool.
+    pub(crate) async fn save(&self, key: &str, entry: CachedResponse) {
+        let store = Arc::clone(&self.store);
+        let key = key.to_owned();
+        if let Err(error) = tokio::task::spawn_blocking(move || store.put(&key, entry)).await {
+            tracing::debug!(%error, "failed to write cache entry");
+        }
+    }
+
+    /// Drops the cached entries a successful write to `path` may have made
+    /// stale, on the blocking thread pool.
+    pub(crate) async fn invalidate(&self, path: &str) {
+        let mut groups: Vec<_> = self
+            .rules
+            .iter()
+            .map(CacheRule::scope)
+            .filter(|scope| path.starts_with(scope))
+            .map(scope_group)
+            .collect();
+        if groups.is_empty() {
+            return;
+        }
+        groups.dedup();
+        tracing::debug!(path, "invalidating cached GETs after write");
+        let store = Arc::clone(&self.store);
+        let removed = tokio::task::spawn_blocking(move || {
+            for group in groups {
+                store.remove_prefix(&group);
+            }
+        })
+        .await;
+        if let Err(error) = removed {
+            tracing::debug!(%error, "failed to invalidate cache entries");
+        }
+    }
+
+    /// Returns the TTL for `path`, or `None` if it should not be cached.
+    pub fn ttl_for(&self, path: &str) -> Option<Duration> {
+        self.rule_for(path).map(|rule| rule.ttl)
+    }
+
+    /// Returns the scope and TTL of the rule covering a GET of `path`, or
+    /// `None` if it should not be cached.
+    pub(crate) fn scope_and_ttl(&self, path: &str) -> Option<(&str, Duration)> {
+        self.rule_for(path).map(|rule| (rule.scope(), rule.ttl))
+    }
+
+    /// Returns the longest rule matching `path`.
+    fn rule_for(&self, path: &str) -> Option<&CacheRule> {
+        self.rules.iter().filter(|rule| rule.matches(path)).max_by_key(|rule| rule.path.len())
+    }
+}
+
+/// Returns the key prefix shared by every entry cached under `scope`.
+fn scope_group(scope: &str) -> String {
+    let digest = Sha256::digest(scope);
+    format!("{}-", hex(&digest[..8]))
+}
+
+/// Derives the store key for a request made with `credential` (an API key
+/// or bearer token) to `url` under the rule scope `scope`.
+///
+/// The credential is part of the key because responses (e.g. voice lists)
+/// are account-specific. The scope prefix lets writes drop a whole group.
+pub(crate) fn cache_key(scope: &str, credential: &str, url: &str) -> String {
+    let digest =
+        Sha256::new().chain_update(credential).chain_update(b"\n").chain_update(url).finalize();
+    format!("{}{}", scope_group(scope), hex(&digest))
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ttl_for_uses_longest_matching_prefix() {
+        let config = CacheConfig::new(MemoryCacheStore::new())
+            .ttl("/v1/voices", Duration::from_secs(10))
+            .ttl("/v1/voices/settings", Duration::from_secs(99));
+
+        assert_eq!(config.ttl_for("/v1/voices?page_size=10"), Some(Duration::from_secs(10)));
+        assert_eq!(config.ttl_for("/v1/voices/settings/default"), Some(Duration::from_secs(99)));
+        assert_eq!(config.ttl_for("/v1/history"), None);
+    }
+
+    #[test]
+    fn ttl_replaces_existing_rule() {
+        let config = CacheConfig::new(MemoryCacheStore::new())
+            .with_default_rules()
+            .ttl("/v1/models", Duration::from_secs(1));
+        assert_eq!(config.ttl_for("/v1/models"), Some(Duration::from_secs(1)));
+        assert_eq!(config.ttl_for("/v1/voices"), Some(DEFAULT_CACHE_TTL));
+    }
+
+    #[test]
+    fn default_rules_cover_metadata_but_not_binary_endpoints() {
+        let config = CacheConfig::new(MemoryCacheStore::new()).with_default_rules();
+
+        assert_eq!(config.ttl_for("/v1/voices?show_legacy=true"), Some(DEFAULT_CACHE_TTL));
+        assert_eq!(config.ttl_for("/v1/voices/abc"), Some(DEFAULT_CACHE_TTL));
+        assert_eq!(config.ttl_for("/v1/voices/abc/settings"), Some(DEFAULT_CACHE_TTL));
+        assert_eq!(config.ttl_for("/v1/voices/settings/default"), Some(DEFAULT_CACHE_TTL));
+        assert_eq!(config.ttl_for("/v1/pronunciation-dictionaries/d1"), Some(DEFAULT_CACHE_TTL));
+        assert_eq!(config.ttl_for("/v1/voices/abc/samples/s1/audio"), None);
+        assert_eq!(config.ttl_for("/v1/pronunciation-dictionaries/d1/v1/download"), None);
+    }
+
+    #[test]
+    fn cache_key_depends_on_api_key_and_url() {
+        let a = cache_key("/v1/models", "key-a", "https://api.elevenlabs.io/v1/models");
+        let b = cache_key("/v1/models", "key-b", "https://api.elevenlabs.io/v1/models");
+        let c = cache_key("/v1/models", "key-a", "https://api.elevenlabs.io/v1/voices");
+        assert_eq!(a.len(), 81);
+        assert!(a.starts_with(&scope_group("/v1/models")));
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a, cache_key("/v1/models", "key-a", "https://api.elevenlabs.io/v1/models"));
+    }
+
+    #[test]
+    fn cached_response_freshness() {
+        let mut entry = CachedResponse::new(Bytes::from_static(b"{}"), None);
+        assert!(entry.is_fresh(Duration::from_mins(1)));
+        entry.stored_at = SystemTime::now() - Duration::from_mins(2);
+        assert!(!entry.is_fresh(Duration::from_mins(1)));
+    }
+
+    #[test]
+    fn memory_store_round_trip() {
+        let store = MemoryCacheStore::new();
+        store.put("k", CachedResponse::new(Bytes::from_static(b"body"), Some("\"v1\"".into())));
+        assert_eq!(store.get("k").unwrap().body, Bytes::from_static(b"body"));
+        assert_eq!(store.len(), 1);
+        store.remove("k");
+        assert!(store.get("k").is_none());
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalidate_drops_entries_under_the_written_scope() {
+        let store = Arc::new(MemoryCacheStore::new());
+        let config =
+            CacheConfig::with_store(Arc::clone(&store) as Arc<dyn CacheStore>).with_default_rules();
+        let voice = cache_key("/v1/voices", "key", "https://api.elevenlabs.io/v1/voices/abc");
+        let model = cache_key("/v1/models", "key", "https://api.elevenlabs.io/v1/models");
+        store.put(&voice, CachedResponse::new(Bytes::from_static(b"{}"), None));
+        store.put(&model, CachedResponse::new(Bytes::from_static(b"[]"), None));
+
+        config.invalidate("/v1/voices/abc/settings/edit").await;
+
+        assert!(store.get(&voice).is_none());
+        assert!(store.get(&model).is_some());
+    }
+
+    #[test]
+    fn disk_store_round_trip() {
+        let dir =
+            std::env::temp_dir().join(format!("elevenlabs-cache-test-{}", std::process::id()));
+        let store = DiskCacheStore::new(&dir);
+        let entry = CachedResponse::new(Bytes::from_static(b"[1,2,3]"), Some("\"abc\"".into()));
+        store.put("k", entry.clone());
+        let files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        assert_eq!(files, ["k.json"]);
+
+        let loaded = store.get("k").unwrap();
+        assert_eq!(loaded.body, entry.body);
+        assert_eq!(loaded.etag, entry.etag);
+
+        store.clear();
+        assert!(store.get("k").is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,76 @@
+//! Idempotency keys and the retry policy for non-idempotent requests.
+//!
+//! A POST or PATCH that fails with a server error or times out may still have
+//! been carried out, so retrying it blindly can, say, dispatch a batch of
+//! calls twice. The client therefore only retries such a request after a
+//! `429` (which the API rejects before doing any work), unless the caller
+//! sets [`RequestOptions::idempotency_key`](crate::RequestOptions::idempotency_key)
+//! or opts in with [`RequestOptions::retry_unsafe`](crate::RequestOptions::retry_unsafe).
+//!
+//! A call that opts in without its own key gets a generated one, sent in the
+//! `Idempotency-Key` header and re-sent on every attempt, so a server that
+//! deduplicates on it sees one logical request. The API reference does not
+//! document deduplication for any endpoint, so keys are never generated for
+//! calls that have not opted in.
+
+use std::{
+    hash::{BuildHasher, RandomState},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use hpx::Method;
+
+/// Returns `true` if repeating a request with this method has the same effect
+/// as sending it once (RFC 9110 §9.2.2).
+pub(crate) fn is_idempotent_method(method: &Method) -> bool {
+    [Method::GET, Method::HEAD, Method::OPTIONS, Method::PUT, Method::DELETE].contains(method)
+}
+
+/// Generates a random (version 4) UUID to use as an idempotency key.
+pub(crate) fn generate_key() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+    let seed = (nanos, COUNTER.fetch_add(1, Ordering::Relaxed));
+    let state = RandomState::new();
+    let high = state.hash_one((seed, 0_u8));
+    let low = state.hash_one((seed, 1_u8));
+
+    let high = (high & !0xf000) | 0x4000;
+    let low = (low & !(0b11 << 62)) | (0b10 << 62);
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        high >> 32,
+        (high >> 16) & 0xffff,
+        high & 0xffff,
+        low >> 48,
+        low & 0xffff_ffff_ffff
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_unsafe_methods_need_protection() {
+        assert!(is_idempotent_method(&Method::GET));
+        assert!(is_idempotent_method(&Method::PUT));
+        assert!(is_idempotent_method(&Method::DELETE));
+        assert!(!is_idempotent_method(&Method::POST));
+        assert!(!is_idempotent_method(&Method::PATCH));
+    }
+
+    #[test]
+    fn generated_keys_are_distinct_v4_uuids() {
+        let a = generate_key();
+        let b = generate_key();
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 36);
+        let groups: Vec<&str> = a.split('-').collect();
+        assert_eq!(groups.iter().map(|g| g.len()).collect::<Vec<_>>(), [8, 4, 4, 4, 12]);
+        assert!(groups[2].starts_with('4'));
+        assert!(matches!(groups[3].chars().next(), Some('8' | '9' | 'a' | 'b')));
+    }
+}
@@ -0,0 +1,244 @@
+//! Metrics hooks for exporting request and WebSocket statistics.
+//!
+//! Register a [`MetricsSink`] with
+//! [`ClientConfigBuilder::metrics_sink`](crate::ClientConfigBuilder::metrics_sink)
+//! to receive one [`RequestMetrics`] per HTTP call and a [`WsEvent`] per
+//! WebSocket frame, then forward them to Prometheus, StatsD or any other
+//! backend. [`PoolStats`] summarises how a client has been using its
+//! connections.
+
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+/// Receives metrics from the client.
+///
+/// Methods are called inline on the request path, so implementations should
+/// only update counters or histograms and return quickly.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{
+///     Arc,
+///     atomic::{AtomicU64, Ordering},
+/// };
+///
+/// use elevenlabs_sdk::{ClientConfig, MetricsSink, RequestMetrics};
+///
+/// #[derive(Debug, Default)]
+/// struct ErrorCounter(AtomicU64);
+///
+/// impl MetricsSink for ErrorCounter {
+///     fn record_request(&self, metrics: &RequestMetrics) {
+///         if !metrics.is_success() {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let config = ClientConfig::builder("your-api-key")
+///     .metrics_sink(Arc::new(ErrorCounter::default()))
+///     .build();
+/// assert!(config.metrics_sink.is_some());
+/// ```
+pub trait MetricsSink: Send + Sync + fmt::Debug {
+    /// Called once per HTTP call, after the final attempt.
+    fn record_request(&self, metrics: &RequestMetrics);
+
+    /// Called for WebSocket lifecycle events and frames. Does nothing by
+    /// default.
+    fn record_ws_event(&self, endpoint: &str, event: &WsEvent) {
+        let _ = (endpoint, event);
+    }
+}
+
+/// Statistics for one HTTP call, including any retries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestMetrics {
+    /// HTTP method, e.g. `"POST"`.
+    pub method: String,
+    /// Request path without the query string, e.g.
+    /// `"/v1/text-to-speech/{voice_id}"` with the ID filled in.
+    pub endpoint: String,
+    /// Status of the final response, or `None` if no response was received
+    /// (timeout or transport error).
+    pub status: Option<u16>,
+    /// Number of retries performed before the final attempt.
+    pub retries: u32,
+    /// Time from the first attempt until the final response headers (or
+    /// error) arrived.
+    pub latency: Duration,
+    /// Size of the request body in bytes.
+    pub bytes_sent: u64,
+    /// Size of the response body from its `Content-Length` header, if known.
+    /// Streamed responses usually have none.
+    pub bytes_received: Option<u64>,
+    /// The final response's `request-id` header, if present.
+    pub request_id: Option<String>,
+}
+
+impl RequestMetrics {
+    /// Returns `true` if a `2xx` response was received.
+    pub fn is_success(&self) -> bool {
+        self.status.is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+/// A WebSocket event reported to [`MetricsSink::record_ws_event`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsEvent {
+    /// The connection was established.
+    Connected {
+        /// Time taken to connect.
+        latency: Duration,
+    },
+    /// A frame was sent.
+    MessageSent {
+        /// Frame payload size in bytes.
+        bytes: u64,
+    },
+    /// A frame was received.
+    MessageReceived {
+        /// Frame payload size in bytes.
+        bytes: u64,
+    },
+    /// The connection was closed by either side.
+    Closed,
+}
+
+/// Connection usage of a client, from
+/// [`ElevenLabsClient::pool_stats`](crate::ElevenLabsClient::pool_stats).
+///
+/// The HTTP client does not expose its pool, so these counters are kept by
+/// the SDK around each attempt. `in_flight` counts attempts waiting for
+/// response headers; a streamed body that is still being read no longer
+/// counts. Comparing `peak_in_flight` with the responses per HTTP version
+/// shows whether concurrent requests are multiplexed over HTTP/2 or each
+/// need an HTTP/1.1 connection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Attempts sent, retries included.
+    pub requests: u64,
+    /// Attempts currently waiting for response headers.
+    pub in_flight: u64,
+    /// Highest `in_flight` seen so far.
+    pub peak_in_flight: u64,
+    /// Responses received over HTTP/1.x.
+    pub http1_responses: u64,
+    /// Responses received over HTTP/2.
+    pub http2_responses: u64,
+    /// Attempts that failed without a response (timeouts, connection
+    /// errors).
+    pub transport_errors: u64,
+}
+
+/// Counters behind [`PoolStats`], shared by a client and its scoped copies.
+#[derive(Debug, Default)]
+pub(crate) struct PoolCounters {
+    requests: AtomicU64,
+    in_flight: AtomicU64,
+    peak_in_flight: AtomicU64,
+    http1_responses: AtomicU64,
+    http2_responses: AtomicU64,
+    transport_errors: AtomicU64,
+}
+
+impl PoolCounters {
+    /// Counts an attempt as started until the returned guard is dropped.
+    pub(crate) fn start(&self) -> InFlight<'_> {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let in_flight = self.in_flight.fetch_add(1, Ordering::Relaxed) + 1;
+        self.peak_in_flight.fetch_max(in_flight, Ordering::Relaxed);
+        InFlight(self)
+    }
+
+    /// Records how an attempt ended: with a response over `version`, or
+    /// with no response if `None`.
+    pub(crate) fn finish(&self, version: Option<hpx::Version>) {
+        let counter = match version {
+            Some(hpx::Version::HTTP_2) => &self.http2_responses,
+            Some(_) => &self.http1_responses,
+            None => &self.transport_errors,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current values.
+    pub(crate) fn snapshot(&self) -> PoolStats {
+        PoolStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+            peak_in_flight: self.peak_in_flight.load(Ordering::Relaxed),
+            http1_responses: self.http1_responses.load(Ordering::Relaxed),
+            http2_responses: self.http2_responses.load(Ordering::Relaxed),
+            transport_errors: self.transport_errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Marks an attempt as in flight while alive, including when its future is
+/// dropped mid-request.
+#[derive(Debug)]
+pub(crate) struct InFlight<'a>(&'a PoolCounters);
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(status: Option<u16>) -> RequestMetrics {
+        RequestMetrics {
+            method: "GET".to_owned(),
+            endpoint: "/v1/models".to_owned(),
+            status,
+            retries: 0,
+            latency: Duration::ZERO,
+            bytes_sent: 0,
+            bytes_received: None,
+            request_id: None,
+        }
+    }
+
+    #[test]
+    fn is_success_only_for_2xx() {
+        assert!(metrics(Some(200)).is_success());
+        assert!(metrics(Some(204)).is_success());
+        assert!(!metrics(Some(304)).is_success());
+        assert!(!metrics(Some(500)).is_success());
+        assert!(!metrics(None).is_success());
+    }
+
+    #[test]
+    fn pool_counters_track_in_flight_and_versions() {
+        let counters = PoolCounters::default();
+        let first = counters.start();
+        let second = counters.start();
+        assert_eq!(counters.snapshot().in_flight, 2);
+        drop(first);
+        counters.finish(Some(hpx::Version::HTTP_2));
+        drop(second);
+        counters.finish(None);
+        let _third = counters.start();
+        counters.finish(Some(hpx::Version::HTTP_11));
+
+        assert_eq!(
+            counters.snapshot(),
+            PoolStats {
+                requests: 3,
+                in_flight: 1,
+                peak_in_flight: 2,
+                http1_responses: 1,
+                http2_responses: 1,
+                transport_errors: 1,
+            }
+        );
+    }
+}
@@ -1,12 +1,29 @@
 //! Retry middleware utilities for the ElevenLabs SDK.
 //!
 //! Provides helpers for determining whether a failed HTTP request should be
-//! retried and computing the appropriate delay between attempts.
+//! retried and computing the appropriate delay between attempts. The optional
+//! GET response cache lives in the `cache` submodule, request recording and
+//! replay in `recorder`, the metrics hooks in `metrics`, the character quota
+//! guard in `quota`, idempotency keys for retried POSTs in `idempotency`,
+//! and the endpoint categories used for per-category timeouts in
+//! `timeouts`.
+
+#[cfg(feature = "cache")]
+pub(crate) mod cache;
+pub(crate) mod idempotency;
+pub(crate) mod metrics;
+pub(crate) mod quota;
+#[cfg(feature = "record-replay")]
+pub(crate) mod recorder;
+pub(crate) mod timeouts;
 
 use std::time::Duration;
 
 use hpx::StatusCode;
 
+#[cfg(any(feature = "tts", feature = "convai"))]
+use crate::error::ElevenLabsError;
+
 /// Maximum delay cap for retry backoff (30 seconds).
 const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
 
@@ -59,6 +76,30 @@ pub(crate) fn compute_delay(
     delay.min(MAX_RETRY_DELAY)
 }
 
+/// Returns `true` if an operation that failed with `error` may succeed when
+/// tried again. Used by the batch helpers that retry whole jobs on top of the
+/// per-request retries.
+#[cfg(any(feature = "tts", feature = "convai"))]
+pub(crate) const fn is_transient(error: &ElevenLabsError) -> bool {
+    match error {
+        ElevenLabsError::Api { status, .. } => *status >= 500,
+        ElevenLabsError::RateLimited { .. } |
+        ElevenLabsError::Timeout |
+        ElevenLabsError::Transport(_) |
+        ElevenLabsError::Io(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns `true` if a queued request that failed with `error` should stay
+/// queued for a later flush: the failure is [transient](is_transient), the
+/// quota guard held the request back, or the flush was cancelled.
+#[cfg(feature = "offline-queue")]
+pub(crate) const fn should_requeue(error: &ElevenLabsError) -> bool {
+    is_transient(error) ||
+        matches!(error, ElevenLabsError::QuotaWouldExceed { .. } | ElevenLabsError::Cancelled)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,6 +145,15 @@ mod tests {
         assert_eq!(compute_delay(0, Duration::from_secs(10), Some(5)), Duration::from_secs(10));
     }
 
+    #[cfg(feature = "offline-queue")]
+    #[test]
+    fn should_requeue_keeps_cancelled_and_local_failures_queued() {
+        assert!(should_requeue(&ElevenLabsError::Cancelled));
+        assert!(should_requeue(&ElevenLabsError::Io(std::io::ErrorKind::BrokenPipe.into())));
+        assert!(should_requeue(&ElevenLabsError::QuotaWouldExceed { requested: 10, remaining: 5 }));
+        assert!(!should_requeue(&ElevenLabsError::Validation("bad".to_owned())));
+    }
+
     #[test]
     fn compute_delay_retry_after_capped_at_30s() {
         let base = Duration::from_millis(100);
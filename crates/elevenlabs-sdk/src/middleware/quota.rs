@@ -0,0 +1,237 @@
+//! Pre-flight character quota guard.
+//!
+//! A [`QuotaGuard`] attached with
+//! [`ElevenLabsClient::with_quota_guard`](crate::ElevenLabsClient::with_quota_guard)
+//! keeps an estimate of the characters left in the billing period: the
+//! remaining quota is fetched from
+//! [`UserService::remaining_quota`](crate::services::UserService::remaining_quota)
+//! every [`refresh_interval`](QuotaGuard::refresh_interval), and the
+//! characters of every request sent since are subtracted from it. A
+//! text-to-speech or speech-to-speech request that would use more than the
+//! estimate is refused locally with [`ElevenLabsError::QuotaWouldExceed`]
+//! instead of failing on the server or using up the last of the budget.
+//!
+//! Nothing is refused while the account can extend its limit, or while the
+//! subscription reports no limit at all.
+//!
+//! Set [`RequestOptions::skip_quota_guard`](crate::RequestOptions::skip_quota_guard)
+//! to send a request regardless.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, ElevenLabsError, QuotaGuard};
+//!
+//! # async fn example() -> elevenlabs_sdk::Result<()> {
+//! let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?
+//!     .with_quota_guard(QuotaGuard::new().refresh_interval(Duration::from_secs(60)));
+//!
+//! let request = elevenlabs_sdk::types::TextToSpeechRequest::new("Hello!");
+//! let result = client.text_to_speech().convert("voice_id", &request, None, None).await;
+//! if let Err(ElevenLabsError::QuotaWouldExceed { remaining, .. }) = result {
+//!     println!("only {remaining} characters left");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    audio,
+    error::{ElevenLabsError, Result},
+    types::RemainingQuota,
+};
+
+/// How often the remaining quota is fetched unless overridden with
+/// [`QuotaGuard::refresh_interval`].
+pub const DEFAULT_QUOTA_REFRESH_INTERVAL: Duration = Duration::from_mins(5);
+
+/// Characters a minute of speech-to-speech audio is billed as.
+const STS_CHARACTERS_PER_MINUTE: u128 = 1_000;
+
+/// Refuses requests that would use more characters than remain in the
+/// quota.
+///
+/// See the [module documentation](self) for how the estimate is kept.
+#[derive(Debug)]
+pub struct QuotaGuard {
+    refresh_interval: Duration,
+    state: Mutex<QuotaState>,
+}
+
+/// Estimate as of the last refresh.
+#[derive(Debug, Default)]
+struct QuotaState {
+    /// Characters remaining at the last refresh.
+    remaining: i64,
+    /// Characters submitted since the last refresh.
+    submitted: i64,
+    /// When the quota was last fetched, or `None` before the first fetch.
+    refreshed_at: Option<Instant>,
+    /// Whether the last fetched quota is a hard limit worth checking.
+    enforced: bool,
+}
+
+impl Default for QuotaGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuotaGuard {
+    /// Creates a guard that fetches the quota every
+    /// [`DEFAULT_QUOTA_REFRESH_INTERVAL`].
+    pub fn new() -> Self {
+        Self {
+            refresh_interval: DEFAULT_QUOTA_REFRESH_INTERVAL,
+            state: Mutex::new(QuotaState::default()),
+        }
+    }
+
+    /// Sets how often the remaining quota is fetched.
+    #[must_use]
+    pub const fn refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Returns the estimated characters remaining, or `None` before the
+    /// quota has been fetched and while the limit is not enforced.
+    pub fn remaining(&self) -> Option<i64> {
+        let state = self.lock();
+        (state.refreshed_at.is_some() && state.enforced)
+            .then(|| (state.remaining - state.submitted).max(0))
+    }
+
+    /// Returns `true` if the quota has never been fetched or is older than
+    /// the refresh interval.
+    pub(crate) fn needs_refresh(&self, now: Instant) -> bool {
+        self.lock()
+            .refreshed_at
+            .is_none_or(|at| now.saturating_duration_since(at) >= self.refresh_interval)
+    }
+
+    /// Replaces the estimate with a freshly fetched quota.
+    pub(crate) fn update(&self, quota: &RemainingQuota, now: Instant) {
+        *self.lock() = QuotaState {
+            remaining: quota.characters_remaining,
+            submitted: 0,
+            refreshed_at: Some(now),
+            enforced: !quota.can_extend && quota.character_limit > 0,
+        };
+    }
+
+    /// Counts `characters` against the estimate, or refuses them if they do
+    /// not fit.
+    pub(crate) fn reserve(&self, characters: i64) -> Result<()> {
+        let mut state = self.lock();
+        let remaining = (state.remaining - state.submitted).max(0);
+        if state.enforced && characters > remaining {
+            return Err(ElevenLabsError::QuotaWouldExceed { requested: characters, remaining });
+        }
+        state.submitted += characters;
+        Ok(())
+    }
+
+    /// Counts `characters` against the estimate without checking them.
+    pub(crate) fn record(&self, characters: i64) {
+        self.lock().submitted += characters;
+    }
+
+    /// Locks the state, recovering it if a holder panicked.
+    fn lock(&self) -> std::sync::MutexGuard<'_, QuotaState> {
+        self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Estimates the characters a speech-to-speech conversion of `audio` is
+/// billed as, from the duration of WAV or MP3 input.
+///
+/// Other input, or input whose duration cannot be read, counts as a single
+/// character, so that it is still refused once the quota is used up.
+pub(crate) fn sts_characters(audio: &[u8]) -> usize {
+    let duration = match audio::sniff_content_type(audio) {
+        Some("audio/wav") => audio::wav_duration(audio),
+        Some("audio/mpeg") => audio::mp3_duration(audio),
+        _ => None,
+    };
+    let characters = duration
+        .map_or(0, |duration| (duration.as_millis() * STS_CHARACTERS_PER_MINUTE).div_ceil(60_000));
+    usize::try_from(characters).unwrap_or(usize::MAX).max(1)
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    fn quota(characters_remaining: i64) -> RemainingQuota {
+        RemainingQuota {
+            character_count: 1_000 - characters_remaining,
+            character_limit: 1_000,
+            characters_remaining,
+            next_reset_unix: None,
+            can_extend: false,
+        }
+    }
+
+    #[test]
+    fn reserve_counts_characters_until_refresh() {
+        let guard = QuotaGuard::new().refresh_interval(Duration::from_mins(1));
+        let start = Instant::now();
+        assert!(guard.needs_refresh(start));
+        assert_eq!(guard.remaining(), None);
+
+        guard.update(&quota(100), start);
+        assert!(!guard.needs_refresh(start + Duration::from_secs(59)));
+        assert!(guard.needs_refresh(start + Duration::from_mins(1)));
+
+        assert!(guard.reserve(60).is_ok());
+        assert_eq!(guard.remaining(), Some(40));
+        assert!(matches!(
+            guard.reserve(41),
+            Err(ElevenLabsError::QuotaWouldExceed { requested: 41, remaining: 40 })
+        ));
+        assert!(guard.reserve(40).is_ok());
+        assert_eq!(guard.remaining(), Some(0));
+        guard.record(10);
+        assert_eq!(guard.remaining(), Some(0));
+
+        guard.update(&quota(500), start);
+        assert_eq!(guard.remaining(), Some(500));
+    }
+
+    #[test]
+    fn reserve_allows_anything_when_limit_is_extendable() {
+        let guard = QuotaGuard::new();
+        guard.update(&RemainingQuota { can_extend: true, ..quota(0) }, Instant::now());
+        assert!(guard.reserve(5_000).is_ok());
+        assert_eq!(guard.remaining(), None);
+    }
+
+    #[test]
+    fn reserve_allows_anything_when_limit_is_missing() {
+        let guard = QuotaGuard::new();
+        let missing = RemainingQuota { character_count: 0, character_limit: 0, ..quota(0) };
+        guard.update(&missing, Instant::now());
+        assert!(guard.reserve(5_000).is_ok());
+        assert_eq!(guard.remaining(), None);
+
+        guard.update(&quota(10), Instant::now());
+        assert!(guard.reserve(11).is_err());
+    }
+
+    #[test]
+    fn sts_characters_follow_audio_duration() {
+        // 3 seconds of 16 kHz mono PCM.
+        let wav = audio::pcm_to_wav(&vec![0; 96_000], 16_000, 1).unwrap();
+        assert_eq!(sts_characters(&wav), 50);
+        assert_eq!(sts_characters(b"not audio"), 1);
+    }
+}
@@ -0,0 +1,404 @@
+//! Request recording and replay for offline tests.
+//!
+//! Enabled with the `record-replay` feature. A [`Cassette`] in record mode
+//! captures every API response a client receives; saved to a JSON fixture
+//! file, it can later answer the same requests without a network:
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use elevenlabs_sdk::{Cassette, ClientConfig, ElevenLabsClient};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Once, against the real API:
+//! let cassette = Arc::new(Cassette::record("tests/fixtures/voices.json"));
+//! let client = ElevenLabsClient::new(ClientConfig::from_env()?)?.with_cassette(cassette.clone());
+//! client.voices().list(None).await?;
+//! cassette.save()?;
+//!
+//! // In tests, with any key:
+//! let cassette = Arc::new(Cassette::replay("tests/fixtures/voices.json")?);
+//! let client =
+//!     ElevenLabsClient::new(ClientConfig::builder("test").build())?.with_cassette(cassette);
+//! let voices = client.voices().list(None).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Sanitizing
+//!
+//! Fixtures store the request path relative to the base URL, never request
+//! headers. The client's API keys are replaced with `REDACTED` wherever they
+//! appear, as are the values of JSON fields and query parameters named like
+//! credentials, and response headers that carry cookies or credentials are
+//! dropped.
+//!
+//! # Matching
+//!
+//! A request is answered by the first interaction not yet replayed with the
+//! same method and path, including the query string. Request bodies are not
+//! compared, since multipart boundaries differ on every call. WebSocket
+//! sessions are neither recorded nor replayed.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ElevenLabsError, Result};
+
+/// Replacement for secrets removed from fixtures.
+const REDACTED: &str = "REDACTED";
+
+/// JSON fields and query parameters whose values are always redacted.
+const SECRET_NAMES: &[&str] = &["xi_api_key", "api_key", "token", "secret", "password"];
+
+/// Whether a [`Cassette`] captures or answers requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests to the API and record the responses.
+    Record,
+    /// Answer requests from recorded responses without sending them.
+    Replay,
+}
+
+/// A response body in a fixture file: JSON bodies stay readable, anything
+/// else is base64-encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedBody {
+    /// A JSON body.
+    Json(serde_json::Value),
+    /// A binary or non-JSON body, base64-encoded.
+    Base64(String),
+}
+
+impl RecordedBody {
+    /// Stores `body`, redacting `secrets`.
+    fn new(body: &[u8], secrets: &[&str]) -> Self {
+        match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(mut json) if !body.is_empty() => {
+                redact_json(&mut json, secrets);
+                Self::Json(json)
+            }
+            _ => Self::Base64(STANDARD.encode(body)),
+        }
+    }
+
+    /// Returns the raw body bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if a base64 body is malformed.
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        match self {
+            Self::Json(json) => Ok(serde_json::to_vec(json)?.into()),
+            Self::Base64(b64) => STANDARD.decode(b64).map(Bytes::from).map_err(|e| {
+                ElevenLabsError::Validation(format!("invalid base64 body in cassette: {e}"))
+            }),
+        }
+    }
+}
+
+/// One recorded request and its response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// HTTP method, e.g. `GET`.
+    pub method: String,
+    /// Request path and query, relative to the base URL.
+    pub path: String,
+    /// Response status code.
+    pub status: u16,
+    /// Response headers, lowercased.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Response body.
+    pub body: RecordedBody,
+}
+
+/// On-disk form of a [`Cassette`].
+#[derive(Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// Mutable part of a [`Cassette`].
+#[derive(Debug, Default)]
+struct State {
+    interactions: Vec<Interaction>,
+    /// Which interactions have been replayed.
+    played: Vec<bool>,
+}
+
+/// A fixture file of recorded API interactions.
+///
+/// Attach one to a client with
+/// [`ElevenLabsClient::with_cassette`](crate::ElevenLabsClient::with_cassette).
+/// Requires the `record-replay` feature.
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<State>,
+}
+
+impl fmt::Debug for Cassette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cassette")
+            .field("path", &self.path)
+            .field("mode", &self.mode)
+            .field("interactions", &self.lock().interactions.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Cassette {
+    /// Creates an empty cassette that records into `path` when
+    /// [`save`](Self::save)d.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), mode: CassetteMode::Record, state: Mutex::default() }
+    }
+
+    /// Loads a cassette recorded at `path` for replay.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Io`] if the file cannot be read, or
+    /// [`ElevenLabsError::Deserialization`] if it is not a cassette.
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let file: CassetteFile = serde_json::from_slice(&std::fs::read(&path)?)?;
+        let played = vec![false; file.interactions.len()];
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay,
+            state: Mutex::new(State { interactions: file.interactions, played }),
+        })
+    }
+
+    /// Returns the fixture file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether requests are recorded or replayed.
+    pub const fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns the interactions recorded or loaded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().interactions.clone()
+    }
+
+    /// Returns `true` once every loaded interaction has been replayed.
+    pub fn is_exhausted(&self) -> bool {
+        self.lock().played.iter().all(|&played| played)
+    }
+
+    /// Writes the recorded interactions to [`path`](Self::path), creating
+    /// its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Io`] if the file cannot be written.
+    pub fn save(&self) -> Result<()> {
+        let file = CassetteFile { interactions: self.interactions() };
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut json = serde_json::to_vec_pretty(&file)?;
+        json.push(b'\n');
+        std::fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    /// Appends a response, redacting `secrets` and credential fields.
+    pub(crate) fn push(
+        &self,
+        method: &str,
+        path: &str,
+        status: u16,
+        headers: &[(&str, &str)],
+        body: &[u8],
+        secrets: &[&str],
+    ) {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| !is_secret_header(name))
+            .map(|(name, value)| (name.to_ascii_lowercase(), redact(value, secrets)))
+            .collect();
+        let interaction = Interaction {
+            method: method.to_owned(),
+            path: redact_path(path, secrets),
+            status,
+            headers,
+            body: RecordedBody::new(body, secrets),
+        };
+        let mut state = self.lock();
+        state.interactions.push(interaction);
+        state.played.push(false);
+    }
+
+    /// Takes the next unplayed interaction for `method` and `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if there is none.
+    pub(crate) fn next(&self, method: &str, path: &str) -> Result<Interaction> {
+        let mut state = self.lock();
+        let State { interactions, played } = &mut *state;
+        let index = interactions
+            .iter()
+            .zip(played.iter())
+            .position(|(i, &done)| !done && i.method == method && i.path == path)
+            .ok_or_else(|| {
+                ElevenLabsError::Validation(format!(
+                    "cassette {} has no unplayed interaction for {method} {path}",
+                    self.path.display()
+                ))
+            })?;
+        played[index] = true;
+        Ok(interactions[index].clone())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Returns `true` for response headers that are never recorded.
+fn is_secret_header(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name == "set-cookie" ||
+        name == "cookie" ||
+        ["key", "token", "authorization"].iter().any(|part| name.contains(part))
+}
+
+/// Returns `true` for JSON fields and query parameters that hold secrets.
+fn is_secret_name(name: &str) -> bool {
+    SECRET_NAMES.iter().any(|secret| name.eq_ignore_ascii_case(secret))
+}
+
+/// Replaces every occurrence of `secrets` in `text`.
+fn redact(text: &str, secrets: &[&str]) -> String {
+    secrets
+        .iter()
+        .filter(|secret| !secret.is_empty())
+        .fold(text.to_owned(), |text, secret| text.replace(secret, REDACTED))
+}
+
+/// Redacts `secrets` and credential query parameters in a path and query.
+fn redact_path(path: &str, secrets: &[&str]) -> String {
+    let path = redact(path, secrets);
+    let Some((path, query)) = path.split_once('?') else {
+        return path;
+    };
+    let query: Vec<String> = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, _)) if is_secret_name(name) => format!("{name}={REDACTED}"),
+            _ => pair.to_owned(),
+        })
+        .collect();
+    format!("{path}?{}", query.join("&"))
+}
+
+/// Redacts `secrets` and credential fields throughout `json`.
+fn redact_json(json: &mut serde_json::Value, secrets: &[&str]) {
+    match json {
+        serde_json::Value::String(text) => *text = redact(text, secrets),
+        serde_json::Value::Array(items) => {
+            for item in items {
+                redact_json(item, secrets);
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            for (name, value) in fields {
+                if is_secret_name(name) && value.is_string() {
+                    *value = REDACTED.into();
+                } else {
+                    redact_json(value, secrets);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_redacts_secrets() {
+        let cassette = Cassette::record("unused.json");
+        let body = br#"{"token":"tok_1","owner":"sk_live","items":[{"note":"key sk_live"}]}"#;
+        cassette.push(
+            "GET",
+            "/v1/thing?xi_api_key=sk_live&page=2",
+            200,
+            &[
+                ("Content-Type", "application/json"),
+                ("Set-Cookie", "session=1"),
+                ("xi-api-key", "sk_live"),
+            ],
+            body,
+            &["sk_live"],
+        );
+
+        let recorded = &cassette.interactions()[0];
+        assert_eq!(recorded.path, "/v1/thing?xi_api_key=REDACTED&page=2");
+        assert_eq!(
+            recorded.headers,
+            BTreeMap::from([("content-type".to_owned(), "application/json".to_owned())])
+        );
+        assert_eq!(
+            recorded.body,
+            RecordedBody::Json(serde_json::json!({
+                "token": "REDACTED",
+                "owner": "REDACTED",
+                "items": [{"note": "key REDACTED"}]
+            }))
+        );
+    }
+
+    #[test]
+    fn binary_bodies_round_trip() {
+        let body = RecordedBody::new(b"\xff\xfbaudio", &[]);
+        assert!(matches!(body, RecordedBody::Base64(_)));
+        assert_eq!(body.to_bytes().unwrap(), &b"\xff\xfbaudio"[..]);
+        assert!(matches!(RecordedBody::new(b"", &[]), RecordedBody::Base64(_)));
+    }
+
+    #[test]
+    fn replay_takes_interactions_in_order() {
+        let path = std::env::temp_dir()
+            .join(format!("elevenlabs-cassette-{}", std::process::id()))
+            .join("cassette.json");
+        let recorder = Cassette::record(&path);
+        recorder.push("GET", "/v1/a", 200, &[], br#"{"n":1}"#, &[]);
+        recorder.push("GET", "/v1/a", 200, &[], br#"{"n":2}"#, &[]);
+        recorder.push("POST", "/v1/b", 201, &[], b"", &[]);
+        recorder.save().unwrap();
+
+        let cassette = Cassette::replay(&path).unwrap();
+        assert_eq!(cassette.mode(), CassetteMode::Replay);
+        assert_eq!(cassette.next("POST", "/v1/b").unwrap().status, 201);
+        let first = cassette.next("GET", "/v1/a").unwrap();
+        assert_eq!(first.body, RecordedBody::Json(serde_json::json!({"n": 1})));
+        assert!(!cassette.is_exhausted());
+        let second = cassette.next("GET", "/v1/a").unwrap();
+        assert_eq!(second.body, RecordedBody::Json(serde_json::json!({"n": 2})));
+        assert!(cassette.is_exhausted());
+        assert!(matches!(cassette.next("GET", "/v1/a"), Err(ElevenLabsError::Validation(_))));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
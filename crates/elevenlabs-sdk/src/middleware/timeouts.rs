@@ -0,0 +1,92 @@
+//! Endpoint classification for per-category request timeouts.
+//!
+//! The client picks a [`TimeoutCategory`] for every request and asks the
+//! configured [`TimeoutProfile`](crate::TimeoutProfile) for the matching
+//! per-attempt timeout. JSON GETs are fast reads and multipart requests are
+//! uploads; the POSTs in [`LONG_RUNNING_ENDPOINTS`] are long-running, even
+//! when they are multipart uploads too.
+
+use hpx::Method;
+
+use crate::config::TimeoutCategory;
+
+/// POST endpoints that routinely take minutes to answer. `*` matches one
+/// path segment.
+const LONG_RUNNING_ENDPOINTS: &[&str] = &[
+    "/v1/dubbing",
+    "/v1/music",
+    "/v1/music/detailed",
+    "/v1/music/stream",
+    "/v1/studio/podcasts",
+    "/v1/studio/projects/*/convert",
+    "/v1/studio/projects/*/chapters/*/convert",
+];
+
+/// Returns the category of a request to `path` that would otherwise be in
+/// `fallback`.
+pub(crate) fn classify(method: &Method, path: &str, fallback: TimeoutCategory) -> TimeoutCategory {
+    if *method == Method::POST && is_long_running(path) {
+        TimeoutCategory::LongRunning
+    } else {
+        fallback
+    }
+}
+
+/// Returns `true` if `path`, ignoring any query string, matches one of
+/// [`LONG_RUNNING_ENDPOINTS`].
+fn is_long_running(path: &str) -> bool {
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    LONG_RUNNING_ENDPOINTS.iter().any(|pattern| {
+        let mut segments = path.split('/');
+        let mut expected = pattern.split('/');
+        loop {
+            match (segments.next(), expected.next()) {
+                (None, None) => return true,
+                (Some(segment), Some("*")) if !segment.is_empty() => {}
+                (Some(segment), Some(literal)) if segment == literal => {}
+                _ => return false,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_running_posts_are_recognised() {
+        for path in [
+            "/v1/dubbing",
+            "/v1/music?output_format=mp3_44100_128",
+            "/v1/studio/projects/p1/convert",
+            "/v1/studio/projects/p1/chapters/c1/convert",
+        ] {
+            assert_eq!(
+                classify(&Method::POST, path, TimeoutCategory::Default),
+                TimeoutCategory::LongRunning,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn other_requests_keep_their_fallback() {
+        assert_eq!(
+            classify(&Method::GET, "/v1/dubbing", TimeoutCategory::FastRead),
+            TimeoutCategory::FastRead
+        );
+        assert_eq!(
+            classify(&Method::POST, "/v1/dubbing/d1/audio", TimeoutCategory::Upload),
+            TimeoutCategory::Upload
+        );
+        assert_eq!(
+            classify(&Method::POST, "/v1/studio/projects//convert", TimeoutCategory::Default),
+            TimeoutCategory::Default
+        );
+        assert_eq!(
+            classify(&Method::POST, "/v1/music/plan", TimeoutCategory::Default),
+            TimeoutCategory::Default
+        );
+    }
+}
@@ -0,0 +1,790 @@
+//! Disk-backed queue for synthesis requests made while offline
+//! (`offline-queue` feature).
+//!
+//! [`OfflineQueue`] keeps text-to-speech and sound-generation requests in a
+//! local directory until the API can be reached, then sends them in the
+//! order they were queued and writes each result to the file named when the
+//! request was queued. It is meant for kiosks and embedded devices whose
+//! connectivity comes and goes: a request made during an outage survives a
+//! restart and is delivered once the network is back.
+//!
+//! Each job is a small JSON file named after its sequence number and the
+//! SHA-256 of its request, written atomically through `tokio::fs`. Queuing a
+//! request that is already pending returns the pending job instead of adding
+//! a second one, so a button pressed twice is only paid for once.
+//!
+//! [`flush`](OfflineQueue::flush) stops at the first error that suggests the
+//! network or API is unavailable (a transport error, timeout, rate limit or
+//! 5xx response) and leaves that job and the ones behind it queued. Requests
+//! the API rejects for good are moved to a `failed` subdirectory so they do
+//! not hold up the rest. [`run`](OfflineQueue::run) flushes on an interval
+//! until cancelled.
+//!
+//! A queue directory should be used by one process at a time.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use elevenlabs_sdk::{
+//!     CancellationToken, ClientConfig, ElevenLabsClient,
+//!     offline::{OfflineQueue, QueuedRequest, Submission},
+//!     types::TextToSpeechRequest,
+//! };
+//!
+//! # async fn example() -> elevenlabs_sdk::Result<()> {
+//! let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+//! let queue = OfflineQueue::open("/var/lib/kiosk/tts-queue").await?;
+//!
+//! let request = QueuedRequest::text_to_speech(
+//!     "voice_id",
+//!     &TextToSpeechRequest::new("Welcome! Please scan your ticket."),
+//!     None,
+//! )?;
+//! match queue.submit(&client, request, "/var/lib/kiosk/audio/welcome.mp3").await? {
+//!     Submission::Written(path) => println!("ready: {}", path.display()),
+//!     Submission::Queued { id, .. } => println!("offline, queued as job {id}"),
+//!     Submission::Duplicate { job, .. } => println!("already queued as job {}", job.id),
+//! }
+//!
+//! // Deliver queued jobs in the background until shutdown.
+//! let shutdown = CancellationToken::new();
+//! queue.run(&client, Duration::from_secs(30), &shutdown).await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    client::ElevenLabsClient,
+    download::hex,
+    error::{ElevenLabsError, Result},
+    middleware,
+    services::TextToSpeechService,
+    types::{OutputFormat, SoundGenerationRequest, TextToSpeechRequest},
+};
+
+/// Name of the subdirectory rejected jobs are moved to.
+const FAILED_DIR: &str = "failed";
+
+// ---------------------------------------------------------------------------
+// Requests & jobs
+// ---------------------------------------------------------------------------
+
+/// A request that can be queued, with its body already serialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QueuedRequest {
+    /// `POST /v1/text-to-speech/{voice_id}`.
+    TextToSpeech {
+        /// Voice to synthesize with.
+        voice_id: String,
+        /// Audio format; the API default when `None`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        output_format: Option<OutputFormat>,
+        /// Serialized [`TextToSpeechRequest`].
+        body: serde_json::Value,
+    },
+    /// `POST /v1/sound-generation`.
+    SoundGeneration {
+        /// Serialized [`SoundGenerationRequest`].
+        body: serde_json::Value,
+    },
+}
+
+impl QueuedRequest {
+    /// Creates a text-to-speech request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `request` cannot be serialized.
+    pub fn text_to_speech(
+        voice_id: impl Into<String>,
+        request: &TextToSpeechRequest,
+        output_format: Option<OutputFormat>,
+    ) -> Result<Self> {
+        Ok(Self::TextToSpeech {
+            voice_id: voice_id.into(),
+            output_format,
+            body: serde_json::to_value(request)?,
+        })
+    }
+
+    /// Creates a sound-generation request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `request` cannot be serialized.
+    pub fn sound_generation(request: &SoundGenerationRequest) -> Result<Self> {
+        Ok(Self::SoundGeneration { body: serde_json::to_value(request)? })
+    }
+
+    /// Returns the hex SHA-256 of the request, used to detect duplicates.
+    ///
+    /// Object keys are sorted before hashing, so equal requests always hash
+    /// the same even when serde_json's `preserve_order` feature is enabled
+    /// elsewhere in the dependency graph.
+    pub fn fingerprint(&self) -> String {
+        let mut canonical = serde_json::to_value(self).unwrap_or_default();
+        canonical.sort_all_objects();
+        hex(&Sha256::digest(canonical.to_string()))
+    }
+
+    /// Sends the request and returns the audio.
+    async fn send(&self, client: &ElevenLabsClient) -> Result<Bytes> {
+        match self {
+            Self::TextToSpeech { voice_id, output_format, body } => {
+                let path = TextToSpeechService::build_path(voice_id, "", *output_format, None);
+                let text = body.get("text").and_then(serde_json::Value::as_str).unwrap_or_default();
+                client.guard_quota(text.chars().count()).await?;
+                client.post_bytes(&path, body).await
+            }
+            Self::SoundGeneration { body } => client.post_bytes("/v1/sound-generation", body).await,
+        }
+    }
+}
+
+/// A job waiting in an [`OfflineQueue`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueuedJob {
+    /// Sequence number; jobs are sent in increasing order.
+    pub id: u64,
+    /// [`QueuedRequest::fingerprint`] of the request.
+    pub fingerprint: String,
+    /// The request to send.
+    pub request: QueuedRequest,
+    /// File the audio is written to once the request succeeds.
+    pub output: PathBuf,
+    /// When the job was queued.
+    pub enqueued_at: SystemTime,
+    /// Number of sends that failed without the job being dropped.
+    pub attempts: u32,
+}
+
+/// On-disk representation of a [`QueuedJob`].
+#[derive(Serialize, Deserialize)]
+struct JobFile {
+    request: QueuedRequest,
+    output: PathBuf,
+    /// Queue time in Unix seconds.
+    enqueued_at: u64,
+    #[serde(default)]
+    attempts: u32,
+    /// Why the API rejected the job; only set on jobs in `failed/`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl JobFile {
+    fn new(job: &QueuedJob, error: Option<String>) -> Self {
+        let enqueued_at =
+            job.enqueued_at.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs());
+        Self {
+            request: job.request.clone(),
+            output: job.output.clone(),
+            enqueued_at,
+            attempts: job.attempts,
+            error,
+        }
+    }
+}
+
+/// Result of [`OfflineQueue::enqueue`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Enqueued {
+    /// The request was added to the queue.
+    New(QueuedJob),
+    /// An identical request was already pending; nothing was added. The
+    /// pending job keeps its own output path.
+    Duplicate(QueuedJob),
+}
+
+impl Enqueued {
+    /// Returns the queued job, new or pre-existing.
+    pub const fn job(&self) -> &QueuedJob {
+        match self {
+            Self::New(job) | Self::Duplicate(job) => job,
+        }
+    }
+}
+
+/// Result of [`OfflineQueue::submit`].
+#[derive(Debug)]
+pub enum Submission {
+    /// The request was sent and its audio written to this path.
+    Written(PathBuf),
+    /// The request is queued and will be sent by a later flush.
+    Queued {
+        /// Sequence number of the queued job.
+        id: u64,
+        /// Error that stopped the flush, if one did.
+        reason: Option<ElevenLabsError>,
+    },
+    /// An identical request was already pending, so nothing was queued and
+    /// the output path passed to [`submit`](OfflineQueue::submit) is not
+    /// written. The pending job was flushed with the rest of the queue.
+    Duplicate {
+        /// The pending job; its audio goes to `job.output`.
+        job: QueuedJob,
+        /// `true` if the flush sent the job and its audio is now at
+        /// `job.output`.
+        written: bool,
+    },
+}
+
+/// A job the API rejected during a flush.
+#[derive(Debug)]
+pub struct FailedJob {
+    /// The job, now stored under the queue's `failed` subdirectory.
+    pub job: QueuedJob,
+    /// The error the API returned.
+    pub error: ElevenLabsError,
+}
+
+/// Outcome of [`OfflineQueue::flush`].
+#[derive(Debug, Default)]
+pub struct FlushReport {
+    /// Jobs sent successfully, in the order they were sent.
+    pub sent: Vec<QueuedJob>,
+    /// Jobs the API rejected; they were moved out of the queue.
+    pub failed: Vec<FailedJob>,
+    /// Error that stopped the flush early, if the network or API was
+    /// unavailable.
+    pub interrupted: Option<ElevenLabsError>,
+    /// Jobs still queued after the flush.
+    pub remaining: usize,
+}
+
+// ---------------------------------------------------------------------------
+// Queue
+// ---------------------------------------------------------------------------
+
+/// Directory-backed queue of synthesis requests. See the
+/// [module documentation](self).
+#[derive(Debug)]
+pub struct OfflineQueue {
+    dir: PathBuf,
+    /// Next sequence number to hand out; held while enqueuing so that the
+    /// duplicate check and the write happen together.
+    next_id: tokio::sync::Mutex<u64>,
+    /// Held while flushing so that concurrent flushes do not send a job
+    /// twice.
+    flushing: tokio::sync::Mutex<()>,
+}
+
+impl OfflineQueue {
+    /// Opens the queue stored in `dir`, creating the directory if needed.
+    /// Jobs left by an earlier process are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created or read.
+    pub async fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        tokio::fs::create_dir_all(&dir).await?;
+        let next_id = job_files(&dir).await?.last().map_or(1, |(id, _, _)| id + 1);
+        Ok(Self {
+            dir,
+            next_id: tokio::sync::Mutex::new(next_id),
+            flushing: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    /// Returns the directory jobs are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Adds `request` to the end of the queue. Its audio will be written to
+    /// `output` when it is sent.
+    ///
+    /// If an identical request is already pending, that job is returned as
+    /// [`Enqueued::Duplicate`] and nothing is added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the job file cannot be written.
+    pub async fn enqueue(
+        &self,
+        request: QueuedRequest,
+        output: impl Into<PathBuf>,
+    ) -> Result<Enqueued> {
+        let fingerprint = request.fingerprint();
+        let mut next_id = self.next_id.lock().await;
+        if let Some(job) =
+            self.pending().await?.into_iter().find(|job| job.fingerprint == fingerprint)
+        {
+            return Ok(Enqueued::Duplicate(job));
+        }
+        let job = QueuedJob {
+            id: *next_id,
+            fingerprint,
+            request,
+            output: output.into(),
+            enqueued_at: SystemTime::now(),
+            attempts: 0,
+        };
+        self.write_job(&self.dir, &job, None).await?;
+        *next_id += 1;
+        Ok(Enqueued::New(job))
+    }
+
+    /// Returns the pending jobs in the order they will be sent.
+    ///
+    /// Job files that cannot be read are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the queue directory cannot be read.
+    pub async fn pending(&self) -> Result<Vec<QueuedJob>> {
+        let mut jobs = Vec::new();
+        for (id, fingerprint, path) in job_files(&self.dir).await? {
+            jobs.extend(read_job(id, fingerprint, &path).await);
+        }
+        Ok(jobs)
+    }
+
+    /// Returns the number of pending jobs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the queue directory cannot be read.
+    pub async fn len(&self) -> Result<usize> {
+        Ok(job_files(&self.dir).await?.len())
+    }
+
+    /// Returns `true` if no jobs are pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the queue directory cannot be read.
+    pub async fn is_empty(&self) -> Result<bool> {
+        Ok(self.len().await? == 0)
+    }
+
+    /// Removes a pending job without sending it. Returns `false` if there is
+    /// no such job.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the job file cannot be removed.
+    pub async fn remove(&self, id: u64) -> Result<bool> {
+        let Some((_, _, path)) =
+            job_files(&self.dir).await?.into_iter().find(|(job, _, _)| *job == id)
+        else {
+            return Ok(false);
+        };
+        remove_file(&path).await?;
+        Ok(true)
+    }
+
+    /// Sends pending jobs in order, writing each result to its output file
+    /// and removing the job once written.
+    ///
+    /// Stops at the first error that suggests the network or API is
+    /// unavailable, a local failure, a quota shortfall or cancellation,
+    /// leaving that job and the rest queued; the error is reported in
+    /// [`FlushReport::interrupted`]. Jobs the API rejects for
+    /// any other reason are moved to the `failed` subdirectory and reported
+    /// in [`FlushReport::failed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the queue directory cannot be read or an output
+    /// file cannot be written. The job being processed stays queued.
+    pub async fn flush(&self, client: &ElevenLabsClient) -> Result<FlushReport> {
+        let _flushing = self.flushing.lock().await;
+        let mut report = FlushReport::default();
+        let jobs = job_files(&self.dir).await?;
+        let total = jobs.len();
+        for (id, fingerprint, path) in jobs {
+            let Some(mut job) = read_job(id, fingerprint, &path).await else {
+                continue;
+            };
+            match job.request.send(client).await {
+                Ok(audio) => {
+                    write_atomic(&job.output, &audio).await?;
+                    remove_file(&path).await?;
+                    report.sent.push(job);
+                }
+                Err(error) if middleware::should_requeue(&error) => {
+                    job.attempts += 1;
+                    self.write_job(&self.dir, &job, None).await?;
+                    report.interrupted = Some(error);
+                    break;
+                }
+                Err(error) => {
+                    tracing::warn!(%error, id, "offline queue job rejected");
+                    job.attempts += 1;
+                    self.write_job(&self.dir.join(FAILED_DIR), &job, Some(error.to_string()))
+                        .await?;
+                    remove_file(&path).await?;
+                    report.failed.push(FailedJob { job, error });
+                }
+            }
+        }
+        report.remaining = total - report.sent.len() - report.failed.len();
+        Ok(report)
+    }
+
+    /// Queues `request` and flushes, so it is sent straight away when the
+    /// API is reachable and no earlier job is stuck ahead of it.
+    ///
+    /// If an identical request is already pending, the queue is flushed and
+    /// [`Submission::Duplicate`] reports the pending job, whose output path
+    /// is used instead of `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the API's error if the request itself was rejected (the job
+    /// is moved to the `failed` subdirectory), or an error if the queue
+    /// cannot be read or written.
+    pub async fn submit(
+        &self,
+        client: &ElevenLabsClient,
+        request: QueuedRequest,
+        output: impl Into<PathBuf>,
+    ) -> Result<Submission> {
+        let enqueued = self.enqueue(request, output).await?;
+        let id = enqueued.job().id;
+        let report = self.flush(client).await?;
+        let sent = report.sent.into_iter().find(|job| job.id == id);
+        if let Some(failed) = report.failed.into_iter().find(|failed| failed.job.id == id) {
+            return Err(failed.error);
+        }
+        match (enqueued, sent) {
+            (Enqueued::Duplicate(job), sent) => {
+                Ok(Submission::Duplicate { job, written: sent.is_some() })
+            }
+            (Enqueued::New(_), Some(job)) => Ok(Submission::Written(job.output)),
+            (Enqueued::New(_), None) => Ok(Submission::Queued { id, reason: report.interrupted }),
+        }
+    }
+
+    /// Flushes the queue every `interval` until `cancel` is cancelled.
+    ///
+    /// Flush errors are logged and retried on the next tick.
+    pub async fn run(
+        &self,
+        client: &ElevenLabsClient,
+        interval: Duration,
+        cancel: &CancellationToken,
+    ) {
+        loop {
+            match self.flush(client).await {
+                Ok(report) => {
+                    if let Some(error) = report.interrupted {
+                        tracing::debug!(%error, remaining = report.remaining, "offline queue waiting");
+                    }
+                }
+                Err(error) => tracing::warn!(%error, "offline queue flush failed"),
+            }
+            tokio::select! {
+                () = cancel.cancelled() => return,
+                () = tokio::time::sleep(interval) => {}
+            }
+        }
+    }
+
+    /// Writes `job` into `dir` under its file name, replacing any previous
+    /// version.
+    async fn write_job(&self, dir: &Path, job: &QueuedJob, error: Option<String>) -> Result<()> {
+        tokio::fs::create_dir_all(dir).await?;
+        let path = dir.join(job_file_name(job.id, &job.fingerprint));
+        let tmp = path.with_extension("tmp");
+        tokio::fs::write(&tmp, serde_json::to_vec(&JobFile::new(job, error))?).await?;
+        tokio::fs::rename(&tmp, &path).await?;
+        tracing::trace!(id = job.id, dir = %self.dir.display(), "offline queue job written");
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Returns the file name of a job: `{id:020}-{fingerprint}.json`, so that
+/// name order is queue order.
+fn job_file_name(id: u64, fingerprint: &str) -> String {
+    format!("{id:020}-{fingerprint}.json")
+}
+
+/// Lists the job files in `dir` as `(id, fingerprint, path)`, in queue
+/// order.
+async fn job_files(dir: &Path) -> Result<Vec<(u64, String, PathBuf)>> {
+    let mut jobs = Vec::new();
+    let mut entries = tokio::fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let Some(stem) = path.file_name().and_then(|name| name.to_str()?.strip_suffix(".json"))
+        else {
+            continue;
+        };
+        let Some((id, fingerprint)) = stem.split_once('-') else {
+            continue;
+        };
+        if let Ok(id) = id.parse() {
+            jobs.push((id, fingerprint.to_owned(), path));
+        }
+    }
+    jobs.sort_unstable_by_key(|(id, _, _)| *id);
+    Ok(jobs)
+}
+
+/// Reads a job file, logging and skipping files that cannot be parsed.
+async fn read_job(id: u64, fingerprint: String, path: &Path) -> Option<QueuedJob> {
+    let file: JobFile = match tokio::fs::read(path)
+        .await
+        .map_err(ElevenLabsError::from)
+        .and_then(|raw| Ok(serde_json::from_slice(&raw)?))
+    {
+        Ok(file) => file,
+        Err(error) => {
+            tracing::warn!(%error, path = %path.display(), "skipping unreadable offline queue job");
+            return None;
+        }
+    };
+    Some(QueuedJob {
+        id,
+        fingerprint,
+        request: file.request,
+        output: file.output,
+        enqueued_at: UNIX_EPOCH + Duration::from_secs(file.enqueued_at),
+        attempts: file.attempts,
+    })
+}
+
+/// Removes `path`; a file that is already gone is not an error.
+async fn remove_file(path: &Path) -> Result<()> {
+    match tokio::fs::remove_file(path).await {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Writes `data` to `path` through a temporary file, creating parent
+/// directories as needed.
+async fn write_atomic(path: &Path, data: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".part");
+    tokio::fs::write(&tmp, data).await?;
+    tokio::fs::rename(&tmp, path).await?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_partial_json, method, path, query_param},
+    };
+
+    use super::*;
+    use crate::config::ClientConfig;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("elevenlabs-offline-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn tts(text: &str) -> QueuedRequest {
+        QueuedRequest::text_to_speech("v1", &TextToSpeechRequest::new(text), None).unwrap()
+    }
+
+    #[test]
+    fn fingerprint_ignores_key_order() {
+        let body = |pairs: &[(&str, serde_json::Value)]| {
+            serde_json::Value::Object(
+                pairs.iter().map(|(key, value)| ((*key).to_owned(), value.clone())).collect(),
+            )
+        };
+        let settings = serde_json::json!({"stability": 0.5, "similarity_boost": 0.75});
+        let a = QueuedRequest::SoundGeneration {
+            body: body(&[("text", "rain".into()), ("settings", settings.clone())]),
+        };
+        let b = QueuedRequest::SoundGeneration {
+            body: body(&[("settings", settings), ("text", "rain".into())]),
+        };
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_ne!(a.fingerprint(), tts("rain").fingerprint());
+    }
+
+    #[tokio::test]
+    async fn enqueue_orders_and_dedupes() {
+        let dir = temp_dir("enqueue");
+        let queue = OfflineQueue::open(&dir).await.unwrap();
+        let first = queue.enqueue(tts("one"), dir.join("one.mp3")).await.unwrap();
+        let second = queue.enqueue(tts("two"), dir.join("two.mp3")).await.unwrap();
+        let again = queue.enqueue(tts("one"), dir.join("other.mp3")).await.unwrap();
+
+        assert!(matches!(first, Enqueued::New(_)));
+        assert!(matches!(again, Enqueued::Duplicate(ref job) if job.id == first.job().id));
+        assert_eq!(again.job().output, dir.join("one.mp3"));
+        assert_eq!(queue.len().await.unwrap(), 2);
+
+        // Reopening keeps the jobs and continues the sequence.
+        drop(queue);
+        let queue = OfflineQueue::open(&dir).await.unwrap();
+        let ids: Vec<u64> = queue.pending().await.unwrap().iter().map(|job| job.id).collect();
+        assert_eq!(ids, [first.job().id, second.job().id]);
+        let third = queue.enqueue(tts("three"), dir.join("three.mp3")).await.unwrap();
+        assert_eq!(third.job().id, second.job().id + 1);
+
+        assert!(queue.remove(second.job().id).await.unwrap());
+        assert!(!queue.remove(second.job().id).await.unwrap());
+        assert_eq!(queue.len().await.unwrap(), 2);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn flush_sends_in_order_and_keeps_jobs_while_offline() {
+        let mock_server = MockServer::start().await;
+        let dir = temp_dir("flush");
+        let config =
+            ClientConfig::builder("test-key").base_url(mock_server.uri()).max_retries(0).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let queue = OfflineQueue::open(&dir).await.unwrap();
+        queue.enqueue(tts("one"), dir.join("out/one.mp3")).await.unwrap();
+        queue
+            .enqueue(
+                QueuedRequest::sound_generation(&SoundGenerationRequest {
+                    text: "rain".into(),
+                    ..Default::default()
+                })
+                .unwrap(),
+                dir.join("out/rain.mp3"),
+            )
+            .await
+            .unwrap();
+
+        // The API is down: nothing is sent and both jobs stay queued.
+        let outage = Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount_as_scoped(&mock_server)
+            .await;
+        let report = queue.flush(&client).await.unwrap();
+        assert!(report.sent.is_empty());
+        assert!(report.interrupted.is_some());
+        assert_eq!(report.remaining, 2);
+        assert_eq!(queue.pending().await.unwrap()[0].attempts, 1);
+        drop(outage);
+
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/v1"))
+            .and(body_partial_json(serde_json::json!({"text": "one"})))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"speech".to_vec()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/sound-generation"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(
+                serde_json::json!({"detail": {"status": "invalid", "message": "bad prompt"}}),
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let report = queue.flush(&client).await.unwrap();
+        assert_eq!(report.sent.len(), 1);
+        assert_eq!(report.failed.len(), 1);
+        assert!(report.interrupted.is_none());
+        assert_eq!(report.remaining, 0);
+        assert_eq!(std::fs::read(dir.join("out/one.mp3")).unwrap(), b"speech");
+        assert!(!dir.join("out/rain.mp3").exists());
+        assert!(queue.is_empty().await.unwrap());
+        assert_eq!(std::fs::read_dir(dir.join(FAILED_DIR)).unwrap().count(), 1);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn submit_writes_output_when_online() {
+        let mock_server = MockServer::start().await;
+        let dir = temp_dir("submit");
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/v1"))
+            .and(query_param("output_format", "pcm_16000"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"pcm".to_vec()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let queue = OfflineQueue::open(&dir).await.unwrap();
+
+        let request = QueuedRequest::text_to_speech(
+            "v1",
+            &TextToSpeechRequest::new("hi"),
+            Some(OutputFormat::Pcm_16000),
+        )
+        .unwrap();
+        let submission = queue.submit(&client, request, dir.join("hi.pcm")).await.unwrap();
+
+        assert!(matches!(submission, Submission::Written(ref path) if *path == dir.join("hi.pcm")));
+        assert_eq!(std::fs::read(dir.join("hi.pcm")).unwrap(), b"pcm");
+        assert!(queue.is_empty().await.unwrap());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn submit_reports_duplicate_with_pending_output() {
+        let mock_server = MockServer::start().await;
+        let dir = temp_dir("submit-duplicate");
+        let config =
+            ClientConfig::builder("test-key").base_url(mock_server.uri()).max_retries(0).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let queue = OfflineQueue::open(&dir).await.unwrap();
+        queue.enqueue(tts("hi"), dir.join("first.mp3")).await.unwrap();
+
+        // Offline: the duplicate is not queued and reports the pending job.
+        let outage = Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount_as_scoped(&mock_server)
+            .await;
+        let submission = queue.submit(&client, tts("hi"), dir.join("second.mp3")).await.unwrap();
+        assert!(matches!(
+            submission,
+            Submission::Duplicate { ref job, written: false } if job.output == dir.join("first.mp3")
+        ));
+        assert_eq!(queue.len().await.unwrap(), 1);
+        drop(outage);
+
+        // Online: the pending job is sent to its own output path only.
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"speech".to_vec()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let submission = queue.submit(&client, tts("hi"), dir.join("second.mp3")).await.unwrap();
+        assert!(matches!(
+            submission,
+            Submission::Duplicate { ref job, written: true } if job.output == dir.join("first.mp3")
+        ));
+        assert_eq!(std::fs::read(dir.join("first.mp3")).unwrap(), b"speech");
+        assert!(!dir.join("second.mp3").exists());
+        assert!(queue.is_empty().await.unwrap());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,372 @@
+//! Reading and writing PLS (Pronunciation Lexicon Specification) files.
+//!
+//! Pronunciation dictionaries are downloaded with
+//! [`StudioService::download_pronunciation_dictionary_version`](crate::services::StudioService::download_pronunciation_dictionary_version)
+//! as PLS XML. [`Lexicon::parse`] reads such a file into typed
+//! [`PronunciationRule`]s and [`Lexicon::to_xml`] writes rules back out,
+//! so a dictionary can be kept in version control and uploaded with
+//! [`StudioService::create_pronunciation_dictionary_from_file`](crate::services::StudioService::create_pronunciation_dictionary_from_file).
+//! [`diff`] compares two sets of rules, such as two dictionary versions.
+//!
+//! Only the parts of PLS the API uses are supported: lexemes with one or
+//! more graphemes and an alias or a phoneme. Each grapheme becomes one
+//! rule.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, pls};
+//!
+//! # async fn example() -> elevenlabs_sdk::Result<()> {
+//! let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+//! let studio = client.studio();
+//! let old = studio.get_pronunciation_dictionary_version_rules("dict_id", "v1").await?;
+//! let new = studio.get_pronunciation_dictionary_version_rules("dict_id", "v2").await?;
+//!
+//! let changes = pls::diff(&old, &new);
+//! for rule in &changes.added {
+//!     println!("+ {}", rule.string_to_replace());
+//! }
+//! for rule in &changes.removed {
+//!     println!("- {}", rule.string_to_replace());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::BTreeMap, fmt::Write as _};
+
+use crate::{
+    error::{ElevenLabsError, Result},
+    types::{PhonemeAlphabet, PronunciationRule},
+};
+
+/// Language written to lexicons created with [`Lexicon::new`].
+pub const DEFAULT_LANGUAGE: &str = "en-US";
+
+/// Content type of PLS files, for uploading them.
+pub const PLS_CONTENT_TYPE: &str = "application/pls+xml";
+
+/// The rules of a PLS file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lexicon {
+    /// Language of the lexicon (`xml:lang`), e.g. `en-US`.
+    pub language: String,
+    /// Alphabet of phonemes that do not name their own.
+    pub alphabet: PhonemeAlphabet,
+    /// The rules, in document order.
+    pub rules: Vec<PronunciationRule>,
+}
+
+impl Lexicon {
+    /// Creates an IPA lexicon in [`DEFAULT_LANGUAGE`] holding `rules`.
+    pub fn new(rules: Vec<PronunciationRule>) -> Self {
+        Self { language: DEFAULT_LANGUAGE.to_owned(), alphabet: PhonemeAlphabet::Ipa, rules }
+    }
+
+    /// Sets the language of the lexicon.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = language.into();
+        self
+    }
+
+    /// Sets the alphabet of phonemes that do not name their own.
+    pub const fn alphabet(mut self, alphabet: PhonemeAlphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Parses a PLS document.
+    ///
+    /// A lexeme's `<alias>` makes alias rules and its `<phoneme>` phoneme
+    /// rules, in the alphabet of the phoneme element or else of the
+    /// lexicon (IPA if neither names one). A missing `xml:lang` is read as
+    /// [`DEFAULT_LANGUAGE`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Deserialization`] if the document has no
+    /// `<lexicon>` element, a lexeme has no grapheme or neither an alias nor
+    /// a phoneme, or an alphabet is unknown.
+    pub fn parse(xml: &str) -> Result<Self> {
+        let (lexicon_attrs, body) =
+            element(xml, "lexicon").ok_or_else(|| pls_error("missing <lexicon> element"))?;
+        let alphabet = parse_alphabet(attribute(lexicon_attrs, "alphabet"), PhonemeAlphabet::Ipa)?;
+        let language = attribute(lexicon_attrs, "xml:lang").map_or(DEFAULT_LANGUAGE, str::trim);
+
+        let mut rules = Vec::new();
+        for (_, lexeme) in elements(body, "lexeme") {
+            let start = rules.len();
+            let graphemes = elements(lexeme, "grapheme").map(|(_, text)| unescape(text.trim()));
+            if let Some((_, alias)) = element(lexeme, "alias") {
+                let alias = unescape(alias.trim());
+                rules.extend(graphemes.map(|g| PronunciationRule::alias(g, alias.clone())));
+            } else if let Some((attrs, phoneme)) = element(lexeme, "phoneme") {
+                let alphabet = parse_alphabet(attribute(attrs, "alphabet"), alphabet)?;
+                let phoneme = unescape(phoneme.trim());
+                rules.extend(
+                    graphemes.map(|g| PronunciationRule::phoneme(g, phoneme.clone(), alphabet)),
+                );
+            } else {
+                return Err(pls_error("lexeme without <alias> or <phoneme>"));
+            }
+            if rules.len() == start {
+                return Err(pls_error("lexeme without <grapheme>"));
+            }
+        }
+        Ok(Self { language: unescape(language), alphabet, rules })
+    }
+
+    /// Renders the lexicon as a PLS document with one lexeme per rule.
+    ///
+    /// Phonemes in another alphabet than the lexicon's carry an `alphabet`
+    /// attribute.
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<lexicon version=\"1.0\" xmlns=\"http://www.w3.org/2005/01/pronunciation-lexicon\" \
+             alphabet=\"{}\" xml:lang=\"{}\">",
+            self.alphabet,
+            escape(&self.language),
+        );
+        for rule in &self.rules {
+            xml.push_str("  <lexeme>\n");
+            let _ = writeln!(xml, "    <grapheme>{}</grapheme>", escape(rule.string_to_replace()));
+            match rule {
+                PronunciationRule::Alias { alias, .. } => {
+                    let _ = writeln!(xml, "    <alias>{}</alias>", escape(alias));
+                }
+                PronunciationRule::Phoneme { phoneme, alphabet, .. }
+                    if *alphabet == self.alphabet =>
+                {
+                    let _ = writeln!(xml, "    <phoneme>{}</phoneme>", escape(phoneme));
+                }
+                PronunciationRule::Phoneme { phoneme, alphabet, .. } => {
+                    let _ = writeln!(
+                        xml,
+                        "    <phoneme alphabet=\"{alphabet}\">{}</phoneme>",
+                        escape(phoneme)
+                    );
+                }
+            }
+            xml.push_str("  </lexeme>\n");
+        }
+        xml.push_str("</lexicon>\n");
+        xml
+    }
+}
+
+/// Differences between two sets of rules, returned by [`diff`].
+///
+/// Each list is sorted by the string the rules replace.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RulesDiff {
+    /// Rules only in the new set.
+    pub added: Vec<PronunciationRule>,
+    /// Rules only in the old set.
+    pub removed: Vec<PronunciationRule>,
+    /// Rules for the same string that differ, as `(old, new)`.
+    pub changed: Vec<(PronunciationRule, PronunciationRule)>,
+}
+
+impl RulesDiff {
+    /// Returns `true` if both sets hold the same rules.
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares two sets of rules, matching them by the string they replace.
+///
+/// If a set has several rules for one string, the last one counts, as it
+/// does when the API applies a dictionary.
+pub fn diff(old: &[PronunciationRule], new: &[PronunciationRule]) -> RulesDiff {
+    let by_string = |rules: &[PronunciationRule]| -> BTreeMap<String, PronunciationRule> {
+        rules.iter().map(|rule| (rule.string_to_replace().to_owned(), rule.clone())).collect()
+    };
+    let mut old = by_string(old);
+    let mut result = RulesDiff::default();
+    for (string, rule) in by_string(new) {
+        match old.remove(&string) {
+            None => result.added.push(rule),
+            Some(previous) if previous != rule => result.changed.push((previous, rule)),
+            Some(_) => {}
+        }
+    }
+    result.removed = old.into_values().collect();
+    result
+}
+
+/// Parses an optional `alphabet` attribute, falling back to `default`.
+fn parse_alphabet(value: Option<&str>, default: PhonemeAlphabet) -> Result<PhonemeAlphabet> {
+    value.map_or(Ok(default), |value| value.trim().parse().map_err(|e: String| pls_error(&e)))
+}
+
+/// Wraps a PLS parsing problem in an error.
+fn pls_error(message: &str) -> ElevenLabsError {
+    ElevenLabsError::Deserialization(serde::de::Error::custom(format!("invalid PLS: {message}")))
+}
+
+/// Finds the first `<name ...>...</name>` element in `xml`, returning its
+/// attribute text and content. A self-closing element has empty content.
+fn element<'a>(xml: &'a str, name: &str) -> Option<(&'a str, &'a str)> {
+    elements(xml, name).next()
+}
+
+/// Iterates over the `<name ...>...</name>` elements in `xml`, as
+/// [`element`] does.
+fn elements<'a>(xml: &'a str, name: &str) -> impl Iterator<Item = (&'a str, &'a str)> {
+    let open = format!("<{name}");
+    let close = format!("</{name}>");
+    let mut rest = xml;
+    std::iter::from_fn(move || {
+        loop {
+            let start = rest.find(&open)?;
+            let after_name = &rest[start + open.len()..];
+            // Skip elements whose name merely starts with `name`.
+            if !after_name.starts_with(|c: char| c == '>' || c == '/' || c.is_whitespace()) {
+                rest = after_name;
+                continue;
+            }
+            let tag_end = after_name.find('>')?;
+            let attrs = &after_name[..tag_end];
+            let after_tag = &after_name[tag_end + 1..];
+            if let Some(attrs) = attrs.strip_suffix('/') {
+                rest = after_tag;
+                return Some((attrs, ""));
+            }
+            let content_end = after_tag.find(&close)?;
+            rest = &after_tag[content_end + close.len()..];
+            return Some((attrs, &after_tag[..content_end]));
+        }
+    })
+}
+
+/// Returns the value of attribute `name` in an element's attribute text.
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attrs;
+    while let Some(start) = rest.find(name) {
+        let preceded_by_space = rest[..start].ends_with(char::is_whitespace);
+        rest = &rest[start + name.len()..];
+        let Some(value) = rest.trim_start().strip_prefix('=').filter(|_| preceded_by_space) else {
+            continue;
+        };
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        return value.find(quote).map(|end| &value[..end]);
+    }
+    None
+}
+
+/// Replaces the predefined XML entities in `text`.
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Escapes `value` for use in XML text and attributes.
+pub(crate) fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rules_in_document_order() {
+        let pls = r#"<?xml version="1.0" encoding="UTF-8"?>
+<lexicon version="1.0"
+      xmlns="http://www.w3.org/2005/01/pronunciation-lexicon"
+      alphabet="cmu-arpabet" xml:lang="en-US">
+  <lexeme>
+    <grapheme>tomato</grapheme>
+    <grapheme>Tomato</grapheme>
+    <phoneme>T AH0 M EY1 T OW2</phoneme>
+  </lexeme>
+  <lexeme>
+    <grapheme>AT&amp;T</grapheme>
+    <alias>A T and T</alias>
+  </lexeme>
+  <lexeme>
+    <grapheme>Nguyen</grapheme>
+    <phoneme alphabet="ipa">wɪn</phoneme>
+  </lexeme>
+</lexicon>"#;
+        let lexicon = Lexicon::parse(pls).unwrap();
+        assert_eq!(lexicon.language, "en-US");
+        assert_eq!(lexicon.alphabet, PhonemeAlphabet::CmuArpabet);
+        assert_eq!(
+            lexicon.rules,
+            vec![
+                PronunciationRule::phoneme(
+                    "tomato",
+                    "T AH0 M EY1 T OW2",
+                    PhonemeAlphabet::CmuArpabet
+                ),
+                PronunciationRule::phoneme(
+                    "Tomato",
+                    "T AH0 M EY1 T OW2",
+                    PhonemeAlphabet::CmuArpabet
+                ),
+                PronunciationRule::alias("AT&T", "A T and T"),
+                PronunciationRule::phoneme("Nguyen", "wɪn", PhonemeAlphabet::Ipa),
+            ]
+        );
+
+        assert!(Lexicon::parse("<lexicon></lexicon>").unwrap().rules.is_empty());
+        assert!(Lexicon::parse("<html></html>").is_err());
+        let no_grapheme = "<lexicon><lexeme><alias>x</alias></lexeme></lexicon>";
+        assert!(Lexicon::parse(no_grapheme).is_err());
+    }
+
+    #[test]
+    fn written_lexicons_parse_back() {
+        let lexicon = Lexicon::new(vec![
+            PronunciationRule::alias("AT&T", "A T and T"),
+            PronunciationRule::phoneme("tomato", "təˈmeɪtoʊ", PhonemeAlphabet::Ipa),
+            PronunciationRule::phoneme("Nguyen", "W IH1 N", PhonemeAlphabet::CmuArpabet),
+        ])
+        .language("en-GB");
+        let xml = lexicon.to_xml();
+        assert!(xml.contains("<grapheme>AT&amp;T</grapheme>"));
+        assert!(xml.contains("<phoneme alphabet=\"cmu-arpabet\">W IH1 N</phoneme>"));
+        assert_eq!(Lexicon::parse(&xml).unwrap(), lexicon);
+    }
+
+    #[test]
+    fn diff_matches_rules_by_string() {
+        let old = [
+            PronunciationRule::alias("UN", "United Nations"),
+            PronunciationRule::alias("EU", "European Union"),
+            PronunciationRule::alias("NATO", "nay toe"),
+        ];
+        let new = [
+            PronunciationRule::alias("NATO", "nay toe"),
+            PronunciationRule::alias("UN", "U N"),
+            PronunciationRule::alias("WHO", "World Health Organization"),
+        ];
+        let changes = diff(&old, &new);
+        assert_eq!(changes.added, vec![new[2].clone()]);
+        assert_eq!(changes.removed, vec![old[1].clone()]);
+        assert_eq!(changes.changed, vec![(old[0].clone(), new[1].clone())]);
+        assert!(diff(&new, &new).is_empty());
+    }
+}
@@ -0,0 +1,274 @@
+//! Named configuration profiles stored in a TOML file.
+//!
+//! A profile file keeps several API keys and per-profile defaults side by
+//! side, so one machine can switch between accounts without juggling
+//! environment variables:
+//!
+//! ```toml
+//! default_profile = "work"
+//!
+//! [profiles.work]
+//! api_key = "sk_work"
+//! voice_id = "21m00Tcm4TlvDq8ikWAM"
+//! model_id = "eleven_multilingual_v2"
+//! output_format = "mp3_44100_128"
+//!
+//! [profiles.personal]
+//! api_key = "sk_personal"
+//! base_url = "http://localhost:8080"
+//! ```
+//!
+//! The file lives at [`ProfileFile::default_path`] unless
+//! [`ENV_CONFIG_FILE`] points elsewhere. Use
+//! [`ClientConfig::from_profile`](crate::ClientConfig::from_profile) to build
+//! a client from a profile.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{config::ConfigError, types::OutputFormat};
+
+/// Environment variable overriding the location of the profile file.
+pub const ENV_CONFIG_FILE: &str = "ELEVENLABS_CONFIG";
+
+/// Environment variable naming the profile to use.
+pub const ENV_PROFILE: &str = "ELEVENLABS_PROFILE";
+
+/// Name of the profile used when neither a name nor a
+/// [`default_profile`](ProfileFile::default_profile) is given.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Settings stored in a profile.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    /// API key for the account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// Base URL of the API, for data residency or a mock server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    /// Voice used when a command does not name one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voice_id: Option<String>,
+    /// Model used when a command does not name one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+    /// Audio output format used when a command does not name one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<OutputFormat>,
+}
+
+impl Profile {
+    /// Names of the settings accepted by [`get`](Self::get) and
+    /// [`set`](Self::set).
+    pub const KEYS: [&str; 5] = ["api_key", "base_url", "voice_id", "model_id", "output_format"];
+
+    /// Returns the setting named `key`, if set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidProfileSetting`] if `key` is not one of
+    /// [`KEYS`](Self::KEYS).
+    pub fn get(&self, key: &str) -> Result<Option<String>, ConfigError> {
+        Ok(match key {
+            "api_key" => self.api_key.clone(),
+            "base_url" => self.base_url.clone(),
+            "voice_id" => self.voice_id.clone(),
+            "model_id" => self.model_id.clone(),
+            "output_format" => self.output_format.map(|f| f.to_string()),
+            _ => return Err(unknown_key(key)),
+        })
+    }
+
+    /// Sets the setting named `key`, or clears it if `value` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidProfileSetting`] if `key` is not one of
+    /// [`KEYS`](Self::KEYS) or `value` is not a valid output format.
+    pub fn set(&mut self, key: &str, value: Option<&str>) -> Result<(), ConfigError> {
+        let value = value.map(str::to_owned);
+        match key {
+            "api_key" => self.api_key = value,
+            "base_url" => self.base_url = value,
+            "voice_id" => self.voice_id = value,
+            "model_id" => self.model_id = value,
+            "output_format" => {
+                self.output_format = value
+                    .map(|v| {
+                        serde_json::from_value(serde_json::Value::String(v.clone())).map_err(|_| {
+                            ConfigError::InvalidProfileSetting(format!(
+                                "unknown output format `{v}`"
+                            ))
+                        })
+                    })
+                    .transpose()?;
+            }
+            _ => return Err(unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Profile")
+            .field("api_key", &self.api_key.as_ref().map(|_| "****"))
+            .field("base_url", &self.base_url)
+            .field("voice_id", &self.voice_id)
+            .field("model_id", &self.model_id)
+            .field("output_format", &self.output_format)
+            .finish()
+    }
+}
+
+fn unknown_key(key: &str) -> ConfigError {
+    ConfigError::InvalidProfileSetting(format!(
+        "unknown setting `{key}`; expected one of {}",
+        Profile::KEYS.join(", ")
+    ))
+}
+
+/// Contents of a profile file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileFile {
+    /// Profile used when none is named.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_profile: Option<String>,
+    /// Profiles, keyed by name.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl ProfileFile {
+    /// Returns the profile file location: [`ENV_CONFIG_FILE`] if set, else
+    /// `elevenlabs/config.toml` in `$XDG_CONFIG_HOME` or `~/.config`.
+    ///
+    /// Returns `None` if no home directory can be determined.
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os(ENV_CONFIG_FILE).filter(|p| !p.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|p| !p.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .or_else(|| std::env::var_os("USERPROFILE"))
+                    .filter(|p| !p.is_empty())
+                    .map(|home| PathBuf::from(home).join(".config"))
+            })?;
+        Some(config_dir.join("elevenlabs").join("config.toml"))
+    }
+
+    /// Reads the profile file at `path`. A missing file reads as empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::ProfileFile`] if the file cannot be read or is
+    /// not valid TOML.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(file_error(path, &e)),
+        };
+        toml::from_str(&text).map_err(|e| file_error(path, &e))
+    }
+
+    /// Writes the profiles to `path`, creating its directory if needed.
+    ///
+    /// On Unix the file is made readable by its owner only, since it holds
+    /// API keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::ProfileFile`] if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        let text = toml::to_string_pretty(self).map_err(|e| file_error(path, &e))?;
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(|e| file_error(path, &e))?;
+        }
+        std::fs::write(path, text).map_err(|e| file_error(path, &e))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+                .map_err(|e| file_error(path, &e))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the name of the profile to use: `name` if given, else
+    /// [`default_profile`](Self::default_profile), else
+    /// [`DEFAULT_PROFILE`].
+    pub fn resolve_name<'a>(&'a self, name: Option<&'a str>) -> &'a str {
+        name.or(self.default_profile.as_deref()).unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Returns the profile named `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::UnknownProfile`] if there is no such profile.
+    pub fn profile(&self, name: &str) -> Result<&Profile, ConfigError> {
+        self.profiles.get(name).ok_or_else(|| ConfigError::UnknownProfile(name.to_owned()))
+    }
+}
+
+fn file_error(path: &Path, error: &dyn fmt::Display) -> ConfigError {
+    ConfigError::ProfileFile { path: path.to_path_buf(), message: error.to_string() }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_file_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("elevenlabs-profile-{}", std::process::id()))
+            .join("config.toml");
+        assert_eq!(ProfileFile::load(&path).unwrap(), ProfileFile::default());
+
+        let mut file = ProfileFile { default_profile: Some("work".into()), ..Default::default() };
+        let work = file.profiles.entry("work".into()).or_default();
+        work.set("api_key", Some("sk_work")).unwrap();
+        work.set("output_format", Some("pcm_24000")).unwrap();
+        file.save(&path).unwrap();
+
+        let loaded = ProfileFile::load(&path).unwrap();
+        assert_eq!(loaded, file);
+        assert_eq!(loaded.resolve_name(None), "work");
+        assert_eq!(loaded.resolve_name(Some("other")), "other");
+        let work = loaded.profile("work").unwrap();
+        assert_eq!(work.output_format, Some(OutputFormat::Pcm_24000));
+        assert_eq!(work.get("output_format").unwrap().as_deref(), Some("pcm_24000"));
+        assert!(!format!("{work:?}").contains("sk_work"));
+        assert!(matches!(loaded.profile("other"), Err(ConfigError::UnknownProfile(_))));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn profile_rejects_unknown_settings() {
+        let mut profile = Profile::default();
+        assert!(matches!(
+            profile.set("voice", Some("x")),
+            Err(ConfigError::InvalidProfileSetting(_))
+        ));
+        assert!(matches!(
+            profile.set("output_format", Some("mp3_1")),
+            Err(ConfigError::InvalidProfileSetting(_))
+        ));
+        profile.set("voice_id", Some("v1")).unwrap();
+        profile.set("voice_id", None).unwrap();
+        assert_eq!(profile, Profile::default());
+    }
+}
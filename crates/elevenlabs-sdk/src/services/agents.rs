@@ -3,41 +3,64 @@
 //! This module covers the full Conversational AI surface of the ElevenLabs
 //! API, organised into the following groups:
 //!
-//! - **Agents** — CRUD, avatars, branches, deployments, drafts, duplication, link, widget
-//! - **Conversations** — list, get, delete, audio, feedback, signed URL, token
+//! - **Agents** — CRUD, avatars, branches, deployments, drafts, duplication, export/import, link,
+//!   widget
+//! - **Conversations** — list, get, delete, audio, feedback, signed URL, token, bulk export
 //! - **Knowledge Base** — CRUD, documents, folders, RAG indexes, move/bulk-move
 //! - **Tools** — CRUD
 //! - **Phone Numbers** — CRUD
-//! - **MCP Servers** — CRUD, tool configs, approval policies
+//! - **MCP Servers** — CRUD, tool configs, approval policies, connection test
 //! - **Batch Calling** — submit, list, get, cancel, retry
 //! - **Secrets** — CRUD
 //! - **Settings** — workspace ConvAI settings, dashboard settings
-//! - **Agent Testing** — test CRUD, summaries, invocations
+//! - **Agent Testing** — test CRUD, summaries, invocations, conversation simulation
 //! - **Misc** — SIP trunk, analytics, LLM usage, WhatsApp
 
+use std::{
+    collections::{HashMap, VecDeque},
+    path::Path,
+    pin::Pin,
+    time::Instant,
+};
+
 use bytes::Bytes;
 use futures_core::Stream;
+use futures_util::{StreamExt, stream};
+use tokio::io::AsyncWrite;
 
 use crate::{
     client::ElevenLabsClient,
-    error::Result,
+    error::{ElevenLabsError, Result},
+    services::{
+        conversation_export,
+        knowledge_base::{KnowledgeBaseSync, KnowledgeBaseUploader},
+        knowledge_base_content,
+    },
     types::{
-        AddKnowledgeBaseResponse, AgentBranchResponse, AgentDeploymentResponse, AgentLinkResponse,
-        BatchCallResponse, ConversationFeedbackRequest, ConversationTokenResponse,
-        CreateAgentRequest, CreateBranchRequest, CreateDeploymentRequest,
-        CreateKnowledgeBaseFolderRequest, CreateKnowledgeBaseTextRequest,
-        CreateKnowledgeBaseUrlRequest, CreatePhoneNumberResponse, CreateSecretRequest,
-        GetAgentResponse, GetAgentSummariesResponse, GetAgentsResponse, GetConvAiSettingsResponse,
-        GetConversationResponse, GetConversationUsersResponse, GetConversationsResponse,
-        GetKnowledgeBaseListResponse, GetSecretsResponse, GetToolDependentAgentsResponse,
-        GetToolsResponse, KnowledgeBaseBulkMoveRequest, KnowledgeBaseMoveRequest,
+        AGENT_BUNDLE_VERSION, AddKnowledgeBaseResponse, AgentAvatar, AgentBranchResponse,
+        AgentBundle, AgentDeploymentResponse, AgentLinkResponse, AgentWidgetResponse,
+        BatchCallResponse, ConvAiDashboardSettings, ConversationAnalysis, ConversationAudio,
+        ConversationExportFormat, ConversationExportSummary, ConversationFeedbackRequest,
+        ConversationTokenResponse, ConversationTranscriptEntry, CreateAgentRequest,
+        CreateBranchRequest, CreateDeploymentRequest, CreateKnowledgeBaseFolderRequest,
+        CreateKnowledgeBaseTextRequest, CreateKnowledgeBaseUrlRequest, CreateMcpServerRequest,
+        CreatePhoneNumberResponse, CreateRagIndexRequest, CreateSecretRequest, DateRange,
+        GetAgentBranchesResponse, GetAgentResponse, GetAgentSummariesResponse, GetAgentsResponse,
+        GetConvAiSettingsResponse, GetConversationResponse, GetConversationUsersResponse,
+        GetConversationsResponse, GetDocumentRagIndexesResponse, GetKnowledgeBaseListResponse,
+        GetSecretsResponse, GetToolDependentAgentsResponse, GetToolsResponse, ImportOptions,
+        KnowledgeBaseBulkMoveRequest, KnowledgeBaseContentFormat, KnowledgeBaseMoveRequest,
         ListPhoneNumbersResponse, ListWhatsAppAccountsResponse, LiveCountResponse,
-        McpServerResponse, McpServersResponse, MergeBranchRequest, SignedUrlResponse,
-        SipTrunkOutboundCallRequest, SubmitBatchCallRequest, ToolResponse,
-        TwilioOutboundCallRequest, TwilioOutboundCallResponse, TwilioRegisterCallRequest,
-        UpdateAgentRequest, UpdateBranchRequest, UpdateKnowledgeBaseDocumentRequest,
+        McpServerResponse, McpServerTestReport, McpServerToolsResponse, McpServersResponse,
+        MergeBranchRequest, MergedAgentUpdate, PatchConvAiDashboardSettingsRequest,
+        PatchConvAiSettingsRequest, RagDocumentIndex, RagIndexBatchRequest, RagIndexBatchResult,
+        RagIndexOverview, RagIndexWaitOptions, SignedUrlResponse, SimulationEvent,
+        SimulationResult, SimulationSpec, SipTrunkOutboundCallRequest, SubmitBatchCallRequest,
+        ToolRequest, ToolResponse, ToolUsageReport, TwilioOutboundCallRequest,
+        TwilioOutboundCallResponse, TwilioRegisterCallRequest, UpdateAgentRequest,
+        UpdateBranchRequest, UpdateKnowledgeBaseDocumentRequest, UpdateMcpServerRequest,
         UpdateSecretRequest, WhatsAppAccount, WhatsAppOutboundCallRequest,
-        WhatsAppOutboundMessageRequest, WorkspaceBatchCallsResponse,
+        WhatsAppOutboundMessageRequest, WidgetAvatar, WidgetConfig, WorkspaceBatchCallsResponse,
     },
 };
 
@@ -113,6 +136,29 @@ impl<'a> AgentsService<'a> {
         self.client.patch(&path, request).await
     }
 
+    /// Updates an agent by merging `patch` into its current config.
+    ///
+    /// Fetches the agent, applies `patch` with
+    /// [`GetAgentResponse::merge_update`], and sends only the top-level
+    /// fields that changed. Nested settings the patch does not mention are
+    /// kept. If nothing changes, no update is sent and the current agent is
+    /// returned with an empty diff.
+    ///
+    /// `GET` then `PATCH /v1/convai/agents/{agent_id}`
+    pub async fn update_agent_merged(
+        &self,
+        agent_id: &str,
+        patch: &UpdateAgentRequest,
+    ) -> Result<MergedAgentUpdate> {
+        let current = self.get_agent(agent_id).await?;
+        let (request, diff) = current.merge_update(patch);
+        if diff.is_empty() {
+            return Ok(MergedAgentUpdate { agent: current, diff });
+        }
+        let agent = self.update_agent(agent_id, &request).await?;
+        Ok(MergedAgentUpdate { agent, diff })
+    }
+
     /// Deletes an agent.
     ///
     /// `DELETE /v1/convai/agents/{agent_id}`
@@ -121,6 +167,83 @@ impl<'a> AgentsService<'a> {
         self.client.delete(&path).await
     }
 
+    // =======================================================================
+    // Agents — Export & Import
+    // =======================================================================
+
+    /// Exports an agent and the tools it uses as an [`AgentBundle`].
+    ///
+    /// `GET /v1/convai/agents/{agent_id}`, then `GET /v1/convai/tools/{tool_id}`
+    /// for each tool in the agent's prompt config.
+    pub async fn export_agent(&self, agent_id: &str) -> Result<AgentBundle> {
+        let agent = self.get_agent(agent_id).await?;
+        let mut tools = Vec::new();
+        for tool_id in agent.tool_ids() {
+            tools.push(self.get_tool(&tool_id).await?);
+        }
+        Ok(AgentBundle::from_agent(&agent, &tools))
+    }
+
+    /// Creates or overwrites an agent from an [`AgentBundle`].
+    ///
+    /// Unless [`ImportOptions::recreate_tools`] is turned off, each tool in
+    /// the bundle is created first (`POST /v1/convai/tools`) and the agent
+    /// is pointed at the new tools. The agent is then created
+    /// (`POST /v1/convai/agents/create`) or, with
+    /// [`ImportOptions::update_agent`], overwritten
+    /// (`PATCH /v1/convai/agents/{agent_id}`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the bundle was written by
+    /// a newer SDK, or the first API error. Tools created before a failure
+    /// are not deleted.
+    pub async fn import_agent(
+        &self,
+        bundle: &AgentBundle,
+        options: &ImportOptions,
+    ) -> Result<GetAgentResponse> {
+        if bundle.version > AGENT_BUNDLE_VERSION {
+            return Err(ElevenLabsError::Validation(format!(
+                "agent bundle version {} is newer than the supported version {AGENT_BUNDLE_VERSION}",
+                bundle.version
+            )));
+        }
+
+        let mut tool_ids = HashMap::new();
+        if options.recreate_tools {
+            for tool in &bundle.tools {
+                let request = ToolRequest::from(tool.tool_config.clone());
+                let created = self.create_tool(&request).await?;
+                tool_ids.insert(tool.id.clone(), created.id);
+            }
+        }
+
+        let conversation_config =
+            bundle.remapped_conversation_config(&tool_ids, &options.knowledge_base_ids);
+        let name = options.name.clone().unwrap_or_else(|| bundle.name.clone());
+        if let Some(agent_id) = &options.agent_id {
+            let request = UpdateAgentRequest {
+                conversation_config: Some(conversation_config),
+                platform_settings: Some(bundle.platform_settings.clone()),
+                workflow: bundle.workflow.clone(),
+                name: Some(name),
+                tags: Some(bundle.tags.clone()),
+                ..Default::default()
+            };
+            self.update_agent(agent_id, &request).await
+        } else {
+            let request = CreateAgentRequest {
+                conversation_config: Some(conversation_config),
+                platform_settings: Some(bundle.platform_settings.clone()),
+                workflow: bundle.workflow.clone(),
+                name: Some(name),
+                tags: Some(bundle.tags.clone()),
+            };
+            self.create_agent(&request).await
+        }
+    }
+
     // =======================================================================
     // Agents — Avatar
     // =======================================================================
@@ -166,10 +289,11 @@ impl<'a> AgentsService<'a> {
         self.client.post(&path, request).await
     }
 
-    /// Lists branches for an agent.
+    /// Lists branches for an agent, with the share of live traffic each
+    /// receives.
     ///
     /// `GET /v1/convai/agents/{agent_id}/branches`
-    pub async fn list_branches(&self, agent_id: &str) -> Result<serde_json::Value> {
+    pub async fn list_branches(&self, agent_id: &str) -> Result<GetAgentBranchesResponse> {
         let path = format!("/v1/convai/agents/{agent_id}/branches");
         self.client.get(&path).await
     }
@@ -275,11 +399,59 @@ impl<'a> AgentsService<'a> {
     /// Retrieves the widget configuration for an agent.
     ///
     /// `GET /v1/convai/agents/{agent_id}/widget`
-    pub async fn get_agent_widget(&self, agent_id: &str) -> Result<serde_json::Value> {
+    pub async fn get_agent_widget(&self, agent_id: &str) -> Result<AgentWidgetResponse> {
         let path = format!("/v1/convai/agents/{agent_id}/widget");
         self.client.get(&path).await
     }
 
+    /// Updates the widget configuration of an agent.
+    ///
+    /// `config` is merged into `platform_settings.widget` with
+    /// [`update_agent_merged`](Self::update_agent_merged), so widget
+    /// settings left unset in `config` keep their current values.
+    ///
+    /// `GET` then `PATCH /v1/convai/agents/{agent_id}`
+    pub async fn update_widget(
+        &self,
+        agent_id: &str,
+        config: &WidgetConfig,
+    ) -> Result<MergedAgentUpdate> {
+        let patch = UpdateAgentRequest {
+            platform_settings: Some(serde_json::json!({ "widget": config })),
+            ..Default::default()
+        };
+        self.update_agent_merged(agent_id, &patch).await
+    }
+
+    /// Downloads the avatar image shown in an agent's widget.
+    ///
+    /// Reads the avatar URL from the widget configuration and fetches it
+    /// without the API key. Fails with [`ElevenLabsError::Validation`] if
+    /// the widget uses an orb or has no avatar.
+    ///
+    /// `GET /v1/convai/agents/{agent_id}/widget`, then `GET` the avatar URL
+    pub async fn download_avatar(&self, agent_id: &str) -> Result<AgentAvatar> {
+        let widget = self.get_agent_widget(agent_id).await?;
+        let url = match widget.widget_config.avatar {
+            Some(WidgetAvatar::Image { url } | WidgetAvatar::Url { custom_url: url }) => url,
+            Some(WidgetAvatar::Orb { .. }) | None => {
+                return Err(ElevenLabsError::Validation(format!(
+                    "agent {agent_id} has no avatar image"
+                )));
+            }
+        };
+        let url = url::Url::parse(&url)
+            .map_err(|e| ElevenLabsError::Validation(format!("invalid avatar URL {url:?}: {e}")))?;
+        let response = self.client.fetch_remote(&url).await?;
+        let content_type = response
+            .headers()
+            .get(hpx::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let data = response.bytes().await.map_err(ElevenLabsError::Transport)?;
+        Ok(AgentAvatar { data, content_type })
+    }
+
     // =======================================================================
     // Agents — Test Suite & Simulation
     // =======================================================================
@@ -296,28 +468,51 @@ impl<'a> AgentsService<'a> {
         self.client.post(&path, request).await
     }
 
-    /// Runs a conversation simulation for an agent.
+    /// Runs a simulated conversation between an agent and an LLM playing
+    /// the user, and returns the transcript with its evaluation.
     ///
     /// `POST /v1/convai/agents/{agent_id}/simulate-conversation`
     pub async fn simulate_conversation(
         &self,
         agent_id: &str,
-        request: &serde_json::Value,
-    ) -> Result<serde_json::Value> {
+        spec: &SimulationSpec,
+    ) -> Result<SimulationResult> {
         let path = format!("/v1/convai/agents/{agent_id}/simulate-conversation");
-        self.client.post(&path, request).await
+        self.client.post(&path, spec).await
     }
 
-    /// Runs a conversation simulation with streaming response.
+    /// Runs a simulated conversation with a streaming response.
+    ///
+    /// Returns the raw byte stream; use
+    /// [`simulate_conversation_turns`](Self::simulate_conversation_turns)
+    /// for decoded turns.
     ///
     /// `POST /v1/convai/agents/{agent_id}/simulate-conversation/stream`
     pub async fn simulate_conversation_stream(
         &self,
         agent_id: &str,
-        request: &serde_json::Value,
+        spec: &SimulationSpec,
     ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<'_>> {
         let path = format!("/v1/convai/agents/{agent_id}/simulate-conversation/stream");
-        self.client.post_stream(&path, request).await
+        self.client.post_stream(&path, spec).await
+    }
+
+    /// Runs a simulated conversation, yielding each turn as it is generated
+    /// and the evaluation once the conversation ends.
+    ///
+    /// `POST /v1/convai/agents/{agent_id}/simulate-conversation/stream`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the initial API request fails. Stream items carry
+    /// transport errors and malformed chunks.
+    pub async fn simulate_conversation_turns(
+        &self,
+        agent_id: &str,
+        spec: &SimulationSpec,
+    ) -> Result<impl Stream<Item = Result<SimulationEvent>> + use<'_>> {
+        let chunks = self.simulate_conversation_stream(agent_id, spec).await?;
+        Ok(simulation_events(chunks))
     }
 
     // =======================================================================
@@ -361,6 +556,10 @@ impl<'a> AgentsService<'a> {
 
     /// Submits a new batch call job.
     ///
+    /// Like other POSTs, the call is not retried after a server error or
+    /// timeout, since the batch may already have been dispatched; opt in with
+    /// [`RequestOptions::retry_unsafe`](crate::RequestOptions::retry_unsafe).
+    ///
     /// `POST /v1/convai/batch-calling/submit`
     pub async fn submit_batch_call(
         &self,
@@ -440,13 +639,15 @@ impl<'a> AgentsService<'a> {
         self.client.get(&path).await
     }
 
-    /// Lists conversation histories.
+    /// Lists conversation histories, optionally limited to conversations
+    /// started inside `range`.
     ///
     /// `GET /v1/convai/conversations`
     pub async fn list_conversations(
         &self,
         agent_id: Option<&str>,
         cursor: Option<&str>,
+        range: Option<DateRange>,
     ) -> Result<GetConversationsResponse> {
         let mut path = "/v1/convai/conversations".to_owned();
         if let Some(id) = agent_id {
@@ -455,6 +656,13 @@ impl<'a> AgentsService<'a> {
         if let Some(c) = cursor {
             append_query(&mut path, "cursor", c);
         }
+        let range = range.unwrap_or_default();
+        if let Some(after) = range.after {
+            append_query(&mut path, "call_start_after_unix", &after.to_string());
+        }
+        if let Some(before) = range.before {
+            append_query(&mut path, "call_start_before_unix", &before.to_string());
+        }
         self.client.get(&path).await
     }
 
@@ -474,12 +682,42 @@ impl<'a> AgentsService<'a> {
         self.client.delete(&path).await
     }
 
-    /// Retrieves conversation audio as raw bytes.
+    /// Retrieves the conversation recording along with its audio format.
     ///
     /// `GET /v1/convai/conversations/{conversation_id}/audio`
-    pub async fn get_conversation_audio(&self, conversation_id: &str) -> Result<Bytes> {
+    pub async fn get_conversation_audio(&self, conversation_id: &str) -> Result<ConversationAudio> {
         let path = format!("/v1/convai/conversations/{conversation_id}/audio");
-        self.client.get_bytes(&path).await
+        let (data, content_type) = self.client.get_bytes_with_content_type(&path).await?;
+        Ok(ConversationAudio {
+            data,
+            content_type: content_type.unwrap_or_else(|| "audio/mpeg".into()),
+        })
+    }
+
+    /// Exports every conversation of `agent_id` started inside `range` to
+    /// the directory `dest`, creating it if needed.
+    ///
+    /// Transcripts (and, with
+    /// [`ConversationExportFormat::TranscriptsAndAudio`], recordings) are
+    /// fetched concurrently, retrying transient failures. The archive holds
+    /// `transcripts.jsonl`, an `audio/` directory and an `index.csv` listing
+    /// every conversation; see [`conversation_export`] for the layout.
+    ///
+    /// A conversation that still fails after retries is recorded in the
+    /// summary and the index instead of aborting the export.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if listing conversations fails or the archive
+    /// cannot be written.
+    pub async fn export_conversations(
+        &self,
+        agent_id: &str,
+        range: DateRange,
+        format: ConversationExportFormat,
+        dest: impl AsRef<Path>,
+    ) -> Result<ConversationExportSummary> {
+        conversation_export::export(self.client, agent_id, range, format, dest.as_ref()).await
     }
 
     /// Posts feedback for a conversation.
@@ -581,20 +819,22 @@ impl<'a> AgentsService<'a> {
         self.client.post("/v1/convai/knowledge-base/folder", request).await
     }
 
-    /// Gets or creates a RAG index.
+    /// Gets, and optionally creates, the RAG indexes of several documents.
+    ///
+    /// Results are keyed by document ID.
     ///
     /// `POST /v1/convai/knowledge-base/rag-index`
     pub async fn get_or_create_rag_index(
         &self,
-        request: &serde_json::Value,
-    ) -> Result<serde_json::Value> {
+        request: &RagIndexBatchRequest,
+    ) -> Result<HashMap<String, RagIndexBatchResult>> {
         self.client.post("/v1/convai/knowledge-base/rag-index", request).await
     }
 
-    /// Retrieves the RAG index overview.
+    /// Retrieves the workspace's RAG storage usage.
     ///
     /// `GET /v1/convai/knowledge-base/rag-index`
-    pub async fn get_rag_index_overview(&self) -> Result<serde_json::Value> {
+    pub async fn get_rag_index_overview(&self) -> Result<RagIndexOverview> {
         self.client.get("/v1/convai/knowledge-base/rag-index").await
     }
 
@@ -625,6 +865,18 @@ impl<'a> AgentsService<'a> {
         self.client.post("/v1/convai/knowledge-base/url", request).await
     }
 
+    /// Returns a [`KnowledgeBaseUploader`] for creating many documents from
+    /// a directory or a list of URLs.
+    pub const fn knowledge_base_uploader(&self) -> KnowledgeBaseUploader<'a> {
+        KnowledgeBaseUploader::new(self.client)
+    }
+
+    /// Returns a [`KnowledgeBaseSync`] for keeping a knowledge base folder
+    /// in line with local files and URLs.
+    pub const fn knowledge_base_sync(&self) -> KnowledgeBaseSync<'a> {
+        KnowledgeBaseSync::new(self.client)
+    }
+
     /// Moves a knowledge base document to a folder.
     ///
     /// `POST /v1/convai/knowledge-base/{document_id}/move`
@@ -691,6 +943,51 @@ impl<'a> AgentsService<'a> {
         self.client.get(&path).await
     }
 
+    /// Downloads the full content of a knowledge base document in `format`.
+    ///
+    /// The HTML served by
+    /// `GET /v1/convai/knowledge-base/{documentation_id}/content` is
+    /// converted as it streams in; see [`knowledge_base_content`] for what
+    /// the conversion keeps. For very large documents, write straight to a
+    /// file with [`download_document_to`](Self::download_document_to).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the stream is interrupted.
+    pub async fn download_document(
+        &self,
+        documentation_id: &str,
+        format: KnowledgeBaseContentFormat,
+    ) -> Result<String> {
+        let mut content = Vec::new();
+        knowledge_base_content::download_to(self.client, documentation_id, format, &mut content)
+            .await?;
+        Ok(String::from_utf8(content)
+            .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()))
+    }
+
+    /// Streams the full content of a knowledge base document in `format`
+    /// into `writer`, converting it chunk by chunk so the document is never
+    /// held in memory. Returns the number of bytes written.
+    ///
+    /// The writer is flushed but not shut down.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the stream is interrupted or
+    /// a write fails. Content written before the error is left in `writer`.
+    pub async fn download_document_to<W>(
+        &self,
+        documentation_id: &str,
+        format: KnowledgeBaseContentFormat,
+        writer: &mut W,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        knowledge_base_content::download_to(self.client, documentation_id, format, writer).await
+    }
+
     /// Retrieves agents that depend on a knowledge base document.
     ///
     /// `GET /v1/convai/knowledge-base/{documentation_id}/dependent-agents`
@@ -702,14 +999,18 @@ impl<'a> AgentsService<'a> {
         self.client.get(&path).await
     }
 
-    /// Creates or checks a RAG index for a document.
+    /// Starts computing a document's RAG index, or returns the existing one
+    /// for the same model.
+    ///
+    /// Indexing runs in the background; use
+    /// [`wait_for_rag_index`](Self::wait_for_rag_index) to wait for it.
     ///
     /// `POST /v1/convai/knowledge-base/{documentation_id}/rag-index`
     pub async fn create_document_rag_index(
         &self,
         documentation_id: &str,
-        request: &serde_json::Value,
-    ) -> Result<serde_json::Value> {
+        request: &CreateRagIndexRequest,
+    ) -> Result<RagDocumentIndex> {
         let path = format!("/v1/convai/knowledge-base/{documentation_id}/rag-index");
         self.client.post(&path, request).await
     }
@@ -720,21 +1021,77 @@ impl<'a> AgentsService<'a> {
     pub async fn get_document_rag_indexes(
         &self,
         documentation_id: &str,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<GetDocumentRagIndexesResponse> {
         let path = format!("/v1/convai/knowledge-base/{documentation_id}/rag-index");
         self.client.get(&path).await
     }
 
-    /// Deletes a RAG index for a document.
+    /// Deletes a RAG index for a document, returning the deleted index.
     ///
     /// `DELETE /v1/convai/knowledge-base/{documentation_id}/rag-index/{rag_index_id}`
     pub async fn delete_document_rag_index(
         &self,
         documentation_id: &str,
         rag_index_id: &str,
-    ) -> Result<()> {
+    ) -> Result<RagDocumentIndex> {
         let path = format!("/v1/convai/knowledge-base/{documentation_id}/rag-index/{rag_index_id}");
-        self.client.delete(&path).await
+        self.client.delete_json(&path).await
+    }
+
+    /// Waits for a document's RAG index to finish indexing, with default
+    /// [`RagIndexWaitOptions`].
+    ///
+    /// See [`wait_for_rag_index_with`](Self::wait_for_rag_index_with).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`wait_for_rag_index_with`](Self::wait_for_rag_index_with).
+    pub async fn wait_for_rag_index(&self, documentation_id: &str) -> Result<RagDocumentIndex> {
+        self.wait_for_rag_index_with(documentation_id, &RagIndexWaitOptions::default()).await
+    }
+
+    /// Polls a document's RAG indexes until the one selected by
+    /// `options.model` stops indexing.
+    ///
+    /// Returns the index in its final state, which may be a failure such as
+    /// [`RagLimitExceeded`](crate::types::RagIndexStatus::RagLimitExceeded);
+    /// check [`is_ready`](crate::types::RagIndexStatus::is_ready) before
+    /// relying on it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the document has no
+    /// matching index, [`ElevenLabsError::Timeout`] if `options.timeout`
+    /// elapses, or an error if a poll fails.
+    pub async fn wait_for_rag_index_with(
+        &self,
+        documentation_id: &str,
+        options: &RagIndexWaitOptions,
+    ) -> Result<RagDocumentIndex> {
+        let deadline = options.timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+        loop {
+            let index = self
+                .get_document_rag_indexes(documentation_id)
+                .await?
+                .indexes
+                .into_iter()
+                .find(|index| options.model.is_none_or(|model| index.model == model))
+                .ok_or_else(|| {
+                    let model = options.model.map(|model| format!(" for {model}"));
+                    ElevenLabsError::Validation(format!(
+                        "document {documentation_id} has no RAG index{}",
+                        model.unwrap_or_default()
+                    ))
+                })?;
+
+            if index.status.is_terminal() {
+                return Ok(index);
+            }
+            if deadline.is_some_and(|deadline| tokio::time::Instant::now() >= deadline) {
+                return Err(ElevenLabsError::Timeout);
+            }
+            tokio::time::sleep(options.poll_interval).await;
+        }
     }
 
     /// Retrieves the source file URL for a knowledge base document.
@@ -771,7 +1128,7 @@ impl<'a> AgentsService<'a> {
     /// `POST /v1/convai/mcp-servers`
     pub async fn create_mcp_server(
         &self,
-        request: &serde_json::Value,
+        request: &CreateMcpServerRequest,
     ) -> Result<McpServerResponse> {
         self.client.post("/v1/convai/mcp-servers", request).await
     }
@@ -805,7 +1162,7 @@ impl<'a> AgentsService<'a> {
     pub async fn update_mcp_server(
         &self,
         mcp_server_id: &str,
-        request: &serde_json::Value,
+        request: &UpdateMcpServerRequest,
     ) -> Result<McpServerResponse> {
         let path = format!("/v1/convai/mcp-servers/{mcp_server_id}");
         self.client.patch(&path, request).await
@@ -895,11 +1252,38 @@ impl<'a> AgentsService<'a> {
     /// Lists tools available on an MCP server.
     ///
     /// `GET /v1/convai/mcp-servers/{mcp_server_id}/tools`
-    pub async fn list_mcp_server_tools(&self, mcp_server_id: &str) -> Result<serde_json::Value> {
+    pub async fn list_mcp_server_tools(
+        &self,
+        mcp_server_id: &str,
+    ) -> Result<McpServerToolsResponse> {
         let path = format!("/v1/convai/mcp-servers/{mcp_server_id}/tools");
         self.client.get(&path).await
     }
 
+    /// Checks that an MCP server can be reached by listing its tools.
+    ///
+    /// A server that fails to answer is reported as unreachable rather than
+    /// as an error, with the reason in
+    /// [`McpServerTestReport::error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ElevenLabs API request itself fails, e.g.
+    /// because no server with this ID exists.
+    pub async fn test_mcp_server(&self, mcp_server_id: &str) -> Result<McpServerTestReport> {
+        let started = Instant::now();
+        let response = self.list_mcp_server_tools(mcp_server_id).await?;
+        let latency = started.elapsed();
+        let error = (!response.success)
+            .then(|| response.error_message.unwrap_or_else(|| "tool listing failed".to_owned()));
+        Ok(McpServerTestReport {
+            reachable: response.success,
+            latency,
+            tools: response.tools,
+            error,
+        })
+    }
+
     // =======================================================================
     // Phone Numbers
     // =======================================================================
@@ -1003,7 +1387,7 @@ impl<'a> AgentsService<'a> {
     /// `PATCH /v1/convai/settings`
     pub async fn update_settings(
         &self,
-        request: &serde_json::Value,
+        request: &PatchConvAiSettingsRequest,
     ) -> Result<GetConvAiSettingsResponse> {
         self.client.patch("/v1/convai/settings", request).await
     }
@@ -1011,17 +1395,30 @@ impl<'a> AgentsService<'a> {
     /// Retrieves dashboard settings.
     ///
     /// `GET /v1/convai/settings/dashboard`
-    pub async fn get_dashboard_settings(&self) -> Result<serde_json::Value> {
+    pub async fn get_dashboard_settings(&self) -> Result<ConvAiDashboardSettings> {
         self.client.get("/v1/convai/settings/dashboard").await
     }
 
     /// Updates dashboard settings.
     ///
     /// `PATCH /v1/convai/settings/dashboard`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if more than
+    /// [`PatchConvAiDashboardSettingsRequest::MAX_DASHBOARD_CHARTS`] charts
+    /// are given, or an error if the API request fails.
     pub async fn update_dashboard_settings(
         &self,
-        request: &serde_json::Value,
-    ) -> Result<serde_json::Value> {
+        request: &PatchConvAiDashboardSettingsRequest,
+    ) -> Result<ConvAiDashboardSettings> {
+        let max = PatchConvAiDashboardSettingsRequest::MAX_DASHBOARD_CHARTS;
+        if let Some(charts) = request.charts.as_ref().filter(|c| c.len() > max) {
+            return Err(ElevenLabsError::Validation(format!(
+                "dashboard accepts at most {max} charts, got {}",
+                charts.len()
+            )));
+        }
         self.client.patch("/v1/convai/settings/dashboard", request).await
     }
 
@@ -1143,7 +1540,7 @@ impl<'a> AgentsService<'a> {
     /// Creates a new tool.
     ///
     /// `POST /v1/convai/tools`
-    pub async fn create_tool(&self, request: &serde_json::Value) -> Result<ToolResponse> {
+    pub async fn create_tool(&self, request: &ToolRequest) -> Result<ToolResponse> {
         self.client.post("/v1/convai/tools", request).await
     }
 
@@ -1154,6 +1551,16 @@ impl<'a> AgentsService<'a> {
         self.client.get("/v1/convai/tools").await
     }
 
+    /// Lists all tools and summarises their usage statistics.
+    ///
+    /// See [`ToolUsageReport`] for how entries are ordered.
+    ///
+    /// `GET /v1/convai/tools`
+    pub async fn tool_usage_report(&self) -> Result<ToolUsageReport> {
+        let response = self.list_tools().await?;
+        Ok(ToolUsageReport::from_tools(&response.tools))
+    }
+
     /// Retrieves a specific tool.
     ///
     /// `GET /v1/convai/tools/{tool_id}`
@@ -1165,11 +1572,7 @@ impl<'a> AgentsService<'a> {
     /// Updates a tool.
     ///
     /// `PATCH /v1/convai/tools/{tool_id}`
-    pub async fn update_tool(
-        &self,
-        tool_id: &str,
-        request: &serde_json::Value,
-    ) -> Result<ToolResponse> {
+    pub async fn update_tool(&self, tool_id: &str, request: &ToolRequest) -> Result<ToolResponse> {
         let path = format!("/v1/convai/tools/{tool_id}");
         self.client.patch(&path, request).await
     }
@@ -1379,6 +1782,102 @@ fn build_single_file_multipart(
     buf
 }
 
+// ---------------------------------------------------------------------------
+// Simulation streaming
+// ---------------------------------------------------------------------------
+
+/// One line of the `simulate-conversation/stream` response.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum SimulationChunk {
+    /// New turns, to be appended to those already received.
+    Turns(Vec<ConversationTranscriptEntry>),
+    /// Final message carrying the evaluation.
+    Completed { analysis: Box<ConversationAnalysis> },
+    /// A single new turn.
+    Turn(Box<ConversationTranscriptEntry>),
+}
+
+/// State for [`simulation_events`].
+struct SimulationEvents<S> {
+    chunks: Pin<Box<S>>,
+    buffer: Vec<u8>,
+    pending: VecDeque<SimulationEvent>,
+    done: bool,
+}
+
+impl<S> SimulationEvents<S> {
+    /// Decodes one line into pending events. Lines may carry a server-sent
+    /// events `data:` prefix.
+    fn handle_line(&mut self, line: &[u8]) -> Result<()> {
+        let line = line.trim_ascii();
+        let line = line.strip_prefix(b"data:").map_or(line, <[u8]>::trim_ascii);
+        if line.is_empty() {
+            return Ok(());
+        }
+        match serde_json::from_slice(line)? {
+            SimulationChunk::Turns(turns) => {
+                self.pending
+                    .extend(turns.into_iter().map(|turn| SimulationEvent::Turn(Box::new(turn))));
+            }
+            SimulationChunk::Turn(turn) => self.pending.push_back(SimulationEvent::Turn(turn)),
+            SimulationChunk::Completed { analysis } => {
+                self.pending.push_back(SimulationEvent::Completed(analysis));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Turns the raw `simulate-conversation/stream` byte stream into
+/// [`SimulationEvent`]s.
+///
+/// The endpoint sends one JSON message per line, but network chunks do not
+/// follow line boundaries, so bytes are buffered until a full line arrives.
+fn simulation_events<S>(chunks: S) -> impl Stream<Item = Result<SimulationEvent>>
+where
+    S: Stream<Item = std::result::Result<Bytes, hpx::Error>>,
+{
+    let state = SimulationEvents {
+        chunks: Box::pin(chunks),
+        buffer: Vec::new(),
+        pending: VecDeque::new(),
+        done: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((Ok(event), state));
+            }
+            if state.done {
+                return None;
+            }
+            if let Some(newline) = state.buffer.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = state.buffer.drain(..=newline).collect();
+                if let Err(e) = state.handle_line(&line) {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+                continue;
+            }
+            match state.chunks.next().await {
+                Some(Ok(bytes)) => state.buffer.extend_from_slice(&bytes),
+                Some(Err(e)) => {
+                    state.done = true;
+                    return Some((Err(ElevenLabsError::Transport(e)), state));
+                }
+                None => {
+                    state.done = true;
+                    let rest = std::mem::take(&mut state.buffer);
+                    if let Err(e) = state.handle_line(&rest) {
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        }
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1388,7 +1887,7 @@ fn build_single_file_multipart(
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{method, path},
+        matchers::{body_json, method, path, query_param},
     };
 
     use super::*;
@@ -1422,6 +1921,84 @@ mod tests {
         assert!(!result.has_more);
     }
 
+    #[tokio::test]
+    async fn test_export_then_import_agent_recreates_tools() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+        let access_info = serde_json::json!({
+            "is_creator": true, "creator_name": "a", "creator_email": "a@b.c", "role": "admin"
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/agents/agent_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "agent_id": "agent_1",
+                "name": "Support",
+                "conversation_config": {"agent": {"prompt": {"tool_ids": ["tool_1"]}}},
+                "platform_settings": {},
+                "tags": ["prod"]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/tools/tool_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "tool_1",
+                "tool_config": {"type": "client", "name": "lookup"},
+                "access_info": access_info
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/tools"))
+            .and(body_json(serde_json::json!({
+                "tool_config": {"type": "client", "name": "lookup"}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "tool_2",
+                "tool_config": {"type": "client", "name": "lookup"},
+                "access_info": access_info
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/agents/create"))
+            .and(body_json(serde_json::json!({
+                "conversation_config": {"agent": {"prompt": {"tool_ids": ["tool_2"]}}},
+                "platform_settings": {},
+                "name": "Support (staging)",
+                "tags": ["prod"]
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"agent_id": "agent_2"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let agents = client.agents();
+        let bundle = agents.export_agent("agent_1").await.unwrap();
+        assert_eq!(bundle.tools.len(), 1);
+
+        let options = ImportOptions::new().name("Support (staging)");
+        let imported = agents.import_agent(&bundle, &options).await.unwrap();
+        assert_eq!(imported.agent_id, "agent_2");
+    }
+
+    #[tokio::test]
+    async fn test_import_agent_rejects_newer_bundle() {
+        let client =
+            crate::client::ElevenLabsClient::new(test_config("http://127.0.0.1:1")).unwrap();
+        let mut bundle = AgentBundle::from_agent(
+            &serde_json::from_value(serde_json::json!({"agent_id": "a"})).unwrap(),
+            &[],
+        );
+        bundle.version = AGENT_BUNDLE_VERSION + 1;
+        let err = client.agents().import_agent(&bundle, &ImportOptions::new()).await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+    }
+
     #[tokio::test]
     async fn test_create_agent() {
         let mock_server = MockServer::start().await;
@@ -1434,8 +2011,8 @@ mod tests {
                 "name": "Test Agent",
                 "conversation_config": {},
                 "metadata": {
-                    "created_at_unix_secs": 1700000000,
-                    "updated_at_unix_secs": 1700000000
+                    "created_at_unix_secs": 1_700_000_000,
+                    "updated_at_unix_secs": 1_700_000_000
                 },
                 "platform_settings": {},
                 "tags": []
@@ -1467,8 +2044,8 @@ mod tests {
                 "name": "Support Bot",
                 "conversation_config": {},
                 "metadata": {
-                    "created_at_unix_secs": 1700000000,
-                    "updated_at_unix_secs": 1700001000
+                    "created_at_unix_secs": 1_700_000_000,
+                    "updated_at_unix_secs": 1_700_001_000
                 },
                 "platform_settings": {},
                 "tags": []
@@ -1481,6 +2058,131 @@ mod tests {
         assert_eq!(result.name, "Support Bot");
     }
 
+    #[tokio::test]
+    async fn test_list_branches_and_create_deployment() {
+        use crate::types::CreateDeploymentRequest;
+
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/agents/agent_xyz/branches"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "meta": {"total": 2},
+                "results": [
+                    {"id": "br_main", "name": "Main", "agent_id": "agent_xyz", "description": "",
+                     "created_at": 1, "last_committed_at": 2, "is_archived": false,
+                     "current_live_percentage": 1.0},
+                    {"id": "br_new", "name": "New prompt", "agent_id": "agent_xyz", "description": "",
+                     "created_at": 3, "last_committed_at": 4, "is_archived": false}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/agents/agent_xyz/deployments"))
+            .and(body_json(serde_json::json!({
+                "deployment_request": {"requests": [
+                    {"branch_id": "br_main", "deployment_strategy": {"type": "percentage", "traffic_percentage": 0.75}},
+                    {"branch_id": "br_new", "deployment_strategy": {"type": "percentage", "traffic_percentage": 0.25}}
+                ]}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "traffic_percentage_branch_id_map": {"br_main": 0.75, "br_new": 0.25}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let agents = client.agents();
+        let branches = agents.list_branches("agent_xyz").await.unwrap();
+        let live: Vec<&str> = branches.live().map(|branch| branch.id.as_str()).collect();
+        assert_eq!(live, ["br_main"]);
+
+        let request = CreateDeploymentRequest::traffic_split([("br_main", 0.75), ("br_new", 0.25)]);
+        let deployment = agents.create_deployment("agent_xyz", &request).await.unwrap();
+        assert!(
+            (deployment.traffic_percentage_branch_id_map["br_new"] - 0.25).abs() < f64::EPSILON
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_agent_merged_sends_changed_sections() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/agents/agent_xyz"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "agent_id": "agent_xyz",
+                "name": "Support Bot",
+                "conversation_config": {
+                    "agent": {"first_message": "Hi", "prompt": {"prompt": "Be nice", "llm": "gpt-4o"}},
+                    "tts": {"voice_id": "voice_1"}
+                },
+                "platform_settings": {"widget": {"variant": "compact"}},
+                "tags": ["support"]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/v1/convai/agents/agent_xyz"))
+            .and(body_json(serde_json::json!({
+                "conversation_config": {
+                    "agent": {"first_message": "Hi", "prompt": {"prompt": "Be kind", "llm": "gpt-4o"}},
+                    "tts": {"voice_id": "voice_1"}
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "agent_id": "agent_xyz",
+                "name": "Support Bot"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let patch = UpdateAgentRequest {
+            conversation_config: Some(
+                serde_json::json!({"agent": {"prompt": {"prompt": "Be kind"}}}),
+            ),
+            name: Some("Support Bot".into()),
+            ..Default::default()
+        };
+        let result = client.agents().update_agent_merged("agent_xyz", &patch).await.unwrap();
+        assert_eq!(result.diff.len(), 1);
+        assert_eq!(result.diff.changes[0].path(), "/conversation_config/agent/prompt/prompt");
+    }
+
+    #[tokio::test]
+    async fn test_update_agent_merged_skips_noop() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/agents/agent_xyz"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "agent_id": "agent_xyz",
+                "conversation_config": {"tts": {"voice_id": "voice_1"}}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let patch = UpdateAgentRequest {
+            conversation_config: Some(serde_json::json!({"tts": {"voice_id": "voice_1"}})),
+            ..Default::default()
+        };
+        let result = client.agents().update_agent_merged("agent_xyz", &patch).await.unwrap();
+        assert!(result.diff.is_empty());
+        assert_eq!(result.agent.agent_id, "agent_xyz");
+    }
+
     #[tokio::test]
     async fn test_delete_agent() {
         let mock_server = MockServer::start().await;
@@ -1495,6 +2197,105 @@ mod tests {
         client.agents().delete_agent("agent_xyz").await.unwrap();
     }
 
+    // -- Widget --------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_update_widget_merges_into_platform_settings() {
+        use crate::types::{WidgetFeedbackMode, WidgetTextContents, WidgetVariant};
+
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/agents/agent_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "agent_id": "agent_1",
+                "platform_settings": {"widget": {"variant": "full", "bg_color": "#000000"}}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/v1/convai/agents/agent_1"))
+            .and(body_json(serde_json::json!({
+                "platform_settings": {"widget": {
+                    "variant": "compact",
+                    "bg_color": "#000000",
+                    "feedback_mode": "end",
+                    "text_contents": {"main_label": "Help"}
+                }}
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"agent_id": "agent_1"})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = WidgetConfig {
+            variant: Some(WidgetVariant::Compact),
+            feedback_mode: Some(WidgetFeedbackMode::End),
+            text_contents: Some(WidgetTextContents {
+                main_label: Some("Help".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let update = client.agents().update_widget("agent_1", &config).await.unwrap();
+        assert!(update.diff.touches("/platform_settings/widget/variant"));
+        assert!(!update.diff.touches("/platform_settings/widget/bg_color"));
+    }
+
+    #[tokio::test]
+    async fn test_download_avatar_fetches_widget_image() {
+        use crate::types::WidgetVariant;
+
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/agents/agent_1/widget"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "agent_id": "agent_1",
+                "widget_config": {
+                    "variant": "full",
+                    "avatar": {"type": "image", "url": format!("{}/avatars/a.png", mock_server.uri())},
+                    "language_presets": {"de": {"text_contents": {"start_call": "Anrufen"}}},
+                    "shareable_page_text": "Hi"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/avatars/a.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"\x89PNG".to_vec(), "image/png"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/agents/agent_2/widget"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "agent_id": "agent_2",
+                "widget_config": {"avatar": {"type": "orb", "color_1": "#2792dc"}}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let agents = client.agents();
+        let widget = agents.get_agent_widget("agent_1").await.unwrap();
+        assert_eq!(widget.widget_config.variant, Some(WidgetVariant::Full));
+        let presets = widget.widget_config.language_presets.unwrap();
+        let de = presets["de"].text_contents.as_ref().unwrap();
+        assert_eq!(de.start_call.as_deref(), Some("Anrufen"));
+        assert!(widget.widget_config.extra.contains_key("shareable_page_text"));
+
+        let avatar = agents.download_avatar("agent_1").await.unwrap();
+        assert_eq!(avatar.data.as_ref(), b"\x89PNG");
+        assert_eq!(avatar.content_type.as_deref(), Some("image/png"));
+
+        let result = agents.download_avatar("agent_2").await;
+        assert!(matches!(result, Err(ElevenLabsError::Validation(_))));
+    }
+
     // -- Conversations -------------------------------------------------------
 
     #[tokio::test]
@@ -1512,7 +2313,30 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.agents().list_conversations(None, None).await.unwrap();
+        let result = client.agents().list_conversations(None, None, None).await.unwrap();
+        assert!(result.conversations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_conversations_in_date_range() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/conversations"))
+            .and(query_param("call_start_after_unix", "1700000000"))
+            .and(query_param("call_start_before_unix", "1700086399"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "conversations": [],
+                "next_cursor": null,
+                "has_more": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let range = DateRange::between(1_700_000_000, 1_700_086_399);
+        let result = client.agents().list_conversations(None, None, Some(range)).await.unwrap();
         assert!(result.conversations.is_empty());
     }
 
@@ -1528,7 +2352,7 @@ mod tests {
                 "status": "done",
                 "transcript": [],
                 "metadata": {
-                    "start_time_unix_secs": 1700000000,
+                    "start_time_unix_secs": 1_700_000_000,
                     "call_duration_secs": 30,
                     "deletion_settings": {},
                     "feedback": {"likes": 0, "dislikes": 0},
@@ -1546,6 +2370,23 @@ mod tests {
         assert_eq!(result.conversation_id, "conv_1");
     }
 
+    #[tokio::test]
+    async fn test_get_conversation_audio_captures_content_type() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/conversations/conv_1/audio"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"RIFF".to_vec(), "audio/wav"))
+            .mount(&mock_server)
+            .await;
+
+        let audio = client.agents().get_conversation_audio("conv_1").await.unwrap();
+        assert_eq!(audio.content_type, "audio/wav");
+        assert_eq!(audio.extension(), "wav");
+        assert_eq!(audio.data.as_ref(), b"RIFF");
+    }
+
     // -- Knowledge Base ------------------------------------------------------
 
     #[tokio::test]
@@ -1591,6 +2432,53 @@ mod tests {
         assert_eq!(result.name, "FAQ Page");
     }
 
+    fn rag_indexes(status: &str, progress: f64) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "indexes": [{
+                "id": "rag_1",
+                "model": "e5_mistral_7b_instruct",
+                "status": status,
+                "progress_percentage": progress,
+                "document_model_index_usage": {"used_bytes": 2048}
+            }]
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_rag_index_polls_until_terminal() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/knowledge-base/doc_1/rag-index"))
+            .respond_with(rag_indexes("processing", 50.0))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/knowledge-base/doc_1/rag-index"))
+            .respond_with(rag_indexes("succeeded", 100.0))
+            .mount(&mock_server)
+            .await;
+
+        let options = RagIndexWaitOptions {
+            model: Some(crate::types::EmbeddingModel::E5Mistral7bInstruct),
+            poll_interval: std::time::Duration::from_millis(1),
+            ..RagIndexWaitOptions::default()
+        };
+        let index = client.agents().wait_for_rag_index_with("doc_1", &options).await.unwrap();
+        assert!(index.status.is_ready());
+        assert_eq!(index.document_model_index_usage.used_bytes, 2048);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+
+        let options = RagIndexWaitOptions {
+            model: Some(crate::types::EmbeddingModel::Qwen3Embedding4b),
+            ..RagIndexWaitOptions::default()
+        };
+        let err = client.agents().wait_for_rag_index_with("doc_1", &options).await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+    }
+
     // -- Tools ---------------------------------------------------------------
 
     #[tokio::test]
@@ -1610,6 +2498,48 @@ mod tests {
         assert!(result.tools.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_tool_usage_report() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+        let access_info = serde_json::json!({
+            "is_creator": true,
+            "creator_name": "Alice",
+            "creator_email": "alice@example.com",
+            "role": "admin"
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/tools"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "tools": [
+                    {
+                        "id": "tool_busy",
+                        "tool_config": {
+                            "type": "webhook",
+                            "name": "lookup",
+                            "api_schema": {"url": "https://example.com/lookup"}
+                        },
+                        "access_info": access_info,
+                        "usage_stats": {"total_calls": 42, "avg_latency_secs": 0.3}
+                    },
+                    {
+                        "id": "tool_idle",
+                        "tool_config": {"type": "client", "name": "legacy"},
+                        "access_info": access_info,
+                        "usage_stats": {"avg_latency_secs": 0.0}
+                    }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let report = client.agents().tool_usage_report().await.unwrap();
+        assert_eq!(report.entries.len(), 2);
+        assert_eq!(report.entries[0].tool_id, "tool_idle");
+        assert_eq!(report.unused().count(), 1);
+    }
+
     // -- MCP Servers ---------------------------------------------------------
 
     #[tokio::test]
@@ -1629,6 +2559,42 @@ mod tests {
         assert!(result.mcp_servers.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_mcp_server_reports_reachability() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/mcp-servers/mcp_up/tools"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "tools": [{"name": "lookup", "description": "Find a customer", "inputSchema": {}}],
+                "error_message": null
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/mcp-servers/mcp_down/tools"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": false,
+                "tools": [],
+                "error_message": "connection refused"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let up = client.agents().test_mcp_server("mcp_up").await.unwrap();
+        assert!(up.reachable);
+        assert_eq!(up.tools[0].name, "lookup");
+        assert_eq!(up.error, None);
+
+        let down = client.agents().test_mcp_server("mcp_down").await.unwrap();
+        assert!(!down.reachable);
+        assert_eq!(down.error.as_deref(), Some("connection refused"));
+    }
+
     // -- Batch Calling -------------------------------------------------------
 
     #[tokio::test]
@@ -1693,6 +2659,34 @@ mod tests {
         assert_eq!(result.rag_retention_period_days, 10);
     }
 
+    #[tokio::test]
+    async fn test_update_dashboard_settings() {
+        use crate::types::ChartConfig;
+
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        let charts = serde_json::json!([{"type": "call_success", "name": "Success rate"}]);
+        Mock::given(method("PATCH"))
+            .and(path("/v1/convai/settings/dashboard"))
+            .and(body_json(serde_json::json!({"charts": charts})))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"charts": charts})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let chart = ChartConfig::CallSuccess { name: "Success rate".into() };
+        let request = PatchConvAiDashboardSettingsRequest { charts: Some(vec![chart.clone()]) };
+        let result = client.agents().update_dashboard_settings(&request).await.unwrap();
+        assert_eq!(result.charts, std::slice::from_ref(&chart));
+
+        let too_many = PatchConvAiDashboardSettingsRequest { charts: Some(vec![chart; 5]) };
+        let err = client.agents().update_dashboard_settings(&too_many).await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+    }
+
     // -- Phone Numbers -------------------------------------------------------
 
     #[tokio::test]
@@ -1766,6 +2760,103 @@ mod tests {
         client.agents().delete_agent_test("test_1").await.unwrap();
     }
 
+    // -- Simulation ----------------------------------------------------------
+
+    fn simulation_analysis() -> serde_json::Value {
+        serde_json::json!({
+            "call_successful": "success",
+            "transcript_summary": "Moved to Friday.",
+            "evaluation_criteria_results": {
+                "rescheduled": {"criteria_id": "rescheduled", "result": "success", "rationale": "ok"},
+                "polite": {"criteria_id": "polite", "result": "failure", "rationale": "curt"}
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn simulate_conversation_sends_spec_and_reads_outcomes() {
+        let mock_server = MockServer::start().await;
+        let client = crate::client::ElevenLabsClient::new(test_config(&mock_server.uri())).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/agents/agent_1/simulate-conversation"))
+            .and(body_json(serde_json::json!({
+                "simulation_specification": {
+                    "simulated_user_config": {"prompt": {"prompt": "Move my appointment."}},
+                    "tool_mock_config": {
+                        "get_slots": {"default_return_value": "[]", "default_is_error": false}
+                    }
+                },
+                "extra_evaluation_criteria": [{
+                    "id": "rescheduled",
+                    "name": "rescheduled",
+                    "conversation_goal_prompt": "The appointment was moved.",
+                    "use_knowledge_base": false,
+                    "type": "prompt"
+                }],
+                "new_turns_limit": 4
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "simulated_conversation": [
+                    {"role": "user", "message": "Can we move it?"},
+                    {"role": "agent", "message": "Friday works."}
+                ],
+                "analysis": simulation_analysis()
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let spec = SimulationSpec::new("Move my appointment.")
+            .max_turns(4)
+            .criterion(crate::types::SimulationCriterion::new(
+                "rescheduled",
+                "The appointment was moved.",
+            ))
+            .mock_tool("get_slots", crate::types::ToolMock::returning("[]"));
+        let result = client.agents().simulate_conversation("agent_1", &spec).await.unwrap();
+
+        assert_eq!(result.turns.len(), 2);
+        assert_eq!(result.turns[1].message.as_deref(), Some("Friday works."));
+        assert_eq!(
+            result.criterion("rescheduled").map(|c| c.result),
+            Some(crate::types::EvaluationSuccessResult::Success)
+        );
+        let failed: Vec<_> = result.failed_criteria().map(|c| c.criteria_id.as_str()).collect();
+        assert_eq!(failed, ["polite"]);
+        assert!(!result.passed());
+    }
+
+    #[tokio::test]
+    async fn simulation_events_split_lines_across_chunks() {
+        let analysis = serde_json::to_string(&serde_json::json!({
+            "analysis": simulation_analysis()
+        }))
+        .unwrap();
+        let body = format!(
+            "[{{\"role\":\"user\",\"message\":\"Hi\"}}]\ndata: {{\"role\":\"agent\",\
+             \"message\":\"Hello\"}}\n\n{analysis}"
+        );
+        let (head, tail) = body.split_at(20);
+        let chunks = stream::iter([
+            Ok(Bytes::copy_from_slice(head.as_bytes())),
+            Ok(Bytes::copy_from_slice(tail.as_bytes())),
+        ]);
+
+        let events: Vec<SimulationEvent> =
+            simulation_events(chunks).map(|event| event.unwrap()).collect().await;
+        assert_eq!(events.len(), 3);
+        assert!(
+            matches!(&events[0], SimulationEvent::Turn(t) if t.message.as_deref() == Some("Hi"))
+        );
+        assert!(
+            matches!(&events[1], SimulationEvent::Turn(t) if t.message.as_deref() == Some("Hello"))
+        );
+        assert!(
+            matches!(&events[2], SimulationEvent::Completed(a) if a.transcript_summary == "Moved to Friday.")
+        );
+    }
+
     // -- Query parameter helper -----------------------------------------------
 
     #[test]
@@ -7,14 +7,18 @@
 //! |--------|----------|-------------|
 //! | [`isolate`](AudioIsolationService::isolate) | `POST /v1/audio-isolation` | Isolate vocals/speech (full audio) |
 //! | [`isolate_stream`](AudioIsolationService::isolate_stream) | `POST /v1/audio-isolation/stream` | Isolate vocals/speech (streaming) |
+//! | [`isolate_file`](AudioIsolationService::isolate_file) | `POST /v1/audio-isolation` | Isolate vocals/speech from a file or reader |
+//! | [`isolate_file_stream`](AudioIsolationService::isolate_file_stream) | `POST /v1/audio-isolation/stream` | Isolate vocals/speech from a file or reader (streaming) |
 //!
 //! Both endpoints accept `multipart/form-data` with an audio file and
-//! optional configuration fields. The response is raw audio bytes.
+//! optional configuration fields. The response is raw audio bytes. The
+//! `*_file` methods take a [`FileInput`], which reads the audio from a path
+//! or [`AsyncRead`](tokio::io::AsyncRead) as it is uploaded.
 //!
 //! # Example
 //!
 //! ```no_run
-//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, types::AudioIsolationRequest};
+//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, FileInput, types::AudioIsolationRequest};
 //!
 //! # async fn example() -> elevenlabs_sdk::Result<()> {
 //! let config = ClientConfig::builder("your-api-key").build();
@@ -27,6 +31,10 @@
 //!     .await?;
 //!
 //! println!("Received {} bytes of isolated audio", audio.len());
+//!
+//! // Upload straight from disk without buffering the whole file.
+//! let input = FileInput::from_path("interview.wav");
+//! let audio = client.audio_isolation().isolate_file(&request, input).await?;
 //! # Ok(())
 //! # }
 //! ```
@@ -37,7 +45,8 @@ use futures_core::Stream;
 use crate::{
     client::ElevenLabsClient,
     error::Result,
-    types::{AudioIsolationRequest, AudioIsolationStreamRequest},
+    types::{AudioIsolationFileFormat, AudioIsolationRequest, AudioIsolationStreamRequest},
+    upload::{self, FileInput},
 };
 
 /// Audio isolation service providing typed access to vocal/speech isolation
@@ -119,6 +128,59 @@ impl<'a> AudioIsolationService<'a> {
         let ct = format!("multipart/form-data; boundary={boundary}");
         self.client.post_multipart_stream("/v1/audio-isolation/stream", body, &ct).await
     }
+
+    /// Isolates vocals/speech from `input`, returning the full isolated audio
+    /// as raw bytes.
+    ///
+    /// Like [`isolate`](Self::isolate), but the audio is read from a path or
+    /// reader while it is uploaded rather than passed in memory.
+    ///
+    /// Calls `POST /v1/audio-isolation` with `multipart/form-data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file input cannot be opened, reading it fails,
+    /// or the API request fails.
+    pub async fn isolate_file(
+        &self,
+        request: &AudioIsolationRequest,
+        input: FileInput,
+    ) -> Result<Bytes> {
+        let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
+        let mut fields = format_field(request.file_format.as_ref());
+        if let Some(ref preview) = request.preview_b64 {
+            fields.push(("preview_b64", preview.clone()));
+        }
+        let body = upload::multipart_body(&boundary, "audio", input, &fields).await?;
+        let ct = format!("multipart/form-data; boundary={boundary}");
+        self.client.post_multipart_bytes("/v1/audio-isolation", body, &ct).await
+    }
+
+    /// Isolates vocals/speech from `input`, returning a stream of audio byte
+    /// chunks.
+    ///
+    /// Like [`isolate_stream`](Self::isolate_stream), but the audio is read
+    /// from a path or reader while it is uploaded rather than passed in
+    /// memory.
+    ///
+    /// Calls `POST /v1/audio-isolation/stream` with `multipart/form-data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file input cannot be opened or the initial API
+    /// request fails. Individual stream items may also carry transport
+    /// errors.
+    pub async fn isolate_file_stream(
+        &self,
+        request: &AudioIsolationStreamRequest,
+        input: FileInput,
+    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<>> {
+        let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
+        let fields = format_field(request.file_format.as_ref());
+        let body = upload::multipart_body(&boundary, "audio", input, &fields).await?;
+        let ct = format!("multipart/form-data; boundary={boundary}");
+        self.client.post_multipart_stream("/v1/audio-isolation/stream", body, &ct).await
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -132,6 +194,15 @@ fn uuid_v4_simple() -> String {
     format!("{nanos:032x}")
 }
 
+/// Returns the `file_format` form field, if set.
+fn format_field(file_format: Option<&AudioIsolationFileFormat>) -> Vec<(&'static str, String)> {
+    file_format
+        .and_then(|ff| serde_json::to_string(ff).ok())
+        .map(|json| ("file_format", json.trim_matches('"').to_owned()))
+        .into_iter()
+        .collect()
+}
+
 /// Appends a text field to a multipart body buffer.
 fn append_text_field(buf: &mut Vec<u8>, boundary: &str, name: &str, value: &str) {
     buf.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
@@ -227,12 +298,13 @@ fn build_audio_isolation_stream_multipart(
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path},
+        matchers::{body_string_contains, header, method, path},
     };
 
     use crate::{
-        ElevenLabsClient,
+        ElevenLabsClient, FileInput,
         config::ClientConfig,
+        error::ElevenLabsError,
         types::{AudioIsolationRequest, AudioIsolationStreamRequest},
     };
 
@@ -318,6 +390,80 @@ mod tests {
         assert_stream(&stream);
     }
 
+    // -- file inputs --------------------------------------------------------
+
+    #[tokio::test]
+    async fn isolate_file_uploads_from_path() {
+        let mock_server = MockServer::start().await;
+        let input_path =
+            std::env::temp_dir().join(format!("elevenlabs-isolation-{}.wav", std::process::id()));
+        std::fs::write(&input_path, b"on-disk-audio").unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/v1/audio-isolation"))
+            .and(body_string_contains("on-disk-audio"))
+            .and(body_string_contains("Content-Type: audio/wav"))
+            .and(body_string_contains("pcm_s16le_16"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"output-audio", "audio/mpeg"))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request = AudioIsolationRequest {
+            file_format: Some(crate::types::AudioIsolationFileFormat::PcmS16le16),
+            preview_b64: None,
+        };
+        let result = client
+            .audio_isolation()
+            .isolate_file(&request, FileInput::from_path(&input_path))
+            .await
+            .unwrap();
+        assert_eq!(result.as_ref(), b"output-audio");
+
+        std::fs::remove_file(input_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn isolate_file_stream_uploads_from_reader() {
+        use futures_util::StreamExt;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/audio-isolation/stream"))
+            .and(body_string_contains("reader-audio"))
+            .and(body_string_contains("filename=\"live.mp3\""))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"isolated", "audio/mpeg"))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let input = FileInput::from_reader(&b"reader-audio"[..], "live.mp3", "audio/mpeg");
+        let svc = client.audio_isolation();
+        let stream =
+            svc.isolate_file_stream(&AudioIsolationStreamRequest::default(), input).await.unwrap();
+        let chunks: Vec<_> = stream.map(|chunk| chunk.unwrap()).collect().await;
+        assert_eq!(chunks.concat(), b"isolated");
+    }
+
+    #[tokio::test]
+    async fn isolate_file_rejects_missing_path() {
+        let config = ClientConfig::builder("test-key").base_url("http://127.0.0.1:9").build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let input = FileInput::from_path("/definitely/not/here.mp3");
+        let err = client
+            .audio_isolation()
+            .isolate_file(&AudioIsolationRequest::default(), input)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+    }
+
     // -- multipart helpers --------------------------------------------------
 
     #[test]
@@ -1,16 +1,27 @@
 //! Audio Native service providing access to Audio Native project endpoints.
 //!
-//! This module wraps the three Audio Native endpoints exposed by the
-//! ElevenLabs API:
+//! This module wraps the Audio Native endpoints exposed by the ElevenLabs
+//! API:
 //!
 //! | Method | Endpoint | Description |
 //! |--------|----------|-------------|
 //! | [`create_project`](AudioNativeService::create_project) | `POST /v1/audio-native` | Create an Audio Native project |
 //! | [`get_settings`](AudioNativeService::get_settings) | `GET /v1/audio-native/{project_id}/settings` | Get project settings |
+//! | [`update_settings`](AudioNativeService::update_settings) | `POST /v1/audio-native/{project_id}/settings` | Update player settings |
 //! | [`update_content`](AudioNativeService::update_content) | `POST /v1/audio-native/{project_id}/content` | Update project content |
+//! | [`get_snapshot`](AudioNativeService::get_snapshot) | `GET /v1/studio/projects/{project_id}/snapshots/{snapshot_id}` | Get the player's current snapshot (`studio` feature) |
+//! | [`wait_until_converted`](AudioNativeService::wait_until_converted) | `GET /v1/audio-native/{project_id}/settings` | Poll until the article audio is ready |
 //!
-//! The create and content-update endpoints accept `multipart/form-data`.
-//! The settings endpoint returns JSON.
+//! The create and update endpoints accept `multipart/form-data`; the
+//! [`create_from_content`](AudioNativeService::create_from_content) and
+//! [`update_from_content`](AudioNativeService::update_from_content) variants
+//! take an [`AudioNativeContent`] instead of a raw file.
+//! [`get_embed_html`](AudioNativeService::get_embed_html) returns a
+//! ready-to-paste player snippet for an existing project.
+//!
+//! Conversion runs in the background after a create or content update with
+//! `auto_convert`. [`wait_until_converted`](AudioNativeService::wait_until_converted)
+//! blocks until the player has audio to play.
 //!
 //! # Example
 //!
@@ -33,12 +44,15 @@
 //! # }
 //! ```
 
+#[cfg(feature = "studio")]
+use crate::types::ProjectSnapshotExtendedResponse;
 use crate::{
     client::ElevenLabsClient,
-    error::Result,
+    error::{ElevenLabsError, Result},
     types::{
-        AudioNativeCreateProjectRequest, AudioNativeCreateProjectResponse,
-        AudioNativeEditContentResponse, AudioNativeUpdateContentRequest,
+        AudioNativeContent, AudioNativeCreateProjectRequest, AudioNativeCreateProjectResponse,
+        AudioNativeEditContentResponse, AudioNativeEmbedOptions, AudioNativeUpdateContentRequest,
+        AudioNativeUpdateSettingsRequest, AudioNativeWaitOptions,
         GetAudioNativeProjectSettingsResponse,
     },
 };
@@ -83,6 +97,24 @@ impl<'a> AudioNativeService<'a> {
         self.client.post_multipart("/v1/audio-native", body, &content_type).await
     }
 
+    /// Creates an Audio Native–enabled project from HTML or plain-text
+    /// article content.
+    ///
+    /// Equivalent to [`create_project`](Self::create_project) with the file
+    /// built from `content`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn create_from_content(
+        &self,
+        request: &AudioNativeCreateProjectRequest,
+        content: &AudioNativeContent,
+    ) -> Result<AudioNativeCreateProjectResponse> {
+        self.create_project(request, Some(content.file_part())).await
+    }
+
     /// Retrieves settings for an Audio Native project.
     ///
     /// Calls `GET /v1/audio-native/{project_id}/settings`.
@@ -103,6 +135,121 @@ impl<'a> AudioNativeService<'a> {
         self.client.get(&path).await
     }
 
+    /// Retrieves the snapshot the player currently serves, with its
+    /// duration and character alignment.
+    ///
+    /// Reads `snapshot_id` from [`get_settings`](Self::get_settings) and
+    /// fetches it via
+    /// [`StudioService::get_project_snapshot`](crate::services::StudioService::get_project_snapshot).
+    /// Returns `None` while the project has no snapshot yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either API request fails or a response cannot be
+    /// deserialized.
+    #[cfg(feature = "studio")]
+    pub async fn get_snapshot(
+        &self,
+        project_id: &str,
+    ) -> Result<Option<ProjectSnapshotExtendedResponse>> {
+        let Some(snapshot_id) = self.get_settings(project_id).await?.snapshot_id else {
+            return Ok(None);
+        };
+        self.client.studio().get_project_snapshot(project_id, &snapshot_id).await.map(Some)
+    }
+
+    /// Waits for a project's audio to be converted with the default
+    /// [`AudioNativeWaitOptions`].
+    ///
+    /// # Errors
+    ///
+    /// See [`wait_until_converted_with`](Self::wait_until_converted_with).
+    pub async fn wait_until_converted(
+        &self,
+        project_id: &str,
+    ) -> Result<GetAudioNativeProjectSettingsResponse> {
+        self.wait_until_converted_with(project_id, &AudioNativeWaitOptions::default()).await
+    }
+
+    /// Polls [`get_settings`](Self::get_settings) until the project's
+    /// article audio is ready, as reported by
+    /// [`is_converted`](GetAudioNativeProjectSettingsResponse::is_converted).
+    ///
+    /// Returns the settings in their converted state, including the
+    /// `audio_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Timeout`] if `options.timeout` elapses, or
+    /// an error if a settings request fails.
+    pub async fn wait_until_converted_with(
+        &self,
+        project_id: &str,
+        options: &AudioNativeWaitOptions,
+    ) -> Result<GetAudioNativeProjectSettingsResponse> {
+        let deadline = options.timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+        loop {
+            let settings = self.get_settings(project_id).await?;
+            if settings.is_converted() {
+                return Ok(settings);
+            }
+            if deadline.is_some_and(|deadline| tokio::time::Instant::now() >= deadline) {
+                return Err(ElevenLabsError::Timeout);
+            }
+            tokio::time::sleep(options.poll_interval).await;
+        }
+    }
+
+    /// Updates the player settings of an Audio Native project.
+    ///
+    /// Calls `POST /v1/audio-native/{project_id}/settings` with
+    /// `multipart/form-data`. Unset fields keep their current values.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` — The project ID to update.
+    /// * `request` — Settings to change (title, author, colors, etc.).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn update_settings(
+        &self,
+        project_id: &str,
+        request: &AudioNativeUpdateSettingsRequest,
+    ) -> Result<GetAudioNativeProjectSettingsResponse> {
+        let path = format!("/v1/audio-native/{project_id}/settings");
+        let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
+        let body = build_update_settings_multipart(&boundary, request);
+        let content_type = format!("multipart/form-data; boundary={boundary}");
+        self.client.post_multipart(&path, body, &content_type).await
+    }
+
+    /// Returns a ready-to-paste HTML snippet that embeds the player for
+    /// `project_id`.
+    ///
+    /// Fetches the project settings first so that a snippet is only produced
+    /// for projects with Audio Native enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if Audio Native is not
+    /// enabled for the project, or an error if the settings request fails.
+    pub async fn get_embed_html(
+        &self,
+        project_id: &str,
+        options: &AudioNativeEmbedOptions,
+    ) -> Result<String> {
+        let settings = self.get_settings(project_id).await?;
+        if !settings.enabled {
+            return Err(ElevenLabsError::Validation(format!(
+                "Audio Native is not enabled for project {project_id}"
+            )));
+        }
+        Ok(options.to_html(project_id))
+    }
+
     /// Updates the content of an Audio Native project.
     ///
     /// Calls `POST /v1/audio-native/{project_id}/content` with
@@ -131,6 +278,25 @@ impl<'a> AudioNativeService<'a> {
         let content_type = format!("multipart/form-data; boundary={boundary}");
         self.client.post_multipart(&path, body, &content_type).await
     }
+
+    /// Replaces the article of an Audio Native project with HTML or
+    /// plain-text content.
+    ///
+    /// Equivalent to [`update_content`](Self::update_content) with the file
+    /// built from `content`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn update_from_content(
+        &self,
+        project_id: &str,
+        request: &AudioNativeUpdateContentRequest,
+        content: &AudioNativeContent,
+    ) -> Result<AudioNativeEditContentResponse> {
+        self.update_content(project_id, request, Some(content.file_part())).await
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -291,6 +457,41 @@ fn build_update_content_multipart(
     buf
 }
 
+/// Builds the multipart body for
+/// `POST /v1/audio-native/{project_id}/settings`.
+fn build_update_settings_multipart(
+    boundary: &str,
+    request: &AudioNativeUpdateSettingsRequest,
+) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    let text_fields = [
+        ("title", request.title.as_deref()),
+        ("image", request.image.as_deref()),
+        ("author", request.author.as_deref()),
+        ("text_color", request.text_color.as_deref()),
+        ("background_color", request.background_color.as_deref()),
+    ];
+    for (name, value) in text_fields {
+        if let Some(value) = value {
+            append_text_field(&mut buf, boundary, name, value);
+        }
+    }
+
+    // small (bool → string, optional)
+    if let Some(small) = request.small {
+        append_text_field(&mut buf, boundary, "small", if small { "true" } else { "false" });
+    }
+
+    // sessionization (optional)
+    if let Some(sessionization) = request.sessionization {
+        append_text_field(&mut buf, boundary, "sessionization", &sessionization.to_string());
+    }
+
+    buf.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+    buf
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -300,13 +501,18 @@ fn build_update_content_multipart(
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path},
+        matchers::{body_string_contains, header, method, path},
     };
 
     use crate::{
         ElevenLabsClient,
         config::ClientConfig,
-        types::{AudioNativeCreateProjectRequest, AudioNativeUpdateContentRequest},
+        error::ElevenLabsError,
+        types::{
+            AudioNativeContent, AudioNativeCreateProjectRequest, AudioNativeEmbedOptions,
+            AudioNativeUpdateContentRequest, AudioNativeUpdateSettingsRequest,
+            AudioNativeWaitOptions,
+        },
     };
 
     // -- create_project -----------------------------------------------------
@@ -409,6 +615,89 @@ mod tests {
         assert_eq!(settings.title, "My Article");
     }
 
+    // -- get_snapshot / wait_until_converted ---------------------------------
+
+    fn settings_body(status: &str, audio_url: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "enabled": true,
+            "snapshot_id": "snap_123",
+            "settings": {
+                "title": "My Article",
+                "status": status,
+                "audio_path": audio_url.map(|_| "audio/snap_123.mp3"),
+                "audio_url": audio_url
+            }
+        })
+    }
+
+    #[cfg(feature = "studio")]
+    #[tokio::test]
+    async fn get_snapshot_follows_settings_snapshot_id() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/audio-native/proj_abc/settings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(settings_body("ready", None)))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/studio/projects/proj_abc/snapshots/snap_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "project_snapshot_id": "snap_123",
+                "project_id": "proj_abc",
+                "name": "Latest",
+                "audio_duration_secs": 42.5
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let snapshot = client.audio_native().get_snapshot("proj_abc").await.unwrap().unwrap();
+
+        assert_eq!(snapshot.project_snapshot_id, "snap_123");
+        assert!((snapshot.audio_duration_secs - 42.5).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn wait_until_converted_polls_until_audio_is_ready() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/audio-native/proj_abc/settings"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(settings_body("processing", None)),
+            )
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/audio-native/proj_abc/settings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(settings_body(
+                "ready",
+                Some("https://cdn.example.com/snap_123.mp3"),
+            )))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let options = AudioNativeWaitOptions {
+            poll_interval: std::time::Duration::from_millis(10),
+            ..AudioNativeWaitOptions::default()
+        };
+        let settings =
+            client.audio_native().wait_until_converted_with("proj_abc", &options).await.unwrap();
+
+        assert!(settings.is_converted());
+        assert_eq!(
+            settings.settings.unwrap().audio_url.as_deref(),
+            Some("https://cdn.example.com/snap_123.mp3")
+        );
+    }
+
     // -- update_content -----------------------------------------------------
 
     #[tokio::test]
@@ -446,6 +735,145 @@ mod tests {
         assert!(!result.publishing);
     }
 
+    #[tokio::test]
+    async fn update_from_content_uploads_text_file() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/audio-native/proj_abc/content"))
+            .and(body_string_contains("filename=\"article.txt\""))
+            .and(body_string_contains("Plain article body"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "project_id": "proj_abc",
+                "converting": false,
+                "publishing": false,
+                "html_snippet": ""
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let content = AudioNativeContent::Text("Plain article body".into());
+        let result = client
+            .audio_native()
+            .update_from_content("proj_abc", &AudioNativeUpdateContentRequest::default(), &content)
+            .await
+            .unwrap();
+        assert_eq!(result.project_id, "proj_abc");
+    }
+
+    #[tokio::test]
+    async fn create_from_content_uploads_html_file() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/audio-native"))
+            .and(body_string_contains("filename=\"article.html\""))
+            .and(body_string_contains("Content-Type: text/html"))
+            .and(body_string_contains("name=\"voice_id\"\r\n\r\nv1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "project_id": "proj_new",
+                "converting": true,
+                "html_snippet": "<div></div>"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request =
+            AudioNativeCreateProjectRequest::new("Post").voice_id("v1").auto_convert(true);
+        let content = AudioNativeContent::Html("<html><body><p>Hi</p></body></html>".into());
+        let result = client.audio_native().create_from_content(&request, &content).await.unwrap();
+        assert_eq!(result.project_id, "proj_new");
+    }
+
+    // -- update_settings ----------------------------------------------------
+
+    #[tokio::test]
+    async fn update_settings_sends_only_set_fields() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/audio-native/proj_abc/settings"))
+            .and(body_string_contains("name=\"title\"\r\n\r\nNew title"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "enabled": true,
+                "snapshot_id": null,
+                "settings": null
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request = AudioNativeUpdateSettingsRequest {
+            title: Some("New title".into()),
+            ..Default::default()
+        };
+        let result = client.audio_native().update_settings("proj_abc", &request).await.unwrap();
+        assert!(result.enabled);
+
+        let body = super::build_update_settings_multipart("b", &request);
+        let body = String::from_utf8_lossy(&body);
+        assert!(!body.contains("author"));
+        assert!(!body.contains("small"));
+    }
+
+    // -- get_embed_html -----------------------------------------------------
+
+    #[tokio::test]
+    async fn get_embed_html_renders_snippet_for_enabled_project() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/audio-native/proj_abc/settings"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"enabled": true})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let html = client
+            .audio_native()
+            .get_embed_html("proj_abc", &AudioNativeEmbedOptions::default())
+            .await
+            .unwrap();
+        assert!(html.contains(r#"data-projectid="proj_abc""#));
+    }
+
+    #[tokio::test]
+    async fn get_embed_html_rejects_disabled_project() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/audio-native/proj_off/settings"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"enabled": false})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let result = client
+            .audio_native()
+            .get_embed_html("proj_off", &AudioNativeEmbedOptions::default())
+            .await;
+        assert!(matches!(result, Err(ElevenLabsError::Validation(_))));
+    }
+
     // -- multipart helpers --------------------------------------------------
 
     #[test]
@@ -0,0 +1,439 @@
+//! Parallel bulk text-to-speech for large narration batches.
+//!
+//! [`BulkSynthesizer`] runs many [`BulkJob`]s through
+//! [`TextToSpeechService::convert_with_metadata`] with bounded concurrency,
+//! an optional request rate limit, and per-job retries. Results are yielded
+//! as a stream of [`JobResult`]s in completion order, so clips can be written
+//! out while the rest of the batch is still running.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use elevenlabs_sdk::{BulkJob, ClientConfig, ElevenLabsClient, types::TextToSpeechRequest};
+//! use futures_util::StreamExt;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = ClientConfig::builder("your-api-key").build();
+//! let client = ElevenLabsClient::new(config)?;
+//!
+//! let lines = ["Chapter one.", "It was a dark and stormy night."];
+//! let jobs = lines.iter().enumerate().map(|(i, line)| {
+//!     BulkJob::new(format!("clip_{i:04}"), "voice_id", TextToSpeechRequest::new(*line))
+//! });
+//!
+//! let tts = client.text_to_speech();
+//! let bulk = tts
+//!     .bulk()
+//!     .concurrency(8)
+//!     .rate_limit(120, Duration::from_secs(60))
+//!     .on_progress(|p| eprintln!("[{}/{}] {}", p.completed, p.total, p.result.id));
+//! let mut results = std::pin::pin!(bulk.run(jobs));
+//! while let Some(result) = results.next().await {
+//!     match result.outcome {
+//!         Ok(audio) => std::fs::write(format!("{}.mp3", result.id), &audio.audio)?,
+//!         Err(e) => eprintln!("{} failed after {} attempts: {e}", result.id, result.attempts),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use futures_core::Stream;
+use futures_util::{StreamExt, stream};
+
+use crate::{
+    client::ElevenLabsClient,
+    error::{ElevenLabsError, Result},
+    middleware,
+    services::TextToSpeechService,
+    types::{OutputFormat, TextToSpeechAudio, TextToSpeechRequest},
+};
+
+/// Number of jobs run concurrently unless overridden with
+/// [`BulkSynthesizer::concurrency`].
+pub const DEFAULT_BULK_CONCURRENCY: usize = 4;
+
+/// Attempts made per job unless overridden with
+/// [`BulkSynthesizer::max_attempts`].
+pub const DEFAULT_BULK_MAX_ATTEMPTS: u32 = 3;
+
+/// Base delay between attempts of a job unless overridden with
+/// [`BulkSynthesizer::retry_backoff`].
+pub const DEFAULT_BULK_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Callback invoked after each job finishes.
+type ProgressCallback<'a> = Box<dyn Fn(BulkProgress<'_>) + Send + Sync + 'a>;
+
+/// One text-to-speech request in a bulk batch.
+#[derive(Debug, Clone)]
+pub struct BulkJob {
+    /// Caller-chosen identifier, echoed in the job's [`JobResult`].
+    pub id: String,
+    /// Voice to synthesize with.
+    pub voice_id: String,
+    /// The TTS request body.
+    pub request: TextToSpeechRequest,
+}
+
+impl BulkJob {
+    /// Creates a job synthesizing `request` with `voice_id`.
+    pub fn new(
+        id: impl Into<String>,
+        voice_id: impl Into<String>,
+        request: TextToSpeechRequest,
+    ) -> Self {
+        Self { id: id.into(), voice_id: voice_id.into(), request }
+    }
+}
+
+/// Outcome of one [`BulkJob`].
+#[derive(Debug)]
+pub struct JobResult {
+    /// The job's [`BulkJob::id`].
+    pub id: String,
+    /// Position of the job in the input.
+    pub index: usize,
+    /// Number of requests made for the job, including the successful one.
+    pub attempts: u32,
+    /// Time from the start of the batch until the job's first request.
+    pub queued: Duration,
+    /// Time from the job's first request until it finished, including
+    /// retries and rate-limit waits between them.
+    pub elapsed: Duration,
+    /// The generated audio, or the error from the last attempt.
+    pub outcome: Result<TextToSpeechAudio>,
+}
+
+impl JobResult {
+    /// Returns `true` if the job produced audio.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.outcome.is_ok()
+    }
+}
+
+/// Progress notification emitted after each job finishes.
+#[derive(Debug, Clone, Copy)]
+pub struct BulkProgress<'a> {
+    /// Number of jobs finished so far, including this one.
+    pub completed: usize,
+    /// Number of finished jobs that failed, including this one.
+    pub failed: usize,
+    /// Total number of jobs in the batch.
+    pub total: usize,
+    /// The job that just finished.
+    pub result: &'a JobResult,
+}
+
+/// Synthesizes many TTS requests concurrently.
+///
+/// Obtained via [`TextToSpeechService::bulk`]. Each job is retried with
+/// exponential backoff when it fails with a rate limit, a server error
+/// (HTTP 5xx), a timeout, or a transport error; other errors fail the job
+/// immediately. These retries come on top of the client's own
+/// [`max_retries`](crate::ClientConfig::max_retries), so a batch keeps going
+/// through longer outages than a single request would.
+///
+/// A failed job does not abort the batch; its error is reported in its
+/// [`JobResult`].
+pub struct BulkSynthesizer<'a> {
+    tts: TextToSpeechService<'a>,
+    concurrency: usize,
+    max_attempts: u32,
+    retry_backoff: Duration,
+    min_interval: Option<Duration>,
+    output_format: Option<OutputFormat>,
+    on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl fmt::Debug for BulkSynthesizer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BulkSynthesizer")
+            .field("concurrency", &self.concurrency)
+            .field("max_attempts", &self.max_attempts)
+            .field("retry_backoff", &self.retry_backoff)
+            .field("min_interval", &self.min_interval)
+            .field("output_format", &self.output_format)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> BulkSynthesizer<'a> {
+    /// Creates a synthesizer bound to the given client.
+    pub(crate) const fn new(client: &'a ElevenLabsClient) -> Self {
+        Self {
+            tts: TextToSpeechService::new(client),
+            concurrency: DEFAULT_BULK_CONCURRENCY,
+            max_attempts: DEFAULT_BULK_MAX_ATTEMPTS,
+            retry_backoff: DEFAULT_BULK_RETRY_BACKOFF,
+            min_interval: None,
+            output_format: None,
+            on_progress: None,
+        }
+    }
+
+    /// Sets the maximum number of jobs in flight at once (at least 1).
+    #[must_use]
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets the number of attempts made per job before giving up (at
+    /// least 1).
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the base delay between attempts of a job. The delay doubles
+    /// with each attempt and honours the server's `Retry-After`.
+    #[must_use]
+    pub const fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Starts at most `requests` requests, retries included, in any window
+    /// of length `per`. Requests are spaced evenly rather than sent in
+    /// bursts.
+    #[must_use]
+    pub fn rate_limit(mut self, requests: u32, per: Duration) -> Self {
+        self.min_interval = Some(per / requests.max(1));
+        self
+    }
+
+    /// Sets the output format for every job.
+    #[must_use]
+    pub const fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+
+    /// Registers a callback invoked after each job finishes, in completion
+    /// order.
+    #[must_use]
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(BulkProgress<'_>) + Send + Sync + 'a,
+    {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Runs `jobs` and yields each [`JobResult`] as soon as the job
+    /// finishes.
+    ///
+    /// Nothing is sent until the stream is polled. Dropping the stream
+    /// cancels the jobs still in flight.
+    pub fn run<I>(&self, jobs: I) -> impl Stream<Item = JobResult> + '_
+    where
+        I: IntoIterator<Item = BulkJob>,
+    {
+        let jobs: Vec<BulkJob> = jobs.into_iter().collect();
+        let total = jobs.len();
+        let started = Instant::now();
+        let throttle = Arc::new(Throttle::new(self.min_interval));
+
+        stream::iter(jobs.into_iter().enumerate())
+            .map(move |(index, job)| {
+                let throttle = Arc::clone(&throttle);
+                async move { self.run_one(index, job, started, &throttle).await }
+            })
+            .buffer_unordered(self.concurrency)
+            .scan((0, 0), move |(completed, failed), result| {
+                *completed += 1;
+                if !result.is_success() {
+                    *failed += 1;
+                }
+                if let Some(callback) = &self.on_progress {
+                    callback(BulkProgress {
+                        completed: *completed,
+                        failed: *failed,
+                        total,
+                        result: &result,
+                    });
+                }
+                std::future::ready(Some(result))
+            })
+    }
+
+    /// Runs one job, retrying transient failures.
+    async fn run_one(
+        &self,
+        index: usize,
+        job: BulkJob,
+        started: Instant,
+        throttle: &Throttle,
+    ) -> JobResult {
+        let first_attempt = Instant::now();
+        let mut attempts = 0;
+        let outcome = loop {
+            throttle.wait().await;
+            attempts += 1;
+            let outcome = self
+                .tts
+                .convert_with_metadata(&job.voice_id, &job.request, self.output_format, None)
+                .await;
+            match outcome {
+                Err(e) if attempts < self.max_attempts && middleware::is_transient(&e) => {
+                    let retry_after = match &e {
+                        ElevenLabsError::RateLimited { retry_after, .. } => *retry_after,
+                        _ => None,
+                    };
+                    tracing::debug!(job = %job.id, attempts, error = %e, "retrying bulk TTS job");
+                    let delay =
+                        middleware::compute_delay(attempts - 1, self.retry_backoff, retry_after);
+                    tokio::time::sleep(delay).await;
+                }
+                outcome => break outcome,
+            }
+        };
+        JobResult {
+            id: job.id,
+            index,
+            attempts,
+            queued: first_attempt.duration_since(started),
+            elapsed: first_attempt.elapsed(),
+            outcome,
+        }
+    }
+}
+
+/// Spaces request starts at least `min_interval` apart across all jobs.
+#[derive(Debug)]
+struct Throttle {
+    min_interval: Option<Duration>,
+    next_slot: Mutex<Option<tokio::time::Instant>>,
+}
+
+impl Throttle {
+    const fn new(min_interval: Option<Duration>) -> Self {
+        Self { min_interval, next_slot: Mutex::new(None) }
+    }
+
+    /// Reserves the next free slot and sleeps until it arrives.
+    async fn wait(&self) {
+        let Some(interval) = self.min_interval else { return };
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap_or_else(PoisonError::into_inner);
+            let now = tokio::time::Instant::now();
+            let slot = next_slot.map_or(now, |next| next.max(now));
+            *next_slot = Some(slot + interval);
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_partial_json, method, path},
+    };
+
+    use super::*;
+
+    fn test_client(base_url: &str) -> ElevenLabsClient {
+        let config = crate::config::ClientConfig::builder("test-key")
+            .base_url(base_url)
+            .max_retries(0_u32)
+            .build();
+        ElevenLabsClient::new(config).unwrap()
+    }
+
+    fn job(id: &str, text: &str) -> BulkJob {
+        BulkJob::new(id, "voice123", TextToSpeechRequest::new(text))
+    }
+
+    #[tokio::test]
+    async fn run_retries_transient_failures_and_reports_progress() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice123"))
+            .and(body_partial_json(serde_json::json!({"text": "flaky"})))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice123"))
+            .and(body_partial_json(serde_json::json!({"text": "invalid"})))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "detail": {"status": "invalid_request", "message": "bad text"}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice123"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(&b"audio"[..], "audio/mpeg"))
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let tts = client.text_to_speech();
+        let progress = AtomicUsize::new(0);
+        let bulk =
+            tts.bulk().concurrency(2).retry_backoff(Duration::from_millis(1)).on_progress(|p| {
+                assert_eq!(p.total, 3);
+                progress.fetch_add(1, Ordering::Relaxed);
+            });
+        let jobs = [job("a", "fine"), job("b", "flaky"), job("c", "invalid")];
+        let mut results: Vec<JobResult> = bulk.run(jobs).collect().await;
+        results.sort_by_key(|result| result.index);
+
+        assert_eq!(progress.load(Ordering::Relaxed), 3);
+        let ids: Vec<&str> = results.iter().map(|result| result.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        assert_eq!(results[0].attempts, 1);
+        assert_eq!(results[0].outcome.as_ref().unwrap().audio.as_ref(), b"audio");
+        assert_eq!(results[1].attempts, 2);
+        assert!(results[1].is_success());
+        assert_eq!(results[2].attempts, 1);
+        assert!(!results[2].is_success());
+    }
+
+    #[tokio::test]
+    async fn run_gives_up_after_max_attempts() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice123"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let tts = client.text_to_speech();
+        let bulk = tts.bulk().max_attempts(2).retry_backoff(Duration::from_millis(1));
+        let results: Vec<JobResult> = bulk.run([job("a", "down")]).collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].attempts, 2);
+        assert!(matches!(results[0].outcome, Err(ElevenLabsError::Api { status: 500, .. })));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn throttle_spaces_requests_evenly() {
+        let throttle = Throttle::new(Some(Duration::from_millis(100)));
+        let start = tokio::time::Instant::now();
+        for _ in 0..3 {
+            throttle.wait().await;
+        }
+        assert_eq!(start.elapsed(), Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,391 @@
+//! Bulk export of an agent's conversations to a directory archive.
+//!
+//! [`AgentsService::export_conversations`] pages through every conversation
+//! of an agent in a date range, fetches transcripts (and optionally
+//! recordings) concurrently with retries, and writes:
+//!
+//! - `transcripts.jsonl` — one [`GetConversationResponse`] per line
+//! - `audio/<conversation_id>.<ext>` — recordings, when requested
+//! - `index.csv` — one row per conversation with its metadata, transcript line, recording path and
+//!   any error
+//!
+//! # Example
+//!
+//! ```no_run
+//! use elevenlabs_sdk::{
+//!     ClientConfig, ElevenLabsClient,
+//!     types::{ConversationExportFormat, DateRange},
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+//!
+//! let summary = client
+//!     .agents()
+//!     .export_conversations(
+//!         "agent_id",
+//!         DateRange::between(1_735_689_600, 1_738_367_999),
+//!         ConversationExportFormat::TranscriptsAndAudio,
+//!         "archive/2025-01",
+//!     )
+//!     .await?;
+//! println!("{} exported, {} failed", summary.exported().count(), summary.failed().count());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use futures_util::{StreamExt, stream};
+use tokio::io::{AsyncWriteExt, BufWriter};
+
+use crate::{
+    client::ElevenLabsClient,
+    error::{ElevenLabsError, Result},
+    middleware,
+    services::AgentsService,
+    types::{
+        ConversationExportFormat, ConversationExportItem, ConversationExportStatus,
+        ConversationExportSummary, ConversationSummary, DateRange, GetConversationResponse,
+        GetConversationsResponse,
+    },
+};
+
+/// Number of conversations fetched concurrently.
+pub const EXPORT_CONCURRENCY: usize = 4;
+
+/// Attempts made per request before a conversation is reported as failed.
+pub const EXPORT_MAX_ATTEMPTS: u32 = 3;
+
+/// Base delay between attempts, doubled with each attempt.
+const EXPORT_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Columns of `index.csv`.
+const INDEX_HEADER: &str = "conversation_id,agent_id,start_time_unix_secs,call_duration_secs,\
+                            message_count,status,call_successful,transcript_line,audio_file,error";
+
+/// Runs an export; see [`AgentsService::export_conversations`].
+pub(crate) async fn export(
+    client: &ElevenLabsClient,
+    agent_id: &str,
+    range: DateRange,
+    format: ConversationExportFormat,
+    dest: &Path,
+) -> Result<ConversationExportSummary> {
+    let conversations = list_all(client, agent_id, range).await?;
+    tokio::fs::create_dir_all(dest).await?;
+    if format.includes_audio() {
+        tokio::fs::create_dir_all(dest.join("audio")).await?;
+    }
+
+    let mut transcripts =
+        BufWriter::new(tokio::fs::File::create(dest.join("transcripts.jsonl")).await?);
+    let mut index = BufWriter::new(tokio::fs::File::create(dest.join("index.csv")).await?);
+    index.write_all(format!("{INDEX_HEADER}\n").as_bytes()).await?;
+
+    let agents = client.agents();
+    let mut fetched = stream::iter(conversations)
+        .map(|conversation| {
+            let agents = &agents;
+            async move {
+                let outcome = fetch(agents, &conversation.conversation_id, format, dest).await;
+                (conversation, outcome)
+            }
+        })
+        .buffered(EXPORT_CONCURRENCY);
+
+    let mut summary = ConversationExportSummary::default();
+    let mut line = 0;
+    while let Some((conversation, outcome)) = fetched.next().await {
+        let status = match outcome {
+            Ok((detail, audio_file)) => {
+                transcripts.write_all(&serde_json::to_vec(&detail)?).await?;
+                transcripts.write_all(b"\n").await?;
+                line += 1;
+                ConversationExportStatus::Exported { transcript_line: line, audio_file }
+            }
+            Err(e) => {
+                tracing::warn!(
+                    conversation_id = %conversation.conversation_id,
+                    error = %e,
+                    "conversation export failed"
+                );
+                ConversationExportStatus::Failed { error: e.to_string() }
+            }
+        };
+        let item = ConversationExportItem { conversation, status };
+        index.write_all(index_row(&item).as_bytes()).await?;
+        summary.items.push(item);
+    }
+    transcripts.flush().await?;
+    index.flush().await?;
+    Ok(summary)
+}
+
+/// Pages through every conversation of `agent_id` started inside `range`.
+async fn list_all(
+    client: &ElevenLabsClient,
+    agent_id: &str,
+    range: DateRange,
+) -> Result<Vec<ConversationSummary>> {
+    let mut conversations = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut path = format!("/v1/convai/conversations?agent_id={agent_id}");
+        if let Some(after) = range.after {
+            path.push_str(&format!("&call_start_after_unix={after}"));
+        }
+        if let Some(before) = range.before {
+            path.push_str(&format!("&call_start_before_unix={before}"));
+        }
+        if let Some(cursor) = &cursor {
+            path.push_str(&format!("&cursor={cursor}"));
+        }
+        let page: GetConversationsResponse = with_retry(|| client.get(&path)).await?;
+        conversations.extend(
+            page.conversations
+                .into_iter()
+                .filter(|conversation| range.contains(conversation.start_time_unix_secs)),
+        );
+        match page.next_cursor {
+            Some(next) if page.has_more => cursor = Some(next),
+            _ => return Ok(conversations),
+        }
+    }
+}
+
+/// Fetches one transcript and, if requested and available, its recording.
+///
+/// Returns the recording path relative to `dest`.
+async fn fetch(
+    agents: &AgentsService<'_>,
+    conversation_id: &str,
+    format: ConversationExportFormat,
+    dest: &Path,
+) -> Result<(GetConversationResponse, Option<PathBuf>)> {
+    let detail = with_retry(|| agents.get_conversation(conversation_id)).await?;
+    if !format.includes_audio() || !detail.has_audio {
+        return Ok((detail, None));
+    }
+    let audio = with_retry(|| agents.get_conversation_audio(conversation_id)).await?;
+    let relative = Path::new("audio").join(conversation_id).with_extension(audio.extension());
+    tokio::fs::write(dest.join(&relative), &audio.data).await?;
+    Ok((detail, Some(relative)))
+}
+
+/// Runs `request`, retrying transient failures up to
+/// [`EXPORT_MAX_ATTEMPTS`] times.
+async fn with_retry<T, F, Fut>(mut request: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match request().await {
+            Err(e) if attempts < EXPORT_MAX_ATTEMPTS && middleware::is_transient(&e) => {
+                let retry_after = match &e {
+                    ElevenLabsError::RateLimited { retry_after, .. } => *retry_after,
+                    _ => None,
+                };
+                tracing::debug!(attempts, error = %e, "retrying conversation export request");
+                tokio::time::sleep(middleware::compute_delay(
+                    attempts - 1,
+                    EXPORT_RETRY_BACKOFF,
+                    retry_after,
+                ))
+                .await;
+            }
+            outcome => return outcome,
+        }
+    }
+}
+
+/// Formats one `index.csv` row, including the trailing newline.
+fn index_row(item: &ConversationExportItem) -> String {
+    let c = &item.conversation;
+    let (line, audio, error) = match &item.status {
+        ConversationExportStatus::Exported { transcript_line, audio_file } => (
+            transcript_line.to_string(),
+            audio_file.as_ref().map(|p| p.display().to_string()).unwrap_or_default(),
+            String::new(),
+        ),
+        ConversationExportStatus::Failed { error } => (String::new(), String::new(), error.clone()),
+    };
+    let fields = [
+        c.conversation_id.clone(),
+        c.agent_id.clone(),
+        c.start_time_unix_secs.to_string(),
+        c.call_duration_secs.to_string(),
+        c.message_count.to_string(),
+        label(&c.status),
+        label(&c.call_successful),
+        line,
+        audio,
+        error,
+    ];
+    let mut row = fields.iter().map(|field| csv_field(field)).collect::<Vec<_>>().join(",");
+    row.push('\n');
+    row
+}
+
+/// Returns the serialized name of a unit enum variant.
+fn label(value: &impl serde::Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        _ => String::new(),
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path, query_param},
+    };
+
+    use super::*;
+
+    fn summary(id: &str, start: i64) -> serde_json::Value {
+        serde_json::json!({
+            "agent_id": "agent_1",
+            "conversation_id": id,
+            "start_time_unix_secs": start,
+            "call_duration_secs": 30,
+            "message_count": 4,
+            "status": "done",
+            "call_successful": "success",
+            "transcript_summary": "Caller asked, \"where is my order?\""
+        })
+    }
+
+    fn detail(id: &str, has_audio: bool) -> serde_json::Value {
+        serde_json::json!({
+            "agent_id": "agent_1",
+            "conversation_id": id,
+            "status": "done",
+            "transcript": [{"role": "user", "message": "hello", "time_in_call_secs": 1}],
+            "has_audio": has_audio
+        })
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[tokio::test]
+    async fn export_writes_transcripts_audio_and_index() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/conversations"))
+            .and(query_param("call_start_after_unix", "100"))
+            .and(query_param("cursor", "page2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "conversations": [summary("conv_c", 300)],
+                "has_more": false
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/conversations"))
+            .and(query_param("agent_id", "agent_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "conversations": [summary("conv_a", 200), summary("conv_old", 50), summary("conv_b", 250)],
+                "next_cursor": "page2",
+                "has_more": true
+            })))
+            .mount(&mock_server)
+            .await;
+        for (id, has_audio) in [("conv_a", true), ("conv_c", false)] {
+            Mock::given(method("GET"))
+                .and(path(format!("/v1/convai/conversations/{id}")))
+                .respond_with(ResponseTemplate::new(200).set_body_json(detail(id, has_audio)))
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/conversations/conv_b"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "detail": {"status": "not_found", "message": "Conversation not found"}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/conversations/conv_a/audio"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"RIFF".to_vec(), "audio/wav"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = crate::config::ClientConfig::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(0_u32)
+            .build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let dest =
+            std::env::temp_dir().join(format!("elevenlabs-convai-export-{}", std::process::id()));
+        let range = DateRange::since(100);
+        let summary = client
+            .agents()
+            .export_conversations(
+                "agent_1",
+                range,
+                ConversationExportFormat::TranscriptsAndAudio,
+                &dest,
+            )
+            .await
+            .unwrap();
+
+        let transcripts = std::fs::read_to_string(dest.join("transcripts.jsonl")).unwrap();
+        let index = std::fs::read_to_string(dest.join("index.csv")).unwrap();
+        let audio = std::fs::read(dest.join("audio/conv_a.wav")).unwrap();
+        std::fs::remove_dir_all(&dest).unwrap();
+
+        let ids: Vec<&str> =
+            summary.items.iter().map(|item| item.conversation.conversation_id.as_str()).collect();
+        assert_eq!(ids, ["conv_a", "conv_b", "conv_c"]);
+        assert_eq!(summary.exported().count(), 2);
+        assert!(!summary.is_success());
+        assert_eq!(
+            summary.items[0].status,
+            ConversationExportStatus::Exported {
+                transcript_line: 1,
+                audio_file: Some(PathBuf::from("audio/conv_a.wav")),
+            }
+        );
+        assert_eq!(
+            summary.items[2].status,
+            ConversationExportStatus::Exported { transcript_line: 2, audio_file: None }
+        );
+
+        let lines: Vec<GetConversationResponse> =
+            transcripts.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].conversation_id, "conv_c");
+        assert_eq!(audio, b"RIFF");
+
+        let rows: Vec<&str> = index.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0], INDEX_HEADER);
+        assert_eq!(rows[1], "conv_a,agent_1,200,30,4,done,success,1,audio/conv_a.wav,");
+        assert!(rows[2].starts_with("conv_b,agent_1,250,30,4,done,success,,,"));
+    }
+}
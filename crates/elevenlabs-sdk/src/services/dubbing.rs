@@ -41,22 +41,25 @@
 //! # }
 //! ```
 
+use std::path::Path;
+
 use bytes::Bytes;
 
 use crate::{
     client::ElevenLabsClient,
-    error::Result,
+    download::{DownloadOptions, DownloadSummary},
+    error::{ElevenLabsError, Result},
     types::{
         AddLanguageRequest, CreateDubbingRequest, CreateSpeakerRequest, DeleteDubbingResponse,
-        DoDubbingResponse, DubSegmentsRequest, DubbingMetadataPageResponse,
-        DubbingMetadataResponse, DubbingRenderResponse, DubbingResource, DubbingTranscriptResponse,
-        DubbingTranscriptsResponse, LanguageAddedResponse, MigrateSegmentsRequest,
-        RenderDubbingRequest, SegmentCreatePayload, SegmentCreateResponse, SegmentDeleteResponse,
-        SegmentDubResponse, SegmentMigrationResponse, SegmentTranscriptionResponse,
-        SegmentTranslationResponse, SegmentUpdatePayload, SegmentUpdateResponse,
-        SimilarVoicesForSpeakerResponse, SpeakerCreatedResponse, SpeakerUpdatedResponse,
-        TranscribeSegmentsRequest, TranscriptFormat, TranslateSegmentsRequest,
-        UpdateSpeakerRequest,
+        DoDubbingResponse, DubSegmentsRequest, DubbingFile, DubbingMetadataPageResponse,
+        DubbingMetadataResponse, DubbingMode, DubbingRenderResponse, DubbingResource,
+        DubbingTranscriptResponse, DubbingTranscriptsResponse, LanguageAddedResponse,
+        ManualDubInputs, MigrateSegmentsRequest, RenderDubbingRequest, SegmentCreatePayload,
+        SegmentCreateResponse, SegmentDeleteResponse, SegmentDubResponse, SegmentMigrationResponse,
+        SegmentTranscriptionResponse, SegmentTranslationResponse, SegmentUpdatePayload,
+        SegmentUpdateResponse, SimilarVoicesForSpeakerResponse, SpeakerCreatedResponse,
+        SpeakerUpdatedResponse, TranscribeSegmentsRequest, TranscriptFormat,
+        TranslateSegmentsRequest, UpdateSpeakerRequest,
     },
 };
 
@@ -83,24 +86,28 @@ impl<'a> DubbingService<'a> {
     ///
     /// Calls `POST /v1/dubbing` with `multipart/form-data`.
     ///
-    /// File fields (source media, CSV, foreground/background audio) are
-    /// optional binary uploads. Non-file fields are taken from
-    /// [`CreateDubbingRequest`].
+    /// The source media is an optional binary upload. Non-file fields, and
+    /// the CSV and foreground/background audio of a
+    /// [`DubbingMode::Manual`] dub, are taken from [`CreateDubbingRequest`].
+    /// A manual dub is sent as a dubbing studio project.
     ///
     /// # Arguments
     ///
-    /// * `request` — Project configuration (name, languages, etc.).
+    /// * `request` — Project configuration (name, languages, mode, etc.).
     /// * `file` — Optional source media file as `(filename, content_type, data)`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be
-    /// deserialized.
+    /// Returns [`ElevenLabsError::Validation`] if manual inputs are
+    /// incomplete: an empty CSV or audio file, a non-positive `csv_fps`, or
+    /// `dubbing_studio` set to `false`. Otherwise returns an error if the API
+    /// request fails or the response cannot be deserialized.
     pub async fn create(
         &self,
         request: &CreateDubbingRequest,
         file: Option<(&str, &str, &[u8])>,
     ) -> Result<DoDubbingResponse> {
+        validate_create(request)?;
         let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
         let body = build_create_dubbing_multipart(&boundary, request, file);
         let content_type = format!("multipart/form-data; boundary={boundary}");
@@ -196,6 +203,27 @@ impl<'a> DubbingService<'a> {
         self.client.get_bytes(&path).await
     }
 
+    /// Streams the dubbed audio or video file for a language to `dest`.
+    ///
+    /// Like [`get_audio`](Self::get_audio), but writes to disk as the file
+    /// arrives instead of buffering it; see
+    /// [`ElevenLabsClient::download_to_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the file cannot be
+    /// written, or the checksum does not match.
+    pub async fn download_audio_to_file(
+        &self,
+        dubbing_id: &str,
+        language_code: &str,
+        dest: impl AsRef<Path>,
+        options: DownloadOptions<'_>,
+    ) -> Result<DownloadSummary> {
+        let path = format!("/v1/dubbing/{dubbing_id}/audio/{language_code}");
+        self.client.download_to_file(&path, dest, options).await
+    }
+
     /// Gets the transcript for a specific language.
     ///
     /// Calls `GET /v1/dubbing/{dubbing_id}/transcript/{language_code}`.
@@ -598,6 +626,38 @@ fn append_file_part(
     buf.extend_from_slice(b"\r\n");
 }
 
+/// Returns the files of a manual dub with their form field names.
+fn manual_dub_files(
+    inputs: &ManualDubInputs,
+) -> impl Iterator<Item = (&'static str, &DubbingFile)> {
+    [
+        ("csv_file", Some(&inputs.csv_file)),
+        ("foreground_audio_file", Some(&inputs.foreground_audio_file)),
+        ("background_audio_file", inputs.background_audio_file.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(field_name, file)| file.map(|file| (field_name, file)))
+}
+
+/// Checks that the inputs of a [`DubbingMode::Manual`] dub are complete.
+fn validate_create(request: &CreateDubbingRequest) -> Result<()> {
+    let DubbingMode::Manual(ref inputs) = request.mode else {
+        return Ok(());
+    };
+    if request.dubbing_studio == Some(false) {
+        return Err(ElevenLabsError::Validation(
+            "manual dubbing requires a dubbing studio project".to_owned(),
+        ));
+    }
+    if let Some((field_name, _)) = manual_dub_files(inputs).find(|(_, file)| file.data.is_empty()) {
+        return Err(ElevenLabsError::Validation(format!("{field_name} is empty")));
+    }
+    if inputs.csv_fps.is_some_and(|fps| !(fps > 0.0 && fps.is_finite())) {
+        return Err(ElevenLabsError::Validation("csv_fps must be positive".to_owned()));
+    }
+    Ok(())
+}
+
 /// Builds the multipart body for `POST /v1/dubbing`.
 fn build_create_dubbing_multipart(
     boundary: &str,
@@ -668,17 +728,33 @@ fn build_create_dubbing_multipart(
             &disable_voice_cloning.to_string(),
         );
     }
-    if let Some(ref mode) = request.mode {
-        append_text_field(&mut buf, boundary, "mode", mode);
-    }
-    if let Some(csv_fps) = request.csv_fps {
-        append_text_field(&mut buf, boundary, "csv_fps", &csv_fps.to_string());
+    if let DubbingMode::Manual(ref inputs) = request.mode {
+        append_text_field(&mut buf, boundary, "mode", "manual");
+        if request.dubbing_studio.is_none() {
+            append_text_field(&mut buf, boundary, "dubbing_studio", "true");
+        }
+        if let Some(csv_fps) = inputs.csv_fps {
+            append_text_field(&mut buf, boundary, "csv_fps", &csv_fps.to_string());
+        }
     }
 
     if let Some((filename, content_type, data)) = file {
         append_file_part(&mut buf, boundary, "file", filename, content_type, data);
     }
 
+    if let DubbingMode::Manual(ref inputs) = request.mode {
+        for (field_name, file) in manual_dub_files(inputs) {
+            append_file_part(
+                &mut buf,
+                boundary,
+                field_name,
+                &file.file_name,
+                &file.content_type,
+                &file.data,
+            );
+        }
+    }
+
     buf.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
     buf
 }
@@ -700,9 +776,9 @@ mod tests {
         config::ClientConfig,
         types::{
             AddLanguageRequest, CreateDubbingRequest, CreateSpeakerRequest, DubSegmentsRequest,
-            MigrateSegmentsRequest, RenderDubbingRequest, RenderType, SegmentCreatePayload,
-            SegmentUpdatePayload, TranscribeSegmentsRequest, TranslateSegmentsRequest,
-            UpdateSpeakerRequest,
+            DubbingFile, DubbingMode, ManualDubInputs, MigrateSegmentsRequest,
+            RenderDubbingRequest, RenderType, SegmentCreatePayload, SegmentUpdatePayload,
+            TranscribeSegmentsRequest, TranslateSegmentsRequest, UpdateSpeakerRequest,
         },
     };
 
@@ -744,8 +820,7 @@ mod tests {
             use_profanity_filter: None,
             dubbing_studio: None,
             disable_voice_cloning: None,
-            mode: None,
-            csv_fps: None,
+            mode: DubbingMode::Automatic,
         };
         let result = client.dubbing().create(&req, None).await.unwrap();
         assert_eq!(result.dubbing_id, "dub_123");
@@ -948,6 +1023,7 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/dubbing/resource/dub_123/speaker"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({"speaker_name": "Speaker A"})))
             .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
                 "version": 3,
                 "speaker_id": "spk_new"
@@ -956,13 +1032,8 @@ mod tests {
             .await;
 
         let client = test_client(&mock_server.uri());
-        let req = CreateSpeakerRequest {
-            speaker_name: Some("Speaker A".into()),
-            voice_id: None,
-            voice_stability: None,
-            voice_similarity: None,
-            voice_style: None,
-        };
+        let req =
+            CreateSpeakerRequest { speaker_name: Some("Speaker A".into()), ..Default::default() };
         let result = client.dubbing().create_speaker("dub_123", &req).await.unwrap();
         assert_eq!(result.version, 3);
         assert_eq!(result.speaker_id, "spk_new");
@@ -977,6 +1048,7 @@ mod tests {
         Mock::given(method("PATCH"))
             .and(path("/v1/dubbing/resource/dub_123/speaker/spk_1"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({"speaker_name": "Updated Name"})))
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(serde_json::json!({"version": 4})),
             )
@@ -986,11 +1058,7 @@ mod tests {
         let client = test_client(&mock_server.uri());
         let req = UpdateSpeakerRequest {
             speaker_name: Some("Updated Name".into()),
-            voice_id: None,
-            voice_stability: None,
-            voice_similarity: None,
-            voice_style: None,
-            languages: None,
+            ..Default::default()
         };
         let result = client.dubbing().update_speaker("dub_123", "spk_1", &req).await.unwrap();
         assert_eq!(result.version, 4);
@@ -1005,6 +1073,9 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/dubbing/resource/dub_123/speaker/spk_1/segment"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(
+                serde_json::json!({"start_time": 1.0, "end_time": 5.0, "text": "Hello"}),
+            ))
             .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
                 "version": 5,
                 "new_segment": "seg_new"
@@ -1013,12 +1084,7 @@ mod tests {
             .await;
 
         let client = test_client(&mock_server.uri());
-        let req = SegmentCreatePayload {
-            start_time: 1.0,
-            end_time: 5.0,
-            text: Some("Hello".into()),
-            translations: None,
-        };
+        let req = SegmentCreatePayload::new(1.0, 5.0).text("Hello");
         let result = client.dubbing().create_segment("dub_123", "spk_1", &req).await.unwrap();
         assert_eq!(result.version, 5);
         assert_eq!(result.new_segment, "seg_new");
@@ -1033,6 +1099,7 @@ mod tests {
         Mock::given(method("PATCH"))
             .and(path("/v1/dubbing/resource/dub_123/segment/seg_1/es"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({"text": "Hola"})))
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(serde_json::json!({"version": 6})),
             )
@@ -1040,8 +1107,7 @@ mod tests {
             .await;
 
         let client = test_client(&mock_server.uri());
-        let req =
-            SegmentUpdatePayload { start_time: None, end_time: None, text: Some("Hola".into()) };
+        let req = SegmentUpdatePayload::default().text("Hola");
         let result = client.dubbing().update_segment("dub_123", "seg_1", "es", &req).await.unwrap();
         assert_eq!(result.version, 6);
     }
@@ -1075,6 +1141,7 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/dubbing/resource/dub_123/dub"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({"segments": ["seg_1"], "languages": ["es"]})))
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(serde_json::json!({"version": 8})),
             )
@@ -1099,6 +1166,7 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/dubbing/resource/dub_123/render/es"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({"render_type": "mp4", "normalize_volume": true})))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
                 "version": 9,
                 "render_id": "render_abc"
@@ -1123,6 +1191,7 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/dubbing/resource/dub_123/transcribe"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({"segments": ["seg_1", "seg_2"]})))
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(serde_json::json!({"version": 10})),
             )
@@ -1144,6 +1213,7 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/dubbing/resource/dub_123/translate"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({"segments": ["seg_1"]})))
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(serde_json::json!({"version": 11})),
             )
@@ -1165,6 +1235,9 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/dubbing/resource/dub_123/migrate-segments"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(
+                serde_json::json!({"segment_ids": ["seg_1", "seg_2"], "speaker_id": "spk_target"}),
+            ))
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(serde_json::json!({"version": 12})),
             )
@@ -1233,8 +1306,7 @@ mod tests {
             use_profanity_filter: None,
             dubbing_studio: None,
             disable_voice_cloning: None,
-            mode: None,
-            csv_fps: None,
+            mode: DubbingMode::Automatic,
         };
         let boundary = "test-boundary";
         let body = super::build_create_dubbing_multipart(boundary, &req, None);
@@ -1263,8 +1335,7 @@ mod tests {
             use_profanity_filter: None,
             dubbing_studio: None,
             disable_voice_cloning: None,
-            mode: None,
-            csv_fps: None,
+            mode: DubbingMode::Automatic,
         };
         let boundary = "test-boundary";
         let body = super::build_create_dubbing_multipart(
@@ -1277,4 +1348,62 @@ mod tests {
         assert!(body_str.contains("video/mp4"));
         assert!(body_str.contains("fake-video-data"));
     }
+
+    fn manual_request() -> CreateDubbingRequest {
+        let inputs = ManualDubInputs {
+            background_audio_file: Some(DubbingFile::new("bg.wav", "audio/wav", &b"bg-track"[..])),
+            csv_fps: Some(25.0),
+            ..ManualDubInputs::new(
+                DubbingFile::new("dub.csv", "text/csv", &b"speaker,start_time"[..]),
+                DubbingFile::new("fg.wav", "audio/wav", &b"fg-track"[..]),
+            )
+        };
+        CreateDubbingRequest {
+            target_lang: Some("es".into()),
+            mode: DubbingMode::Manual(Box::new(inputs)),
+            ..CreateDubbingRequest::default()
+        }
+    }
+
+    #[test]
+    fn build_create_dubbing_multipart_with_manual_inputs() {
+        let body = super::build_create_dubbing_multipart("b", &manual_request(), None);
+        let body_str = String::from_utf8_lossy(&body);
+        assert!(body_str.contains("name=\"mode\"\r\n\r\nmanual\r\n"));
+        assert!(body_str.contains("name=\"dubbing_studio\"\r\n\r\ntrue\r\n"));
+        assert!(body_str.contains("name=\"csv_fps\"\r\n\r\n25\r\n"));
+        assert!(body_str.contains("name=\"csv_file\"; filename=\"dub.csv\""));
+        assert!(body_str.contains("name=\"foreground_audio_file\"; filename=\"fg.wav\""));
+        assert!(body_str.contains("name=\"background_audio_file\"; filename=\"bg.wav\""));
+
+        let automatic = CreateDubbingRequest::default();
+        let body = super::build_create_dubbing_multipart("b", &automatic, None);
+        assert!(!String::from_utf8_lossy(&body).contains("mode"));
+    }
+
+    #[tokio::test]
+    async fn create_rejects_incomplete_manual_inputs() {
+        let client = test_client("http://127.0.0.1:9");
+
+        let mut request = manual_request();
+        if let DubbingMode::Manual(ref mut inputs) = request.mode {
+            inputs.foreground_audio_file.data = bytes::Bytes::new();
+        }
+        let result = client.dubbing().create(&request, None).await;
+        assert!(matches!(
+            result,
+            Err(crate::ElevenLabsError::Validation(ref msg)) if msg.contains("foreground_audio_file")
+        ));
+
+        let request = CreateDubbingRequest { dubbing_studio: Some(false), ..manual_request() };
+        let result = client.dubbing().create(&request, None).await;
+        assert!(matches!(result, Err(crate::ElevenLabsError::Validation(_))));
+
+        let mut request = manual_request();
+        if let DubbingMode::Manual(ref mut inputs) = request.mode {
+            inputs.csv_fps = Some(0.0);
+        }
+        let result = client.dubbing().create(&request, None).await;
+        assert!(matches!(result, Err(crate::ElevenLabsError::Validation(_))));
+    }
 }
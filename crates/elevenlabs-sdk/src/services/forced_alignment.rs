@@ -3,24 +3,44 @@
 //! Provides a multipart endpoint that takes an audio file and text input,
 //! returning character-level alignment data.
 //!
+//! [`ForcedAlignmentService::align`] takes the audio as a [`FileInput`], so
+//! it can come from memory, a path or any reader. Files at a path are
+//! streamed from disk as they are uploaded, and inputs of known size are
+//! checked against [`MAX_FORCED_ALIGNMENT_FILE_BYTES`] before anything is
+//! sent.
+//!
 //! # Example
 //!
 //! ```no_run
-//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient};
+//! use elevenlabs_sdk::{
+//!     ClientConfig, ElevenLabsClient, FileInput, types::ForcedAlignmentRequest,
+//! };
 //!
 //! # async fn example() -> elevenlabs_sdk::Result<()> {
 //! let config = ClientConfig::builder("your-api-key").build();
 //! let client = ElevenLabsClient::new(config)?;
 //!
-//! let audio = std::fs::read("sample.mp3").unwrap();
-//! let alignment = client.forced_alignment().create(&audio, "sample.mp3", "Hello world").await?;
+//! let request = ForcedAlignmentRequest {
+//!     enabled_spooled_file: true,
+//!     ..ForcedAlignmentRequest::new("Hello world")
+//! };
+//! let alignment =
+//!     client.forced_alignment().align(FileInput::from_path("lecture.wav"), &request).await?;
 //! println!("Aligned {} characters", alignment.characters.len());
 //! # Ok(())
 //! # }
 //! ```
 
-use super::voices::{append_file_part, append_text_field, uuid_v4_simple};
-use crate::{client::ElevenLabsClient, error::Result, types::ForcedAlignmentResponse};
+use super::voices::uuid_v4_simple;
+use crate::{
+    client::ElevenLabsClient,
+    error::{ElevenLabsError, Result},
+    types::{ForcedAlignmentRequest, ForcedAlignmentResponse},
+    upload::{self, FileInput},
+};
+
+/// Largest audio file the forced alignment endpoint accepts (1 GiB).
+pub const MAX_FORCED_ALIGNMENT_FILE_BYTES: u64 = 1 << 30;
 
 /// Forced alignment service providing typed access to alignment endpoints.
 ///
@@ -39,7 +59,8 @@ impl<'a> ForcedAlignmentService<'a> {
     /// Aligns text to an audio file and returns character-level alignment data.
     ///
     /// Calls `POST /v1/forced-alignment` with a multipart request containing
-    /// the audio file and the text to align.
+    /// the audio file and the text to align. Equivalent to
+    /// [`align`](Self::align) with in-memory audio.
     ///
     /// # Arguments
     ///
@@ -49,28 +70,52 @@ impl<'a> ForcedAlignmentService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails.
+    /// Returns an error if the input fails validation or the API request
+    /// fails.
     pub async fn create(
         &self,
         audio_data: &[u8],
         file_name: &str,
         text: &str,
     ) -> Result<ForcedAlignmentResponse> {
-        let boundary = uuid_v4_simple();
-        let mut body = Vec::new();
+        let input =
+            FileInput::from_bytes(audio_data.to_vec(), file_name, "application/octet-stream");
+        self.align(input, &ForcedAlignmentRequest::new(text)).await
+    }
 
-        append_file_part(
-            &mut body,
-            &boundary,
-            "file",
-            file_name,
-            "application/octet-stream",
-            audio_data,
-        );
-        append_text_field(&mut body, &boundary, "text", text);
+    /// Aligns `request.text` to the audio in `input`.
+    ///
+    /// Calls `POST /v1/forced-alignment` with `multipart/form-data`. The
+    /// audio is read from a path or reader while it is uploaded. Set
+    /// [`ForcedAlignmentRequest::enabled_spooled_file`] for long recordings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the text is empty, a file
+    /// input cannot be found, or the audio is known to be larger than
+    /// [`MAX_FORCED_ALIGNMENT_FILE_BYTES`]; otherwise an error if reading the
+    /// input or the API request fails. A reader's size is not known up
+    /// front, so an oversized reader is rejected by the server instead.
+    pub async fn align(
+        &self,
+        input: FileInput,
+        request: &ForcedAlignmentRequest,
+    ) -> Result<ForcedAlignmentResponse> {
+        if request.text.trim().is_empty() {
+            return Err(ElevenLabsError::Validation("alignment text is empty".to_owned()));
+        }
+        if let Some(size) = input.size().await?.filter(|&s| s > MAX_FORCED_ALIGNMENT_FILE_BYTES) {
+            return Err(ElevenLabsError::Validation(format!(
+                "audio is {size} bytes, over the {MAX_FORCED_ALIGNMENT_FILE_BYTES}-byte limit"
+            )));
+        }
 
-        // Close the multipart body.
-        body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+        let boundary = uuid_v4_simple();
+        let mut fields = vec![("text", request.text.clone())];
+        if request.enabled_spooled_file {
+            fields.push(("enabled_spooled_file", "true".to_owned()));
+        }
+        let body = upload::multipart_body(&boundary, "file", input, &fields).await?;
 
         let content_type = format!("multipart/form-data; boundary={boundary}");
         self.client.post_multipart("/v1/forced-alignment", body, &content_type).await
@@ -89,7 +134,10 @@ mod tests {
         matchers::{header, method, path},
     };
 
-    use crate::{ElevenLabsClient, config::ClientConfig};
+    use crate::{
+        ElevenLabsClient, ElevenLabsError, FileInput, config::ClientConfig,
+        types::ForcedAlignmentRequest,
+    };
 
     #[tokio::test]
     async fn create_returns_alignment() {
@@ -120,4 +168,59 @@ mod tests {
 
         assert_eq!(result.characters.len(), 3);
     }
+
+    #[tokio::test]
+    async fn align_streams_path_input_with_spooled_flag() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/forced-alignment"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "characters": [{"text": "H", "start": 0.0, "end": 0.1}],
+                "words": [{"text": "H", "start": 0.0, "end": 0.1, "loss": 0.1}],
+                "loss": 0.1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let file = std::env::temp_dir().join(format!("fa-{}.mp3", std::process::id()));
+        tokio::fs::write(&file, b"audio-from-disk").await.unwrap();
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let request = ForcedAlignmentRequest {
+            enabled_spooled_file: true,
+            ..ForcedAlignmentRequest::new("H")
+        };
+        let result =
+            client.forced_alignment().align(FileInput::from_path(&file), &request).await.unwrap();
+        tokio::fs::remove_file(&file).await.unwrap();
+
+        assert_eq!(result.characters.len(), 1);
+        let requests = mock_server.received_requests().await.unwrap();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("audio-from-disk"));
+        assert!(body.contains("name=\"enabled_spooled_file\"\r\n\r\ntrue"));
+    }
+
+    #[tokio::test]
+    async fn align_rejects_empty_text_and_missing_file() {
+        let config = ClientConfig::builder("test-key").base_url("http://127.0.0.1:9").build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let service = client.forced_alignment();
+
+        let empty = service
+            .align(FileInput::from_bytes(b"a".to_vec(), "a.mp3", "audio/mpeg"), &Default::default())
+            .await;
+        assert!(matches!(empty, Err(ElevenLabsError::Validation(_))));
+
+        let missing = service
+            .align(
+                FileInput::from_path("/nonexistent/audio.mp3"),
+                &ForcedAlignmentRequest::new("Hello"),
+            )
+            .await;
+        assert!(matches!(missing, Err(ElevenLabsError::Validation(_))));
+    }
 }
@@ -3,10 +3,14 @@
 //! | Method | Endpoint | Description |
 //! |--------|----------|-------------|
 //! | [`list`](HistoryService::list) | `GET /v1/history` | List speech history items |
+//! | [`list_enriched`](HistoryService::list_enriched) | `GET /v1/history` + cached `/v1/voices`, `/v1/models` | List items with voice and model names |
 //! | [`get`](HistoryService::get) | `GET /v1/history/{history_item_id}` | Get a single history item |
 //! | [`get_audio`](HistoryService::get_audio) | `GET /v1/history/{history_item_id}/audio` | Download audio |
+//! | [`get_audio_stream`](HistoryService::get_audio_stream) | `GET /v1/history/{history_item_id}/audio` | Stream audio |
+//! | [`get_item_with_audio`](HistoryService::get_item_with_audio) | `GET /v1/history/{history_item_id}` + `/audio` | Metadata and audio together |
 //! | [`delete`](HistoryService::delete) | `DELETE /v1/history/{history_item_id}` | Delete a history item |
 //! | [`download`](HistoryService::download) | `POST /v1/history/download` | Download multiple items |
+//! | `podcast_feed` | `GET /v1/history/{history_item_id}/audio` per item | Podcast RSS feed (`podcast-feed` feature) |
 //!
 //! # Example
 //!
@@ -17,20 +21,27 @@
 //! let config = ClientConfig::builder("your-api-key").build();
 //! let client = ElevenLabsClient::new(config)?;
 //!
-//! let history = client.history().list(None, None, None).await?;
+//! let history = client.history().list(None, None, None, None).await?;
 //! println!("Found {} history items", history.history.len());
 //! # Ok(())
 //! # }
 //! ```
 
+use std::{collections::HashMap, path::Path};
+
 use bytes::Bytes;
+use futures_core::Stream;
+use hpx::Method;
 
+#[cfg(feature = "podcast-feed")]
+use crate::feed::PodcastFeed;
 use crate::{
-    client::ElevenLabsClient,
+    client::{ElevenLabsClient, NameKind},
+    download::{DownloadOptions, DownloadSummary},
     error::Result,
     types::{
-        DeleteHistoryItemResponse, DownloadHistoryItemsRequest, GetSpeechHistoryResponse,
-        SpeechHistoryItem,
+        DateRange, DeleteHistoryItemResponse, DownloadHistoryItemsRequest, EnrichedHistoryItem,
+        EnrichedHistoryPage, GetSpeechHistoryResponse, HistoryItemWithAudio, SpeechHistoryItem,
     },
 };
 
@@ -57,6 +68,7 @@ impl<'a> HistoryService<'a> {
     /// * `page_size` — Maximum items per page.
     /// * `start_after_history_item_id` — Cursor for pagination.
     /// * `voice_id` — Filter by voice ID.
+    /// * `range` — Only items created inside this range.
     ///
     /// # Errors
     ///
@@ -67,6 +79,7 @@ impl<'a> HistoryService<'a> {
         page_size: Option<u32>,
         start_after_history_item_id: Option<&str>,
         voice_id: Option<&str>,
+        range: Option<DateRange>,
     ) -> Result<GetSpeechHistoryResponse> {
         let mut path = "/v1/history".to_owned();
         let mut sep = '?';
@@ -80,10 +93,78 @@ impl<'a> HistoryService<'a> {
         }
         if let Some(vid) = voice_id {
             path.push_str(&format!("{sep}voice_id={vid}"));
+            sep = '&';
+        }
+        let range = range.unwrap_or_default();
+        if let Some(after) = range.after {
+            path.push_str(&format!("{sep}date_after_unix={after}"));
+            sep = '&';
+        }
+        if let Some(before) = range.before {
+            path.push_str(&format!("{sep}date_before_unix={before}"));
         }
         self.client.get(&path).await
     }
 
+    /// Lists speech history items like [`list`](Self::list), with the names
+    /// of their voices and models resolved.
+    ///
+    /// Voice and model names are fetched with one `GET /v1/voices` and one
+    /// `GET /v1/models` the first time they are needed and then cached on
+    /// the client, so rendering further pages costs no extra requests. Call
+    /// [`forget_display_names`](Self::forget_display_names) after renaming
+    /// voices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the API requests fails or a response
+    /// cannot be deserialized.
+    pub async fn list_enriched(
+        &self,
+        page_size: Option<u32>,
+        start_after_history_item_id: Option<&str>,
+        voice_id: Option<&str>,
+        range: Option<DateRange>,
+    ) -> Result<EnrichedHistoryPage> {
+        let page = self.list(page_size, start_after_history_item_id, voice_id, range).await?;
+        let cache = self.client.display_names();
+        if !cache.contains_async(&NameKind::Voice).await &&
+            page.history.iter().any(|i| i.voice_id.is_some())
+        {
+            let voices = self.client.voices().list(None).await?.voices;
+            let names = voices.into_iter().map(|voice| (voice.voice_id, voice.name)).collect();
+            cache.upsert_async(NameKind::Voice, names).await;
+        }
+        if !cache.contains_async(&NameKind::Model).await &&
+            page.history.iter().any(|i| i.model_id.is_some())
+        {
+            let models = self.client.models().list().await?.0;
+            let names = models.into_iter().map(|model| (model.model_id, model.name)).collect();
+            cache.upsert_async(NameKind::Model, names).await;
+        }
+
+        let mut items = Vec::with_capacity(page.history.len());
+        for item in page.history {
+            let voice_name = lookup(cache, NameKind::Voice, item.voice_id.as_deref())
+                .await
+                .or_else(|| item.voice_name.clone());
+            let model_name = lookup(cache, NameKind::Model, item.model_id.as_deref()).await;
+            items.push(EnrichedHistoryItem { item, voice_name, model_name });
+        }
+        Ok(EnrichedHistoryPage {
+            items,
+            last_history_item_id: page.last_history_item_id,
+            has_more: page.has_more,
+        })
+    }
+
+    /// Drops the voice and model names cached by
+    /// [`list_enriched`](Self::list_enriched), so the next call fetches them
+    /// again.
+    pub fn forget_display_names(&self) {
+        self.client.display_names().clear_sync();
+    }
+
     /// Gets a single speech history item by its ID.
     ///
     /// Calls `GET /v1/history/{history_item_id}`.
@@ -111,6 +192,42 @@ impl<'a> HistoryService<'a> {
         self.client.get_bytes(&path).await
     }
 
+    /// Streams the audio for a single history item.
+    ///
+    /// Calls `GET /v1/history/{history_item_id}/audio` and yields the audio
+    /// in chunks as it arrives, so playback can start before the download
+    /// finishes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails.
+    pub async fn get_audio_stream(
+        &self,
+        history_item_id: &str,
+    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<>> {
+        let path = format!("/v1/history/{history_item_id}/audio");
+        self.client.get_stream(&path).await
+    }
+
+    /// Gets a history item's metadata and audio in one call.
+    ///
+    /// Sends `GET /v1/history/{history_item_id}` and
+    /// `GET /v1/history/{history_item_id}/audio` concurrently.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either request fails.
+    pub async fn get_item_with_audio(&self, history_item_id: &str) -> Result<HistoryItemWithAudio> {
+        let audio_path = format!("/v1/history/{history_item_id}/audio");
+        let (item, (audio, content_type)) = futures_util::future::try_join(
+            self.get(history_item_id),
+            self.client.get_bytes_with_content_type(&audio_path),
+        )
+        .await?;
+        let content_type = content_type.unwrap_or_else(|| item.content_type.clone());
+        Ok(HistoryItemWithAudio { item, audio, content_type })
+    }
+
     /// Deletes a speech history item.
     ///
     /// Calls `DELETE /v1/history/{history_item_id}`.
@@ -135,12 +252,83 @@ impl<'a> HistoryService<'a> {
     pub async fn download(&self, request: &DownloadHistoryItemsRequest) -> Result<Bytes> {
         self.client.post_bytes("/v1/history/download", request).await
     }
+
+    /// Streams multiple history items to `dest`.
+    ///
+    /// Like [`download`](Self::download), but writes to disk as the archive
+    /// arrives instead of buffering it; see
+    /// [`ElevenLabsClient::download_to_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the file cannot be
+    /// written, or the checksum does not match.
+    pub async fn download_to_file(
+        &self,
+        request: &DownloadHistoryItemsRequest,
+        dest: impl AsRef<Path>,
+        options: DownloadOptions<'_>,
+    ) -> Result<DownloadSummary> {
+        let body = serde_json::to_value(request)?;
+        self.client
+            .download(Method::POST, "/v1/history/download", Some(body), dest.as_ref(), &options)
+            .await
+    }
+
+    /// Builds a podcast RSS feed with one episode per item in `items`, in
+    /// the given order.
+    ///
+    /// With [`PodcastFeed::download_to`] set, the audio of each item is
+    /// downloaded into that directory first (one
+    /// `GET /v1/history/{history_item_id}/audio` call per item), and the
+    /// episodes carry its size and duration. See [`crate::feed`] for how
+    /// items map to episodes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if downloading the audio fails or it cannot be
+    /// written.
+    #[cfg(feature = "podcast-feed")]
+    pub async fn podcast_feed(
+        &self,
+        items: &[SpeechHistoryItem],
+        feed: &PodcastFeed,
+    ) -> Result<String> {
+        let mut episodes = Vec::with_capacity(items.len());
+        if let Some(dir) = feed.download_dir() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        for item in items {
+            let mut episode = feed.episode(item);
+            if let Some(dir) = feed.download_dir() {
+                let audio = self.get_audio(&item.history_item_id).await?;
+                tokio::fs::write(dir.join(&episode.file_name), &audio).await?;
+                episode.length = audio.len() as u64;
+                episode.duration = match crate::audio::sniff_content_type(&audio) {
+                    Some("audio/wav") => crate::audio::wav_duration(&audio),
+                    Some("audio/mpeg") => crate::audio::mp3_duration(&audio),
+                    _ => None,
+                };
+            }
+            episodes.push(episode);
+        }
+        Ok(feed.to_rss(&episodes))
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
 
+/// Looks up the name cached for `id` among the names of `kind`.
+async fn lookup(
+    cache: &scc::HashMap<NameKind, HashMap<String, String>>,
+    kind: NameKind,
+    id: Option<&str>,
+) -> Option<String> {
+    cache.read_async(&kind, |_, names| names.get(id?).cloned()).await.flatten()
+}
+
 #[cfg(test)]
 #[expect(clippy::unwrap_used, reason = "tests use unwrap")]
 mod tests {
@@ -149,7 +337,11 @@ mod tests {
         matchers::{header, method, path, query_param},
     };
 
-    use crate::{ElevenLabsClient, config::ClientConfig, types::DownloadHistoryItemsRequest};
+    use crate::{
+        ElevenLabsClient,
+        config::ClientConfig,
+        types::{DateRange, DownloadHistoryItemsRequest},
+    };
 
     #[tokio::test]
     async fn list_returns_history() {
@@ -162,7 +354,7 @@ mod tests {
                 "history": [
                     {
                         "history_item_id": "item1",
-                        "date_unix": 1714650306,
+                        "date_unix": 1_714_650_306,
                         "character_count_change_from": 100,
                         "character_count_change_to": 150,
                         "content_type": "audio/mpeg",
@@ -178,11 +370,81 @@ mod tests {
         let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
         let client = ElevenLabsClient::new(config).unwrap();
 
-        let result = client.history().list(None, None, None).await.unwrap();
+        let result = client.history().list(None, None, None, None).await.unwrap();
         assert_eq!(result.history.len(), 1);
         assert!(!result.has_more);
     }
 
+    #[tokio::test]
+    async fn list_enriched_resolves_names_once() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/history"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "history": [
+                    {
+                        "history_item_id": "item1",
+                        "voice_id": "v1",
+                        "voice_name": "Rachel",
+                        "model_id": "eleven_multilingual_v2",
+                        "date_unix": 1_714_650_306,
+                        "character_count_change_from": 0,
+                        "character_count_change_to": 10,
+                        "content_type": "audio/mpeg",
+                        "state": "created"
+                    },
+                    {
+                        "history_item_id": "item2",
+                        "voice_id": "deleted",
+                        "voice_name": "Old Voice",
+                        "date_unix": 1_714_650_307,
+                        "character_count_change_from": 10,
+                        "character_count_change_to": 20,
+                        "content_type": "audio/mpeg",
+                        "state": "created"
+                    }
+                ],
+                "last_history_item_id": "item2",
+                "has_more": true
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "voices": [{"voice_id": "v1", "name": "Rachel (narration)", "category": "premade"}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "model_id": "eleven_multilingual_v2",
+                    "name": "Eleven Multilingual v2",
+                    "model_rates": {"character_cost_multiplier": 1.0}
+                }
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let page = client.history().list_enriched(None, None, None, None).await.unwrap();
+        assert!(page.has_more);
+        assert_eq!(page.items[0].voice_name.as_deref(), Some("Rachel (narration)"));
+        assert_eq!(page.items[0].model_name.as_deref(), Some("Eleven Multilingual v2"));
+        assert_eq!(page.items[1].voice_name.as_deref(), Some("Old Voice"));
+        assert_eq!(page.items[1].model_name, None);
+
+        let again = client.history().list_enriched(None, Some("item2"), None, None).await.unwrap();
+        assert_eq!(again, page);
+    }
+
     #[tokio::test]
     async fn list_with_page_size() {
         let mock_server = MockServer::start().await;
@@ -200,10 +462,41 @@ mod tests {
         let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
         let client = ElevenLabsClient::new(config).unwrap();
 
-        let result = client.history().list(Some(5), None, None).await.unwrap();
+        let result = client.history().list(Some(5), None, None, None).await.unwrap();
         assert!(result.history.is_empty());
     }
 
+    #[tokio::test]
+    async fn list_in_date_range() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/history"))
+            .and(query_param("date_after_unix", "1714650000"))
+            .and(query_param("date_before_unix", "1714653599"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "history": [{
+                    "history_item_id": "item1",
+                    "date_unix": 1_714_650_306,
+                    "character_count_change_from": 0,
+                    "character_count_change_to": 10,
+                    "content_type": "audio/mpeg",
+                    "state": "created"
+                }],
+                "has_more": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let range = DateRange::between(1_714_650_000, 1_714_653_599);
+        let result = client.history().list(None, None, None, Some(range)).await.unwrap();
+        assert!(range.contains(result.history[0].date_unix));
+    }
+
     #[tokio::test]
     async fn get_returns_item() {
         let mock_server = MockServer::start().await;
@@ -213,7 +506,7 @@ mod tests {
             .and(header("xi-api-key", "test-key"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
                 "history_item_id": "item123",
-                "date_unix": 1714650306,
+                "date_unix": 1_714_650_306,
                 "character_count_change_from": 100,
                 "character_count_change_to": 150,
                 "content_type": "audio/mpeg",
@@ -250,6 +543,59 @@ mod tests {
         assert_eq!(bytes.as_ref(), audio_data);
     }
 
+    #[tokio::test]
+    async fn get_audio_stream_yields_chunks() {
+        use futures_util::TryStreamExt;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/history/item123/audio"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"streamed-audio", "audio/mpeg"))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let stream = client.history().get_audio_stream("item123").await.unwrap();
+        let chunks: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), b"streamed-audio");
+    }
+
+    #[tokio::test]
+    async fn get_item_with_audio_joins_metadata_and_audio() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/history/item123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "history_item_id": "item123",
+                "text": "Hello there",
+                "date_unix": 1_714_650_306,
+                "character_count_change_from": 100,
+                "character_count_change_to": 111,
+                "content_type": "audio/mpeg",
+                "state": "created"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/history/item123/audio"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"wav-data", "audio/wav"))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let joined = client.history().get_item_with_audio("item123").await.unwrap();
+        assert_eq!(joined.item.history_item_id, "item123");
+        assert_eq!(joined.transcript(), Some("Hello there"));
+        assert_eq!(joined.audio.as_ref(), b"wav-data");
+        assert_eq!(joined.content_type, "audio/wav");
+    }
+
     #[tokio::test]
     async fn delete_returns_ok() {
         let mock_server = MockServer::start().await;
@@ -294,4 +640,49 @@ mod tests {
         let bytes = client.history().download(&req).await.unwrap();
         assert_eq!(bytes.as_ref(), zip_data);
     }
+
+    #[cfg(feature = "podcast-feed")]
+    #[tokio::test]
+    async fn podcast_feed_downloads_enclosures() {
+        use crate::{audio, feed::PodcastFeed, types::SpeechHistoryItem};
+
+        let mock_server = MockServer::start().await;
+        // 2 seconds of 8 kHz mono PCM.
+        let wav = audio::pcm_to_wav(&vec![0; 32_000], 8_000, 1).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v1/history/item1/audio"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(wav.to_vec(), "audio/wav"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let item: SpeechHistoryItem = serde_json::from_value(serde_json::json!({
+            "history_item_id": "item1",
+            "text": "Welcome to the show",
+            "date_unix": 1_714_650_306,
+            "character_count_change_from": 0,
+            "character_count_change_to": 19,
+            "content_type": "audio/wav",
+            "state": "created"
+        }))
+        .unwrap();
+        let dir = std::env::temp_dir().join(format!("elevenlabs-feed-{}", std::process::id()));
+        let feed = PodcastFeed::new("Show", "https://example.com", "A show", "https://cdn")
+            .download_to(&dir);
+
+        let rss = client.history().podcast_feed(&[item], &feed).await.unwrap();
+        let written = std::fs::read(dir.join("item1.wav")).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(written, wav.as_ref());
+        assert!(rss.contains("<title>Welcome to the show</title>"));
+        assert!(rss.contains(&format!(
+            "<enclosure url=\"https://cdn/item1.wav\" length=\"{}\" type=\"audio/wav\"/>",
+            wav.len()
+        )));
+        assert!(rss.contains("<itunes:duration>2</itunes:duration>"));
+    }
 }
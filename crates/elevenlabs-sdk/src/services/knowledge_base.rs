@@ -0,0 +1,1093 @@
+//! Bulk knowledge base uploads and syncs from a directory or a list of URLs.
+//!
+//! [`KnowledgeBaseUploader`] wraps the single-document endpoints of
+//! [`AgentsService`] with bounded concurrency, content-hash deduplication,
+//! and per-item progress reporting. [`KnowledgeBaseSync`] keeps a folder in
+//! line with local sources, re-uploading only what changed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = ClientConfig::builder("your-api-key").build();
+//! let client = ElevenLabsClient::new(config)?;
+//!
+//! let summary = client
+//!     .agents()
+//!     .knowledge_base_uploader()
+//!     .parallelism(8)
+//!     .on_progress(|p| println!("[{}/{}] {}", p.completed, p.total, p.item.source))
+//!     .upload_directory("./docs")
+//!     .await?;
+//! println!("Created {} documents", summary.created().count());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    future::Future,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use futures_util::{StreamExt, stream};
+use scc::hash_map::Entry;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    client::ElevenLabsClient,
+    download::hex,
+    error::Result,
+    services::AgentsService,
+    types::{
+        AddKnowledgeBaseResponse, CreateKnowledgeBaseUrlRequest, CreateRagIndexRequest,
+        EmbeddingModel, KnowledgeBaseChange, KnowledgeBaseDiff, KnowledgeBaseDiffEntry,
+        KnowledgeBaseDocumentSummary, KnowledgeBaseSyncError, KnowledgeBaseSyncSummary,
+        KnowledgeBaseUploadItem, KnowledgeBaseUploadProgress, KnowledgeBaseUploadSource,
+        KnowledgeBaseUploadStatus, KnowledgeBaseUploadSummary, Timestamp,
+    },
+};
+
+/// Number of uploads run concurrently unless overridden with
+/// [`KnowledgeBaseUploader::parallelism`].
+pub const DEFAULT_UPLOAD_PARALLELISM: usize = 4;
+
+/// File extensions accepted by the knowledge base, with their MIME types.
+const SUPPORTED_FILE_TYPES: &[(&str, &str)] = &[
+    ("docx", "application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+    ("epub", "application/epub+zip"),
+    ("htm", "text/html"),
+    ("html", "text/html"),
+    ("md", "text/markdown"),
+    ("pdf", "application/pdf"),
+    ("txt", "text/plain"),
+];
+
+/// Callback invoked after each source finishes.
+type ProgressCallback<'a> = Box<dyn Fn(KnowledgeBaseUploadProgress<'_>) + Send + Sync + 'a>;
+
+/// Uploads many knowledge base documents concurrently.
+///
+/// Obtained via [`AgentsService::knowledge_base_uploader`]. Sources whose
+/// content hashes to the same value as an earlier source in the batch are
+/// reported as [`KnowledgeBaseUploadStatus::Duplicate`] instead of being
+/// uploaded twice. URL sources are hashed by address, since the API fetches
+/// their content server-side.
+///
+/// A failed upload does not abort the batch; it is recorded as
+/// [`KnowledgeBaseUploadStatus::Failed`] in the returned summary.
+pub struct KnowledgeBaseUploader<'a> {
+    agents: AgentsService<'a>,
+    parallelism: usize,
+    parent_folder_id: Option<String>,
+    recursive: bool,
+    on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl fmt::Debug for KnowledgeBaseUploader<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KnowledgeBaseUploader")
+            .field("parallelism", &self.parallelism)
+            .field("parent_folder_id", &self.parent_folder_id)
+            .field("recursive", &self.recursive)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> KnowledgeBaseUploader<'a> {
+    /// Creates an uploader bound to the given client.
+    pub(crate) const fn new(client: &'a ElevenLabsClient) -> Self {
+        Self {
+            agents: AgentsService::new(client),
+            parallelism: DEFAULT_UPLOAD_PARALLELISM,
+            parent_folder_id: None,
+            recursive: true,
+            on_progress: None,
+        }
+    }
+
+    /// Sets the maximum number of concurrent uploads (at least 1).
+    #[must_use]
+    pub fn parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// Places every created document in the given folder.
+    #[must_use]
+    pub fn parent_folder_id(mut self, folder_id: impl Into<String>) -> Self {
+        self.parent_folder_id = Some(folder_id.into());
+        self
+    }
+
+    /// Sets whether [`upload_directory`](Self::upload_directory) descends
+    /// into subdirectories (default `true`).
+    #[must_use]
+    pub const fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Registers a callback invoked after each source finishes, in
+    /// completion order.
+    #[must_use]
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(KnowledgeBaseUploadProgress<'_>) + Send + Sync + 'a,
+    {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Imports each URL as a knowledge base document.
+    pub async fn upload_urls<I, S>(&self, urls: I) -> KnowledgeBaseUploadSummary
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let sources =
+            urls.into_iter().map(|url| KnowledgeBaseUploadSource::Url(url.into())).collect();
+        self.upload(sources).await
+    }
+
+    /// Uploads each file as a knowledge base document.
+    ///
+    /// Files with an extension the knowledge base does not accept are
+    /// reported as [`KnowledgeBaseUploadStatus::Skipped`].
+    pub async fn upload_files<I, P>(&self, paths: I) -> KnowledgeBaseUploadSummary
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        let sources =
+            paths.into_iter().map(|path| KnowledgeBaseUploadSource::File(path.into())).collect();
+        self.upload(sources).await
+    }
+
+    /// Uploads every file under `dir`, in path order.
+    ///
+    /// Hidden files and directories (names starting with `.`) are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` or one of its subdirectories cannot be
+    /// listed. Errors reading individual files are reported per item.
+    pub async fn upload_directory(
+        &self,
+        dir: impl AsRef<Path>,
+    ) -> std::io::Result<KnowledgeBaseUploadSummary> {
+        let files = collect_files(dir.as_ref(), self.recursive).await?;
+        Ok(self.upload_files(files).await)
+    }
+
+    /// Uploads `sources` with bounded concurrency and collects the results
+    /// in input order.
+    async fn upload(&self, sources: Vec<KnowledgeBaseUploadSource>) -> KnowledgeBaseUploadSummary {
+        let total = sources.len();
+        let seen = scc::HashMap::new();
+        let seen = &seen;
+
+        let mut pending = stream::iter(sources.into_iter().enumerate())
+            .map(move |(index, source)| async move { (index, self.upload_one(source, seen).await) })
+            .buffer_unordered(self.parallelism);
+
+        let mut results = Vec::with_capacity(total);
+        while let Some((index, item)) = pending.next().await {
+            if let Some(callback) = &self.on_progress {
+                callback(KnowledgeBaseUploadProgress {
+                    completed: results.len() + 1,
+                    total,
+                    item: &item,
+                });
+            }
+            results.push((index, item));
+        }
+
+        results.sort_by_key(|(index, _)| *index);
+        KnowledgeBaseUploadSummary { items: results.into_iter().map(|(_, item)| item).collect() }
+    }
+
+    /// Reads, deduplicates, and uploads a single source.
+    async fn upload_one(
+        &self,
+        source: KnowledgeBaseUploadSource,
+        seen: &scc::HashMap<String, KnowledgeBaseUploadSource>,
+    ) -> KnowledgeBaseUploadItem {
+        let payload = match Payload::read(&source).await {
+            Ok(payload) => payload,
+            Err(status) => return KnowledgeBaseUploadItem { source, content_hash: None, status },
+        };
+
+        let content_hash = payload.content_hash();
+        let original = match seen.entry_async(content_hash.clone()).await {
+            Entry::Occupied(entry) => Some(entry.get().clone()),
+            Entry::Vacant(entry) => {
+                entry.insert_entry(source.clone());
+                None
+            }
+        };
+        let status = match original {
+            Some(of) => KnowledgeBaseUploadStatus::Duplicate { of },
+            None => {
+                match create_document(&self.agents, payload, None, self.parent_folder_id.as_deref())
+                    .await
+                {
+                    Ok(document) => KnowledgeBaseUploadStatus::Created { document },
+                    Err(e) => KnowledgeBaseUploadStatus::Failed { error: e.to_string() },
+                }
+            }
+        };
+        KnowledgeBaseUploadItem { source, content_hash: Some(content_hash), status }
+    }
+}
+
+/// A source added to a [`KnowledgeBaseSync`].
+#[derive(Debug, Clone)]
+enum SyncInput {
+    Directory(PathBuf),
+    File(PathBuf),
+    Url(String),
+}
+
+/// Identifies the document a source maps to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum DocumentKey {
+    File(String),
+    Url(String),
+}
+
+/// Brings a knowledge base folder in line with local files and URLs.
+///
+/// Obtained via [`AgentsService::knowledge_base_sync`]. Each source is
+/// matched to a document by name: files added with
+/// [`directory`](Self::directory) by their path relative to that
+/// directory, files added with [`file`](Self::file) by their file name, and
+/// URLs by address. Only file and URL documents directly inside
+/// [`parent_folder_id`](Self::parent_folder_id) (the root folder by
+/// default) take part in the sync.
+///
+/// The API exposes no content hash and cannot replace a document's
+/// content in place, so a matched file counts as stale when its content
+/// hash differs from the one recorded in
+/// [`known_hashes`](Self::known_hashes) or, without a recorded hash, when
+/// its size differs from the document's or it was modified after the
+/// document was last updated. URL documents are fetched server-side and
+/// never count as stale.
+///
+/// [`run`](Self::run) uploads new and stale sources, then deletes each
+/// replaced document. Deleting a document an agent still uses fails and
+/// is reported in [`KnowledgeBaseSyncSummary::errors`]; re-point such
+/// agents using [`KnowledgeBaseSyncSummary::replacements`]. Fresh
+/// checkouts reset modification times, so CI jobs should persist
+/// [`KnowledgeBaseSyncSummary::content_hashes`] between runs.
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, types::EmbeddingModel};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+///
+/// let summary = client
+///     .agents()
+///     .knowledge_base_sync()
+///     .parent_folder_id("folder_docs")
+///     .directory("./docs")
+///     .delete_orphans(true)
+///     .rag_index(EmbeddingModel::E5Mistral7bInstruct)
+///     .run()
+///     .await?;
+/// for (old, new) in summary.replacements() {
+///     println!("{old} -> {}", new.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct KnowledgeBaseSync<'a> {
+    agents: AgentsService<'a>,
+    parallelism: usize,
+    parent_folder_id: Option<String>,
+    inputs: Vec<SyncInput>,
+    known_hashes: BTreeMap<String, String>,
+    delete_orphans: bool,
+    rag_index: Option<EmbeddingModel>,
+}
+
+impl<'a> KnowledgeBaseSync<'a> {
+    /// Creates a sync bound to the given client.
+    pub(crate) const fn new(client: &'a ElevenLabsClient) -> Self {
+        Self {
+            agents: AgentsService::new(client),
+            parallelism: DEFAULT_UPLOAD_PARALLELISM,
+            parent_folder_id: None,
+            inputs: Vec::new(),
+            known_hashes: BTreeMap::new(),
+            delete_orphans: false,
+            rag_index: None,
+        }
+    }
+
+    /// Sets the maximum number of concurrent requests (at least 1).
+    #[must_use]
+    pub fn parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// Syncs the given folder instead of the knowledge base root.
+    #[must_use]
+    pub fn parent_folder_id(mut self, folder_id: impl Into<String>) -> Self {
+        self.parent_folder_id = Some(folder_id.into());
+        self
+    }
+
+    /// Adds every file under `dir`, named by its `/`-separated path
+    /// relative to `dir`. Hidden files and directories are ignored.
+    #[must_use]
+    pub fn directory(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.inputs.push(SyncInput::Directory(dir.into()));
+        self
+    }
+
+    /// Adds a single file, named by its file name.
+    #[must_use]
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.inputs.push(SyncInput::File(path.into()));
+        self
+    }
+
+    /// Adds a web page, matched by its address.
+    #[must_use]
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.inputs.push(SyncInput::Url(url.into()));
+        self
+    }
+
+    /// Sets the content hashes recorded by a previous sync, keyed by
+    /// document ID (see [`KnowledgeBaseSyncSummary::content_hashes`]).
+    #[must_use]
+    pub fn known_hashes<I, K, V>(mut self, hashes: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.known_hashes = hashes.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Sets whether [`run`](Self::run) deletes documents that no source
+    /// maps to (default `false`).
+    #[must_use]
+    pub const fn delete_orphans(mut self, delete: bool) -> Self {
+        self.delete_orphans = delete;
+        self
+    }
+
+    /// Requests a RAG index with `model` for every uploaded document.
+    #[must_use]
+    pub const fn rag_index(mut self, model: EmbeddingModel) -> Self {
+        self.rag_index = Some(model);
+        self
+    }
+
+    /// Compares the sources with the knowledge base without changing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a directory cannot be listed or the knowledge
+    /// base cannot be listed. Errors reading individual files are reported
+    /// per entry.
+    pub async fn diff(&self) -> Result<KnowledgeBaseDiff> {
+        let sources = self.sources().await?;
+        let mut remote = self.remote_documents().await?;
+
+        let mut claimed = HashSet::new();
+        let mut matched = Vec::with_capacity(sources.len());
+        for (name, source) in sources {
+            let key = match &source {
+                KnowledgeBaseUploadSource::File(_) => DocumentKey::File(name.clone()),
+                KnowledgeBaseUploadSource::Url(url) => DocumentKey::Url(url.clone()),
+            };
+            let document = if claimed.insert(key.clone()) {
+                Ok(remote.get_mut(&key).and_then(Vec::pop))
+            } else {
+                Err(KnowledgeBaseChange::Skipped {
+                    reason: "another source has the same document name".to_owned(),
+                })
+            };
+            matched.push((name, source, document));
+        }
+
+        let entries = stream::iter(matched)
+            .map(|(name, source, document)| async move {
+                let (content_hash, change) = match document {
+                    Ok(document) => self.compare(&source, document.as_ref()).await,
+                    Err(change) => (None, change),
+                };
+                KnowledgeBaseDiffEntry { name, source, content_hash, change }
+            })
+            .buffered(self.parallelism)
+            .collect()
+            .await;
+
+        let mut orphans: Vec<_> = remote.into_values().flatten().collect();
+        orphans.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+        Ok(KnowledgeBaseDiff { entries, orphans })
+    }
+
+    /// Uploads new and stale sources, deletes replaced documents (and
+    /// orphans if enabled), and requests RAG indexes for the uploads.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`diff`](Self::diff). Failed uploads, deletions and re-index
+    /// requests are reported in the summary and do not abort the sync.
+    pub async fn run(&self) -> Result<KnowledgeBaseSyncSummary> {
+        let diff = self.diff().await?;
+        let uploads: Vec<_> = stream::iter(diff.pending())
+            .map(|entry| self.upload(entry))
+            .buffered(self.parallelism)
+            .collect()
+            .await;
+
+        let replaced = diff.pending().zip(&uploads).filter_map(|(entry, upload)| {
+            match (&entry.change, &upload.status) {
+                (
+                    KnowledgeBaseChange::Stale { document_id, .. },
+                    KnowledgeBaseUploadStatus::Created { .. },
+                ) => Some(document_id.clone()),
+                _ => None,
+            }
+        });
+        let orphans = diff.orphans.iter().filter(|_| self.delete_orphans).map(|doc| doc.id.clone());
+        let obsolete: Vec<_> = replaced.chain(orphans).collect();
+        let created: Vec<_> = uploads
+            .iter()
+            .filter_map(|item| match &item.status {
+                KnowledgeBaseUploadStatus::Created { document } => Some(document.id.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let mut summary = KnowledgeBaseSyncSummary { diff, uploads, ..Default::default() };
+        let agents = &self.agents;
+        let outcomes = for_each_document(obsolete, self.parallelism, |id| async move {
+            agents.delete_knowledge_base_document(&id).await.map(|()| id)
+        })
+        .await;
+        record(outcomes, &mut summary.deleted, &mut summary.errors);
+
+        if let Some(model) = self.rag_index {
+            let request = &CreateRagIndexRequest { model };
+            let outcomes = for_each_document(created, self.parallelism, |id| async move {
+                agents.create_document_rag_index(&id, request).await.map(|_| id)
+            })
+            .await;
+            record(outcomes, &mut summary.reindexed, &mut summary.errors);
+        }
+        Ok(summary)
+    }
+
+    /// Expands the inputs into named sources, in input order.
+    async fn sources(&self) -> std::io::Result<Vec<(String, KnowledgeBaseUploadSource)>> {
+        let mut sources = Vec::new();
+        for input in &self.inputs {
+            match input {
+                SyncInput::Directory(dir) => {
+                    for path in collect_files(dir, true).await? {
+                        let name = relative_name(dir, &path);
+                        sources.push((name, KnowledgeBaseUploadSource::File(path)));
+                    }
+                }
+                SyncInput::File(path) => {
+                    let name = path
+                        .file_name()
+                        .map_or_else(|| path.to_string_lossy(), |name| name.to_string_lossy());
+                    sources
+                        .push((name.into_owned(), KnowledgeBaseUploadSource::File(path.clone())));
+                }
+                SyncInput::Url(url) => {
+                    sources.push((url.clone(), KnowledgeBaseUploadSource::Url(url.clone())));
+                }
+            }
+        }
+        Ok(sources)
+    }
+
+    /// Lists the file and URL documents in the synced folder, grouped by
+    /// key with the most recently updated document last.
+    async fn remote_documents(
+        &self,
+    ) -> Result<HashMap<DocumentKey, Vec<KnowledgeBaseDocumentSummary>>> {
+        let mut documents: HashMap<_, Vec<_>> = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .agents
+                .list_knowledge_base(cursor.as_deref(), self.parent_folder_id.as_deref())
+                .await?;
+            for document in page.documents {
+                if document.folder_parent_id != self.parent_folder_id {
+                    continue;
+                }
+                let key = match document.document_type.as_str() {
+                    "file" => DocumentKey::File(document.name.clone()),
+                    "url" => DocumentKey::Url(
+                        document.url.clone().unwrap_or_else(|| document.name.clone()),
+                    ),
+                    _ => continue,
+                };
+                documents.entry(key).or_default().push(document);
+            }
+            match page.next_cursor {
+                Some(next) if page.has_more => cursor = Some(next),
+                _ => break,
+            }
+        }
+        for group in documents.values_mut() {
+            group.sort_by_key(|document| document.metadata.last_updated_at_unix_secs);
+        }
+        Ok(documents)
+    }
+
+    /// Reads a source and decides whether its document needs replacing.
+    async fn compare(
+        &self,
+        source: &KnowledgeBaseUploadSource,
+        document: Option<&KnowledgeBaseDocumentSummary>,
+    ) -> (Option<String>, KnowledgeBaseChange) {
+        let payload = match Payload::read(source).await {
+            Ok(payload) => payload,
+            Err(KnowledgeBaseUploadStatus::Skipped { reason }) => {
+                return (None, KnowledgeBaseChange::Skipped { reason });
+            }
+            Err(KnowledgeBaseUploadStatus::Failed { error }) => {
+                return (None, KnowledgeBaseChange::Failed { error });
+            }
+            // `Payload::read` only reports skipped and failed sources.
+            Err(_) => {
+                let error = "source could not be read".to_owned();
+                return (None, KnowledgeBaseChange::Failed { error });
+            }
+        };
+        let content_hash = payload.content_hash();
+        let Some(document) = document else {
+            return (Some(content_hash), KnowledgeBaseChange::New);
+        };
+
+        let reason = match (&payload, source) {
+            (Payload::File { data, .. }, KnowledgeBaseUploadSource::File(path)) => {
+                let metadata = &document.metadata;
+                if let Some(known) = self.known_hashes.get(&document.id) {
+                    (*known != content_hash).then_some("content changed")
+                } else if metadata.size_bytes > 0 &&
+                    i64::try_from(data.len()).ok() != Some(metadata.size_bytes)
+                {
+                    Some("size changed")
+                } else if modified_time(path)
+                    .await
+                    .is_some_and(|modified| modified > metadata.last_updated_at_unix_secs)
+                {
+                    Some("modified since last upload")
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        let document_id = document.id.clone();
+        let change = match reason {
+            Some(reason) => KnowledgeBaseChange::Stale { document_id, reason: reason.to_owned() },
+            None => KnowledgeBaseChange::Unchanged { document_id },
+        };
+        (Some(content_hash), change)
+    }
+
+    /// Uploads a new or stale source under its document name.
+    async fn upload(&self, entry: &KnowledgeBaseDiffEntry) -> KnowledgeBaseUploadItem {
+        let source = entry.source.clone();
+        let payload = match Payload::read(&entry.source).await {
+            Ok(payload) => payload,
+            Err(status) => return KnowledgeBaseUploadItem { source, content_hash: None, status },
+        };
+        let content_hash = payload.content_hash();
+        let name = matches!(source, KnowledgeBaseUploadSource::File(_)).then_some(&*entry.name);
+        let status =
+            match create_document(&self.agents, payload, name, self.parent_folder_id.as_deref())
+                .await
+            {
+                Ok(document) => KnowledgeBaseUploadStatus::Created { document },
+                Err(e) => KnowledgeBaseUploadStatus::Failed { error: e.to_string() },
+            };
+        KnowledgeBaseUploadItem { source, content_hash: Some(content_hash), status }
+    }
+}
+
+/// Creates the document for a prepared payload.
+async fn create_document(
+    agents: &AgentsService<'_>,
+    payload: Payload<'_>,
+    name: Option<&str>,
+    parent_folder_id: Option<&str>,
+) -> Result<AddKnowledgeBaseResponse> {
+    match payload {
+        Payload::Url(url) => {
+            let request = CreateKnowledgeBaseUrlRequest {
+                url: url.to_owned(),
+                name: name.map(str::to_owned),
+                parent_folder_id: parent_folder_id.map(str::to_owned),
+            };
+            agents.create_knowledge_base_url(&request).await
+        }
+        Payload::File { filename, content_type, data } => {
+            agents
+                .create_knowledge_base_file(filename, content_type, &data, name, parent_folder_id)
+                .await
+        }
+    }
+}
+
+/// Runs `op` for each document ID with bounded concurrency, in input order.
+async fn for_each_document<F, Fut>(
+    ids: Vec<String>,
+    parallelism: usize,
+    op: F,
+) -> Vec<(String, Result<String>)>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<String>>,
+{
+    stream::iter(ids)
+        .map(|id| {
+            let outcome = op(id.clone());
+            async move { (id, outcome.await) }
+        })
+        .buffered(parallelism)
+        .collect()
+        .await
+}
+
+/// Sorts per-document outcomes into successes and errors.
+fn record(
+    outcomes: Vec<(String, Result<String>)>,
+    done: &mut Vec<String>,
+    errors: &mut Vec<KnowledgeBaseSyncError>,
+) {
+    for (document_id, outcome) in outcomes {
+        match outcome {
+            Ok(id) => done.push(id),
+            Err(e) => errors.push(KnowledgeBaseSyncError { document_id, error: e.to_string() }),
+        }
+    }
+}
+
+/// Returns `path` relative to `dir`, with `/` separators.
+fn relative_name(dir: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(dir).unwrap_or(path);
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Returns the modification time of `path`.
+async fn modified_time(path: &Path) -> Option<Timestamp> {
+    let modified = tokio::fs::metadata(path).await.ok()?.modified().ok()?;
+    let secs = i64::try_from(modified.duration_since(UNIX_EPOCH).ok()?.as_secs()).ok()?;
+    Some(Timestamp::from_unix_secs(secs))
+}
+
+/// A source ready to upload.
+enum Payload<'s> {
+    Url(&'s str),
+    File { filename: &'s str, content_type: &'static str, data: Vec<u8> },
+}
+
+impl<'s> Payload<'s> {
+    /// Reads a source, or returns the status to report if it cannot be
+    /// uploaded.
+    async fn read(
+        source: &'s KnowledgeBaseUploadSource,
+    ) -> std::result::Result<Self, KnowledgeBaseUploadStatus> {
+        let path = match source {
+            KnowledgeBaseUploadSource::Url(url) => return Ok(Self::Url(url)),
+            KnowledgeBaseUploadSource::File(path) => path,
+        };
+        let Some(content_type) = content_type_for(path) else {
+            return Err(KnowledgeBaseUploadStatus::Skipped {
+                reason: "unsupported file type".to_owned(),
+            });
+        };
+        let data = tokio::fs::read(path)
+            .await
+            .map_err(|e| KnowledgeBaseUploadStatus::Failed { error: e.to_string() })?;
+        let filename = path.file_name().and_then(|name| name.to_str()).unwrap_or("document");
+        Ok(Self::File { filename, content_type, data })
+    }
+
+    /// Returns the hex-encoded SHA-256 used for deduplication.
+    fn content_hash(&self) -> String {
+        let digest = match self {
+            Self::Url(url) => Sha256::digest(url.as_bytes()),
+            Self::File { data, .. } => Sha256::digest(data),
+        };
+        hex(&digest)
+    }
+}
+
+/// Returns the MIME type for a supported knowledge base file.
+fn content_type_for(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    SUPPORTED_FILE_TYPES.iter().find(|(ext, _)| *ext == extension).map(|(_, mime)| *mime)
+}
+
+/// Returns `true` for dotfiles and dot-directories.
+fn is_hidden(path: &Path) -> bool {
+    path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with('.'))
+}
+
+/// Lists the files under `dir` in sorted path order.
+async fn collect_files(dir: &Path, recursive: bool) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if is_hidden(&path) {
+                continue;
+            }
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                if recursive {
+                    pending.push(path);
+                }
+            } else if file_type.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path, query_param},
+    };
+
+    use super::*;
+
+    fn test_client(base_url: &str) -> ElevenLabsClient {
+        let config = crate::config::ClientConfig::builder("test-key")
+            .base_url(base_url)
+            .max_retries(0_u32)
+            .build();
+        ElevenLabsClient::new(config).unwrap()
+    }
+
+    fn created(id: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": id, "name": id}))
+    }
+
+    #[test]
+    fn content_type_for_known_extensions() {
+        assert_eq!(content_type_for(Path::new("a/manual.PDF")), Some("application/pdf"));
+        assert_eq!(content_type_for(Path::new("notes.md")), Some("text/markdown"));
+        assert_eq!(content_type_for(Path::new("image.png")), None);
+        assert_eq!(content_type_for(Path::new("README")), None);
+    }
+
+    #[tokio::test]
+    async fn upload_urls_deduplicates_and_reports_progress() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/knowledge-base/url"))
+            .respond_with(created("doc_url"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let progress = AtomicUsize::new(0);
+        let summary = client
+            .agents()
+            .knowledge_base_uploader()
+            .parallelism(2)
+            .on_progress(|p| {
+                assert_eq!(p.total, 3);
+                progress.fetch_add(1, Ordering::Relaxed);
+            })
+            .upload_urls(["https://a.example", "https://b.example", "https://a.example"])
+            .await;
+
+        assert_eq!(progress.load(Ordering::Relaxed), 3);
+        assert_eq!(summary.items.len(), 3);
+        assert_eq!(summary.created().count(), 2);
+        assert_eq!(summary.duplicates().count(), 1);
+        assert!(summary.is_success());
+        assert_eq!(
+            summary.items[0].source,
+            KnowledgeBaseUploadSource::Url("https://a.example".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_directory_skips_unsupported_and_duplicate_files() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/knowledge-base/file"))
+            .respond_with(created("doc_file"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let dir = std::env::temp_dir().join(format!("elevenlabs-kb-upload-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("a.txt"), "alpha").unwrap();
+        std::fs::write(dir.join("b.md"), "beta").unwrap();
+        std::fs::write(dir.join("nested/copy.txt"), "alpha").unwrap();
+        std::fs::write(dir.join("logo.png"), [0_u8; 4]).unwrap();
+        std::fs::write(dir.join(".hidden.txt"), "secret").unwrap();
+
+        let client = test_client(&mock_server.uri());
+        let summary =
+            client.agents().knowledge_base_uploader().upload_directory(&dir).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let relative: Vec<PathBuf> = summary
+            .items
+            .iter()
+            .map(|item| match &item.source {
+                KnowledgeBaseUploadSource::File(file) => file.strip_prefix(&dir).unwrap().into(),
+                KnowledgeBaseUploadSource::Url(url) => PathBuf::from(url),
+            })
+            .collect();
+        let expected: Vec<PathBuf> =
+            ["a.txt", "b.md", "logo.png", "nested/copy.txt"].iter().map(PathBuf::from).collect();
+        assert_eq!(relative, expected);
+        assert_eq!(summary.created().count(), 2);
+        assert!(matches!(summary.items[2].status, KnowledgeBaseUploadStatus::Skipped { .. }));
+        assert_eq!(
+            summary.items[3].status,
+            KnowledgeBaseUploadStatus::Duplicate {
+                of: KnowledgeBaseUploadSource::File(dir.join("a.txt"))
+            }
+        );
+        assert_eq!(summary.items[0].content_hash, summary.items[3].content_hash);
+    }
+
+    #[tokio::test]
+    async fn failed_upload_is_reported_per_item() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/knowledge-base/url"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "detail": {"status": "invalid_url", "message": "Could not fetch URL"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let summary =
+            client.agents().knowledge_base_uploader().upload_urls(["https://bad.example"]).await;
+
+        assert!(!summary.is_success());
+        assert_eq!(summary.failed().count(), 1);
+    }
+
+    fn document(
+        id: &str,
+        name: &str,
+        kind: &str,
+        size: i64,
+        folder: Option<&str>,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "name": name,
+            "type": kind,
+            "url": (kind == "url").then_some(name),
+            "metadata": {"created_at_unix_secs": 0, "last_updated_at_unix_secs": 4_102_444_800_i64, "size_bytes": size},
+            "access_info": {"role": "admin"},
+            "folder_parent_id": folder
+        })
+    }
+
+    #[tokio::test]
+    async fn sync_diff_classifies_sources_and_orphans() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/knowledge-base"))
+            .and(query_param("cursor", "page_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "documents": [
+                    document("doc_old", "old.txt", "file", 3, None),
+                    document("doc_elsewhere", "c.txt", "file", 5, Some("folder_x")),
+                    document("doc_text", "notes", "text", 10, None),
+                ],
+                "next_cursor": null,
+                "has_more": false
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/knowledge-base"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "documents": [
+                    document("doc_a", "a.txt", "file", 5, None),
+                    document("doc_b", "guides/b.md", "file", 99, None),
+                    document("doc_url", "https://a.example", "url", 0, None),
+                ],
+                "next_cursor": "page_2",
+                "has_more": true
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let dir = std::env::temp_dir().join(format!("elevenlabs-kb-sync-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("guides")).unwrap();
+        std::fs::write(dir.join("a.txt"), "alpha").unwrap();
+        std::fs::write(dir.join("guides/b.md"), "beta").unwrap();
+        std::fs::write(dir.join("c.txt"), "gamma").unwrap();
+
+        let client = test_client(&mock_server.uri());
+        let sync = client
+            .agents()
+            .knowledge_base_sync()
+            .directory(&dir)
+            .url("https://a.example")
+            .url("https://new.example");
+        let diff = sync.diff().await.unwrap();
+        let rehashed = sync.known_hashes([("doc_a", "stale-hash")]).diff().await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let changes: Vec<(&str, &KnowledgeBaseChange)> =
+            diff.entries.iter().map(|entry| (entry.name.as_str(), &entry.change)).collect();
+        assert_eq!(
+            changes,
+            [
+                ("a.txt", &KnowledgeBaseChange::Unchanged { document_id: "doc_a".into() }),
+                ("c.txt", &KnowledgeBaseChange::New),
+                (
+                    "guides/b.md",
+                    &KnowledgeBaseChange::Stale {
+                        document_id: "doc_b".into(),
+                        reason: "size changed".into()
+                    }
+                ),
+                (
+                    "https://a.example",
+                    &KnowledgeBaseChange::Unchanged { document_id: "doc_url".into() }
+                ),
+                ("https://new.example", &KnowledgeBaseChange::New),
+            ]
+        );
+        assert_eq!(diff.pending().count(), 3);
+        let orphans: Vec<&str> = diff.orphans.iter().map(|doc| doc.id.as_str()).collect();
+        assert_eq!(orphans, ["doc_old"]);
+        assert!(!diff.is_up_to_date());
+
+        assert_eq!(
+            rehashed.entries[0].change,
+            KnowledgeBaseChange::Stale {
+                document_id: "doc_a".into(),
+                reason: "content changed".into()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_run_replaces_stale_documents_and_reindexes() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/knowledge-base"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "documents": [
+                    document("doc_b", "b.md", "file", 99, Some("folder_docs")),
+                    document("doc_old", "old.txt", "file", 3, Some("folder_docs")),
+                ],
+                "next_cursor": null,
+                "has_more": false
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/knowledge-base/file"))
+            .respond_with(created("doc_new"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/convai/knowledge-base/doc_b"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/convai/knowledge-base/doc_old"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "detail": {"status": "document_in_use", "message": "Document is used by agents"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/convai/knowledge-base/doc_new/rag-index"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "rag_1",
+                "model": "e5_mistral_7b_instruct",
+                "status": "created",
+                "progress_percentage": 0.0
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let dir =
+            std::env::temp_dir().join(format!("elevenlabs-kb-sync-run-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b.md"), "beta").unwrap();
+        std::fs::write(dir.join("c.txt"), "gamma").unwrap();
+
+        let client = test_client(&mock_server.uri());
+        let summary = client
+            .agents()
+            .knowledge_base_sync()
+            .parent_folder_id("folder_docs")
+            .directory(&dir)
+            .delete_orphans(true)
+            .rag_index(EmbeddingModel::E5Mistral7bInstruct)
+            .run()
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(summary.uploads.len(), 2);
+        let replacements: Vec<(&str, &str)> =
+            summary.replacements().map(|(old, new)| (old, new.id.as_str())).collect();
+        assert_eq!(replacements, [("doc_b", "doc_new")]);
+        assert_eq!(summary.deleted, ["doc_b"]);
+        assert_eq!(summary.reindexed, ["doc_new", "doc_new"]);
+        assert_eq!(summary.errors.len(), 1);
+        assert_eq!(summary.errors[0].document_id, "doc_old");
+        assert!(!summary.is_success());
+        assert!(summary.content_hashes().contains_key("doc_new"));
+    }
+}
@@ -0,0 +1,755 @@
+//! Knowledge base document content as Markdown, plain text or HTML.
+//!
+//! `GET /v1/convai/knowledge-base/{documentation_id}/content` streams the
+//! HTML extracted from a document. [`AgentsService::download_document`] and
+//! [`AgentsService::download_document_to`] read that stream in order and
+//! convert it chunk by chunk, so a large document can be written to a file
+//! without ever being held in memory. Tags, character references and UTF-8
+//! sequences split across network chunks are carried over to the next one.
+//!
+//! The conversion covers what extracted documents contain: headings,
+//! paragraphs, lists, links, images, emphasis, code, quotes, tables and
+//! line breaks. Scripts and styles are dropped; other tags are removed and
+//! their text kept.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, types::KnowledgeBaseContentFormat};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+//!
+//! let markdown =
+//!     client.agents().download_document("doc_id", KnowledgeBaseContentFormat::Markdown).await?;
+//! println!("{markdown}");
+//!
+//! let mut file = tokio::fs::File::create("manual.txt").await?;
+//! client
+//!     .agents()
+//!     .download_document_to("manual_id", KnowledgeBaseContentFormat::PlainText, &mut file)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`AgentsService::download_document`]: crate::services::AgentsService::download_document
+//! [`AgentsService::download_document_to`]: crate::services::AgentsService::download_document_to
+
+use futures_util::StreamExt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{client::ElevenLabsClient, error::Result, types::KnowledgeBaseContentFormat};
+
+/// Longest character reference recognized, such as `&#x10FFFF;`.
+const MAX_REFERENCE_LEN: usize = 10;
+
+/// Elements whose content is dropped.
+const SKIPPED_ELEMENTS: &[&str] = &["head", "noscript", "script", "style", "svg", "template"];
+
+/// Streams a document's content into `writer`; see
+/// [`AgentsService::download_document_to`](crate::services::AgentsService::download_document_to).
+pub(crate) async fn download_to<W>(
+    client: &ElevenLabsClient,
+    documentation_id: &str,
+    format: KnowledgeBaseContentFormat,
+    writer: &mut W,
+) -> Result<u64>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let path = format!("/v1/convai/knowledge-base/{documentation_id}/content");
+    let mut chunks = std::pin::pin!(client.get_stream(&path).await?);
+    let mut converter = HtmlConverter::new(format);
+    let mut written = 0_u64;
+    while let Some(chunk) = chunks.next().await {
+        let text = converter.push(&chunk?);
+        writer.write_all(text.as_bytes()).await?;
+        written += text.len() as u64;
+    }
+    let text = converter.finish();
+    writer.write_all(text.as_bytes()).await?;
+    writer.flush().await?;
+    Ok(written + text.len() as u64)
+}
+
+/// Converts streamed HTML into a [`KnowledgeBaseContentFormat`].
+///
+/// Output is produced as soon as the input allows: text is written once
+/// the word boundary after it is known, and line breaks are held back until
+/// the next text so runs of block elements collapse into one blank line.
+#[derive(Debug)]
+struct HtmlConverter {
+    format: KnowledgeBaseContentFormat,
+    /// Input not yet converted: an incomplete UTF-8 sequence, tag or
+    /// character reference.
+    pending: Vec<u8>,
+    /// Output produced by the current call.
+    out: String,
+    /// Whether anything has been written.
+    started: bool,
+    /// Line breaks owed before the next text: 1 for a new line, 2 for a
+    /// blank line.
+    breaks: usize,
+    /// Quote depth of the blank line owed, the shallowest since the breaks
+    /// were requested.
+    break_quotes: usize,
+    /// Whether collapsed whitespace is owed before the next text.
+    space: bool,
+    /// Written at the start of the next line, such as a list bullet.
+    marker: Option<String>,
+    /// Open lists, with the next number of each ordered one.
+    lists: Vec<Option<u32>>,
+    /// `href` of each open link.
+    links: Vec<Option<String>>,
+    /// Depth of open `<blockquote>` elements.
+    quotes: usize,
+    /// Depth of open `<pre>` elements.
+    pre: usize,
+    /// Whether the next text is the first inside a `<pre>`.
+    pre_start: bool,
+    /// Element whose content is being dropped.
+    skip: Option<String>,
+    /// Cells written in the current table row.
+    cells: usize,
+    /// Whether the current table row has header cells.
+    header_row: bool,
+    /// Whether the current table has its Markdown header separator.
+    table_separated: bool,
+}
+
+/// A parsed start or end tag.
+#[derive(Debug)]
+struct Tag<'a> {
+    name: String,
+    closing: bool,
+    self_closing: bool,
+    attributes: &'a str,
+}
+
+impl<'a> Tag<'a> {
+    /// Parses `<...>`, or returns `None` for comments, doctypes and
+    /// processing instructions.
+    fn parse(raw: &'a str) -> Option<Self> {
+        let inner = raw.strip_prefix('<')?.strip_suffix('>')?;
+        if inner.starts_with(['!', '?']) {
+            return None;
+        }
+        let (closing, inner) = match inner.strip_prefix('/') {
+            Some(inner) => (true, inner),
+            None => (false, inner),
+        };
+        let name_len = inner.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(inner.len());
+        if name_len == 0 {
+            return None;
+        }
+        Some(Self {
+            name: inner[..name_len].to_ascii_lowercase(),
+            closing,
+            self_closing: inner.ends_with('/'),
+            attributes: &inner[name_len..],
+        })
+    }
+
+    /// Returns the decoded value of the attribute `name`, if present.
+    fn attribute(&self, name: &str) -> Option<String> {
+        let mut rest = self.attributes;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+            if rest.is_empty() {
+                return None;
+            }
+            let key_len = rest
+                .find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '/')
+                .unwrap_or(rest.len());
+            let key = &rest[..key_len];
+            rest = rest[key_len..].trim_start();
+            let value = if let Some(after) = rest.strip_prefix('=') {
+                let after = after.trim_start();
+                let (value, next) = if let Some(quote @ ('"' | '\'')) = after.chars().next() {
+                    let end = after[1..].find(quote).map_or(after.len(), |end| end + 1);
+                    (&after[1..end], after.get(end + 1..).unwrap_or_default())
+                } else {
+                    let end = after.find(|c: char| c.is_ascii_whitespace()).unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                };
+                rest = next;
+                value
+            } else {
+                ""
+            };
+            if key.eq_ignore_ascii_case(name) {
+                return Some(decode_references(value));
+            }
+        }
+    }
+}
+
+impl HtmlConverter {
+    const fn new(format: KnowledgeBaseContentFormat) -> Self {
+        Self {
+            format,
+            pending: Vec::new(),
+            out: String::new(),
+            started: false,
+            breaks: 0,
+            break_quotes: 0,
+            space: false,
+            marker: None,
+            lists: Vec::new(),
+            links: Vec::new(),
+            quotes: 0,
+            pre: 0,
+            pre_start: false,
+            skip: None,
+            cells: 0,
+            header_row: false,
+            table_separated: false,
+        }
+    }
+
+    /// Converts the next chunk of input, returning the output it completes.
+    fn push(&mut self, bytes: &[u8]) -> String {
+        self.pending.extend_from_slice(bytes);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            // An incomplete sequence at the end is finished by the next chunk.
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => self.pending.len(),
+        };
+        let text = String::from_utf8_lossy(&self.pending[..valid]).into_owned();
+        self.pending.drain(..valid);
+        let consumed = self.convert(&text, false);
+        self.pending.splice(..0, text[consumed..].bytes());
+        std::mem::take(&mut self.out)
+    }
+
+    /// Converts the remaining input, returning the rest of the output.
+    fn finish(mut self) -> String {
+        let text = String::from_utf8_lossy(&self.pending).into_owned();
+        self.convert(&text, true);
+        if self.started && self.format != KnowledgeBaseContentFormat::Html {
+            self.out.push('\n');
+        }
+        self.out
+    }
+
+    /// Converts as much of `text` as is complete, returning the number of
+    /// bytes consumed.
+    fn convert(&mut self, text: &str, at_end: bool) -> usize {
+        if self.format == KnowledgeBaseContentFormat::Html {
+            self.out.push_str(text);
+            self.started |= !text.is_empty();
+            return text.len();
+        }
+        let mut pos = 0;
+        while pos < text.len() {
+            let rest = &text[pos..];
+            if let Some(after) = rest.strip_prefix('<') {
+                match after.chars().next() {
+                    None if !at_end => return pos,
+                    Some(c) if c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?') => {
+                        let Some(len) = tag_len(rest) else {
+                            // An unterminated tag at the end is dropped.
+                            return if at_end { text.len() } else { pos };
+                        };
+                        self.tag(&rest[..len]);
+                        pos += len;
+                    }
+                    _ => {
+                        self.text("<");
+                        pos += 1;
+                    }
+                }
+            } else if rest.starts_with('&') {
+                if let Some((c, len)) = decode_reference(rest) {
+                    self.literal(c);
+                    pos += len;
+                } else if !at_end && may_be_reference(rest) {
+                    return pos;
+                } else {
+                    self.text("&");
+                    pos += 1;
+                }
+            } else {
+                let len = rest.find(['<', '&']).unwrap_or(rest.len());
+                self.text(&rest[..len]);
+                pos += len;
+            }
+        }
+        pos
+    }
+
+    fn markdown(&self) -> bool {
+        self.format == KnowledgeBaseContentFormat::Markdown
+    }
+
+    /// Requests at least `breaks` line breaks before the next text.
+    fn block(&mut self, breaks: usize) {
+        if self.started {
+            self.break_quotes =
+                if self.breaks == 0 { self.quotes } else { self.break_quotes.min(self.quotes) };
+            self.breaks = self.breaks.max(breaks);
+        }
+        self.space = false;
+    }
+
+    /// Writes owed line breaks, quote prefixes and markers, or owed
+    /// whitespace, before new output.
+    fn flush(&mut self) {
+        if !self.started || self.breaks > 0 {
+            let prefix = if self.markdown() { "> ".repeat(self.quotes) } else { String::new() };
+            if self.started {
+                self.out.push('\n');
+                if self.breaks > 1 && self.markdown() {
+                    self.out.push_str("> ".repeat(self.break_quotes).trim_end());
+                    self.out.push('\n');
+                } else if self.breaks > 1 {
+                    self.out.push('\n');
+                }
+            }
+            self.out.push_str(&prefix);
+            if let Some(marker) = self.marker.take() {
+                self.out.push_str(&marker);
+            }
+            self.breaks = 0;
+            self.space = false;
+            self.started = true;
+        } else if std::mem::take(&mut self.space) {
+            self.out.push(' ');
+        }
+    }
+
+    /// Writes markup that opens an inline span, such as `**`.
+    fn open_inline(&mut self, markup: &str) {
+        self.flush();
+        self.out.push_str(markup);
+    }
+
+    /// Writes markup that closes an inline span, keeping owed whitespace
+    /// after it.
+    fn close_inline(&mut self, markup: &str) {
+        if self.started {
+            self.out.push_str(markup);
+        }
+    }
+
+    /// Writes a character from a character reference, which is never
+    /// collapsed as whitespace.
+    fn literal(&mut self, c: char) {
+        if self.skip.is_some() {
+            return;
+        }
+        self.flush();
+        self.out.push(if c == '\u{a0}' { ' ' } else { c });
+    }
+
+    /// Writes document text, collapsing whitespace outside `<pre>`.
+    fn text(&mut self, text: &str) {
+        if self.skip.is_some() {
+            return;
+        }
+        if self.pre > 0 {
+            let text = if std::mem::take(&mut self.pre_start) {
+                text.strip_prefix('\n').unwrap_or(text)
+            } else {
+                text
+            };
+            // Trailing newlines become owed breaks, so the closing fence
+            // does not get a blank line before it.
+            let body = text.trim_end_matches('\n');
+            if !body.is_empty() {
+                self.flush();
+                self.out.push_str(body);
+            }
+            let newlines = text.len() - body.len();
+            if newlines > 0 {
+                self.breaks = self.breaks.max(newlines.min(2));
+            }
+            return;
+        }
+        for c in text.chars() {
+            if c.is_ascii_whitespace() {
+                self.space = true;
+            } else {
+                self.flush();
+                self.out.push(c);
+            }
+        }
+    }
+
+    fn tag(&mut self, raw: &str) {
+        let Some(tag) = Tag::parse(raw) else {
+            return;
+        };
+        if let Some(skip) = &self.skip {
+            if tag.closing && tag.name == *skip {
+                self.skip = None;
+            }
+            return;
+        }
+        if !tag.closing && !tag.self_closing && SKIPPED_ELEMENTS.contains(&tag.name.as_str()) {
+            self.skip = Some(tag.name);
+            return;
+        }
+        let markdown = self.markdown();
+        match (tag.name.as_str(), tag.closing) {
+            (name @ ("h1" | "h2" | "h3" | "h4" | "h5" | "h6"), false) => {
+                self.block(2);
+                if markdown {
+                    let level = usize::from(name.as_bytes()[1] - b'0');
+                    self.marker = Some(format!("{} ", "#".repeat(level)));
+                }
+            }
+            ("table", false) => {
+                self.block(2);
+                self.table_separated = false;
+            }
+            ("p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "table" | "figure", _) => {
+                self.block(2);
+            }
+            ("ul" | "ol", false) => {
+                self.block(if self.lists.is_empty() { 2 } else { 1 });
+                let start = tag.attribute("start").and_then(|start| start.parse().ok());
+                self.lists.push((tag.name == "ol").then_some(start.unwrap_or(1)));
+            }
+            ("ul" | "ol", true) => {
+                self.lists.pop();
+                self.block(if self.lists.is_empty() { 2 } else { 1 });
+            }
+            ("li", false) => {
+                self.block(1);
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "- ".to_owned(),
+                };
+                self.marker = Some(indent + &bullet);
+            }
+            ("blockquote", false) => {
+                self.block(2);
+                self.quotes += 1;
+            }
+            ("blockquote", true) => {
+                self.block(2);
+                self.quotes = self.quotes.saturating_sub(1);
+            }
+            ("pre", false) => {
+                self.block(2);
+                if markdown {
+                    self.open_inline("```");
+                    self.block(1);
+                }
+                self.pre += 1;
+                self.pre_start = true;
+            }
+            ("pre", true) => {
+                self.pre = self.pre.saturating_sub(1);
+                self.block(1);
+                if markdown {
+                    self.open_inline("```");
+                }
+                self.block(2);
+            }
+            ("hr", _) => {
+                self.block(2);
+                if markdown {
+                    self.open_inline("---");
+                    self.block(2);
+                }
+            }
+            ("tr", false) => {
+                self.block(1);
+                self.cells = 0;
+                self.header_row = false;
+            }
+            ("tr", true) => {
+                self.block(1);
+                if markdown && self.header_row && !self.table_separated && self.cells > 0 {
+                    self.open_inline(&format!("|{}", " --- |".repeat(self.cells)));
+                    self.block(1);
+                }
+                self.table_separated |= self.header_row;
+            }
+            ("td" | "th", false) => {
+                self.header_row |= tag.name == "th";
+                self.space = false;
+                if markdown {
+                    self.open_inline(if self.cells == 0 { "| " } else { " " });
+                } else if self.cells > 0 {
+                    self.open_inline("\t");
+                }
+            }
+            ("td" | "th", true) => {
+                self.cells += 1;
+                self.space = false;
+                if markdown {
+                    self.close_inline(" |");
+                }
+            }
+            (
+                "br" | "div" | "section" | "article" | "header" | "footer" | "main" | "nav" |
+                "aside" | "dl" | "dt" | "dd" | "figcaption" | "caption" | "li",
+                _,
+            ) => self.block(1),
+            ("strong" | "b", _) if markdown => self.inline_pair("**", tag.closing),
+            ("em" | "i", _) if markdown => self.inline_pair("*", tag.closing),
+            ("code", _) if markdown && self.pre == 0 => self.inline_pair("`", tag.closing),
+            ("a", false) => {
+                let href = tag.attribute("href").filter(|href| !href.is_empty());
+                if markdown && href.is_some() {
+                    self.open_inline("[");
+                }
+                self.links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = self.links.pop() &&
+                    markdown
+                {
+                    self.close_inline(&format!("]({href})"));
+                }
+            }
+            ("img", _) => {
+                let alt = tag.attribute("alt").unwrap_or_default();
+                match tag.attribute("src") {
+                    Some(src) if markdown => self.open_inline(&format!("![{alt}]({src})")),
+                    _ if !alt.is_empty() => self.text(&alt),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn inline_pair(&mut self, markup: &str, closing: bool) {
+        if closing {
+            self.close_inline(markup);
+        } else {
+            self.open_inline(markup);
+        }
+    }
+}
+
+/// Returns the length of the tag at the start of `text`, or `None` if it
+/// is not terminated yet. Quoted attribute values may contain `>`.
+fn tag_len(text: &str) -> Option<usize> {
+    if text.starts_with("<!--") {
+        return text.find("-->").map(|end| end + 3);
+    }
+    let mut quote = None;
+    let mut after_equals = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if after_equals && matches!(c, '"' | '\'') => quote = Some(c),
+            None if c == '>' => return Some(i + 1),
+            None => {}
+        }
+        if !c.is_ascii_whitespace() {
+            after_equals = c == '=';
+        }
+    }
+    None
+}
+
+/// Decodes the character reference at the start of `text`, returning the
+/// character and the reference's length.
+fn decode_reference(text: &str) -> Option<(char, usize)> {
+    let (end, _) = text.char_indices().take(MAX_REFERENCE_LEN + 1).find(|&(_, c)| c == ';')?;
+    let name = &text[1..end];
+    let c = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "ndash" => '\u{2013}',
+        "mdash" => '\u{2014}',
+        "lsquo" => '\u{2018}',
+        "rsquo" => '\u{2019}',
+        "ldquo" => '\u{201c}',
+        "rdquo" => '\u{201d}',
+        "hellip" => '\u{2026}',
+        "copy" => '\u{a9}',
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some((c, end + 1))
+}
+
+/// Returns `true` if `text` could be the start of a character reference
+/// whose `;` has not arrived yet.
+fn may_be_reference(text: &str) -> bool {
+    text.len() <= MAX_REFERENCE_LEN &&
+        text[1..].chars().all(|c| c.is_ascii_alphanumeric() || c == '#')
+}
+
+/// Decodes every character reference in an attribute value.
+fn decode_references(value: &str) -> String {
+    let mut decoded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some((c, len)) = decode_reference(rest) {
+            decoded.push(c);
+            rest = &rest[len..];
+        } else {
+            decoded.push('&');
+            rest = &rest[1..];
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use super::*;
+
+    const HTML: &str = concat!(
+        "<!DOCTYPE html><html><head><title>Manual</title><style>p { color: red }</style></head>",
+        "<body><h1>Getting   started</h1>\n<p>Install the <b>CLI</b>, then read the ",
+        "<a href=\"https://example.com/a?b=1&amp;c=2\">guide</a>.</p>",
+        "<ul><li>One</li><li>Two<ol start=\"3\"><li>Three</li></ol></li></ul>",
+        "<blockquote><p>Fish &amp; chips&nbsp;&#8212; &lt;tasty&gt;</p></blockquote>",
+        "<pre><code>\nfn main() {\n    run();\n}\n</code></pre>",
+        "<table><tr><th>Key</th><th>Value</th></tr><tr><td>a</td><td>1</td></tr></table>",
+        "<script>if (a < b) { alert('x'); }</script><p>Done &c</p></body></html>",
+    );
+
+    const MARKDOWN: &str = "# Getting started
+
+Install the **CLI**, then read the [guide](https://example.com/a?b=1&c=2).
+
+- One
+- Two
+  3. Three
+
+> Fish & chips \u{2014} <tasty>
+
+```
+fn main() {
+    run();
+}
+```
+
+| Key | Value |
+| --- | --- |
+| a | 1 |
+
+Done &c
+";
+
+    const PLAIN_TEXT: &str = "Getting started
+
+Install the CLI, then read the guide.
+
+- One
+- Two
+  3. Three
+
+Fish & chips \u{2014} <tasty>
+
+fn main() {
+    run();
+}
+
+Key\tValue
+a\t1
+
+Done &c
+";
+
+    fn convert(format: KnowledgeBaseContentFormat, chunks: &[&[u8]]) -> String {
+        let mut converter = HtmlConverter::new(format);
+        let mut out: String = chunks.iter().map(|chunk| converter.push(chunk)).collect();
+        out.push_str(&converter.finish());
+        out
+    }
+
+    #[test]
+    fn converts_to_markdown() {
+        assert_eq!(convert(KnowledgeBaseContentFormat::Markdown, &[HTML.as_bytes()]), MARKDOWN);
+    }
+
+    #[test]
+    fn converts_to_plain_text() {
+        assert_eq!(convert(KnowledgeBaseContentFormat::PlainText, &[HTML.as_bytes()]), PLAIN_TEXT);
+    }
+
+    #[test]
+    fn html_passes_through() {
+        assert_eq!(convert(KnowledgeBaseContentFormat::Html, &[HTML.as_bytes()]), HTML);
+    }
+
+    #[test]
+    fn output_does_not_depend_on_chunk_boundaries() {
+        let html = format!("{HTML}<p>Caf\u{e9} \u{1f600}</p>");
+        let whole = convert(KnowledgeBaseContentFormat::Markdown, &[html.as_bytes()]);
+        assert!(whole.ends_with("Caf\u{e9} \u{1f600}\n"));
+        for split in 1..html.len() {
+            let halves = html.as_bytes().split_at(split);
+            assert_eq!(
+                convert(KnowledgeBaseContentFormat::Markdown, &<[_; 2]>::from(halves)),
+                whole,
+                "split at byte {split}"
+            );
+        }
+        let bytes: Vec<&[u8]> = html.as_bytes().chunks(1).collect();
+        assert_eq!(convert(KnowledgeBaseContentFormat::Markdown, &bytes), whole);
+    }
+
+    #[test]
+    fn stray_angle_brackets_and_ampersands_are_text() {
+        assert_eq!(
+            convert(KnowledgeBaseContentFormat::PlainText, &[b"a < b & c &unknown; <p"]),
+            "a < b & c &unknown;\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn download_document_converts_streamed_content() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/convai/knowledge-base/doc_1/content"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(HTML, "text/html"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let config =
+            crate::config::ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let agents = client.agents();
+
+        let markdown =
+            agents.download_document("doc_1", KnowledgeBaseContentFormat::Markdown).await.unwrap();
+        assert_eq!(markdown, MARKDOWN);
+
+        let mut file = Vec::new();
+        let written = agents
+            .download_document_to("doc_1", KnowledgeBaseContentFormat::PlainText, &mut file)
+            .await
+            .unwrap();
+        assert_eq!(written, PLAIN_TEXT.len() as u64);
+        assert_eq!(String::from_utf8(file).unwrap(), PLAIN_TEXT);
+    }
+}
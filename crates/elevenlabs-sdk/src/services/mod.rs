@@ -2,46 +2,79 @@
 //!
 //! Each service groups related endpoints (e.g., text-to-speech, voices) and
 //! is accessed via a corresponding method on [`crate::client::ElevenLabsClient`].
+//!
+//! The larger service groups are behind cargo features, all enabled by
+//! default: `tts` (text-to-speech, dialogue and bulk synthesis), `convai`
+//! (agents, knowledge base and conversation export), `studio`, `dubbing`
+//! and `music`.
 
+#[cfg(feature = "convai")]
 pub mod agents;
 pub mod audio_isolation;
 pub mod audio_native;
+#[cfg(feature = "tts")]
+pub mod bulk;
+#[cfg(feature = "convai")]
+pub mod conversation_export;
+#[cfg(feature = "dubbing")]
 pub mod dubbing;
 pub mod forced_alignment;
 pub mod history;
+#[cfg(feature = "convai")]
+pub mod knowledge_base;
+#[cfg(feature = "convai")]
+pub mod knowledge_base_content;
 pub mod models;
+#[cfg(feature = "music")]
 pub mod music;
+pub mod pvc_trainer;
 pub mod pvc_voices;
 pub mod single_use_token;
 pub mod sound_generation;
 pub mod speech_to_speech;
 pub mod speech_to_text;
+#[cfg(feature = "studio")]
 pub mod studio;
+#[cfg(feature = "tts")]
 pub mod text_to_dialogue;
+#[cfg(feature = "tts")]
 pub mod text_to_speech;
 pub mod text_to_voice;
+pub mod usage;
 pub mod user;
 pub mod voice_generation;
 pub mod voices;
 pub mod workspace;
 
+#[cfg(feature = "convai")]
 pub use agents::AgentsService;
 pub use audio_isolation::AudioIsolationService;
 pub use audio_native::AudioNativeService;
+#[cfg(feature = "tts")]
+pub use bulk::{BulkJob, BulkProgress, BulkSynthesizer, JobResult};
+#[cfg(feature = "dubbing")]
 pub use dubbing::DubbingService;
-pub use forced_alignment::ForcedAlignmentService;
+pub use forced_alignment::{ForcedAlignmentService, MAX_FORCED_ALIGNMENT_FILE_BYTES};
 pub use history::HistoryService;
+#[cfg(feature = "convai")]
+pub use knowledge_base::{KnowledgeBaseSync, KnowledgeBaseUploader};
 pub use models::ModelsService;
+#[cfg(feature = "music")]
 pub use music::MusicService;
+pub use pvc_trainer::PvcTrainer;
 pub use pvc_voices::PvcVoicesService;
 pub use single_use_token::SingleUseTokenService;
 pub use sound_generation::SoundGenerationService;
 pub use speech_to_speech::SpeechToSpeechService;
 pub use speech_to_text::SpeechToTextService;
+#[cfg(feature = "studio")]
 pub use studio::StudioService;
+#[cfg(feature = "tts")]
 pub use text_to_dialogue::TextToDialogueService;
-pub use text_to_speech::TextToSpeechService;
+#[cfg(feature = "tts")]
+pub use text_to_speech::{TextChunker, TextToSpeechService};
 pub use text_to_voice::TextToVoiceService;
+pub use usage::UsageService;
 pub use user::UserService;
 pub use voice_generation::VoiceGenerationService;
 pub use voices::VoicesService;
@@ -3,11 +3,15 @@
 //! | Method | Endpoint | Description |
 //! |--------|----------|-------------|
 //! | [`list`](ModelsService::list) | `GET /v1/models` | List available models |
+//! | [`best_for`](ModelsService::best_for) | `GET /v1/models` | Pick the model best matching some criteria |
 //!
 //! # Example
 //!
 //! ```no_run
-//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient};
+//! use elevenlabs_sdk::{
+//!     ClientConfig, ElevenLabsClient,
+//!     types::{ModelCriteria, ModelPriority},
+//! };
 //!
 //! # async fn example() -> elevenlabs_sdk::Result<()> {
 //! let config = ClientConfig::builder("your-api-key").build();
@@ -15,11 +19,21 @@
 //!
 //! let models = client.models().list().await?;
 //! println!("Found {} models", models.0.len());
+//!
+//! let criteria =
+//!     ModelCriteria::text_to_speech().language("ja").priority(ModelPriority::LowLatency);
+//! if let Some(model) = client.models().best_for(&criteria).await? {
+//!     println!("Using {}", model.model_id);
+//! }
 //! # Ok(())
 //! # }
 //! ```
 
-use crate::{client::ElevenLabsClient, error::Result, types::GetModelsResponse};
+use crate::{
+    client::ElevenLabsClient,
+    error::Result,
+    types::{GetModelsResponse, Model, ModelCriteria},
+};
 
 /// Models service providing typed access to model listing endpoints.
 ///
@@ -46,6 +60,21 @@ impl<'a> ModelsService<'a> {
     pub async fn list(&self) -> Result<GetModelsResponse> {
         self.client.get("/v1/models").await
     }
+
+    /// Lists the available models and returns the one that best matches
+    /// `criteria`, or `None` if none meets its requirements.
+    ///
+    /// See [`GetModelsResponse::best_for`] to choose from a list fetched
+    /// earlier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn best_for(&self, criteria: &ModelCriteria) -> Result<Option<Model>> {
+        let models = self.list().await?;
+        Ok(models.best_for(criteria).cloned())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -60,7 +89,11 @@ mod tests {
         matchers::{header, method, path},
     };
 
-    use crate::{ElevenLabsClient, config::ClientConfig};
+    use crate::{
+        ElevenLabsClient,
+        config::ClientConfig,
+        types::{ModelCriteria, ModelPriority},
+    };
 
     #[tokio::test]
     async fn list_returns_models() {
@@ -84,7 +117,7 @@ mod tests {
                     "requires_alpha_access": false,
                     "max_characters_request_free_user": 2500,
                     "max_characters_request_subscribed_user": 5000,
-                    "maximum_text_length_per_request": 1000000,
+                    "maximum_text_length_per_request": 1_000_000,
                     "languages": [{"language_id": "en", "name": "English"}],
                     "model_rates": {"character_cost_multiplier": 1.0},
                     "concurrency_group": "standard"
@@ -117,4 +150,41 @@ mod tests {
         let result = client.models().list().await.unwrap();
         assert!(result.0.is_empty());
     }
+
+    #[tokio::test]
+    async fn best_for_picks_matching_model() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "model_id": "eleven_multilingual_v2",
+                    "can_do_text_to_speech": true,
+                    "languages": [{"language_id": "en"}, {"language_id": "ja"}],
+                    "model_rates": {"character_cost_multiplier": 1.0}
+                },
+                {
+                    "model_id": "eleven_flash_v2",
+                    "can_do_text_to_speech": true,
+                    "languages": [{"language_id": "en"}],
+                    "model_rates": {"character_cost_multiplier": 0.5}
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let fast = ModelCriteria::text_to_speech().priority(ModelPriority::LowLatency);
+        let model = client.models().best_for(&fast).await.unwrap().unwrap();
+        assert_eq!(model.model_id, "eleven_flash_v2");
+
+        let model = client.models().best_for(&fast.language("ja")).await.unwrap().unwrap();
+        assert_eq!(model.model_id, "eleven_multilingual_v2");
+
+        let none = client.models().best_for(&ModelCriteria::voice_conversion()).await.unwrap();
+        assert!(none.is_none());
+    }
 }
@@ -0,0 +1,417 @@
+//! End-to-end Professional Voice Cloning workflow.
+//!
+//! [`PvcTrainer`] strings the PVC endpoints together in three steps:
+//!
+//! 1. [`start`](PvcTrainer::start) creates the voice, uploads samples, and fetches the verification
+//!    captcha.
+//! 2. [`verify_captcha`](PvcTrainer::verify_captcha) submits a recording of the speaker reading the
+//!    captcha. This step needs a human, so the trainer hands control back to the caller instead of
+//!    hiding it.
+//! 3. [`train`](PvcTrainer::train) starts training and polls the voice until it is fine-tuned or
+//!    fails.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use elevenlabs_sdk::{
+//!     ClientConfig, ElevenLabsClient,
+//!     types::{CreatePvcVoiceRequest, PvcAudioFile},
+//! };
+//!
+//! # async fn record_captcha(_: &elevenlabs_sdk::types::PvcCaptchaChallenge) -> PvcAudioFile { unimplemented!() }
+//! # async fn example() -> elevenlabs_sdk::Result<()> {
+//! let config = ClientConfig::builder("your-api-key").build();
+//! let client = ElevenLabsClient::new(config)?;
+//!
+//! let trainer = client.pvc_voices().trainer().on_event(|event| println!("{event:?}"));
+//! let request = CreatePvcVoiceRequest { name: "Narrator".into(), description: None, labels: None };
+//! let samples = [PvcAudioFile::new("take1.mp3", "audio/mpeg", std::fs::read("take1.mp3")?)];
+//!
+//! let challenge = trainer.start(&request, &samples).await?;
+//! let recording = record_captcha(&challenge).await;
+//! trainer.verify_captcha(&challenge, &recording).await?;
+//!
+//! let outcome = trainer.train(&challenge.voice_id).await?;
+//! println!("trained: {}", outcome.is_trained());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt, time::Duration};
+
+use crate::{
+    client::ElevenLabsClient,
+    error::{ElevenLabsError, Result},
+    services::{PvcVoicesService, VoicesService},
+    types::{
+        CreatePvcVoiceRequest, FineTuningState, PvcAudioFile, PvcCaptchaChallenge,
+        PvcTrainingEvent, PvcTrainingOutcome, RunPvcTrainingRequest,
+    },
+};
+
+/// Model PVC voices are trained for unless overridden with
+/// [`PvcTrainer::model_id`].
+pub const DEFAULT_PVC_MODEL_ID: &str = "eleven_multilingual_v2";
+
+/// Delay between training status polls unless overridden with
+/// [`PvcTrainer::poll_interval`].
+pub const DEFAULT_PVC_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Callback invoked for each workflow event.
+type EventCallback<'a> = Box<dyn Fn(&PvcTrainingEvent) + Send + Sync + 'a>;
+
+/// Drives the PVC workflow from voice creation to a trained voice.
+///
+/// Obtained via [`PvcVoicesService::trainer`].
+pub struct PvcTrainer<'a> {
+    client: &'a ElevenLabsClient,
+    model_id: String,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+    on_event: Option<EventCallback<'a>>,
+}
+
+impl fmt::Debug for PvcTrainer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PvcTrainer")
+            .field("model_id", &self.model_id)
+            .field("poll_interval", &self.poll_interval)
+            .field("timeout", &self.timeout)
+            .field("on_event", &self.on_event.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> PvcTrainer<'a> {
+    /// Creates a trainer bound to the given client.
+    pub(crate) const fn new(client: &'a ElevenLabsClient) -> Self {
+        Self {
+            client,
+            model_id: String::new(),
+            poll_interval: DEFAULT_PVC_POLL_INTERVAL,
+            timeout: None,
+            on_event: None,
+        }
+    }
+
+    /// Sets the model to train the voice for (default
+    /// [`DEFAULT_PVC_MODEL_ID`]).
+    #[must_use]
+    pub fn model_id(mut self, model_id: impl Into<String>) -> Self {
+        self.model_id = model_id.into();
+        self
+    }
+
+    /// Sets the delay between training status polls.
+    #[must_use]
+    pub const fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Gives up waiting for training after `timeout`. Training keeps running
+    /// server-side; only the polling stops.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Registers a callback invoked for each workflow event.
+    #[must_use]
+    pub fn on_event<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&PvcTrainingEvent) + Send + Sync + 'a,
+    {
+        self.on_event = Some(Box::new(callback));
+        self
+    }
+
+    /// Creates the voice, uploads `samples`, and fetches the captcha the
+    /// speaker must read.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if `samples` is empty, or an
+    /// error if any API request fails. The voice is not deleted if a later
+    /// step fails.
+    pub async fn start(
+        &self,
+        request: &CreatePvcVoiceRequest,
+        samples: &[PvcAudioFile],
+    ) -> Result<PvcCaptchaChallenge> {
+        if samples.is_empty() {
+            return Err(ElevenLabsError::Validation(
+                "at least one PVC sample is required".to_owned(),
+            ));
+        }
+        let pvc = PvcVoicesService::new(self.client);
+
+        let voice_id = pvc.create_pvc_voice(request).await?.voice_id;
+        self.emit(PvcTrainingEvent::VoiceCreated { voice_id: voice_id.clone() });
+
+        let files: Vec<(&str, &str, &[u8])> = samples
+            .iter()
+            .map(|file| (file.file_name.as_str(), file.content_type.as_str(), file.data.as_ref()))
+            .collect();
+        let uploaded = pvc.add_pvc_voice_samples(&voice_id, &files).await?;
+        self.emit(PvcTrainingEvent::SamplesUploaded {
+            voice_id: voice_id.clone(),
+            count: uploaded.len(),
+        });
+
+        let captcha = pvc.get_pvc_voice_captcha(&voice_id).await?;
+        self.emit(PvcTrainingEvent::CaptchaRequired { voice_id: voice_id.clone() });
+
+        Ok(PvcCaptchaChallenge { voice_id, samples: uploaded, captcha })
+    }
+
+    /// Submits the speaker's recording of the captcha.
+    ///
+    /// A rejected recording returns an error and may be retried with a new
+    /// recording against the same challenge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API rejects the recording or the request
+    /// fails.
+    pub async fn verify_captcha(
+        &self,
+        challenge: &PvcCaptchaChallenge,
+        recording: &PvcAudioFile,
+    ) -> Result<()> {
+        PvcVoicesService::new(self.client)
+            .verify_pvc_voice_captcha(
+                &challenge.voice_id,
+                &recording.data,
+                &recording.file_name,
+                &recording.content_type,
+            )
+            .await?;
+        self.emit(PvcTrainingEvent::CaptchaVerified { voice_id: challenge.voice_id.clone() });
+        Ok(())
+    }
+
+    /// Starts training and polls until the voice is fine-tuned or training
+    /// fails.
+    ///
+    /// A failed training run is reported through
+    /// [`PvcTrainingOutcome::state`], not as an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Timeout`] if the configured timeout
+    /// elapses, or an error if any API request fails.
+    pub async fn train(&self, voice_id: &str) -> Result<PvcTrainingOutcome> {
+        let model_id =
+            if self.model_id.is_empty() { DEFAULT_PVC_MODEL_ID } else { self.model_id.as_str() };
+        let request = RunPvcTrainingRequest { model_id: Some(model_id.to_owned()) };
+        PvcVoicesService::new(self.client).run_pvc_voice_training(voice_id, &request).await?;
+        self.emit(PvcTrainingEvent::TrainingStarted {
+            voice_id: voice_id.to_owned(),
+            model_id: model_id.to_owned(),
+        });
+
+        let deadline = self.timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+        let voices = VoicesService::new(self.client);
+        let mut last = None;
+        loop {
+            let fine_tuning = voices.get(voice_id, None).await?.fine_tuning;
+            let state = fine_tuning
+                .as_ref()
+                .and_then(|ft| ft.state.get(model_id).copied())
+                .unwrap_or(FineTuningState::NotStarted);
+            let progress =
+                fine_tuning.as_ref().and_then(|ft| ft.progress.as_ref()?.get(model_id).copied());
+
+            if last != Some((state, progress)) {
+                last = Some((state, progress));
+                self.emit(PvcTrainingEvent::TrainingProgress {
+                    voice_id: voice_id.to_owned(),
+                    state,
+                    progress,
+                });
+            }
+
+            if matches!(state, FineTuningState::FineTuned | FineTuningState::Failed) {
+                return Ok(PvcTrainingOutcome {
+                    voice_id: voice_id.to_owned(),
+                    model_id: model_id.to_owned(),
+                    state,
+                    fine_tuning,
+                });
+            }
+            if deadline.is_some_and(|deadline| tokio::time::Instant::now() >= deadline) {
+                return Err(ElevenLabsError::Timeout);
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Passes `event` to the registered callback, if any.
+    fn emit(&self, event: PvcTrainingEvent) {
+        if let Some(callback) = &self.on_event {
+            callback(&event);
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use std::sync::Mutex;
+
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_json, method, path},
+    };
+
+    use super::*;
+
+    fn test_client(base_url: &str) -> ElevenLabsClient {
+        let config = crate::config::ClientConfig::builder("test-key")
+            .base_url(base_url)
+            .max_retries(0_u32)
+            .build();
+        ElevenLabsClient::new(config).unwrap()
+    }
+
+    fn voice_in_state(state: &str, progress: f64) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "voice_id": "v1",
+            "category": "professional",
+            "fine_tuning": {
+                "state": {"eleven_multilingual_v2": state},
+                "progress": {"eleven_multilingual_v2": progress}
+            }
+        }))
+    }
+
+    #[tokio::test]
+    async fn start_creates_voice_uploads_samples_and_fetches_captcha() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/pvc"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"voice_id": "v1"})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/pvc/v1/samples"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"sample_id": "s1", "file_name": "take1.mp3"}
+            ])))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/pvc/v1/captcha"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"text": "read this aloud"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let events = Mutex::new(Vec::new());
+        let trainer =
+            client.pvc_voices().trainer().on_event(|e| events.lock().unwrap().push(e.clone()));
+        let request =
+            CreatePvcVoiceRequest { name: "Narrator".into(), description: None, labels: None };
+        let samples = [PvcAudioFile::new("take1.mp3", "audio/mpeg", b"ID3".to_vec())];
+
+        let challenge = trainer.start(&request, &samples).await.unwrap();
+        assert_eq!(challenge.voice_id, "v1");
+        assert_eq!(challenge.samples[0].sample_id, "s1");
+        assert_eq!(challenge.captcha.extra["text"], "read this aloud");
+        drop(trainer);
+        assert_eq!(
+            events.into_inner().unwrap(),
+            [
+                PvcTrainingEvent::VoiceCreated { voice_id: "v1".into() },
+                PvcTrainingEvent::SamplesUploaded { voice_id: "v1".into(), count: 1 },
+                PvcTrainingEvent::CaptchaRequired { voice_id: "v1".into() },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn start_requires_samples() {
+        let client = test_client("http://127.0.0.1:1");
+        let request =
+            CreatePvcVoiceRequest { name: "Narrator".into(), description: None, labels: None };
+        let result = client.pvc_voices().trainer().start(&request, &[]).await;
+        assert!(matches!(result, Err(ElevenLabsError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn train_polls_until_fine_tuned() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/pvc/v1/train"))
+            .and(body_json(serde_json::json!({"model_id": "eleven_multilingual_v2"})))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/v1"))
+            .respond_with(voice_in_state("fine_tuning", 0.5))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/v1"))
+            .respond_with(voice_in_state("fine_tuned", 1.0))
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let progress = Mutex::new(Vec::new());
+        let trainer =
+            client.pvc_voices().trainer().poll_interval(Duration::from_millis(1)).on_event(|e| {
+                if let PvcTrainingEvent::TrainingProgress { state, .. } = e {
+                    progress.lock().unwrap().push(*state);
+                }
+            });
+
+        let outcome = trainer.train("v1").await.unwrap();
+        assert!(outcome.is_trained());
+        drop(trainer);
+        // The repeated `fine_tuning` poll does not produce a second event.
+        assert_eq!(
+            progress.into_inner().unwrap(),
+            [FineTuningState::FineTuning, FineTuningState::FineTuned]
+        );
+    }
+
+    #[tokio::test]
+    async fn train_times_out() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/pvc/v1/train"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/v1"))
+            .respond_with(voice_in_state("queued", 0.0))
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let result = client
+            .pvc_voices()
+            .trainer()
+            .poll_interval(Duration::from_millis(1))
+            .timeout(Duration::ZERO)
+            .train("v1")
+            .await;
+        assert!(matches!(result, Err(ElevenLabsError::Timeout)));
+    }
+}
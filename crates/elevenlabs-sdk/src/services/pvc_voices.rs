@@ -2,18 +2,21 @@
 //!
 //! Provides typed access to the PVC voice creation, editing, sample
 //! management, speaker separation, captcha verification, training,
-//! and manual verification endpoints.
+//! and manual verification endpoints. [`PvcVoicesService::trainer`] wraps
+//! them in a staged end-to-end workflow.
 
 use bytes::Bytes;
 
 use crate::{
     client::ElevenLabsClient,
     error::Result,
+    services::pvc_trainer::PvcTrainer,
     types::{
         AddVoiceResponse, CreatePvcVoiceRequest, DeletePvcSampleResponse, EditPvcVoiceRequest,
         EditPvcVoiceSampleRequest, GetPvcCaptchaResponse, RequestPvcManualVerificationResponse,
-        SpeakerSeparationResponse, StartPvcTrainingResponse, StartSpeakerSeparationResponse,
-        VerifyPvcCaptchaResponse, VoiceSamplePreviewResponse, VoiceSampleWaveformResponse,
+        RunPvcTrainingRequest, SampleResponse, SpeakerSeparationResponse, StartPvcTrainingResponse,
+        StartSpeakerSeparationResponse, VerifyPvcCaptchaResponse, VoiceSamplePreviewResponse,
+        VoiceSampleWaveformResponse,
     },
 };
 
@@ -71,7 +74,7 @@ impl<'a> PvcVoicesService<'a> {
         &self,
         voice_id: &str,
         files: &[(&str, &str, &[u8])],
-    ) -> Result<serde_json::Value> {
+    ) -> Result<Vec<SampleResponse>> {
         let boundary = multipart_boundary();
         let mut buf = Vec::new();
         for (filename, content_type, data) in files {
@@ -219,9 +222,13 @@ impl<'a> PvcVoicesService<'a> {
     /// Starts the PVC training process for a voice.
     ///
     /// `POST /v1/voices/pvc/{voice_id}/train`
-    pub async fn run_pvc_voice_training(&self, voice_id: &str) -> Result<StartPvcTrainingResponse> {
+    pub async fn run_pvc_voice_training(
+        &self,
+        voice_id: &str,
+        request: &RunPvcTrainingRequest,
+    ) -> Result<StartPvcTrainingResponse> {
         let path = format!("/v1/voices/pvc/{voice_id}/train");
-        self.client.post(&path, &serde_json::Value::Object(Default::default())).await
+        self.client.post(&path, request).await
     }
 
     /// Requests manual verification for a PVC voice.
@@ -234,6 +241,16 @@ impl<'a> PvcVoicesService<'a> {
         let path = format!("/v1/voices/pvc/{voice_id}/verification");
         self.client.post(&path, &serde_json::Value::Object(Default::default())).await
     }
+
+    // =======================================================================
+    // Workflow
+    // =======================================================================
+
+    /// Returns a [`PvcTrainer`] that drives voice creation, sample upload,
+    /// captcha verification, and training.
+    pub const fn trainer(&self) -> PvcTrainer<'a> {
+        PvcTrainer::new(self.client)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -434,7 +451,8 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.pvc_voices().run_pvc_voice_training("v1").await.unwrap();
+        let request = crate::types::RunPvcTrainingRequest::default();
+        let result = client.pvc_voices().run_pvc_voice_training("v1", &request).await.unwrap();
         assert_eq!(result.status, "ok");
     }
 
@@ -3,19 +3,23 @@
 //! # Example
 //!
 //! ```no_run
-//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient};
+//! use elevenlabs_sdk::{ClientConfig, ElevenLabsClient, types::TokenPurpose};
 //!
 //! # async fn example() -> elevenlabs_sdk::Result<()> {
 //! let config = ClientConfig::builder("your-api-key").build();
 //! let client = ElevenLabsClient::new(config)?;
 //!
-//! let token = client.single_use_token().create("tts").await?;
-//! println!("Token: {}", token.token);
+//! let token = client.single_use_token().create(TokenPurpose::TtsWebsocket).await?;
+//! println!("Token: {} (expires at {})", token.token, token.expires_at);
 //! # Ok(())
 //! # }
 //! ```
 
-use crate::{client::ElevenLabsClient, error::Result, types::SingleUseTokenResponse};
+use crate::{
+    client::ElevenLabsClient,
+    error::Result,
+    types::{SingleUseToken, SingleUseTokenResponse, TokenPurpose},
+};
 
 /// Single-use token service providing typed access to token generation.
 ///
@@ -31,20 +35,24 @@ impl<'a> SingleUseTokenService<'a> {
         Self { client }
     }
 
-    /// Creates a single-use token for the given token type.
+    /// Creates a single-use token for the given purpose.
     ///
-    /// Calls `POST /v1/single-use-token/{token_type}`.
+    /// Calls `POST /v1/single-use-token/{token_type}`. The returned token
+    /// expires after [`SINGLE_USE_TOKEN_TTL`](crate::types::SINGLE_USE_TOKEN_TTL)
+    /// and can only authenticate one connection.
     ///
     /// # Arguments
     ///
-    /// * `token_type` — The type of token to create (e.g. `"tts"`).
+    /// * `purpose` — What the token will be used for.
     ///
     /// # Errors
     ///
     /// Returns an error if the API request fails.
-    pub async fn create(&self, token_type: &str) -> Result<SingleUseTokenResponse> {
-        let path = format!("/v1/single-use-token/{token_type}");
-        self.client.post(&path, &serde_json::json!({})).await
+    pub async fn create(&self, purpose: TokenPurpose) -> Result<SingleUseToken> {
+        let path = format!("/v1/single-use-token/{purpose}");
+        let response: SingleUseTokenResponse =
+            self.client.post(&path, &serde_json::json!({})).await?;
+        Ok(SingleUseToken::new(response.token, purpose))
     }
 }
 
@@ -60,14 +68,14 @@ mod tests {
         matchers::{header, method, path},
     };
 
-    use crate::{ElevenLabsClient, config::ClientConfig};
+    use crate::{ElevenLabsClient, config::ClientConfig, types::TokenPurpose};
 
     #[tokio::test]
     async fn create_returns_token() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("POST"))
-            .and(path("/v1/single-use-token/tts"))
+            .and(path("/v1/single-use-token/tts_websocket"))
             .and(header("xi-api-key", "test-key"))
             .respond_with(
                 ResponseTemplate::new(200)
@@ -79,7 +87,9 @@ mod tests {
         let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
         let client = ElevenLabsClient::new(config).unwrap();
 
-        let result = client.single_use_token().create("tts").await.unwrap();
+        let result = client.single_use_token().create(TokenPurpose::TtsWebsocket).await.unwrap();
         assert_eq!(result.token, "tok_abc123");
+        assert_eq!(result.purpose, TokenPurpose::TtsWebsocket);
+        assert!(!result.is_expired());
     }
 }
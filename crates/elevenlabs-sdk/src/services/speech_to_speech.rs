@@ -6,6 +6,8 @@
 //! |--------|----------|-------------|
 //! | [`convert`](SpeechToSpeechService::convert) | `POST /v1/speech-to-speech/{voice_id}` | Convert speech (full audio) |
 //! | [`convert_stream`](SpeechToSpeechService::convert_stream) | `POST /v1/speech-to-speech/{voice_id}/stream` | Convert speech (streaming) |
+//! | [`convert_stream_with_options`](SpeechToSpeechService::convert_stream_with_options) | `POST /v1/speech-to-speech/{voice_id}/stream` | Streaming with latency and logging options |
+//! | `convert_long` | `POST /v1/speech-to-speech/{voice_id}` per chunk | Resample, chunk and convert a long WAV recording (`audio-processing` feature) |
 //!
 //! Both endpoints accept `multipart/form-data` with an audio file and
 //! optional configuration fields. The response is raw audio bytes.
@@ -33,10 +35,16 @@
 use bytes::Bytes;
 use futures_core::Stream;
 
+#[cfg(feature = "audio-processing")]
+use crate::{
+    audio::processing::{self, PreprocessOptions},
+    types::SpeechToSpeechFileFormat,
+};
 use crate::{
     client::ElevenLabsClient,
     error::Result,
-    types::{OutputFormat, SpeechToSpeechRequest},
+    middleware::quota::sts_characters,
+    types::{OutputFormat, SpeechToSpeechRequest, SpeechToSpeechStreamOptions},
 };
 
 /// Speech-to-speech service providing typed access to S2S endpoints.
@@ -53,14 +61,24 @@ impl<'a> SpeechToSpeechService<'a> {
         Self { client }
     }
 
-    /// Builds the endpoint path with an optional `output_format` query
-    /// parameter.
-    fn build_path(voice_id: &str, suffix: &str, output_format: Option<OutputFormat>) -> String {
+    /// Builds the endpoint path with optional query parameters.
+    fn build_path(voice_id: &str, suffix: &str, options: &SpeechToSpeechStreamOptions) -> String {
         let mut path = format!("/v1/speech-to-speech/{voice_id}{suffix}");
-        if let Some(fmt) = output_format {
-            path.push_str("?output_format=");
-            path.push_str(&fmt.to_string());
+        let mut params = Vec::new();
+        if let Some(fmt) = options.output_format {
+            params.push(format!("output_format={fmt}"));
+        }
+        if let Some(latency) = options.optimize_streaming_latency {
+            params.push(format!("optimize_streaming_latency={latency}"));
+        }
+        if let Some(enable_logging) = options.enable_logging {
+            params.push(format!("enable_logging={enable_logging}"));
         }
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
         path
     }
 
@@ -92,7 +110,9 @@ impl<'a> SpeechToSpeechService<'a> {
         content_type: &str,
         output_format: Option<OutputFormat>,
     ) -> Result<Bytes> {
-        let path = Self::build_path(voice_id, "", output_format);
+        let options = SpeechToSpeechStreamOptions { output_format, ..Default::default() };
+        let path = Self::build_path(voice_id, "", &options);
+        self.client.guard_quota(sts_characters(audio_data)).await?;
         let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
         let body = build_s2s_multipart(&boundary, request, audio_data, filename, content_type);
         let ct = format!("multipart/form-data; boundary={boundary}");
@@ -103,7 +123,9 @@ impl<'a> SpeechToSpeechService<'a> {
     /// byte chunks.
     ///
     /// Calls `POST /v1/speech-to-speech/{voice_id}/stream` with
-    /// `multipart/form-data`.
+    /// `multipart/form-data`. Equivalent to
+    /// [`convert_stream_with_options`](Self::convert_stream_with_options)
+    /// with only `output_format` set.
     ///
     /// # Arguments
     ///
@@ -126,13 +148,100 @@ impl<'a> SpeechToSpeechService<'a> {
         filename: &str,
         content_type: &str,
         output_format: Option<OutputFormat>,
-    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>>> {
-        let path = Self::build_path(voice_id, "/stream", output_format);
+    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<>> {
+        let options = SpeechToSpeechStreamOptions { output_format, ..Default::default() };
+        self.convert_stream_with_options(
+            voice_id,
+            request,
+            audio_data,
+            filename,
+            content_type,
+            &options,
+        )
+        .await
+    }
+
+    /// Converts speech using the given voice, returning a stream of audio
+    /// byte chunks, with full control over the streaming query parameters.
+    ///
+    /// Calls `POST /v1/speech-to-speech/{voice_id}/stream` with
+    /// `multipart/form-data`.
+    ///
+    /// # Arguments
+    ///
+    /// * `voice_id` — The target voice ID for conversion.
+    /// * `request` — Configuration fields (model, seed, background noise removal, etc.).
+    /// * `audio_data` — Raw bytes of the input audio file.
+    /// * `filename` — Filename for the audio part (e.g. `"input.mp3"`).
+    /// * `content_type` — MIME type of the audio file (e.g. `"audio/mpeg"`).
+    /// * `options` — Output format, latency optimization, and logging.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the initial API request fails. Individual stream
+    /// items may also carry transport errors.
+    pub async fn convert_stream_with_options(
+        &self,
+        voice_id: &str,
+        request: &SpeechToSpeechRequest,
+        audio_data: &[u8],
+        filename: &str,
+        content_type: &str,
+        options: &SpeechToSpeechStreamOptions,
+    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<>> {
+        let path = Self::build_path(voice_id, "/stream", options);
+        self.client.guard_quota(sts_characters(audio_data)).await?;
         let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
         let body = build_s2s_multipart(&boundary, request, audio_data, filename, content_type);
         let ct = format!("multipart/form-data; boundary={boundary}");
         self.client.post_multipart_stream(&path, body, &ct).await
     }
+
+    /// Converts a WAV recording of any length, sample rate or channel
+    /// count.
+    ///
+    /// The recording is resampled to 16 kHz mono and split at pauses with
+    /// [`processing::prepare`]; each chunk is sent to
+    /// [`convert`](Self::convert) as
+    /// [`PcmS16le16`](SpeechToSpeechFileFormat::PcmS16le16) and the results
+    /// are joined with [`processing::concat`]. Set `request.seed` to keep
+    /// the voice consistent across chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`](crate::ElevenLabsError::Validation)
+    /// if `wav` is not a 16-bit PCM WAV file, or an error if converting a
+    /// chunk fails.
+    #[cfg(feature = "audio-processing")]
+    pub async fn convert_long(
+        &self,
+        voice_id: &str,
+        request: &SpeechToSpeechRequest,
+        wav: &[u8],
+        options: &PreprocessOptions,
+        output_format: Option<OutputFormat>,
+    ) -> Result<Bytes> {
+        let chunks = processing::prepare(wav, options)?;
+        let request = SpeechToSpeechRequest {
+            file_format: Some(SpeechToSpeechFileFormat::PcmS16le16),
+            ..request.clone()
+        };
+        let mut parts = Vec::with_capacity(chunks.len());
+        for chunk in &chunks {
+            let part = self
+                .convert(
+                    voice_id,
+                    &request,
+                    chunk,
+                    "audio.pcm",
+                    "application/octet-stream",
+                    output_format,
+                )
+                .await?;
+            parts.push(part);
+        }
+        processing::concat(&parts, output_format.unwrap_or_default())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -236,13 +345,13 @@ fn build_s2s_multipart(
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path, query_param},
+        matchers::{body_string_contains, header, method, path, query_param},
     };
 
     use crate::{
         ElevenLabsClient,
         config::ClientConfig,
-        types::{OutputFormat, SpeechToSpeechRequest},
+        types::{OutputFormat, SpeechToSpeechRequest, SpeechToSpeechStreamOptions},
     };
 
     // -- convert -----------------------------------------------------------
@@ -370,27 +479,137 @@ mod tests {
         assert_stream(&stream);
     }
 
+    #[tokio::test]
+    async fn convert_stream_with_options_sends_query_and_body_fields() {
+        use futures_util::StreamExt;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/speech-to-speech/voice789/stream"))
+            .and(query_param("output_format", "pcm_16000"))
+            .and(query_param("optimize_streaming_latency", "2"))
+            .and(query_param("enable_logging", "false"))
+            .and(body_string_contains("name=\"seed\"\r\n\r\n7\r\n"))
+            .and(body_string_contains("name=\"remove_background_noise\"\r\n\r\ntrue\r\n"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"pcm-chunks", "audio/pcm"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request = SpeechToSpeechRequest {
+            seed: Some(7),
+            remove_background_noise: true,
+            ..Default::default()
+        };
+        let options = SpeechToSpeechStreamOptions {
+            output_format: Some(OutputFormat::Pcm_16000),
+            optimize_streaming_latency: Some(2),
+            enable_logging: Some(false),
+        };
+        let s2s = client.speech_to_speech();
+        let stream = s2s
+            .convert_stream_with_options(
+                "voice789",
+                &request,
+                b"input-audio",
+                "input.wav",
+                "audio/wav",
+                &options,
+            )
+            .await
+            .unwrap();
+
+        let chunks: Vec<_> = stream.collect().await;
+        let audio: Vec<u8> = chunks.into_iter().flat_map(|c| c.unwrap()).collect();
+        assert_eq!(audio, b"pcm-chunks");
+    }
+
+    // -- convert_long ------------------------------------------------------
+
+    #[cfg(feature = "audio-processing")]
+    #[tokio::test]
+    async fn convert_long_converts_each_chunk_and_joins_them() {
+        use std::time::Duration;
+
+        use crate::audio::{self, processing::PreprocessOptions};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/speech-to-speech/voice123"))
+            .and(query_param("output_format", "pcm_16000"))
+            .and(body_string_contains("pcm_s16le_16"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"ab".to_vec()))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        // 5 s of 8 kHz audio, converted in chunks of at most 2 s.
+        let wav = audio::pcm_to_wav(&vec![0_u8; 8_000 * 2 * 5], 8_000, 1).unwrap();
+        let options = PreprocessOptions {
+            max_chunk: Duration::from_secs(2),
+            search_window: Duration::from_millis(500),
+        };
+        let audio = client
+            .speech_to_speech()
+            .convert_long(
+                "voice123",
+                &SpeechToSpeechRequest::default(),
+                &wav,
+                &options,
+                Some(OutputFormat::Pcm_16000),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(&audio[..], b"ababab");
+    }
+
     // -- build_path --------------------------------------------------------
 
     #[test]
     fn build_path_no_params() {
-        let path = super::SpeechToSpeechService::build_path("v123", "", None);
+        let path = super::SpeechToSpeechService::build_path("v123", "", &Default::default());
         assert_eq!(path, "/v1/speech-to-speech/v123");
     }
 
     #[test]
     fn build_path_with_stream_suffix() {
-        let path = super::SpeechToSpeechService::build_path("v123", "/stream", None);
+        let path = super::SpeechToSpeechService::build_path("v123", "/stream", &Default::default());
         assert_eq!(path, "/v1/speech-to-speech/v123/stream");
     }
 
     #[test]
     fn build_path_with_output_format() {
-        let path =
-            super::SpeechToSpeechService::build_path("v123", "", Some(OutputFormat::Pcm_16000));
+        let options = SpeechToSpeechStreamOptions {
+            output_format: Some(OutputFormat::Pcm_16000),
+            ..Default::default()
+        };
+        let path = super::SpeechToSpeechService::build_path("v123", "", &options);
         assert_eq!(path, "/v1/speech-to-speech/v123?output_format=pcm_16000");
     }
 
+    #[test]
+    fn build_path_with_all_stream_options() {
+        let options = SpeechToSpeechStreamOptions {
+            output_format: Some(OutputFormat::Mp3_22050_32),
+            optimize_streaming_latency: Some(3),
+            enable_logging: Some(false),
+        };
+        let path = super::SpeechToSpeechService::build_path("v123", "/stream", &options);
+        assert_eq!(
+            path,
+            "/v1/speech-to-speech/v123/stream?output_format=mp3_22050_32&optimize_streaming_latency=3&enable_logging=false"
+        );
+    }
+
     // -- multipart helpers -------------------------------------------------
 
     #[test]
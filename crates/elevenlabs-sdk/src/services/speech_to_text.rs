@@ -5,12 +5,20 @@
 //! | Method | Endpoint | Description |
 //! |--------|----------|-------------|
 //! | [`transcribe`](SpeechToTextService::transcribe) | `POST /v1/speech-to-text` | Transcribe audio |
+//! | [`transcribe_async`](SpeechToTextService::transcribe_async) | `POST /v1/speech-to-text` | Submit an asynchronous (webhook) transcription |
 //! | [`get_transcript`](SpeechToTextService::get_transcript) | `GET /v1/speech-to-text/transcripts/{transcription_id}` | Retrieve a transcript |
 //! | [`delete_transcript`](SpeechToTextService::delete_transcript) | `DELETE /v1/speech-to-text/transcripts/{transcription_id}` | Delete a transcript |
+//! | [`wait_for_transcript`](SpeechToTextService::wait_for_transcript) | `GET /v1/speech-to-text/transcripts/{transcription_id}` | Poll until a transcript is ready |
 //!
 //! The transcription endpoint accepts `multipart/form-data` with an audio
 //! file (or a `cloud_storage_url`) and configuration fields.
 //!
+//! Long recordings can be transcribed asynchronously:
+//! [`transcribe_async`](SpeechToTextService::transcribe_async) returns a
+//! `transcription_id` right away, and the transcript is posted to the
+//! workspace webhook once ready. Without a webhook receiver, poll for it
+//! with [`wait_for_transcript`](SpeechToTextService::wait_for_transcript).
+//!
 //! # Example
 //!
 //! ```no_run
@@ -33,8 +41,11 @@
 
 use crate::{
     client::ElevenLabsClient,
-    error::Result,
-    types::{SpeechToTextChunkResponse, SpeechToTextRequest},
+    error::{ElevenLabsError, Result},
+    types::{
+        SpeechToTextChunkResponse, SpeechToTextRequest, SpeechToTextWebhookResponse,
+        TranscriptWaitOptions,
+    },
 };
 
 /// Speech-to-text service providing typed access to STT endpoints.
@@ -79,6 +90,39 @@ impl<'a> SpeechToTextService<'a> {
         self.client.post_multipart("/v1/speech-to-text", body, &content_type).await
     }
 
+    /// Submits audio for asynchronous transcription.
+    ///
+    /// Calls `POST /v1/speech-to-text` with `webhook` set, whatever
+    /// `request.webhook` says. The call returns once the audio is accepted;
+    /// the transcript is sent to the webhook and can be fetched with
+    /// [`get_transcript`](Self::get_transcript) or
+    /// [`wait_for_transcript`](Self::wait_for_transcript) using the returned
+    /// `transcription_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the acknowledgement has no
+    /// `transcription_id`, or an error if the API request fails.
+    pub async fn transcribe_async(
+        &self,
+        request: &SpeechToTextRequest,
+        audio_file: Option<(&[u8], &str, &str)>,
+    ) -> Result<SpeechToTextWebhookResponse> {
+        let request = SpeechToTextRequest { webhook: true, ..request.clone() };
+        let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
+        let body = build_stt_multipart(&boundary, &request, audio_file);
+        let content_type = format!("multipart/form-data; boundary={boundary}");
+        let ack: SpeechToTextWebhookResponse =
+            self.client.post_multipart("/v1/speech-to-text", body, &content_type).await?;
+        if ack.transcription_id.is_none() {
+            return Err(ElevenLabsError::Validation(format!(
+                "transcription request {} was accepted without a transcription_id",
+                ack.request_id
+            )));
+        }
+        Ok(ack)
+    }
+
     /// Retrieves a previously created transcript.
     ///
     /// Calls `GET /v1/speech-to-text/transcripts/{transcription_id}`.
@@ -114,6 +158,47 @@ impl<'a> SpeechToTextService<'a> {
         let path = format!("/v1/speech-to-text/transcripts/{transcription_id}");
         self.client.delete(&path).await
     }
+
+    /// Waits for the transcript of an asynchronous transcription with the
+    /// default [`TranscriptWaitOptions`].
+    ///
+    /// # Errors
+    ///
+    /// See [`wait_for_transcript_with`](Self::wait_for_transcript_with).
+    pub async fn wait_for_transcript(
+        &self,
+        transcription_id: &str,
+    ) -> Result<SpeechToTextChunkResponse> {
+        self.wait_for_transcript_with(transcription_id, &TranscriptWaitOptions::default()).await
+    }
+
+    /// Polls [`get_transcript`](Self::get_transcript) until the transcript
+    /// of an asynchronous transcription is ready.
+    ///
+    /// A `404 Not Found` is taken to mean the transcript is still being
+    /// produced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Timeout`] if `options.timeout` elapses, or
+    /// an error if a poll fails otherwise.
+    pub async fn wait_for_transcript_with(
+        &self,
+        transcription_id: &str,
+        options: &TranscriptWaitOptions,
+    ) -> Result<SpeechToTextChunkResponse> {
+        let deadline = options.timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+        loop {
+            match self.get_transcript(transcription_id).await {
+                Err(ElevenLabsError::Api { status: 404, .. }) => {}
+                result => return result,
+            }
+            if deadline.is_some_and(|deadline| tokio::time::Instant::now() >= deadline) {
+                return Err(ElevenLabsError::Timeout);
+            }
+            tokio::time::sleep(options.poll_interval).await;
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -300,10 +385,14 @@ fn build_stt_multipart(
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path},
+        matchers::{body_string_contains, header, method, path},
     };
 
-    use crate::{ElevenLabsClient, config::ClientConfig, types::SpeechToTextRequest};
+    use crate::{
+        ElevenLabsClient,
+        config::ClientConfig,
+        types::{SpeechToTextRequest, TranscriptWaitOptions},
+    };
 
     // -- transcribe --------------------------------------------------------
 
@@ -427,6 +516,76 @@ mod tests {
         assert_eq!(result.words[2].speaker_id.as_deref(), Some("speaker_1"));
     }
 
+    // -- transcribe_async --------------------------------------------------
+
+    #[tokio::test]
+    async fn transcribe_async_sets_webhook_and_returns_id() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/speech-to-text"))
+            .and(body_string_contains("name=\"webhook\"\r\n\r\ntrue"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "message": "Request accepted",
+                "request_id": "req_1",
+                "transcription_id": "tx_async1"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let ack = client
+            .speech_to_text()
+            .transcribe_async(
+                &SpeechToTextRequest::default(),
+                Some((b"fake-audio", "audio.mp3", "audio/mpeg")),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ack.transcription_id.as_deref(), Some("tx_async1"));
+    }
+
+    #[tokio::test]
+    async fn wait_for_transcript_polls_until_ready() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/speech-to-text/transcripts/tx_async1"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({"detail": "Transcript not found"})),
+            )
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/speech-to-text/transcripts/tx_async1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "language_code": "eng",
+                "language_probability": 0.97,
+                "text": "Done.",
+                "words": [],
+                "transcription_id": "tx_async1"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let options = TranscriptWaitOptions {
+            poll_interval: std::time::Duration::from_millis(10),
+            ..TranscriptWaitOptions::default()
+        };
+        let transcript =
+            client.speech_to_text().wait_for_transcript_with("tx_async1", &options).await.unwrap();
+
+        assert_eq!(transcript.text, "Done.");
+    }
+
     // -- get_transcript ----------------------------------------------------
 
     #[tokio::test]
@@ -508,7 +667,7 @@ mod tests {
         assert!(body_str.contains("diarize"));
         assert!(body_str.contains("true"));
         assert!(body_str.contains("num_speakers"));
-        assert!(body_str.contains("2"));
+        assert!(body_str.contains('2'));
         assert!(body_str.contains("--test-boundary--"));
     }
 
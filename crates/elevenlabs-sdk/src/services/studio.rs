@@ -18,6 +18,7 @@
 //! | [`get_project_snapshot`](StudioService::get_project_snapshot) | `GET /v1/studio/projects/{id}/snapshots/{snap_id}` | Get project snapshot |
 //! | [`stream_project_snapshot_audio`](StudioService::stream_project_snapshot_audio) | `POST /v1/studio/projects/{id}/snapshots/{snap_id}/stream` | Stream snapshot audio |
 //! | [`stream_project_snapshot_archive`](StudioService::stream_project_snapshot_archive) | `POST /v1/studio/projects/{id}/snapshots/{snap_id}/archive` | Stream snapshot archive |
+//! | [`download_snapshot`](StudioService::download_snapshot) | `POST /v1/studio/projects/{id}/snapshots/{snap_id}/stream` | Download snapshot audio, optionally per chapter |
 //! | [`get_project_muted_tracks`](StudioService::get_project_muted_tracks) | `GET /v1/studio/projects/{id}/muted-tracks` | Get muted tracks |
 //! | [`get_chapters`](StudioService::get_chapters) | `GET /v1/studio/projects/{id}/chapters` | List chapters |
 //! | [`get_chapter`](StudioService::get_chapter) | `GET /v1/studio/projects/{id}/chapters/{ch_id}` | Get chapter |
@@ -32,6 +33,7 @@
 //! | [`get_pronunciation_dictionaries`](StudioService::get_pronunciation_dictionaries) | `GET /v1/pronunciation-dictionaries` | List dictionaries |
 //! | [`get_pronunciation_dictionary`](StudioService::get_pronunciation_dictionary) | `GET /v1/pronunciation-dictionaries/{id}` | Get dictionary |
 //! | [`download_pronunciation_dictionary_version`](StudioService::download_pronunciation_dictionary_version) | `GET /v1/pronunciation-dictionaries/{id}/{ver}/download` | Download version PLS |
+//! | [`get_pronunciation_dictionary_version_rules`](StudioService::get_pronunciation_dictionary_version_rules) | `GET /v1/pronunciation-dictionaries/{id}/{ver}/download` | Download and parse version rules |
 //! | [`create_pronunciation_dictionary_from_file`](StudioService::create_pronunciation_dictionary_from_file) | `POST /v1/pronunciation-dictionaries/add-from-file` | Create from file (multipart) |
 //! | [`create_pronunciation_dictionary_from_rules`](StudioService::create_pronunciation_dictionary_from_rules) | `POST /v1/pronunciation-dictionaries/add-from-rules` | Create from rules |
 //! | [`add_pronunciation_rules`](StudioService::add_pronunciation_rules) | `POST /v1/pronunciation-dictionaries/{id}/add-rules` | Add rules |
@@ -53,8 +55,11 @@
 //! # }
 //! ```
 
+use std::path::Path;
+
 use bytes::Bytes;
 use futures_core::Stream;
+use hpx::Method;
 use serde::Serialize;
 
 use crate::types::{
@@ -63,6 +68,7 @@ use crate::types::{
     // Pronunciation
     AddPronunciationDictionaryResponse,
     AddPronunciationRulesRequest,
+    ChapterContentInput,
     ChapterSnapshotExtendedResponse,
     ChapterSnapshotsResponse,
     ChapterWithContentResponse,
@@ -75,7 +81,9 @@ use crate::types::{
     GetChaptersResponse,
     GetProjectsResponse,
     GetPronunciationDictionariesResponse,
+    PodcastMode,
     PodcastProjectResponse,
+    PodcastSource,
     ProjectExtendedResponse,
     ProjectMutedTracksResponse,
     ProjectSnapshotExtendedResponse,
@@ -83,10 +91,17 @@ use crate::types::{
     PronunciationDictionaryLocatorRequest,
     PronunciationDictionaryMetadata,
     PronunciationDictionaryRulesResponse,
+    PronunciationRule,
     RemovePronunciationRulesRequest,
+    SplitMode,
     UpdatePronunciationDictionaryRequest,
 };
-use crate::{client::ElevenLabsClient, error::Result};
+use crate::{
+    client::ElevenLabsClient,
+    download::{DownloadOptions, DownloadSummary},
+    error::{ElevenLabsError, Result},
+    pls::Lexicon,
+};
 
 /// Studio service providing typed access to project, chapter, snapshot,
 /// podcast, and pronunciation dictionary endpoints.
@@ -358,6 +373,122 @@ impl<'a> StudioService<'a> {
         self.client.post_stream(&path, &serde_json::Value::Null).await
     }
 
+    /// Downloads the archive (zip) for a project snapshot to `dest`.
+    ///
+    /// Like [`stream_project_snapshot_archive`](Self::stream_project_snapshot_archive),
+    /// but writes the stream to disk; see
+    /// [`ElevenLabsClient::download_to_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the file cannot be
+    /// written, or the checksum does not match.
+    pub async fn download_project_snapshot_archive(
+        &self,
+        project_id: &str,
+        snapshot_id: &str,
+        dest: impl AsRef<Path>,
+        options: DownloadOptions<'_>,
+    ) -> Result<DownloadSummary> {
+        let path = format!("/v1/studio/projects/{project_id}/snapshots/{snapshot_id}/archive");
+        self.client
+            .download(Method::POST, &path, Some(serde_json::Value::Null), dest.as_ref(), &options)
+            .await
+    }
+
+    /// Downloads a project snapshot to disk as one MP3, its zip archive, or
+    /// one MP3 per chapter, depending on `mode`.
+    ///
+    /// With [`SplitMode::Chapters`], `dest` is a directory (created if
+    /// missing). Each chapter's audio comes from its latest snapshot taken
+    /// no later than the project snapshot, and chapters listed by
+    /// [`get_project_muted_tracks`](Self::get_project_muted_tracks) are
+    /// skipped. Otherwise `dest` is the file to write.
+    ///
+    /// Returns one summary per file written, in project order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if a chapter has no snapshot
+    /// from before the project snapshot, or an error if a request fails or a
+    /// file cannot be written. Files already written are kept.
+    pub async fn download_snapshot(
+        &self,
+        project_id: &str,
+        snapshot_id: &str,
+        dest: impl AsRef<Path>,
+        mode: SplitMode,
+    ) -> Result<Vec<DownloadSummary>> {
+        let dest = dest.as_ref();
+        match mode {
+            SplitMode::Single => {
+                let path =
+                    format!("/v1/studio/projects/{project_id}/snapshots/{snapshot_id}/stream");
+                let summary = self.download_mpeg(&path, dest).await?;
+                Ok(vec![summary])
+            }
+            SplitMode::Archive => {
+                let summary = self
+                    .download_project_snapshot_archive(
+                        project_id,
+                        snapshot_id,
+                        dest,
+                        DownloadOptions::new(),
+                    )
+                    .await?;
+                Ok(vec![summary])
+            }
+            SplitMode::Chapters => self.download_chapters(project_id, snapshot_id, dest).await,
+        }
+    }
+
+    /// Writes one MP3 per unmuted chapter of a project snapshot into `dir`.
+    async fn download_chapters(
+        &self,
+        project_id: &str,
+        snapshot_id: &str,
+        dir: &Path,
+    ) -> Result<Vec<DownloadSummary>> {
+        let taken_at = self.get_project_snapshot(project_id, snapshot_id).await?.created_at_unix;
+        let chapters = self.get_chapters(project_id).await?.chapters;
+        let muted = self.get_project_muted_tracks(project_id).await?.chapter_ids;
+        tokio::fs::create_dir_all(dir).await?;
+
+        let mut summaries = Vec::new();
+        for (position, chapter) in chapters.iter().enumerate() {
+            if muted.contains(&chapter.chapter_id) {
+                continue;
+            }
+            let chapter_id = &chapter.chapter_id;
+            let snapshot = self
+                .get_chapter_snapshots(project_id, chapter_id)
+                .await?
+                .snapshots
+                .into_iter()
+                .filter(|snapshot| snapshot.created_at_unix <= taken_at)
+                .max_by_key(|snapshot| snapshot.created_at_unix)
+                .ok_or_else(|| {
+                    ElevenLabsError::Validation(format!(
+                        "chapter {chapter_id} has no snapshot from before project snapshot \
+                         {snapshot_id}"
+                    ))
+                })?;
+            let path = format!(
+                "/v1/studio/projects/{project_id}/chapters/{chapter_id}/snapshots/{}/stream",
+                snapshot.chapter_snapshot_id
+            );
+            let file_name = chapter_file_name(position + 1, &chapter.name, chapter_id);
+            summaries.push(self.download_mpeg(&path, &dir.join(file_name)).await?);
+        }
+        Ok(summaries)
+    }
+
+    /// Downloads snapshot audio from `path`, converted to MPEG, to `dest`.
+    async fn download_mpeg(&self, path: &str, dest: &Path) -> Result<DownloadSummary> {
+        let body = serde_json::json!({ "convert_to_mpeg": true });
+        self.client.download(Method::POST, path, Some(body), dest, &DownloadOptions::new()).await
+    }
+
     // =======================================================================
     // Muted tracks
     // =======================================================================
@@ -608,11 +739,8 @@ impl<'a> StudioService<'a> {
     ///
     /// Calls `POST /v1/studio/podcasts` with a JSON body.
     ///
-    /// The `mode` and `source` fields are polymorphic — use
-    /// [`PodcastConversationMode`](crate::types::PodcastConversationMode) /
-    /// [`PodcastBulletinMode`](crate::types::PodcastBulletinMode) and
-    /// [`PodcastTextSource`](crate::types::PodcastTextSource) /
-    /// [`PodcastUrlSource`](crate::types::PodcastUrlSource).
+    /// See [`PodcastMode`] and [`PodcastSource`] for the podcast formats and
+    /// the content it can be generated from.
     ///
     /// # Arguments
     ///
@@ -709,6 +837,24 @@ impl<'a> StudioService<'a> {
         self.client.get_bytes(&path).await
     }
 
+    /// Downloads a pronunciation dictionary version and parses its rules.
+    ///
+    /// Calls `GET /v1/pronunciation-dictionaries/{dictionary_id}/{version_id}/download`
+    /// and reads the PLS file with [`Lexicon::parse`](crate::pls::Lexicon::parse).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the PLS file cannot be
+    /// parsed.
+    pub async fn get_pronunciation_dictionary_version_rules(
+        &self,
+        dictionary_id: &str,
+        version_id: &str,
+    ) -> Result<Vec<PronunciationRule>> {
+        let pls = self.download_pronunciation_dictionary_version(dictionary_id, version_id).await?;
+        Ok(Lexicon::parse(&String::from_utf8_lossy(&pls))?.rules)
+    }
+
     /// Creates a pronunciation dictionary from an uploaded PLS/CSV file.
     ///
     /// Calls `POST /v1/pronunciation-dictionaries/add-from-file` with
@@ -749,12 +895,14 @@ impl<'a> StudioService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be
-    /// deserialized.
+    /// Returns [`ElevenLabsError::Validation`] if there are no rules or a
+    /// rule fails [`PronunciationRule::validate`], or an error if the API
+    /// request fails or the response cannot be deserialized.
     pub async fn create_pronunciation_dictionary_from_rules(
         &self,
         request: &CreatePronunciationDictionaryFromRulesRequest,
     ) -> Result<AddPronunciationDictionaryResponse> {
+        validate_rules(&request.rules)?;
         self.client.post("/v1/pronunciation-dictionaries/add-from-rules", request).await
     }
 
@@ -770,13 +918,15 @@ impl<'a> StudioService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be
-    /// deserialized.
+    /// Returns [`ElevenLabsError::Validation`] if there are no rules or a
+    /// rule fails [`PronunciationRule::validate`], or an error if the API
+    /// request fails or the response cannot be deserialized.
     pub async fn add_pronunciation_rules(
         &self,
         dictionary_id: &str,
         request: &AddPronunciationRulesRequest,
     ) -> Result<PronunciationDictionaryRulesResponse> {
+        validate_rules(&request.rules)?;
         let path = format!("/v1/pronunciation-dictionaries/{dictionary_id}/add-rules");
         self.client.post(&path, request).await
     }
@@ -793,13 +943,20 @@ impl<'a> StudioService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be
-    /// deserialized.
+    /// Returns [`ElevenLabsError::Validation`] if there are no rule strings
+    /// or one is blank, or an error if the API request fails or the
+    /// response cannot be deserialized.
     pub async fn remove_pronunciation_rules(
         &self,
         dictionary_id: &str,
         request: &RemovePronunciationRulesRequest,
     ) -> Result<PronunciationDictionaryRulesResponse> {
+        if request.rule_strings.is_empty() {
+            return Err(ElevenLabsError::Validation("no rule strings to remove".to_owned()));
+        }
+        if request.rule_strings.iter().any(|s| s.trim().is_empty()) {
+            return Err(ElevenLabsError::Validation("rule strings must not be blank".to_owned()));
+        }
         let path = format!("/v1/pronunciation-dictionaries/{dictionary_id}/remove-rules");
         self.client.post(&path, request).await
     }
@@ -941,9 +1098,9 @@ pub struct EditChapterRequest {
     /// Chapter name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    /// Chapter content. See [`ChapterContentInput`](crate::types::ChapterContentInput).
+    /// Chapter content.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<serde_json::Value>,
+    pub content: Option<ChapterContentInput>,
 }
 
 /// Request body for streaming a snapshot's audio.
@@ -959,19 +1116,32 @@ struct SnapshotStreamRequest {
 /// Request body for creating a podcast.
 ///
 /// Used with [`StudioService::create_podcast`]. Sent as JSON.
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[expect(clippy::derive_partial_eq_without_eq, reason = "serde_json::Value does not implement Eq")]
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::{
+///     services::studio::CreatePodcastRequest,
+///     types::{PodcastMode, PodcastSource},
+/// };
+///
+/// let request = CreatePodcastRequest::new(
+///     "eleven_multilingual_v2",
+///     PodcastMode::bulletin("host_voice"),
+///     PodcastSource::Url(vec!["https://example.com/article".into()]),
+/// )
+/// .highlights(["Rust 2024 ships", "Async closures land"])
+/// .intro("Welcome to the weekly digest.");
+/// assert_eq!(request.highlights.as_ref().map(Vec::len), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct CreatePodcastRequest {
     /// Model ID (required).
     pub model_id: String,
-    /// Podcast mode configuration (required). Use
-    /// [`PodcastConversationMode`](crate::types::PodcastConversationMode) or
-    /// [`PodcastBulletinMode`](crate::types::PodcastBulletinMode).
-    pub mode: serde_json::Value,
-    /// Podcast source (required). Use
-    /// [`PodcastTextSource`](crate::types::PodcastTextSource) or
-    /// [`PodcastUrlSource`](crate::types::PodcastUrlSource).
-    pub source: serde_json::Value,
+    /// Podcast format and voices (required).
+    pub mode: PodcastMode,
+    /// Content to generate the podcast from (required).
+    pub source: PodcastSource,
     /// Quality preset.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quality_preset: Option<String>,
@@ -981,6 +1151,54 @@ pub struct CreatePodcastRequest {
     /// Language code.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
+    /// Key points the podcast should cover.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlights: Option<Vec<String>>,
+    /// Text the podcast opens with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intro: Option<String>,
+    /// Text the podcast closes with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outro: Option<String>,
+}
+
+impl CreatePodcastRequest {
+    /// Creates a request with the required fields.
+    pub fn new(model_id: impl Into<String>, mode: PodcastMode, source: PodcastSource) -> Self {
+        Self {
+            model_id: model_id.into(),
+            mode,
+            source,
+            quality_preset: None,
+            duration_scale: None,
+            language: None,
+            highlights: None,
+            intro: None,
+            outro: None,
+        }
+    }
+
+    /// Sets the key points the podcast should cover.
+    pub fn highlights<I, S>(mut self, highlights: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.highlights = Some(highlights.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the text the podcast opens with.
+    pub fn intro(mut self, intro: impl Into<String>) -> Self {
+        self.intro = Some(intro.into());
+        self
+    }
+
+    /// Sets the text the podcast closes with.
+    pub fn outro(mut self, outro: impl Into<String>) -> Self {
+        self.outro = Some(outro.into());
+        self
+    }
 }
 
 /// Request body for attaching pronunciation dictionaries to a project.
@@ -1002,13 +1220,21 @@ pub struct UpdateProjectPronunciationDictionariesRequest {
 pub struct CreatePronunciationDictionaryFromRulesRequest {
     /// Dictionary name (required).
     pub name: String,
-    /// Rules to add (can be alias or phoneme rules, serialized as JSON).
-    pub rules: Vec<serde_json::Value>,
+    /// Rules to add.
+    pub rules: Vec<PronunciationRule>,
     /// Optional description.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
 
+/// Checks rules before they are sent to the API.
+fn validate_rules(rules: &[PronunciationRule]) -> Result<()> {
+    if rules.is_empty() {
+        return Err(ElevenLabsError::Validation("no pronunciation rules given".to_owned()));
+    }
+    rules.iter().try_for_each(PronunciationRule::validate)
+}
+
 // ===========================================================================
 // Multipart helpers
 // ===========================================================================
@@ -1111,6 +1337,19 @@ fn build_add_project_multipart(
     buf
 }
 
+/// File name for the chapter at 1-based `position`, with characters that
+/// are not safe in file names replaced by `_`.
+fn chapter_file_name(position: usize, name: &str, chapter_id: &str) -> String {
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.') { c } else { '_' })
+        .collect();
+    let name = name.trim_matches('.');
+    let name = if name.is_empty() { chapter_id } else { name };
+    format!("{position:02} - {name}.mp3")
+}
+
 /// Builds a multipart body for `POST /v1/studio/projects/{id}/content`.
 fn build_edit_content_multipart(
     boundary: &str,
@@ -1175,8 +1414,8 @@ mod tests {
         ElevenLabsClient,
         config::ClientConfig,
         types::{
-            AddPronunciationRulesRequest, PronunciationDictionaryLocatorRequest,
-            RemovePronunciationRulesRequest, UpdatePronunciationDictionaryRequest,
+            AddPronunciationRulesRequest, BlockSubType, ChapterBlock, ChapterBlockSubNode,
+            RemovePronunciationRulesRequest,
         },
     };
 
@@ -1199,7 +1438,7 @@ mod tests {
                 "projects": [{
                     "project_id": "proj_1",
                     "name": "My Project",
-                    "create_date_unix": 1714204800,
+                    "create_date_unix": 1_714_204_800,
                     "created_by_user_id": null,
                     "default_title_voice_id": "v1",
                     "default_paragraph_voice_id": "v2",
@@ -1299,6 +1538,66 @@ mod tests {
         assert_eq!(result.chapter.chapter_id, "ch_new");
     }
 
+    // -- edit_chapter ------------------------------------------------------
+
+    #[tokio::test]
+    async fn edit_chapter_sends_typed_content() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/studio/projects/proj_1/chapters/ch_1"))
+            .and(body_json(serde_json::json!({
+                "content": {
+                    "blocks": [
+                        {
+                            "sub_type": "h1",
+                            "nodes": [{"type": "tts_node", "voice_id": "narrator", "text": "One"}]
+                        },
+                        {
+                            "sub_type": "p",
+                            "nodes": [{"type": "tts_node", "voice_id": "alice", "text": "Hi."}]
+                        }
+                    ]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "chapter": {
+                    "chapter_id": "ch_1",
+                    "name": "Chapter 1",
+                    "state": "default",
+                    "content": {
+                        "blocks": [
+                            {
+                                "block_id": "b1",
+                                "nodes": [{"type": "tts_node", "voice_id": "narrator", "text": "One"}]
+                            },
+                            {
+                                "block_id": "b2",
+                                "nodes": [{"type": "tts_node", "voice_id": "alice", "text": "Hi."}]
+                            }
+                        ]
+                    }
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let req = EditChapterRequest {
+            name: None,
+            content: Some(
+                ChapterContentInput::new()
+                    .block(ChapterBlock::heading(BlockSubType::H1, "narrator", "One"))
+                    .block(ChapterBlock::paragraph("alice", "Hi.")),
+            ),
+        };
+        let result = client.studio().edit_chapter("proj_1", "ch_1", &req).await.unwrap();
+        let blocks = &result.chapter.content.blocks;
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].block_id.as_deref(), Some("b2"));
+        assert_eq!(blocks[1].nodes, vec![ChapterBlockSubNode::tts("alice", "Hi.")]);
+    }
+
     // -- delete_chapter ----------------------------------------------------
 
     #[tokio::test]
@@ -1332,7 +1631,7 @@ mod tests {
                 "snapshots": [{
                     "project_snapshot_id": "snap_1",
                     "project_id": "proj_1",
-                    "created_at_unix": 1714204800,
+                    "created_at_unix": 1_714_204_800,
                     "name": "Snapshot 1"
                 }]
             })))
@@ -1365,6 +1664,95 @@ mod tests {
         assert_eq!(result.chapter_ids, vec!["ch_1", "ch_2"]);
     }
 
+    // -- download_snapshot -------------------------------------------------
+
+    #[tokio::test]
+    async fn download_snapshot_splits_unmuted_chapters() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/studio/projects/proj_1/snapshots/snap_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "project_snapshot_id": "snap_1",
+                "project_id": "proj_1",
+                "created_at_unix": 2000
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/studio/projects/proj_1/chapters"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "chapters": [
+                    {"chapter_id": "ch_1", "name": "Intro: Part/1", "state": "default"},
+                    {"chapter_id": "ch_2", "name": "Outtakes", "state": "default"},
+                    {"chapter_id": "ch_3", "name": "", "state": "default"}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/studio/projects/proj_1/muted-tracks"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "chapter_ids": ["ch_2"] })),
+            )
+            .mount(&mock_server)
+            .await;
+        for (chapter, snapshots) in [
+            ("ch_1", serde_json::json!([["cs_old", 1000], ["cs_1", 1900], ["cs_new", 2100]])),
+            ("ch_3", serde_json::json!([["cs_3", 1500]])),
+        ] {
+            let snapshots: Vec<serde_json::Value> = snapshots
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|s| {
+                    serde_json::json!({
+                        "chapter_snapshot_id": s[0],
+                        "project_id": "proj_1",
+                        "chapter_id": chapter,
+                        "created_at_unix": s[1]
+                    })
+                })
+                .collect();
+            Mock::given(method("GET"))
+                .and(path(format!("/v1/studio/projects/proj_1/chapters/{chapter}/snapshots")))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(serde_json::json!({ "snapshots": snapshots })),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+        for (chapter, snapshot) in [("ch_1", "cs_1"), ("ch_3", "cs_3")] {
+            Mock::given(method("POST"))
+                .and(path(format!(
+                    "/v1/studio/projects/proj_1/chapters/{chapter}/snapshots/{snapshot}/stream"
+                )))
+                .and(body_json(serde_json::json!({ "convert_to_mpeg": true })))
+                .respond_with(ResponseTemplate::new(200).set_body_bytes(snapshot.as_bytes()))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let dir =
+            std::env::temp_dir().join(format!("elevenlabs-studio-chapters-{}", std::process::id()));
+        let client = test_client(&mock_server.uri());
+        let summaries = client
+            .studio()
+            .download_snapshot("proj_1", "snap_1", &dir, SplitMode::Chapters)
+            .await
+            .unwrap();
+
+        let names: Vec<_> =
+            summaries.iter().map(|s| s.path.file_name().unwrap().to_owned()).collect();
+        assert_eq!(names, ["01 - Intro_ Part_1.mp3", "03 - ch_3.mp3"]);
+        assert_eq!(std::fs::read(&summaries[0].path).unwrap(), b"cs_1");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     // -- create_podcast ----------------------------------------------------
 
     #[tokio::test]
@@ -1374,6 +1762,15 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/studio/podcasts"))
             .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({
+                "model_id": "eleven_multilingual_v2",
+                "mode": {
+                    "type": "conversation",
+                    "conversation": {"host_voice_id": "host_v", "guest_voice_id": "guest_v"}
+                },
+                "source": {"type": "text", "text": "Hello podcast world"},
+                "highlights": ["Big news"]
+            })))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
                 "project": {
                     "project_id": "pod_1",
@@ -1395,23 +1792,12 @@ mod tests {
             .await;
 
         let client = test_client(&mock_server.uri());
-        let req = CreatePodcastRequest {
-            model_id: "eleven_multilingual_v2".into(),
-            mode: serde_json::json!({
-                "type": "conversation",
-                "conversation": {
-                    "host_voice_id": "host_v",
-                    "guest_voice_id": "guest_v"
-                }
-            }),
-            source: serde_json::json!({
-                "type": "text",
-                "text": "Hello podcast world"
-            }),
-            quality_preset: None,
-            duration_scale: None,
-            language: None,
-        };
+        let req = CreatePodcastRequest::new(
+            "eleven_multilingual_v2",
+            PodcastMode::conversation("host_v", "guest_v"),
+            PodcastSource::Text("Hello podcast world".into()),
+        )
+        .highlights(["Big news"]);
         let result = client.studio().create_podcast(&req).await.unwrap();
         assert_eq!(result.project.project_id, "pod_1");
     }
@@ -1433,7 +1819,7 @@ mod tests {
                     "name": "Dict One",
                     "permission_on_resource": "admin",
                     "created_by": "user1",
-                    "creation_time_unix": 1700000000
+                    "creation_time_unix": 1_700_000_000
                 }],
                 "has_more": false,
                 "next_cursor": null
@@ -1466,17 +1852,62 @@ mod tests {
 
         let client = test_client(&mock_server.uri());
         let req = AddPronunciationRulesRequest {
-            rules: vec![serde_json::json!({
-                "type": "alias",
-                "string_to_replace": "ElevenLabs",
-                "alias": "Eleven Labs"
-            })],
+            rules: vec![PronunciationRule::alias("ElevenLabs", "Eleven Labs")],
         };
         let result = client.studio().add_pronunciation_rules("dict1", &req).await.unwrap();
         assert_eq!(result.version_rules_num, 7);
         assert_eq!(result.version_id, "v2");
     }
 
+    #[tokio::test]
+    async fn add_pronunciation_rules_rejects_invalid_rules_locally() {
+        let mock_server = MockServer::start().await;
+        let client = test_client(&mock_server.uri());
+
+        let req = AddPronunciationRulesRequest {
+            rules: vec![PronunciationRule::phoneme(
+                "tomato",
+                "təˈmeɪtoʊ",
+                crate::types::PhonemeAlphabet::CmuArpabet,
+            )],
+        };
+        let err = client.studio().add_pronunciation_rules("dict1", &req).await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+        let req = RemovePronunciationRulesRequest { rule_strings: Vec::new() };
+        let err = client.studio().remove_pronunciation_rules("dict1", &req).await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    // -- get_pronunciation_dictionary_version_rules --------------------------
+
+    #[tokio::test]
+    async fn get_pronunciation_dictionary_version_rules_parses_pls() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/pronunciation-dictionaries/dict1/v2/download"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<lexicon version="1.0" xmlns="http://www.w3.org/2005/01/pronunciation-lexicon" alphabet="ipa" xml:lang="en-US">
+  <lexeme>
+    <grapheme>UN</grapheme>
+    <alias>United Nations</alias>
+  </lexeme>
+</lexicon>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let rules = client
+            .studio()
+            .get_pronunciation_dictionary_version_rules("dict1", "v2")
+            .await
+            .unwrap();
+        assert_eq!(rules, vec![PronunciationRule::alias("UN", "United Nations")]);
+    }
+
     // -- convert_project ---------------------------------------------------
 
     #[tokio::test]
@@ -11,6 +11,9 @@
 //! | [`convert_with_timestamps`](TextToDialogueService::convert_with_timestamps) | `POST /v1/text-to-dialogue/with-timestamps` | JSON with audio + alignment + voice segments |
 //! | [`convert_stream_with_timestamps`](TextToDialogueService::convert_stream_with_timestamps) | `POST /v1/text-to-dialogue/stream/with-timestamps` | Streaming JSON chunks with timestamps |
 //!
+//! Scripts with named speakers can be turned into a request with
+//! [`TextToDialogueRequest::from_script`](crate::types::TextToDialogueRequest::from_script).
+//!
 //! # Example
 //!
 //! ```no_run
@@ -23,13 +26,11 @@
 //! let config = ClientConfig::builder("your-api-key").build();
 //! let client = ElevenLabsClient::new(config)?;
 //!
-//! let request = TextToDialogueRequest {
-//!     inputs: vec![
-//!         DialogueInput { text: "Hello!".into(), voice_id: "voice1".into() },
-//!         DialogueInput { text: "Hi there!".into(), voice_id: "voice2".into() },
-//!     ],
-//!     ..Default::default()
-//! };
+//! let request = TextToDialogueRequest::new(vec![
+//!     DialogueInput::new("Hello!", "voice1"),
+//!     DialogueInput::new("Hi there!", "voice2"),
+//! ])
+//! .model_id("eleven_v3");
 //! let audio = client.text_to_dialogue().convert(&request).await?;
 //!
 //! println!("Received {} bytes of dialogue audio", audio.len());
@@ -155,7 +156,7 @@ impl<'a> TextToDialogueService<'a> {
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path},
+        matchers::{body_json, header, method, path},
     };
 
     use crate::{
@@ -165,13 +166,10 @@ mod tests {
     };
 
     fn sample_request() -> TextToDialogueRequest {
-        TextToDialogueRequest {
-            inputs: vec![
-                DialogueInput { text: "Hello!".into(), voice_id: "voice1".into() },
-                DialogueInput { text: "Hi there!".into(), voice_id: "voice2".into() },
-            ],
-            ..Default::default()
-        }
+        TextToDialogueRequest::new(vec![
+            DialogueInput::new("Hello!", "voice1"),
+            DialogueInput::new("Hi there!", "voice2"),
+        ])
     }
 
     // -- convert -----------------------------------------------------------
@@ -226,6 +224,49 @@ mod tests {
         assert_stream(&stream);
     }
 
+    #[tokio::test]
+    async fn convert_stream_sends_script_request() {
+        use std::collections::HashMap;
+
+        use futures_util::TryStreamExt;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-dialogue/stream"))
+            .and(body_json(serde_json::json!({
+                "inputs": [
+                    {"text": "Knock knock.", "voice_id": "voice1"},
+                    {"text": "Who's there?", "voice_id": "voice2"}
+                ],
+                "model_id": "eleven_v3",
+                "seed": 12345
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(&b"audio"[..], "audio/mpeg"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let voices = HashMap::from([
+            ("ALICE".to_owned(), "voice1".to_owned()),
+            ("BOB".to_owned(), "voice2".to_owned()),
+        ]);
+        let request = TextToDialogueRequest::from_script(
+            [("ALICE", "Knock knock."), ("BOB", "Who's there?")],
+            &voices,
+        )
+        .unwrap()
+        .model_id("eleven_v3")
+        .seed(12345);
+        let svc = client.text_to_dialogue();
+        let stream = svc.convert_stream(&request).await.unwrap();
+        let chunks: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), b"audio");
+    }
+
     // -- convert_with_timestamps -------------------------------------------
 
     #[tokio::test]
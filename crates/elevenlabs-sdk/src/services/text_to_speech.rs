@@ -5,9 +5,17 @@
 //! | Method | Endpoint | Description |
 //! |--------|----------|-------------|
 //! | [`convert`](TextToSpeechService::convert) | `POST /v1/text-to-speech/{voice_id}` | Full audio bytes |
+//! | [`convert_with_metadata`](TextToSpeechService::convert_with_metadata) | `POST /v1/text-to-speech/{voice_id}` | Full audio bytes with request and history IDs |
 //! | [`convert_with_timestamps`](TextToSpeechService::convert_with_timestamps) | `POST /v1/text-to-speech/{voice_id}/with-timestamps` | JSON with audio + alignment |
 //! | [`convert_stream`](TextToSpeechService::convert_stream) | `POST /v1/text-to-speech/{voice_id}/stream` | Streaming audio bytes |
-//! | [`convert_stream_with_timestamps`](TextToSpeechService::convert_stream_with_timestamps) | `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps` | Streaming JSON chunks |
+//! | [`convert_stream_with_timestamps`](TextToSpeechService::convert_stream_with_timestamps) | `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps` | Streaming audio + alignment chunks |
+//! | [`convert_stream_with_words`](TextToSpeechService::convert_stream_with_words) | `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps` | Streaming audio + word events |
+//! | [`convert_stream_resilient`](TextToSpeechService::convert_stream_resilient) | `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps` | Streaming audio that resumes after a dropped connection |
+//!
+//! Texts longer than one request allows can be converted with
+//! [`TextChunker`], obtained via [`TextToSpeechService::chunker`]. Large
+//! batches of separate requests can be run in parallel with
+//! [`BulkSynthesizer`], obtained via [`TextToSpeechService::bulk`].
 //!
 //! # Example
 //!
@@ -29,15 +37,30 @@
 //! # }
 //! ```
 
+use std::{collections::VecDeque, future::Future, pin::Pin};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
 use bytes::Bytes;
 use futures_core::Stream;
+use futures_util::{StreamExt, TryStreamExt, stream};
 
 use crate::{
-    client::ElevenLabsClient,
-    error::Result,
-    types::{AudioWithTimestampsResponse, OutputFormat, TextToSpeechRequest},
+    audio::strip_id3_tag,
+    client::{ElevenLabsClient, REQUEST_ID_HEADER},
+    error::{ElevenLabsError, Result},
+    services::bulk::BulkSynthesizer,
+    types::{
+        AudioWithTimestampsResponse, Model, OutputFormat, StreamingAudioChunkWithTimestamps,
+        TextToSpeechAudio, TextToSpeechRequest, TimestampedAudioChunk, TtsStreamEvent, WordAligner,
+    },
 };
 
+/// Response header carrying the ID of the history item for a generation.
+const HISTORY_ITEM_ID_HEADER: &str = "history-item-id";
+
+/// Response header carrying the number of characters billed.
+const CHARACTER_COST_HEADER: &str = "character-cost";
+
 /// Text-to-speech service providing typed access to TTS endpoints.
 ///
 /// Obtained via [`ElevenLabsClient::text_to_speech`].
@@ -56,7 +79,7 @@ impl<'a> TextToSpeechService<'a> {
     ///
     /// The base path is `/v1/text-to-speech/{voice_id}` with an optional
     /// suffix (e.g. `/stream`, `/with-timestamps`).
-    fn build_path(
+    pub(crate) fn build_path(
         voice_id: &str,
         suffix: &str,
         output_format: Option<OutputFormat>,
@@ -104,9 +127,39 @@ impl<'a> TextToSpeechService<'a> {
         optimize_streaming_latency: Option<u8>,
     ) -> Result<Bytes> {
         let path = Self::build_path(voice_id, "", output_format, optimize_streaming_latency);
+        self.client.guard_quota(request.text.chars().count()).await?;
         self.client.post_bytes(&path, request).await
     }
 
+    /// Converts text to speech like [`convert`](Self::convert), also
+    /// returning the request ID, history item ID and character cost from the
+    /// response headers.
+    ///
+    /// Calls `POST /v1/text-to-speech/{voice_id}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be read.
+    pub async fn convert_with_metadata(
+        &self,
+        voice_id: &str,
+        request: &TextToSpeechRequest,
+        output_format: Option<OutputFormat>,
+        optimize_streaming_latency: Option<u8>,
+    ) -> Result<TextToSpeechAudio> {
+        let path = Self::build_path(voice_id, "", output_format, optimize_streaming_latency);
+        self.client.guard_quota(request.text.chars().count()).await?;
+        let (audio, headers) = self.client.post_bytes_with_headers(&path, request).await?;
+        let header =
+            |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(str::to_owned);
+        Ok(TextToSpeechAudio {
+            audio,
+            request_id: header(REQUEST_ID_HEADER),
+            history_item_id: header(HISTORY_ITEM_ID_HEADER),
+            character_cost: header(CHARACTER_COST_HEADER).and_then(|v| v.parse().ok()),
+        })
+    }
+
     /// Converts text to speech with character-level timestamp alignment.
     ///
     /// Calls `POST /v1/text-to-speech/{voice_id}/with-timestamps`.
@@ -138,6 +191,7 @@ impl<'a> TextToSpeechService<'a> {
             output_format,
             optimize_streaming_latency,
         );
+        self.client.guard_quota(request.text.chars().count()).await?;
         self.client.post(&path, request).await
     }
 
@@ -164,16 +218,17 @@ impl<'a> TextToSpeechService<'a> {
         optimize_streaming_latency: Option<u8>,
     ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>>> {
         let path = Self::build_path(voice_id, "/stream", output_format, optimize_streaming_latency);
+        self.client.guard_quota(request.text.chars().count()).await?;
         self.client.post_stream(&path, request).await
     }
 
     /// Converts text to speech with streaming and timestamp alignment.
     ///
-    /// Calls `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps`.
-    ///
-    /// Returns a stream of raw byte chunks. Each chunk is a JSON-encoded
-    /// [`StreamingAudioChunkWithTimestamps`](crate::types::StreamingAudioChunkWithTimestamps)
-    /// that callers should deserialize.
+    /// Calls `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps` and
+    /// yields each chunk as soon as it arrives, with its audio decoded and
+    /// the alignment of the characters it speaks. Alignment times count from
+    /// the start of the stream, so captions can be rendered as the audio
+    /// plays.
     ///
     /// # Arguments
     ///
@@ -184,22 +239,643 @@ impl<'a> TextToSpeechService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the initial API request fails.
+    /// Returns an error if the initial API request fails. Stream items carry
+    /// transport errors and malformed chunks.
     pub async fn convert_stream_with_timestamps(
         &self,
         voice_id: &str,
         request: &TextToSpeechRequest,
         output_format: Option<OutputFormat>,
         optimize_streaming_latency: Option<u8>,
-    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>>> {
+    ) -> Result<impl Stream<Item = Result<TimestampedAudioChunk>>> {
         let path = Self::build_path(
             voice_id,
             "/stream/with-timestamps",
             output_format,
             optimize_streaming_latency,
         );
-        self.client.post_stream(&path, request).await
+        self.client.guard_quota(request.text.chars().count()).await?;
+        let chunks = self.client.post_stream(&path, request).await?;
+        Ok(timestamped_chunks(chunks))
+    }
+
+    /// Converts text to speech with streaming, yielding decoded audio
+    /// interleaved with word timings.
+    ///
+    /// Calls `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps` and
+    /// groups the character alignment into [`TtsStreamEvent::WordSpoken`]
+    /// events, so a UI can highlight words as the audio plays. Timings come
+    /// from the original-text alignment, falling back to the normalized
+    /// alignment when the former is absent.
+    ///
+    /// # Arguments
+    ///
+    /// * `voice_id` — The voice ID to use for synthesis.
+    /// * `request` — The TTS request body.
+    /// * `output_format` — Optional output format.
+    /// * `optimize_streaming_latency` — Optional latency optimization level (0–4).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the initial API request fails. Stream items carry
+    /// transport errors and malformed chunks.
+    pub async fn convert_stream_with_words(
+        &self,
+        voice_id: &str,
+        request: &TextToSpeechRequest,
+        output_format: Option<OutputFormat>,
+        optimize_streaming_latency: Option<u8>,
+    ) -> Result<impl Stream<Item = Result<TtsStreamEvent>>> {
+        let chunks = self
+            .convert_stream_with_timestamps(
+                voice_id,
+                request,
+                output_format,
+                optimize_streaming_latency,
+            )
+            .await?;
+        Ok(word_events(chunks))
     }
+
+    /// Converts text to speech with streaming, resuming the stream if the
+    /// connection drops part-way through.
+    ///
+    /// Calls `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps` and
+    /// yields the decoded audio. The character alignment of each chunk records
+    /// how much of `request.text` has been spoken. If the response breaks off,
+    /// the rest of the text is requested again with the spoken part appended
+    /// to `previous_text`, so intonation carries across the seam, and the
+    /// audio continues from the last complete chunk. A resumed MP3 stream has
+    /// its leading ID3 tag removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `voice_id` — The voice ID to use for synthesis.
+    /// * `request` — The TTS request body.
+    /// * `output_format` — Optional output format.
+    /// * `optimize_streaming_latency` — Optional latency optimization level (0–4).
+    /// * `max_resumes` — How many times the stream may be resumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the initial API request fails. Stream items carry
+    /// malformed chunks, the transport error once `max_resumes` is used up,
+    /// and any error from a resumed request.
+    pub async fn convert_stream_resilient(
+        &self,
+        voice_id: &str,
+        request: &TextToSpeechRequest,
+        output_format: Option<OutputFormat>,
+        optimize_streaming_latency: Option<u8>,
+        max_resumes: u32,
+    ) -> Result<impl Stream<Item = Result<Bytes>> + use<'a>> {
+        let path = Self::build_path(
+            voice_id,
+            "/stream/with-timestamps",
+            output_format,
+            optimize_streaming_latency,
+        );
+        let client = self.client;
+        client.guard_quota(request.text.chars().count()).await?;
+        let chunks = client.post_stream(&path, request).await?;
+        let reconnect = move |request: TextToSpeechRequest| {
+            let path = path.clone();
+            async move { client.post_stream(&path, &request).await }
+        };
+        Ok(resumable_audio(request.clone(), chunks, reconnect, max_resumes))
+    }
+
+    /// Returns a [`TextChunker`] for converting text longer than a single
+    /// request allows.
+    pub const fn chunker(&self) -> TextChunker<'a> {
+        TextChunker::new(self.client)
+    }
+
+    /// Returns a [`BulkSynthesizer`] for running many requests concurrently
+    /// with rate limiting and retries.
+    pub const fn bulk(&self) -> BulkSynthesizer<'a> {
+        BulkSynthesizer::new(self.client)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Long-text chunking
+// ---------------------------------------------------------------------------
+
+/// Characters per chunk unless overridden with [`TextChunker::max_chars`].
+///
+/// Matches the per-request limit for subscribed users on
+/// `eleven_multilingual_v2`.
+pub const DEFAULT_CHUNK_MAX_CHARS: usize = 5_000;
+
+/// Characters that end a sentence when followed by whitespace.
+const SENTENCE_TERMINATORS: &[char] = &['.', '!', '?', '\u{2026}'];
+
+/// Full-width terminators that end a sentence without trailing whitespace.
+const FULL_WIDTH_TERMINATORS: &[char] = &['\u{3002}', '\u{ff01}', '\u{ff1f}'];
+
+/// Closing quotes and brackets that may follow a sentence terminator.
+const SENTENCE_CLOSERS: &[char] = &['"', '\'', ')', ']', '\u{201d}', '\u{2019}'];
+
+/// Converts text longer than a single request allows.
+///
+/// Obtained via [`TextToSpeechService::chunker`]. The text is split on
+/// sentence boundaries into chunks of at most
+/// [`max_chars`](Self::max_chars) characters; a sentence that does not fit
+/// on its own is split between words, and a word that does not fit is split
+/// between characters. Each chunk is converted with the same settings and
+/// the audio is concatenated in order.
+///
+/// By default every chunk is sent with its neighbours as `previous_text` and
+/// `next_text`, so intonation carries across chunk boundaries.
+///
+/// Only formats that can be joined byte-for-byte are supported: MP3, PCM,
+/// μ-law and A-law. WAV and Opus output is rejected.
+#[derive(Debug)]
+pub struct TextChunker<'a> {
+    client: &'a ElevenLabsClient,
+    max_chars: usize,
+    concurrency: usize,
+    condition: bool,
+}
+
+impl<'a> TextChunker<'a> {
+    /// Creates a chunker bound to the given client.
+    pub(crate) const fn new(client: &'a ElevenLabsClient) -> Self {
+        Self { client, max_chars: DEFAULT_CHUNK_MAX_CHARS, concurrency: 1, condition: true }
+    }
+
+    /// Sets the maximum number of characters per request (at least 1).
+    #[must_use]
+    pub fn max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = max_chars.max(1);
+        self
+    }
+
+    /// Uses the subscribed-user character limit advertised by `model`.
+    ///
+    /// Free-tier accounts have a lower limit; use
+    /// [`max_chars`](Self::max_chars) with
+    /// [`Model::max_characters_request_free_user`] instead.
+    #[must_use]
+    pub fn limit_for_model(self, model: &Model) -> Self {
+        match usize::try_from(model.max_characters_request_subscribed_user) {
+            Ok(limit) if limit > 0 => self.max_chars(limit),
+            _ => self,
+        }
+    }
+
+    /// Sets how many chunks are converted at once (default 1, sequential).
+    #[must_use]
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets whether neighbouring chunks are sent as `previous_text` and
+    /// `next_text` (default `true`).
+    #[must_use]
+    pub const fn condition_on_neighbours(mut self, condition: bool) -> Self {
+        self.condition = condition;
+        self
+    }
+
+    /// Splits `text` into the chunks [`convert`](Self::convert) would send.
+    pub fn split(&self, text: &str) -> Vec<String> {
+        let mut packer = ChunkPacker::new(self.max_chars);
+        for sentence in split_sentences(text) {
+            if packer.push(sentence) {
+                continue;
+            }
+            for word in sentence.split_inclusive(char::is_whitespace) {
+                if packer.push(word) {
+                    continue;
+                }
+                let chars: Vec<char> = word.chars().collect();
+                for part in chars.chunks(self.max_chars) {
+                    packer.push(&part.iter().collect::<String>());
+                }
+            }
+        }
+        packer.finish()
+    }
+
+    /// Converts `request.text` chunk by chunk and returns the joined audio.
+    ///
+    /// All other fields of `request` apply to every chunk. Its
+    /// `previous_text` and `next_text`, if set, are kept for the first and
+    /// last chunk respectively.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the text is empty or the
+    /// output format cannot be joined, or the first error from any chunk's
+    /// conversion.
+    pub async fn convert(
+        &self,
+        voice_id: &str,
+        request: &TextToSpeechRequest,
+        output_format: Option<OutputFormat>,
+    ) -> Result<Bytes> {
+        let format = output_format.unwrap_or_default();
+        let name = format.to_string();
+        if name.starts_with("wav_") || name.starts_with("opus_") {
+            return Err(ElevenLabsError::Validation(format!(
+                "{name} output cannot be joined; use an MP3 or PCM format"
+            )));
+        }
+
+        let chunks = self.split(&request.text);
+        if chunks.is_empty() {
+            return Err(ElevenLabsError::Validation("text is empty".to_owned()));
+        }
+        let requests: Vec<TextToSpeechRequest> = chunks
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let mut chunk_request = request.clone();
+                chunk_request.text.clone_from(text);
+                if self.condition {
+                    if let Some(previous) = i.checked_sub(1).and_then(|p| chunks.get(p)) {
+                        chunk_request.previous_text = Some(previous.clone());
+                    }
+                    if let Some(next) = chunks.get(i + 1) {
+                        chunk_request.next_text = Some(next.clone());
+                    }
+                }
+                chunk_request
+            })
+            .collect();
+
+        let tts = TextToSpeechService::new(self.client);
+        let parts: Vec<Bytes> = stream::iter(&requests)
+            .map(|chunk_request| tts.convert(voice_id, chunk_request, Some(format), None))
+            .buffered(self.concurrency)
+            .try_collect()
+            .await?;
+
+        let is_mp3 = name.starts_with("mp3_");
+        let mut audio = Vec::with_capacity(parts.iter().map(Bytes::len).sum());
+        for (i, part) in parts.iter().enumerate() {
+            // Only the first chunk keeps its ID3 tag; a tag in the middle of
+            // the stream would be decoded as noise by some players.
+            let body = if is_mp3 && i > 0 { strip_id3_tag(part) } else { part };
+            audio.extend_from_slice(body);
+        }
+        Ok(Bytes::from(audio))
+    }
+}
+
+/// Accumulates text pieces into chunks of at most `max_chars` characters.
+struct ChunkPacker {
+    max_chars: usize,
+    chunks: Vec<String>,
+    current: String,
+    current_chars: usize,
+}
+
+impl ChunkPacker {
+    const fn new(max_chars: usize) -> Self {
+        Self { max_chars, chunks: Vec::new(), current: String::new(), current_chars: 0 }
+    }
+
+    /// Appends `piece`, starting a new chunk if it does not fit in the
+    /// current one. Returns `false` without appending if `piece` is longer
+    /// than a whole chunk.
+    fn push(&mut self, piece: &str) -> bool {
+        // Trailing whitespace is trimmed from finished chunks, so it does not
+        // count towards the limit.
+        let needed = piece.trim_end().chars().count();
+        if self.current_chars + needed > self.max_chars {
+            self.flush();
+        }
+        if needed > self.max_chars {
+            return false;
+        }
+        self.current.push_str(piece);
+        self.current_chars += piece.chars().count();
+        true
+    }
+
+    fn flush(&mut self) {
+        let chunk = self.current.trim();
+        if !chunk.is_empty() {
+            self.chunks.push(chunk.to_owned());
+        }
+        self.current.clear();
+        self.current_chars = 0;
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        self.flush();
+        self.chunks
+    }
+}
+
+/// Splits `text` into sentences, each keeping its trailing whitespace.
+///
+/// A sentence ends at a terminator (optionally followed by closing quotes)
+/// and whitespace, at a full-width terminator, or at a line break. A period
+/// inside a token, as in `3.14` or `e.g.`, does not end a sentence.
+fn split_sentences(text: &str) -> Vec<&str> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum State {
+        Text,
+        Terminated,
+        Gap,
+    }
+
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut state = State::Text;
+    for (i, c) in text.char_indices() {
+        if c.is_whitespace() {
+            if state == State::Terminated || c == '\n' {
+                state = State::Gap;
+            }
+            continue;
+        }
+        if state == State::Gap {
+            sentences.push(&text[start..i]);
+            start = i;
+        }
+        state = if FULL_WIDTH_TERMINATORS.contains(&c) {
+            State::Gap
+        } else if SENTENCE_TERMINATORS.contains(&c) ||
+            (state == State::Terminated && SENTENCE_CLOSERS.contains(&c))
+        {
+            State::Terminated
+        } else {
+            State::Text
+        };
+    }
+    if start < text.len() {
+        sentences.push(&text[start..]);
+    }
+    sentences
+}
+
+/// Splits the raw `stream/with-timestamps` byte stream into decoded chunks.
+///
+/// The endpoint sends one JSON object per line, but network chunks do not
+/// follow line boundaries, so bytes are buffered until a full line arrives.
+fn timestamped_chunks<S>(chunks: S) -> impl Stream<Item = Result<TimestampedAudioChunk>>
+where
+    S: Stream<Item = std::result::Result<Bytes, hpx::Error>>,
+{
+    let state = (Box::pin(chunks), Vec::new(), false);
+    stream::unfold(state, |(mut chunks, mut buffer, mut done)| async move {
+        loop {
+            if done {
+                return None;
+            }
+            let line: Vec<u8> = if let Some(newline) = buffer.iter().position(|&b| b == b'\n') {
+                buffer.drain(..=newline).collect()
+            } else {
+                match chunks.next().await {
+                    Some(Ok(bytes)) => {
+                        buffer.extend_from_slice(&bytes);
+                        continue;
+                    }
+                    Some(Err(e)) => {
+                        return Some((Err(ElevenLabsError::Transport(e)), (chunks, buffer, true)));
+                    }
+                    None => {
+                        done = true;
+                        std::mem::take(&mut buffer)
+                    }
+                }
+            };
+            match decode_timestamped_line(&line) {
+                Ok(None) => {}
+                Ok(Some(chunk)) => return Some((Ok(chunk), (chunks, buffer, done))),
+                Err(e) => return Some((Err(e), (chunks, buffer, true))),
+            }
+        }
+    })
+}
+
+/// Decodes one newline-delimited JSON chunk, or returns `None` for a blank
+/// line.
+fn decode_timestamped_line(line: &[u8]) -> Result<Option<TimestampedAudioChunk>> {
+    if line.trim_ascii().is_empty() {
+        return Ok(None);
+    }
+    let chunk: StreamingAudioChunkWithTimestamps = serde_json::from_slice(line)?;
+    let audio = STANDARD.decode(&chunk.audio_base64).map_err(|e| {
+        ElevenLabsError::Deserialization(serde::de::Error::custom(format!(
+            "invalid audio_base64: {e}"
+        )))
+    })?;
+    Ok(Some(TimestampedAudioChunk {
+        audio: Bytes::from(audio),
+        alignment: chunk.alignment,
+        normalized_alignment: chunk.normalized_alignment,
+    }))
+}
+
+/// State for [`word_events`].
+struct WordEvents<S> {
+    chunks: Pin<Box<S>>,
+    aligner: WordAligner,
+    pending: VecDeque<TtsStreamEvent>,
+    done: bool,
+}
+
+impl<S> WordEvents<S> {
+    /// Queues the audio and the words completed by one chunk.
+    fn push(&mut self, chunk: TimestampedAudioChunk) {
+        if !chunk.audio.is_empty() {
+            self.pending.push_back(TtsStreamEvent::Audio(chunk.audio));
+        }
+        if let Some(alignment) = chunk.alignment.or(chunk.normalized_alignment) {
+            let words = self.aligner.push(&alignment);
+            self.pending.extend(words.into_iter().map(TtsStreamEvent::WordSpoken));
+        }
+    }
+}
+
+/// Turns decoded `stream/with-timestamps` chunks into audio and word events.
+fn word_events<S>(chunks: S) -> impl Stream<Item = Result<TtsStreamEvent>>
+where
+    S: Stream<Item = Result<TimestampedAudioChunk>>,
+{
+    let state = WordEvents {
+        chunks: Box::pin(chunks),
+        aligner: WordAligner::new(),
+        pending: VecDeque::new(),
+        done: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((Ok(event), state));
+            }
+            if state.done {
+                return None;
+            }
+            match state.chunks.next().await {
+                Some(Ok(chunk)) => state.push(chunk),
+                Some(Err(e)) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+                None => {
+                    state.done = true;
+                    let last = state.aligner.finish();
+                    state.pending.extend(last.map(TtsStreamEvent::WordSpoken));
+                }
+            }
+        }
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Resumable streaming
+// ---------------------------------------------------------------------------
+
+/// State for [`resumable_audio`].
+struct ResumableAudio<S, F> {
+    request: TextToSpeechRequest,
+    text: Vec<char>,
+    /// Characters of `text` covered by complete chunks so far.
+    spoken: usize,
+    resumes_left: u32,
+    reconnect: F,
+    chunks: Pin<Box<S>>,
+    buffer: Vec<u8>,
+    pending: VecDeque<Bytes>,
+    /// Whether the next audio chunk starts a resumed stream.
+    strip_tag: bool,
+    done: bool,
+}
+
+impl<S, F, Fut, E> ResumableAudio<S, F>
+where
+    S: Stream<Item = std::result::Result<Bytes, E>>,
+    F: FnMut(TextToSpeechRequest) -> Fut,
+    Fut: Future<Output = Result<S>>,
+{
+    /// Decodes one newline-delimited JSON chunk into pending audio.
+    fn handle_line(&mut self, line: &[u8]) -> Result<()> {
+        let Some(chunk) = decode_timestamped_line(line)? else {
+            return Ok(());
+        };
+        if !chunk.audio.is_empty() {
+            let audio = if std::mem::take(&mut self.strip_tag) {
+                chunk.audio.slice_ref(strip_id3_tag(&chunk.audio))
+            } else {
+                chunk.audio
+            };
+            self.pending.push_back(audio);
+        }
+        // Only the original-text alignment maps onto `request.text`; the
+        // normalized alignment may spell out numbers and abbreviations.
+        if let Some(alignment) = chunk.alignment {
+            let chars: usize = alignment.characters.iter().map(|c| c.chars().count()).sum();
+            self.spoken = (self.spoken + chars).min(self.text.len());
+        }
+        Ok(())
+    }
+
+    /// Requests the unspoken rest of the text. Returns `Ok(false)` if no
+    /// resumes are left or nothing remains to be spoken.
+    async fn resume(&mut self) -> Result<bool> {
+        let start = self.text[self.spoken..]
+            .iter()
+            .position(|c| !c.is_whitespace())
+            .map_or(self.text.len(), |offset| self.spoken + offset);
+        if self.resumes_left == 0 || start == self.text.len() {
+            return Ok(false);
+        }
+        self.resumes_left -= 1;
+
+        let spoken: String = self.text[..self.spoken].iter().collect();
+        let previous: Vec<&str> = [self.request.previous_text.as_deref(), Some(spoken.trim())]
+            .into_iter()
+            .flatten()
+            .filter(|text| !text.is_empty())
+            .collect();
+        let mut request = self.request.clone();
+        request.text = self.text[start..].iter().collect();
+        request.previous_text = (!previous.is_empty()).then(|| previous.join(" "));
+
+        self.chunks = Box::pin((self.reconnect)(request).await?);
+        self.buffer.clear();
+        self.spoken = start;
+        self.strip_tag = true;
+        Ok(true)
+    }
+}
+
+/// Turns a `stream/with-timestamps` byte stream into decoded audio, calling
+/// `reconnect` with the rest of the text when the stream fails.
+///
+/// Audio is only yielded once its whole line has arrived, so a line cut off
+/// by the failure is dropped and spoken again by the resumed request.
+fn resumable_audio<S, F, Fut, E>(
+    request: TextToSpeechRequest,
+    chunks: S,
+    reconnect: F,
+    max_resumes: u32,
+) -> impl Stream<Item = Result<Bytes>>
+where
+    S: Stream<Item = std::result::Result<Bytes, E>>,
+    F: FnMut(TextToSpeechRequest) -> Fut,
+    Fut: Future<Output = Result<S>>,
+    E: Into<ElevenLabsError>,
+{
+    let state = ResumableAudio {
+        text: request.text.chars().collect(),
+        request,
+        spoken: 0,
+        resumes_left: max_resumes,
+        reconnect,
+        chunks: Box::pin(chunks),
+        buffer: Vec::new(),
+        pending: VecDeque::new(),
+        strip_tag: false,
+        done: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(audio) = state.pending.pop_front() {
+                return Some((Ok(audio), state));
+            }
+            if state.done {
+                return None;
+            }
+            if let Some(newline) = state.buffer.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = state.buffer.drain(..=newline).collect();
+                if let Err(e) = state.handle_line(&line) {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+                continue;
+            }
+            match state.chunks.next().await {
+                Some(Ok(bytes)) => state.buffer.extend_from_slice(&bytes),
+                Some(Err(e)) => match state.resume().await {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        state.done = true;
+                        return Some((Err(e.into()), state));
+                    }
+                    Err(resume_err) => {
+                        state.done = true;
+                        return Some((Err(resume_err), state));
+                    }
+                },
+                None => {
+                    state.done = true;
+                    let rest = std::mem::take(&mut state.buffer);
+                    if let Err(e) = state.handle_line(&rest) {
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        }
+    })
 }
 
 // ---------------------------------------------------------------------------
@@ -211,7 +887,7 @@ impl<'a> TextToSpeechService<'a> {
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path, query_param},
+        matchers::{body_partial_json, header, method, path, query_param},
     };
 
     use crate::{
@@ -268,6 +944,48 @@ mod tests {
         assert_eq!(result.as_ref(), b"pcm-data");
     }
 
+    #[tokio::test]
+    async fn quota_guard_refuses_text_beyond_remaining_quota() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/user/subscription"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "tier": "starter",
+                "character_count": 9_990,
+                "character_limit": 10_000
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice123"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"mp3".as_slice(), "audio/mpeg"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client =
+            ElevenLabsClient::new(config).unwrap().with_quota_guard(crate::QuotaGuard::new());
+        let tts = client.text_to_speech();
+
+        tts.convert("voice123", &TextToSpeechRequest::new("Hello"), None, None).await.unwrap();
+        let result = tts.convert("voice123", &TextToSpeechRequest::new("Hello!"), None, None).await;
+        assert!(matches!(
+            result,
+            Err(crate::ElevenLabsError::QuotaWouldExceed { requested: 6, remaining: 5 })
+        ));
+
+        let forced = client.with_options(crate::RequestOptions::new().skip_quota_guard());
+        forced
+            .text_to_speech()
+            .convert("voice123", &TextToSpeechRequest::new("Hello!"), None, None)
+            .await
+            .unwrap();
+        assert_eq!(client.quota_guard().unwrap().remaining(), Some(0));
+    }
+
     #[tokio::test]
     async fn convert_with_optimize_streaming_latency() {
         let mock_server = MockServer::start().await;
@@ -314,6 +1032,38 @@ mod tests {
         assert_eq!(result.as_ref(), b"audio");
     }
 
+    #[tokio::test]
+    async fn convert_with_metadata_reads_response_headers() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("request-id", "req_1")
+                    .insert_header("history-item-id", "hist_1")
+                    .insert_header("character-cost", "13")
+                    .set_body_raw(b"audio", "audio/mpeg"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request = TextToSpeechRequest::new("Hello, world!");
+        let result = client
+            .text_to_speech()
+            .convert_with_metadata("voice123", &request, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.audio.as_ref(), b"audio");
+        assert_eq!(result.request_id.as_deref(), Some("req_1"));
+        assert_eq!(result.history_item_id.as_deref(), Some("hist_1"));
+        assert_eq!(result.character_cost, Some(13));
+    }
+
     // -- convert_with_timestamps -------------------------------------------
 
     #[tokio::test]
@@ -411,31 +1161,198 @@ mod tests {
     // -- convert_stream_with_timestamps ------------------------------------
 
     #[tokio::test]
-    async fn convert_stream_with_timestamps_returns_stream() {
-        use futures_core::Stream;
+    async fn convert_stream_with_timestamps_decodes_chunks() {
+        use futures_util::StreamExt;
+
+        use crate::types::CharacterAlignment;
 
         let mock_server = MockServer::start().await;
+        let body = concat!(
+            r#"{"audio_base64":"SGVs","alignment":{"characters":["H","i"],"#,
+            r#""character_start_times_seconds":[0.0,0.1],"#,
+            r#""character_end_times_seconds":[0.1,0.2]}}"#,
+            "\n\n",
+            r#"{"audio_base64":"bG8=","alignment":null}"#,
+        );
 
         Mock::given(method("POST"))
             .and(path("/v1/text-to-speech/voiceABC/stream/with-timestamps"))
             .and(header("xi-api-key", "test-key"))
-            .respond_with(
-                ResponseTemplate::new(200)
-                    .set_body_raw(b"streaming-json-chunks", "application/json"),
-            )
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
         let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
         let client = ElevenLabsClient::new(config).unwrap();
 
-        let request = TextToSpeechRequest::new("Timestamps");
+        let request = TextToSpeechRequest::new("Hi");
         let tts = client.text_to_speech();
         let stream =
             tts.convert_stream_with_timestamps("voiceABC", &request, None, None).await.unwrap();
+        let chunks: Vec<_> = stream.map(|chunk| chunk.unwrap()).collect().await;
 
-        fn assert_stream<S: Stream>(_s: &S) {}
-        assert_stream(&stream);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].audio, bytes::Bytes::from_static(b"Hel"));
+        assert_eq!(
+            chunks[0].alignment,
+            Some(CharacterAlignment {
+                characters: vec!["H".into(), "i".into()],
+                character_start_times_seconds: vec![0.0, 0.1],
+                character_end_times_seconds: vec![0.1, 0.2],
+            })
+        );
+        assert_eq!(chunks[0].text(), "Hi");
+        assert_eq!(chunks[1].audio, bytes::Bytes::from_static(b"lo"));
+        assert!(chunks[1].alignment.is_none());
+        assert_eq!(chunks[1].text(), "");
+    }
+
+    // -- convert_stream_with_words -----------------------------------------
+
+    #[tokio::test]
+    async fn convert_stream_with_words_interleaves_audio_and_words() {
+        use futures_util::StreamExt;
+
+        use crate::types::{TtsStreamEvent, WordSpoken};
+
+        let mock_server = MockServer::start().await;
+        let body = concat!(
+            r#"{"audio_base64":"SGVs","alignment":{"characters":["H","i"," ","y"],"#,
+            r#""character_start_times_seconds":[0.0,0.1,0.2,0.3],"#,
+            r#""character_end_times_seconds":[0.1,0.2,0.3,0.4]}}"#,
+            "\n",
+            r#"{"audio_base64":"bG8=","alignment":null,"normalized_alignment":{"characters":["o"],"#,
+            r#""character_start_times_seconds":[0.4],"character_end_times_seconds":[0.5]}}"#,
+        );
+
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voiceABC/stream/with-timestamps"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request = TextToSpeechRequest::new("Hi yo");
+        let tts = client.text_to_speech();
+        let stream = tts.convert_stream_with_words("voiceABC", &request, None, None).await.unwrap();
+        let events: Vec<TtsStreamEvent> =
+            stream.map(|event| event.unwrap()).collect::<Vec<_>>().await;
+
+        assert_eq!(
+            events,
+            [
+                TtsStreamEvent::Audio(bytes::Bytes::from_static(b"Hel")),
+                TtsStreamEvent::WordSpoken(WordSpoken {
+                    word: "Hi".into(),
+                    start_ms: 0,
+                    end_ms: 200
+                }),
+                TtsStreamEvent::Audio(bytes::Bytes::from_static(b"lo")),
+                TtsStreamEvent::WordSpoken(WordSpoken {
+                    word: "yo".into(),
+                    start_ms: 300,
+                    end_ms: 500
+                }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn convert_stream_with_words_reports_malformed_chunk() {
+        use futures_util::StreamExt;
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voiceABC/stream/with-timestamps"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("not json\n", "application/json"))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request = TextToSpeechRequest::new("Hi");
+        let tts = client.text_to_speech();
+        let stream = tts.convert_stream_with_words("voiceABC", &request, None, None).await.unwrap();
+        let events: Vec<_> = stream.collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Err(crate::ElevenLabsError::Deserialization(_))));
+    }
+
+    // -- convert_stream_resilient ------------------------------------------
+
+    fn timestamp_line(audio: &[u8], characters: &str) -> String {
+        use base64::Engine;
+
+        let characters: Vec<String> = characters.chars().map(String::from).collect();
+        let times = vec![0.0; characters.len()];
+        let chunk = serde_json::json!({
+            "audio_base64": base64::engine::general_purpose::STANDARD.encode(audio),
+            "alignment": {
+                "characters": characters,
+                "character_start_times_seconds": times,
+                "character_end_times_seconds": times,
+            },
+        });
+        format!("{chunk}\n")
+    }
+
+    #[tokio::test]
+    async fn resumable_audio_resumes_with_previous_text() {
+        use std::sync::{Arc, Mutex};
+
+        use futures_util::{StreamExt, stream};
+
+        type Chunk = Result<bytes::Bytes, std::io::Error>;
+
+        // The connection drops in the middle of the second line.
+        let second = timestamp_line(b"bb", "world.");
+        let first: Vec<Chunk> = vec![
+            Ok(timestamp_line(b"aa", "Hello ").into()),
+            Ok(second[..10].to_owned().into()),
+            Err(std::io::ErrorKind::ConnectionReset.into()),
+        ];
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let reconnect = {
+            let sent = Arc::clone(&sent);
+            move |request: TextToSpeechRequest| {
+                sent.lock().unwrap().push(request);
+                let chunks: Vec<Chunk> = vec![Ok(second.clone().into())];
+                async move { Ok(stream::iter(chunks)) }
+            }
+        };
+
+        let mut request = TextToSpeechRequest::new("Hello world. Bye.");
+        request.previous_text = Some("Earlier.".into());
+        let audio: Vec<_> =
+            super::resumable_audio(request, stream::iter(first), reconnect, 1).collect().await;
+        let audio: Vec<_> = audio.into_iter().map(Result::unwrap).collect();
+        assert_eq!(audio, [&b"aa"[..], &b"bb"[..]]);
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].text, "world. Bye.");
+        assert_eq!(sent[0].previous_text.as_deref(), Some("Earlier. Hello"));
+    }
+
+    #[tokio::test]
+    async fn resumable_audio_gives_up_after_max_resumes() {
+        use futures_util::{StreamExt, stream};
+
+        type Chunk = Result<bytes::Bytes, std::io::Error>;
+
+        let failing = || -> Vec<Chunk> { vec![Err(std::io::ErrorKind::ConnectionReset.into())] };
+        let reconnect = move |_: TextToSpeechRequest| async move { Ok(stream::iter(failing())) };
+
+        let request = TextToSpeechRequest::new("Hello");
+        let items: Vec<_> =
+            super::resumable_audio(request, stream::iter(failing()), reconnect, 2).collect().await;
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(crate::ElevenLabsError::Io(_))));
     }
 
     // -- build_path --------------------------------------------------------
@@ -477,4 +1394,81 @@ mod tests {
             "/v1/text-to-speech/v123/stream/with-timestamps?output_format=mp3_44100_128&optimize_streaming_latency=2"
         );
     }
+
+    // -- chunker -----------------------------------------------------------
+
+    fn chunker(client: &ElevenLabsClient, max_chars: usize) -> super::TextChunker<'_> {
+        client.text_to_speech().chunker().max_chars(max_chars)
+    }
+
+    #[test]
+    fn split_packs_whole_sentences() {
+        let client = ElevenLabsClient::new(ClientConfig::builder("k").build()).unwrap();
+        let chunks = chunker(&client, 25).split("One two. Three four! Five six? Seven eight.");
+        assert_eq!(chunks, ["One two. Three four!", "Five six? Seven eight."]);
+    }
+
+    #[test]
+    fn split_keeps_decimals_and_quotes_together() {
+        let client = ElevenLabsClient::new(ClientConfig::builder("k").build()).unwrap();
+        let text = "Pi is 3.14 roughly. \"Really?\" she asked.\nYes\u{3002}Fine.";
+        let chunks = chunker(&client, 20).split(text);
+        assert_eq!(chunks, ["Pi is 3.14 roughly.", "\"Really?\" she asked.", "Yes\u{3002}Fine."]);
+    }
+
+    #[test]
+    fn split_breaks_long_sentences_between_words_then_characters() {
+        let client = ElevenLabsClient::new(ClientConfig::builder("k").build()).unwrap();
+        let chunks = chunker(&client, 10).split("alpha beta gamma abcdefghijklmno.");
+        assert_eq!(chunks, ["alpha beta", "gamma", "abcdefghij", "klmno."]);
+        assert!(chunker(&client, 10).split("  \n ").is_empty());
+    }
+
+    #[tokio::test]
+    async fn chunker_converts_chunks_in_order_with_context() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice123"))
+            .and(body_partial_json(serde_json::json!({
+                "text": "First part.",
+                "next_text": "Second part.",
+                "model_id": "eleven_flash_v2_5"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"AAA", "audio/mpeg"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/text-to-speech/voice123"))
+            .and(body_partial_json(serde_json::json!({
+                "text": "Second part.",
+                "previous_text": "First part."
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(b"ID3\x04\x00\x00\x00\x00\x00\x00BBB", "audio/mpeg"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let mut request = TextToSpeechRequest::new("First part. Second part.");
+        request.model_id = Some("eleven_flash_v2_5".into());
+
+        let audio =
+            chunker(&client, 15).concurrency(2).convert("voice123", &request, None).await.unwrap();
+        assert_eq!(audio.as_ref(), b"AAABBB");
+    }
+
+    #[tokio::test]
+    async fn chunker_rejects_unjoinable_formats() {
+        let client = ElevenLabsClient::new(ClientConfig::builder("k").build()).unwrap();
+        let request = TextToSpeechRequest::new("Hello.");
+        let result = chunker(&client, 100)
+            .convert("voice123", &request, Some(OutputFormat::Wav_16000))
+            .await;
+        assert!(matches!(result, Err(crate::ElevenLabsError::Validation(_))));
+    }
 }
@@ -12,6 +12,10 @@
 //! | [`remix`](TextToVoiceService::remix) | `POST /v1/text-to-voice/{voice_id}/remix` | Remix an existing voice |
 //! | [`stream_preview`](TextToVoiceService::stream_preview) | `GET /v1/text-to-voice/{generated_voice_id}/stream` | Stream preview audio |
 //!
+//! [`preview_audio`](TextToVoiceService::preview_audio) reads the same stream
+//! into memory. Preview text is checked against [`MIN_PREVIEW_TEXT_CHARS`]
+//! and [`MAX_PREVIEW_TEXT_CHARS`] before a request is sent.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -23,7 +27,11 @@
 //!
 //! let request = VoicePreviewsRequest {
 //!     voice_description: "A warm female narrator".into(),
-//!     text: Some("Hello, world!".into()),
+//!     text: Some(
+//!         "Welcome back to the show. Today we have a very special guest joining us \
+//!                 in the studio to talk about their new book."
+//!             .into(),
+//!     ),
 //!     auto_generate_text: None,
 //!     loudness: None,
 //!     quality: None,
@@ -33,19 +41,23 @@
 //! };
 //! let previews = client.text_to_voice().create_previews(&request).await?;
 //!
-//! println!("Got {} previews", previews.previews.len());
+//! for preview in &previews.previews {
+//!     let audio = preview.audio().expect("preview audio is base64");
+//!     println!("{}: {} bytes", preview.generated_voice_id, audio.len());
+//! }
 //! # Ok(())
 //! # }
 //! ```
 
 use bytes::Bytes;
+use futures_core::Stream;
 
 use crate::{
     client::ElevenLabsClient,
-    error::Result,
+    error::{ElevenLabsError, Result},
     types::{
-        CreateVoiceFromPreviewRequest, Voice, VoiceDesignRequest, VoicePreviewsRequest,
-        VoicePreviewsResponse, VoiceRemixRequest,
+        CreateVoiceFromPreviewRequest, MAX_PREVIEW_TEXT_CHARS, MIN_PREVIEW_TEXT_CHARS, Voice,
+        VoiceDesignRequest, VoicePreviewsRequest, VoicePreviewsResponse, VoiceRemixRequest,
     },
 };
 
@@ -79,12 +91,14 @@ impl<'a> TextToVoiceService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be
-    /// deserialized.
+    /// Returns [`ElevenLabsError::Validation`] if the preview text is outside
+    /// the allowed length, or an error if the API request fails or the
+    /// response cannot be deserialized.
     pub async fn create_previews(
         &self,
         request: &VoicePreviewsRequest,
     ) -> Result<VoicePreviewsResponse> {
+        validate_preview_text(request.text.as_deref())?;
         self.client.post("/v1/text-to-voice/create-previews", request).await
     }
 
@@ -121,9 +135,11 @@ impl<'a> TextToVoiceService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be
-    /// deserialized.
+    /// Returns [`ElevenLabsError::Validation`] if the preview text is outside
+    /// the allowed length, or an error if the API request fails or the
+    /// response cannot be deserialized.
     pub async fn design(&self, request: &VoiceDesignRequest) -> Result<VoicePreviewsResponse> {
+        validate_preview_text(request.text.as_deref())?;
         self.client.post("/v1/text-to-voice/design", request).await
     }
 
@@ -142,22 +158,44 @@ impl<'a> TextToVoiceService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be
-    /// deserialized.
+    /// Returns [`ElevenLabsError::Validation`] if the preview text is outside
+    /// the allowed length, or an error if the API request fails or the
+    /// response cannot be deserialized.
     pub async fn remix(
         &self,
         voice_id: &str,
         request: &VoiceRemixRequest,
     ) -> Result<VoicePreviewsResponse> {
+        validate_preview_text(request.text.as_deref())?;
         let path = format!("/v1/text-to-voice/{voice_id}/remix");
         self.client.post(&path, request).await
     }
 
     /// Streams preview audio for a generated voice.
     ///
-    /// Calls `GET /v1/text-to-voice/{generated_voice_id}/stream`.
+    /// Calls `GET /v1/text-to-voice/{generated_voice_id}/stream` and yields
+    /// the audio in chunks as it arrives. Use this for previews requested
+    /// with `stream_previews`, whose responses carry no inline audio.
+    ///
+    /// # Arguments
+    ///
+    /// * `generated_voice_id` — The generated voice ID obtained from a preview response.
+    ///
+    /// # Errors
     ///
-    /// Returns the preview audio as raw bytes.
+    /// Returns an error if the API request fails.
+    pub async fn stream_preview(
+        &self,
+        generated_voice_id: &str,
+    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>>> {
+        let path = format!("/v1/text-to-voice/{generated_voice_id}/stream");
+        self.client.get_stream(&path).await
+    }
+
+    /// Downloads the full preview audio for a generated voice.
+    ///
+    /// Calls `GET /v1/text-to-voice/{generated_voice_id}/stream` and returns
+    /// the audio as raw bytes once it has been received.
     ///
     /// # Arguments
     ///
@@ -167,12 +205,27 @@ impl<'a> TextToVoiceService<'a> {
     ///
     /// Returns an error if the API request fails or the response cannot be
     /// read.
-    pub async fn stream_preview(&self, generated_voice_id: &str) -> Result<Bytes> {
+    pub async fn preview_audio(&self, generated_voice_id: &str) -> Result<Bytes> {
         let path = format!("/v1/text-to-voice/{generated_voice_id}/stream");
         self.client.get_bytes(&path).await
     }
 }
 
+/// Checks preview `text` against the length the API accepts.
+fn validate_preview_text(text: Option<&str>) -> Result<()> {
+    let Some(text) = text else {
+        return Ok(());
+    };
+    let chars = text.chars().count();
+    if !(MIN_PREVIEW_TEXT_CHARS..=MAX_PREVIEW_TEXT_CHARS).contains(&chars) {
+        return Err(ElevenLabsError::Validation(format!(
+            "preview text must be between {MIN_PREVIEW_TEXT_CHARS} and \
+             {MAX_PREVIEW_TEXT_CHARS} characters, got {chars}"
+        )));
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -180,13 +233,14 @@ impl<'a> TextToVoiceService<'a> {
 #[cfg(test)]
 #[expect(clippy::unwrap_used, reason = "tests use unwrap")]
 mod tests {
+    use futures_util::StreamExt;
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
         matchers::{header, method, path},
     };
 
     use crate::{
-        ElevenLabsClient,
+        ElevenLabsClient, ElevenLabsError,
         config::ClientConfig,
         types::{
             CreateVoiceFromPreviewRequest, VoiceDesignRequest, VoicePreviewsRequest,
@@ -194,6 +248,9 @@ mod tests {
         },
     };
 
+    const PREVIEW_TEXT: &str = "Welcome back to the show. Today we have a very special guest \
+                                joining us in the studio to talk about their new book.";
+
     // -- create_previews ---------------------------------------------------
 
     #[tokio::test]
@@ -210,7 +267,7 @@ mod tests {
                     "language": "en"
                 }
             ],
-            "text": "Hello world"
+            "text": PREVIEW_TEXT
         });
 
         Mock::given(method("POST"))
@@ -225,7 +282,7 @@ mod tests {
 
         let request = VoicePreviewsRequest {
             voice_description: "A warm female voice".into(),
-            text: Some("Hello world".into()),
+            text: Some(PREVIEW_TEXT.into()),
             auto_generate_text: None,
             loudness: None,
             quality: None,
@@ -237,7 +294,31 @@ mod tests {
 
         assert_eq!(result.previews.len(), 1);
         assert_eq!(result.previews[0].generated_voice_id, "gen1");
-        assert_eq!(result.text, "Hello world");
+        assert_eq!(result.text, PREVIEW_TEXT);
+    }
+
+    #[tokio::test]
+    async fn create_previews_rejects_short_text() {
+        // No mock is mounted: validation must fail before any request.
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request = VoicePreviewsRequest {
+            voice_description: "A warm female voice".into(),
+            text: Some("Hello world".into()),
+            auto_generate_text: None,
+            loudness: None,
+            quality: None,
+            seed: None,
+            guidance_scale: None,
+            should_enhance: None,
+        };
+        let result = client.text_to_voice().create_previews(&request).await;
+
+        assert!(
+            matches!(result, Err(ElevenLabsError::Validation(ref msg)) if msg.contains("got 11"))
+        );
     }
 
     // -- create_voice ------------------------------------------------------
@@ -376,7 +457,7 @@ mod tests {
     // -- stream_preview ----------------------------------------------------
 
     #[tokio::test]
-    async fn stream_preview_returns_audio_bytes() {
+    async fn stream_preview_yields_audio_chunks() {
         let mock_server = MockServer::start().await;
         let audio_bytes: &[u8] = b"\xff\xfb\x90\x00preview-audio";
 
@@ -384,15 +465,23 @@ mod tests {
             .and(path("/v1/text-to-voice/gen123/stream"))
             .and(header("xi-api-key", "test-key"))
             .respond_with(ResponseTemplate::new(200).set_body_raw(audio_bytes, "audio/mpeg"))
+            .expect(2)
             .mount(&mock_server)
             .await;
 
         let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
         let client = ElevenLabsClient::new(config).unwrap();
+        let text_to_voice = client.text_to_voice();
 
-        let result = client.text_to_voice().stream_preview("gen123").await.unwrap();
+        let mut stream = Box::pin(text_to_voice.stream_preview("gen123").await.unwrap());
+        let mut streamed = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            streamed.extend_from_slice(&chunk.unwrap());
+        }
+        assert_eq!(streamed, audio_bytes);
 
-        assert_eq!(result.as_ref(), audio_bytes);
+        let buffered = text_to_voice.preview_audio("gen123").await.unwrap();
+        assert_eq!(buffered.as_ref(), audio_bytes);
     }
 
     // -- error handling ----------------------------------------------------
@@ -0,0 +1,166 @@
+//! Usage service providing access to usage analytics and subscription
+//! endpoints.
+//!
+//! | Method | Endpoint | Description |
+//! |--------|----------|-------------|
+//! | [`character_stats`](UsageService::character_stats) | `GET /v1/usage/character-stats` | Character usage as a time series |
+//! | [`subscription`](UsageService::subscription) | `GET /v1/user/subscription` | Current subscription and quota |
+//!
+//! # Example
+//!
+//! ```no_run
+//! use elevenlabs_sdk::{
+//!     ClientConfig, ElevenLabsClient,
+//!     types::{CharacterStatsQuery, UsageBreakdown, UsageInterval},
+//! };
+//!
+//! # async fn example() -> elevenlabs_sdk::Result<()> {
+//! let config = ClientConfig::builder("your-api-key").build();
+//! let client = ElevenLabsClient::new(config)?;
+//!
+//! let query = CharacterStatsQuery::new(1_700_000_000_000, 1_702_592_000_000)
+//!     .breakdown(UsageBreakdown::Voice)
+//!     .interval(UsageInterval::Day);
+//! let stats = client.usage().character_stats(&query).await?;
+//! for (voice, total) in stats.totals() {
+//!     println!("{voice}: {total} characters");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    client::ElevenLabsClient,
+    error::Result,
+    types::{CharacterStatsQuery, ExtendedSubscriptionResponse, UsageTimeSeries},
+};
+
+/// Usage service providing typed access to usage analytics endpoints.
+///
+/// Obtained via [`ElevenLabsClient::usage`].
+#[derive(Debug)]
+pub struct UsageService<'a> {
+    client: &'a ElevenLabsClient,
+}
+
+impl<'a> UsageService<'a> {
+    /// Creates a new `UsageService` bound to the given client.
+    pub(crate) const fn new(client: &'a ElevenLabsClient) -> Self {
+        Self { client }
+    }
+
+    /// Gets character usage over time, aggregated as described by `query`.
+    ///
+    /// Calls `GET /v1/usage/character-stats`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn character_stats(&self, query: &CharacterStatsQuery) -> Result<UsageTimeSeries> {
+        self.client.get(&character_stats_path(query)).await
+    }
+
+    /// Gets the current subscription, including the character count and
+    /// limit for the billing period.
+    ///
+    /// Calls `GET /v1/user/subscription`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn subscription(&self) -> Result<ExtendedSubscriptionResponse> {
+        self.client.get("/v1/user/subscription").await
+    }
+}
+
+/// Builds the request path with query string for `query`.
+fn character_stats_path(query: &CharacterStatsQuery) -> String {
+    let mut path = format!(
+        "/v1/usage/character-stats?start_unix={}&end_unix={}",
+        query.start_unix, query.end_unix
+    );
+    if query.include_workspace_metrics {
+        path.push_str("&include_workspace_metrics=true");
+    }
+    if let Some(breakdown) = query.breakdown {
+        path.push_str(&format!("&breakdown_type={breakdown}"));
+    }
+    if let Some(interval) = query.interval {
+        path.push_str(&format!("&aggregation_interval={interval}"));
+    }
+    if let Some(secs) = query.bucket_size_secs {
+        path.push_str(&format!("&aggregation_bucket_size={secs}"));
+    }
+    path
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path, query_param},
+    };
+
+    use super::*;
+    use crate::{
+        config::ClientConfig,
+        types::{UsageBreakdown, UsageInterval},
+    };
+
+    #[test]
+    fn character_stats_path_includes_options() {
+        let query = CharacterStatsQuery::new(1, 2);
+        assert_eq!(
+            character_stats_path(&query),
+            "/v1/usage/character-stats?start_unix=1&end_unix=2"
+        );
+
+        let query = query
+            .include_workspace_metrics(true)
+            .breakdown(UsageBreakdown::ApiKey)
+            .interval(UsageInterval::Week)
+            .bucket_size_secs(3600);
+        assert_eq!(
+            character_stats_path(&query),
+            "/v1/usage/character-stats?start_unix=1&end_unix=2&include_workspace_metrics=true\
+             &breakdown_type=api_keys&aggregation_interval=week&aggregation_bucket_size=3600"
+        );
+    }
+
+    #[tokio::test]
+    async fn character_stats_returns_time_series() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/usage/character-stats"))
+            .and(query_param("breakdown_type", "model"))
+            .and(query_param("aggregation_interval", "day"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "time": [1_700_000_000_000_i64, 1_700_086_400_000_i64],
+                "usage": {
+                    "eleven_multilingual_v2": [120, 80],
+                    "eleven_flash_v2_5": [0, 40]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let query = CharacterStatsQuery::new(1_700_000_000_000, 1_700_172_800_000)
+            .breakdown(UsageBreakdown::Model)
+            .interval(UsageInterval::Day);
+        let stats = client.usage().character_stats(&query).await.unwrap();
+        assert_eq!(stats.time.len(), 2);
+        assert_eq!(stats.total("eleven_multilingual_v2"), Some(200.0));
+        assert_eq!(stats.total("eleven_flash_v2_5"), Some(40.0));
+    }
+}
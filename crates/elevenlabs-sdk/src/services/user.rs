@@ -5,6 +5,7 @@
 //! | [`get`](UserService::get) | `GET /v1/user` | Get user profile |
 //! | [`get_subscription`](UserService::get_subscription) | `GET /v1/user/subscription` | Get extended subscription info |
 //! | [`get_character_usage`](UserService::get_character_usage) | `GET /v1/usage/character-stats` | Get character usage stats |
+//! | [`remaining_quota`](UserService::remaining_quota) | `GET /v1/user/subscription` | Characters left and reset time |
 //!
 //! # Example
 //!
@@ -27,7 +28,7 @@
 use crate::{
     client::ElevenLabsClient,
     error::Result,
-    types::{ExtendedSubscriptionResponse, UsageCharactersResponse, UserResponse},
+    types::{ExtendedSubscriptionResponse, RemainingQuota, UsageCharactersResponse, UserResponse},
 };
 
 /// User service providing typed access to user profile and usage endpoints.
@@ -68,6 +69,20 @@ impl<'a> UserService<'a> {
         self.client.get("/v1/user/subscription").await
     }
 
+    /// Gets the characters left in the current billing period and when the
+    /// count resets.
+    ///
+    /// Calls `GET /v1/user/subscription`. See
+    /// [`ExtendedSubscriptionResponse::remaining_quota`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn remaining_quota(&self) -> Result<RemainingQuota> {
+        Ok(self.get_subscription().await?.remaining_quota())
+    }
+
     /// Gets character usage statistics for a time range.
     ///
     /// Calls `GET /v1/usage/character-stats`.
@@ -128,7 +143,7 @@ mod tests {
                 "subscription": {
                     "tier": "creator",
                     "character_count": 5000,
-                    "character_limit": 100000,
+                    "character_limit": 100_000,
                     "can_extend_character_limit": true,
                     "allowed_to_extend_character_limit": true,
                     "voice_slots_used": 3,
@@ -145,7 +160,7 @@ mod tests {
                 "can_use_delayed_payment_methods": false,
                 "is_onboarding_completed": true,
                 "is_onboarding_checklist_completed": true,
-                "created_at": 1700000000
+                "created_at": 1_700_000_000
             })))
             .mount(&mock_server)
             .await;
@@ -168,7 +183,7 @@ mod tests {
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
                 "tier": "creator",
                 "character_count": 5000,
-                "character_limit": 100000,
+                "character_limit": 100_000,
                 "can_extend_character_limit": true,
                 "allowed_to_extend_character_limit": true,
                 "voice_slots_used": 3,
@@ -193,6 +208,30 @@ mod tests {
         assert_eq!(sub.character_count, 5000);
     }
 
+    #[tokio::test]
+    async fn remaining_quota_computes_characters_left() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/user/subscription"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "tier": "creator",
+                "character_count": 5000,
+                "character_limit": 100_000,
+                "next_character_count_reset_unix": 1_700_086_400
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let quota = client.user().remaining_quota().await.unwrap();
+        assert_eq!(quota.characters_remaining, 95_000);
+        assert_eq!(quota.next_reset_unix, Some(1_700_086_400));
+        assert!(!quota.can_extend);
+    }
+
     #[tokio::test]
     async fn get_character_usage_returns_stats() {
         let mock_server = MockServer::start().await;
@@ -202,7 +241,7 @@ mod tests {
             .and(query_param("start_unix", "1700000000"))
             .and(query_param("end_unix", "1700100000"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "time": [1700000000, 1700050000, 1700100000],
+                "time": [1_700_000_000, 1_700_050_000, 1_700_100_000],
                 "usage": {
                     "tts": [100, 200, 150]
                 }
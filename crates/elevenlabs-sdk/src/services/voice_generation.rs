@@ -8,7 +8,12 @@
 //! |--------|----------|-------------|
 //! | [`get_parameters`](VoiceGenerationService::get_parameters) | `GET /v1/voice-generation/generate-voice/parameters` | List generation parameters |
 //! | [`generate_random`](VoiceGenerationService::generate_random) | `POST /v1/voice-generation/generate-voice` | Generate a random voice (audio bytes) |
+//! | [`generate_preview`](VoiceGenerationService::generate_preview) | `POST /v1/voice-generation/generate-voice` | Generate a random voice, keeping its ID |
 //! | [`create_voice`](VoiceGenerationService::create_voice) | `POST /v1/voice-generation/create-voice` | Create a voice from a generated preview |
+//! | [`create_from_preview`](VoiceGenerationService::create_from_preview) | `POST /v1/voice-generation/create-voice` | Save a preview under a name |
+//!
+//! Generation requests are checked against the API's text length and
+//! accent strength limits before they are sent.
 //!
 //! # Example
 //!
@@ -26,31 +31,41 @@
 //! let params = client.voice_generation().get_parameters().await?;
 //! println!("Available genders: {:?}", params.genders);
 //!
-//! // Generate a random voice
-//! let request = GenerateRandomVoiceRequest {
-//!     gender: GenerateVoiceGender::Female,
-//!     accent: "british".into(),
-//!     age: GenerateVoiceAge::Young,
-//!     accent_strength: 1.0,
-//!     text: "Every act of kindness carries value.".repeat(3),
-//! };
-//! let audio = client.voice_generation().generate_random(&request).await?;
+//! // Generate a random voice and keep it if it sounds right
+//! let request = GenerateRandomVoiceRequest::new(
+//!     GenerateVoiceGender::Female,
+//!     "british",
+//!     GenerateVoiceAge::Young,
+//!     "Every act of kindness carries value.".repeat(3),
+//! );
+//! let preview = client.voice_generation().generate_preview(&request).await?;
+//! println!("Received {} bytes of audio", preview.audio.len());
 //!
-//! println!("Received {} bytes of audio", audio.len());
+//! let voice = client
+//!     .voice_generation()
+//!     .create_from_preview(&preview.generated_voice_id, "Narrator", None)
+//!     .await?;
+//! println!("Saved voice {}", voice.voice_id);
 //! # Ok(())
 //! # }
 //! ```
 
+use std::collections::HashMap;
+
 use bytes::Bytes;
 
 use crate::{
     client::ElevenLabsClient,
-    error::Result,
+    error::{ElevenLabsError, Result},
     types::{
         CreateGeneratedVoiceRequest, GenerateRandomVoiceRequest, Voice, VoiceGenerationParameters,
+        VoicePreview,
     },
 };
 
+/// Response header carrying the ID of a generated voice.
+const GENERATED_VOICE_ID_HEADER: &str = "generated_voice_id";
+
 /// Voice generation service providing typed access to legacy voice
 /// generation endpoints.
 ///
@@ -85,8 +100,8 @@ impl<'a> VoiceGenerationService<'a> {
     /// Calls `POST /v1/voice-generation/generate-voice` with a JSON body.
     ///
     /// The generated voice ID is returned in the response headers
-    /// (`generated_voice_id`), which can be used with
-    /// [`create_voice`](Self::create_voice) to persist the voice.
+    /// (`generated_voice_id`); use [`generate_preview`](Self::generate_preview)
+    /// to keep it so the voice can be persisted later.
     ///
     /// # Arguments
     ///
@@ -95,12 +110,48 @@ impl<'a> VoiceGenerationService<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be
-    /// read.
+    /// Returns [`ElevenLabsError::Validation`] if the text is not 100–1000
+    /// characters long or the accent strength is outside 0.3–2.0, or an
+    /// error if the API request fails or the response cannot be read.
     pub async fn generate_random(&self, request: &GenerateRandomVoiceRequest) -> Result<Bytes> {
+        validate(request)?;
         self.client.post_bytes("/v1/voice-generation/generate-voice", request).await
     }
 
+    /// Generates a random voice and returns its preview audio together with
+    /// the generated voice ID.
+    ///
+    /// Calls `POST /v1/voice-generation/generate-voice` with a JSON body.
+    /// Pass [`VoicePreview::generated_voice_id`] to
+    /// [`create_from_preview`](Self::create_from_preview) to keep the voice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the request is outside the
+    /// API's limits (see [`generate_random`](Self::generate_random)),
+    /// [`ElevenLabsError::Deserialization`] if the response has no
+    /// `generated_voice_id` header, or an error if the API request fails.
+    pub async fn generate_preview(
+        &self,
+        request: &GenerateRandomVoiceRequest,
+    ) -> Result<VoicePreview> {
+        validate(request)?;
+        let (audio, headers) = self
+            .client
+            .post_bytes_with_headers("/v1/voice-generation/generate-voice", request)
+            .await?;
+        let generated_voice_id = headers
+            .get(GENERATED_VOICE_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                ElevenLabsError::Deserialization(serde::de::Error::custom(
+                    "response has no generated_voice_id header",
+                ))
+            })?;
+        Ok(VoicePreview { generated_voice_id, audio })
+    }
+
     /// Creates a persistent voice from a previously generated voice
     /// preview.
     ///
@@ -119,6 +170,56 @@ impl<'a> VoiceGenerationService<'a> {
     pub async fn create_voice(&self, request: &CreateGeneratedVoiceRequest) -> Result<Voice> {
         self.client.post("/v1/voice-generation/create-voice", request).await
     }
+
+    /// Saves a generated voice preview as a voice named `name`.
+    ///
+    /// A shorthand for [`create_voice`](Self::create_voice) with an empty
+    /// description; use that method to set a description or the voices
+    /// that were played but not selected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn create_from_preview(
+        &self,
+        generated_voice_id: &str,
+        name: &str,
+        labels: Option<HashMap<String, String>>,
+    ) -> Result<Voice> {
+        let request = CreateGeneratedVoiceRequest {
+            voice_name: name.to_owned(),
+            voice_description: String::new(),
+            generated_voice_id: generated_voice_id.to_owned(),
+            played_not_selected_voice_ids: None,
+            labels,
+        };
+        self.create_voice(&request).await
+    }
+}
+
+/// Checks `request` against the limits the API enforces.
+fn validate(request: &GenerateRandomVoiceRequest) -> Result<()> {
+    let chars = request.text.chars().count();
+    let (min_chars, max_chars) =
+        (GenerateRandomVoiceRequest::MIN_TEXT_CHARS, GenerateRandomVoiceRequest::MAX_TEXT_CHARS);
+    if !(min_chars..=max_chars).contains(&chars) {
+        return Err(ElevenLabsError::Validation(format!(
+            "text must be between {min_chars} and {max_chars} characters, got {chars}"
+        )));
+    }
+
+    let (min_strength, max_strength) = (
+        GenerateRandomVoiceRequest::MIN_ACCENT_STRENGTH,
+        GenerateRandomVoiceRequest::MAX_ACCENT_STRENGTH,
+    );
+    if !(min_strength..=max_strength).contains(&request.accent_strength) {
+        return Err(ElevenLabsError::Validation(format!(
+            "accent_strength must be between {min_strength} and {max_strength}, got {}",
+            request.accent_strength
+        )));
+    }
+    Ok(())
 }
 
 // ---------------------------------------------------------------------------
@@ -130,11 +231,11 @@ impl<'a> VoiceGenerationService<'a> {
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path},
+        matchers::{body_json, header, method, path},
     };
 
     use crate::{
-        ElevenLabsClient,
+        ElevenLabsClient, ElevenLabsError,
         config::ClientConfig,
         types::{
             CreateGeneratedVoiceRequest, GenerateRandomVoiceRequest, GenerateVoiceAge,
@@ -277,10 +378,145 @@ mod tests {
             accent: "american".into(),
             age: GenerateVoiceAge::MiddleAged,
             accent_strength: 1.0,
-            text: "short".into(),
+            text: "x".repeat(150),
         };
         let result = client.voice_generation().generate_random(&request).await;
 
-        assert!(result.is_err());
+        assert!(matches!(result, Err(ElevenLabsError::Api { status: 422, .. })));
+    }
+
+    #[tokio::test]
+    async fn generate_random_rejects_out_of_range_request_locally() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/voice-generation/generate-voice"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let request = |text: String| {
+            GenerateRandomVoiceRequest::new(
+                GenerateVoiceGender::Female,
+                "british",
+                GenerateVoiceAge::Young,
+                text,
+            )
+        };
+
+        let short = client.voice_generation().generate_random(&request("x".repeat(99))).await;
+        assert!(
+            matches!(short, Err(ElevenLabsError::Validation(ref msg)) if msg.contains("got 99"))
+        );
+        let long = client.voice_generation().generate_preview(&request("x".repeat(1001))).await;
+        assert!(matches!(long, Err(ElevenLabsError::Validation(_))));
+        let strength = client
+            .voice_generation()
+            .generate_random(&request("x".repeat(100)).accent_strength(2.5))
+            .await;
+        assert!(
+            matches!(strength, Err(ElevenLabsError::Validation(ref msg)) if msg.contains("accent_strength"))
+        );
+    }
+
+    // -- generate_preview --------------------------------------------------
+
+    #[tokio::test]
+    async fn generate_preview_keeps_generated_voice_id() {
+        let mock_server = MockServer::start().await;
+        let audio_bytes: &[u8] = b"\xff\xfb\x90\x00preview";
+
+        Mock::given(method("POST"))
+            .and(path("/v1/voice-generation/generate-voice"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("generated_voice_id", "gen-789")
+                    .set_body_raw(audio_bytes, "audio/mpeg"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        // Multi-byte characters count once each towards the limit.
+        let request = GenerateRandomVoiceRequest::new(
+            GenerateVoiceGender::Male,
+            "american",
+            GenerateVoiceAge::Old,
+            "é".repeat(100),
+        );
+        let preview = client.voice_generation().generate_preview(&request).await.unwrap();
+
+        assert_eq!(preview.generated_voice_id, "gen-789");
+        assert_eq!(preview.audio.as_ref(), audio_bytes);
+    }
+
+    #[tokio::test]
+    async fn generate_preview_requires_generated_voice_id_header() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/voice-generation/generate-voice"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(&b"audio"[..], "audio/mpeg"))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let request = GenerateRandomVoiceRequest::new(
+            GenerateVoiceGender::Female,
+            "british",
+            GenerateVoiceAge::Young,
+            "x".repeat(100),
+        );
+        let result = client.voice_generation().generate_preview(&request).await;
+
+        assert!(matches!(result, Err(ElevenLabsError::Deserialization(_))));
+    }
+
+    // -- create_from_preview -----------------------------------------------
+
+    #[tokio::test]
+    async fn create_from_preview_sends_name_and_labels() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/voice-generation/create-voice"))
+            .and(body_json(serde_json::json!({
+                "voice_name": "Narrator",
+                "voice_description": "",
+                "generated_voice_id": "gen-789",
+                "labels": {"accent": "american"}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "voice_id": "v-saved",
+                "name": "Narrator",
+                "category": "generated",
+                "labels": {"accent": "american"},
+                "available_for_tiers": [],
+                "high_quality_base_model_ids": [],
+                "is_legacy": false,
+                "is_mixed": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let labels = std::collections::HashMap::from([("accent".into(), "american".into())]);
+        let voice = client
+            .voice_generation()
+            .create_from_preview("gen-789", "Narrator", Some(labels))
+            .await
+            .unwrap();
+
+        assert_eq!(voice.voice_id, "v-saved");
     }
 }
@@ -6,15 +6,21 @@
 //! | Method | Endpoint | Description |
 //! |--------|----------|-------------|
 //! | [`list`](VoicesService::list) | `GET /v1/voices` | List all voices |
+//! | [`list_by_language`](VoicesService::list_by_language) | `GET /v1/voices` | List voices that speak a language |
 //! | [`get`](VoicesService::get) | `GET /v1/voices/{voice_id}` | Get a single voice |
 //! | [`get_default_settings`](VoicesService::get_default_settings) | `GET /v1/voices/settings/default` | Get default voice settings |
 //! | [`get_settings`](VoicesService::get_settings) | `GET /v1/voices/{voice_id}/settings` | Get voice settings |
+//! | [`default_settings_for`](VoicesService::default_settings_for) | `GET /v1/voices/{voice_id}/settings` | Get voice settings, cached on the client |
 //! | [`edit_settings`](VoicesService::edit_settings) | `POST /v1/voices/{voice_id}/settings/edit` | Edit voice settings |
 //! | [`add`](VoicesService::add) | `POST /v1/voices/add` | Add a new voice (multipart) |
+//! | [`add_voice_from_urls`](VoicesService::add_voice_from_urls) | `POST /v1/voices/add` | Add a new voice from remote samples |
 //! | [`edit`](VoicesService::edit) | `POST /v1/voices/{voice_id}/edit` | Edit a voice (multipart) |
 //! | [`delete`](VoicesService::delete) | `DELETE /v1/voices/{voice_id}` | Delete a voice |
 //! | [`add_sharing`](VoicesService::add_sharing) | `POST /v1/voices/add/{public_user_id}/{voice_id}` | Add a shared voice |
+//! | [`list_samples`](VoicesService::list_samples) | `GET /v1/voices/{voice_id}` | List a voice's samples |
+//! | [`add_sample`](VoicesService::add_sample) | `POST /v1/voices/{voice_id}/edit` | Upload another sample |
 //! | [`get_sample_audio`](VoicesService::get_sample_audio) | `GET /v1/voices/{voice_id}/samples/{sample_id}/audio` | Get sample audio |
+//! | [`get_sample_audio_stream`](VoicesService::get_sample_audio_stream) | `GET /v1/voices/{voice_id}/samples/{sample_id}/audio` | Stream sample audio |
 //! | [`delete_sample`](VoicesService::delete_sample) | `DELETE /v1/voices/{voice_id}/samples/{sample_id}` | Delete a sample |
 //!
 //! # Example
@@ -35,18 +41,32 @@
 //! # }
 //! ```
 
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
 use bytes::Bytes;
+use futures_util::{Stream, StreamExt, stream};
+use serde::Deserialize;
 
 use crate::{
+    audio,
     client::ElevenLabsClient,
-    error::Result,
+    error::{ElevenLabsError, Result},
     types::{
         AddVoiceRequest, AddVoiceResponse, DeleteVoiceResponse, DeleteVoiceSampleResponse,
         EditVoiceRequest, EditVoiceResponse, EditVoiceSettingsResponse, GetLibraryVoicesResponse,
-        GetSimilarVoicesResponse, GetVoicesResponse, GetVoicesV2Response, Voice, VoiceSettings,
+        GetSimilarVoicesResponse, GetVoicesResponse, GetVoicesV2Response, LocalizedVoice,
+        RemoteSampleOptions, SampleTransfer, SimilarLibraryVoice, Voice, VoiceSample,
+        VoiceSettings,
     },
+    upload::{self, FileInput, StreamedPart},
 };
 
+/// Most samples accepted by [`VoicesService::add_voice_from_urls`].
+const MAX_VOICE_SAMPLES: usize = 25;
+
 /// Voices service providing typed access to voice management endpoints.
 ///
 /// Obtained via [`ElevenLabsClient::voices`].
@@ -81,6 +101,35 @@ impl<'a> VoicesService<'a> {
         self.client.get(&path).await
     }
 
+    /// Lists the voices that speak `lang_code`, such as `"es"` or `"pt-BR"`.
+    ///
+    /// Calls `GET /v1/voices` and keeps the voices for which
+    /// [`Voice::language`] finds a match, merging verified languages,
+    /// fine-tuning and labels. Voices verified in the language come first;
+    /// otherwise the API order is kept. Voices that report no language at
+    /// all are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if `lang_code` is empty, or
+    /// an error if the API request fails.
+    pub async fn list_by_language(&self, lang_code: &str) -> Result<Vec<LocalizedVoice>> {
+        if lang_code.trim().is_empty() {
+            return Err(ElevenLabsError::Validation("language code must not be empty".into()));
+        }
+        let response = self.list(None).await?;
+        let mut voices: Vec<LocalizedVoice> = response
+            .voices
+            .into_iter()
+            .filter_map(|voice| {
+                let language = voice.language(lang_code)?;
+                Some(LocalizedVoice { language, voice })
+            })
+            .collect();
+        voices.sort_by_key(|voice| !voice.language.is_verified());
+        Ok(voices)
+    }
+
     /// Gets a single voice by ID.
     ///
     /// Calls `GET /v1/voices/{voice_id}`.
@@ -131,6 +180,38 @@ impl<'a> VoicesService<'a> {
         self.client.get(&path).await
     }
 
+    /// Gets the settings for a specific voice, caching them on the client.
+    ///
+    /// The first call for a voice calls `GET /v1/voices/{voice_id}/settings`;
+    /// later calls, including through scoped clients, return the cached
+    /// settings. [`edit_settings`](Self::edit_settings) updates the cached
+    /// entry and [`forget_settings`](Self::forget_settings) drops it.
+    ///
+    /// # Arguments
+    ///
+    /// * `voice_id` — The voice ID whose settings to retrieve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings are not cached and the API request
+    /// fails or the response cannot be deserialized.
+    pub async fn default_settings_for(&self, voice_id: &str) -> Result<VoiceSettings> {
+        let cache = self.client.voice_settings_cache();
+        if let Some(settings) = cache.read_async(voice_id, |_, settings| settings.clone()).await {
+            return Ok(settings);
+        }
+        let settings = self.get_settings(voice_id).await?;
+        cache.upsert_async(voice_id.to_owned(), settings.clone()).await;
+        Ok(settings)
+    }
+
+    /// Drops the cached settings for `voice_id`, so the next
+    /// [`default_settings_for`](Self::default_settings_for) call fetches them
+    /// again.
+    pub fn forget_settings(&self, voice_id: &str) {
+        self.client.voice_settings_cache().remove_sync(voice_id);
+    }
+
     /// Edits the settings for a specific voice.
     ///
     /// Calls `POST /v1/voices/{voice_id}/settings/edit`.
@@ -150,7 +231,12 @@ impl<'a> VoicesService<'a> {
         settings: &VoiceSettings,
     ) -> Result<EditVoiceSettingsResponse> {
         let path = format!("/v1/voices/{voice_id}/settings/edit");
-        self.client.post(&path, settings).await
+        let response = self.client.post(&path, settings).await?;
+        self.client
+            .voice_settings_cache()
+            .update_async(voice_id, |_, cached| cached.clone_from(settings))
+            .await;
+        Ok(response)
     }
 
     /// Adds a new voice.
@@ -180,6 +266,156 @@ impl<'a> VoicesService<'a> {
         self.client.post_multipart("/v1/voices/add", body, &content_type).await
     }
 
+    /// Adds a new instant voice clone from samples at remote URLs.
+    ///
+    /// Calls `POST /v1/voices/add` after fetching each sample, with the
+    /// default [`RemoteSampleOptions`]. See
+    /// [`add_voice_from_urls_with`](Self::add_voice_from_urls_with).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a sample cannot be fetched or fails validation,
+    /// or if the API request fails.
+    pub async fn add_voice_from_urls(
+        &self,
+        name: &str,
+        urls: &[&str],
+        labels: Option<HashMap<String, String>>,
+    ) -> Result<AddVoiceResponse> {
+        self.add_voice_from_urls_with(name, urls, labels, &RemoteSampleOptions::default()).await
+    }
+
+    /// Adds a new instant voice clone from samples at remote URLs.
+    ///
+    /// The endpoint only accepts uploaded files, so the SDK fetches each
+    /// sample and uploads it, without the API key being sent to the sample
+    /// hosts. Every sample must be MP3, WAV, FLAC, Ogg, AAC, MP4 or WebM
+    /// audio, recognized from its first bytes, and no larger than
+    /// [`max_sample_bytes`](RemoteSampleOptions::max_sample_bytes).
+    ///
+    /// With [`SampleTransfer::Buffered`] the samples are downloaded before
+    /// anything is uploaded, and their total duration is checked against
+    /// [`min_total_duration`](RemoteSampleOptions::min_total_duration). With
+    /// [`SampleTransfer::Streamed`] each download is piped into the upload, so
+    /// a sample that turns out too large fails the upload part-way.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if no or more than 25 URLs are
+    /// given, a URL is not `http` or `https`, a sample cannot be fetched or
+    /// fails validation, and an error if the API request fails.
+    pub async fn add_voice_from_urls_with(
+        &self,
+        name: &str,
+        urls: &[&str],
+        labels: Option<HashMap<String, String>>,
+        options: &RemoteSampleOptions,
+    ) -> Result<AddVoiceResponse> {
+        if urls.is_empty() || urls.len() > MAX_VOICE_SAMPLES {
+            return Err(ElevenLabsError::Validation(format!(
+                "between 1 and {MAX_VOICE_SAMPLES} sample URLs are required, got {}",
+                urls.len()
+            )));
+        }
+        let urls = urls.iter().map(|url| parse_sample_url(url)).collect::<Result<Vec<_>>>()?;
+        let request = AddVoiceRequest {
+            name: name.to_owned(),
+            description: options.description.clone(),
+            labels,
+        };
+        let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
+        let content_type = format!("multipart/form-data; boundary={boundary}");
+
+        match options.transfer {
+            SampleTransfer::Buffered => {
+                let mut samples = Vec::with_capacity(urls.len());
+                for url in &urls {
+                    samples.push(self.fetch_sample(url, options.max_sample_bytes).await?);
+                }
+                let durations: Vec<Duration> =
+                    samples.iter().filter_map(|sample| sample.duration).collect();
+                let total: Duration = durations.iter().sum();
+                if let Some(min) = options.min_total_duration &&
+                    !durations.is_empty() &&
+                    total < min
+                {
+                    return Err(ElevenLabsError::Validation(format!(
+                        "samples last {:.1} s, at least {:.1} s is required",
+                        total.as_secs_f64(),
+                        min.as_secs_f64()
+                    )));
+                }
+                let files: Vec<(&str, &str, &[u8])> = samples
+                    .iter()
+                    .map(|sample| (sample.filename.as_str(), sample.content_type, &sample.data[..]))
+                    .collect();
+                let body = build_add_voice_multipart(&boundary, &request, &files);
+                self.client.post_multipart("/v1/voices/add", body, &content_type).await
+            }
+            SampleTransfer::Streamed => {
+                let mut parts = Vec::with_capacity(urls.len());
+                for url in &urls {
+                    parts.push(self.stream_sample(url, options.max_sample_bytes).await?);
+                }
+                let body = upload::multipart_parts_body(
+                    &boundary,
+                    "files",
+                    &add_voice_fields(&request),
+                    parts,
+                );
+                self.client.post_multipart("/v1/voices/add", body, &content_type).await
+            }
+        }
+    }
+
+    /// Downloads the sample at `url` and checks its format.
+    async fn fetch_sample(&self, url: &url::Url, max_bytes: u64) -> Result<RemoteSample> {
+        let response = self.client.fetch_remote(url).await?;
+        check_sample_len(url, response_len(&response), max_bytes)?;
+        let mut stream = response.bytes_stream();
+        let mut data = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            data.extend_from_slice(&chunk.map_err(ElevenLabsError::Transport)?);
+            check_sample_len(url, Some(data.len() as u64), max_bytes)?;
+        }
+        let content_type = sample_content_type(url, &data)?;
+        let duration = match content_type {
+            "audio/wav" => audio::wav_duration(&data),
+            "audio/mpeg" => audio::mp3_duration(&data),
+            _ => None,
+        };
+        Ok(RemoteSample { filename: sample_filename(url), content_type, data, duration })
+    }
+
+    /// Starts downloading the sample at `url`, checking its format from the
+    /// first chunk, and returns it as an upload part.
+    async fn stream_sample(&self, url: &url::Url, max_bytes: u64) -> Result<StreamedPart> {
+        let response = self.client.fetch_remote(url).await?;
+        check_sample_len(url, response_len(&response), max_bytes)?;
+        let mut stream = response.bytes_stream();
+        let first = stream.next().await.transpose().map_err(ElevenLabsError::Transport)?;
+        let first = first.unwrap_or_default();
+        let content_type = sample_content_type(url, &first)?;
+
+        let limit_url = url.clone();
+        let mut received = 0_u64;
+        let body = stream::once(async move { Ok(first) })
+            .chain(stream)
+            .map(move |chunk| {
+                let chunk = chunk.map_err(std::io::Error::other)?;
+                received += chunk.len() as u64;
+                check_sample_len(&limit_url, Some(received), max_bytes)
+                    .map_err(std::io::Error::other)?;
+                Ok(chunk)
+            })
+            .boxed();
+        Ok(StreamedPart {
+            filename: sample_filename(url),
+            content_type: content_type.to_owned(),
+            body,
+        })
+    }
+
     /// Edits an existing voice.
     ///
     /// Calls `POST /v1/voices/{voice_id}/edit` with `multipart/form-data`.
@@ -251,6 +487,58 @@ impl<'a> VoicesService<'a> {
         self.client.post(&path, &Body { new_name }).await
     }
 
+    /// Lists the samples of a voice.
+    ///
+    /// Calls `GET /v1/voices/{voice_id}` and returns its samples. A voice
+    /// without samples, such as a premade voice, gives an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be
+    /// deserialized.
+    pub async fn list_samples(&self, voice_id: &str) -> Result<Vec<VoiceSample>> {
+        Ok(self.get(voice_id, None).await?.samples.unwrap_or_default())
+    }
+
+    /// Uploads another sample to an instant voice clone.
+    ///
+    /// Calls `POST /v1/voices/{voice_id}/edit` with the voice's current name,
+    /// description and labels and `input` as a new file, then fetches the
+    /// voice again and returns the sample that was added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an API request fails, a file input cannot be
+    /// found, or the voice has no new sample after the upload.
+    pub async fn add_sample(&self, voice_id: &str, input: FileInput) -> Result<VoiceSample> {
+        let voice = self.get(voice_id, None).await?;
+        let existing: HashSet<String> =
+            voice.samples.iter().flatten().map(|sample| sample.sample_id.clone()).collect();
+
+        let mut fields = vec![("name", voice.name)];
+        if let Some(description) = voice.description {
+            fields.push(("description", description));
+        }
+        if !voice.labels.is_empty() {
+            fields.push(("labels", serde_json::to_string(&voice.labels)?));
+        }
+        let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
+        let body = upload::multipart_body(&boundary, "files", input, &fields).await?;
+        let content_type = format!("multipart/form-data; boundary={boundary}");
+        let path = format!("/v1/voices/{voice_id}/edit");
+        let _: EditVoiceResponse = self.client.post_multipart(&path, body, &content_type).await?;
+
+        self.list_samples(voice_id)
+            .await?
+            .into_iter()
+            .find(|sample| !existing.contains(&sample.sample_id))
+            .ok_or_else(|| {
+                ElevenLabsError::Validation(format!(
+                    "voice {voice_id} has no new sample after the upload"
+                ))
+            })
+    }
+
     /// Gets the audio data for a specific voice sample.
     ///
     /// Calls `GET /v1/voices/{voice_id}/samples/{sample_id}/audio`.
@@ -270,6 +558,23 @@ impl<'a> VoicesService<'a> {
         self.client.get_bytes(&path).await
     }
 
+    /// Streams the audio for a voice sample.
+    ///
+    /// Calls `GET /v1/voices/{voice_id}/samples/{sample_id}/audio` and yields
+    /// the audio in chunks as it arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails.
+    pub async fn get_sample_audio_stream(
+        &self,
+        voice_id: &str,
+        sample_id: &str,
+    ) -> Result<impl Stream<Item = std::result::Result<Bytes, hpx::Error>> + use<>> {
+        let path = format!("/v1/voices/{voice_id}/samples/{sample_id}/audio");
+        self.client.get_stream(&path).await
+    }
+
     /// Deletes a voice sample.
     ///
     /// Calls `DELETE /v1/voices/{voice_id}/samples/{sample_id}`.
@@ -404,6 +709,50 @@ impl<'a> VoicesService<'a> {
         self.client.post_multipart("/v1/similar-voices", body, &content_type).await
     }
 
+    /// Finds library voices similar to an audio sample, closest first.
+    ///
+    /// Calls `POST /v1/similar-voices` with `audio` streamed from memory or
+    /// disk (see [`FileInput`]), and numbers the matches from 1 in the
+    /// order the API ranks them.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio` — The reference audio sample.
+    /// * `similarity_threshold` — How far a voice may be from the sample (0.0–2.0); lower values
+    ///   return only closer matches.
+    /// * `top_k` — Maximum number of matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sample file cannot be read or the API request
+    /// fails.
+    pub async fn find_similar(
+        &self,
+        audio: FileInput,
+        similarity_threshold: Option<f64>,
+        top_k: Option<u32>,
+    ) -> Result<Vec<SimilarLibraryVoice>> {
+        let mut fields = Vec::new();
+        if let Some(v) = similarity_threshold {
+            fields.push(("similarity_threshold", v.to_string()));
+        }
+        if let Some(v) = top_k {
+            fields.push(("top_k", v.to_string()));
+        }
+        let boundary = format!("----ElevenLabsSDK{}", uuid_v4_simple());
+        let body = upload::multipart_body(&boundary, "audio_file", audio, &fields).await?;
+        let content_type = format!("multipart/form-data; boundary={boundary}");
+        let page: SimilarVoicesPage =
+            self.client.post_multipart("/v1/similar-voices", body, &content_type).await?;
+
+        Ok(page
+            .voices
+            .into_iter()
+            .enumerate()
+            .map(|(i, voice)| SimilarLibraryVoice { rank: i + 1, ..voice })
+            .collect())
+    }
+
     /// Lists voices using the v2 API with pagination.
     ///
     /// Calls `GET /v2/voices`.
@@ -497,6 +846,80 @@ pub(crate) fn append_file_part(
     buf.extend_from_slice(b"\r\n");
 }
 
+/// Response of `POST /v1/similar-voices` as read by
+/// [`VoicesService::find_similar`].
+#[derive(Deserialize)]
+struct SimilarVoicesPage {
+    #[serde(default)]
+    voices: Vec<SimilarLibraryVoice>,
+}
+
+/// A sample downloaded by [`VoicesService::add_voice_from_urls_with`].
+struct RemoteSample {
+    filename: String,
+    content_type: &'static str,
+    data: Vec<u8>,
+    duration: Option<Duration>,
+}
+
+/// Parses a sample URL, accepting only `http` and `https`.
+fn parse_sample_url(url: &str) -> Result<url::Url> {
+    let parsed = url::Url::parse(url)?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(ElevenLabsError::Validation(format!("sample URL {url} must use http or https")));
+    }
+    Ok(parsed)
+}
+
+/// Returns the `Content-Length` of `response`, if sent.
+fn response_len(response: &hpx::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(hpx::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+}
+
+/// Fails if a sample of `len` bytes exceeds `max_bytes`.
+fn check_sample_len(url: &url::Url, len: Option<u64>, max_bytes: u64) -> Result<()> {
+    match len {
+        Some(len) if len > max_bytes => Err(ElevenLabsError::Validation(format!(
+            "sample {url} is larger than {max_bytes} bytes"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the content type of a sample from its first bytes.
+fn sample_content_type(url: &url::Url, data: &[u8]) -> Result<&'static str> {
+    audio::sniff_content_type(data).ok_or_else(|| {
+        ElevenLabsError::Validation(format!("sample {url} is not a supported audio format"))
+    })
+}
+
+/// Returns the last path segment of `url`, or `sample` if it has none.
+fn sample_filename(url: &url::Url) -> String {
+    url.path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|segment| !segment.is_empty())
+        .unwrap_or("sample")
+        .to_owned()
+}
+
+/// Returns the text fields of a `POST /v1/voices/add` request.
+fn add_voice_fields(request: &AddVoiceRequest) -> Vec<(&'static str, String)> {
+    let mut fields = vec![("name", request.name.clone())];
+    if let Some(ref desc) = request.description {
+        fields.push(("description", desc.clone()));
+    }
+    if let Some(ref labels) = request.labels &&
+        let Ok(json) = serde_json::to_string(labels)
+    {
+        fields.push(("labels", json));
+    }
+    fields
+}
+
 /// Builds the multipart body for `POST /v1/voices/add`.
 fn build_add_voice_multipart(
     boundary: &str,
@@ -505,16 +928,8 @@ fn build_add_voice_multipart(
 ) -> Vec<u8> {
     let mut buf = Vec::new();
 
-    append_text_field(&mut buf, boundary, "name", &request.name);
-
-    if let Some(ref desc) = request.description {
-        append_text_field(&mut buf, boundary, "description", desc);
-    }
-
-    if let Some(ref labels) = request.labels &&
-        let Ok(json) = serde_json::to_string(labels)
-    {
-        append_text_field(&mut buf, boundary, "labels", &json);
+    for (name, value) in add_voice_fields(request) {
+        append_text_field(&mut buf, boundary, name, &value);
     }
 
     for (filename, content_type, data) in files {
@@ -565,6 +980,7 @@ mod tests {
         matchers::{header, method, path, query_param},
     };
 
+    use super::*;
     use crate::{
         ElevenLabsClient,
         config::ClientConfig,
@@ -623,6 +1039,52 @@ mod tests {
         assert!(result.voices.is_empty());
     }
 
+    // -- list_by_language --------------------------------------------------
+
+    #[tokio::test]
+    async fn list_by_language_filters_and_puts_verified_first() {
+        let mock_server = MockServer::start().await;
+
+        let voice = |id: &str, extra: serde_json::Value| {
+            let mut voice = serde_json::json!({
+                "voice_id": id,
+                "name": id,
+                "category": "professional"
+            });
+            voice.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+            voice
+        };
+        Mock::given(method("GET"))
+            .and(path("/v1/voices"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "voices": [
+                    voice("labelled", serde_json::json!({"labels": {"language": "es"}})),
+                    voice("german", serde_json::json!({"labels": {"language": "de"}})),
+                    voice("unknown", serde_json::json!({})),
+                    voice("verified", serde_json::json!({
+                        "verified_languages": [
+                            {"language": "es", "model_id": "eleven_multilingual_v2", "locale": "es-MX"}
+                        ]
+                    })),
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let voices = client.voices().list_by_language("es").await.unwrap();
+        let ids: Vec<&str> = voices.iter().map(|v| v.voice.voice_id.as_str()).collect();
+        assert_eq!(ids, ["verified", "labelled"]);
+        assert_eq!(voices[0].language.locale.as_deref(), Some("es-MX"));
+        assert!(!voices[1].language.is_verified());
+
+        let err = client.voices().list_by_language(" ").await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+    }
+
     // -- get ---------------------------------------------------------------
 
     #[tokio::test]
@@ -707,6 +1169,48 @@ mod tests {
         assert_eq!(settings.speed, Some(1.0));
     }
 
+    // -- default_settings_for ----------------------------------------------
+
+    #[tokio::test]
+    async fn default_settings_for_caches_per_voice() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice123/settings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "stability": 0.8,
+                "similarity_boost": 0.9
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/voice123/settings/edit"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let voices = client.voices();
+
+        let first = voices.default_settings_for("voice123").await.unwrap();
+        let scoped = client.with_options(crate::RequestOptions::new());
+        let second = scoped.voices().default_settings_for("voice123").await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.stability, Some(0.8));
+
+        let edited = VoiceSettings { stability: Some(0.3), ..first };
+        voices.edit_settings("voice123", &edited).await.unwrap();
+        assert_eq!(voices.default_settings_for("voice123").await.unwrap(), edited);
+
+        voices.forget_settings("voice123");
+        let refetched = voices.default_settings_for("voice123").await.unwrap();
+        assert_eq!(refetched.stability, Some(0.8));
+    }
+
     // -- get_settings ------------------------------------------------------
 
     #[tokio::test]
@@ -831,6 +1335,96 @@ mod tests {
         assert_eq!(result.voice_id, "new_voice_456");
     }
 
+    // -- add_voice_from_urls ------------------------------------------------
+
+    #[tokio::test]
+    async fn add_voice_from_urls_uploads_fetched_samples() {
+        let mock_server = MockServer::start().await;
+        let wav = crate::audio::pcm_to_wav(&vec![0; 160_000], 8000, 1).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/samples/take1.wav"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(wav.to_vec()))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/add"))
+            .and(header("xi-api-key", "test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "voice_id": "remote_voice"
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let url = format!("{}/samples/take1.wav", mock_server.uri());
+
+        let result = client.voices().add_voice_from_urls("Remote", &[&url], None).await.unwrap();
+        assert_eq!(result.voice_id, "remote_voice");
+
+        let streamed =
+            RemoteSampleOptions { transfer: SampleTransfer::Streamed, ..Default::default() };
+        let labels = HashMap::from([("accent".to_owned(), "British".to_owned())]);
+        client
+            .voices()
+            .add_voice_from_urls_with("Remote", &[&url], Some(labels), &streamed)
+            .await
+            .unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let fetches: Vec<_> = requests.iter().filter(|r| r.method.as_str() == "GET").collect();
+        assert_eq!(fetches.len(), 2);
+        assert!(fetches.iter().all(|r| !r.headers.contains_key("xi-api-key")));
+        for upload in requests.iter().filter(|r| r.method.as_str() == "POST") {
+            let body = String::from_utf8_lossy(&upload.body);
+            assert!(body.contains("filename=\"take1.wav\"\r\nContent-Type: audio/wav"));
+            assert!(upload.body.windows(4).any(|w| w == b"RIFF"));
+        }
+        assert!(String::from_utf8_lossy(&requests[3].body).contains("British"));
+    }
+
+    #[tokio::test]
+    async fn add_voice_from_urls_validates_samples() {
+        let mock_server = MockServer::start().await;
+        let short = crate::audio::pcm_to_wav(&vec![0; 16_000], 8000, 1).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/page.html"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<!DOCTYPE html>"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/short.wav"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(short.to_vec()))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/add"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let voices = client.voices();
+        let html = format!("{}/page.html", mock_server.uri());
+        let short = format!("{}/short.wav", mock_server.uri());
+        let tiny = RemoteSampleOptions { max_sample_bytes: 1000, ..Default::default() };
+
+        for result in [
+            voices.add_voice_from_urls("V", &[], None).await,
+            voices.add_voice_from_urls("V", &["file:///etc/passwd"], None).await,
+            voices.add_voice_from_urls("V", &[&html], None).await,
+            voices.add_voice_from_urls("V", &[&short], None).await,
+            voices.add_voice_from_urls_with("V", &[&short], None, &tiny).await,
+        ] {
+            assert!(matches!(result, Err(ElevenLabsError::Validation(_))), "{result:?}");
+        }
+    }
+
     // -- edit --------------------------------------------------------------
 
     #[tokio::test]
@@ -904,6 +1498,123 @@ mod tests {
         assert_eq!(result.as_ref(), audio_data);
     }
 
+    #[tokio::test]
+    async fn get_sample_audio_stream_yields_chunks() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice123/samples/sample456/audio"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(b"fake-sample-audio".as_slice(), "audio/mpeg"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let stream =
+            client.voices().get_sample_audio_stream("voice123", "sample456").await.unwrap();
+        let chunks: Vec<Bytes> = stream.map(|chunk| chunk.unwrap()).collect().await;
+        assert_eq!(chunks.concat(), b"fake-sample-audio");
+    }
+
+    // -- add_sample --------------------------------------------------------
+
+    fn voice_with_samples(sample_ids: &[&str]) -> serde_json::Value {
+        let samples: Vec<_> = sample_ids
+            .iter()
+            .map(|id| serde_json::json!({"sample_id": id, "file_name": format!("{id}.mp3")}))
+            .collect();
+        serde_json::json!({
+            "voice_id": "voice123",
+            "name": "Clone",
+            "category": "cloned",
+            "labels": {"accent": "British"},
+            "description": "Narration",
+            "samples": samples
+        })
+    }
+
+    #[tokio::test]
+    async fn add_sample_uploads_file_and_returns_new_sample() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(voice_with_samples(&["s1"])))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/voices/voice123"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(voice_with_samples(&["s1", "s2"])),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/voices/voice123/edit"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let input = FileInput::from_bytes(&b"new-take"[..], "s2.mp3", "audio/mpeg");
+        let sample = client.voices().add_sample("voice123", input).await.unwrap();
+        assert_eq!(sample.sample_id, "s2");
+        assert_eq!(sample.file_name, "s2.mp3");
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let edit = requests.iter().find(|r| r.method.as_str() == "POST").unwrap();
+        let body = String::from_utf8_lossy(&edit.body);
+        assert!(body.contains("name=\"files\"; filename=\"s2.mp3\""));
+        assert!(body.contains("new-take"));
+        assert!(body.contains("name=\"name\"\r\n\r\nClone\r\n"));
+        assert!(body.contains("name=\"description\"\r\n\r\nNarration\r\n"));
+    }
+
+    // -- find_similar ------------------------------------------------------
+
+    #[tokio::test]
+    async fn find_similar_uploads_sample_and_ranks_matches() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/similar-voices"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "voices": [
+                    {"public_owner_id": "o1", "voice_id": "close", "similarity_score": 0.2},
+                    {"public_owner_id": "o2", "voice_id": "far", "similarity_score": 0.9}
+                ],
+                "has_more": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let input = FileInput::from_bytes(&b"reference"[..], "sample.wav", "audio/wav");
+        let matches = client.voices().find_similar(input, Some(1.5), Some(5)).await.unwrap();
+        let ranked: Vec<_> = matches.iter().map(|m| (m.rank, m.voice.voice_id.as_str())).collect();
+        assert_eq!(ranked, [(1, "close"), (2, "far")]);
+        assert_eq!(matches[0].similarity_score, Some(0.2));
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("name=\"audio_file\"; filename=\"sample.wav\""));
+        assert!(body.contains("name=\"similarity_threshold\"\r\n\r\n1.5\r\n"));
+        assert!(body.contains("name=\"top_k\"\r\n\r\n5\r\n"));
+    }
+
     // -- delete_sample -----------------------------------------------------
 
     #[tokio::test]
@@ -26,9 +26,10 @@ use crate::{
         DeleteInviteRequest, EditServiceAccountApiKeyRequest, InviteBulkRequest,
         InviteWorkspaceMemberRequest, RemoveGroupMemberRequest, ResourceMetadataResponse,
         SearchGroupsResponse, ShareWorkspaceResourceRequest, UnshareWorkspaceResourceRequest,
-        UpdateWorkspaceMemberRequest, UpdateWorkspaceWebhookRequest, WorkspaceApiKeyList,
-        WorkspaceCreateApiKeyResponse, WorkspaceCreateWebhookResponse, WorkspaceServiceAccountList,
-        WorkspaceStatusResponse, WorkspaceWebhookList,
+        UpdateWorkspaceMemberRequest, UpdateWorkspaceWebhookRequest, WorkspaceApiKey,
+        WorkspaceApiKeyList, WorkspaceCreateApiKeyResponse, WorkspaceCreateWebhookResponse,
+        WorkspaceResourceType, WorkspaceServiceAccountList, WorkspaceStatusResponse,
+        WorkspaceWebhookList,
     },
 };
 
@@ -107,6 +108,25 @@ impl<'a> WorkspaceService<'a> {
         self.client.patch(&path, request).await
     }
 
+    /// Enables or disables a service account API key.
+    ///
+    /// Convenience wrapper around [`Self::edit_service_account_api_key`]
+    /// that keeps the key's current name, permissions and character limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`](crate::error::ElevenLabsError::Validation)
+    /// if the key was reported without permissions, or an error if the API
+    /// request fails.
+    pub async fn set_service_account_api_key_enabled(
+        &self,
+        key: &WorkspaceApiKey,
+        enabled: bool,
+    ) -> Result<WorkspaceStatusResponse> {
+        let request = EditServiceAccountApiKeyRequest::from_key(key, enabled)?;
+        self.edit_service_account_api_key(&key.service_account_user_id, &key.key_id, &request).await
+    }
+
     /// Deletes a service account API key.
     ///
     /// Calls `DELETE /v1/service-accounts/{service_account_user_id}/api-keys/{api_key_id}`.
@@ -137,6 +157,7 @@ impl<'a> WorkspaceService<'a> {
     ///
     /// Returns an error if the API request fails.
     pub async fn search_groups(&self, name: &str) -> Result<SearchGroupsResponse> {
+        let name: String = url::form_urlencoded::byte_serialize(name.as_bytes()).collect();
         let path = format!("/v1/workspace/groups/search?name={name}");
         self.client.get(&path).await
     }
@@ -242,7 +263,7 @@ impl<'a> WorkspaceService<'a> {
     /// # Arguments
     ///
     /// * `resource_id` — ID of the resource.
-    /// * `resource_type` — Type of the resource (required query parameter).
+    /// * `resource_type` — Type of the resource.
     ///
     /// # Errors
     ///
@@ -250,7 +271,7 @@ impl<'a> WorkspaceService<'a> {
     pub async fn get_resource_metadata(
         &self,
         resource_id: &str,
-        resource_type: &str,
+        resource_type: WorkspaceResourceType,
     ) -> Result<ResourceMetadataResponse> {
         let path = format!("/v1/workspace/resources/{resource_id}?resource_type={resource_type}");
         self.client.get(&path).await
@@ -353,15 +374,18 @@ impl<'a> WorkspaceService<'a> {
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path, query_param},
+        matchers::{body_json, header, method, path, query_param},
     };
 
     use crate::{
         ElevenLabsClient,
         config::ClientConfig,
         types::{
-            AddGroupMemberRequest, CreateWorkspaceWebhookRequest, DeleteInviteRequest,
-            InviteWorkspaceMemberRequest, UpdateWorkspaceMemberRequest,
+            AddGroupMemberRequest, ApiKeyPermission, ApiKeyPermissions,
+            CreateServiceAccountApiKeyRequest, CreateWorkspaceWebhookRequest, DeleteInviteRequest,
+            InviteWorkspaceMemberRequest, RemoveGroupMemberRequest, ResourceRole,
+            ShareWorkspaceResourceRequest, UnshareWorkspaceResourceRequest,
+            UpdateWorkspaceMemberRequest, WorkspaceApiKey, WorkspaceResourceType,
         },
     };
 
@@ -511,6 +535,124 @@ mod tests {
         assert_eq!(result.status, "ok");
     }
 
+    #[tokio::test]
+    async fn remove_group_member_sends_email() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/workspace/groups/grp1/members/remove"))
+            .and(body_json(serde_json::json!({"email": "user@example.com"})))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let req = RemoveGroupMemberRequest::new("user@example.com");
+        let result = client.workspace().remove_group_member("grp1", &req).await.unwrap();
+        assert_eq!(result.status, "ok");
+    }
+
+    #[tokio::test]
+    async fn search_groups_encodes_name() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/workspace/groups/search"))
+            .and(query_param("name", "Sales & Support"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let result = client.workspace().search_groups("Sales & Support").await.unwrap();
+        assert!(result.0.is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_resource_metadata_types_roles_and_share_options() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/workspace/resources/voice1"))
+            .and(query_param("resource_type", "voice"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "resource_id": "voice1",
+                "resource_name": "Narrator",
+                "resource_type": "voice",
+                "creator_user_id": "user1",
+                "anonymous_access_level_override": null,
+                "role_to_group_ids": {"admin": ["user1"], "viewer": ["default", "grp1"]},
+                "share_options": [{"name": "Developers", "id": "grp2", "type": "group"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let metadata = client
+            .workspace()
+            .get_resource_metadata("voice1", WorkspaceResourceType::Voice)
+            .await
+            .unwrap();
+        assert_eq!(metadata.role_of("user1"), Some(ResourceRole::Admin));
+        assert_eq!(metadata.role_of("grp1"), Some(ResourceRole::Viewer));
+        assert_eq!(metadata.role_of("grp2"), None);
+        assert_eq!(metadata.share_options[0].id, "grp2");
+    }
+
+    #[tokio::test]
+    async fn share_and_unshare_resource_send_principal() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/workspace/resources/voice1/share"))
+            .and(body_json(serde_json::json!({
+                "role": "editor",
+                "resource_type": "voice",
+                "group_id": "grp1"
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/workspace/resources/voice1/unshare"))
+            .and(body_json(serde_json::json!({
+                "resource_type": "voice",
+                "user_email": "user@example.com"
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+        let workspace = client.workspace();
+
+        let share = ShareWorkspaceResourceRequest::with_group(
+            ResourceRole::Editor,
+            WorkspaceResourceType::Voice,
+            "grp1",
+        );
+        assert_eq!(workspace.share_resource("voice1", &share).await.unwrap().status, "ok");
+
+        let unshare = UnshareWorkspaceResourceRequest::with_user(
+            WorkspaceResourceType::Voice,
+            "user@example.com",
+        );
+        assert_eq!(workspace.unshare_resource("voice1", &unshare).await.unwrap().status, "ok");
+    }
+
     #[tokio::test]
     async fn get_webhooks_returns_list() {
         let mock_server = MockServer::start().await;
@@ -526,7 +668,7 @@ mod tests {
                         "webhook_url": "https://example.com/cb",
                         "is_disabled": false,
                         "is_auto_disabled": false,
-                        "created_at_unix": 1700000000,
+                        "created_at_unix": 1_700_000_000,
                         "auth_type": "hmac"
                     }
                 ]
@@ -608,4 +750,76 @@ mod tests {
             client.workspace().delete_service_account_api_key("sa1", "key1").await.unwrap();
         assert_eq!(result.status, "ok");
     }
+
+    #[tokio::test]
+    async fn create_service_account_api_key_sends_scoped_permissions() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/service-accounts/sa1/api-keys"))
+            .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({
+                "name": "tts-worker",
+                "permissions": ["text_to_speech", "voices_read"],
+                "character_limit": 100_000
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "xi-api-key": "sk_new",
+                "key_id": "key_new"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let req = CreateServiceAccountApiKeyRequest {
+            name: "tts-worker".into(),
+            permissions: ApiKeyPermissions::Only(vec![
+                ApiKeyPermission::TextToSpeech,
+                ApiKeyPermission::VoicesRead,
+            ]),
+            character_limit: Some(100_000),
+        };
+        let result = client.workspace().create_service_account_api_key("sa1", &req).await.unwrap();
+        assert_eq!(result.key_id, "key_new");
+    }
+
+    #[tokio::test]
+    async fn set_service_account_api_key_enabled_patches_key() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/v1/service-accounts/sa1/api-keys/key1"))
+            .and(header("xi-api-key", "test-key"))
+            .and(body_json(serde_json::json!({
+                "is_enabled": false,
+                "name": "Prod Key",
+                "permissions": "all"
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "ok"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::builder("test-key").base_url(mock_server.uri()).build();
+        let client = ElevenLabsClient::new(config).unwrap();
+
+        let key = WorkspaceApiKey {
+            name: "Prod Key".into(),
+            hint: "xi_...abc".into(),
+            key_id: "key1".into(),
+            service_account_user_id: "sa1".into(),
+            hashed_xi_api_key: "hash".into(),
+            created_at_unix: None,
+            is_disabled: false,
+            permissions: Some(ApiKeyPermissions::All),
+            character_limit: None,
+            character_count: None,
+        };
+        let result =
+            client.workspace().set_service_account_api_key_enabled(&key, false).await.unwrap();
+        assert_eq!(result.status, "ok");
+    }
 }
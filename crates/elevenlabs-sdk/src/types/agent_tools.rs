@@ -0,0 +1,740 @@
+//! Tool definitions for the ElevenLabs Agents Platform.
+//!
+//! Covers the `tool_config` accepted by `POST /v1/convai/tools` and
+//! `PATCH /v1/convai/tools/{tool_id}` and returned in [`ToolResponse`]s:
+//!
+//! - [`WebhookToolConfig`] — calls an HTTP endpoint described by a [`WebhookApiSchema`]
+//! - [`ClientToolConfig`] — asks the client application to run the tool
+//! - [`SystemToolConfig`] — built-in tools such as ending or transferring a call
+//! - [`McpToolConfig`] — a tool exposed by an MCP server
+//!
+//! Parameters are described with [`JsonSchemaProperty`] trees. Fields the SDK
+//! does not model are kept in each config's `extra` map, so a config read
+//! from the API can be sent back unchanged.
+//!
+//! [`ToolResponse`]: super::ToolResponse
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::agents::WebhookHeaderValue;
+
+// ---------------------------------------------------------------------------
+// Tool config
+// ---------------------------------------------------------------------------
+
+/// Configuration of an agent tool, tagged by `type`.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{
+///     JsonSchemaProperty, ObjectJsonSchemaProperty, ToolConfig, WebhookApiSchema,
+///     WebhookToolConfig,
+/// };
+///
+/// let body = ObjectJsonSchemaProperty::default().property(
+///     "order_id",
+///     JsonSchemaProperty::string("The customer's order number"),
+///     true,
+/// );
+/// let tool = ToolConfig::Webhook(WebhookToolConfig::new(
+///     "get_order",
+///     "Looks up an order by its number.",
+///     WebhookApiSchema::new("https://api.example.com/orders").method("POST").request_body(body),
+/// ));
+///
+/// let json = serde_json::to_value(&tool).unwrap();
+/// assert_eq!(json["type"], "webhook");
+/// assert_eq!(json["api_schema"]["request_body_schema"]["required"][0], "order_id");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ToolConfig {
+    /// A tool that calls an HTTP endpoint.
+    Webhook(WebhookToolConfig),
+    /// A tool run by the client application.
+    Client(ClientToolConfig),
+    /// A built-in platform tool.
+    System(SystemToolConfig),
+    /// A tool exposed by an MCP server.
+    Mcp(McpToolConfig),
+}
+
+impl ToolConfig {
+    /// Returns the tool name.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Webhook(config) => &config.name,
+            Self::Client(config) => &config.name,
+            Self::System(config) => &config.name,
+            Self::Mcp(config) => &config.name,
+        }
+    }
+
+    /// Returns the description the LLM uses to decide when to call the tool.
+    pub fn description(&self) -> &str {
+        match self {
+            Self::Webhook(config) => &config.description,
+            Self::Client(config) => &config.description,
+            Self::System(config) => &config.description,
+            Self::Mcp(config) => &config.description,
+        }
+    }
+
+    /// Returns the dynamic variables assigned from the tool's response.
+    pub fn assignments(&self) -> &[DynamicVariableAssignment] {
+        match self {
+            Self::Webhook(config) => &config.assignments,
+            Self::Client(config) => &config.assignments,
+            Self::System(config) => &config.assignments,
+            Self::Mcp(config) => &config.assignments,
+        }
+    }
+}
+
+/// Request body for `POST /v1/convai/tools` and
+/// `PATCH /v1/convai/tools/{tool_id}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolRequest {
+    /// The tool configuration.
+    pub tool_config: ToolConfig,
+}
+
+impl From<ToolConfig> for ToolRequest {
+    fn from(tool_config: ToolConfig) -> Self {
+        Self { tool_config }
+    }
+}
+
+/// Configuration of a tool that calls an HTTP endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookToolConfig {
+    /// Tool name, as seen by the LLM.
+    #[serde(default)]
+    pub name: String,
+    /// When the tool should be used and what it does.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// The outgoing request.
+    pub api_schema: WebhookApiSchema,
+    /// Seconds to wait for the call to complete (5–120, 20 by default).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_timeout_secs: Option<u32>,
+    /// Whether the user is prevented from interrupting while the tool runs.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub disable_interruptions: bool,
+    /// Whether the agent speaks before calling the tool.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub force_pre_tool_speech: bool,
+    /// Dynamic variables assigned from the response.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignments: Vec<DynamicVariableAssignment>,
+    /// Placeholder values for dynamic variables used by the tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dynamic_variables: Option<DynamicVariablesConfig>,
+    /// Fields not modelled by the SDK, such as tool call sounds.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl WebhookToolConfig {
+    /// Creates a webhook tool calling the endpoint described by
+    /// `api_schema`.
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        api_schema: WebhookApiSchema,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            api_schema,
+            response_timeout_secs: None,
+            disable_interruptions: false,
+            force_pre_tool_speech: false,
+            assignments: Vec::new(),
+            dynamic_variables: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Assigns a value from the response to a dynamic variable.
+    #[must_use]
+    pub fn assign(mut self, assignment: DynamicVariableAssignment) -> Self {
+        self.assignments.push(assignment);
+        self
+    }
+}
+
+/// The HTTP request a [`WebhookToolConfig`] makes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookApiSchema {
+    /// Endpoint URL. May contain `{placeholders}` filled from
+    /// [`path_params_schema`](Self::path_params_schema).
+    pub url: String,
+    /// HTTP method (`GET` by default).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Headers sent with the request.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub request_headers: BTreeMap<String, WebhookHeaderValue>,
+    /// Path parameters, keyed by URL placeholder. Only literal types are
+    /// accepted.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub path_params_schema: BTreeMap<String, JsonSchemaProperty>,
+    /// Query parameters appended to the URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_params_schema: Option<QueryParamsSchema>,
+    /// Request body, for `POST`, `PUT` and `PATCH` requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body_schema: Option<ObjectJsonSchemaProperty>,
+    /// Content type of the request body (`application/json` by default).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Fields not modelled by the SDK, such as auth connections.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl WebhookApiSchema {
+    /// Creates a `GET` request to `url` without parameters.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            method: None,
+            request_headers: BTreeMap::new(),
+            path_params_schema: BTreeMap::new(),
+            query_params_schema: None,
+            request_body_schema: None,
+            content_type: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the HTTP method.
+    #[must_use]
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    /// Adds a header sent with the request.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: WebhookHeaderValue) -> Self {
+        self.request_headers.insert(name.into(), value);
+        self
+    }
+
+    /// Adds a path parameter filling the `{name}` placeholder in the URL.
+    #[must_use]
+    pub fn path_param(mut self, name: impl Into<String>, property: JsonSchemaProperty) -> Self {
+        self.path_params_schema.insert(name.into(), property);
+        self
+    }
+
+    /// Adds a query parameter.
+    #[must_use]
+    pub fn query_param(
+        mut self,
+        name: impl Into<String>,
+        property: JsonSchemaProperty,
+        required: bool,
+    ) -> Self {
+        let name = name.into();
+        let query = self.query_params_schema.get_or_insert_with(QueryParamsSchema::default);
+        if required {
+            query.required.push(name.clone());
+        }
+        query.properties.insert(name, property);
+        self
+    }
+
+    /// Sets the request body schema.
+    #[must_use]
+    pub fn request_body(mut self, schema: ObjectJsonSchemaProperty) -> Self {
+        self.request_body_schema = Some(schema);
+        self
+    }
+}
+
+/// Query parameters of a [`WebhookApiSchema`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryParamsSchema {
+    /// Parameters, keyed by name. Only literal types are accepted.
+    #[serde(default)]
+    pub properties: BTreeMap<String, JsonSchemaProperty>,
+    /// Names of the required parameters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+}
+
+/// Configuration of a tool run by the client application.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientToolConfig {
+    /// Tool name, as seen by the LLM and the client.
+    #[serde(default)]
+    pub name: String,
+    /// When the tool should be used and what it does.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Parameters passed to the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<ObjectJsonSchemaProperty>,
+    /// Whether the conversation waits for the client to respond.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub expects_response: bool,
+    /// Seconds to wait for the call to complete (20 by default).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_timeout_secs: Option<u32>,
+    /// Whether the user is prevented from interrupting while the tool runs.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub disable_interruptions: bool,
+    /// Whether the agent speaks before calling the tool.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub force_pre_tool_speech: bool,
+    /// Dynamic variables assigned from the response.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignments: Vec<DynamicVariableAssignment>,
+    /// Placeholder values for dynamic variables used by the tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dynamic_variables: Option<DynamicVariablesConfig>,
+    /// Fields not modelled by the SDK, such as tool call sounds.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl ClientToolConfig {
+    /// Creates a client tool without parameters.
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            parameters: None,
+            expects_response: false,
+            response_timeout_secs: None,
+            disable_interruptions: false,
+            force_pre_tool_speech: false,
+            assignments: Vec::new(),
+            dynamic_variables: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the parameters passed to the client.
+    #[must_use]
+    pub fn parameters(mut self, parameters: ObjectJsonSchemaProperty) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    /// Sets whether the conversation waits for the client to respond.
+    #[must_use]
+    pub const fn expects_response(mut self, expects_response: bool) -> Self {
+        self.expects_response = expects_response;
+        self
+    }
+}
+
+/// Configuration of a built-in platform tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SystemToolConfig {
+    /// Tool name, as seen by the LLM.
+    #[serde(default)]
+    pub name: String,
+    /// When the tool should be used. Empty to use the platform default.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Tool-specific parameters, tagged by `system_tool_type` (e.g.
+    /// `end_call`, `transfer_to_number`).
+    #[serde(default)]
+    pub params: serde_json::Value,
+    /// Dynamic variables assigned from the response.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignments: Vec<DynamicVariableAssignment>,
+    /// Fields not modelled by the SDK.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl SystemToolConfig {
+    /// Creates a system tool of the given `system_tool_type` with no further
+    /// parameters.
+    pub fn new(name: impl Into<String>, system_tool_type: &str) -> Self {
+        Self {
+            name: name.into(),
+            description: String::new(),
+            params: serde_json::json!({ "system_tool_type": system_tool_type }),
+            assignments: Vec::new(),
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the `system_tool_type` of [`params`](Self::params).
+    pub fn system_tool_type(&self) -> Option<&str> {
+        self.params.get("system_tool_type").and_then(serde_json::Value::as_str)
+    }
+}
+
+/// Configuration of a tool exposed by an MCP server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpToolConfig {
+    /// Tool name, as seen by the LLM.
+    #[serde(default)]
+    pub name: String,
+    /// When the tool should be used and what it does.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// ID of the MCP server.
+    pub mcp_server_id: String,
+    /// Name of the MCP server.
+    #[serde(default)]
+    pub mcp_server_name: String,
+    /// Name of the tool on the MCP server.
+    #[serde(default)]
+    pub mcp_tool_name: String,
+    /// Description of the tool on the MCP server.
+    #[serde(default)]
+    pub mcp_tool_description: String,
+    /// MCP integration type.
+    #[serde(default)]
+    pub integration_type: String,
+    /// Parameters the LLM provides to the tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<ObjectJsonSchemaProperty>,
+    /// Approval policy (`require_approval_all` by default).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval_policy: Option<String>,
+    /// Dynamic variables assigned from the response.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignments: Vec<DynamicVariableAssignment>,
+    /// Fields not modelled by the SDK, such as the original MCP input
+    /// schema.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+// ---------------------------------------------------------------------------
+// Dynamic variables
+// ---------------------------------------------------------------------------
+
+/// Assigns a value from a tool response to a dynamic variable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DynamicVariableAssignment {
+    /// Name of the dynamic variable to set.
+    pub dynamic_variable: String,
+    /// Dot-separated path to the value in the response, e.g. `user.name`
+    /// or `data.0.id`.
+    pub value_path: String,
+    /// Where the value is read from (`response` by default, the only
+    /// source supported).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Whether the value is removed from the response before it reaches
+    /// the LLM.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub sanitize: bool,
+}
+
+impl DynamicVariableAssignment {
+    /// Assigns the value at `value_path` to `dynamic_variable`.
+    pub fn new(dynamic_variable: impl Into<String>, value_path: impl Into<String>) -> Self {
+        Self {
+            dynamic_variable: dynamic_variable.into(),
+            value_path: value_path.into(),
+            source: None,
+            sanitize: false,
+        }
+    }
+
+    /// Removes the value from the response before it reaches the LLM.
+    #[must_use]
+    pub const fn sanitized(mut self) -> Self {
+        self.sanitize = true;
+        self
+    }
+}
+
+/// Placeholder values for dynamic variables used by a tool.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DynamicVariablesConfig {
+    /// Placeholder values, keyed by variable name.
+    #[serde(default)]
+    pub dynamic_variable_placeholders: BTreeMap<String, serde_json::Value>,
+}
+
+// ---------------------------------------------------------------------------
+// JSON schema
+// ---------------------------------------------------------------------------
+
+/// A parameter in a tool's JSON schema, tagged by `type`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsonSchemaProperty {
+    /// A string value.
+    String(LiteralJsonSchemaProperty),
+    /// A floating-point number.
+    Number(LiteralJsonSchemaProperty),
+    /// An integer.
+    Integer(LiteralJsonSchemaProperty),
+    /// A boolean.
+    Boolean(LiteralJsonSchemaProperty),
+    /// An object with named properties.
+    Object(ObjectJsonSchemaProperty),
+    /// An array of values.
+    Array(ArrayJsonSchemaProperty),
+}
+
+impl JsonSchemaProperty {
+    /// A string the LLM fills in according to `description`.
+    pub fn string(description: impl Into<String>) -> Self {
+        Self::String(LiteralJsonSchemaProperty::described(description))
+    }
+
+    /// A number the LLM fills in according to `description`.
+    pub fn number(description: impl Into<String>) -> Self {
+        Self::Number(LiteralJsonSchemaProperty::described(description))
+    }
+
+    /// An integer the LLM fills in according to `description`.
+    pub fn integer(description: impl Into<String>) -> Self {
+        Self::Integer(LiteralJsonSchemaProperty::described(description))
+    }
+
+    /// A boolean the LLM fills in according to `description`.
+    pub fn boolean(description: impl Into<String>) -> Self {
+        Self::Boolean(LiteralJsonSchemaProperty::described(description))
+    }
+
+    /// A string taken from the dynamic variable `name` instead of the LLM.
+    pub fn dynamic_variable(name: impl Into<String>) -> Self {
+        Self::String(LiteralJsonSchemaProperty {
+            dynamic_variable: name.into(),
+            ..LiteralJsonSchemaProperty::default()
+        })
+    }
+
+    /// An array of `items`.
+    pub fn array(description: impl Into<String>, items: Self) -> Self {
+        Self::Array(ArrayJsonSchemaProperty {
+            description: description.into(),
+            items: Box::new(items),
+        })
+    }
+}
+
+/// A string, number, integer or boolean parameter.
+///
+/// Exactly one of [`description`](Self::description),
+/// [`dynamic_variable`](Self::dynamic_variable) and
+/// [`constant_value`](Self::constant_value) decides where the value comes
+/// from.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiteralJsonSchemaProperty {
+    /// Tells the LLM what value to provide.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Allowed values, for string parameters.
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    pub enum_values: Option<Vec<String>>,
+    /// Dynamic variable the value is taken from.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub dynamic_variable: String,
+    /// Fixed value sent for the parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constant_value: Option<serde_json::Value>,
+    /// Whether the platform fills in the value at runtime.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_system_provided: bool,
+}
+
+impl LiteralJsonSchemaProperty {
+    /// A parameter the LLM fills in according to `description`.
+    pub fn described(description: impl Into<String>) -> Self {
+        Self { description: description.into(), ..Self::default() }
+    }
+}
+
+/// An object parameter, also used for request bodies and tool parameters.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectJsonSchemaProperty {
+    /// What the object represents.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Properties, keyed by name.
+    #[serde(default)]
+    pub properties: BTreeMap<String, JsonSchemaProperty>,
+    /// Names of the required properties.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+}
+
+impl ObjectJsonSchemaProperty {
+    /// Adds a property, marking it required if `required` is set.
+    #[must_use]
+    pub fn property(
+        mut self,
+        name: impl Into<String>,
+        property: JsonSchemaProperty,
+        required: bool,
+    ) -> Self {
+        let name = name.into();
+        if required {
+            self.required.push(name.clone());
+        }
+        self.properties.insert(name, property);
+        self
+    }
+}
+
+/// An array parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArrayJsonSchemaProperty {
+    /// What the array represents.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Schema of each item.
+    pub items: Box<JsonSchemaProperty>,
+}
+
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, clippy::panic, reason = "tests use unwrap and panic")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn webhook_tool_round_trip() {
+        let json = serde_json::json!({
+            "type": "webhook",
+            "name": "get_weather",
+            "description": "Gets the weather for a city.",
+            "response_timeout_secs": 10,
+            "assignments": [
+                {"dynamic_variable": "temp", "value_path": "current.temp", "source": "response"}
+            ],
+            "tool_call_sound": "typing",
+            "api_schema": {
+                "url": "https://api.example.com/weather/{city}",
+                "method": "GET",
+                "request_headers": {
+                    "X-Api-Key": {"secret_id": "sec_1"},
+                    "X-User": {"dynamic_variable": "user_id"}
+                },
+                "path_params_schema": {
+                    "city": {"type": "string", "description": "City name"}
+                },
+                "query_params_schema": {
+                    "properties": {
+                        "units": {"type": "string", "enum": ["metric", "imperial"], "description": "Units"},
+                        "session": {"type": "string", "dynamic_variable": "system__conversation_id"}
+                    },
+                    "required": ["units"]
+                },
+                "auth_connection": {"auth_connection_id": "auth_1"}
+            }
+        });
+        let config: ToolConfig = serde_json::from_value(json.clone()).unwrap();
+        let ToolConfig::Webhook(webhook) = &config else {
+            panic!("expected a webhook tool, got {config:?}");
+        };
+        assert_eq!(config.name(), "get_weather");
+        assert_eq!(config.assignments()[0].value_path, "current.temp");
+        assert_eq!(
+            webhook.api_schema.request_headers["X-User"],
+            WebhookHeaderValue::DynamicVariable { dynamic_variable: "user_id".into() }
+        );
+        let query = webhook.api_schema.query_params_schema.as_ref().unwrap();
+        let JsonSchemaProperty::String(units) = &query.properties["units"] else {
+            panic!("expected a string parameter");
+        };
+        assert_eq!(units.enum_values.as_deref().unwrap(), ["metric", "imperial"]);
+        assert_eq!(webhook.extra["tool_call_sound"], "typing");
+        assert!(webhook.api_schema.extra.contains_key("auth_connection"));
+
+        assert_eq!(serde_json::to_value(&config).unwrap(), json);
+    }
+
+    #[test]
+    fn client_and_system_tools_round_trip() {
+        let client = ToolConfig::Client(
+            ClientToolConfig::new("show_product", "Shows a product to the user.")
+                .parameters(ObjectJsonSchemaProperty::default().property(
+                    "skus",
+                    JsonSchemaProperty::array(
+                        "Products to show",
+                        JsonSchemaProperty::string("SKU"),
+                    ),
+                    true,
+                ))
+                .expects_response(true),
+        );
+        let json = serde_json::to_value(&client).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "client",
+                "name": "show_product",
+                "description": "Shows a product to the user.",
+                "expects_response": true,
+                "parameters": {
+                    "properties": {
+                        "skus": {
+                            "type": "array",
+                            "description": "Products to show",
+                            "items": {"type": "string", "description": "SKU"}
+                        }
+                    },
+                    "required": ["skus"]
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<ToolConfig>(json).unwrap(), client);
+
+        let system = ToolConfig::System(SystemToolConfig::new("end_call", "end_call"));
+        let json = serde_json::to_value(&system).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "system",
+                "name": "end_call",
+                "params": {"system_tool_type": "end_call"}
+            })
+        );
+        let ToolConfig::System(back) = serde_json::from_value(json).unwrap() else {
+            panic!("expected a system tool");
+        };
+        assert_eq!(back.system_tool_type(), Some("end_call"));
+    }
+
+    #[test]
+    fn mcp_tool_deserialize() {
+        let json = r#"{
+            "type": "mcp",
+            "name": "search",
+            "description": "Searches the wiki.",
+            "integration_type": "mcp_server",
+            "mcp_tool_name": "search",
+            "mcp_tool_description": "Full-text search",
+            "mcp_server_id": "mcp_1",
+            "mcp_server_name": "Wiki",
+            "approval_policy": "auto_approve_all",
+            "mcp_input_schema": {"type": "object"}
+        }"#;
+        let config: ToolConfig = serde_json::from_str(json).unwrap();
+        let ToolConfig::Mcp(mcp) = &config else {
+            panic!("expected an MCP tool, got {config:?}");
+        };
+        assert_eq!(mcp.mcp_server_id, "mcp_1");
+        assert_eq!(mcp.approval_policy.as_deref(), Some("auto_approve_all"));
+        assert!(mcp.extra.contains_key("mcp_input_schema"));
+    }
+}
@@ -18,36 +18,30 @@
 //! to keep the type surface manageable while still providing fully typed
 //! wrappers for the most commonly used request/response shapes.
 
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    time::Duration,
+};
 
 use serde::{Deserialize, Serialize};
 
+use super::{Timestamp, agent_tools::ToolConfig, workspace::ResourceRole};
+
 // ===========================================================================
 // Common Enums (used across multiple agent sub-resources)
 // ===========================================================================
 
-/// Role of a user in relation to a resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum ResourceRole {
-    /// Full administrative access.
-    Admin,
-    /// Can edit the resource.
-    Editor,
-    /// Can comment on the resource.
-    Commenter,
-    /// Read-only access.
-    Viewer,
-}
-
 /// Access information for a shared resource (agent, tool, document, etc.).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ResourceAccessInfo {
     /// Whether the requesting user is the creator.
+    #[serde(default)]
     pub is_creator: bool,
     /// Name of the resource creator.
+    #[serde(default)]
     pub creator_name: String,
     /// Email of the resource creator.
+    #[serde(default)]
     pub creator_email: String,
     /// Role of the requesting user.
     pub role: ResourceRole,
@@ -151,12 +145,13 @@ pub enum ConversationInitiationSource {
 }
 
 /// Authorization method used for a conversation.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AuthorizationMethod {
     /// Invalid or unspecified.
     Invalid,
     /// Public access (no auth required).
+    #[default]
     Public,
     /// Via Authorization header.
     AuthorizationHeader,
@@ -253,6 +248,19 @@ pub enum McpServerTransport {
     StreamableHttp,
 }
 
+/// Whether the agent needs approval before calling an MCP server's tools.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum McpApprovalPolicy {
+    /// Every tool may be called without approval.
+    AutoApproveAll,
+    /// Every tool call needs approval.
+    #[default]
+    RequireApprovalAll,
+    /// Only tools without a stored approval need one.
+    RequireApprovalPerTool,
+}
+
 /// Usage mode for a knowledge base document.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -288,12 +296,14 @@ pub enum SecretDependencyType {
 // ===========================================================================
 
 /// Agent metadata (timestamps).
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AgentMetadata {
     /// Creation time in Unix seconds.
-    pub created_at_unix_secs: i64,
+    #[serde(default)]
+    pub created_at_unix_secs: Timestamp,
     /// Last update time in Unix seconds.
-    pub updated_at_unix_secs: i64,
+    #[serde(default)]
+    pub updated_at_unix_secs: Timestamp,
 }
 
 /// Summary information for an agent (returned in list responses).
@@ -302,15 +312,18 @@ pub struct AgentSummary {
     /// Unique agent identifier.
     pub agent_id: String,
     /// Display name of the agent.
+    #[serde(default)]
     pub name: String,
     /// Tags used to categorize the agent.
+    #[serde(default)]
     pub tags: Vec<String>,
     /// Creation time in Unix seconds.
-    pub created_at_unix_secs: i64,
+    #[serde(default)]
+    pub created_at_unix_secs: Timestamp,
     /// Access information for the requesting user.
     pub access_info: ResourceAccessInfo,
     /// Time of the most recent call in Unix seconds, if any.
-    pub last_call_time_unix_secs: Option<i64>,
+    pub last_call_time_unix_secs: Option<Timestamp>,
     /// Whether the agent is archived.
     #[serde(default)]
     pub archived: bool,
@@ -320,10 +333,12 @@ pub struct AgentSummary {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetAgentsResponse {
     /// List of agents.
+    #[serde(default)]
     pub agents: Vec<AgentSummary>,
     /// Cursor for the next page, if any.
     pub next_cursor: Option<String>,
     /// Whether more pages exist.
+    #[serde(default)]
     pub has_more: bool,
 }
 
@@ -337,12 +352,16 @@ pub struct GetAgentResponse {
     /// Unique agent identifier.
     pub agent_id: String,
     /// Display name of the agent.
+    #[serde(default)]
     pub name: String,
     /// Conversation configuration (prompt, LLM, TTS, STT, turn-taking, etc.).
+    #[serde(default)]
     pub conversation_config: serde_json::Value,
     /// Agent metadata (timestamps).
+    #[serde(default)]
     pub metadata: AgentMetadata,
     /// Platform settings (evaluation, widget, data collection, guardrails, etc.).
+    #[serde(default)]
     pub platform_settings: serde_json::Value,
     /// Phone numbers assigned to this agent.
     #[serde(default)]
@@ -358,6 +377,17 @@ pub struct GetAgentResponse {
     /// Tags used to categorize the agent.
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Version of the agent this response describes, if versioning is
+    /// enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    /// Branch of the agent this response describes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_id: Option<String>,
+    /// The agent's main branch, which receives the traffic not deployed to
+    /// other branches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main_branch_id: Option<String>,
 }
 
 /// Request body for creating a new agent.
@@ -385,7 +415,11 @@ pub struct CreateAgentRequest {
 }
 
 /// Request body for updating (patching) an agent.
-#[derive(Debug, Clone, Serialize)]
+///
+/// Nested config objects sent here replace the stored ones. To change a
+/// single nested setting without restating the rest, use
+/// [`AgentsService::update_agent_merged`](crate::services::AgentsService::update_agent_merged).
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct UpdateAgentRequest {
     /// Conversation configuration updates.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -437,6 +471,273 @@ const fn default_true() -> bool {
     true
 }
 
+// ===========================================================================
+// Agents — Merged updates
+// ===========================================================================
+
+impl GetAgentResponse {
+    /// Applies `patch` to this agent as a JSON merge patch (RFC 7386) and
+    /// returns the request to send along with what it changes.
+    ///
+    /// Objects in `patch` are merged key by key into the current config, a
+    /// `null` value removes a key, and arrays and scalars replace the current
+    /// value. The returned request carries only the top-level fields that
+    /// changed, each as the full merged value, so settings the patch does
+    /// not mention are sent back unchanged. `version_description` and
+    /// `procedure_refs` are passed through as-is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elevenlabs_sdk::types::{GetAgentResponse, UpdateAgentRequest};
+    /// use serde_json::json;
+    ///
+    /// let agent: GetAgentResponse = serde_json::from_value(json!({
+    ///     "agent_id": "agent_1",
+    ///     "conversation_config": {"agent": {"first_message": "Hi", "language": "en"}}
+    /// }))
+    /// .unwrap();
+    /// let patch = UpdateAgentRequest {
+    ///     conversation_config: Some(json!({"agent": {"first_message": "Hello!"}})),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let (request, diff) = agent.merge_update(&patch);
+    /// assert_eq!(diff.len(), 1);
+    /// assert_eq!(
+    ///     request.conversation_config,
+    ///     Some(json!({"agent": {"first_message": "Hello!", "language": "en"}}))
+    /// );
+    /// ```
+    pub fn merge_update(&self, patch: &UpdateAgentRequest) -> (UpdateAgentRequest, ConfigDiff) {
+        let current = self.editable_config();
+        let mut merged = current.clone();
+        json_merge_patch(&mut merged, &agent_patch_document(patch));
+        let diff = ConfigDiff::between(&current, &merged);
+
+        let changed = |key: &str| current.get(key) != merged.get(key);
+        let section = |key: &str| merged.get(key).cloned().filter(|_| changed(key));
+        let mut request = UpdateAgentRequest {
+            conversation_config: section("conversation_config"),
+            platform_settings: section("platform_settings"),
+            workflow: section("workflow"),
+            name: section("name").map(|v| v.as_str().unwrap_or_default().to_owned()),
+            tags: section("tags").map(|v| {
+                v.as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|tag| tag.as_str().map(str::to_owned))
+                    .collect()
+            }),
+            ..UpdateAgentRequest::default()
+        };
+        if !diff.is_empty() {
+            request.version_description.clone_from(&patch.version_description);
+            request.procedure_refs.clone_from(&patch.procedure_refs);
+        }
+        (request, diff)
+    }
+
+    /// Returns the fields an [`UpdateAgentRequest`] can change as one JSON
+    /// object.
+    fn editable_config(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.name,
+            "tags": self.tags,
+            "conversation_config": self.conversation_config,
+            "platform_settings": self.platform_settings,
+            "workflow": self.workflow,
+        })
+    }
+}
+
+/// Returns the fields set in `patch` as a merge patch document.
+fn agent_patch_document(patch: &UpdateAgentRequest) -> serde_json::Value {
+    let mut doc = serde_json::Map::new();
+    if let Some(config) = &patch.conversation_config {
+        doc.insert("conversation_config".to_owned(), config.clone());
+    }
+    if let Some(settings) = &patch.platform_settings {
+        doc.insert("platform_settings".to_owned(), settings.clone());
+    }
+    if let Some(workflow) = &patch.workflow {
+        doc.insert("workflow".to_owned(), workflow.clone());
+    }
+    if let Some(name) = &patch.name {
+        doc.insert("name".to_owned(), serde_json::Value::from(name.as_str()));
+    }
+    if let Some(tags) = &patch.tags {
+        doc.insert("tags".to_owned(), serde_json::Value::from(tags.clone()));
+    }
+    serde_json::Value::Object(doc)
+}
+
+/// Applies `patch` to `target` following RFC 7386 (JSON Merge Patch).
+///
+/// A non-object patch replaces `target`; object patches are merged key by
+/// key, with `null` removing the key.
+fn json_merge_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    let serde_json::Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = serde_json::Value::Object(serde_json::Map::new());
+    }
+    if let serde_json::Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                json_merge_patch(
+                    target.entry(key.as_str()).or_insert(serde_json::Value::Null),
+                    value,
+                );
+            }
+        }
+    }
+}
+
+/// One difference found by [`ConfigDiff::between`].
+///
+/// Paths are JSON pointers (RFC 6901) into the agent config, e.g.
+/// `/conversation_config/agent/first_message`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigChange {
+    /// A key that did not exist before.
+    Added {
+        /// Location of the key.
+        path: String,
+        /// The new value.
+        value: serde_json::Value,
+    },
+    /// A key that no longer exists.
+    Removed {
+        /// Location of the key.
+        path: String,
+        /// The value before the change.
+        value: serde_json::Value,
+    },
+    /// A value that changed. Arrays are compared as a whole.
+    Modified {
+        /// Location of the value.
+        path: String,
+        /// The value before the change.
+        old: serde_json::Value,
+        /// The value after the change.
+        new: serde_json::Value,
+    },
+}
+
+impl ConfigChange {
+    /// Returns the JSON pointer of the changed value.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Modified { path, .. } => {
+                path
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Added { path, value } => write!(f, "+ {path}: {value}"),
+            Self::Removed { path, value } => write!(f, "- {path}: {value}"),
+            Self::Modified { path, old, new } => write!(f, "~ {path}: {old} -> {new}"),
+        }
+    }
+}
+
+/// The leaf-level differences between two JSON documents, in key order.
+///
+/// Displays as one line per change, prefixed with `+`, `-` or `~`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    /// The individual changes.
+    pub changes: Vec<ConfigChange>,
+}
+
+impl ConfigDiff {
+    /// Compares `old` against `new`, descending into objects.
+    pub fn between(old: &serde_json::Value, new: &serde_json::Value) -> Self {
+        let mut changes = Vec::new();
+        diff_values(String::new(), old, new, &mut changes);
+        Self { changes }
+    }
+
+    /// Returns `true` if nothing changed.
+    pub const fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the number of changes.
+    pub const fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if any change is at or below `prefix`, e.g.
+    /// `"/conversation_config/tts"`.
+    pub fn touches(&self, prefix: &str) -> bool {
+        self.changes.iter().any(|change| {
+            change
+                .path()
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+}
+
+impl std::fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Recursively collects the differences between `old` and `new` at `path`.
+fn diff_values(
+    path: String,
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    changes: &mut Vec<ConfigChange>,
+) {
+    let (serde_json::Value::Object(old_map), serde_json::Value::Object(new_map)) = (old, new)
+    else {
+        if old != new {
+            changes.push(ConfigChange::Modified { path, old: old.clone(), new: new.clone() });
+        }
+        return;
+    };
+
+    let keys: BTreeSet<&String> = old_map.keys().chain(new_map.keys()).collect();
+    for key in keys {
+        let child = format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"));
+        match (old_map.get(key), new_map.get(key)) {
+            (Some(old), Some(new)) => diff_values(child, old, new, changes),
+            (Some(old), None) => {
+                changes.push(ConfigChange::Removed { path: child, value: old.clone() });
+            }
+            (None, Some(new)) => {
+                changes.push(ConfigChange::Added { path: child, value: new.clone() });
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Result of [`AgentsService::update_agent_merged`](crate::services::AgentsService::update_agent_merged).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedAgentUpdate {
+    /// The agent after the update, or unchanged if the diff is empty.
+    pub agent: GetAgentResponse,
+    /// What the update changed.
+    pub diff: ConfigDiff,
+}
+
 // ===========================================================================
 // Agents — Branches
 // ===========================================================================
@@ -447,11 +748,12 @@ pub struct AgentBranchBasicInfo {
     /// Branch identifier.
     pub id: String,
     /// Branch display name.
+    #[serde(default)]
     pub name: String,
 }
 
 /// Version parent references for branch merge tracking.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AgentVersionParents {
     /// Parent version ID within the same branch.
     pub in_branch_parent_id: Option<String>,
@@ -473,33 +775,92 @@ pub struct AgentVersionMetadata {
     /// Branch this version belongs to.
     pub branch_id: String,
     /// Description of changes in this version.
+    #[serde(default)]
     pub version_description: String,
     /// Sequence number within the branch.
+    #[serde(default)]
     pub seq_no_in_branch: i64,
     /// Commit time in Unix seconds.
-    pub time_committed_secs: i64,
+    #[serde(default)]
+    pub time_committed_secs: Timestamp,
     /// Parent version references.
+    #[serde(default)]
     pub parents: AgentVersionParents,
     /// Access information for the requesting user.
     pub access_info: Option<ResourceAccessInfo>,
 }
 
+/// A branch in [`GetAgentBranchesResponse`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentBranchSummary {
+    /// Branch identifier.
+    pub id: String,
+    /// Branch display name.
+    #[serde(default)]
+    pub name: String,
+    /// Parent agent identifier.
+    pub agent_id: String,
+    /// Branch description.
+    #[serde(default)]
+    pub description: String,
+    /// Creation time in Unix seconds.
+    #[serde(default)]
+    pub created_at: Timestamp,
+    /// Time of last commit in Unix seconds.
+    #[serde(default)]
+    pub last_committed_at: Timestamp,
+    /// Whether the branch is archived.
+    #[serde(default)]
+    pub is_archived: bool,
+    /// Branch protection status.
+    #[serde(default)]
+    pub protection_status: Option<BranchProtectionStatus>,
+    /// Access information for the requesting user.
+    pub access_info: Option<ResourceAccessInfo>,
+    /// Share of live traffic routed to this branch.
+    #[serde(default)]
+    pub current_live_percentage: f64,
+    /// Whether the branch has an uncommitted draft.
+    #[serde(default)]
+    pub draft_exists: bool,
+}
+
+/// Response from `GET /v1/convai/agents/{agent_id}/branches`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GetAgentBranchesResponse {
+    /// The agent's branches.
+    #[serde(default)]
+    pub results: Vec<AgentBranchSummary>,
+}
+
+impl GetAgentBranchesResponse {
+    /// Returns the branches currently receiving live traffic.
+    pub fn live(&self) -> impl Iterator<Item = &AgentBranchSummary> {
+        self.results.iter().filter(|branch| branch.current_live_percentage > 0.0)
+    }
+}
+
 /// Full branch response.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AgentBranchResponse {
     /// Branch identifier.
     pub id: String,
     /// Branch display name.
+    #[serde(default)]
     pub name: String,
     /// Parent agent identifier.
     pub agent_id: String,
     /// Branch description.
+    #[serde(default)]
     pub description: String,
     /// Creation time in Unix seconds.
-    pub created_at: i64,
+    #[serde(default)]
+    pub created_at: Timestamp,
     /// Time of last commit in Unix seconds.
-    pub last_committed_at: i64,
+    #[serde(default)]
+    pub last_committed_at: Timestamp,
     /// Whether the branch is archived.
+    #[serde(default)]
     pub is_archived: bool,
     /// Branch protection status.
     #[serde(default)]
@@ -542,10 +903,13 @@ pub struct ConversationSummary {
     /// Unique conversation identifier.
     pub conversation_id: String,
     /// Start time in Unix seconds.
-    pub start_time_unix_secs: i64,
+    #[serde(default)]
+    pub start_time_unix_secs: Timestamp,
     /// Duration in seconds.
+    #[serde(default)]
     pub call_duration_secs: i64,
     /// Number of messages exchanged.
+    #[serde(default)]
     pub message_count: i64,
     /// Current conversation status.
     pub status: ConversationStatus,
@@ -565,10 +929,12 @@ pub struct ConversationSummary {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetConversationsResponse {
     /// List of conversation summaries.
+    #[serde(default)]
     pub conversations: Vec<ConversationSummary>,
     /// Cursor for the next page, if any.
     pub next_cursor: Option<String>,
     /// Whether more pages exist.
+    #[serde(default)]
     pub has_more: bool,
 }
 
@@ -612,16 +978,16 @@ pub struct ConversationTranscriptEntry {
 }
 
 /// Deletion settings for a conversation's data.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConversationDeletionSettings {
     /// Scheduled deletion time in Unix seconds.
-    pub deletion_time_unix_secs: Option<i64>,
+    pub deletion_time_unix_secs: Option<Timestamp>,
     /// When logs were deleted (Unix seconds).
-    pub deleted_logs_at_time_unix_secs: Option<i64>,
+    pub deleted_logs_at_time_unix_secs: Option<Timestamp>,
     /// When audio was deleted (Unix seconds).
-    pub deleted_audio_at_time_unix_secs: Option<i64>,
+    pub deleted_audio_at_time_unix_secs: Option<Timestamp>,
     /// When transcript was deleted (Unix seconds).
-    pub deleted_transcript_at_time_unix_secs: Option<i64>,
+    pub deleted_transcript_at_time_unix_secs: Option<Timestamp>,
     /// Whether to delete transcript and PII data.
     #[serde(default)]
     pub delete_transcript_and_pii: bool,
@@ -631,7 +997,7 @@ pub struct ConversationDeletionSettings {
 }
 
 /// Feedback information for a conversation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConversationFeedback {
     /// Type of feedback collection.
     #[serde(rename = "type")]
@@ -651,7 +1017,7 @@ pub struct ConversationFeedback {
 }
 
 /// Charging/billing information for a conversation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConversationCharging {
     /// Whether a developer discount was applied.
     #[serde(default)]
@@ -679,24 +1045,29 @@ pub struct ConversationCharging {
 }
 
 /// Metadata about a conversation's execution.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConversationMetadata {
     /// Start time in Unix seconds.
-    pub start_time_unix_secs: i64,
+    #[serde(default)]
+    pub start_time_unix_secs: Timestamp,
     /// Time the call was accepted in Unix seconds.
-    pub accepted_time_unix_secs: Option<i64>,
+    pub accepted_time_unix_secs: Option<Timestamp>,
     /// Duration in seconds.
+    #[serde(default)]
     pub call_duration_secs: i64,
     /// Cost in credits.
     pub cost: Option<i64>,
     /// Data deletion settings.
+    #[serde(default)]
     pub deletion_settings: ConversationDeletionSettings,
     /// User feedback.
+    #[serde(default)]
     pub feedback: ConversationFeedback,
     /// Authorization method used.
     #[serde(default = "default_authorization_method")]
     pub authorization_method: AuthorizationMethod,
     /// Billing/charging information.
+    #[serde(default)]
     pub charging: ConversationCharging,
     /// Phone call details, if applicable (opaque due to Twilio/SIP variants).
     pub phone_call: Option<serde_json::Value>,
@@ -727,6 +1098,7 @@ pub struct EvaluationCriteriaResult {
     /// Evaluation result.
     pub result: EvaluationSuccessResult,
     /// Rationale for the evaluation result.
+    #[serde(default)]
     pub rationale: String,
 }
 
@@ -748,6 +1120,7 @@ pub struct ConversationAnalysis {
     /// Overall call success evaluation.
     pub call_successful: EvaluationSuccessResult,
     /// AI-generated summary of the transcript.
+    #[serde(default)]
     pub transcript_summary: String,
     /// Short title summarizing the call.
     pub call_summary_title: Option<String>,
@@ -772,6 +1145,7 @@ pub struct GetConversationResponse {
     #[serde(default)]
     pub transcript: Vec<ConversationTranscriptEntry>,
     /// Conversation execution metadata.
+    #[serde(default)]
     pub metadata: ConversationMetadata,
     /// Post-call analysis, if available.
     pub analysis: Option<ConversationAnalysis>,
@@ -781,13 +1155,64 @@ pub struct GetConversationResponse {
     /// Unique conversation identifier.
     pub conversation_id: String,
     /// Whether full audio is available.
+    #[serde(default)]
     pub has_audio: bool,
     /// Whether user audio is available.
+    #[serde(default)]
     pub has_user_audio: bool,
     /// Whether response audio is available.
+    #[serde(default)]
     pub has_response_audio: bool,
 }
 
+/// Conversation recording returned by
+/// [`AgentsService::get_conversation_audio`](crate::services::AgentsService::get_conversation_audio).
+///
+/// The API serves a single mixed recording of the whole conversation; the
+/// `has_user_audio` / `has_response_audio` flags on
+/// [`GetConversationResponse`] describe what that recording contains, but
+/// separate user or agent tracks are not downloadable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationAudio {
+    /// Raw audio bytes.
+    pub data: bytes::Bytes,
+    /// MIME type reported by the `Content-Type` header (e.g. `"audio/mpeg"`).
+    pub content_type: String,
+}
+
+impl ConversationAudio {
+    /// Returns the file extension matching [`content_type`](Self::content_type),
+    /// falling back to `"mp3"` (the API's default format) for unknown types.
+    pub fn extension(&self) -> &'static str {
+        let mime = self.content_type.split(';').next().unwrap_or_default().trim();
+        match mime.to_ascii_lowercase().as_str() {
+            "audio/wav" | "audio/wave" | "audio/x-wav" => "wav",
+            "audio/ogg" | "audio/opus" => "ogg",
+            "audio/webm" => "webm",
+            "audio/flac" | "audio/x-flac" => "flac",
+            "audio/mp4" | "audio/aac" | "audio/x-m4a" => "m4a",
+            "audio/pcm" | "audio/l16" => "pcm",
+            "audio/basic" | "audio/x-mulaw" => "ulaw",
+            _ => "mp3",
+        }
+    }
+
+    /// Writes the audio to `path` with its extension replaced by
+    /// [`extension`](Self::extension), returning the path written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub async fn save_to(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> std::io::Result<std::path::PathBuf> {
+        let path = path.as_ref().with_extension(self.extension());
+        tokio::fs::write(&path, &self.data).await?;
+        Ok(path)
+    }
+}
+
 /// Request body for submitting conversation feedback.
 #[derive(Debug, Clone, Serialize)]
 pub struct ConversationFeedbackRequest {
@@ -796,18 +1221,304 @@ pub struct ConversationFeedbackRequest {
     pub feedback: Option<UserFeedbackScore>,
 }
 
+// ===========================================================================
+// Conversations — Transcript export
+// ===========================================================================
+
+/// Placeholder written in place of redacted text.
+const REDACTED: &str = "[redacted]";
+
+/// Which personal data to strip when exporting a [`ConversationTranscript`].
+///
+/// Pattern-based redaction is deliberately simple and errs on the side of
+/// removing too much: any run of seven or more digits (optionally separated
+/// by spaces, dashes, dots or parentheses) counts as a phone number, so
+/// long order numbers and dates are redacted too.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TranscriptRedaction {
+    /// Replace e-mail addresses in messages with `[email]`.
+    pub emails: bool,
+    /// Replace phone numbers in messages with `[phone]`.
+    pub phone_numbers: bool,
+    /// Replace every user message with `[redacted]`.
+    pub user_messages: bool,
+    /// Drop tool call and tool result payloads, which often carry the
+    /// caller's details.
+    pub tool_payloads: bool,
+    /// Exact strings (such as a customer's name) replaced with
+    /// `[redacted]`. Matching is case-sensitive.
+    pub terms: Vec<String>,
+}
+
+impl TranscriptRedaction {
+    /// Redacts e-mail addresses, phone numbers and tool payloads, keeping
+    /// the rest of each message.
+    pub fn pii() -> Self {
+        Self { emails: true, phone_numbers: true, tool_payloads: true, ..Self::default() }
+    }
+
+    /// Adds a literal string to redact.
+    pub fn term(mut self, term: impl Into<String>) -> Self {
+        self.terms.push(term.into());
+        self
+    }
+
+    /// Applies the redaction rules to one message.
+    fn message(&self, role: TranscriptRole, text: &str) -> String {
+        if self.user_messages && role == TranscriptRole::User {
+            return REDACTED.to_owned();
+        }
+        let mut text = text.to_owned();
+        for term in self.terms.iter().filter(|t| !t.is_empty()) {
+            text = text.replace(term.as_str(), REDACTED);
+        }
+        if self.emails {
+            text = redact_emails(&text);
+        }
+        if self.phone_numbers {
+            text = redact_phone_numbers(&text);
+        }
+        text
+    }
+
+    /// Returns a redacted copy of `entry`.
+    fn entry(&self, entry: &ConversationTranscriptEntry) -> ConversationTranscriptEntry {
+        let mut entry = entry.clone();
+        entry.message = entry.message.map(|m| self.message(entry.role, &m));
+        if self.tool_payloads {
+            entry.tool_calls.clear();
+            entry.tool_results.clear();
+        }
+        entry
+    }
+}
+
+/// Export view over a conversation transcript.
+///
+/// Obtained from [`GetConversationResponse::export_transcript`] or
+/// [`ConversationTranscript::new`]. Exports apply the configured
+/// [`TranscriptRedaction`]; the underlying entries are never modified.
+///
+/// # Examples
+///
+/// ```
+/// use elevenlabs_sdk::types::{ConversationTranscript, TranscriptRedaction};
+///
+/// # fn example(conversation: &elevenlabs_sdk::types::GetConversationResponse) {
+/// let transcript = conversation.export_transcript().redact(TranscriptRedaction::pii());
+/// std::fs::write("call.md", transcript.to_markdown()).ok();
+/// std::fs::write("call.srt", transcript.to_srt()).ok();
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationTranscript<'a> {
+    entries: &'a [ConversationTranscriptEntry],
+    redaction: TranscriptRedaction,
+}
+
+impl<'a> ConversationTranscript<'a> {
+    /// Creates an export view over `entries` with no redaction.
+    pub fn new(entries: &'a [ConversationTranscriptEntry]) -> Self {
+        Self { entries, redaction: TranscriptRedaction::default() }
+    }
+
+    /// Sets the redaction applied to every export.
+    pub fn redact(mut self, redaction: TranscriptRedaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Returns the entries with redaction applied.
+    pub fn redacted_entries(&self) -> Vec<ConversationTranscriptEntry> {
+        self.entries.iter().map(|entry| self.redaction.entry(entry)).collect()
+    }
+
+    /// Renders the transcript as Markdown, one paragraph per turn.
+    ///
+    /// Each turn is written as `**Role** (m:ss): message`; turns without a
+    /// message list the tools the agent called instead.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        for entry in self.redacted_entries() {
+            let tools: Vec<&str> = entry
+                .tool_calls
+                .iter()
+                .filter_map(|call| call.get("tool_name").and_then(serde_json::Value::as_str))
+                .collect();
+            let text = match entry.message.as_deref().map(str::trim) {
+                Some(message) if !message.is_empty() => message.to_owned(),
+                _ if !tools.is_empty() => format!("_called {}_", tools.join(", ")),
+                _ => continue,
+            };
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("**{}**", entry.role.label()));
+            if let Some(secs) = entry.time_in_call_secs {
+                out.push_str(&format!(" ({})", format_call_time(secs)));
+            }
+            out.push_str(&format!(": {text}\n"));
+        }
+        out
+    }
+
+    /// Renders the transcript as JSON Lines, one serialized
+    /// [`ConversationTranscriptEntry`] per line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entry cannot be serialized.
+    pub fn to_jsonl(&self) -> serde_json::Result<String> {
+        let mut out = String::new();
+        for entry in self.redacted_entries() {
+            out.push_str(&serde_json::to_string(&entry)?);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Renders the messages as SubRip (`.srt`) captions timed by
+    /// [`time_in_call_secs`](ConversationTranscriptEntry::time_in_call_secs).
+    ///
+    /// Each cue runs until the next timed message starts. The last cue, or
+    /// one followed by a message at the same second, lasts one second per
+    /// 15 characters (at least one second). Messages without a timestamp
+    /// are skipped.
+    pub fn to_srt(&self) -> String {
+        let messages: Vec<(i64, TranscriptRole, String)> = self
+            .redacted_entries()
+            .into_iter()
+            .filter_map(|entry| {
+                let message = entry.message?.trim().to_owned();
+                (!message.is_empty()).then_some((entry.time_in_call_secs?, entry.role, message))
+            })
+            .collect();
+
+        let mut out = String::new();
+        for (index, (start, role, message)) in messages.iter().enumerate() {
+            let start = *start as f64;
+            let estimated = start + (message.chars().count() as f64 / 15.0).max(1.0);
+            let end = messages
+                .get(index + 1)
+                .map(|(next, _, _)| *next as f64)
+                .filter(|next| *next > start)
+                .unwrap_or(estimated);
+            if index > 0 {
+                out.push('\n');
+            }
+            out.push_str(&format!(
+                "{}\n{} --> {}\n{}: {message}\n",
+                index + 1,
+                super::forced_alignment::format_timestamp(start, ',', 3),
+                super::forced_alignment::format_timestamp(end, ',', 3),
+                role.label()
+            ));
+        }
+        out
+    }
+}
+
+impl GetConversationResponse {
+    /// Returns an export view over [`transcript`](Self::transcript).
+    pub fn export_transcript(&self) -> ConversationTranscript<'_> {
+        ConversationTranscript::new(&self.transcript)
+    }
+}
+
+impl TranscriptRole {
+    /// Display name used in transcript exports.
+    const fn label(self) -> &'static str {
+        match self {
+            Self::User => "User",
+            Self::Agent => "Agent",
+        }
+    }
+}
+
+/// Formats seconds into the call as `m:ss`, or `h:mm:ss` past an hour.
+fn format_call_time(secs: i64) -> String {
+    let secs = secs.max(0);
+    let (hours, minutes, seconds) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+/// Replaces whitespace-separated words that look like e-mail addresses
+/// with `[email]`, keeping surrounding punctuation.
+fn redact_emails(text: &str) -> String {
+    let is_email = |word: &str| {
+        word.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty() &&
+                !domain.contains('@') &&
+                domain.split('.').count() > 1 &&
+                domain.split('.').all(|label| !label.is_empty())
+        })
+    };
+
+    let mut out = String::with_capacity(text.len());
+    for piece in text.split_inclusive(char::is_whitespace) {
+        let word = piece.trim_end_matches(char::is_whitespace);
+        let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if is_email(core) {
+            // `core` is a subslice of `word`, so the offsets are valid.
+            let lead = word.len() - word.trim_start_matches(|c: char| !c.is_alphanumeric()).len();
+            out.push_str(&word[..lead]);
+            out.push_str("[email]");
+            out.push_str(&piece[lead + core.len()..]);
+        } else {
+            out.push_str(piece);
+        }
+    }
+    out
+}
+
+/// Replaces runs of seven or more digits, optionally separated by spaces,
+/// `+`, `-`, `.` or parentheses, with `[phone]`.
+fn redact_phone_numbers(text: &str) -> String {
+    let is_phone_char = |c: char| c.is_ascii_digit() || " +-.()".contains(c);
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit() || c == '+' || c == '(') {
+        out.push_str(&rest[..start]);
+        let candidate = &rest[start..];
+        let len = candidate.find(|c: char| !is_phone_char(c)).unwrap_or(candidate.len());
+        let run = candidate[..len].trim_end_matches(|c: char| !c.is_ascii_digit());
+        if run.is_empty() {
+            // A lone `+` or `(`: keep it and move on.
+            out.push_str(&candidate[..1]);
+            rest = &candidate[1..];
+            continue;
+        }
+        if run.chars().filter(char::is_ascii_digit).count() >= 7 {
+            out.push_str("[phone]");
+        } else {
+            out.push_str(run);
+        }
+        rest = &candidate[run.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
 // ===========================================================================
 // Knowledge Base
 // ===========================================================================
 
 /// Metadata for a knowledge base document.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct KnowledgeBaseDocumentMetadata {
     /// Creation time in Unix seconds.
-    pub created_at_unix_secs: i64,
+    #[serde(default)]
+    pub created_at_unix_secs: Timestamp,
     /// Last update time in Unix seconds.
-    pub last_updated_at_unix_secs: i64,
+    #[serde(default)]
+    pub last_updated_at_unix_secs: Timestamp,
     /// Document size in bytes.
+    #[serde(default)]
     pub size_bytes: i64,
 }
 
@@ -827,8 +1538,10 @@ pub struct KnowledgeBaseDocumentSummary {
     /// Document identifier.
     pub id: String,
     /// Document display name.
+    #[serde(default)]
     pub name: String,
     /// Document metadata (timestamps, size).
+    #[serde(default)]
     pub metadata: KnowledgeBaseDocumentMetadata,
     /// Supported usage modes.
     #[serde(default)]
@@ -845,6 +1558,7 @@ pub struct KnowledgeBaseDocumentSummary {
     pub dependent_agents: Vec<serde_json::Value>,
     /// Document type discriminator (`url`, `file`, `text`, `folder`).
     #[serde(rename = "type")]
+    #[serde(default)]
     pub document_type: String,
     /// URL for URL-type documents.
     #[serde(default)]
@@ -858,10 +1572,12 @@ pub struct KnowledgeBaseDocumentSummary {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetKnowledgeBaseListResponse {
     /// List of documents.
+    #[serde(default)]
     pub documents: Vec<KnowledgeBaseDocumentSummary>,
     /// Cursor for the next page, if any.
     pub next_cursor: Option<String>,
     /// Whether more pages exist.
+    #[serde(default)]
     pub has_more: bool,
 }
 
@@ -871,6 +1587,7 @@ pub struct AddKnowledgeBaseResponse {
     /// New document identifier.
     pub id: String,
     /// Document display name.
+    #[serde(default)]
     pub name: String,
     /// Folder path to the document.
     #[serde(default)]
@@ -922,46 +1639,242 @@ pub struct UpdateKnowledgeBaseDocumentRequest {
 }
 
 // ===========================================================================
-// Phone Numbers
+// Knowledge Base — RAG indexes
 // ===========================================================================
 
-/// Agent info attached to a phone number.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct PhoneNumberAgentInfo {
-    /// Assigned agent identifier.
-    pub agent_id: String,
-    /// Assigned agent name.
-    pub agent_name: String,
+/// Embedding model used to build a RAG index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EmbeddingModel {
+    /// `e5_mistral_7b_instruct`.
+    #[serde(rename = "e5_mistral_7b_instruct")]
+    E5Mistral7bInstruct,
+    /// `multilingual_e5_large_instruct`.
+    #[serde(rename = "multilingual_e5_large_instruct")]
+    MultilingualE5LargeInstruct,
+    /// `qwen3_embedding_4b`.
+    #[serde(rename = "qwen3_embedding_4b")]
+    Qwen3Embedding4b,
 }
 
-/// Twilio phone number configuration.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct PhoneNumberTwilio {
-    /// Phone number string (E.164 format).
-    pub phone_number: String,
-    /// Display label for the number.
-    pub label: String,
-    /// Unique phone number identifier.
-    pub phone_number_id: String,
-    /// Agent assigned to this number, if any.
-    pub assigned_agent: Option<PhoneNumberAgentInfo>,
-    /// Provider type (always `"twilio"`).
-    #[serde(default)]
-    pub provider: Option<String>,
+impl EmbeddingModel {
+    /// Returns the wire name of the model.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::E5Mistral7bInstruct => "e5_mistral_7b_instruct",
+            Self::MultilingualE5LargeInstruct => "multilingual_e5_large_instruct",
+            Self::Qwen3Embedding4b => "qwen3_embedding_4b",
+        }
+    }
 }
 
-/// SIP trunk phone number configuration.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct PhoneNumberSipTrunk {
-    /// Phone number string.
-    pub phone_number: String,
-    /// Display label for the number.
-    pub label: String,
-    /// Unique phone number identifier.
-    pub phone_number_id: String,
-    /// Agent assigned to this number, if any.
-    pub assigned_agent: Option<PhoneNumberAgentInfo>,
-    /// Provider type (always `"sip_trunk"`).
+impl std::fmt::Display for EmbeddingModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Indexing state of a RAG index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RagIndexStatus {
+    /// The index was created and is waiting to be processed.
+    Created,
+    /// The document is being embedded.
+    Processing,
+    /// Indexing failed.
+    Failed,
+    /// The index is ready to use.
+    Succeeded,
+    /// The workspace's RAG storage limit was reached.
+    RagLimitExceeded,
+    /// The document is too small to be worth indexing.
+    DocumentTooSmall,
+    /// Folders cannot be indexed.
+    CannotIndexFolder,
+}
+
+impl RagIndexStatus {
+    /// Returns `true` once indexing has stopped, successfully or not.
+    pub const fn is_terminal(self) -> bool {
+        !matches!(self, Self::Created | Self::Processing)
+    }
+
+    /// Returns `true` if the index is ready to use.
+    pub const fn is_ready(self) -> bool {
+        matches!(self, Self::Succeeded)
+    }
+}
+
+/// Storage used by a single RAG index.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RagDocumentIndexUsage {
+    /// Bytes used by the index.
+    #[serde(default)]
+    pub used_bytes: i64,
+}
+
+/// A document's RAG index for one embedding model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RagDocumentIndex {
+    /// RAG index identifier.
+    pub id: String,
+    /// Embedding model the index is built with.
+    pub model: EmbeddingModel,
+    /// Indexing state.
+    pub status: RagIndexStatus,
+    /// Indexing progress, from 0 to 100.
+    #[serde(default)]
+    pub progress_percentage: f64,
+    /// Storage used by the index.
+    #[serde(default)]
+    pub document_model_index_usage: RagDocumentIndexUsage,
+}
+
+/// Response for listing a document's RAG indexes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GetDocumentRagIndexesResponse {
+    /// One index per embedding model.
+    #[serde(default)]
+    pub indexes: Vec<RagDocumentIndex>,
+}
+
+/// Request to compute a document's RAG index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CreateRagIndexRequest {
+    /// Embedding model to index the document with.
+    pub model: EmbeddingModel,
+}
+
+/// One document in a [`RagIndexBatchRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RagIndexBatchItem {
+    /// Knowledge base document ID.
+    pub document_id: String,
+    /// Embedding model of the index.
+    pub model: EmbeddingModel,
+    /// Whether to start indexing if the index does not exist yet.
+    pub create_if_missing: bool,
+}
+
+/// Request to get, and optionally create, RAG indexes for several documents.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RagIndexBatchRequest {
+    /// Documents to look up.
+    pub items: Vec<RagIndexBatchItem>,
+}
+
+/// Result for one document in a RAG index batch, keyed by document ID in the
+/// response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RagIndexBatchResult {
+    /// The index was found or created.
+    Success {
+        /// The document's index.
+        data: RagDocumentIndex,
+    },
+    /// The index could not be found or created.
+    Failure {
+        /// HTTP-style error code.
+        error_code: i64,
+        /// Error message.
+        message: String,
+    },
+}
+
+/// Storage used by all RAG indexes of one embedding model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RagIndexModelUsage {
+    /// Embedding model.
+    pub model: EmbeddingModel,
+    /// Bytes used by the model's indexes.
+    #[serde(default)]
+    pub used_bytes: i64,
+}
+
+/// Workspace-wide RAG storage usage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RagIndexOverview {
+    /// Bytes used by all indexes.
+    #[serde(default)]
+    pub total_used_bytes: i64,
+    /// Storage limit in bytes.
+    #[serde(default)]
+    pub total_max_bytes: i64,
+    /// Usage per embedding model.
+    #[serde(default)]
+    pub models: Vec<RagIndexModelUsage>,
+}
+
+/// Options for waiting on a document's RAG index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RagIndexWaitOptions {
+    /// Embedding model of the index to wait for. `None` waits for the
+    /// document's first index.
+    pub model: Option<EmbeddingModel>,
+    /// Delay between status polls.
+    pub poll_interval: Duration,
+    /// Gives up after this long. Indexing keeps running server-side; only
+    /// the polling stops.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for RagIndexWaitOptions {
+    fn default() -> Self {
+        Self {
+            model: None,
+            poll_interval: Duration::from_secs(2),
+            timeout: Some(Duration::from_mins(10)),
+        }
+    }
+}
+
+// ===========================================================================
+// Phone Numbers
+// ===========================================================================
+
+/// Agent info attached to a phone number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoneNumberAgentInfo {
+    /// Assigned agent identifier.
+    pub agent_id: String,
+    /// Assigned agent name.
+    #[serde(default)]
+    pub agent_name: String,
+}
+
+/// Twilio phone number configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoneNumberTwilio {
+    /// Phone number string (E.164 format).
+    #[serde(default)]
+    pub phone_number: String,
+    /// Display label for the number.
+    #[serde(default)]
+    pub label: String,
+    /// Unique phone number identifier.
+    pub phone_number_id: String,
+    /// Agent assigned to this number, if any.
+    pub assigned_agent: Option<PhoneNumberAgentInfo>,
+    /// Provider type (always `"twilio"`).
+    #[serde(default)]
+    pub provider: Option<String>,
+}
+
+/// SIP trunk phone number configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoneNumberSipTrunk {
+    /// Phone number string.
+    #[serde(default)]
+    pub phone_number: String,
+    /// Display label for the number.
+    #[serde(default)]
+    pub label: String,
+    /// Unique phone number identifier.
+    pub phone_number_id: String,
+    /// Agent assigned to this number, if any.
+    pub assigned_agent: Option<PhoneNumberAgentInfo>,
+    /// Provider type (always `"sip_trunk"`).
     #[serde(default)]
     pub provider: Option<String>,
     /// Outbound SIP trunk configuration.
@@ -982,28 +1895,105 @@ pub struct CreatePhoneNumberResponse {
 // ===========================================================================
 
 /// Tool usage statistics.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ToolUsageStats {
-    /// Usage statistics as opaque JSON (varies by tool type).
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    /// Total number of calls made to the tool.
+    #[serde(default)]
+    pub total_calls: i64,
+    /// Average call latency in seconds.
+    #[serde(default)]
+    pub avg_latency_secs: f64,
 }
 
 /// Response model for a tool.
-///
-/// The `tool_config` is represented as `serde_json::Value` because it's
-/// a discriminated union of webhook, client, system, and MCP tool configs
-/// with deeply nested sub-types.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ToolResponse {
     /// Tool identifier.
     pub id: String,
     /// Tool configuration (webhook, client, system, or MCP).
-    pub tool_config: serde_json::Value,
+    #[serde(default)]
+    pub tool_config: Option<ToolConfig>,
     /// Access information for the requesting user.
     pub access_info: ResourceAccessInfo,
     /// Tool usage statistics.
-    pub usage_stats: serde_json::Value,
+    #[serde(default)]
+    pub usage_stats: ToolUsageStats,
+}
+
+impl ToolResponse {
+    /// Returns the tool name from its configuration, if present.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.tool_config.as_ref().map(ToolConfig::name).filter(|name| !name.is_empty())
+    }
+}
+
+/// Usage summary for a single tool in a [`ToolUsageReport`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolUsageEntry {
+    /// Tool identifier.
+    pub tool_id: String,
+    /// Tool name, if the configuration has one.
+    pub name: Option<String>,
+    /// Total number of calls made to the tool.
+    #[serde(default)]
+    pub total_calls: i64,
+    /// Average call latency in seconds.
+    #[serde(default)]
+    pub avg_latency_secs: f64,
+}
+
+/// Usage report across all tools in a workspace.
+///
+/// Entries are ordered from least to most used, so unused tools and tools
+/// that are rarely invoked come first. The API does not report per-tool
+/// failure counts or last-used timestamps, so call volume and latency are
+/// the only signals available.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolUsageReport {
+    /// Per-tool usage, ordered by ascending call count.
+    #[serde(default)]
+    pub entries: Vec<ToolUsageEntry>,
+}
+
+impl ToolUsageReport {
+    /// Builds a report from a list of tools.
+    #[must_use]
+    pub fn from_tools(tools: &[ToolResponse]) -> Self {
+        let mut entries: Vec<ToolUsageEntry> = tools
+            .iter()
+            .map(|tool| ToolUsageEntry {
+                tool_id: tool.id.clone(),
+                name: tool.name().map(str::to_owned),
+                total_calls: tool.usage_stats.total_calls,
+                avg_latency_secs: tool.usage_stats.avg_latency_secs,
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            a.total_calls.cmp(&b.total_calls).then_with(|| a.tool_id.cmp(&b.tool_id))
+        });
+        Self { entries }
+    }
+
+    /// Returns tools that have never been called.
+    pub fn unused(&self) -> impl Iterator<Item = &ToolUsageEntry> {
+        self.entries.iter().filter(|entry| entry.total_calls == 0)
+    }
+
+    /// Returns up to `n` of the least-used tools.
+    #[must_use]
+    pub fn least_used(&self, n: usize) -> &[ToolUsageEntry] {
+        &self.entries[..n.min(self.entries.len())]
+    }
+
+    /// Returns up to `n` tools with the highest average latency.
+    #[must_use]
+    pub fn slowest(&self, n: usize) -> Vec<&ToolUsageEntry> {
+        let mut by_latency: Vec<&ToolUsageEntry> = self.entries.iter().collect();
+        by_latency.sort_by(|a, b| b.avg_latency_secs.total_cmp(&a.avg_latency_secs));
+        by_latency.truncate(n);
+        by_latency
+    }
 }
 
 // ===========================================================================
@@ -1011,44 +2001,247 @@ pub struct ToolResponse {
 // ===========================================================================
 
 /// Metadata for an MCP server.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct McpServerMetadata {
     /// Creation time in Unix seconds.
-    pub created_at: i64,
+    #[serde(default)]
+    pub created_at: Timestamp,
     /// Owner user identifier.
     pub owner_user_id: Option<String>,
 }
 
-/// MCP server configuration (output/response variant).
-///
-/// Complex sub-fields (URL variants, secret tokens, request headers) are
-/// represented as `serde_json::Value` for flexibility.
+/// Reference to a secret in the workspace secret store, resolved by the
+/// server.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SecretRef {
+    /// ID of the secret.
+    pub secret_id: String,
+}
+
+/// URL of an MCP server, given directly or kept in a workspace secret.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum McpServerUrl {
+    /// A literal URL.
+    Plain(String),
+    /// A URL stored as a workspace secret.
+    Secret(SecretRef),
+}
+
+impl From<&str> for McpServerUrl {
+    fn from(url: &str) -> Self {
+        Self::Plain(url.to_owned())
+    }
+}
+
+impl From<String> for McpServerUrl {
+    fn from(url: String) -> Self {
+        Self::Plain(url)
+    }
+}
+
+/// MCP server configuration (output/response variant).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct McpServerConfig {
     /// Approval policy for tool invocations.
     #[serde(default)]
-    pub approval_policy: Option<String>,
+    pub approval_policy: Option<McpApprovalPolicy>,
     /// Transport type used to connect.
     pub transport: Option<McpServerTransport>,
-    /// Server URL (may be a string or secret locator).
-    pub url: Option<serde_json::Value>,
-    /// Secret token for authorization.
-    pub secret_token: Option<serde_json::Value>,
+    /// Server URL.
+    pub url: Option<McpServerUrl>,
+    /// Secret sent as the bearer token.
+    pub secret_token: Option<SecretRef>,
     /// Custom request headers.
     #[serde(default)]
-    pub request_headers: HashMap<String, serde_json::Value>,
+    pub request_headers: HashMap<String, WebhookHeaderValue>,
     /// Server display name.
     pub name: Option<String>,
     /// Server description.
     pub description: Option<String>,
 }
 
+/// Request body for creating an MCP server.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{
+///     CreateMcpServerRequest, McpApprovalPolicy, McpServerTransport, WebhookHeaderValue,
+/// };
+///
+/// let request = CreateMcpServerRequest::new("CRM", "https://mcp.example.com/mcp")
+///     .transport(McpServerTransport::StreamableHttp)
+///     .secret_token("sec_1")
+///     .approval_policy(McpApprovalPolicy::RequireApprovalPerTool)
+///     .header("X-Tenant", WebhookHeaderValue::Plain("acme".into()));
+///
+/// let json = serde_json::to_value(&request).unwrap();
+/// assert_eq!(json["config"]["transport"], "STREAMABLE_HTTP");
+/// assert_eq!(json["config"]["secret_token"]["secret_id"], "sec_1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateMcpServerRequest {
+    /// Server display name.
+    pub name: String,
+    /// Server description.
+    pub description: Option<String>,
+    /// Server URL.
+    pub url: McpServerUrl,
+    /// Transport used to connect (default: SSE).
+    pub transport: McpServerTransport,
+    /// Secret sent as the bearer token.
+    pub secret_token: Option<SecretRef>,
+    /// Approval policy for tool calls; the server default applies when
+    /// unset.
+    pub approval_policy: Option<McpApprovalPolicy>,
+    /// Headers sent with every request to the server.
+    pub request_headers: BTreeMap<String, WebhookHeaderValue>,
+}
+
+impl CreateMcpServerRequest {
+    /// Creates a request for an SSE server at `url`.
+    pub fn new(name: impl Into<String>, url: impl Into<McpServerUrl>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            url: url.into(),
+            transport: McpServerTransport::Sse,
+            secret_token: None,
+            approval_policy: None,
+            request_headers: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the transport.
+    pub const fn transport(mut self, transport: McpServerTransport) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Sends the workspace secret `secret_id` as the bearer token.
+    pub fn secret_token(mut self, secret_id: impl Into<String>) -> Self {
+        self.secret_token = Some(SecretRef { secret_id: secret_id.into() });
+        self
+    }
+
+    /// Sets the approval policy.
+    pub const fn approval_policy(mut self, policy: McpApprovalPolicy) -> Self {
+        self.approval_policy = Some(policy);
+        self
+    }
+
+    /// Adds a request header.
+    pub fn header(mut self, name: impl Into<String>, value: WebhookHeaderValue) -> Self {
+        self.request_headers.insert(name.into(), value);
+        self
+    }
+}
+
+impl Serialize for CreateMcpServerRequest {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Config<'a> {
+            name: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: Option<&'a str>,
+            url: &'a McpServerUrl,
+            transport: McpServerTransport,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            secret_token: Option<&'a SecretRef>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            approval_policy: Option<McpApprovalPolicy>,
+            #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+            request_headers: &'a BTreeMap<String, WebhookHeaderValue>,
+        }
+
+        let config = Config {
+            name: &self.name,
+            description: self.description.as_deref(),
+            url: &self.url,
+            transport: self.transport,
+            secret_token: self.secret_token.as_ref(),
+            approval_policy: self.approval_policy,
+            request_headers: &self.request_headers,
+        };
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("config", &config)?;
+        map.end()
+    }
+}
+
+/// Request body for updating an MCP server. Unset fields are left
+/// unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateMcpServerRequest {
+    /// New approval policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_policy: Option<McpApprovalPolicy>,
+    /// Replacement request headers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_headers: Option<BTreeMap<String, WebhookHeaderValue>>,
+    /// Whether the agent speaks before calling the server's tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_pre_tool_speech: Option<bool>,
+    /// Whether the user can interrupt while a tool runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_interruptions: Option<bool>,
+}
+
+/// A tool offered by an MCP server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpServerTool {
+    /// Tool name.
+    pub name: String,
+    /// Tool description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// JSON Schema of the tool's arguments.
+    #[serde(rename = "inputSchema", default)]
+    pub input_schema: Option<serde_json::Value>,
+}
+
+/// Response from listing the tools of an MCP server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpServerToolsResponse {
+    /// Whether the server answered the tool listing.
+    pub success: bool,
+    /// Tools offered by the server.
+    #[serde(default)]
+    pub tools: Vec<McpServerTool>,
+    /// Why the listing failed, if it did.
+    pub error_message: Option<String>,
+}
+
+/// Result of [`AgentsService::test_mcp_server`](crate::services::AgentsService::test_mcp_server).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpServerTestReport {
+    /// Whether the server listed its tools.
+    pub reachable: bool,
+    /// Round-trip time of the tool listing, including the hop through the
+    /// ElevenLabs API.
+    pub latency: Duration,
+    /// Tools offered by the server.
+    pub tools: Vec<McpServerTool>,
+    /// Why the server could not be reached, if it could not.
+    pub error: Option<String>,
+}
+
 /// Response model for a single MCP server.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct McpServerResponse {
     /// MCP server identifier.
     pub id: String,
     /// Server configuration.
+    #[serde(default)]
     pub config: McpServerConfig,
     /// Access information for the requesting user.
     pub access_info: Option<ResourceAccessInfo>,
@@ -1056,6 +2249,7 @@ pub struct McpServerResponse {
     #[serde(default)]
     pub dependent_agents: Vec<serde_json::Value>,
     /// Server metadata.
+    #[serde(default)]
     pub metadata: McpServerMetadata,
 }
 
@@ -1063,6 +2257,7 @@ pub struct McpServerResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct McpServersResponse {
     /// List of MCP server entries.
+    #[serde(default)]
     pub mcp_servers: Vec<McpServerResponse>,
 }
 
@@ -1095,13 +2290,16 @@ pub struct BatchCallResponse {
     /// WhatsApp parameters, if applicable.
     pub whatsapp_params: Option<BatchCallWhatsAppParams>,
     /// Batch call display name.
+    #[serde(default)]
     pub name: String,
     /// Agent used for the calls.
     pub agent_id: String,
     /// Creation time in Unix seconds.
-    pub created_at_unix: i64,
+    #[serde(default)]
+    pub created_at_unix: Timestamp,
     /// Scheduled execution time in Unix seconds.
-    pub scheduled_time_unix: i64,
+    #[serde(default)]
+    pub scheduled_time_unix: Timestamp,
     /// Timezone for scheduling.
     pub timezone: Option<String>,
     /// Number of calls dispatched.
@@ -1114,13 +2312,15 @@ pub struct BatchCallResponse {
     #[serde(default)]
     pub total_calls_finished: i64,
     /// Last update time in Unix seconds.
-    pub last_updated_at_unix: i64,
+    #[serde(default)]
+    pub last_updated_at_unix: Timestamp,
     /// Batch call status.
     pub status: BatchCallStatus,
     /// Number of retry attempts.
     #[serde(default)]
     pub retry_count: i64,
     /// Agent display name.
+    #[serde(default)]
     pub agent_name: String,
 }
 
@@ -1128,6 +2328,7 @@ pub struct BatchCallResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkspaceBatchCallsResponse {
     /// List of batch call entries.
+    #[serde(default)]
     pub batch_calls: Vec<BatchCallResponse>,
     /// Cursor for the next page (named `next_doc` in the API).
     pub next_doc: Option<String>,
@@ -1143,11 +2344,11 @@ pub struct SubmitBatchCallRequest {
     pub call_name: String,
     /// Agent to use for the calls.
     pub agent_id: String,
-    /// List of recipients (opaque — includes phone/name/metadata per recipient).
-    pub recipients: Vec<serde_json::Value>,
+    /// People to call.
+    pub recipients: Vec<BatchCallRecipient>,
     /// Scheduled execution time in Unix seconds.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_time_unix: Option<i64>,
+    pub scheduled_time_unix: Option<Timestamp>,
     /// Phone number to call from.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agent_phone_number_id: Option<String>,
@@ -1159,6 +2360,263 @@ pub struct SubmitBatchCallRequest {
     pub timezone: Option<String>,
 }
 
+impl SubmitBatchCallRequest {
+    /// Creates a request that calls `recipients` with `agent_id` right away.
+    pub fn new(
+        call_name: impl Into<String>,
+        agent_id: impl Into<String>,
+        recipients: impl Into<Vec<BatchCallRecipient>>,
+    ) -> Self {
+        Self {
+            call_name: call_name.into(),
+            agent_id: agent_id.into(),
+            recipients: recipients.into(),
+            scheduled_time_unix: None,
+            agent_phone_number_id: None,
+            whatsapp_params: None,
+            timezone: None,
+        }
+    }
+}
+
+/// One person to call in a batch.
+///
+/// `dynamic_variables` are sent inside
+/// `conversation_initiation_client_data`, so the agent can reference them as
+/// `{{name}}` in its prompt and first message.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::BatchCallRecipient;
+///
+/// let recipient = BatchCallRecipient::new("+14155550100").dynamic_variable("first_name", "Ada");
+/// let json = serde_json::to_value(&recipient).unwrap();
+/// assert_eq!(
+///     json["conversation_initiation_client_data"]["dynamic_variables"]["first_name"],
+///     "Ada"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchCallRecipient {
+    /// Number to call in E.164 format, e.g. `"+14155550100"`.
+    pub phone_number: String,
+    /// Other client data for the conversation (config overrides and so on).
+    /// Must be a JSON object; `dynamic_variables` are merged into it.
+    pub conversation_initiation_client_data: Option<serde_json::Value>,
+    /// Values for the agent's dynamic variables.
+    pub dynamic_variables: BTreeMap<String, serde_json::Value>,
+}
+
+impl BatchCallRecipient {
+    /// Creates a recipient with no dynamic variables.
+    pub fn new(phone_number: impl Into<String>) -> Self {
+        Self { phone_number: phone_number.into(), ..Self::default() }
+    }
+
+    /// Sets a dynamic variable.
+    pub fn dynamic_variable(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.dynamic_variables.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the other client data for the conversation.
+    pub fn client_data(mut self, data: serde_json::Value) -> Self {
+        self.conversation_initiation_client_data = Some(data);
+        self
+    }
+
+    /// Returns the client data as sent, with `dynamic_variables` merged in.
+    /// Variables set on the recipient win over ones already in the data.
+    fn merged_client_data(&self) -> Option<serde_json::Value> {
+        if self.dynamic_variables.is_empty() {
+            return self.conversation_initiation_client_data.clone();
+        }
+        let mut data = match &self.conversation_initiation_client_data {
+            Some(serde_json::Value::Object(map)) => map.clone(),
+            _ => serde_json::Map::new(),
+        };
+        let vars = data
+            .entry("dynamic_variables")
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        if !vars.is_object() {
+            *vars = serde_json::Value::Object(serde_json::Map::new());
+        }
+        if let serde_json::Value::Object(vars) = vars {
+            vars.extend(self.dynamic_variables.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        Some(serde_json::Value::Object(data))
+    }
+}
+
+impl Serialize for BatchCallRecipient {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let client_data = self.merged_client_data();
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("phone_number", &self.phone_number)?;
+        if let Some(data) = &client_data {
+            map.serialize_entry("conversation_initiation_client_data", data)?;
+        }
+        map.end()
+    }
+}
+
+/// Recipients for a batch call, usually loaded from a CSV file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchCallRecipients(pub Vec<BatchCallRecipient>);
+
+impl BatchCallRecipients {
+    /// Reads recipients from CSV with a header row.
+    ///
+    /// The `phone_number` (or `phone`) column is required and must hold
+    /// E.164 numbers. Every other column becomes a dynamic variable named
+    /// after its header; empty cells are left out. Blank rows are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BatchCallCsvError`] if the input cannot be read, is not
+    /// valid CSV, has no phone column, or has a number that is not E.164.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elevenlabs_sdk::types::BatchCallRecipients;
+    ///
+    /// let csv = "phone_number,first_name\n+14155550100,Ada\n+442071838750,Alan\n";
+    /// let recipients = BatchCallRecipients::from_csv(csv.as_bytes()).unwrap();
+    /// assert_eq!(recipients.0.len(), 2);
+    /// assert_eq!(recipients.0[1].dynamic_variables["first_name"], "Alan");
+    /// ```
+    pub fn from_csv(mut reader: impl std::io::Read) -> Result<Self, BatchCallCsvError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+
+        let mut rows = parse_csv(&input)?.into_iter();
+        let header = rows.next().ok_or(BatchCallCsvError::Empty)?;
+        let header: Vec<&str> = header.iter().map(|h| h.trim()).collect();
+        let phone_col = header
+            .iter()
+            .position(|h| h.eq_ignore_ascii_case("phone_number") || h.eq_ignore_ascii_case("phone"))
+            .ok_or(BatchCallCsvError::MissingPhoneColumn)?;
+
+        let mut recipients = Vec::new();
+        for (i, row) in rows.enumerate() {
+            if row.iter().all(|cell| cell.trim().is_empty()) {
+                continue;
+            }
+            let phone = row.get(phone_col).map_or("", |cell| cell.trim());
+            if !is_e164(phone) {
+                return Err(BatchCallCsvError::InvalidPhoneNumber {
+                    line: i + 2,
+                    value: phone.to_owned(),
+                });
+            }
+            let mut recipient = BatchCallRecipient::new(phone);
+            for (col, cell) in row.iter().enumerate() {
+                let name = header.get(col).copied().unwrap_or_default();
+                if col == phone_col || name.is_empty() || cell.trim().is_empty() {
+                    continue;
+                }
+                recipient = recipient.dynamic_variable(name, cell.trim());
+            }
+            recipients.push(recipient);
+        }
+        Ok(Self(recipients))
+    }
+}
+
+impl From<BatchCallRecipients> for Vec<BatchCallRecipient> {
+    fn from(recipients: BatchCallRecipients) -> Self {
+        recipients.0
+    }
+}
+
+impl IntoIterator for BatchCallRecipients {
+    type Item = BatchCallRecipient;
+    type IntoIter = std::vec::IntoIter<BatchCallRecipient>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Error returned by [`BatchCallRecipients::from_csv`].
+#[derive(Debug, thiserror::Error)]
+pub enum BatchCallCsvError {
+    /// The input could not be read.
+    #[error("failed to read recipients CSV: {0}")]
+    Io(#[from] std::io::Error),
+    /// The input has no header row.
+    #[error("recipients CSV is empty")]
+    Empty,
+    /// A quoted field is not closed.
+    #[error("recipients CSV has an unterminated quoted field")]
+    UnterminatedQuote,
+    /// The header has no `phone_number` column.
+    #[error("recipients CSV has no `phone_number` column")]
+    MissingPhoneColumn,
+    /// A phone number is not in E.164 format.
+    #[error("line {line}: `{value}` is not an E.164 phone number")]
+    InvalidPhoneNumber {
+        /// 1-based line of the row, counting the header as line 1.
+        line: usize,
+        /// The rejected value.
+        value: String,
+    },
+}
+
+/// Returns `true` for `+` followed by 2 to 15 digits, the first non-zero.
+fn is_e164(number: &str) -> bool {
+    let Some(digits) = number.strip_prefix('+') else {
+        return false;
+    };
+    (2..=15).contains(&digits.len()) &&
+        !digits.starts_with('0') &&
+        digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parses RFC 4180 CSV: comma-separated fields, optionally double-quoted,
+/// with `""` escaping a quote inside a quoted field.
+fn parse_csv(input: &str) -> Result<Vec<Vec<String>>, BatchCallCsvError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(BatchCallCsvError::UnterminatedQuote);
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
 // ===========================================================================
 // Secrets
 // ===========================================================================
@@ -1168,12 +2626,15 @@ pub struct SubmitBatchCallRequest {
 pub struct WorkspaceStoredSecret {
     /// Secret type discriminator (always `"stored"`).
     #[serde(rename = "type")]
+    #[serde(default)]
     pub secret_type: String,
     /// Secret identifier.
     pub secret_id: String,
     /// Secret display name.
+    #[serde(default)]
     pub name: String,
     /// Resources that depend on this secret.
+    #[serde(default)]
     pub used_by: serde_json::Value,
 }
 
@@ -1202,7 +2663,7 @@ pub struct UpdateSecretRequest {
 // ===========================================================================
 
 /// ConvAI webhook configuration.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConvAiWebhooks {
     /// Post-call webhook identifier.
     pub post_call_webhook_id: Option<String>,
@@ -1211,12 +2672,81 @@ pub struct ConvAiWebhooks {
     pub events: Vec<WebhookEventType>,
 }
 
+/// Value of a header sent with a [`ConversationInitiationWebhook`] or a
+/// [`WebhookApiSchema`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WebhookHeaderValue {
+    /// A literal header value.
+    Plain(String),
+    /// A reference to a workspace secret, resolved by the server.
+    Secret {
+        /// ID of the secret in the workspace secret store.
+        secret_id: String,
+    },
+    /// A dynamic variable, resolved per conversation. Only supported by
+    /// webhook tools.
+    DynamicVariable {
+        /// Name of the dynamic variable.
+        dynamic_variable: String,
+    },
+}
+
+/// Webhook called at the start of each conversation to fetch
+/// conversation initiation client data.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{ConversationInitiationWebhook, WebhookHeaderValue};
+///
+/// let webhook = ConversationInitiationWebhook::new("https://example.com/webhook")
+///     .header("X-Source", WebhookHeaderValue::Plain("elevenlabs".into()))
+///     .header("Authorization", WebhookHeaderValue::Secret { secret_id: "sec_1".into() });
+/// let json = serde_json::to_value(&webhook).unwrap();
+/// assert_eq!(json["request_headers"]["Authorization"]["secret_id"], "sec_1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationInitiationWebhook {
+    /// URL the webhook is sent to.
+    pub url: String,
+    /// Headers sent with the webhook request.
+    #[serde(default)]
+    pub request_headers: BTreeMap<String, WebhookHeaderValue>,
+}
+
+impl ConversationInitiationWebhook {
+    /// Creates a webhook for `url` without extra headers.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into(), request_headers: BTreeMap::new() }
+    }
+
+    /// Adds a header sent with the webhook request.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: WebhookHeaderValue) -> Self {
+        self.request_headers.insert(name.into(), value);
+        self
+    }
+}
+
+/// LiveKit stack used for new conversations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LivekitStackType {
+    /// The standard stack.
+    #[default]
+    Standard,
+    /// The static stack.
+    Static,
+}
+
 /// Workspace-level ConvAI settings.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetConvAiSettingsResponse {
     /// Conversation initiation data webhook configuration.
-    pub conversation_initiation_client_data_webhook: Option<serde_json::Value>,
+    pub conversation_initiation_client_data_webhook: Option<ConversationInitiationWebhook>,
     /// Webhook configuration.
+    #[serde(default)]
     pub webhooks: ConvAiWebhooks,
     /// Whether MCP servers are enabled for the workspace.
     #[serde(default)]
@@ -1226,13 +2756,110 @@ pub struct GetConvAiSettingsResponse {
     pub rag_retention_period_days: i64,
     /// Default LiveKit stack type.
     #[serde(default)]
-    pub default_livekit_stack: Option<String>,
+    pub default_livekit_stack: Option<LivekitStackType>,
 }
 
 const fn default_rag_retention() -> i64 {
     10
 }
 
+/// Request body for `PATCH /v1/convai/settings`.
+///
+/// Only the fields that are set are sent.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{ConversationInitiationWebhook, PatchConvAiSettingsRequest};
+///
+/// let request = PatchConvAiSettingsRequest {
+///     conversation_initiation_client_data_webhook: Some(ConversationInitiationWebhook::new(
+///         "https://example.com/webhook",
+///     )),
+///     rag_retention_period_days: Some(30),
+///     ..Default::default()
+/// };
+/// let json = serde_json::to_value(&request).unwrap();
+/// assert_eq!(json["rag_retention_period_days"], 30);
+/// assert!(json.get("webhooks").is_none());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchConvAiSettingsRequest {
+    /// Conversation initiation data webhook configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation_initiation_client_data_webhook: Option<ConversationInitiationWebhook>,
+    /// Post-call webhook configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhooks: Option<ConvAiWebhooks>,
+    /// Whether MCP servers are enabled for the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_use_mcp_servers: Option<bool>,
+    /// RAG data retention period in days (1–30).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rag_retention_period_days: Option<i64>,
+    /// Default LiveKit stack type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_livekit_stack: Option<LivekitStackType>,
+}
+
+/// A chart shown on the ConvAI dashboard, tagged by `type`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChartConfig {
+    /// Call success rate.
+    CallSuccess {
+        /// Chart title.
+        name: String,
+    },
+    /// Results of one evaluation criterion.
+    Criteria {
+        /// Chart title.
+        name: String,
+        /// ID of the evaluation criterion to plot.
+        criteria_id: String,
+    },
+    /// Values of one data collection item.
+    DataCollection {
+        /// Chart title.
+        name: String,
+        /// ID of the data collection item to plot.
+        data_collection_id: String,
+    },
+}
+
+impl ChartConfig {
+    /// Returns the chart title.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::CallSuccess { name } |
+            Self::Criteria { name, .. } |
+            Self::DataCollection { name, .. } => name,
+        }
+    }
+}
+
+/// ConvAI dashboard settings, from `GET /v1/convai/settings/dashboard`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConvAiDashboardSettings {
+    /// Charts shown on the dashboard.
+    #[serde(default)]
+    pub charts: Vec<ChartConfig>,
+}
+
+/// Request body for `PATCH /v1/convai/settings/dashboard`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchConvAiDashboardSettingsRequest {
+    /// Charts to show, replacing the current ones. At most
+    /// [`MAX_DASHBOARD_CHARTS`](Self::MAX_DASHBOARD_CHARTS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charts: Option<Vec<ChartConfig>>,
+}
+
+impl PatchConvAiDashboardSettingsRequest {
+    /// Largest number of charts the dashboard accepts.
+    pub const MAX_DASHBOARD_CHARTS: usize = 4;
+}
+
 // ===========================================================================
 // WhatsApp
 // ===========================================================================
@@ -1245,10 +2872,13 @@ pub struct WhatsAppAccount {
     /// Phone number ID within WhatsApp Business.
     pub phone_number_id: String,
     /// Business account display name.
+    #[serde(default)]
     pub business_account_name: String,
     /// Phone number display name.
+    #[serde(default)]
     pub phone_number_name: String,
     /// Phone number string.
+    #[serde(default)]
     pub phone_number: String,
     /// Agent assigned to this WhatsApp number.
     pub assigned_agent_id: Option<String>,
@@ -1282,6 +2912,7 @@ pub struct SipTrunkOutboundCallRequest {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetAgentSummariesResponse {
     /// List of agent summaries.
+    #[serde(default)]
     pub agents: Vec<AgentSummary>,
 }
 
@@ -1293,9 +2924,235 @@ pub struct GetAgentSummariesResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AgentLinkResponse {
     /// The shareable link URL.
+    #[serde(default)]
     pub url: String,
 }
 
+/// Response for retrieving an agent's embeddable widget configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentWidgetResponse {
+    /// The agent the widget belongs to.
+    pub agent_id: String,
+    /// The widget configuration.
+    pub widget_config: WidgetConfig,
+}
+
+/// Appearance and behaviour of an agent's embeddable widget.
+///
+/// Read with
+/// [`AgentsService::get_agent_widget`](crate::services::AgentsService::get_agent_widget)
+/// and written with
+/// [`AgentsService::update_widget`](crate::services::AgentsService::update_widget).
+/// Unset fields are left out when updating, so a default config with a few
+/// fields set changes only those fields. Settings without a typed field are
+/// kept in [`extra`](Self::extra).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WidgetConfig {
+    /// Widget size and layout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<WidgetVariant>,
+    /// Where the widget sits on the page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement: Option<WidgetPlacement>,
+    /// On which devices a compact widget can be expanded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expandable: Option<WidgetExpandable>,
+    /// Avatar shown in the widget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<WidgetAvatar>,
+    /// When users are asked to rate the conversation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feedback_mode: Option<WidgetFeedbackMode>,
+    /// Background color (e.g. `"#ffffff"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bg_color: Option<String>,
+    /// Text color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+    /// Button color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub btn_color: Option<String>,
+    /// Button text color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub btn_text_color: Option<String>,
+    /// Border color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub border_color: Option<String>,
+    /// Focus outline color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub focus_color: Option<String>,
+    /// Widget corner radius in pixels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub border_radius: Option<i64>,
+    /// Button corner radius in pixels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub btn_radius: Option<i64>,
+    /// Labels and messages shown in the widget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_contents: Option<WidgetTextContents>,
+    /// Default language of the widget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Languages users can switch to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supported_language_overrides: Option<Vec<String>>,
+    /// Per-language overrides, keyed by language code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_presets: Option<HashMap<String, WidgetLanguagePreset>>,
+    /// Whether users can mute their microphone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mic_muting_enabled: Option<bool>,
+    /// Whether the conversation transcript is shown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transcript_enabled: Option<bool>,
+    /// Whether users can type messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_input_enabled: Option<bool>,
+    /// Whether the widget is text-only, without voice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_only: Option<bool>,
+    /// Other widget settings returned by the API.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Size and layout of the widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WidgetVariant {
+    /// A single button.
+    Tiny,
+    /// A small card.
+    Compact,
+    /// A full card with transcript.
+    Full,
+    /// A compact card that expands into the full widget.
+    Expandable,
+}
+
+/// Where the widget sits on the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WidgetPlacement {
+    /// Top left corner.
+    TopLeft,
+    /// Top center.
+    Top,
+    /// Top right corner.
+    TopRight,
+    /// Bottom left corner.
+    BottomLeft,
+    /// Bottom center.
+    Bottom,
+    /// Bottom right corner.
+    BottomRight,
+}
+
+/// On which devices the widget can be expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WidgetExpandable {
+    /// Never expandable.
+    Never,
+    /// Expandable on mobile only.
+    Mobile,
+    /// Expandable on desktop only.
+    Desktop,
+    /// Always expandable.
+    Always,
+}
+
+/// When users are asked for feedback on the conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WidgetFeedbackMode {
+    /// Never.
+    None,
+    /// While the conversation is running.
+    During,
+    /// Once the conversation has ended.
+    End,
+}
+
+/// Avatar shown in the widget.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WidgetAvatar {
+    /// An animated orb in two colors.
+    Orb {
+        /// First orb color.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        color_1: Option<String>,
+        /// Second orb color.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        color_2: Option<String>,
+    },
+    /// An image hosted elsewhere.
+    Url {
+        /// URL of the image.
+        custom_url: String,
+    },
+    /// An image uploaded with
+    /// [`AgentsService::upload_avatar`](crate::services::AgentsService::upload_avatar).
+    Image {
+        /// URL the uploaded image is served from.
+        url: String,
+    },
+}
+
+/// Labels and messages shown in the widget.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WidgetTextContents {
+    /// Title of the widget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main_label: Option<String>,
+    /// Label of the start call button.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_call: Option<String>,
+    /// Label of the start chat button, for text-only widgets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_chat: Option<String>,
+    /// Label of the end call button.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_call: Option<String>,
+    /// Status shown while the agent listens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listening_status: Option<String>,
+    /// Status shown while the agent speaks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaking_status: Option<String>,
+    /// Placeholder of the message input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_placeholder: Option<String>,
+    /// Other labels returned by the API.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Widget overrides for one language.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WidgetLanguagePreset {
+    /// Labels and messages in this language.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_contents: Option<WidgetTextContents>,
+    /// Terms shown before a call, as Markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terms_text: Option<String>,
+    /// Other overrides returned by the API.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// An agent's avatar image, downloaded by
+/// [`AgentsService::download_avatar`](crate::services::AgentsService::download_avatar).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentAvatar {
+    /// Raw image bytes.
+    pub data: bytes::Bytes,
+    /// MIME type reported by the image host, if any.
+    pub content_type: Option<String>,
+}
+
 // ===========================================================================
 // Conversations — Signed URL & Token
 // ===========================================================================
@@ -1304,6 +3161,7 @@ pub struct AgentLinkResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SignedUrlResponse {
     /// The signed URL.
+    #[serde(default)]
     pub signed_url: String,
 }
 
@@ -1311,6 +3169,7 @@ pub struct SignedUrlResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConversationTokenResponse {
     /// The LiveKit token.
+    #[serde(default)]
     pub token: String,
     /// Additional fields returned by the API.
     #[serde(flatten)]
@@ -1325,6 +3184,7 @@ pub struct ConversationTokenResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ListPhoneNumbersResponse {
     /// List of phone numbers (polymorphic — Twilio or SIP trunk).
+    #[serde(default)]
     pub phone_numbers: Vec<serde_json::Value>,
 }
 
@@ -1333,9 +3193,10 @@ pub struct ListPhoneNumbersResponse {
 // ===========================================================================
 
 /// Response for listing tools.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GetToolsResponse {
     /// List of tool entries.
+    #[serde(default)]
     pub tools: Vec<ToolResponse>,
 }
 
@@ -1347,6 +3208,7 @@ pub struct GetToolsResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ListWhatsAppAccountsResponse {
     /// List of WhatsApp accounts.
+    #[serde(default)]
     pub accounts: Vec<WhatsAppAccount>,
 }
 
@@ -1389,6 +3251,7 @@ pub struct WhatsAppOutboundMessageRequest {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LiveCountResponse {
     /// Number of currently active conversations.
+    #[serde(default)]
     pub count: i64,
 }
 
@@ -1406,55 +3269,758 @@ pub struct CreateBranchRequest {
     pub description: Option<String>,
 }
 
-/// Request to update an agent branch.
-#[derive(Debug, Clone, Serialize)]
-pub struct UpdateBranchRequest {
-    /// Updated branch name.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// Updated branch description.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+/// Request to update an agent branch.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateBranchRequest {
+    /// Updated branch name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Updated branch description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Request to merge a source branch into a target branch.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeBranchRequest {
+    /// Target branch to merge into.
+    pub target_branch_id: String,
+}
+
+// ===========================================================================
+// Deployments — Request type
+// ===========================================================================
+
+/// Request to create or update an agent deployment (traffic split).
+///
+/// Build one with [`traffic_split`](Self::traffic_split) unless you need
+/// the raw items.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::CreateDeploymentRequest;
+///
+/// let request = CreateDeploymentRequest::traffic_split([("main", 0.9), ("canary", 0.1)]);
+/// let json = serde_json::to_value(&request).unwrap();
+/// assert_eq!(json["deployment_request"]["requests"][1]["branch_id"], "canary");
+/// assert_eq!(
+///     json["deployment_request"]["requests"][1]["deployment_strategy"]["type"],
+///     "percentage"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateDeploymentRequest {
+    /// The branches to deploy.
+    pub deployment_request: AgentDeploymentRequest,
+}
+
+impl CreateDeploymentRequest {
+    /// Routes each branch its share of traffic (0.0–1.0). Shares should sum
+    /// to 1.0; give a branch 0.0 to take it out of rotation.
+    pub fn traffic_split<I, S>(shares: I) -> Self
+    where
+        I: IntoIterator<Item = (S, f64)>,
+        S: Into<String>,
+    {
+        let requests = shares
+            .into_iter()
+            .map(|(branch_id, share)| AgentDeploymentRequestItem {
+                branch_id: branch_id.into(),
+                deployment_strategy: DeploymentStrategy::Percentage { traffic_percentage: share },
+            })
+            .collect();
+        Self { deployment_request: AgentDeploymentRequest { requests } }
+    }
+}
+
+/// The deployment items of a [`CreateDeploymentRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgentDeploymentRequest {
+    /// One item per branch whose traffic changes.
+    pub requests: Vec<AgentDeploymentRequestItem>,
+}
+
+/// How traffic is routed to one branch.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgentDeploymentRequestItem {
+    /// Branch to deploy.
+    pub branch_id: String,
+    /// How much traffic the branch receives.
+    pub deployment_strategy: DeploymentStrategy,
+}
+
+/// Strategy for routing traffic to a deployed branch.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DeploymentStrategy {
+    /// A fixed share of traffic.
+    Percentage {
+        /// Share of traffic (0.0–1.0).
+        traffic_percentage: f64,
+    },
+}
+
+// ===========================================================================
+// Knowledge Base — Move operations
+// ===========================================================================
+
+/// Request to move a knowledge base document into a folder.
+#[derive(Debug, Clone, Serialize)]
+pub struct KnowledgeBaseMoveRequest {
+    /// Target folder ID, or `None` for root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<String>,
+}
+
+/// Request to bulk-move knowledge base documents.
+#[derive(Debug, Clone, Serialize)]
+pub struct KnowledgeBaseBulkMoveRequest {
+    /// Document IDs to move.
+    pub document_ids: Vec<String>,
+    /// Target folder ID, or `None` for root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<String>,
+}
+
+// ===========================================================================
+// Knowledge Base — Bulk upload
+// ===========================================================================
+
+/// Where a bulk-uploaded knowledge base document came from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum KnowledgeBaseUploadSource {
+    /// A local file uploaded via `POST /v1/convai/knowledge-base/file`.
+    File(std::path::PathBuf),
+    /// A web page imported via `POST /v1/convai/knowledge-base/url`.
+    Url(String),
+}
+
+impl std::fmt::Display for KnowledgeBaseUploadSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => f.write_str(url),
+        }
+    }
+}
+
+/// Outcome of uploading a single source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum KnowledgeBaseUploadStatus {
+    /// The document was created.
+    Created {
+        /// The created document.
+        document: AddKnowledgeBaseResponse,
+    },
+    /// The content matched an earlier source in the same batch and was not
+    /// uploaded again.
+    Duplicate {
+        /// The source that was uploaded with the same content.
+        of: KnowledgeBaseUploadSource,
+    },
+    /// The source was not uploaded, e.g. because its file type is not
+    /// supported by the knowledge base.
+    Skipped {
+        /// Why the source was skipped.
+        reason: String,
+    },
+    /// Reading or uploading the source failed.
+    Failed {
+        /// Error message.
+        error: String,
+    },
+}
+
+/// Result for one source in a [`KnowledgeBaseUploadSummary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnowledgeBaseUploadItem {
+    /// The uploaded file or URL.
+    pub source: KnowledgeBaseUploadSource,
+    /// Hex-encoded SHA-256 of the file content, or of the URL for URL
+    /// sources. `None` if the source could not be read.
+    pub content_hash: Option<String>,
+    /// What happened to the source.
+    #[serde(flatten)]
+    pub status: KnowledgeBaseUploadStatus,
+}
+
+/// Progress notification emitted after each source finishes.
+#[derive(Debug, Clone, Copy)]
+pub struct KnowledgeBaseUploadProgress<'a> {
+    /// Number of sources finished so far, including this one.
+    pub completed: usize,
+    /// Total number of sources in the batch.
+    pub total: usize,
+    /// The source that just finished.
+    pub item: &'a KnowledgeBaseUploadItem,
+}
+
+/// Summary of a bulk knowledge base upload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnowledgeBaseUploadSummary {
+    /// Per-source results, in input order.
+    #[serde(default)]
+    pub items: Vec<KnowledgeBaseUploadItem>,
+}
+
+impl KnowledgeBaseUploadSummary {
+    /// Returns the documents that were created.
+    pub fn created(&self) -> impl Iterator<Item = &AddKnowledgeBaseResponse> {
+        self.items.iter().filter_map(|item| match &item.status {
+            KnowledgeBaseUploadStatus::Created { document } => Some(document),
+            _ => None,
+        })
+    }
+
+    /// Returns the sources skipped as duplicates of an earlier source.
+    pub fn duplicates(&self) -> impl Iterator<Item = &KnowledgeBaseUploadItem> {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, KnowledgeBaseUploadStatus::Duplicate { .. }))
+    }
+
+    /// Returns the sources that failed to upload.
+    pub fn failed(&self) -> impl Iterator<Item = &KnowledgeBaseUploadItem> {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, KnowledgeBaseUploadStatus::Failed { .. }))
+    }
+
+    /// Returns `true` if no source failed.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
+// ===========================================================================
+// Knowledge Base — Sync
+// ===========================================================================
+
+/// How a local source compares to the knowledge base.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum KnowledgeBaseChange {
+    /// No document with the source's name exists yet.
+    New,
+    /// The matching document is up to date.
+    Unchanged {
+        /// The matching document.
+        document_id: String,
+    },
+    /// The matching document is out of date and is replaced by a fresh
+    /// upload.
+    Stale {
+        /// The document to replace.
+        document_id: String,
+        /// Why the document is considered out of date.
+        reason: String,
+    },
+    /// The source is not synced, e.g. because its file type is not
+    /// supported or another source has the same document name.
+    Skipped {
+        /// Why the source was skipped.
+        reason: String,
+    },
+    /// Reading the source failed.
+    Failed {
+        /// Error message.
+        error: String,
+    },
+}
+
+/// One local source in a [`KnowledgeBaseDiff`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnowledgeBaseDiffEntry {
+    /// Document name the source is matched by: the path relative to the
+    /// synced directory, the file name, or the URL.
+    pub name: String,
+    /// The local file or URL.
+    pub source: KnowledgeBaseUploadSource,
+    /// Hex-encoded SHA-256 of the file content, or of the URL for URL
+    /// sources. `None` if the source could not be read.
+    pub content_hash: Option<String>,
+    /// How the source compares to the knowledge base.
+    #[serde(flatten)]
+    pub change: KnowledgeBaseChange,
+}
+
+/// Differences between local sources and the knowledge base.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnowledgeBaseDiff {
+    /// Per-source results, in input order.
+    #[serde(default)]
+    pub entries: Vec<KnowledgeBaseDiffEntry>,
+    /// Documents in the synced folder that no source maps to.
+    #[serde(default)]
+    pub orphans: Vec<KnowledgeBaseDocumentSummary>,
+}
+
+impl KnowledgeBaseDiff {
+    /// Returns the sources that need uploading: new ones and those whose
+    /// document is stale.
+    pub fn pending(&self) -> impl Iterator<Item = &KnowledgeBaseDiffEntry> {
+        self.entries.iter().filter(|entry| {
+            matches!(entry.change, KnowledgeBaseChange::New | KnowledgeBaseChange::Stale { .. })
+        })
+    }
+
+    /// Returns `true` if nothing needs uploading and there are no orphans.
+    #[must_use]
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending().next().is_none() && self.orphans.is_empty()
+    }
+}
+
+/// A document that could not be deleted or re-indexed during a sync.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnowledgeBaseSyncError {
+    /// The affected document.
+    pub document_id: String,
+    /// Error message.
+    pub error: String,
+}
+
+/// Summary of a knowledge base sync.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnowledgeBaseSyncSummary {
+    /// The differences the sync acted on.
+    #[serde(default)]
+    pub diff: KnowledgeBaseDiff,
+    /// Upload results, in the order of [`KnowledgeBaseDiff::pending`].
+    #[serde(default)]
+    pub uploads: Vec<KnowledgeBaseUploadItem>,
+    /// Stale and orphaned documents that were deleted.
+    #[serde(default)]
+    pub deleted: Vec<String>,
+    /// Uploaded documents whose RAG index was requested.
+    #[serde(default)]
+    pub reindexed: Vec<String>,
+    /// Deletions and re-index requests that failed.
+    #[serde(default)]
+    pub errors: Vec<KnowledgeBaseSyncError>,
+}
+
+impl KnowledgeBaseSyncSummary {
+    /// Returns `(old document ID, new document)` for every stale document
+    /// that was re-uploaded.
+    ///
+    /// Agents that referenced the old document need to be pointed at the
+    /// new one.
+    pub fn replacements(&self) -> impl Iterator<Item = (&str, &AddKnowledgeBaseResponse)> {
+        self.diff.pending().zip(&self.uploads).filter_map(|(entry, upload)| {
+            match (&entry.change, &upload.status) {
+                (
+                    KnowledgeBaseChange::Stale { document_id, .. },
+                    KnowledgeBaseUploadStatus::Created { document },
+                ) => Some((document_id.as_str(), document)),
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns the content hash of every synced document, keyed by
+    /// document ID.
+    ///
+    /// Persist this map and pass it to the next sync's
+    /// `known_hashes` so unchanged files are recognized by content rather
+    /// than by modification time.
+    #[must_use]
+    pub fn content_hashes(&self) -> std::collections::BTreeMap<String, String> {
+        let unchanged = self.diff.entries.iter().filter_map(|entry| match &entry.change {
+            KnowledgeBaseChange::Unchanged { document_id } => {
+                Some((document_id.clone(), entry.content_hash.clone()?))
+            }
+            _ => None,
+        });
+        let uploaded = self.uploads.iter().filter_map(|item| match &item.status {
+            KnowledgeBaseUploadStatus::Created { document } => {
+                Some((document.id.clone(), item.content_hash.clone()?))
+            }
+            _ => None,
+        });
+        unchanged.chain(uploaded).collect()
+    }
+
+    /// Returns `true` if every source was read and uploaded and every
+    /// deletion and re-index request succeeded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty() &&
+            !self
+                .uploads
+                .iter()
+                .any(|item| matches!(item.status, KnowledgeBaseUploadStatus::Failed { .. })) &&
+            !self
+                .diff
+                .entries
+                .iter()
+                .any(|entry| matches!(entry.change, KnowledgeBaseChange::Failed { .. }))
+    }
+}
+
+// ===========================================================================
+// Knowledge Base — Content download
+// ===========================================================================
+
+/// Text format produced when downloading a knowledge base document.
+///
+/// The API serves document content as HTML; the other formats are converted
+/// from it on the fly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KnowledgeBaseContentFormat {
+    /// Markdown with headings, lists, links, emphasis and code blocks.
+    #[default]
+    Markdown,
+    /// Plain text with the document's paragraphs and list items.
+    PlainText,
+    /// The HTML exactly as served by the API.
+    Html,
+}
+
+impl KnowledgeBaseContentFormat {
+    /// Returns the usual file extension for the format, without a dot.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::PlainText => "txt",
+            Self::Html => "html",
+        }
+    }
+}
+
+// ===========================================================================
+// Conversations — Bulk export
+// ===========================================================================
+
+/// What a conversation export writes for each conversation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationExportFormat {
+    /// Transcripts only.
+    #[default]
+    Transcripts,
+    /// Transcripts plus the recording of every conversation that has one.
+    TranscriptsAndAudio,
+}
+
+impl ConversationExportFormat {
+    /// Returns `true` if recordings are downloaded.
+    pub const fn includes_audio(self) -> bool {
+        matches!(self, Self::TranscriptsAndAudio)
+    }
+}
+
+/// Outcome of exporting a single conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ConversationExportStatus {
+    /// The transcript was written to the archive.
+    Exported {
+        /// 1-based line of the transcript in `transcripts.jsonl`.
+        transcript_line: usize,
+        /// Recording path relative to the archive directory, if one was
+        /// downloaded.
+        audio_file: Option<std::path::PathBuf>,
+    },
+    /// Fetching the transcript or recording failed after retries; nothing
+    /// was written for this conversation.
+    Failed {
+        /// Error message.
+        error: String,
+    },
+}
+
+/// Result for one conversation in a [`ConversationExportSummary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationExportItem {
+    /// The exported conversation.
+    pub conversation: ConversationSummary,
+    /// What happened to it.
+    #[serde(flatten)]
+    pub status: ConversationExportStatus,
+}
+
+/// Summary of a conversation export.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationExportSummary {
+    /// Per-conversation results, in listing order.
+    #[serde(default)]
+    pub items: Vec<ConversationExportItem>,
+}
+
+impl ConversationExportSummary {
+    /// Returns the conversations written to the archive.
+    pub fn exported(&self) -> impl Iterator<Item = &ConversationExportItem> {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, ConversationExportStatus::Exported { .. }))
+    }
+
+    /// Returns the conversations that could not be exported.
+    pub fn failed(&self) -> impl Iterator<Item = &ConversationExportItem> {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, ConversationExportStatus::Failed { .. }))
+    }
+
+    /// Returns `true` if no conversation failed.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
+// ===========================================================================
+// Agents — Conversation simulation
+// ===========================================================================
+
+/// A goal the simulated conversation is judged against, in addition to the
+/// agent's own evaluation criteria.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationCriterion {
+    /// Criterion identifier, used as the key of its result.
+    pub id: String,
+    /// Display name.
+    pub name: String,
+    /// Prompt describing what the agent should achieve.
+    pub conversation_goal_prompt: String,
+    /// Whether the evaluator may consult the agent's knowledge base.
+    #[serde(default)]
+    pub use_knowledge_base: bool,
+}
+
+impl SimulationCriterion {
+    /// Creates a criterion named after its ID.
+    pub fn new(id: impl Into<String>, conversation_goal_prompt: impl Into<String>) -> Self {
+        let id = id.into();
+        Self {
+            name: id.clone(),
+            id,
+            conversation_goal_prompt: conversation_goal_prompt.into(),
+            use_knowledge_base: false,
+        }
+    }
+}
+
+/// Canned response returned when the agent calls a tool during a simulation,
+/// instead of running the tool.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolMock {
+    /// Result handed back to the agent.
+    #[serde(rename = "default_return_value")]
+    pub return_value: String,
+    /// Whether the call is reported as failed.
+    #[serde(rename = "default_is_error", default)]
+    pub is_error: bool,
+}
+
+impl ToolMock {
+    /// Creates a mock that succeeds with `return_value`.
+    pub fn returning(return_value: impl Into<String>) -> Self {
+        Self { return_value: return_value.into(), is_error: false }
+    }
+
+    /// Creates a mock that fails with `message`.
+    pub fn failing(message: impl Into<String>) -> Self {
+        Self { return_value: message.into(), is_error: true }
+    }
+}
+
+/// Script for a simulated conversation between an agent and an LLM playing
+/// the user.
+///
+/// Serializes to the `simulate-conversation` request body.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{SimulationCriterion, SimulationSpec, ToolMock};
+///
+/// let spec = SimulationSpec::new("You want to move your appointment to Friday.")
+///     .max_turns(8)
+///     .criterion(SimulationCriterion::new("rescheduled", "The appointment was moved."))
+///     .mock_tool("get_slots", ToolMock::returning(r#"["Fri 10:00"]"#));
+///
+/// let json = serde_json::to_value(&spec).unwrap();
+/// assert_eq!(json["new_turns_limit"], 8);
+/// assert_eq!(
+///     json["simulation_specification"]["tool_mock_config"]["get_slots"]["default_is_error"],
+///     false
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulationSpec {
+    /// System prompt for the simulated user.
+    pub user_prompt: String,
+    /// Language the simulated user speaks, e.g. `"en"`.
+    pub language: Option<String>,
+    /// Maximum number of new turns before the simulation stops.
+    pub max_turns: Option<u32>,
+    /// Extra criteria the conversation is evaluated against.
+    pub evaluation_criteria: Vec<SimulationCriterion>,
+    /// Mock responses for tool calls, keyed by tool name.
+    pub tool_mocks: BTreeMap<String, ToolMock>,
+    /// Earlier turns the simulation continues from.
+    pub history: Vec<ConversationTranscriptEntry>,
+    /// Values for the agent's dynamic variables.
+    pub dynamic_variables: BTreeMap<String, serde_json::Value>,
 }
 
-/// Request to merge a source branch into a target branch.
-#[derive(Debug, Clone, Serialize)]
-pub struct MergeBranchRequest {
-    /// Target branch to merge into.
-    pub target_branch_id: String,
+impl SimulationSpec {
+    /// Creates a spec with the given simulated user prompt.
+    pub fn new(user_prompt: impl Into<String>) -> Self {
+        Self { user_prompt: user_prompt.into(), ..Self::default() }
+    }
+
+    /// Sets the simulated user's language.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Sets the maximum number of new turns.
+    pub const fn max_turns(mut self, max_turns: u32) -> Self {
+        self.max_turns = Some(max_turns);
+        self
+    }
+
+    /// Adds an evaluation criterion.
+    pub fn criterion(mut self, criterion: SimulationCriterion) -> Self {
+        self.evaluation_criteria.push(criterion);
+        self
+    }
+
+    /// Mocks the tool called `tool_name`.
+    pub fn mock_tool(mut self, tool_name: impl Into<String>, mock: ToolMock) -> Self {
+        self.tool_mocks.insert(tool_name.into(), mock);
+        self
+    }
+
+    /// Continues the simulation from earlier turns.
+    pub fn history(mut self, history: Vec<ConversationTranscriptEntry>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Sets a dynamic variable.
+    pub fn dynamic_variable(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.dynamic_variables.insert(name.into(), value.into());
+        self
+    }
 }
 
-// ===========================================================================
-// Deployments — Request type
-// ===========================================================================
+impl Serialize for SimulationSpec {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Prompt<'a> {
+            prompt: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct UserConfig<'a> {
+            prompt: Prompt<'a>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            language: Option<&'a str>,
+        }
+
+        #[derive(Serialize)]
+        struct Specification<'a> {
+            simulated_user_config: UserConfig<'a>,
+            #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+            tool_mock_config: &'a BTreeMap<String, ToolMock>,
+            #[serde(skip_serializing_if = "<[_]>::is_empty")]
+            partial_conversation_history: &'a [ConversationTranscriptEntry],
+            #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+            dynamic_variables: &'a BTreeMap<String, serde_json::Value>,
+        }
+
+        #[derive(Serialize)]
+        struct Criterion<'a> {
+            #[serde(flatten)]
+            criterion: &'a SimulationCriterion,
+            r#type: &'static str,
+        }
+
+        #[derive(Serialize)]
+        struct Body<'a> {
+            simulation_specification: Specification<'a>,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            extra_evaluation_criteria: Vec<Criterion<'a>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            new_turns_limit: Option<u32>,
+        }
+
+        Body {
+            simulation_specification: Specification {
+                simulated_user_config: UserConfig {
+                    prompt: Prompt { prompt: &self.user_prompt },
+                    language: self.language.as_deref(),
+                },
+                tool_mock_config: &self.tool_mocks,
+                partial_conversation_history: &self.history,
+                dynamic_variables: &self.dynamic_variables,
+            },
+            extra_evaluation_criteria: self
+                .evaluation_criteria
+                .iter()
+                .map(|criterion| Criterion { criterion, r#type: "prompt" })
+                .collect(),
+            new_turns_limit: self.max_turns,
+        }
+        .serialize(serializer)
+    }
+}
 
-/// Request to create or update an agent deployment (traffic split).
-#[derive(Debug, Clone, Serialize)]
-pub struct CreateDeploymentRequest {
-    /// Mapping of branch IDs to traffic percentages (0.0–1.0).
-    pub traffic_percentage_branch_id_map: HashMap<String, f64>,
+/// Outcome of a simulated conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationResult {
+    /// The conversation, one entry per turn.
+    #[serde(rename = "simulated_conversation", default)]
+    pub turns: Vec<ConversationTranscriptEntry>,
+    /// Evaluation of the conversation, including every criterion's outcome.
+    pub analysis: ConversationAnalysis,
 }
 
-// ===========================================================================
-// Knowledge Base — Move operations
-// ===========================================================================
+impl SimulationResult {
+    /// Returns the outcome of the criterion with the given ID.
+    pub fn criterion(&self, id: &str) -> Option<&EvaluationCriteriaResult> {
+        self.analysis.evaluation_criteria_results.get(id)
+    }
 
-/// Request to move a knowledge base document into a folder.
-#[derive(Debug, Clone, Serialize)]
-pub struct KnowledgeBaseMoveRequest {
-    /// Target folder ID, or `None` for root.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub folder_id: Option<String>,
+    /// Returns the criteria that were not met.
+    pub fn failed_criteria(&self) -> impl Iterator<Item = &EvaluationCriteriaResult> {
+        self.analysis
+            .evaluation_criteria_results
+            .values()
+            .filter(|result| result.result == EvaluationSuccessResult::Failure)
+    }
+
+    /// Returns `true` if the call was judged successful and no criterion
+    /// failed.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.analysis.call_successful == EvaluationSuccessResult::Success &&
+            self.failed_criteria().next().is_none()
+    }
 }
 
-/// Request to bulk-move knowledge base documents.
-#[derive(Debug, Clone, Serialize)]
-pub struct KnowledgeBaseBulkMoveRequest {
-    /// Document IDs to move.
-    pub document_ids: Vec<String>,
-    /// Target folder ID, or `None` for root.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub folder_id: Option<String>,
+/// Event yielded by
+/// [`AgentsService::simulate_conversation_turns`](crate::services::AgentsService::simulate_conversation_turns).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulationEvent {
+    /// A turn was generated.
+    Turn(Box<ConversationTranscriptEntry>),
+    /// The conversation ended; this is the last event.
+    Completed(Box<ConversationAnalysis>),
 }
 
 // ===========================================================================
@@ -1465,6 +4031,7 @@ pub struct KnowledgeBaseBulkMoveRequest {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetSecretsResponse {
     /// List of stored secrets.
+    #[serde(default)]
     pub secrets: Vec<WorkspaceStoredSecret>,
 }
 
@@ -1490,8 +4057,10 @@ pub struct TwilioOutboundCallRequest {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TwilioOutboundCallResponse {
     /// Whether the call was initiated successfully.
+    #[serde(default)]
     pub success: bool,
     /// Status message.
+    #[serde(default)]
     pub message: String,
     /// Conversation ID for the call.
     pub conversation_id: Option<String>,
@@ -1527,10 +4096,13 @@ pub struct ConversationUser {
     /// User identifier.
     pub user_id: String,
     /// Last contact time in Unix seconds.
-    pub last_contact_unix_secs: i64,
+    #[serde(default)]
+    pub last_contact_unix_secs: Timestamp,
     /// First contact time in Unix seconds.
-    pub first_contact_unix_secs: i64,
+    #[serde(default)]
+    pub first_contact_unix_secs: Timestamp,
     /// Number of conversations.
+    #[serde(default)]
     pub conversation_count: i64,
     /// Last agent the user interacted with.
     pub last_agent_id: Option<String>,
@@ -1542,10 +4114,12 @@ pub struct ConversationUser {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetConversationUsersResponse {
     /// List of users.
+    #[serde(default)]
     pub users: Vec<ConversationUser>,
     /// Cursor for the next page, if any.
     pub next_cursor: Option<String>,
     /// Whether more pages exist.
+    #[serde(default)]
     pub has_more: bool,
 }
 
@@ -1557,13 +4131,271 @@ pub struct GetConversationUsersResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetToolDependentAgentsResponse {
     /// List of dependent agents (polymorphic — available or unknown).
+    #[serde(default)]
     pub agents: Vec<serde_json::Value>,
     /// Cursor for the next page, if any.
     pub next_cursor: Option<String>,
     /// Whether more pages exist.
+    #[serde(default)]
     pub has_more: bool,
 }
 
+// ===========================================================================
+// Agent Bundles (config as code)
+// ===========================================================================
+
+/// Version of the [`AgentBundle`] format written by this SDK.
+pub const AGENT_BUNDLE_VERSION: u32 = 1;
+
+/// A portable definition of an agent, for version control and for promoting
+/// agents between workspaces.
+///
+/// Holds everything needed to recreate the agent: its conversation config,
+/// platform settings and workflow, the configs of the tools it uses, and
+/// references to its knowledge base documents. Knowledge base documents are
+/// referenced by ID, not copied; map them to documents in the target
+/// workspace with [`ImportOptions::map_knowledge_base`].
+///
+/// [`to_json`](Self::to_json) writes keys in sorted order and tools and
+/// documents sorted by ID, so exporting an unchanged agent twice gives the
+/// same file.
+///
+/// Created by
+/// [`AgentsService::export_agent`](crate::services::AgentsService::export_agent)
+/// and applied by
+/// [`AgentsService::import_agent`](crate::services::AgentsService::import_agent).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentBundle {
+    /// Bundle format version; see [`AGENT_BUNDLE_VERSION`].
+    pub version: u32,
+    /// Display name of the agent.
+    pub name: String,
+    /// Tags used to categorize the agent.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Conversation configuration, with tool and document IDs from the
+    /// source workspace.
+    #[serde(default)]
+    pub conversation_config: serde_json::Value,
+    /// Platform settings.
+    #[serde(default)]
+    pub platform_settings: serde_json::Value,
+    /// Multi-agent workflow definition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<serde_json::Value>,
+    /// Tools the agent uses.
+    #[serde(default)]
+    pub tools: Vec<AgentBundleTool>,
+    /// Knowledge base documents the agent uses.
+    #[serde(default)]
+    pub knowledge_base: Vec<AgentKnowledgeBaseRef>,
+}
+
+/// A tool stored in an [`AgentBundle`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentBundleTool {
+    /// Tool ID in the source workspace.
+    pub id: String,
+    /// Tool configuration, as accepted by `POST /v1/convai/tools`.
+    pub tool_config: ToolConfig,
+}
+
+/// A knowledge base document referenced by an agent's prompt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentKnowledgeBaseRef {
+    /// Document ID.
+    pub id: String,
+    /// Document name.
+    #[serde(default)]
+    pub name: String,
+    /// Document type (`"file"`, `"url"` or `"text"`).
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    /// How the document is used (`"auto"` or `"prompt"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_mode: Option<String>,
+}
+
+impl GetAgentResponse {
+    /// Returns the IDs of the tools listed in the agent's prompt config.
+    #[must_use]
+    pub fn tool_ids(&self) -> Vec<String> {
+        prompt_field(&self.conversation_config, "tool_ids")
+            .and_then(serde_json::Value::as_array)
+            .map(|ids| ids.iter().filter_map(|id| id.as_str().map(str::to_owned)).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl AgentBundle {
+    /// Builds a bundle from an agent and the tools it uses.
+    ///
+    /// Tools returned without a configuration cannot be recreated and are
+    /// left out.
+    #[must_use]
+    pub fn from_agent(agent: &GetAgentResponse, tools: &[ToolResponse]) -> Self {
+        let mut tools: Vec<AgentBundleTool> = tools
+            .iter()
+            .filter_map(|tool| {
+                Some(AgentBundleTool {
+                    id: tool.id.clone(),
+                    tool_config: tool.tool_config.clone()?,
+                })
+            })
+            .collect();
+        tools.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut knowledge_base: Vec<AgentKnowledgeBaseRef> =
+            prompt_field(&agent.conversation_config, "knowledge_base")
+                .and_then(|docs| serde_json::from_value(docs.clone()).ok())
+                .unwrap_or_default();
+        knowledge_base.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Self {
+            version: AGENT_BUNDLE_VERSION,
+            name: agent.name.clone(),
+            tags: agent.tags.clone(),
+            conversation_config: agent.conversation_config.clone(),
+            platform_settings: agent.platform_settings.clone(),
+            workflow: agent.workflow.clone(),
+            tools,
+            knowledge_base,
+        }
+    }
+
+    /// Serializes the bundle as pretty-printed JSON with sorted keys and a
+    /// trailing newline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a config value cannot be serialized.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        // Round-tripping through `Value` sorts object keys, including those
+        // of the struct itself.
+        let value = serde_json::to_value(self)?;
+        let mut json = serde_json::to_string_pretty(&value)?;
+        json.push('\n');
+        Ok(json)
+    }
+
+    /// Parses a bundle written by [`to_json`](Self::to_json).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a valid bundle.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the conversation config with tool and knowledge base IDs
+    /// replaced according to `tool_ids` and `knowledge_base_ids`. IDs
+    /// missing from the maps are kept.
+    #[must_use]
+    pub fn remapped_conversation_config(
+        &self,
+        tool_ids: &HashMap<String, String>,
+        knowledge_base_ids: &HashMap<String, String>,
+    ) -> serde_json::Value {
+        let mut config = self.conversation_config.clone();
+        let Some(prompt) = config
+            .get_mut("agent")
+            .and_then(|agent| agent.get_mut("prompt"))
+            .and_then(serde_json::Value::as_object_mut)
+        else {
+            return config;
+        };
+        let remap = |id: &mut serde_json::Value, ids: &HashMap<String, String>| {
+            if let Some(new_id) = id.as_str().and_then(|old| ids.get(old)) {
+                *id = serde_json::Value::String(new_id.clone());
+            }
+        };
+        if let Some(ids) = prompt.get_mut("tool_ids").and_then(serde_json::Value::as_array_mut) {
+            for id in ids {
+                remap(id, tool_ids);
+            }
+        }
+        if let Some(docs) =
+            prompt.get_mut("knowledge_base").and_then(serde_json::Value::as_array_mut)
+        {
+            for id in docs.iter_mut().filter_map(|doc| doc.get_mut("id")) {
+                remap(id, knowledge_base_ids);
+            }
+        }
+        config
+    }
+}
+
+/// Returns `conversation_config.agent.prompt.<field>`.
+fn prompt_field<'a>(config: &'a serde_json::Value, field: &str) -> Option<&'a serde_json::Value> {
+    config.get("agent")?.get("prompt")?.get(field)
+}
+
+/// Options for
+/// [`AgentsService::import_agent`](crate::services::AgentsService::import_agent).
+///
+/// By default the bundle is imported as a new agent and its tools are
+/// created afresh in the target workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportOptions {
+    /// Agent to overwrite instead of creating a new one.
+    pub agent_id: Option<String>,
+    /// Name to use instead of the bundle's.
+    pub name: Option<String>,
+    /// Whether to create the bundle's tools and point the agent at the new
+    /// copies. When `false`, the bundle's tool IDs are kept, which suits
+    /// re-importing into the source workspace.
+    pub recreate_tools: bool,
+    /// Knowledge base document IDs to replace, from source to target.
+    pub knowledge_base_ids: HashMap<String, String>,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            agent_id: None,
+            name: None,
+            recreate_tools: true,
+            knowledge_base_ids: HashMap::new(),
+        }
+    }
+}
+
+impl ImportOptions {
+    /// Creates the default options.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overwrites the agent `agent_id` instead of creating a new one.
+    #[must_use]
+    pub fn update_agent(mut self, agent_id: impl Into<String>) -> Self {
+        self.agent_id = Some(agent_id.into());
+        self
+    }
+
+    /// Uses `name` instead of the bundle's name.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets whether the bundle's tools are created afresh (default `true`).
+    #[must_use]
+    pub const fn recreate_tools(mut self, recreate: bool) -> Self {
+        self.recreate_tools = recreate;
+        self
+    }
+
+    /// Points references to the document `from` at the document `to`.
+    #[must_use]
+    pub fn map_knowledge_base(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.knowledge_base_ids.insert(from.into(), to.into());
+        self
+    }
+}
+
 // ===========================================================================
 // Tests
 // ===========================================================================
@@ -1694,9 +4526,9 @@ mod tests {
         assert_eq!(agent.agent_id, "J3Pbu5gP6NNKBscdCdwB");
         assert_eq!(agent.name, "My Agent");
         assert_eq!(agent.tags.len(), 2);
-        assert_eq!(agent.created_at_unix_secs, 1716153600);
+        assert_eq!(agent.created_at_unix_secs, 1_716_153_600);
         assert!(agent.access_info.is_creator);
-        assert_eq!(agent.last_call_time_unix_secs, Some(1716240000));
+        assert_eq!(agent.last_call_time_unix_secs, Some(Timestamp::from_unix_secs(1_716_240_000)));
         assert!(!agent.archived);
     }
 
@@ -1749,7 +4581,7 @@ mod tests {
         let resp: GetAgentResponse = serde_json::from_str(json).unwrap();
         assert_eq!(resp.agent_id, "agent_xyz");
         assert_eq!(resp.name, "Support Bot");
-        assert_eq!(resp.metadata.created_at_unix_secs, 1700000000);
+        assert_eq!(resp.metadata.created_at_unix_secs, 1_700_000_000);
         assert_eq!(resp.tags, vec!["support"]);
     }
 
@@ -1786,13 +4618,86 @@ mod tests {
 
     #[test]
     fn agent_call_limits_defaults() {
-        let json = r#"{}"#;
+        let json = r"{}";
         let limits: AgentCallLimits = serde_json::from_str(json).unwrap();
         assert_eq!(limits.agent_concurrency_limit, -1);
         assert_eq!(limits.daily_limit, 100_000);
         assert!(limits.bursting_enabled);
     }
 
+    // -- Merged updates ------------------------------------------------------
+
+    #[test]
+    fn json_merge_patch_follows_rfc_7386() {
+        let mut target = serde_json::json!({
+            "a": "b",
+            "c": {"d": "e", "f": "g"},
+            "list": [1, 2]
+        });
+        json_merge_patch(
+            &mut target,
+            &serde_json::json!({"a": "z", "c": {"f": null}, "list": [3], "new": {"x": 1}}),
+        );
+        assert_eq!(
+            target,
+            serde_json::json!({"a": "z", "c": {"d": "e"}, "list": [3], "new": {"x": 1}})
+        );
+    }
+
+    #[test]
+    fn config_diff_reports_leaf_changes() {
+        let old = serde_json::json!({"agent": {"language": "en", "first_message": "Hi"}, "a/b": 1});
+        let new = serde_json::json!({"agent": {"language": "fr", "llm": "gpt-4o"}, "a/b": 1});
+        let diff = ConfigDiff::between(&old, &new);
+        assert_eq!(
+            diff.to_string(),
+            "- /agent/first_message: \"Hi\"\n\
+             ~ /agent/language: \"en\" -> \"fr\"\n\
+             + /agent/llm: \"gpt-4o\"\n"
+        );
+        assert!(diff.touches("/agent"));
+        assert!(!diff.touches("/age"));
+        assert!(ConfigDiff::between(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn merge_update_keeps_unmentioned_settings() {
+        let agent: GetAgentResponse = serde_json::from_value(serde_json::json!({
+            "agent_id": "agent_1",
+            "name": "Bot",
+            "conversation_config": {"agent": {"first_message": "Hi"}, "tts": {"voice_id": "v1"}},
+            "platform_settings": {"widget": {"variant": "full"}},
+            "tags": ["a"]
+        }))
+        .unwrap();
+        let patch = UpdateAgentRequest {
+            platform_settings: Some(serde_json::json!({"widget": {"variant": "compact"}})),
+            tags: Some(vec!["a".into(), "b".into()]),
+            version_description: Some("compact widget".into()),
+            ..Default::default()
+        };
+
+        let (request, diff) = agent.merge_update(&patch);
+        assert_eq!(diff.len(), 2);
+        assert!(request.conversation_config.is_none());
+        assert!(request.name.is_none());
+        assert_eq!(
+            request.platform_settings,
+            Some(serde_json::json!({"widget": {"variant": "compact"}}))
+        );
+        assert_eq!(request.tags, Some(vec!["a".to_owned(), "b".to_owned()]));
+        assert_eq!(request.version_description.as_deref(), Some("compact widget"));
+
+        let (request, diff) = agent.merge_update(&UpdateAgentRequest {
+            name: Some("Bot".into()),
+            version_description: Some("noop".into()),
+            ..Default::default()
+        });
+        assert!(diff.is_empty());
+        assert!(request.name.is_none());
+        assert!(request.version_description.is_none());
+    }
+
     // -- Branch Response -----------------------------------------------------
 
     #[test]
@@ -1813,6 +4718,7 @@ mod tests {
         assert_eq!(branch.id, "branch_1");
         assert_eq!(branch.name, "main");
         assert!(!branch.is_archived);
+        assert_eq!(branch.last_committed_at, 1_700_001_000);
         assert_eq!(branch.protection_status, Some(BranchProtectionStatus::WriterPermsRequired));
         assert!((branch.current_live_percentage - 100.0).abs() < f64::EPSILON);
     }
@@ -1941,6 +4847,98 @@ mod tests {
         assert_eq!(json, r#"{"feedback":"like"}"#);
     }
 
+    // -- Transcript export ---------------------------------------------------
+
+    fn sample_transcript() -> Vec<ConversationTranscriptEntry> {
+        serde_json::from_value(serde_json::json!([
+            {"role": "agent", "message": "Hi! How can I help?", "time_in_call_secs": 0},
+            {
+                "role": "user",
+                "message": "I'm Jane, reach me at jane.doe@example.com or +1 (555) 123-4567.",
+                "time_in_call_secs": 4
+            },
+            {
+                "role": "agent",
+                "message": null,
+                "tool_calls": [{"tool_name": "lookup_customer", "params_as_json": "{\"name\":\"Jane\"}"}],
+                "tool_results": [{"tool_name": "lookup_customer", "result_value": "found"}],
+                "time_in_call_secs": 9
+            },
+            {"role": "agent", "message": "Thanks Jane, found you.", "time_in_call_secs": 9}
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn transcript_to_markdown() {
+        let entries = sample_transcript();
+        let markdown = ConversationTranscript::new(&entries).to_markdown();
+        assert_eq!(
+            markdown,
+            "**Agent** (0:00): Hi! How can I help?\n\n\
+             **User** (0:04): I'm Jane, reach me at jane.doe@example.com or +1 (555) 123-4567.\n\n\
+             **Agent** (0:09): _called lookup_customer_\n\n\
+             **Agent** (0:09): Thanks Jane, found you.\n"
+        );
+    }
+
+    #[test]
+    fn transcript_to_jsonl_round_trips_entries() {
+        let entries = sample_transcript();
+        let jsonl = ConversationTranscript::new(&entries).to_jsonl().unwrap();
+        let lines: Vec<&str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 4);
+        let back: ConversationTranscriptEntry = serde_json::from_str(lines[2]).unwrap();
+        assert_eq!(back, entries[2]);
+    }
+
+    #[test]
+    fn transcript_to_srt_times_cues_by_next_message() {
+        let entries = sample_transcript();
+        let srt = ConversationTranscript::new(&entries).to_srt();
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:04,000\nAgent: Hi! How can I help?\n\n\
+             2\n00:00:04,000 --> 00:00:09,000\n\
+             User: I'm Jane, reach me at jane.doe@example.com or +1 (555) 123-4567.\n\n\
+             3\n00:00:09,000 --> 00:00:10,533\nAgent: Thanks Jane, found you.\n"
+        );
+    }
+
+    #[test]
+    fn transcript_redaction_strips_pii() {
+        let entries = sample_transcript();
+        let transcript =
+            ConversationTranscript::new(&entries).redact(TranscriptRedaction::pii().term("Jane"));
+
+        let redacted = transcript.redacted_entries();
+        assert_eq!(
+            redacted[1].message.as_deref(),
+            Some("I'm [redacted], reach me at [email] or [phone].")
+        );
+        assert_eq!(redacted[3].message.as_deref(), Some("Thanks [redacted], found you."));
+        assert!(redacted[2].tool_calls.is_empty());
+        assert!(redacted[2].tool_results.is_empty());
+        assert!(!transcript.to_jsonl().unwrap().contains("Jane"));
+        // The source entries are untouched.
+        assert_eq!(entries[2].tool_calls.len(), 1);
+
+        let user_hidden = ConversationTranscript::new(&entries)
+            .redact(TranscriptRedaction { user_messages: true, ..TranscriptRedaction::default() });
+        assert!(user_hidden.to_markdown().contains("**User** (0:04): [redacted]\n"));
+    }
+
+    #[test]
+    fn phone_redaction_keeps_short_numbers() {
+        assert_eq!(
+            redact_phone_numbers("Order 42 for $19.99 (2 items)"),
+            "Order 42 for $19.99 (2 items)"
+        );
+        assert_eq!(redact_phone_numbers("Code 123-456 works"), "Code 123-456 works");
+        assert_eq!(redact_phone_numbers("Call 555 010 0199, thanks"), "Call [phone], thanks");
+        assert_eq!(redact_emails("<a@b.co>, not a@b"), "<[email]>, not a@b");
+    }
+
     // -- Knowledge Base Document Summary -------------------------------------
 
     #[test]
@@ -2076,7 +5074,7 @@ mod tests {
         assert_eq!(resp.id, "mcp_1");
         assert_eq!(resp.config.name.as_deref(), Some("My MCP Server"));
         assert_eq!(resp.config.transport, Some(McpServerTransport::Sse));
-        assert_eq!(resp.metadata.created_at, 1700000000);
+        assert_eq!(resp.metadata.created_at, 1_700_000_000);
     }
 
     #[test]
@@ -2100,6 +5098,45 @@ mod tests {
         assert_eq!(resp.mcp_servers[0].id, "mcp_1");
     }
 
+    #[test]
+    fn mcp_server_config_reads_secret_references() {
+        let json = r#"{
+            "approval_policy": "require_approval_per_tool",
+            "transport": "STREAMABLE_HTTP",
+            "url": {"secret_id": "sec_url"},
+            "secret_token": {"secret_id": "sec_token"},
+            "request_headers": {"X-Tenant": "acme"}
+        }"#;
+        let config: McpServerConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.approval_policy, Some(McpApprovalPolicy::RequireApprovalPerTool));
+        assert_eq!(
+            config.url,
+            Some(McpServerUrl::Secret(SecretRef { secret_id: "sec_url".into() }))
+        );
+        assert_eq!(config.secret_token.unwrap().secret_id, "sec_token");
+        assert_eq!(config.request_headers["X-Tenant"], WebhookHeaderValue::Plain("acme".into()));
+    }
+
+    #[test]
+    fn create_mcp_server_request_wraps_config() {
+        let request = CreateMcpServerRequest::new("CRM", "https://mcp.example.com/sse");
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "config": {"name": "CRM", "url": "https://mcp.example.com/sse", "transport": "SSE"}
+            })
+        );
+
+        let update = UpdateMcpServerRequest {
+            approval_policy: Some(McpApprovalPolicy::AutoApproveAll),
+            ..UpdateMcpServerRequest::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            serde_json::json!({"approval_policy": "auto_approve_all"})
+        );
+    }
+
     // -- Batch Call -----------------------------------------------------------
 
     #[test]
@@ -2143,6 +5180,70 @@ mod tests {
         assert!(!resp.has_more);
     }
 
+    #[test]
+    fn batch_call_recipient_merges_dynamic_variables() {
+        let recipient = BatchCallRecipient::new("+14155550100")
+            .client_data(serde_json::json!({
+                "conversation_config_override": {"agent": {"language": "en"}},
+                "dynamic_variables": {"plan": "free", "city": "Paris"}
+            }))
+            .dynamic_variable("plan", "pro")
+            .dynamic_variable("visits", 3);
+        let json = serde_json::to_value(&recipient).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "phone_number": "+14155550100",
+                "conversation_initiation_client_data": {
+                    "conversation_config_override": {"agent": {"language": "en"}},
+                    "dynamic_variables": {"plan": "pro", "city": "Paris", "visits": 3}
+                }
+            })
+        );
+
+        let bare = serde_json::to_value(BatchCallRecipient::new("+14155550100")).unwrap();
+        assert_eq!(bare, serde_json::json!({"phone_number": "+14155550100"}));
+    }
+
+    #[test]
+    fn batch_call_recipients_from_csv() {
+        let csv = "Phone, first_name ,note\r\n\
+                   +14155550100,Ada,\"Likes \"\"tea\"\", not coffee\"\r\n\
+                   ,,\r\n\
+                   +442071838750,,\r\n";
+        let recipients = BatchCallRecipients::from_csv(csv.as_bytes()).unwrap().0;
+        assert_eq!(recipients.len(), 2);
+        assert_eq!(recipients[0].phone_number, "+14155550100");
+        assert_eq!(recipients[0].dynamic_variables["first_name"], "Ada");
+        assert_eq!(recipients[0].dynamic_variables["note"], "Likes \"tea\", not coffee");
+        assert!(recipients[1].dynamic_variables.is_empty());
+
+        let request = SubmitBatchCallRequest::new("Outreach", "agent_1", recipients);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["recipients"][1], serde_json::json!({"phone_number": "+442071838750"}));
+    }
+
+    #[test]
+    fn batch_call_recipients_csv_errors() {
+        let err = BatchCallRecipients::from_csv(&b"name\nAda\n"[..]).unwrap_err();
+        assert!(matches!(err, BatchCallCsvError::MissingPhoneColumn));
+
+        let err = BatchCallRecipients::from_csv(&b""[..]).unwrap_err();
+        assert!(matches!(err, BatchCallCsvError::Empty));
+
+        for bad in ["4155550100", "+0155550100", "+1 415 555 0100", "+1234567890123456"] {
+            let csv = format!("phone_number\n+14155550100\n{bad}\n");
+            let err = BatchCallRecipients::from_csv(csv.as_bytes()).unwrap_err();
+            assert!(
+                matches!(&err, BatchCallCsvError::InvalidPhoneNumber { line: 3, value } if value == bad),
+                "{bad}: {err}"
+            );
+        }
+
+        let err = BatchCallRecipients::from_csv(&b"phone\n\"+1415"[..]).unwrap_err();
+        assert!(matches!(err, BatchCallCsvError::UnterminatedQuote));
+    }
+
     // -- Secrets --------------------------------------------------------------
 
     #[test]
@@ -2186,6 +5287,86 @@ mod tests {
         assert_eq!(resp.webhooks.events[0], WebhookEventType::Transcript);
     }
 
+    #[test]
+    fn patch_convai_settings_round_trip() {
+        let request = PatchConvAiSettingsRequest {
+            conversation_initiation_client_data_webhook: Some(
+                ConversationInitiationWebhook::new("https://example.com/webhook")
+                    .header("Content-Type", WebhookHeaderValue::Plain("application/json".into()))
+                    .header("X-Token", WebhookHeaderValue::Secret { secret_id: "sec_1".into() }),
+            ),
+            webhooks: Some(ConvAiWebhooks {
+                post_call_webhook_id: Some("wh_1".into()),
+                events: vec![WebhookEventType::CallInitiationFailure],
+            }),
+            default_livekit_stack: Some(LivekitStackType::Static),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "conversation_initiation_client_data_webhook": {
+                    "url": "https://example.com/webhook",
+                    "request_headers": {
+                        "Content-Type": "application/json",
+                        "X-Token": {"secret_id": "sec_1"}
+                    }
+                },
+                "webhooks": {
+                    "post_call_webhook_id": "wh_1",
+                    "events": ["call_initiation_failure"]
+                },
+                "default_livekit_stack": "static"
+            })
+        );
+        let back: PatchConvAiSettingsRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(back, request);
+    }
+
+    #[test]
+    fn convai_settings_response_with_webhook_deserialize() {
+        let json = r#"{
+            "conversation_initiation_client_data_webhook": {
+                "url": "https://example.com/webhook",
+                "request_headers": {"Authorization": {"secret_id": "sec_1"}}
+            },
+            "webhooks": {"post_call_webhook_id": null, "events": []},
+            "default_livekit_stack": "standard"
+        }"#;
+        let resp: GetConvAiSettingsResponse = serde_json::from_str(json).unwrap();
+        let webhook = resp.conversation_initiation_client_data_webhook.unwrap();
+        assert_eq!(
+            webhook.request_headers["Authorization"],
+            WebhookHeaderValue::Secret { secret_id: "sec_1".into() }
+        );
+        assert_eq!(resp.default_livekit_stack, Some(LivekitStackType::Standard));
+    }
+
+    #[test]
+    fn dashboard_chart_config_round_trip() {
+        let json = r#"{"charts": [
+            {"type": "call_success", "name": "Success rate"},
+            {"type": "criteria", "name": "Resolved", "criteria_id": "crit_1"},
+            {"type": "data_collection", "name": "Plan", "data_collection_id": "dc_1"}
+        ]}"#;
+        let settings: ConvAiDashboardSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            settings.charts[1],
+            ChartConfig::Criteria { name: "Resolved".into(), criteria_id: "crit_1".into() }
+        );
+        assert_eq!(settings.charts[2].name(), "Plan");
+
+        let request = PatchConvAiDashboardSettingsRequest { charts: Some(settings.charts) };
+        let value = serde_json::to_value(&request).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(value, expected);
+        assert_eq!(
+            serde_json::from_value::<PatchConvAiDashboardSettingsRequest>(value).unwrap(),
+            request
+        );
+    }
+
     // -- WhatsApp -------------------------------------------------------------
 
     #[test]
@@ -2309,4 +5490,182 @@ mod tests {
         assert_eq!(resp.agents.len(), 1);
         assert!(!resp.has_more);
     }
+
+    // -- Tool usage -----------------------------------------------------------
+
+    fn tool(id: &str, name: &str, total_calls: i64, avg_latency_secs: f64) -> ToolResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "tool_config": {
+                "type": "webhook",
+                "name": name,
+                "api_schema": {"url": "https://example.com/tool"}
+            },
+            "access_info": {
+                "is_creator": true,
+                "creator_name": "Alice",
+                "creator_email": "alice@example.com",
+                "role": "admin"
+            },
+            "usage_stats": {"total_calls": total_calls, "avg_latency_secs": avg_latency_secs}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn tool_usage_stats_defaults_total_calls() {
+        let stats: ToolUsageStats = serde_json::from_str(r#"{"avg_latency_secs": 0.25}"#).unwrap();
+        assert_eq!(stats.total_calls, 0);
+        assert!((stats.avg_latency_secs - 0.25).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn tool_usage_report_orders_by_usage() {
+        let tools = [
+            tool("t1", "lookup_order", 120, 0.4),
+            tool("t2", "legacy_crm", 0, 0.0),
+            tool("t3", "send_email", 7, 2.5),
+        ];
+        let report = ToolUsageReport::from_tools(&tools);
+
+        let ids: Vec<&str> = report.entries.iter().map(|e| e.tool_id.as_str()).collect();
+        assert_eq!(ids, ["t2", "t3", "t1"]);
+        assert_eq!(report.entries[0].name.as_deref(), Some("legacy_crm"));
+        assert_eq!(report.unused().count(), 1);
+        assert_eq!(report.least_used(2).len(), 2);
+        assert_eq!(report.least_used(10).len(), 3);
+        assert_eq!(report.slowest(1)[0].tool_id, "t3");
+    }
+
+    // -- Conversation Audio ---------------------------------------------------
+
+    #[test]
+    fn conversation_audio_extension_from_content_type() {
+        let audio = |content_type: &str| ConversationAudio {
+            data: bytes::Bytes::new(),
+            content_type: content_type.to_owned(),
+        };
+        assert_eq!(audio("audio/mpeg").extension(), "mp3");
+        assert_eq!(audio("audio/wav").extension(), "wav");
+        assert_eq!(audio("audio/ogg; codecs=opus").extension(), "ogg");
+        assert_eq!(audio("Audio/FLAC").extension(), "flac");
+        assert_eq!(audio("application/octet-stream").extension(), "mp3");
+    }
+
+    #[tokio::test]
+    async fn conversation_audio_save_to_sets_extension() {
+        let audio = ConversationAudio {
+            data: bytes::Bytes::from_static(b"RIFF"),
+            content_type: "audio/wav".into(),
+        };
+        let dir = std::env::temp_dir();
+        let target = dir.join(format!("conv-audio-{}.mp3", std::process::id()));
+        let written = audio.save_to(&target).await.unwrap();
+        assert_eq!(written.extension().and_then(|e| e.to_str()), Some("wav"));
+        assert_eq!(std::fs::read(&written).unwrap(), b"RIFF");
+        std::fs::remove_file(written).unwrap();
+    }
+
+    // -- Agent Bundles --------------------------------------------------------
+
+    fn bundle_agent() -> GetAgentResponse {
+        serde_json::from_value(serde_json::json!({
+            "agent_id": "agent_1",
+            "name": "Support",
+            "tags": ["prod"],
+            "conversation_config": {"agent": {"prompt": {
+                "prompt": "Be helpful.",
+                "tool_ids": ["tool_b", "tool_a"],
+                "knowledge_base": [
+                    {"type": "url", "name": "Docs", "id": "doc_2", "usage_mode": "auto"},
+                    {"type": "file", "name": "FAQ", "id": "doc_1"}
+                ]
+            }}},
+            "platform_settings": {"widget": {"variant": "compact"}}
+        }))
+        .unwrap()
+    }
+
+    fn bundle_tool(id: &str) -> ToolResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "tool_config": {
+                "type": "webhook",
+                "name": id,
+                "api_schema": {"url": "https://example.com/tool"}
+            },
+            "access_info": {"is_creator": true, "creator_name": "a", "creator_email": "a@b.c", "role": "admin"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn agent_bundle_is_sorted_and_deterministic() {
+        let agent = bundle_agent();
+        assert_eq!(agent.tool_ids(), ["tool_b", "tool_a"]);
+
+        let bundle =
+            AgentBundle::from_agent(&agent, &[bundle_tool("tool_b"), bundle_tool("tool_a")]);
+        assert_eq!(bundle.version, AGENT_BUNDLE_VERSION);
+        assert_eq!(bundle.tools[0].id, "tool_a");
+        assert_eq!(bundle.knowledge_base[0].id, "doc_1");
+        assert_eq!(bundle.knowledge_base[1].usage_mode.as_deref(), Some("auto"));
+
+        let json = bundle.to_json().unwrap();
+        assert!(json.ends_with("}\n"));
+        assert!(json.find("\"conversation_config\"") < json.find("\"name\""));
+        let back = AgentBundle::from_json(&json).unwrap();
+        assert_eq!(back, bundle);
+        assert_eq!(back.to_json().unwrap(), json);
+    }
+
+    #[test]
+    fn agent_bundle_remaps_tool_and_document_ids() {
+        let bundle = AgentBundle::from_agent(&bundle_agent(), &[]);
+        let tools = HashMap::from([("tool_a".to_owned(), "new_a".to_owned())]);
+        let docs = HashMap::from([("doc_2".to_owned(), "new_doc".to_owned())]);
+        let config = bundle.remapped_conversation_config(&tools, &docs);
+        let prompt = &config["agent"]["prompt"];
+        assert_eq!(prompt["tool_ids"], serde_json::json!(["tool_b", "new_a"]));
+        assert_eq!(prompt["knowledge_base"][0]["id"], "new_doc");
+        assert_eq!(prompt["knowledge_base"][1]["id"], "doc_1");
+    }
+
+    #[test]
+    fn rag_index_batch_results_deserialize() {
+        let results: HashMap<String, RagIndexBatchResult> =
+            serde_json::from_value(serde_json::json!({
+                "doc_1": {
+                    "status": "success",
+                    "data": {
+                        "id": "rag_1",
+                        "model": "multilingual_e5_large_instruct",
+                        "status": "processing",
+                        "progress_percentage": 40.0,
+                        "document_model_index_usage": {"used_bytes": 1024}
+                    }
+                },
+                "doc_2": {"status": "failure", "error_code": 404, "message": "not found"}
+            }))
+            .unwrap();
+
+        assert!(matches!(
+            &results["doc_1"],
+            RagIndexBatchResult::Success { data }
+                if data.model == EmbeddingModel::MultilingualE5LargeInstruct
+                    && data.status == RagIndexStatus::Processing
+                    && data.document_model_index_usage.used_bytes == 1024
+        ));
+        assert!(!RagIndexStatus::Processing.is_terminal());
+        assert!(matches!(results["doc_2"], RagIndexBatchResult::Failure { error_code: 404, .. }));
+        assert!(RagIndexStatus::RagLimitExceeded.is_terminal());
+        assert!(!RagIndexStatus::RagLimitExceeded.is_ready());
+        assert_eq!(
+            serde_json::to_value(CreateRagIndexRequest {
+                model: EmbeddingModel::E5Mistral7bInstruct
+            })
+            .unwrap(),
+            serde_json::json!({"model": "e5_mistral_7b_instruct"})
+        );
+    }
 }
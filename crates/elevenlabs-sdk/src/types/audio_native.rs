@@ -1,12 +1,19 @@
 //! Types for the ElevenLabs Audio Native endpoints.
 //!
-//! Covers the three Audio Native endpoints:
+//! Covers the Audio Native endpoints:
 //! - `POST /v1/audio-native` — create an Audio Native–enabled project
 //! - `GET /v1/audio-native/{project_id}/settings` — get project settings
+//! - `POST /v1/audio-native/{project_id}/settings` — update project settings
 //! - `POST /v1/audio-native/{project_id}/content` — update project content
 //!
-//! The create and content-update endpoints accept `multipart/form-data`.
-//! The types below capture the **non-file** fields the caller provides.
+//! The create, settings-update and content-update endpoints accept
+//! `multipart/form-data`. The request types below capture the **non-file**
+//! fields the caller provides; article content is passed as an
+//! [`AudioNativeContent`]. [`AudioNativeEmbedOptions`] renders the player
+//! snippet for a project, and [`AudioNativeWaitOptions`] controls how long
+//! to wait for a project's audio to be converted.
+
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
@@ -48,6 +55,32 @@ pub enum AudioNativeProjectStatus {
     Ready,
 }
 
+// ---------------------------------------------------------------------------
+// Content
+// ---------------------------------------------------------------------------
+
+/// Article content uploaded when creating or updating a project.
+///
+/// HTML should be a simple document of paragraphs and headings, e.g.
+/// `<html><body><div><p>Your content</p><h3>More content</h3></div></body></html>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AudioNativeContent {
+    /// An HTML document.
+    Html(String),
+    /// Plain text.
+    Text(String),
+}
+
+impl AudioNativeContent {
+    /// Returns the upload as `(data, filename, content_type)`.
+    pub const fn file_part(&self) -> (&[u8], &'static str, &'static str) {
+        match self {
+            Self::Html(html) => (html.as_bytes(), "article.html", "text/html"),
+            Self::Text(text) => (text.as_bytes(), "article.txt", "text/plain"),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Requests
 // ---------------------------------------------------------------------------
@@ -65,6 +98,13 @@ pub enum AudioNativeProjectStatus {
 /// let req = AudioNativeCreateProjectRequest { name: "My Article".into(), ..Default::default() };
 /// assert_eq!(req.name, "My Article");
 /// assert!(!req.auto_convert);
+///
+/// let req = AudioNativeCreateProjectRequest::new("Launch post")
+///     .title("We launched")
+///     .author("Jane Doe")
+///     .voice_id("21m00Tcm4TlvDq8ikWAM")
+///     .auto_convert(true);
+/// assert_eq!(req.author.as_deref(), Some("Jane Doe"));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
 pub struct AudioNativeCreateProjectRequest {
@@ -120,6 +160,91 @@ pub struct AudioNativeCreateProjectRequest {
     pub pronunciation_dictionary_locators: Vec<String>,
 }
 
+impl AudioNativeCreateProjectRequest {
+    /// Creates a request for a project called `name`, with every player
+    /// setting left to the account defaults.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), ..Self::default() }
+    }
+
+    /// Sets the title shown in the player.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the author shown in the player.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Sets the voice used to read the article.
+    pub fn voice_id(mut self, voice_id: impl Into<String>) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Sets the TTS model used to read the article.
+    pub fn model_id(mut self, model_id: impl Into<String>) -> Self {
+        self.model_id = Some(model_id.into());
+        self
+    }
+
+    /// Sets whether the article is converted to audio right away.
+    pub const fn auto_convert(mut self, auto_convert: bool) -> Self {
+        self.auto_convert = auto_convert;
+        self
+    }
+}
+
+/// Request fields for `POST /v1/audio-native/{project_id}/settings`.
+///
+/// Only fields that are set are sent; the rest keep their current values.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::AudioNativeUpdateSettingsRequest;
+///
+/// let req = AudioNativeUpdateSettingsRequest {
+///     background_color: Some("#101010".into()),
+///     text_color: Some("#FAFAFA".into()),
+///     ..Default::default()
+/// };
+/// assert!(req.title.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct AudioNativeUpdateSettingsRequest {
+    /// Title shown in the player.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Image URL used in the player (deprecated).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+
+    /// Author shown in the player.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Whether to use the small player (deprecated).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub small: Option<bool>,
+
+    /// Text color used in the player (CSS hex).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+
+    /// Background color used in the player (CSS hex).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+
+    /// How many minutes to persist the session across page reloads (deprecated).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sessionization: Option<i64>,
+}
+
 /// Request fields for `POST /v1/audio-native/{project_id}/content`.
 ///
 /// Updates an Audio Native project's content. Uses `multipart/form-data`;
@@ -155,8 +280,10 @@ pub struct AudioNativeCreateProjectResponse {
     /// The ID of the created Audio Native project.
     pub project_id: String,
     /// Whether the project is currently being converted.
+    #[serde(default)]
     pub converting: bool,
     /// The HTML snippet to embed the Audio Native player.
+    #[serde(default)]
     pub html_snippet: String,
 }
 
@@ -168,10 +295,13 @@ pub struct AudioNativeEditContentResponse {
     /// The ID of the project.
     pub project_id: String,
     /// Whether the project is currently being converted.
+    #[serde(default)]
     pub converting: bool,
     /// Whether the project is currently being published.
+    #[serde(default)]
     pub publishing: bool,
     /// The HTML snippet to embed the Audio Native player.
+    #[serde(default)]
     pub html_snippet: String,
 }
 
@@ -182,18 +312,25 @@ pub struct AudioNativeEditContentResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AudioNativeProjectSettings {
     /// The title of the project.
+    #[serde(default)]
     pub title: String,
     /// The image URL of the project.
+    #[serde(default)]
     pub image: String,
     /// The author of the project.
+    #[serde(default)]
     pub author: String,
     /// Whether the project uses the small player.
+    #[serde(default)]
     pub small: bool,
     /// The text color (CSS hex).
+    #[serde(default)]
     pub text_color: String,
     /// The background color (CSS hex).
+    #[serde(default)]
     pub background_color: String,
     /// How many minutes to persist the session across page reloads.
+    #[serde(default)]
     pub sessionization: i64,
     /// The path of the audio file, if available.
     pub audio_path: Option<String>,
@@ -210,6 +347,7 @@ pub struct AudioNativeProjectSettings {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetAudioNativeProjectSettingsResponse {
     /// Whether the project is enabled.
+    #[serde(default)]
     pub enabled: bool,
     /// The ID of the latest snapshot of the project.
     pub snapshot_id: Option<String>,
@@ -217,6 +355,151 @@ pub struct GetAudioNativeProjectSettingsResponse {
     pub settings: Option<AudioNativeProjectSettings>,
 }
 
+impl GetAudioNativeProjectSettingsResponse {
+    /// Returns `true` once the article audio has been converted: the
+    /// project is [`Ready`](AudioNativeProjectStatus::Ready) and has an
+    /// audio URL.
+    pub fn is_converted(&self) -> bool {
+        self.settings.as_ref().is_some_and(|settings| {
+            settings.status == AudioNativeProjectStatus::Ready && settings.audio_url.is_some()
+        })
+    }
+}
+
+/// Options for waiting on an Audio Native conversion with
+/// [`AudioNativeService::wait_until_converted_with`](crate::services::AudioNativeService::wait_until_converted_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioNativeWaitOptions {
+    /// Delay between settings polls.
+    pub poll_interval: Duration,
+    /// Gives up after this long. Conversion keeps running server-side; only
+    /// the polling stops.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for AudioNativeWaitOptions {
+    fn default() -> Self {
+        Self { poll_interval: Duration::from_secs(3), timeout: Some(Duration::from_mins(15)) }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Embed snippet
+// ---------------------------------------------------------------------------
+
+/// Default player page loaded by the embed snippet.
+pub const AUDIO_NATIVE_PLAYER_URL: &str = "https://elevenlabs.io/player/index.html";
+
+/// Default helper script that turns the snippet into a player.
+pub const AUDIO_NATIVE_HELPER_SCRIPT_URL: &str =
+    "https://elevenlabs.io/player/audioNativeHelper.js";
+
+/// Options for rendering an Audio Native player snippet.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::AudioNativeEmbedOptions;
+///
+/// let html = AudioNativeEmbedOptions::default().height(120).to_html("proj_123");
+/// assert!(html.contains(r#"data-projectid="proj_123""#));
+/// assert!(html.contains(r#"data-height="120""#));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioNativeEmbedOptions {
+    /// Player height in pixels.
+    pub height: u32,
+    /// Player width as a CSS length.
+    pub width: String,
+    /// Public user ID of the account that owns the project, if the player
+    /// should be attributed to it.
+    pub public_user_id: Option<String>,
+    /// Player page URL.
+    pub player_url: String,
+    /// Whether to append the `<script>` tag loading the helper script. Turn
+    /// off when embedding several players on one page and loading the script
+    /// once.
+    pub include_script: bool,
+}
+
+impl Default for AudioNativeEmbedOptions {
+    fn default() -> Self {
+        Self {
+            height: 90,
+            width: "100%".to_owned(),
+            public_user_id: None,
+            player_url: AUDIO_NATIVE_PLAYER_URL.to_owned(),
+            include_script: true,
+        }
+    }
+}
+
+impl AudioNativeEmbedOptions {
+    /// Sets the player height in pixels.
+    pub const fn height(mut self, height: u32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the player width as a CSS length.
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the public user ID the player is attributed to.
+    pub fn public_user_id(mut self, public_user_id: impl Into<String>) -> Self {
+        self.public_user_id = Some(public_user_id.into());
+        self
+    }
+
+    /// Sets whether the helper `<script>` tag is included.
+    pub const fn include_script(mut self, include_script: bool) -> Self {
+        self.include_script = include_script;
+        self
+    }
+
+    /// Renders the snippet for `project_id`. Attribute values are
+    /// HTML-escaped.
+    pub fn to_html(&self, project_id: &str) -> String {
+        let mut html = format!(
+            r#"<div id="elevenlabs-audionative-widget" data-height="{}" data-width="{}" data-frameborder="no" data-scrolling="no""#,
+            self.height,
+            escape_attr(&self.width),
+        );
+        if let Some(ref user_id) = self.public_user_id {
+            html.push_str(&format!(r#" data-publicuserid="{}""#, escape_attr(user_id)));
+        }
+        html.push_str(&format!(
+            r#" data-playerurl="{}" data-projectid="{}">Loading the <a href="https://elevenlabs.io/text-to-speech" target="_blank" rel="noopener">Elevenlabs Text to Speech</a> AudioNative Player...</div>"#,
+            escape_attr(&self.player_url),
+            escape_attr(project_id),
+        ));
+        if self.include_script {
+            html.push_str(&format!(
+                r#"<script src="{AUDIO_NATIVE_HELPER_SCRIPT_URL}" type="text/javascript"></script>"#
+            ));
+        }
+        html
+    }
+}
+
+/// Escapes `value` for use inside a double-quoted HTML attribute.
+fn escape_attr(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -467,4 +750,62 @@ mod tests {
     fn project_status_default_is_ready() {
         assert_eq!(AudioNativeProjectStatus::default(), AudioNativeProjectStatus::Ready);
     }
+
+    // -- AudioNativeContent -------------------------------------------------
+
+    #[test]
+    fn content_file_part_matches_kind() {
+        let html = AudioNativeContent::Html("<p>Hi</p>".into());
+        assert_eq!(html.file_part(), (&b"<p>Hi</p>"[..], "article.html", "text/html"));
+        let text = AudioNativeContent::Text("Hi".into());
+        assert_eq!(text.file_part(), (&b"Hi"[..], "article.txt", "text/plain"));
+    }
+
+    // -- AudioNativeCreateProjectRequest builder ----------------------------
+
+    #[test]
+    fn create_request_builder_sets_fields() {
+        let req = AudioNativeCreateProjectRequest::new("Post")
+            .title("Title")
+            .author("Jane")
+            .voice_id("v1")
+            .model_id("eleven_multilingual_v2")
+            .auto_convert(true);
+        assert_eq!(req.name, "Post");
+        assert_eq!(req.title.as_deref(), Some("Title"));
+        assert_eq!(req.author.as_deref(), Some("Jane"));
+        assert_eq!(req.voice_id.as_deref(), Some("v1"));
+        assert_eq!(req.model_id.as_deref(), Some("eleven_multilingual_v2"));
+        assert!(req.auto_convert);
+        assert!(req.image.is_none());
+    }
+
+    // -- AudioNativeEmbedOptions --------------------------------------------
+
+    #[test]
+    fn embed_html_defaults() {
+        let html = AudioNativeEmbedOptions::default().to_html("proj_1");
+        assert!(html.starts_with(
+            r#"<div id="elevenlabs-audionative-widget" data-height="90" data-width="100%""#
+        ));
+        assert!(html.contains(r#"data-playerurl="https://elevenlabs.io/player/index.html""#));
+        assert!(html.contains(r#"data-projectid="proj_1""#));
+        assert!(!html.contains("data-publicuserid"));
+        assert!(html.ends_with(
+            r#"<script src="https://elevenlabs.io/player/audioNativeHelper.js" type="text/javascript"></script>"#
+        ));
+    }
+
+    #[test]
+    fn embed_html_applies_options_and_escapes() {
+        let html = AudioNativeEmbedOptions::default()
+            .width("50%")
+            .public_user_id("u\"1")
+            .include_script(false)
+            .to_html("a<b>&");
+        assert!(html.contains(r#"data-width="50%""#));
+        assert!(html.contains(r#"data-publicuserid="u&quot;1""#));
+        assert!(html.contains(r#"data-projectid="a&lt;b&gt;&amp;""#));
+        assert!(!html.contains("<script"));
+    }
 }
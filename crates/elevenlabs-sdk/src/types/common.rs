@@ -1,7 +1,8 @@
 //! Common types shared across multiple ElevenLabs API groups.
 //!
 //! Includes voice settings, output format enums, model metadata,
-//! language descriptors, and cursor-based pagination helpers.
+//! language descriptors, cursor-based pagination helpers, and Unix
+//! timestamps with date-range filters.
 
 use std::fmt;
 
@@ -71,6 +72,27 @@ impl Default for VoiceSettings {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Text Normalization
+// ---------------------------------------------------------------------------
+
+/// Controls how text normalization is applied before synthesis.
+///
+/// When set to `Auto` (the default), the system automatically decides whether
+/// to apply text normalization (e.g., spelling out numbers). `On` forces
+/// normalization on every request; `Off` skips it entirely.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextNormalization {
+    /// System decides automatically.
+    #[default]
+    Auto,
+    /// Always apply text normalization.
+    On,
+    /// Never apply text normalization.
+    Off,
+}
+
 // ---------------------------------------------------------------------------
 // Output Format
 // ---------------------------------------------------------------------------
@@ -223,16 +245,74 @@ impl fmt::Display for OutputFormat {
     }
 }
 
+impl OutputFormat {
+    /// Returns the sample rate in Hz.
+    pub const fn sample_rate(self) -> u32 {
+        match self {
+            Self::Pcm_8000 | Self::Wav_8000 | Self::Ulaw_8000 | Self::Alaw_8000 => 8000,
+            Self::Pcm_16000 | Self::Wav_16000 => 16000,
+            Self::Mp3_22050_32 | Self::Pcm_22050 | Self::Wav_22050 => 22050,
+            Self::Mp3_24000_48 | Self::Pcm_24000 | Self::Wav_24000 => 24000,
+            Self::Pcm_32000 | Self::Wav_32000 => 32000,
+            Self::Mp3_44100_32 |
+            Self::Mp3_44100_64 |
+            Self::Mp3_44100_96 |
+            Self::Mp3_44100_128 |
+            Self::Mp3_44100_192 |
+            Self::Pcm_44100 |
+            Self::Wav_44100 => 44100,
+            Self::Pcm_48000 |
+            Self::Wav_48000 |
+            Self::Opus_48000_32 |
+            Self::Opus_48000_64 |
+            Self::Opus_48000_96 |
+            Self::Opus_48000_128 |
+            Self::Opus_48000_192 => 48000,
+        }
+    }
+
+    /// Returns the bitrate in kbps of MP3 and Opus formats, or `None` for
+    /// uncompressed formats.
+    pub const fn bitrate_kbps(self) -> Option<u32> {
+        match self {
+            Self::Mp3_22050_32 | Self::Mp3_44100_32 | Self::Opus_48000_32 => Some(32),
+            Self::Mp3_24000_48 => Some(48),
+            Self::Mp3_44100_64 | Self::Opus_48000_64 => Some(64),
+            Self::Mp3_44100_96 | Self::Opus_48000_96 => Some(96),
+            Self::Mp3_44100_128 | Self::Opus_48000_128 => Some(128),
+            Self::Mp3_44100_192 | Self::Opus_48000_192 => Some(192),
+            _ => None,
+        }
+    }
+
+    /// Returns the PCM format with the given sample rate, if the API offers
+    /// one.
+    pub const fn pcm(sample_rate: u32) -> Option<Self> {
+        match sample_rate {
+            8000 => Some(Self::Pcm_8000),
+            16000 => Some(Self::Pcm_16000),
+            22050 => Some(Self::Pcm_22050),
+            24000 => Some(Self::Pcm_24000),
+            32000 => Some(Self::Pcm_32000),
+            44100 => Some(Self::Pcm_44100),
+            48000 => Some(Self::Pcm_48000),
+            _ => None,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Language
 // ---------------------------------------------------------------------------
 
 /// A language supported by an ElevenLabs model.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct Language {
     /// ISO-style language identifier (e.g. `"en"`, `"es"`).
     pub language_id: String,
     /// Human-readable language name (e.g. `"English"`).
+    #[serde(default)]
     pub name: String,
 }
 
@@ -242,11 +322,24 @@ pub struct Language {
 
 /// Billing rates for a model.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct ModelRates {
     /// Multiplier applied to the base character cost.
+    #[serde(default = "default_cost_multiplier")]
     pub character_cost_multiplier: f64,
 }
 
+impl Default for ModelRates {
+    fn default() -> Self {
+        Self { character_cost_multiplier: default_cost_multiplier() }
+    }
+}
+
+/// Cost multipliers default to 1 (base pricing) when the API omits them.
+const fn default_cost_multiplier() -> f64 {
+    1.0
+}
+
 // ---------------------------------------------------------------------------
 // Model
 // ---------------------------------------------------------------------------
@@ -257,43 +350,83 @@ pub struct ModelRates {
 /// capabilities (TTS, voice conversion, style, speaker boost) and the
 /// languages it supports.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct Model {
     /// Unique model identifier (e.g. `"eleven_multilingual_v2"`).
     pub model_id: String,
     /// Human-readable model name.
+    #[serde(default)]
     pub name: String,
     /// Whether the model can be finetuned with custom voice data.
+    #[serde(default)]
     pub can_be_finetuned: bool,
     /// Whether the model supports text-to-speech.
+    #[serde(default)]
     pub can_do_text_to_speech: bool,
     /// Whether the model supports voice conversion (speech-to-speech).
+    #[serde(default)]
     pub can_do_voice_conversion: bool,
     /// Whether the model supports the style parameter.
+    #[serde(default)]
     pub can_use_style: bool,
     /// Whether the model supports the speaker boost parameter.
+    #[serde(default)]
     pub can_use_speaker_boost: bool,
     /// Whether the model serves professional voices.
+    #[serde(default)]
     pub serves_pro_voices: bool,
     /// Cost factor relative to the base model pricing.
+    #[serde(default = "default_cost_multiplier")]
     pub token_cost_factor: f64,
     /// Human-readable description of the model.
+    #[serde(default)]
     pub description: String,
     /// Whether the model requires alpha access to use.
+    #[serde(default)]
     pub requires_alpha_access: bool,
     /// Maximum characters per request for free-tier users.
+    #[serde(default)]
     pub max_characters_request_free_user: i64,
     /// Maximum characters per request for subscribed users.
+    #[serde(default)]
     pub max_characters_request_subscribed_user: i64,
     /// Absolute maximum text length that can be sent in a single request.
+    #[serde(default)]
     pub maximum_text_length_per_request: i64,
     /// Languages this model supports.
+    #[serde(default)]
     pub languages: Vec<Language>,
     /// Billing rates for this model.
+    #[serde(default)]
     pub model_rates: ModelRates,
     /// Concurrency group this model belongs to.
+    #[serde(default)]
     pub concurrency_group: String,
 }
 
+impl Model {
+    /// Whether the model supports the language with ID `language_id`
+    /// (e.g. `"en"`), compared case-insensitively.
+    pub fn supports_language(&self, language_id: &str) -> bool {
+        self.languages.iter().any(|l| l.language_id.eq_ignore_ascii_case(language_id))
+    }
+
+    /// Maximum characters per request for a free or subscribed account.
+    pub const fn max_characters_per_request(&self, subscribed: bool) -> i64 {
+        if subscribed {
+            self.max_characters_request_subscribed_user
+        } else {
+            self.max_characters_request_free_user
+        }
+    }
+
+    /// Whether this is one of the low-latency model families (Flash or
+    /// Turbo), judged from the model ID.
+    pub fn is_low_latency(&self) -> bool {
+        self.model_id.contains("flash") || self.model_id.contains("turbo")
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Subscription
 // ---------------------------------------------------------------------------
@@ -347,41 +480,55 @@ pub enum Currency {
 
 /// User subscription details returned by the API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct Subscription {
     /// Subscription tier name (e.g. `"trial"`, `"creator"`).
+    #[serde(default)]
     pub tier: String,
     /// Number of characters used in the current billing period.
+    #[serde(default)]
     pub character_count: i64,
     /// Maximum characters allowed in the current billing period.
+    #[serde(default)]
     pub character_limit: i64,
     /// Maximum additional characters the limit can be extended by.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_character_limit_extension: Option<i64>,
     /// Whether the user can extend their character limit.
+    #[serde(default)]
     pub can_extend_character_limit: bool,
     /// Whether the user is allowed to extend their character limit.
+    #[serde(default)]
     pub allowed_to_extend_character_limit: bool,
     /// Unix timestamp of next character count reset.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_character_count_reset_unix: Option<i64>,
     /// Number of voice slots in use.
+    #[serde(default)]
     pub voice_slots_used: i64,
     /// Number of professional voice slots in use.
+    #[serde(default)]
     pub professional_voice_slots_used: i64,
     /// Maximum number of voice slots allowed.
+    #[serde(default)]
     pub voice_limit: i64,
     /// Maximum voice add/edit operations allowed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_voice_add_edits: Option<i64>,
     /// Number of voice add/edit operations performed.
+    #[serde(default)]
     pub voice_add_edit_counter: i64,
     /// Maximum number of professional voices allowed.
+    #[serde(default)]
     pub professional_voice_limit: i64,
     /// Whether the user can extend their voice limit.
+    #[serde(default)]
     pub can_extend_voice_limit: bool,
     /// Whether the user can use instant voice cloning.
+    #[serde(default)]
     pub can_use_instant_voice_cloning: bool,
     /// Whether the user can use professional voice cloning.
+    #[serde(default)]
     pub can_use_professional_voice_cloning: bool,
     /// Currency of the subscription.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -446,6 +593,7 @@ pub enum SafetyControl {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VerifiedVoiceLanguage {
     /// ISO language code (e.g. `"en"`).
+    #[serde(default)]
     pub language: String,
     /// Model ID this verification applies to.
     pub model_id: String,
@@ -486,12 +634,181 @@ pub struct CursorPageParams {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PageInfo {
     /// Whether additional pages are available.
+    #[serde(default)]
     pub has_more: bool,
     /// Opaque cursor to fetch the next page, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_cursor: Option<String>,
 }
 
+// ---------------------------------------------------------------------------
+// Timestamps
+// ---------------------------------------------------------------------------
+
+/// A point in time as whole seconds since the Unix epoch.
+///
+/// Serializes as a bare integer, exactly like the API's `*_unix` fields.
+/// With the `chrono` feature it converts to and from
+/// [`chrono::DateTime`].
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::Timestamp;
+///
+/// let created: Timestamp = serde_json::from_str("1714650306").unwrap();
+/// assert_eq!(created.as_unix_secs(), 1_714_650_306);
+/// assert_eq!(serde_json::to_string(&created).unwrap(), "1714650306");
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    /// Creates a timestamp from seconds since the Unix epoch.
+    pub const fn from_unix_secs(secs: i64) -> Self {
+        Self(secs)
+    }
+
+    /// Returns the seconds since the Unix epoch.
+    pub const fn as_unix_secs(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the current time, truncated to whole seconds.
+    pub fn now() -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self(i64::try_from(secs).unwrap_or(i64::MAX))
+    }
+
+    /// Converts to a UTC date-time, or `None` if out of chrono's range.
+    #[cfg(feature = "chrono")]
+    pub const fn to_datetime(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.0, 0)
+    }
+
+    /// Converts to a date-time in the local time zone, or `None` if out of
+    /// chrono's range.
+    #[cfg(feature = "chrono")]
+    pub fn to_local(self) -> Option<chrono::DateTime<chrono::Local>> {
+        self.to_datetime().map(|utc| utc.with_timezone(&chrono::Local))
+    }
+}
+
+impl From<i64> for Timestamp {
+    fn from(secs: i64) -> Self {
+        Self(secs)
+    }
+}
+
+impl From<Timestamp> for i64 {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+impl PartialEq<i64> for Timestamp {
+    fn eq(&self, other: &i64) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        Self(datetime.timestamp())
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A range of [`Timestamp`]s used to filter list endpoints.
+///
+/// Both bounds are inclusive; `None` leaves that side open. The default
+/// range is unbounded.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{DateRange, Timestamp};
+///
+/// let range = DateRange::between(1_735_689_600, 1_738_367_999);
+/// assert!(range.contains(Timestamp::from_unix_secs(1_736_000_000)));
+/// assert!(!DateRange::since(1_738_368_000).contains(range.before.unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DateRange {
+    /// Earliest time to include.
+    pub after: Option<Timestamp>,
+    /// Latest time to include.
+    pub before: Option<Timestamp>,
+}
+
+impl DateRange {
+    /// Creates a range from `after` to `before`.
+    pub fn between(after: impl Into<Timestamp>, before: impl Into<Timestamp>) -> Self {
+        Self { after: Some(after.into()), before: Some(before.into()) }
+    }
+
+    /// Creates a range of everything at or after `after`.
+    pub fn since(after: impl Into<Timestamp>) -> Self {
+        Self { after: Some(after.into()), before: None }
+    }
+
+    /// Creates a range of everything at or before `before`.
+    pub fn until(before: impl Into<Timestamp>) -> Self {
+        Self { after: None, before: Some(before.into()) }
+    }
+
+    /// Creates the range covering calendar day `date` in time zone `tz`,
+    /// from its first to its last second.
+    ///
+    /// A bound whose local midnight does not exist (a DST gap) is left
+    /// open.
+    #[cfg(feature = "chrono")]
+    pub fn day<Tz: chrono::TimeZone>(date: chrono::NaiveDate, tz: &Tz) -> Self {
+        let midnight = |date: chrono::NaiveDate| {
+            tz.from_local_datetime(&date.and_time(chrono::NaiveTime::MIN))
+                .earliest()
+                .map(Timestamp::from)
+        };
+        Self {
+            after: midnight(date),
+            before: date
+                .succ_opt()
+                .and_then(midnight)
+                .map(|next| Timestamp(next.0.saturating_sub(1))),
+        }
+    }
+
+    /// Creates the range covering calendar day `date` in the local time
+    /// zone. See [`day`](Self::day).
+    #[cfg(feature = "chrono")]
+    pub fn local_day(date: chrono::NaiveDate) -> Self {
+        Self::day(date, &chrono::Local)
+    }
+
+    /// Returns `true` if `timestamp` falls inside the range.
+    pub fn contains(&self, timestamp: impl Into<Timestamp>) -> bool {
+        let timestamp = timestamp.into();
+        self.after.is_none_or(|after| timestamp >= after) &&
+            self.before.is_none_or(|before| timestamp <= before)
+    }
+
+    /// Returns `true` if neither bound is set.
+    pub const fn is_unbounded(&self) -> bool {
+        self.after.is_none() && self.before.is_none()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Type aliases for readability
 // ---------------------------------------------------------------------------
@@ -623,6 +940,16 @@ mod tests {
         assert_eq!(OutputFormat::default(), OutputFormat::Mp3_44100_128);
     }
 
+    #[test]
+    fn output_format_sample_rate_and_bitrate() {
+        assert_eq!(OutputFormat::Mp3_22050_32.sample_rate(), 22050);
+        assert_eq!(OutputFormat::Mp3_22050_32.bitrate_kbps(), Some(32));
+        assert_eq!(OutputFormat::Ulaw_8000.sample_rate(), 8000);
+        assert_eq!(OutputFormat::Pcm_44100.bitrate_kbps(), None);
+        assert_eq!(OutputFormat::pcm(24000), Some(OutputFormat::Pcm_24000));
+        assert_eq!(OutputFormat::pcm(11025), None);
+    }
+
     #[test]
     fn output_format_display() {
         assert_eq!(OutputFormat::Mp3_44100_128.to_string(), "mp3_44100_128");
@@ -828,4 +1155,37 @@ mod tests {
         let info_done = PageInfo { has_more: false, next_cursor: None };
         round_trip(&info_done);
     }
+
+    // -- Timestamp / DateRange -----------------------------------------------
+
+    #[test]
+    fn timestamp_serializes_as_unix_seconds() {
+        let timestamp = Timestamp::from_unix_secs(1_714_650_306);
+        assert_eq!(serde_json::to_string(&timestamp).unwrap(), "1714650306");
+        assert_eq!(timestamp.to_string(), "1714650306");
+        round_trip(&timestamp);
+    }
+
+    #[test]
+    fn date_range_bounds_are_inclusive() {
+        let range = DateRange::between(100, 200);
+        assert!(range.contains(100) && range.contains(200));
+        assert!(!range.contains(99) && !range.contains(201));
+        assert!(DateRange::until(200).contains(i64::MIN));
+        assert!(DateRange::default().is_unbounded());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn date_range_day_covers_local_calendar_day() {
+        let tz = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+        let range = DateRange::day(date, &tz);
+        assert_eq!(range.after, Some(Timestamp::from_unix_secs(1_714_600_800)));
+        assert_eq!(range.before, Some(Timestamp::from_unix_secs(1_714_687_199)));
+        assert_eq!(
+            range.after.unwrap().to_datetime().unwrap().to_rfc3339(),
+            "2024-05-01T22:00:00+00:00"
+        );
+    }
 }
@@ -24,6 +24,7 @@
 
 use std::collections::HashMap;
 
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
 use super::common::VoiceCategory;
@@ -100,27 +101,36 @@ pub enum RenderStatus {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DubbingMediaMetadata {
     /// The MIME content type of the media.
+    #[serde(default)]
     pub content_type: String,
     /// Duration of the media in seconds.
+    #[serde(default)]
     pub duration: f64,
 }
 
 /// A reference to a media file stored in the dubbing system.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DubbingMediaReference {
     /// Source path.
+    #[serde(default)]
     pub src: String,
     /// MIME content type.
+    #[serde(default)]
     pub content_type: String,
     /// Storage bucket name.
+    #[serde(default)]
     pub bucket_name: String,
     /// Random path slug.
+    #[serde(default)]
     pub random_path_slug: String,
     /// Duration in seconds.
+    #[serde(default)]
     pub duration_secs: f64,
     /// Whether this is an audio-only file.
+    #[serde(default)]
     pub is_audio: bool,
     /// Accessible URL for the media.
+    #[serde(default)]
     pub url: String,
 }
 
@@ -130,17 +140,21 @@ pub struct DubbingMetadataResponse {
     /// The ID of the dubbing project.
     pub dubbing_id: String,
     /// The name of the dubbing project.
+    #[serde(default)]
     pub name: String,
     /// The state this dub is in.
+    #[serde(default)]
     pub status: String,
     /// ISO-639-1 code of the original media's source language, if detected.
     pub source_language: Option<String>,
     /// ISO-639-1 codes of the languages this media has been dubbed into.
+    #[serde(default)]
     pub target_languages: Vec<String>,
     /// Whether this dubbing project is editable in Dubbing Studio.
     #[serde(default)]
     pub editable: bool,
     /// Timestamp when this dub was created (ISO-8601).
+    #[serde(default)]
     pub created_at: String,
     /// Metadata about the dubbed content (length, type).
     pub media_metadata: Option<DubbingMediaMetadata>,
@@ -148,14 +162,32 @@ pub struct DubbingMetadataResponse {
     pub error: Option<String>,
 }
 
+#[cfg(feature = "chrono")]
+impl DubbingMetadataResponse {
+    /// Parses [`created_at`](Self::created_at), reading a date-time without
+    /// an offset as UTC. Returns `None` if it cannot be parsed.
+    pub fn created_at_timestamp(&self) -> Option<super::Timestamp> {
+        let created_at = self.created_at.as_str();
+        chrono::DateTime::parse_from_rfc3339(created_at)
+            .map(|datetime| datetime.timestamp())
+            .or_else(|_| {
+                created_at.parse::<chrono::NaiveDateTime>().map(|naive| naive.and_utc().timestamp())
+            })
+            .ok()
+            .map(super::Timestamp::from_unix_secs)
+    }
+}
+
 /// Paginated list of dubbing projects.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DubbingMetadataPageResponse {
     /// The dubbing projects on this page.
+    #[serde(default)]
     pub dubs: Vec<DubbingMetadataResponse>,
     /// Cursor for the next page, if any.
     pub next_cursor: Option<String>,
     /// Whether there are more pages.
+    #[serde(default)]
     pub has_more: bool,
 }
 
@@ -165,6 +197,7 @@ pub struct DoDubbingResponse {
     /// The ID of the created dubbing project.
     pub dubbing_id: String,
     /// Expected duration of the dubbing in seconds.
+    #[serde(default)]
     pub expected_duration_sec: f64,
 }
 
@@ -172,6 +205,7 @@ pub struct DoDubbingResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeleteDubbingResponse {
     /// Status of the deletion. "ok" on success.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -185,12 +219,13 @@ pub struct Render {
     /// Render ID.
     pub id: String,
     /// Resource version when rendered.
+    #[serde(default)]
     pub version: i64,
     /// Target language of the render.
     pub language: Option<String>,
-    /// Type of render (e.g., audio, video). Complex type — stored as Value.
+    /// Output format of the render.
     #[serde(rename = "type")]
-    pub render_type: Option<serde_json::Value>,
+    pub render_type: Option<RenderType>,
     /// Media reference for the rendered file.
     pub media_ref: Option<DubbingMediaReference>,
     /// Status of the render.
@@ -201,10 +236,13 @@ pub struct Render {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SegmentSubtitleFrame {
     /// Start time of the subtitle frame in seconds.
+    #[serde(default)]
     pub start_time: f64,
     /// End time of the subtitle frame in seconds.
+    #[serde(default)]
     pub end_time: f64,
     /// Lines of subtitle text.
+    #[serde(default)]
     pub lines: Vec<String>,
 }
 
@@ -212,14 +250,18 @@ pub struct SegmentSubtitleFrame {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DubbedSegment {
     /// Start time of the segment in seconds.
+    #[serde(default)]
     pub start_time: f64,
     /// End time of the segment in seconds.
+    #[serde(default)]
     pub end_time: f64,
     /// The dubbed text for this segment.
     pub text: Option<String>,
     /// Subtitle frames within this segment.
+    #[serde(default)]
     pub subtitles: Vec<SegmentSubtitleFrame>,
     /// Whether the audio for this segment is stale and needs re-dubbing.
+    #[serde(default)]
     pub audio_stale: bool,
     /// Media reference for the dubbed audio.
     pub media_ref: Option<DubbingMediaReference>,
@@ -231,14 +273,19 @@ pub struct SpeakerSegment {
     /// Segment ID.
     pub id: String,
     /// Start time in seconds.
+    #[serde(default)]
     pub start_time: f64,
     /// End time in seconds.
+    #[serde(default)]
     pub end_time: f64,
     /// Source text for this segment.
+    #[serde(default)]
     pub text: String,
     /// Subtitle frames for this segment.
+    #[serde(default)]
     pub subtitles: Vec<SegmentSubtitleFrame>,
     /// Per-language dubbed segments. Keys are language codes.
+    #[serde(default)]
     pub dubs: HashMap<String, DubbedSegment>,
 }
 
@@ -248,12 +295,16 @@ pub struct SpeakerTrack {
     /// Speaker track ID.
     pub id: String,
     /// Media reference for the speaker's audio track.
+    #[serde(default)]
     pub media_ref: DubbingMediaReference,
     /// Display name for the speaker.
+    #[serde(default)]
     pub speaker_name: String,
     /// Per-language voice ID assignments. Keys are language codes.
+    #[serde(default)]
     pub voices: HashMap<String, String>,
     /// Segment IDs belonging to this speaker.
+    #[serde(default)]
     pub segments: Vec<String>,
 }
 
@@ -263,22 +314,29 @@ pub struct DubbingResource {
     /// Resource ID (same as dubbing_id).
     pub id: String,
     /// Version of the resource.
+    #[serde(default)]
     pub version: i64,
     /// Source language code.
+    #[serde(default)]
     pub source_language: String,
     /// Target language codes.
+    #[serde(default)]
     pub target_languages: Vec<String>,
     /// Input media reference.
+    #[serde(default)]
     pub input: DubbingMediaReference,
     /// Background audio track, if separated.
     pub background: Option<DubbingMediaReference>,
     /// Foreground audio track, if separated.
     pub foreground: Option<DubbingMediaReference>,
     /// Speaker tracks keyed by speaker ID.
+    #[serde(default)]
     pub speaker_tracks: HashMap<String, SpeakerTrack>,
     /// Speaker segments keyed by segment ID.
+    #[serde(default)]
     pub speaker_segments: HashMap<String, SpeakerSegment>,
     /// Renders keyed by render ID.
+    #[serde(default)]
     pub renders: HashMap<String, Render>,
 }
 
@@ -344,8 +402,10 @@ pub struct DubbingTranscriptUtterance {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DubbingTranscript {
     /// Language code of this transcript.
+    #[serde(default)]
     pub language: String,
     /// Utterances in this transcript.
+    #[serde(default)]
     pub utterances: Vec<DubbingTranscriptUtterance>,
 }
 
@@ -353,8 +413,10 @@ pub struct DubbingTranscript {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DubbingTranscriptResponse {
     /// Language code of this transcript.
+    #[serde(default)]
     pub language: String,
     /// Utterances in this transcript.
+    #[serde(default)]
     pub utterances: Vec<DubbingTranscriptUtterance>,
 }
 
@@ -379,8 +441,10 @@ pub struct DubbingTranscriptsResponse {
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct UtteranceResponse {
     /// Start time in seconds.
+    #[serde(default)]
     pub start: f64,
     /// End time in seconds.
+    #[serde(default)]
     pub end: f64,
 }
 
@@ -390,6 +454,7 @@ pub struct SpeakerResponse {
     /// The speaker ID.
     pub speaker_id: String,
     /// Duration of the speaker's audio in seconds.
+    #[serde(default)]
     pub duration_secs: f64,
     /// Utterances spoken by this speaker.
     pub utterances: Option<Vec<UtteranceResponse>>,
@@ -399,10 +464,13 @@ pub struct SpeakerResponse {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpeakerAudioResponse {
     /// Base64-encoded audio data.
+    #[serde(default)]
     pub audio_base_64: String,
     /// MIME type of the audio (e.g. "audio/mpeg").
+    #[serde(default)]
     pub media_type: String,
     /// Duration of the audio in seconds.
+    #[serde(default)]
     pub duration_secs: f64,
 }
 
@@ -410,6 +478,7 @@ pub struct SpeakerAudioResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SpeakerCreatedResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
     /// ID of the created speaker.
     pub speaker_id: String,
@@ -419,6 +488,7 @@ pub struct SpeakerCreatedResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SpeakerUpdatedResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
 }
 
@@ -428,6 +498,7 @@ pub struct SimilarVoice {
     /// Voice ID.
     pub voice_id: String,
     /// Display name of the voice.
+    #[serde(default)]
     pub name: String,
     /// Category of the voice.
     pub category: VoiceCategory,
@@ -441,6 +512,7 @@ pub struct SimilarVoice {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SimilarVoicesForSpeakerResponse {
     /// List of similar voices.
+    #[serde(default)]
     pub voices: Vec<SimilarVoice>,
 }
 
@@ -449,6 +521,15 @@ pub struct SimilarVoicesForSpeakerResponse {
 // ===========================================================================
 
 /// Payload to create a new segment for a speaker.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::SegmentCreatePayload;
+///
+/// let segment = SegmentCreatePayload::new(1.5, 4.0).text("Hello").translation("es", "Hola");
+/// assert_eq!(segment.translations.unwrap()["es"], "Hola");
+/// ```
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SegmentCreatePayload {
     /// Start time of the segment in seconds.
@@ -463,17 +544,38 @@ pub struct SegmentCreatePayload {
     pub translations: Option<HashMap<String, String>>,
 }
 
+impl SegmentCreatePayload {
+    /// Creates a segment spanning `start_time` to `end_time` seconds.
+    pub const fn new(start_time: f64, end_time: f64) -> Self {
+        Self { start_time, end_time, text: None, translations: None }
+    }
+
+    /// Sets the source text.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Adds the translation for `language`.
+    pub fn translation(mut self, language: impl Into<String>, text: impl Into<String>) -> Self {
+        self.translations.get_or_insert_with(HashMap::new).insert(language.into(), text.into());
+        self
+    }
+}
+
 /// Response after creating a new segment.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SegmentCreateResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
     /// ID of the newly created segment.
+    #[serde(default)]
     pub new_segment: String,
 }
 
-/// Payload to update an existing segment.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+/// Payload to update an existing segment. Unset fields are left unchanged.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct SegmentUpdatePayload {
     /// New start time in seconds.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -486,10 +588,26 @@ pub struct SegmentUpdatePayload {
     pub text: Option<String>,
 }
 
+impl SegmentUpdatePayload {
+    /// Replaces the segment's text.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Moves the segment to span `start_time` to `end_time` seconds.
+    pub const fn timing(mut self, start_time: f64, end_time: f64) -> Self {
+        self.start_time = Some(start_time);
+        self.end_time = Some(end_time);
+        self
+    }
+}
+
 /// Response after updating a segment.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SegmentUpdateResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
 }
 
@@ -497,6 +615,7 @@ pub struct SegmentUpdateResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SegmentDeleteResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
 }
 
@@ -504,6 +623,7 @@ pub struct SegmentDeleteResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SegmentDubResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
 }
 
@@ -511,6 +631,7 @@ pub struct SegmentDubResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SegmentMigrationResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
 }
 
@@ -518,6 +639,7 @@ pub struct SegmentMigrationResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SegmentTranscriptionResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
 }
 
@@ -525,6 +647,7 @@ pub struct SegmentTranscriptionResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SegmentTranslationResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
 }
 
@@ -536,6 +659,7 @@ pub struct SegmentTranslationResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DubbingRenderResponse {
     /// Resource version when the render started.
+    #[serde(default)]
     pub version: i64,
     /// ID of the render job.
     pub render_id: String,
@@ -580,6 +704,7 @@ pub enum RenderType {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LanguageAddedResponse {
     /// Updated resource version.
+    #[serde(default)]
     pub version: i64,
 }
 
@@ -655,7 +780,7 @@ pub struct RenderDubbingRequest {
 /// Request body for creating a new speaker in a dubbing resource.
 ///
 /// Sent as JSON to `POST /v1/dubbing/resource/{dubbing_id}/speaker`.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct CreateSpeakerRequest {
     /// Display name for the speaker.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -678,8 +803,9 @@ pub struct CreateSpeakerRequest {
 ///
 /// This is a multipart request. File fields (`file`, `csv_file`, etc.) are
 /// binary uploads handled at the client layer. This struct covers the
-/// non-file fields typically sent as form parameters.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+/// non-file fields typically sent as form parameters, plus the files of a
+/// [`DubbingMode::Manual`] dub.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
 pub struct CreateDubbingRequest {
     /// Name of the dubbing project.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -723,16 +849,70 @@ pub struct CreateDubbingRequest {
     /// Whether to disable voice cloning.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_voice_cloning: Option<bool>,
-    /// Dubbing mode.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
-    /// Frames per second for CSV-based dubbing.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Whether the dub is automatic or built from a CSV transcript.
+    ///
+    /// Sent as the `mode` form field and, for manual dubs, the CSV and
+    /// audio files; not part of the JSON form.
+    #[serde(skip)]
+    pub mode: DubbingMode,
+}
+
+/// How a dubbing project is created.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum DubbingMode {
+    /// Transcribe, translate and voice the source media automatically.
+    #[default]
+    Automatic,
+    /// Dub from a CSV of timed, translated segments and separate audio
+    /// tracks. Only supported for dubbing studio projects.
+    Manual(Box<ManualDubInputs>),
+}
+
+/// Files for a [`DubbingMode::Manual`] dub.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManualDubInputs {
+    /// CSV with one row per segment: speaker, start and end time,
+    /// transcription and translation.
+    pub csv_file: DubbingFile,
+    /// Speech track of the source, which the CSV timings refer to.
+    pub foreground_audio_file: DubbingFile,
+    /// Music and effects track, mixed under the dubbed speech.
+    pub background_audio_file: Option<DubbingFile>,
+    /// Frame rate used to read frame-based CSV timecodes.
     pub csv_fps: Option<f64>,
 }
 
-/// Payload to update speaker metadata.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+impl ManualDubInputs {
+    /// Creates manual inputs from a CSV transcript and the speech track.
+    pub const fn new(csv_file: DubbingFile, foreground_audio_file: DubbingFile) -> Self {
+        Self { csv_file, foreground_audio_file, background_audio_file: None, csv_fps: None }
+    }
+}
+
+/// A file uploaded with a dubbing request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DubbingFile {
+    /// File name sent with the upload.
+    pub file_name: String,
+    /// MIME type (e.g. `"text/csv"`).
+    pub content_type: String,
+    /// Raw file contents.
+    pub data: Bytes,
+}
+
+impl DubbingFile {
+    /// Creates a file from its name, MIME type, and contents.
+    pub fn new(
+        file_name: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<Bytes>,
+    ) -> Self {
+        Self { file_name: file_name.into(), content_type: content_type.into(), data: data.into() }
+    }
+}
+
+/// Payload to update speaker metadata. Unset fields are left unchanged.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct UpdateSpeakerRequest {
     /// New display name for the speaker.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -908,7 +1088,7 @@ mod tests {
     #[test]
     fn dubbing_transcript_utterance_defaults() {
         // All fields have defaults, so an empty object should deserialize.
-        let json = r#"{}"#;
+        let json = r"{}";
         let utt: DubbingTranscriptUtterance = serde_json::from_str(json).unwrap();
         assert_eq!(utt.text, "");
         assert_eq!(utt.speaker_id, "");
@@ -1106,8 +1286,7 @@ mod tests {
             use_profanity_filter: None,
             dubbing_studio: None,
             disable_voice_cloning: None,
-            mode: None,
-            csv_fps: None,
+            mode: DubbingMode::Automatic,
         };
         let json = serde_json::to_string(&req).unwrap();
         assert!(json.contains("\"name\":\"Test dub\""));
@@ -1209,6 +1388,34 @@ mod tests {
         assert_eq!(seg.dubs["es"].text.as_deref(), Some("Hola mundo"));
     }
 
+    #[test]
+    fn render_deserializes_type() {
+        let render: Render = serde_json::from_str(
+            r#"{"id": "r1", "version": 3, "language": "es", "type": "tracks_zip",
+                "media_ref": null, "status": "complete"}"#,
+        )
+        .unwrap();
+        assert_eq!(render.render_type, Some(RenderType::TracksZip));
+        assert_eq!(render.status, RenderStatus::Complete);
+    }
+
+    #[test]
+    fn segment_payload_builders() {
+        let create = SegmentCreatePayload::new(1.0, 2.0).text("Hi").translation("fr", "Salut");
+        assert_eq!(
+            serde_json::to_value(&create).unwrap(),
+            serde_json::json!({
+                "start_time": 1.0, "end_time": 2.0, "text": "Hi", "translations": {"fr": "Salut"}
+            })
+        );
+
+        let update = SegmentUpdatePayload::default().timing(0.5, 1.5);
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            serde_json::json!({"start_time": 0.5, "end_time": 1.5})
+        );
+    }
+
     // -- RenderType ---------------------------------------------------------
 
     #[test]
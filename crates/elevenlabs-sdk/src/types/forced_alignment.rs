@@ -1,35 +1,66 @@
 //! Types for the ElevenLabs Forced Alignment endpoint.
 //!
 //! Covers `POST /v1/forced-alignment` — align audio with text to get
-//! character-level and word-level timing information.
+//! character-level and word-level timing information, and converts the
+//! word timings into SRT, WebVTT, or ASS captions.
 
 use serde::{Deserialize, Serialize};
 
+// ---------------------------------------------------------------------------
+// Request
+// ---------------------------------------------------------------------------
+
+/// Form fields sent with the audio to `POST /v1/forced-alignment`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForcedAlignmentRequest {
+    /// The transcript to align against the audio.
+    pub text: String,
+    /// Have the server stream the file to disk and process it in chunks,
+    /// for inputs too large to hold in memory.
+    pub enabled_spooled_file: bool,
+}
+
+impl ForcedAlignmentRequest {
+    /// Creates a request aligning `text`, without spooling.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), enabled_spooled_file: false }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Response
 // ---------------------------------------------------------------------------
 
 /// A single character with timing information from the aligner.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct ForcedAlignmentCharacter {
     /// The character that was transcribed.
+    #[serde(default)]
     pub text: String,
     /// Start time of the character in seconds.
+    #[serde(default)]
     pub start: f64,
     /// End time of the character in seconds.
+    #[serde(default)]
     pub end: f64,
 }
 
 /// A single word with timing information from the aligner.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct ForcedAlignmentWord {
     /// The word that was transcribed.
+    #[serde(default)]
     pub text: String,
     /// Start time of the word in seconds.
+    #[serde(default)]
     pub start: f64,
     /// End time of the word in seconds.
+    #[serde(default)]
     pub end: f64,
     /// Average alignment loss/confidence score for this word.
+    #[serde(default)]
     pub loss: f64,
 }
 
@@ -38,15 +69,227 @@ pub struct ForcedAlignmentWord {
 /// Contains character-level and word-level timing information, plus an
 /// overall loss/confidence score.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct ForcedAlignmentResponse {
     /// Character-level timing information.
+    #[serde(default)]
     pub characters: Vec<ForcedAlignmentCharacter>,
     /// Word-level timing information.
+    #[serde(default)]
     pub words: Vec<ForcedAlignmentWord>,
     /// Average alignment loss across all characters (lower = better fit).
+    #[serde(default)]
     pub loss: f64,
 }
 
+// ---------------------------------------------------------------------------
+// Caption export
+// ---------------------------------------------------------------------------
+
+/// Options for converting a [`ForcedAlignmentResponse`] into captions.
+///
+/// Words are packed greedily into lines of at most `max_line_length`
+/// characters, and lines into cues of at most `max_lines_per_cue` lines.
+/// A single word longer than `max_line_length` gets a line of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptionOptions {
+    /// Maximum number of characters per caption line (default: 42).
+    pub max_line_length: usize,
+    /// Maximum number of lines per caption cue (default: 2).
+    pub max_lines_per_cue: usize,
+    /// Speaker label attached to every cue, if any.
+    ///
+    /// Rendered as a `Name: ` prefix in SRT, a `<v Name>` voice tag in
+    /// WebVTT, and the `Name` field in ASS.
+    pub speaker: Option<String>,
+}
+
+impl Default for CaptionOptions {
+    fn default() -> Self {
+        Self { max_line_length: 42, max_lines_per_cue: 2, speaker: None }
+    }
+}
+
+/// A single caption cue built from aligned words.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct CaptionCue {
+    /// Start time in seconds.
+    pub(super) start: f64,
+    /// End time in seconds.
+    pub(super) end: f64,
+    /// Caption lines.
+    pub(super) lines: Vec<String>,
+}
+
+impl ForcedAlignmentResponse {
+    /// Renders the word timings as SubRip (`.srt`) captions.
+    pub fn to_srt(&self, options: &CaptionOptions) -> String {
+        let mut out = String::new();
+        for (index, cue) in self.caption_cues(options).iter().enumerate() {
+            if index > 0 {
+                out.push('\n');
+            }
+            out.push_str(&format!(
+                "{}\n{} --> {}\n",
+                index + 1,
+                format_timestamp(cue.start, ',', 3),
+                format_timestamp(cue.end, ',', 3)
+            ));
+            let text = cue.lines.join("\n");
+            match &options.speaker {
+                Some(speaker) => out.push_str(&format!("{speaker}: {text}\n")),
+                None => out.push_str(&format!("{text}\n")),
+            }
+        }
+        out
+    }
+
+    /// Renders the word timings as WebVTT (`.vtt`) captions.
+    pub fn to_vtt(&self, options: &CaptionOptions) -> String {
+        let mut out = String::from("WEBVTT\n");
+        for cue in self.caption_cues(options) {
+            out.push_str(&format!(
+                "\n{} --> {}\n",
+                format_timestamp(cue.start, '.', 3),
+                format_timestamp(cue.end, '.', 3)
+            ));
+            let text = escape_vtt(&cue.lines.join("\n"));
+            match &options.speaker {
+                Some(speaker) => out.push_str(&format!("<v {}>{text}\n", escape_vtt(speaker))),
+                None => out.push_str(&format!("{text}\n")),
+            }
+        }
+        out
+    }
+
+    /// Renders the word timings as Advanced SubStation Alpha (`.ass`)
+    /// subtitles with a single default style.
+    pub fn to_ass(&self, options: &CaptionOptions) -> String {
+        let mut out = String::from(
+            "[Script Info]\n\
+             ScriptType: v4.00+\n\
+             PlayResX: 384\n\
+             PlayResY: 288\n\
+             \n\
+             [V4+ Styles]\n\
+             Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, \
+             BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, \
+             BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\n\
+             Style: Default,Arial,16,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,\
+             100,0,0,1,1,0,2,10,10,10,1\n\
+             \n\
+             [Events]\n\
+             Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n",
+        );
+        let speaker = escape_ass(options.speaker.as_deref().unwrap_or_default());
+        for cue in self.caption_cues(options) {
+            let lines: Vec<String> = cue.lines.iter().map(|line| escape_ass(line)).collect();
+            out.push_str(&format!(
+                "Dialogue: 0,{},{},Default,{speaker},0,0,0,,{}\n",
+                format_ass_timestamp(cue.start),
+                format_ass_timestamp(cue.end),
+                lines.join("\\N")
+            ));
+        }
+        out
+    }
+
+    /// Groups aligned words into caption cues.
+    fn caption_cues(&self, options: &CaptionOptions) -> Vec<CaptionCue> {
+        caption_cues(
+            self.words.iter().map(|word| (word.text.as_str(), word.start, word.end)),
+            options,
+        )
+    }
+}
+
+/// Packs `(text, start, end)` words into caption cues.
+pub(super) fn caption_cues<'w>(
+    words: impl IntoIterator<Item = (&'w str, f64, f64)>,
+    options: &CaptionOptions,
+) -> Vec<CaptionCue> {
+    let max_line_length = options.max_line_length.max(1);
+    let max_lines = options.max_lines_per_cue.max(1);
+
+    let mut cues = Vec::new();
+    let mut current: Option<CaptionCue> = None;
+
+    for (text, start, end) in words {
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        if let Some(cue) = current.as_mut() {
+            let fits_line = cue.lines.last().is_some_and(|line| {
+                line.chars().count() + 1 + text.chars().count() <= max_line_length
+            });
+            if fits_line {
+                if let Some(line) = cue.lines.last_mut() {
+                    line.push(' ');
+                    line.push_str(text);
+                }
+                cue.end = end;
+                continue;
+            }
+            if cue.lines.len() < max_lines {
+                cue.lines.push(text.to_owned());
+                cue.end = end;
+                continue;
+            }
+            cues.extend(current.take());
+        }
+
+        current = Some(CaptionCue { start, end, lines: vec![text.to_owned()] });
+    }
+
+    cues.extend(current);
+    cues
+}
+
+/// Formats `secs` as `HH:MM:SS<sep>fff` with `digits` fractional digits.
+pub(super) fn format_timestamp(secs: f64, separator: char, digits: u32) -> String {
+    let scale = 10_u64.pow(digits);
+    let total = (secs.max(0.0) * scale as f64).round() as u64;
+    let fraction = total % scale;
+    let whole = total / scale;
+    format!(
+        "{:02}:{:02}:{:02}{separator}{fraction:0width$}",
+        whole / 3600,
+        (whole / 60) % 60,
+        whole % 60,
+        width = digits as usize
+    )
+}
+
+/// Escapes WebVTT markup in cue text. Escaping `>` also breaks up any
+/// `-->`, which would otherwise end the cue.
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Escapes ASS override blocks and tags in event text. A backslash is
+/// followed by a word joiner so that it cannot start a tag such as `\N`.
+fn escape_ass(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '{' => out.push_str("\\{"),
+            '}' => out.push_str("\\}"),
+            '\\' => out.push_str("\\\u{2060}"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Formats `secs` as an ASS timestamp (`H:MM:SS.cc`).
+fn format_ass_timestamp(secs: f64) -> String {
+    let formatted = format_timestamp(secs, '.', 2);
+    // ASS uses a single-digit hour field.
+    formatted.strip_prefix('0').unwrap_or(&formatted).to_owned()
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -94,4 +337,93 @@ mod tests {
         assert_eq!(resp.words.len(), 1);
         assert!((resp.loss - 0.08).abs() < f64::EPSILON);
     }
+
+    fn sample() -> ForcedAlignmentResponse {
+        serde_json::from_str(
+            r#"{
+                "characters": [],
+                "words": [
+                    {"text": "Hello", "start": 0.0, "end": 0.5, "loss": 0.1},
+                    {"text": "there", "start": 0.5, "end": 1.0, "loss": 0.1},
+                    {"text": "general", "start": 1.2, "end": 1.8, "loss": 0.1},
+                    {"text": "Kenobi", "start": 1.8, "end": 3661.25, "loss": 0.1}
+                ],
+                "loss": 0.1
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn format_timestamp_pads_fields() {
+        assert_eq!(format_timestamp(3661.25, ',', 3), "01:01:01,250");
+        assert_eq!(format_timestamp(-1.0, '.', 3), "00:00:00.000");
+        assert_eq!(format_ass_timestamp(62.456), "0:01:02.46");
+    }
+
+    #[test]
+    fn to_srt_wraps_lines_and_cues() {
+        let options = CaptionOptions { max_line_length: 11, max_lines_per_cue: 1, speaker: None };
+        let srt = sample().to_srt(&options);
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:01,000\nHello there\n\n\
+             2\n00:00:01,200 --> 00:00:01,800\ngeneral\n\n\
+             3\n00:00:01,800 --> 01:01:01,250\nKenobi\n"
+        );
+    }
+
+    #[test]
+    fn to_vtt_includes_header_and_speaker() {
+        let options = CaptionOptions { speaker: Some("Narrator".into()), ..Default::default() };
+        let vtt = sample().to_vtt(&options);
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n00:00:00.000 --> 01:01:01.250\n<v Narrator>Hello there general Kenobi\n"
+        );
+    }
+
+    #[test]
+    fn captions_escape_markup_in_words_and_speaker() {
+        let resp: ForcedAlignmentResponse = serde_json::from_str(
+            r#"{
+                "characters": [],
+                "words": [
+                    {"text": "<b>A&B</b>", "start": 0.0, "end": 0.5, "loss": 0.1},
+                    {"text": "-->", "start": 0.5, "end": 1.0, "loss": 0.1},
+                    {"text": "{\\i1}C:\\N", "start": 1.0, "end": 1.5, "loss": 0.1}
+                ],
+                "loss": 0.1
+            }"#,
+        )
+        .unwrap();
+        let options = CaptionOptions { speaker: Some("R&D <1>".into()), ..Default::default() };
+
+        let vtt = resp.to_vtt(&options);
+        assert!(
+            vtt.ends_with("<v R&amp;D &lt;1&gt;>&lt;b&gt;A&amp;B&lt;/b&gt; --&gt; {\\i1}C:\\N\n")
+        );
+
+        let options = CaptionOptions { speaker: Some("{Narrator}".into()), ..options };
+        let ass = resp.to_ass(&options);
+        assert!(ass.ends_with(
+            "Default,\\{Narrator\\},0,0,0,,<b>A&B</b> --> \\{\\\u{2060}i1\\}C:\\\u{2060}N\n"
+        ));
+    }
+
+    #[test]
+    fn to_ass_emits_dialogue_events() {
+        let options = CaptionOptions {
+            max_line_length: 11,
+            max_lines_per_cue: 2,
+            speaker: Some("Obi-Wan".into()),
+        };
+        let ass = sample().to_ass(&options);
+        assert!(ass.starts_with("[Script Info]\n"));
+        assert!(ass.contains("[Events]\n"));
+        assert!(ass.contains(
+            "Dialogue: 0,0:00:00.00,0:00:01.80,Default,Obi-Wan,0,0,0,,Hello there\\Ngeneral\n"
+        ));
+        assert!(ass.ends_with("Dialogue: 0,0:00:01.80,1:01:01.25,Default,Obi-Wan,0,0,0,,Kenobi\n"));
+    }
 }
@@ -9,6 +9,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::Timestamp;
+
 // ---------------------------------------------------------------------------
 // Enums
 // ---------------------------------------------------------------------------
@@ -56,18 +58,25 @@ pub enum HistoryItemSource {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FeedbackResponse {
     /// Whether the user liked the generated item.
+    #[serde(default)]
     pub thumbs_up: bool,
     /// Free-text feedback from the user.
+    #[serde(default)]
     pub feedback: String,
     /// Whether the user flagged emotional issues.
+    #[serde(default)]
     pub emotions: bool,
     /// Whether the user flagged inaccurate cloning.
+    #[serde(default)]
     pub inaccurate_clone: bool,
     /// Whether the user flagged audio glitches.
+    #[serde(default)]
     pub glitches: bool,
     /// Whether the user commented on audio quality.
+    #[serde(default)]
     pub audio_quality: bool,
     /// Whether the user provided other feedback.
+    #[serde(default)]
     pub other: bool,
     /// Review status (defaults to `"not_reviewed"`).
     #[serde(default)]
@@ -79,13 +88,16 @@ pub struct FeedbackResponse {
 // ---------------------------------------------------------------------------
 
 /// Character-level alignment data for a history item.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HistoryAlignment {
     /// The individual characters.
+    #[serde(default)]
     pub characters: Vec<String>,
     /// Start time of each character in seconds.
+    #[serde(default)]
     pub character_start_times_seconds: Vec<f64>,
     /// End time of each character in seconds.
+    #[serde(default)]
     pub character_end_times_seconds: Vec<f64>,
 }
 
@@ -93,11 +105,26 @@ pub struct HistoryAlignment {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HistoryAlignments {
     /// Raw alignment data.
+    #[serde(default)]
     pub alignment: HistoryAlignment,
     /// Normalized alignment data.
+    #[serde(default)]
     pub normalized_alignment: HistoryAlignment,
 }
 
+/// A dialogue input line as returned by the API in history items.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DialogueInputResponse {
+    /// The text of the dialogue input line.
+    #[serde(default)]
+    pub text: String,
+    /// The ID of the voice used for this line.
+    pub voice_id: String,
+    /// The name of the voice used for this line.
+    #[serde(default)]
+    pub voice_name: String,
+}
+
 // ---------------------------------------------------------------------------
 // Speech History Item
 // ---------------------------------------------------------------------------
@@ -107,6 +134,7 @@ pub struct HistoryAlignments {
 /// Contains metadata, settings, and optional alignment data for a
 /// previously generated audio clip.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct SpeechHistoryItem {
     /// Unique identifier for this history item.
     pub history_item_id: String,
@@ -129,12 +157,16 @@ pub struct SpeechHistoryItem {
     #[serde(default)]
     pub text: Option<String>,
     /// Unix timestamp of when the item was created.
-    pub date_unix: i64,
+    #[serde(default)]
+    pub date_unix: Timestamp,
     /// Character count before this generation.
+    #[serde(default)]
     pub character_count_change_from: i64,
     /// Character count after this generation.
+    #[serde(default)]
     pub character_count_change_to: i64,
     /// MIME type of the generated audio (e.g. `"audio/mpeg"`).
+    #[serde(default)]
     pub content_type: String,
     /// Current state of the history item.
     pub state: HistoryItemState,
@@ -155,7 +187,7 @@ pub struct SpeechHistoryItem {
     pub alignments: Option<HistoryAlignments>,
     /// Dialogue inputs if this was a multi-voice dialogue generation.
     #[serde(default)]
-    pub dialogue: Option<Vec<super::text_to_dialogue::DialogueInputResponse>>,
+    pub dialogue: Option<Vec<DialogueInputResponse>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -164,26 +196,74 @@ pub struct SpeechHistoryItem {
 
 /// Response from `GET /v1/history`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct GetSpeechHistoryResponse {
     /// Speech history items on this page.
+    #[serde(default)]
     pub history: Vec<SpeechHistoryItem>,
     /// ID of the last history item on this page (for pagination).
     #[serde(default)]
     pub last_history_item_id: Option<String>,
     /// Whether more items are available.
+    #[serde(default)]
     pub has_more: bool,
     /// Unix timestamp of the last scanned item.
     #[serde(default)]
-    pub scanned_until: Option<i64>,
+    pub scanned_until: Option<Timestamp>,
 }
 
 /// Response from `DELETE /v1/history/{history_item_id}`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeleteHistoryItemResponse {
     /// Status string, typically `"ok"`.
+    #[serde(default)]
     pub status: String,
 }
 
+/// A page of history items with display names resolved, returned by
+/// [`HistoryService::list_enriched`](crate::services::HistoryService::list_enriched).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnrichedHistoryPage {
+    /// History items on this page.
+    pub items: Vec<EnrichedHistoryItem>,
+    /// ID of the last history item on this page (for pagination).
+    pub last_history_item_id: Option<String>,
+    /// Whether more items are available.
+    pub has_more: bool,
+}
+
+/// A history item with the names of its voice and model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnrichedHistoryItem {
+    /// The history item.
+    pub item: SpeechHistoryItem,
+    /// Current name of the voice, or the name recorded on the item if the
+    /// voice is no longer in the account.
+    pub voice_name: Option<String>,
+    /// Display name of the model, e.g. `Eleven Multilingual v2`.
+    pub model_name: Option<String>,
+}
+
+/// A history item together with its audio, returned by
+/// [`HistoryService::get_item_with_audio`](crate::services::HistoryService::get_item_with_audio).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryItemWithAudio {
+    /// Metadata of the history item.
+    pub item: SpeechHistoryItem,
+    /// Raw audio bytes.
+    pub audio: bytes::Bytes,
+    /// MIME type of `audio` from the `Content-Type` header, falling back to
+    /// the item's `content_type`.
+    pub content_type: String,
+}
+
+impl HistoryItemWithAudio {
+    /// Returns the text the audio was generated from, if recorded.
+    pub fn transcript(&self) -> Option<&str> {
+        self.item.text.as_deref()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Request
 // ---------------------------------------------------------------------------
@@ -295,11 +375,13 @@ mod tests {
                 }
             ],
             "last_history_item_id": "item1",
-            "has_more": false
+            "has_more": false,
+            "scanned_until": 1714650306
         }"#;
         let resp: GetSpeechHistoryResponse = serde_json::from_str(json).unwrap();
         assert_eq!(resp.history.len(), 1);
         assert!(!resp.has_more);
+        assert_eq!(resp.scanned_until, Some(Timestamp::from_unix_secs(1_714_650_306)));
     }
 
     #[test]
@@ -329,4 +411,16 @@ mod tests {
         let json = serde_json::to_string(&req).unwrap();
         assert!(!json.contains("output_format"));
     }
+
+    #[test]
+    fn dialogue_input_response_deserialize() {
+        let json = r#"{
+            "text": "Hello!",
+            "voice_id": "v1",
+            "voice_name": "Rachel"
+        }"#;
+        let r: DialogueInputResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(r.text, "Hello!");
+        assert_eq!(r.voice_name, "Rachel");
+    }
 }
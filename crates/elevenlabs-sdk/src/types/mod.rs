@@ -4,15 +4,40 @@
 //! structures that appear in multiple API endpoints. Types here are
 //! intentionally kept close to the wire format defined by the
 //! [ElevenLabs OpenAPI specification](https://elevenlabs.io/docs).
+//!
+//! # Missing fields
+//!
+//! The API regularly drops or renames fields that are not central to a
+//! response, so response types only require what identifies a resource:
+//!
+//! - Identifiers (`id` and `*_id` fields) are required.
+//! - Strings, numbers, booleans, collections, and nested objects whose own fields are all optional
+//!   take `#[serde(default)]`; defaults that would be misleading (such as a zero cost multiplier)
+//!   use a named default function.
+//! - Enums and nested objects without a sensible default are `Option` when they are not essential.
+//!
+//! New response fields should follow the same rules. The `minimal_payloads`
+//! integration test checks the main response types against payloads that
+//! carry only their required fields.
+//!
+//! # Feature flags
+//!
+//! Types belonging to a feature-gated service (`tts`, `convai`, `studio`,
+//! `dubbing`, `music`) are only compiled with that feature.
 
+#[cfg(feature = "convai")]
+mod agent_tools;
+#[cfg(feature = "convai")]
 mod agents;
 mod audio_isolation;
 mod audio_native;
 mod common;
+#[cfg(feature = "dubbing")]
 mod dubbing;
 mod forced_alignment;
 mod history;
 mod models;
+#[cfg(feature = "music")]
 mod music;
 mod pronunciation;
 mod pvc_voices;
@@ -21,23 +46,32 @@ mod single_use_token;
 mod sound_generation;
 mod speech_to_speech;
 mod speech_to_text;
+#[cfg(feature = "studio")]
 mod studio;
+#[cfg(feature = "tts")]
 mod text_to_dialogue;
+#[cfg(feature = "tts")]
 mod text_to_speech;
 mod text_to_voice;
+mod usage;
 mod user;
 mod voice_generation;
 mod voices;
 mod workspace;
 
+#[cfg(feature = "convai")]
+pub use agent_tools::*;
+#[cfg(feature = "convai")]
 pub use agents::*;
 pub use audio_isolation::*;
 pub use audio_native::*;
 pub use common::*;
+#[cfg(feature = "dubbing")]
 pub use dubbing::*;
 pub use forced_alignment::*;
 pub use history::*;
 pub use models::*;
+#[cfg(feature = "music")]
 pub use music::*;
 pub use pronunciation::*;
 pub use pvc_voices::*;
@@ -46,10 +80,14 @@ pub use single_use_token::*;
 pub use sound_generation::*;
 pub use speech_to_speech::*;
 pub use speech_to_text::*;
+#[cfg(feature = "studio")]
 pub use studio::*;
+#[cfg(feature = "tts")]
 pub use text_to_dialogue::*;
+#[cfg(feature = "tts")]
 pub use text_to_speech::*;
 pub use text_to_voice::*;
+pub use usage::*;
 pub use user::*;
 pub use voice_generation::*;
 pub use voices::*;
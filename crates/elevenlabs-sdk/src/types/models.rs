@@ -2,10 +2,14 @@
 //!
 //! Covers `GET /v1/models` which returns a list of available models.
 //! The individual [`Model`](super::common::Model) type is defined in
-//! [`common`](super::common).
+//! [`common`](super::common). [`ModelCriteria`] picks a model from the list.
+
+use std::cmp::Ordering;
 
 use serde::{Deserialize, Serialize};
 
+use super::common::Model;
+
 // ---------------------------------------------------------------------------
 // Response
 // ---------------------------------------------------------------------------
@@ -48,6 +52,163 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GetModelsResponse(pub Vec<super::common::Model>);
 
+impl GetModelsResponse {
+    /// Returns the model that best matches `criteria`, or `None` if no
+    /// model meets its requirements.
+    pub fn best_for(&self, criteria: &ModelCriteria) -> Option<&Model> {
+        self.0.iter().filter(|m| criteria.accepts(m)).min_by(|a, b| criteria.rank(a, b))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Selection
+// ---------------------------------------------------------------------------
+
+/// What to optimise for when several models meet a [`ModelCriteria`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModelPriority {
+    /// Prefer the Flash and Turbo families, then the cheapest.
+    LowLatency,
+    /// Prefer the standard (non-low-latency) families, then the most
+    /// expensive, which tracks the newest and most expressive models.
+    #[default]
+    Quality,
+    /// Prefer the lowest character cost, then low latency.
+    Cost,
+}
+
+/// Requirements and preferences for picking a model with
+/// [`ModelsService::best_for`](crate::services::ModelsService::best_for).
+///
+/// The API does not report latency or quality directly; they are judged from
+/// the model family (see [`Model::is_low_latency`]) and the character cost.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{ModelCriteria, ModelPriority};
+///
+/// let criteria = ModelCriteria::text_to_speech()
+///     .language("ja")
+///     .priority(ModelPriority::LowLatency)
+///     .text_length(4_000);
+/// assert_eq!(criteria.language.as_deref(), Some("ja"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelCriteria {
+    /// Require text-to-speech support.
+    pub text_to_speech: bool,
+    /// Require voice conversion (speech-to-speech) support.
+    pub voice_conversion: bool,
+    /// Require support for this language ID (e.g. `"en"`).
+    pub language: Option<String>,
+    /// Require support for the `style` voice setting.
+    pub style: bool,
+    /// Require support for speaker boost.
+    pub speaker_boost: bool,
+    /// Require accepting this many characters in one request.
+    pub text_length: Option<i64>,
+    /// Allow models that need alpha access.
+    pub allow_alpha: bool,
+    /// How to choose between models that meet the requirements.
+    pub priority: ModelPriority,
+}
+
+impl ModelCriteria {
+    /// Criteria requiring text-to-speech support, preferring quality.
+    pub const fn text_to_speech() -> Self {
+        Self {
+            text_to_speech: true,
+            voice_conversion: false,
+            language: None,
+            style: false,
+            speaker_boost: false,
+            text_length: None,
+            allow_alpha: false,
+            priority: ModelPriority::Quality,
+        }
+    }
+
+    /// Criteria requiring voice conversion support, preferring quality.
+    pub fn voice_conversion() -> Self {
+        Self { text_to_speech: false, voice_conversion: true, ..Self::text_to_speech() }
+    }
+
+    /// Requires support for `language_id`.
+    pub fn language(mut self, language_id: impl Into<String>) -> Self {
+        self.language = Some(language_id.into());
+        self
+    }
+
+    /// Requires support for the `style` voice setting.
+    pub const fn style(mut self, required: bool) -> Self {
+        self.style = required;
+        self
+    }
+
+    /// Requires support for speaker boost.
+    pub const fn speaker_boost(mut self, required: bool) -> Self {
+        self.speaker_boost = required;
+        self
+    }
+
+    /// Requires accepting `characters` characters in one request.
+    pub const fn text_length(mut self, characters: i64) -> Self {
+        self.text_length = Some(characters);
+        self
+    }
+
+    /// Allows models that need alpha access.
+    pub const fn allow_alpha(mut self, allow: bool) -> Self {
+        self.allow_alpha = allow;
+        self
+    }
+
+    /// Sets what to optimise for.
+    pub const fn priority(mut self, priority: ModelPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Whether `model` meets every requirement.
+    pub fn accepts(&self, model: &Model) -> bool {
+        (!self.text_to_speech || model.can_do_text_to_speech) &&
+            (!self.voice_conversion || model.can_do_voice_conversion) &&
+            (!self.style || model.can_use_style) &&
+            (!self.speaker_boost || model.can_use_speaker_boost) &&
+            (self.allow_alpha || !model.requires_alpha_access) &&
+            self.language.as_deref().is_none_or(|lang| model.supports_language(lang)) &&
+            self.text_length.is_none_or(|len| {
+                model.maximum_text_length_per_request <= 0 ||
+                    len <= model.maximum_text_length_per_request
+            })
+    }
+
+    /// Orders two acceptable models, best first.
+    fn rank(&self, a: &Model, b: &Model) -> Ordering {
+        let cost = |m: &Model| m.model_rates.character_cost_multiplier;
+        // `false` sorts before `true`, so compare "is not low latency".
+        let slow = |m: &Model| !m.is_low_latency();
+        match self.priority {
+            ModelPriority::LowLatency => {
+                slow(a).cmp(&slow(b)).then_with(|| cost(a).total_cmp(&cost(b)))
+            }
+            ModelPriority::Quality => {
+                slow(b).cmp(&slow(a)).then_with(|| cost(b).total_cmp(&cost(a)))
+            }
+            ModelPriority::Cost => cost(a).total_cmp(&cost(b)).then_with(|| slow(a).cmp(&slow(b))),
+        }
+        .then_with(|| b.languages.len().cmp(&a.languages.len()))
+        .then_with(|| a.model_id.cmp(&b.model_id))
+    }
+}
+
+impl Default for ModelCriteria {
+    fn default() -> Self {
+        Self::text_to_speech()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -85,6 +246,74 @@ mod tests {
         assert_eq!(models.0[0].model_id, "eleven_multilingual_v2");
     }
 
+    fn model(id: &str, cost: f64, languages: &[&str]) -> Model {
+        serde_json::from_value(serde_json::json!({
+            "model_id": id,
+            "can_do_text_to_speech": true,
+            "can_use_style": !id.contains("flash"),
+            "maximum_text_length_per_request": if id.contains("flash") { 40_000 } else { 10_000 },
+            "languages": languages
+                .iter()
+                .map(|l| serde_json::json!({"language_id": l}))
+                .collect::<Vec<_>>(),
+            "model_rates": {"character_cost_multiplier": cost}
+        }))
+        .unwrap()
+    }
+
+    fn catalogue() -> GetModelsResponse {
+        GetModelsResponse(vec![
+            model("eleven_multilingual_v2", 1.0, &["en", "ja", "de"]),
+            model("eleven_flash_v2_5", 0.5, &["en", "ja"]),
+            model("eleven_flash_v2", 0.5, &["en"]),
+            model("eleven_english_sts_v2", 1.0, &["en"]),
+        ])
+    }
+
+    #[test]
+    fn best_for_follows_priority() {
+        let models = catalogue();
+        let pick =
+            |criteria: ModelCriteria| models.best_for(&criteria).map(|m| m.model_id.as_str());
+
+        assert_eq!(pick(ModelCriteria::text_to_speech()), Some("eleven_multilingual_v2"));
+        assert_eq!(
+            pick(ModelCriteria::text_to_speech().priority(ModelPriority::LowLatency)),
+            Some("eleven_flash_v2_5")
+        );
+        assert_eq!(
+            pick(ModelCriteria::text_to_speech().priority(ModelPriority::Cost).language("EN")),
+            Some("eleven_flash_v2_5")
+        );
+    }
+
+    #[test]
+    fn best_for_applies_requirements() {
+        let models = catalogue();
+        let pick =
+            |criteria: ModelCriteria| models.best_for(&criteria).map(|m| m.model_id.as_str());
+
+        assert_eq!(
+            pick(ModelCriteria::text_to_speech().priority(ModelPriority::LowLatency).style(true)),
+            Some("eleven_multilingual_v2")
+        );
+        assert_eq!(
+            pick(ModelCriteria::text_to_speech().language("ja").text_length(20_000)),
+            Some("eleven_flash_v2_5")
+        );
+        assert_eq!(pick(ModelCriteria::text_to_speech().language("fr")), None);
+        assert_eq!(pick(ModelCriteria::voice_conversion()), None);
+    }
+
+    #[test]
+    fn model_helpers() {
+        let m = model("eleven_turbo_v2_5", 0.5, &["en"]);
+        assert!(m.is_low_latency());
+        assert!(m.supports_language("EN"));
+        assert!(!m.supports_language("fr"));
+        assert_eq!(m.max_characters_per_request(true), 0);
+    }
+
     #[test]
     fn get_models_response_empty() {
         let json = "[]";
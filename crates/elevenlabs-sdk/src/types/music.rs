@@ -21,11 +21,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// Used within [`SectionSource`] to specify which portion of a source song
 /// to extract or exclude during inpainting.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TimeRange {
     /// Start of the range in milliseconds.
+    #[serde(default)]
     pub start_ms: i64,
     /// End of the range in milliseconds.
+    #[serde(default)]
     pub end_ms: i64,
 }
 
@@ -38,6 +40,7 @@ pub struct SectionSource {
     /// The ID of the source song (found in response headers when generating).
     pub song_id: String,
     /// The time range to extract from the source song.
+    #[serde(default)]
     pub range: TimeRange,
     /// Ranges within `range` to exclude.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -51,14 +54,19 @@ pub struct SectionSource {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SongSection {
     /// The name of the section (e.g. "Verse 1", "Chorus"). 1–100 characters.
+    #[serde(default)]
     pub section_name: String,
     /// Styles that *should* be present in this section.
+    #[serde(default)]
     pub positive_local_styles: Vec<String>,
     /// Styles that should *not* be present in this section.
+    #[serde(default)]
     pub negative_local_styles: Vec<String>,
     /// Duration of this section in milliseconds (3 000–120 000).
+    #[serde(default)]
     pub duration_ms: i64,
     /// Lyrics for this section (max 200 chars per line, max 30 lines).
+    #[serde(default)]
     pub lines: Vec<String>,
     /// Optional source for inpainting (enterprise only).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,13 +97,16 @@ pub struct SongSection {
 /// };
 /// assert_eq!(plan.sections.len(), 1);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MusicPrompt {
     /// Styles that should be present throughout the entire song.
+    #[serde(default)]
     pub positive_global_styles: Vec<String>,
     /// Styles that should *not* be present in the song.
+    #[serde(default)]
     pub negative_global_styles: Vec<String>,
     /// The ordered sections of the song.
+    #[serde(default)]
     pub sections: Vec<SongSection>,
 }
 
@@ -123,23 +134,28 @@ pub enum StemVariation {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WordTimestamp {
     /// The word.
+    #[serde(default)]
     pub word: String,
     /// Start time in milliseconds.
+    #[serde(default)]
     pub start_ms: i64,
     /// End time in milliseconds.
+    #[serde(default)]
     pub end_ms: i64,
 }
 
 /// Metadata about a generated song.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SongMetadata {
     /// Title of the song.
     pub title: Option<String>,
     /// Description of the song.
     pub description: Option<String>,
     /// Musical genres.
+    #[serde(default)]
     pub genres: Vec<String>,
     /// Languages used in lyrics.
+    #[serde(default)]
     pub languages: Vec<String>,
     /// Whether the song contains explicit content.
     pub is_explicit: Option<bool>,
@@ -153,8 +169,10 @@ pub struct SongMetadata {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DetailedMusicResponse {
     /// The composition plan used to generate the song.
+    #[serde(default)]
     pub composition_plan: MusicPrompt,
     /// Metadata about the generated song.
+    #[serde(default)]
     pub song_metadata: SongMetadata,
     /// Timestamps for words in the generated song (if lyrics are present).
     pub words_timestamps: Option<Vec<WordTimestamp>>,
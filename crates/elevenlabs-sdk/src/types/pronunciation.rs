@@ -8,9 +8,15 @@
 //! - POST add rules / remove rules
 //! - PATCH update dictionary
 //! - GET dictionary rules
+//!
+//! Rules are typed as [`PronunciationRule`], which can be checked locally
+//! with [`PronunciationRule::validate`]. PLS files are read and written by
+//! [`pls`](crate::pls).
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::{ElevenLabsError, Result};
+
 // ---------------------------------------------------------------------------
 // Rule Types
 // ---------------------------------------------------------------------------
@@ -33,11 +39,14 @@ pub struct PronunciationAliasRuleRequest {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PronunciationAliasRuleResponse {
     /// The string being replaced.
+    #[serde(default)]
     pub string_to_replace: String,
     /// Rule type.
     #[serde(rename = "type")]
+    #[serde(default)]
     pub rule_type: String,
     /// The alias.
+    #[serde(default)]
     pub alias: String,
 }
 
@@ -61,16 +70,180 @@ pub struct PronunciationPhonemeRuleRequest {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PronunciationPhonemeRuleResponse {
     /// The string being replaced.
+    #[serde(default)]
     pub string_to_replace: String,
     /// Rule type.
     #[serde(rename = "type")]
+    #[serde(default)]
     pub rule_type: String,
     /// The phoneme representation.
+    #[serde(default)]
     pub phoneme: String,
     /// The phoneme alphabet.
+    #[serde(default)]
     pub alphabet: String,
 }
 
+/// Phonetic alphabet of a [`PronunciationRule::Phoneme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum PhonemeAlphabet {
+    /// The International Phonetic Alphabet, e.g. `təˈmeɪtoʊ`.
+    #[default]
+    #[serde(rename = "ipa")]
+    Ipa,
+    /// CMU Arpabet, e.g. `T AH0 M EY1 T OW2`.
+    #[serde(rename = "cmu-arpabet")]
+    CmuArpabet,
+}
+
+impl PhonemeAlphabet {
+    /// Returns the name the API and PLS files use for the alphabet.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ipa => "ipa",
+            Self::CmuArpabet => "cmu-arpabet",
+        }
+    }
+}
+
+impl std::fmt::Display for PhonemeAlphabet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for PhonemeAlphabet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ipa" => Ok(Self::Ipa),
+            "cmu-arpabet" | "x-cmu-arpabet" | "cmu" | "arpabet" => Ok(Self::CmuArpabet),
+            other => Err(format!("unknown phoneme alphabet {other:?}")),
+        }
+    }
+}
+
+/// The phonemes of CMU Arpabet, without stress markers.
+const ARPABET_PHONEMES: [&str; 39] = [
+    "AA", "AE", "AH", "AO", "AW", "AY", "B", "CH", "D", "DH", "EH", "ER", "EY", "F", "G", "HH",
+    "IH", "IY", "JH", "K", "L", "M", "N", "NG", "OW", "OY", "P", "R", "S", "SH", "T", "TH", "UH",
+    "UW", "V", "W", "Y", "Z", "ZH",
+];
+
+/// A pronunciation dictionary rule.
+///
+/// ```
+/// use elevenlabs_sdk::types::{PhonemeAlphabet, PronunciationRule};
+///
+/// let rules = [
+///     PronunciationRule::alias("UN", "United Nations"),
+///     PronunciationRule::phoneme("tomato", "T AH0 M EY1 T OW2", PhonemeAlphabet::CmuArpabet),
+/// ];
+/// assert!(rules.iter().all(|rule| rule.validate().is_ok()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PronunciationRule {
+    /// Reads `string_to_replace` as if it were `alias`.
+    Alias {
+        /// The string to replace. Must be non-empty.
+        string_to_replace: String,
+        /// The text read instead.
+        alias: String,
+    },
+    /// Pronounces `string_to_replace` as `phoneme`.
+    Phoneme {
+        /// The string to replace. Must be non-empty.
+        string_to_replace: String,
+        /// The pronunciation, written in `alphabet`.
+        phoneme: String,
+        /// The alphabet `phoneme` is written in.
+        alphabet: PhonemeAlphabet,
+    },
+}
+
+impl PronunciationRule {
+    /// Creates an alias rule.
+    pub fn alias(string_to_replace: impl Into<String>, alias: impl Into<String>) -> Self {
+        Self::Alias { string_to_replace: string_to_replace.into(), alias: alias.into() }
+    }
+
+    /// Creates a phoneme rule.
+    pub fn phoneme(
+        string_to_replace: impl Into<String>,
+        phoneme: impl Into<String>,
+        alphabet: PhonemeAlphabet,
+    ) -> Self {
+        Self::Phoneme {
+            string_to_replace: string_to_replace.into(),
+            phoneme: phoneme.into(),
+            alphabet,
+        }
+    }
+
+    /// Returns the string the rule replaces, which also identifies it when
+    /// removing rules.
+    pub fn string_to_replace(&self) -> &str {
+        match self {
+            Self::Alias { string_to_replace, .. } | Self::Phoneme { string_to_replace, .. } => {
+                string_to_replace
+            }
+        }
+    }
+
+    /// Checks the rule before it is sent.
+    ///
+    /// The string to replace and the alias or phoneme must not be blank.
+    /// Arpabet phonemes must be space-separated Arpabet symbols, each
+    /// optionally followed by a stress digit (`0`, `1` or `2`); IPA phonemes
+    /// must not contain ASCII digits, which usually means Arpabet was given
+    /// as IPA.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] describing the first problem.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: String| {
+            Err(ElevenLabsError::Validation(format!(
+                "pronunciation rule for {:?}: {reason}",
+                self.string_to_replace()
+            )))
+        };
+        if self.string_to_replace().trim().is_empty() {
+            return invalid("string to replace is empty".to_owned());
+        }
+        match self {
+            Self::Alias { alias, .. } if alias.trim().is_empty() => {
+                invalid("alias is empty".to_owned())
+            }
+            Self::Alias { .. } => Ok(()),
+            Self::Phoneme { phoneme, .. } if phoneme.trim().is_empty() => {
+                invalid("phoneme is empty".to_owned())
+            }
+            Self::Phoneme { phoneme, alphabet: PhonemeAlphabet::CmuArpabet, .. } => {
+                match phoneme.split_whitespace().find(|symbol| !is_arpabet_symbol(symbol)) {
+                    Some(symbol) => invalid(format!("{symbol:?} is not an Arpabet symbol")),
+                    None => Ok(()),
+                }
+            }
+            Self::Phoneme { phoneme, alphabet: PhonemeAlphabet::Ipa, .. } => {
+                if phoneme.chars().any(|c| c.is_ascii_digit()) {
+                    invalid(format!("IPA phoneme {phoneme:?} contains digits"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` for an Arpabet phoneme with an optional stress digit.
+fn is_arpabet_symbol(symbol: &str) -> bool {
+    let base = symbol.strip_suffix(['0', '1', '2']).unwrap_or(symbol);
+    ARPABET_PHONEMES.contains(&base)
+}
+
 // ---------------------------------------------------------------------------
 // Dictionary Metadata
 // ---------------------------------------------------------------------------
@@ -83,14 +256,19 @@ pub struct PronunciationDictionaryMetadata {
     /// ID of the latest version.
     pub latest_version_id: String,
     /// Number of rules in the latest version.
+    #[serde(default)]
     pub latest_version_rules_num: i64,
     /// Dictionary name.
+    #[serde(default)]
     pub name: String,
     /// Permission level on this dictionary.
+    #[serde(default)]
     pub permission_on_resource: serde_json::Value,
     /// User ID of the creator.
+    #[serde(default)]
     pub created_by: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub creation_time_unix: i64,
     /// Unix timestamp when archived, if applicable.
     #[serde(default)]
@@ -106,18 +284,24 @@ pub struct PronunciationDictionaryVersion {
     /// Version identifier.
     pub version_id: String,
     /// Number of rules in this version.
+    #[serde(default)]
     pub version_rules_num: i64,
     /// Parent dictionary identifier.
     pub pronunciation_dictionary_id: String,
     /// Dictionary name.
+    #[serde(default)]
     pub dictionary_name: String,
     /// Version name.
+    #[serde(default)]
     pub version_name: String,
     /// Permission level on this resource.
+    #[serde(default)]
     pub permission_on_resource: serde_json::Value,
     /// User ID of the creator.
+    #[serde(default)]
     pub created_by: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub creation_time_unix: i64,
     /// Unix timestamp when archived, if applicable.
     #[serde(default)]
@@ -131,8 +315,8 @@ pub struct PronunciationDictionaryVersion {
 /// Request body for adding rules to a pronunciation dictionary.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AddPronunciationRulesRequest {
-    /// Rules to add (can be alias or phoneme rules, serialized as JSON).
-    pub rules: Vec<serde_json::Value>,
+    /// Rules to add.
+    pub rules: Vec<PronunciationRule>,
 }
 
 /// Request body for removing rules from a pronunciation dictionary.
@@ -142,6 +326,19 @@ pub struct RemovePronunciationRulesRequest {
     pub rule_strings: Vec<String>,
 }
 
+impl RemovePronunciationRulesRequest {
+    /// Creates a request removing `rules`, which are identified by the
+    /// string they replace.
+    pub fn for_rules<'a>(rules: impl IntoIterator<Item = &'a PronunciationRule>) -> Self {
+        Self {
+            rule_strings: rules
+                .into_iter()
+                .map(|rule| rule.string_to_replace().to_owned())
+                .collect(),
+        }
+    }
+}
+
 /// Request body for updating a pronunciation dictionary (PATCH).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct UpdatePronunciationDictionaryRequest {
@@ -173,11 +370,13 @@ pub struct PronunciationDictionaryLocatorRequest {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GetPronunciationDictionariesResponse {
     /// List of pronunciation dictionary metadata entries.
+    #[serde(default)]
     pub pronunciation_dictionaries: Vec<PronunciationDictionaryMetadata>,
     /// Cursor for the next page of results.
     #[serde(default)]
     pub next_cursor: Option<String>,
     /// Whether more results are available.
+    #[serde(default)]
     pub has_more: bool,
 }
 
@@ -185,6 +384,7 @@ pub struct GetPronunciationDictionariesResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CreatePronunciationDictionaryResponse {
     /// Status string, typically `"ok"`.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -194,19 +394,24 @@ pub struct AddPronunciationDictionaryResponse {
     /// ID of the created dictionary.
     pub id: String,
     /// Name of the created dictionary.
+    #[serde(default)]
     pub name: String,
     /// User ID of the creator.
+    #[serde(default)]
     pub created_by: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub creation_time_unix: i64,
     /// ID of the created version.
     pub version_id: String,
     /// Number of rules in the version.
+    #[serde(default)]
     pub version_rules_num: i64,
     /// Optional description.
     #[serde(default)]
     pub description: Option<String>,
     /// Permission on this resource.
+    #[serde(default)]
     pub permission_on_resource: serde_json::Value,
 }
 
@@ -218,6 +423,7 @@ pub struct PronunciationDictionaryRulesResponse {
     /// Version ID after the modification.
     pub version_id: String,
     /// Number of rules in the new version.
+    #[serde(default)]
     pub version_rules_num: i64,
 }
 
@@ -279,6 +485,57 @@ mod tests {
         assert_eq!(rule.phoneme, "təˈmeɪtoʊ");
     }
 
+    #[test]
+    fn typed_rules_serialize_with_type_tag() {
+        let alias = PronunciationRule::alias("UN", "United Nations");
+        let json = serde_json::to_value(&alias).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "alias", "string_to_replace": "UN", "alias": "United Nations"})
+        );
+
+        let phoneme =
+            PronunciationRule::phoneme("tomato", "T AH0 M EY1 T OW2", PhonemeAlphabet::CmuArpabet);
+        let json = serde_json::to_value(&phoneme).unwrap();
+        assert_eq!(json["type"], "phoneme");
+        assert_eq!(json["alphabet"], "cmu-arpabet");
+        assert_eq!(serde_json::from_value::<PronunciationRule>(json).unwrap(), phoneme);
+    }
+
+    #[test]
+    fn typed_rules_validate() {
+        assert!(PronunciationRule::alias("UN", "United Nations").validate().is_ok());
+        assert!(PronunciationRule::alias(" ", "United Nations").validate().is_err());
+        assert!(PronunciationRule::alias("UN", "").validate().is_err());
+        assert!(
+            PronunciationRule::phoneme("tomato", "T AH0 M EY1 T OW2", PhonemeAlphabet::CmuArpabet)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            PronunciationRule::phoneme("tomato", "T AH0 M EY7", PhonemeAlphabet::CmuArpabet)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            PronunciationRule::phoneme("tomato", "təˈmeɪtoʊ", PhonemeAlphabet::Ipa)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            PronunciationRule::phoneme("tomato", "T AH0 M", PhonemeAlphabet::Ipa)
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn remove_request_for_rules_uses_strings_to_replace() {
+        let rules = [PronunciationRule::alias("UN", "United Nations")];
+        let req = RemovePronunciationRulesRequest::for_rules(&rules);
+        assert_eq!(req.rule_strings, vec!["UN".to_owned()]);
+    }
+
     #[test]
     fn dictionary_metadata_deserialize() {
         let json = r#"{
@@ -18,8 +18,14 @@
 
 use std::collections::HashMap;
 
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
+use super::{
+    samples::SampleResponse,
+    voices::{FineTuning, FineTuningState},
+};
+
 // ---------------------------------------------------------------------------
 // Request types
 // ---------------------------------------------------------------------------
@@ -71,6 +77,36 @@ pub struct EditPvcVoiceSampleRequest {
     pub file_name: Option<String>,
 }
 
+/// Request body for starting PVC training.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RunPvcTrainingRequest {
+    /// Model to train the voice for (e.g. `"eleven_multilingual_v2"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+}
+
+/// An audio file uploaded as a PVC sample or captcha recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PvcAudioFile {
+    /// File name sent with the upload.
+    pub file_name: String,
+    /// MIME type (e.g. `"audio/mpeg"`).
+    pub content_type: String,
+    /// Raw audio bytes.
+    pub data: Bytes,
+}
+
+impl PvcAudioFile {
+    /// Creates an audio file from its name, MIME type, and contents.
+    pub fn new(
+        file_name: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<Bytes>,
+    ) -> Self {
+        Self { file_name: file_name.into(), content_type: content_type.into(), data: data.into() }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Enums
 // ---------------------------------------------------------------------------
@@ -97,12 +133,14 @@ pub enum SpeakerSeparationStatus {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VoiceSamplePreviewResponse {
     /// Base64-encoded audio data.
+    #[serde(default)]
     pub audio_base_64: String,
     /// ID of the voice.
     pub voice_id: String,
     /// ID of the sample.
     pub sample_id: String,
     /// Media type (e.g. `"audio/mpeg"`).
+    #[serde(default)]
     pub media_type: String,
     /// Duration in seconds.
     #[serde(default)]
@@ -115,6 +153,7 @@ pub struct VoiceSampleWaveformResponse {
     /// ID of the sample.
     pub sample_id: String,
     /// Waveform values (amplitude data points).
+    #[serde(default)]
     pub visual_waveform: Vec<f64>,
 }
 
@@ -122,6 +161,7 @@ pub struct VoiceSampleWaveformResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VerifyPvcCaptchaResponse {
     /// Status string, typically `"ok"`.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -129,6 +169,7 @@ pub struct VerifyPvcCaptchaResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RequestPvcManualVerificationResponse {
     /// Status string, typically `"ok"`.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -136,6 +177,7 @@ pub struct RequestPvcManualVerificationResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StartPvcTrainingResponse {
     /// Status string, typically `"ok"`.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -143,6 +185,7 @@ pub struct StartPvcTrainingResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeletePvcSampleResponse {
     /// Status string, typically `"ok"`.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -167,6 +210,7 @@ pub struct SpeakerSeparationResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StartSpeakerSeparationResponse {
     /// Status string.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -178,6 +222,97 @@ pub struct GetPvcCaptchaResponse {
     pub extra: HashMap<String, serde_json::Value>,
 }
 
+// ---------------------------------------------------------------------------
+// Training workflow
+// ---------------------------------------------------------------------------
+
+/// A PVC voice with uploaded samples, waiting for captcha verification.
+///
+/// Returned by [`PvcTrainer::start`](crate::services::PvcTrainer::start).
+/// Record the speaker reading the captcha text and pass the recording to
+/// [`PvcTrainer::verify_captcha`](crate::services::PvcTrainer::verify_captcha).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PvcCaptchaChallenge {
+    /// ID of the created voice.
+    pub voice_id: String,
+    /// Samples uploaded to the voice.
+    pub samples: Vec<SampleResponse>,
+    /// Captcha the speaker must read aloud.
+    pub captcha: GetPvcCaptchaResponse,
+}
+
+/// Progress notification emitted by
+/// [`PvcTrainer`](crate::services::PvcTrainer).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PvcTrainingEvent {
+    /// The voice was created.
+    VoiceCreated {
+        /// ID of the created voice.
+        voice_id: String,
+    },
+    /// Samples were uploaded to the voice.
+    SamplesUploaded {
+        /// ID of the voice.
+        voice_id: String,
+        /// Number of samples uploaded.
+        count: usize,
+    },
+    /// The captcha was fetched and must be recorded by the speaker.
+    CaptchaRequired {
+        /// ID of the voice.
+        voice_id: String,
+    },
+    /// The captcha recording was accepted.
+    CaptchaVerified {
+        /// ID of the voice.
+        voice_id: String,
+    },
+    /// Training was started.
+    TrainingStarted {
+        /// ID of the voice.
+        voice_id: String,
+        /// Model the voice is trained for.
+        model_id: String,
+    },
+    /// The training state or progress changed.
+    TrainingProgress {
+        /// ID of the voice.
+        voice_id: String,
+        /// Current training state.
+        state: FineTuningState,
+        /// Completion fraction (0.0 to 1.0), if reported.
+        progress: Option<f64>,
+    },
+}
+
+/// Final result of [`PvcTrainer::train`](crate::services::PvcTrainer::train).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PvcTrainingOutcome {
+    /// ID of the voice.
+    pub voice_id: String,
+    /// Model the voice was trained for.
+    pub model_id: String,
+    /// Terminal training state, either `FineTuned` or `Failed`.
+    pub state: FineTuningState,
+    /// Full fine-tuning details from the last poll, including failure
+    /// messages.
+    pub fine_tuning: Option<FineTuning>,
+}
+
+impl PvcTrainingOutcome {
+    /// Returns `true` if training finished successfully.
+    #[must_use]
+    pub fn is_trained(&self) -> bool {
+        self.state == FineTuningState::FineTuned
+    }
+
+    /// Returns the API's status message for the trained model, if any.
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.fine_tuning.as_ref()?.message.as_ref()?.get(&self.model_id).map(String::as_str)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -254,6 +389,34 @@ mod tests {
         assert_eq!(resp.status, "ok");
     }
 
+    #[test]
+    fn run_training_request_serialize() {
+        let request = RunPvcTrainingRequest { model_id: Some("eleven_multilingual_v2".into()) };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json, serde_json::json!({"model_id": "eleven_multilingual_v2"}));
+        assert_eq!(
+            serde_json::to_value(RunPvcTrainingRequest::default()).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn training_outcome_message() {
+        let fine_tuning: FineTuning = serde_json::from_value(serde_json::json!({
+            "state": {"eleven_multilingual_v2": "failed"},
+            "message": {"eleven_multilingual_v2": "Not enough audio"}
+        }))
+        .unwrap();
+        let outcome = PvcTrainingOutcome {
+            voice_id: "v1".into(),
+            model_id: "eleven_multilingual_v2".into(),
+            state: FineTuningState::Failed,
+            fine_tuning: Some(fine_tuning),
+        };
+        assert!(!outcome.is_trained());
+        assert_eq!(outcome.message(), Some("Not enough audio"));
+    }
+
     #[test]
     fn delete_pvc_sample_response_deserialize() {
         let json = r#"{"status": "ok"}"#;
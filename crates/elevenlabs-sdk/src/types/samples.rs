@@ -19,12 +19,16 @@ pub struct SampleResponse {
     /// Unique identifier for this sample.
     pub sample_id: String,
     /// Original file name of the sample.
+    #[serde(default)]
     pub file_name: String,
     /// MIME type of the sample file.
+    #[serde(default)]
     pub mime_type: String,
     /// Size of the sample file in bytes.
+    #[serde(default)]
     pub size_bytes: i64,
     /// Hash of the sample file content.
+    #[serde(default)]
     pub hash: String,
     /// Duration of the sample in seconds.
     #[serde(default)]
@@ -53,6 +57,7 @@ pub struct SampleResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeleteSampleResponse {
     /// Status string, typically `"ok"`.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -78,7 +83,7 @@ mod tests {
         let sample: SampleResponse = serde_json::from_str(json).unwrap();
         assert_eq!(sample.sample_id, "s1");
         assert_eq!(sample.file_name, "sample.mp3");
-        assert_eq!(sample.size_bytes, 102400);
+        assert_eq!(sample.size_bytes, 102_400);
         assert_eq!(sample.duration_secs, Some(5.2));
     }
 
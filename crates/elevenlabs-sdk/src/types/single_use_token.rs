@@ -2,9 +2,58 @@
 //!
 //! Covers `POST /v1/single-use-token/{token_type}` — generate a single-use
 //! token that can be embedded in client-side code.
+//!
+//! A backend holding the API key creates a [`SingleUseToken`] and hands the
+//! token string to an untrusted client, which rebuilds it with
+//! [`SingleUseToken::new`] and connects with
+//! [`TtsWebSocket::connect_with_token`](crate::TtsWebSocket::connect_with_token)
+//! or
+//! [`ConversationWebSocket::connect_with_token`](crate::ConversationWebSocket::connect_with_token).
+
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use serde::{Deserialize, Serialize};
 
+/// How long a single-use token stays valid after it is created.
+pub const SINGLE_USE_TOKEN_TTL: Duration = Duration::from_mins(15);
+
+/// WebSocket query parameter that carries a single-use token.
+pub(crate) const SINGLE_USE_TOKEN_PARAM: &str = "single_use_token";
+
+// ---------------------------------------------------------------------------
+// Enums
+// ---------------------------------------------------------------------------
+
+/// What a single-use token may be used for (the `token_type` path
+/// parameter).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenPurpose {
+    /// Realtime speech-to-text WebSocket sessions.
+    RealtimeScribe,
+    /// Text-to-speech input-streaming WebSocket sessions.
+    TtsWebsocket,
+}
+
+impl TokenPurpose {
+    /// Returns the value used in the request path.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::RealtimeScribe => "realtime_scribe",
+            Self::TtsWebsocket => "tts_websocket",
+        }
+    }
+}
+
+impl fmt::Display for TokenPurpose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Response
 // ---------------------------------------------------------------------------
@@ -15,9 +64,69 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SingleUseTokenResponse {
     /// The single-use token string.
+    #[serde(default)]
     pub token: String,
 }
 
+/// A single-use token together with its purpose and expiry.
+///
+/// Returned by
+/// [`SingleUseTokenService::create`](crate::services::SingleUseTokenService::create).
+/// The token is consumed by the first connection that uses it. Its `Debug`
+/// output is redacted; serializing it writes the token in full so it can be
+/// handed to a client.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{SingleUseToken, TokenPurpose};
+///
+/// // On the untrusted client, rebuild the token received from the backend.
+/// let token = SingleUseToken::new("sutkn_abc123", TokenPurpose::TtsWebsocket);
+/// assert!(!token.is_expired());
+/// assert!(!format!("{token:?}").contains("sutkn_abc123"));
+/// ```
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SingleUseToken {
+    /// The token string.
+    pub token: String,
+    /// When the token expires, in Unix seconds. Estimated from the time the
+    /// token was requested, since the API does not return it.
+    pub expires_at: u64,
+    /// What the token may be used for.
+    pub purpose: TokenPurpose,
+}
+
+impl SingleUseToken {
+    /// Wraps a token created now, expiring after [`SINGLE_USE_TOKEN_TTL`].
+    pub fn new(token: impl Into<String>, purpose: TokenPurpose) -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        Self { token: token.into(), expires_at: (now + SINGLE_USE_TOKEN_TTL).as_secs(), purpose }
+    }
+
+    /// Returns `true` once the token's expiry time has passed.
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        now.as_secs() >= self.expires_at
+    }
+
+    /// Returns the WebSocket query parameter that authenticates with this
+    /// token.
+    pub fn ws_query_param(&self) -> (&'static str, &str) {
+        (SINGLE_USE_TOKEN_PARAM, &self.token)
+    }
+}
+
+impl fmt::Debug for SingleUseToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SingleUseToken")
+            .field("token", &"****")
+            .field("expires_at", &self.expires_at)
+            .field("purpose", &self.purpose)
+            .finish()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -33,4 +142,26 @@ mod tests {
         let resp: SingleUseTokenResponse = serde_json::from_str(json).unwrap();
         assert_eq!(resp.token, "abc123xyz");
     }
+
+    #[test]
+    fn token_purpose_serde_names() {
+        for purpose in [TokenPurpose::RealtimeScribe, TokenPurpose::TtsWebsocket] {
+            let json = serde_json::to_string(&purpose).unwrap();
+            assert_eq!(json, format!("\"{purpose}\""));
+        }
+    }
+
+    #[test]
+    fn single_use_token_expiry_and_query_param() {
+        let mut token = SingleUseToken::new("sutkn_1", TokenPurpose::TtsWebsocket);
+        assert!(!token.is_expired());
+        assert_eq!(token.ws_query_param(), ("single_use_token", "sutkn_1"));
+
+        token.expires_at = 0;
+        assert!(token.is_expired());
+        assert_eq!(
+            format!("{token:?}"),
+            r#"SingleUseToken { token: "****", expires_at: 0, purpose: TtsWebsocket }"#
+        );
+    }
 }
@@ -51,6 +51,11 @@ pub struct SoundGenerationRequest {
 
     /// The model ID to use for sound generation.
     pub model_id: String,
+
+    /// Seed for reproducible generation. The same prompt, settings and
+    /// seed produce the same sound; `None` picks a random seed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
 }
 
 impl Default for SoundGenerationRequest {
@@ -61,6 +66,7 @@ impl Default for SoundGenerationRequest {
             duration_seconds: None,
             prompt_influence: 0.3,
             model_id: "eleven_text_to_sound_v2".into(),
+            seed: None,
         }
     }
 }
@@ -93,6 +99,7 @@ mod tests {
         assert_eq!(obj["text"], "Thunder rolling");
         assert_eq!(obj["loop"], false);
         assert!(!obj.contains_key("duration_seconds"));
+        assert!(!obj.contains_key("seed"));
         assert_eq!(obj["prompt_influence"], 0.3);
         assert_eq!(obj["model_id"], "eleven_text_to_sound_v2");
     }
@@ -105,6 +112,7 @@ mod tests {
             duration_seconds: Some(5.0),
             prompt_influence: 0.7,
             model_id: "eleven_text_to_sound_v2".into(),
+            seed: Some(42),
         };
         let json = serde_json::to_string(&req).unwrap();
         let v: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -112,6 +120,7 @@ mod tests {
         assert_eq!(v["loop"], true);
         assert_eq!(v["duration_seconds"], 5.0);
         assert_eq!(v["prompt_influence"], 0.7);
+        assert_eq!(v["seed"], 42);
     }
 
     #[test]
@@ -12,7 +12,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::common::VoiceSettings;
+use super::common::{OutputFormat, VoiceSettings};
 
 // ---------------------------------------------------------------------------
 // Input Audio Format
@@ -92,6 +92,39 @@ impl Default for SpeechToSpeechRequest {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Stream options
+// ---------------------------------------------------------------------------
+
+/// Query parameters for `POST /v1/speech-to-speech/{voice_id}/stream`.
+///
+/// Body fields such as `seed` and `remove_background_noise` live on
+/// [`SpeechToSpeechRequest`]; these options only shape the response.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{OutputFormat, SpeechToSpeechStreamOptions};
+///
+/// let options = SpeechToSpeechStreamOptions {
+///     output_format: Some(OutputFormat::Pcm_16000),
+///     optimize_streaming_latency: Some(3),
+///     ..Default::default()
+/// };
+/// assert!(options.enable_logging.is_none());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpeechToSpeechStreamOptions {
+    /// Output audio format (defaults to `mp3_44100_128`).
+    pub output_format: Option<OutputFormat>,
+    /// Latency optimization level (0–4). Higher values trade quality for
+    /// lower time-to-first-byte.
+    pub optimize_streaming_latency: Option<u8>,
+    /// Set to `false` to use zero retention mode; history and request
+    /// stitching are unavailable for the request.
+    pub enable_logging: Option<bool>,
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
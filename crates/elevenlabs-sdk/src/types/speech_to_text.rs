@@ -8,9 +8,16 @@
 //! The transcription endpoint accepts multipart/form-data with an audio file
 //! (or a cloud storage URL) and configuration fields. It returns either a
 //! single-channel or multichannel transcript, or a webhook acknowledgement.
+//!
+//! Diarized transcripts can be grouped into [`SpeakerTurn`]s and rendered as
+//! SubRip captions with [`SpeechToTextChunkResponse::to_srt`].
+
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
+use super::forced_alignment::{CaptionOptions, caption_cues, format_timestamp};
+
 // ---------------------------------------------------------------------------
 // Enums
 // ---------------------------------------------------------------------------
@@ -194,6 +201,8 @@ pub struct SpeechToTextRequest {
 
     /// When `true`, the transcript is delivered asynchronously via webhook
     /// and the request returns early with a [`SpeechToTextWebhookResponse`].
+    /// Set by
+    /// [`SpeechToTextService::transcribe_async`](crate::services::SpeechToTextService::transcribe_async).
     pub webhook: bool,
 
     /// Specific webhook ID to send the result to. Only valid when
@@ -254,6 +263,27 @@ impl Default for SpeechToTextRequest {
     }
 }
 
+impl SpeechToTextRequest {
+    /// Enables speaker diarization.
+    ///
+    /// With `num_speakers` set, the model assumes at most that many
+    /// speakers; otherwise it estimates the count itself.
+    #[must_use]
+    pub const fn with_diarization(mut self, num_speakers: Option<u32>) -> Self {
+        self.diarize = true;
+        self.num_speakers = num_speakers;
+        self
+    }
+
+    /// Sets whether audio events like `(laughter)` are tagged in the
+    /// transcript (default `true`).
+    #[must_use]
+    pub const fn with_audio_events(mut self, tag_audio_events: bool) -> Self {
+        self.tag_audio_events = tag_audio_events;
+        self
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Response types
 // ---------------------------------------------------------------------------
@@ -264,6 +294,7 @@ impl Default for SpeechToTextRequest {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpeechToTextCharacter {
     /// The transcribed character.
+    #[serde(default)]
     pub text: String,
     /// Start time in seconds.
     pub start: Option<f64>,
@@ -273,8 +304,10 @@ pub struct SpeechToTextCharacter {
 
 /// Word-level detail in a speech-to-text transcription.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct SpeechToTextWord {
     /// The transcribed word, spacing, or audio event text.
+    #[serde(default)]
     pub text: String,
     /// Start time of the word in seconds.
     pub start: Option<f64>,
@@ -286,6 +319,7 @@ pub struct SpeechToTextWord {
     /// Unique identifier of the speaker. Present when diarization is enabled.
     pub speaker_id: Option<String>,
     /// Log probability of this word. Range: `(-∞, 0]`.
+    #[serde(default)]
     pub logprob: f64,
     /// Character-level timing breakdown. Present when
     /// `timestamps_granularity` is `Character`.
@@ -296,13 +330,17 @@ pub struct SpeechToTextWord {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DetectedEntity {
     /// The text identified as an entity.
+    #[serde(default)]
     pub text: String,
     /// The entity type (e.g. `"credit_card"`, `"email_address"`,
     /// `"person_name"`).
+    #[serde(default)]
     pub entity_type: String,
     /// Start character position in the transcript text.
+    #[serde(default)]
     pub start_char: u64,
     /// End character position in the transcript text.
+    #[serde(default)]
     pub end_char: u64,
 }
 
@@ -310,14 +348,19 @@ pub struct DetectedEntity {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AdditionalFormatResponse {
     /// The format that was requested (e.g. `"srt"`, `"txt"`).
+    #[serde(default)]
     pub requested_format: String,
     /// File extension for this format.
+    #[serde(default)]
     pub file_extension: String,
     /// MIME content type.
+    #[serde(default)]
     pub content_type: String,
     /// Whether `content` is base64-encoded.
+    #[serde(default)]
     pub is_base64_encoded: bool,
     /// The transcript content in the requested format.
+    #[serde(default)]
     pub content: String,
 }
 
@@ -346,14 +389,19 @@ pub struct AdditionalFormatResponse {
 /// assert_eq!(resp.words.len(), 3);
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct SpeechToTextChunkResponse {
     /// Detected language code (e.g. `"eng"` for English).
+    #[serde(default)]
     pub language_code: String,
     /// Confidence score of the language detection (0.0–1.0).
+    #[serde(default)]
     pub language_probability: f64,
     /// Raw text of the transcription.
+    #[serde(default)]
     pub text: String,
     /// Word-level details with timing information.
+    #[serde(default)]
     pub words: Vec<SpeechToTextWord>,
     /// Channel index (for multichannel audio via `use_multi_channel`).
     pub channel_index: Option<u32>,
@@ -365,6 +413,106 @@ pub struct SpeechToTextChunkResponse {
     pub entities: Option<Vec<DetectedEntity>>,
 }
 
+impl SpeechToTextChunkResponse {
+    /// Groups the transcript into runs of consecutive words by the same
+    /// speaker.
+    ///
+    /// Spacing between two speakers is dropped. Without diarization every
+    /// word has no speaker, so the whole transcript is a single turn.
+    pub fn speaker_turns(&self) -> Vec<SpeakerTurn<'_>> {
+        let mut turns = Vec::new();
+        let mut current: Option<(usize, usize)> = None;
+        for (index, word) in self.words.iter().enumerate() {
+            if word.word_type == WordType::Spacing {
+                continue;
+            }
+            current = match current {
+                Some((first, last)) if self.words[last].speaker_id == word.speaker_id => {
+                    Some((first, index))
+                }
+                Some((first, last)) => {
+                    turns.push(SpeakerTurn::new(&self.words[first..=last]));
+                    Some((index, index))
+                }
+                None => Some((index, index)),
+            };
+        }
+        if let Some((first, last)) = current {
+            turns.push(SpeakerTurn::new(&self.words[first..=last]));
+        }
+        turns
+    }
+
+    /// Renders the transcript as SubRip (`.srt`) captions.
+    ///
+    /// A new cue starts at every speaker change, and long turns are split
+    /// into cues of at most two 42-character lines. Cues of diarized
+    /// transcripts are prefixed with the speaker ID.
+    pub fn to_srt(&self) -> String {
+        let options = CaptionOptions::default();
+        let mut out = String::new();
+        let mut index = 0;
+        for turn in self.speaker_turns() {
+            let words =
+                turn.words.iter().filter(|word| word.word_type != WordType::Spacing).map(|word| {
+                    (
+                        word.text.as_str(),
+                        word.start.unwrap_or(turn.start),
+                        word.end.unwrap_or(turn.end),
+                    )
+                });
+            for cue in caption_cues(words, &options) {
+                if index > 0 {
+                    out.push('\n');
+                }
+                index += 1;
+                out.push_str(&format!(
+                    "{index}\n{} --> {}\n",
+                    format_timestamp(cue.start, ',', 3),
+                    format_timestamp(cue.end, ',', 3)
+                ));
+                let text = cue.lines.join("\n");
+                match turn.speaker_id {
+                    Some(speaker) => out.push_str(&format!("{speaker}: {text}\n")),
+                    None => out.push_str(&format!("{text}\n")),
+                }
+            }
+        }
+        out
+    }
+}
+
+/// A run of consecutive words by one speaker, from
+/// [`SpeechToTextChunkResponse::speaker_turns`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeakerTurn<'a> {
+    /// The speaker, or `None` if the transcript was not diarized.
+    pub speaker_id: Option<&'a str>,
+    /// Start time of the first word in seconds.
+    pub start: f64,
+    /// End time of the last word in seconds.
+    pub end: f64,
+    /// The words of the turn joined with their spacing.
+    pub text: String,
+    /// The words, audio events, and spacing making up the turn.
+    pub words: &'a [SpeechToTextWord],
+}
+
+impl<'a> SpeakerTurn<'a> {
+    /// Builds a turn from a non-empty run of words.
+    fn new(words: &'a [SpeechToTextWord]) -> Self {
+        let start = words.first().and_then(|word| word.start).unwrap_or_default();
+        let end = words.last().and_then(|word| word.end).unwrap_or(start);
+        Self {
+            speaker_id: words.first().and_then(|word| word.speaker_id.as_deref()),
+            start,
+            end,
+            text: words.iter().map(|word| word.text.as_str()).collect::<String>().trim().to_owned(),
+            words,
+        }
+    }
+}
+
 /// Multichannel transcription result.
 ///
 /// Returned by `POST /v1/speech-to-text` when `use_multi_channel` is `true`.
@@ -390,6 +538,7 @@ pub struct SpeechToTextChunkResponse {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MultichannelSpeechToTextResponse {
     /// One transcript per audio channel.
+    #[serde(default)]
     pub transcripts: Vec<SpeechToTextChunkResponse>,
     /// The transcription ID.
     pub transcription_id: Option<String>,
@@ -402,6 +551,7 @@ pub struct MultichannelSpeechToTextResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SpeechToTextWebhookResponse {
     /// Acknowledgement message.
+    #[serde(default)]
     pub message: String,
     /// Request ID for tracking.
     pub request_id: String,
@@ -409,6 +559,23 @@ pub struct SpeechToTextWebhookResponse {
     pub transcription_id: Option<String>,
 }
 
+/// Options for waiting on an asynchronous transcription with
+/// [`SpeechToTextService::wait_for_transcript_with`](crate::services::SpeechToTextService::wait_for_transcript_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranscriptWaitOptions {
+    /// Delay between polls.
+    pub poll_interval: Duration,
+    /// Gives up after this long. Transcription keeps running server-side;
+    /// only the polling stops.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for TranscriptWaitOptions {
+    fn default() -> Self {
+        Self { poll_interval: Duration::from_secs(5), timeout: Some(Duration::from_mins(30)) }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -819,6 +986,57 @@ mod tests {
         assert_eq!(entities[0].entity_type, "greeting");
     }
 
+    fn diarized() -> SpeechToTextChunkResponse {
+        serde_json::from_str(
+            r#"{
+                "language_code": "eng",
+                "language_probability": 0.97,
+                "text": "Hi there. Hello! (laughter)",
+                "words": [
+                    {"text": "Hi", "start": 0.0, "end": 0.4, "type": "word", "speaker_id": "speaker_0", "logprob": -0.1},
+                    {"text": " ", "start": 0.4, "end": 0.5, "type": "spacing", "speaker_id": "speaker_0", "logprob": 0.0},
+                    {"text": "there.", "start": 0.5, "end": 0.9, "type": "word", "speaker_id": "speaker_0", "logprob": -0.1},
+                    {"text": " ", "start": 0.9, "end": 1.2, "type": "spacing", "speaker_id": "speaker_0", "logprob": 0.0},
+                    {"text": "Hello!", "start": 1.2, "end": 1.6, "type": "word", "speaker_id": "speaker_1", "logprob": -0.2},
+                    {"text": " ", "start": 1.6, "end": 1.7, "type": "spacing", "speaker_id": "speaker_1", "logprob": 0.0},
+                    {"text": "(laughter)", "start": 1.7, "end": 2.5, "type": "audio_event", "speaker_id": "speaker_1", "logprob": 0.0}
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn stt_chunk_response_speaker_turns() {
+        let resp = diarized();
+        let turns = resp.speaker_turns();
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].speaker_id, Some("speaker_0"));
+        assert_eq!(turns[0].text, "Hi there.");
+        assert_eq!(turns[0].words.len(), 3);
+        assert_eq!(turns[1].speaker_id, Some("speaker_1"));
+        assert_eq!(turns[1].text, "Hello! (laughter)");
+        assert!((turns[1].start - 1.2).abs() < f64::EPSILON);
+        assert!((turns[1].end - 2.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn stt_chunk_response_to_srt_splits_on_speaker() {
+        assert_eq!(
+            diarized().to_srt(),
+            "1\n00:00:00,000 --> 00:00:00,900\nspeaker_0: Hi there.\n\n\
+             2\n00:00:01,200 --> 00:00:02,500\nspeaker_1: Hello! (laughter)\n"
+        );
+    }
+
+    #[test]
+    fn stt_request_with_diarization() {
+        let req = SpeechToTextRequest::default().with_diarization(Some(2)).with_audio_events(false);
+        assert!(req.diarize);
+        assert_eq!(req.num_speakers, Some(2));
+        assert!(!req.tag_audio_events);
+    }
+
     // -- MultichannelSpeechToTextResponse ------------------------------------
 
     #[test]
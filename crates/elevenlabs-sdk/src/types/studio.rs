@@ -154,6 +154,7 @@ pub enum BlockSubType {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProjectCreationMeta {
     /// Progress of project creation (0.0 to 1.0).
+    #[serde(default)]
     pub creation_progress: f64,
     /// Status of the creation task.
     pub status: ProjectCreationStatus,
@@ -172,8 +173,10 @@ pub struct ProjectResponse {
     /// Project ID.
     pub project_id: String,
     /// Project name.
+    #[serde(default)]
     pub name: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub create_date_unix: i64,
     /// User ID who created the project.
     pub created_by_user_id: Option<String>,
@@ -186,6 +189,7 @@ pub struct ProjectResponse {
     /// Unix timestamp of last conversion.
     pub last_conversion_date_unix: Option<i64>,
     /// Whether the project can be downloaded.
+    #[serde(default)]
     pub can_be_downloaded: bool,
     /// Title of the project.
     pub title: Option<String>,
@@ -210,16 +214,20 @@ pub struct ProjectResponse {
     /// ISBN number.
     pub isbn_number: Option<String>,
     /// Whether volume normalization is enabled.
+    #[serde(default)]
     pub volume_normalization: bool,
     /// Current state of the project.
     pub state: ProjectState,
     /// Access level for the current user.
+    #[serde(default)]
     pub access_level: String,
     /// Fiction/non-fiction classification.
     pub fiction: Option<FictionType>,
     /// Whether quality check is enabled.
+    #[serde(default)]
     pub quality_check_on: bool,
     /// Whether quality check is on during bulk conversion.
+    #[serde(default)]
     pub quality_check_on_when_bulk_convert: bool,
     /// Creation metadata (progress, status).
     pub creation_meta: Option<ProjectCreationMeta>,
@@ -248,8 +256,10 @@ pub struct ProjectExtendedResponse {
     /// Project ID.
     pub project_id: String,
     /// Project name.
+    #[serde(default)]
     pub name: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub create_date_unix: i64,
     /// User ID who created the project.
     pub created_by_user_id: Option<String>,
@@ -262,6 +272,7 @@ pub struct ProjectExtendedResponse {
     /// Unix timestamp of last conversion.
     pub last_conversion_date_unix: Option<i64>,
     /// Whether the project can be downloaded.
+    #[serde(default)]
     pub can_be_downloaded: bool,
     /// Title of the project.
     pub title: Option<String>,
@@ -286,16 +297,20 @@ pub struct ProjectExtendedResponse {
     /// ISBN number.
     pub isbn_number: Option<String>,
     /// Whether volume normalization is enabled.
+    #[serde(default)]
     pub volume_normalization: bool,
     /// Current state of the project.
     pub state: ProjectState,
     /// Access level for the current user.
+    #[serde(default)]
     pub access_level: String,
     /// Fiction/non-fiction classification.
     pub fiction: Option<FictionType>,
     /// Whether quality check is enabled.
+    #[serde(default)]
     pub quality_check_on: bool,
     /// Whether quality check is on during bulk conversion.
+    #[serde(default)]
     pub quality_check_on_when_bulk_convert: bool,
     /// Creation metadata (progress, status).
     pub creation_meta: Option<ProjectCreationMeta>,
@@ -314,21 +329,28 @@ pub struct ProjectExtendedResponse {
     /// Aspect ratio of the project.
     pub aspect_ratio: Option<AspectRatio>,
     /// Quality preset identifier.
+    #[serde(default)]
     pub quality_preset: String,
     /// Chapters in this project.
+    #[serde(default)]
     pub chapters: Vec<ChapterResponse>,
     /// Pronunciation dictionary versions.
+    #[serde(default)]
     pub pronunciation_dictionary_versions: Vec<serde_json::Value>,
     /// Pronunciation dictionary locators.
+    #[serde(default)]
     pub pronunciation_dictionary_locators: Vec<serde_json::Value>,
     /// Text normalization setting.
+    #[serde(default)]
     pub apply_text_normalization: String,
     /// Additional experimental settings.
     #[serde(default)]
     pub experimental: serde_json::Value,
     /// Project assets (images, audio, video — complex nested array).
+    #[serde(default)]
     pub assets: Vec<serde_json::Value>,
     /// Voices used in this project.
+    #[serde(default)]
     pub voices: Vec<ProjectVoiceResponse>,
     /// Base voices for the project.
     pub base_voices: Option<Vec<serde_json::Value>>,
@@ -340,6 +362,7 @@ pub struct ProjectExtendedResponse {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GetProjectsResponse {
     /// List of projects.
+    #[serde(default)]
     pub projects: Vec<ProjectResponse>,
 }
 
@@ -361,6 +384,7 @@ pub struct EditProjectResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeleteProjectResponse {
     /// Status. "ok" on success.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -368,6 +392,7 @@ pub struct DeleteProjectResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConvertProjectResponse {
     /// Status. "ok" on success.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -383,8 +408,10 @@ pub struct ProjectSnapshotResponse {
     /// Project ID.
     pub project_id: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub created_at_unix: i64,
     /// Snapshot name.
+    #[serde(default)]
     pub name: String,
     /// Deprecated audio upload metadata.
     pub audio_upload: Option<serde_json::Value>,
@@ -400,16 +427,20 @@ pub struct ProjectSnapshotExtendedResponse {
     /// Project ID.
     pub project_id: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub created_at_unix: i64,
     /// Snapshot name.
+    #[serde(default)]
     pub name: String,
     /// Deprecated audio upload metadata.
     pub audio_upload: Option<serde_json::Value>,
     /// Deprecated zip upload metadata.
     pub zip_upload: Option<serde_json::Value>,
     /// Character alignment data.
+    #[serde(default)]
     pub character_alignments: Vec<serde_json::Value>,
     /// Total audio duration in seconds.
+    #[serde(default)]
     pub audio_duration_secs: f64,
 }
 
@@ -417,9 +448,25 @@ pub struct ProjectSnapshotExtendedResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProjectSnapshotsResponse {
     /// List of snapshots.
+    #[serde(default)]
     pub snapshots: Vec<ProjectSnapshotResponse>,
 }
 
+/// How [`StudioService::download_snapshot`](crate::services::StudioService::download_snapshot)
+/// writes a project snapshot to disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SplitMode {
+    /// The whole project as one MP3 file at the destination path.
+    #[default]
+    Single,
+    /// The snapshot's zip archive at the destination path.
+    Archive,
+    /// One MP3 file per chapter in the destination directory, named
+    /// `NN - <chapter name>.mp3` after the chapter's position in the
+    /// project. Chapters with muted tracks are skipped.
+    Chapters,
+}
+
 // ===========================================================================
 // Muted tracks
 // ===========================================================================
@@ -428,6 +475,7 @@ pub struct ProjectSnapshotsResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProjectMutedTracksResponse {
     /// Chapter IDs with muted tracks.
+    #[serde(default)]
     pub chapter_ids: Vec<String>,
 }
 
@@ -441,20 +489,28 @@ pub struct ProjectVoiceResponse {
     /// Voice ID.
     pub voice_id: String,
     /// Display alias.
+    #[serde(default)]
     pub alias: String,
     /// Stability setting.
+    #[serde(default)]
     pub stability: f64,
     /// Similarity boost setting.
+    #[serde(default)]
     pub similarity_boost: f64,
     /// Style exaggeration setting.
+    #[serde(default)]
     pub style: f64,
     /// Whether this voice is pinned in the project.
+    #[serde(default)]
     pub is_pinned: bool,
     /// Whether speaker boost is enabled.
+    #[serde(default)]
     pub use_speaker_boost: bool,
     /// Volume gain.
+    #[serde(default)]
     pub volume_gain: f64,
     /// Speed multiplier.
+    #[serde(default)]
     pub speed: f64,
 }
 
@@ -466,12 +522,16 @@ pub struct ProjectVoiceResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ChapterStatistics {
     /// Number of unconverted characters.
+    #[serde(default)]
     pub characters_unconverted: i64,
     /// Number of converted characters.
+    #[serde(default)]
     pub characters_converted: i64,
     /// Number of converted paragraphs.
+    #[serde(default)]
     pub paragraphs_converted: i64,
     /// Number of unconverted paragraphs.
+    #[serde(default)]
     pub paragraphs_unconverted: i64,
 }
 
@@ -481,12 +541,14 @@ pub struct ChapterResponse {
     /// Chapter ID.
     pub chapter_id: String,
     /// Chapter name.
+    #[serde(default)]
     pub name: String,
     /// Unix timestamp of last conversion.
     pub last_conversion_date_unix: Option<i64>,
     /// Conversion progress (0.0 to 1.0).
     pub conversion_progress: Option<f64>,
     /// Whether the chapter can be downloaded.
+    #[serde(default)]
     pub can_be_downloaded: bool,
     /// Current state.
     pub state: ProjectState,
@@ -506,12 +568,14 @@ pub struct ChapterWithContentResponse {
     /// Chapter ID.
     pub chapter_id: String,
     /// Chapter name.
+    #[serde(default)]
     pub name: String,
     /// Unix timestamp of last conversion.
     pub last_conversion_date_unix: Option<i64>,
     /// Conversion progress (0.0 to 1.0).
     pub conversion_progress: Option<f64>,
     /// Whether the chapter can be downloaded.
+    #[serde(default)]
     pub can_be_downloaded: bool,
     /// Current state.
     pub state: ProjectState,
@@ -524,6 +588,7 @@ pub struct ChapterWithContentResponse {
     /// Last conversion error, if any.
     pub last_conversion_error: Option<String>,
     /// The chapter content.
+    #[serde(default)]
     pub content: ChapterContentResponse,
 }
 
@@ -531,6 +596,7 @@ pub struct ChapterWithContentResponse {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GetChaptersResponse {
     /// List of chapters.
+    #[serde(default)]
     pub chapters: Vec<ChapterResponse>,
 }
 
@@ -552,6 +618,7 @@ pub struct EditChapterResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeleteChapterResponse {
     /// Status. "ok" on success.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -559,6 +626,7 @@ pub struct DeleteChapterResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConvertChapterResponse {
     /// Status. "ok" on success.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -576,8 +644,10 @@ pub struct ChapterSnapshotResponse {
     /// Chapter ID.
     pub chapter_id: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub created_at_unix: i64,
     /// Snapshot name.
+    #[serde(default)]
     pub name: String,
 }
 
@@ -591,10 +661,13 @@ pub struct ChapterSnapshotExtendedResponse {
     /// Chapter ID.
     pub chapter_id: String,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub created_at_unix: i64,
     /// Snapshot name.
+    #[serde(default)]
     pub name: String,
     /// Character alignment data.
+    #[serde(default)]
     pub character_alignments: Vec<serde_json::Value>,
 }
 
@@ -602,84 +675,172 @@ pub struct ChapterSnapshotExtendedResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ChapterSnapshotsResponse {
     /// List of chapter snapshots.
+    #[serde(default)]
     pub snapshots: Vec<ChapterSnapshotResponse>,
 }
 
 // ===========================================================================
-// Chapter content types (response)
+// Chapter content types
 // ===========================================================================
 
-/// TTS node within a chapter content block.
+/// A node within a chapter content block.
+///
+/// Only TTS nodes are defined today; any other node type the API returns is
+/// read as [`Other`](Self::Other) so new types do not break deserialization.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ChapterContentTtsNode {
-    /// Node type (always "tts_node").
-    #[serde(rename = "type")]
-    pub node_type: String,
-    /// Voice ID for this node.
-    pub voice_id: String,
-    /// Text content.
-    pub text: String,
+#[serde(tag = "type")]
+pub enum ChapterBlockSubNode {
+    /// Text spoken by one voice.
+    #[serde(rename = "tts_node")]
+    TtsNode {
+        /// Voice ID that speaks this text.
+        voice_id: String,
+        /// Text content.
+        #[serde(default)]
+        text: String,
+    },
+    /// A node type this SDK does not know about.
+    #[serde(rename = "_other", other)]
+    Other,
 }
 
-/// An extensible placeholder node for future content types.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ChapterContentExtendableNode {
-    /// Node type (always "_other").
-    #[serde(rename = "type")]
-    pub node_type: String,
-}
+impl ChapterBlockSubNode {
+    /// Creates a TTS node.
+    pub fn tts(voice_id: impl Into<String>, text: impl Into<String>) -> Self {
+        Self::TtsNode { voice_id: voice_id.into(), text: text.into() }
+    }
 
-/// A content block within a chapter.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ChapterContentBlockResponse {
-    /// Block ID.
-    pub block_id: String,
-    /// Nodes in this block (TTS nodes or extensible nodes).
-    /// Uses `serde_json::Value` because nodes are a polymorphic union.
-    pub nodes: Vec<serde_json::Value>,
+    /// Returns the voice ID of a TTS node.
+    pub fn voice_id(&self) -> Option<&str> {
+        match self {
+            Self::TtsNode { voice_id, .. } => Some(voice_id),
+            Self::Other => None,
+        }
+    }
+
+    /// Returns the text of a TTS node.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::TtsNode { text, .. } => Some(text),
+            Self::Other => None,
+        }
+    }
 }
 
-/// Chapter content containing blocks of TTS nodes.
+/// A paragraph or heading within a chapter.
+///
+/// The same type is returned in chapter responses and accepted in
+/// [`ChapterContentInput`], so fetched content can be edited and sent back.
+/// Leave `block_id` unset for new blocks; keep it to update an existing one.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ChapterContentResponse {
-    /// Content blocks.
-    pub blocks: Vec<ChapterContentBlockResponse>,
+pub struct ChapterBlock {
+    /// Existing block ID. Always set in responses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// Block sub-type (p, h1, h2, h3). Not returned by the API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_type: Option<BlockSubType>,
+    /// Nodes in this block.
+    #[serde(default)]
+    pub nodes: Vec<ChapterBlockSubNode>,
 }
 
-// ===========================================================================
-// Chapter content input types (Serialize only)
-// ===========================================================================
+impl ChapterBlock {
+    /// Creates a new block of `sub_type` with a single TTS node.
+    pub fn new(
+        sub_type: BlockSubType,
+        voice_id: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self {
+            block_id: None,
+            sub_type: Some(sub_type),
+            nodes: vec![ChapterBlockSubNode::tts(voice_id, text)],
+        }
+    }
 
-/// TTS node for chapter content input.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct ChapterContentTtsNodeInput {
-    /// Node type. Must be "tts_node".
-    #[serde(rename = "type")]
-    pub node_type: String,
-    /// Text content.
-    pub text: String,
-    /// Voice ID for this node.
-    pub voice_id: String,
+    /// Creates a new paragraph spoken by `voice_id`.
+    pub fn paragraph(voice_id: impl Into<String>, text: impl Into<String>) -> Self {
+        Self::new(BlockSubType::P, voice_id, text)
+    }
+
+    /// Creates a new heading spoken by `voice_id`.
+    pub fn heading(
+        level: BlockSubType,
+        voice_id: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self::new(level, voice_id, text)
+    }
+
+    /// Returns the voice shared by every TTS node, or `None` if the block has
+    /// no TTS nodes or mixes voices.
+    pub fn voice_id(&self) -> Option<&str> {
+        let mut voices = self.nodes.iter().filter_map(ChapterBlockSubNode::voice_id);
+        let first = voices.next()?;
+        voices.all(|voice| voice == first).then_some(first)
+    }
+
+    /// Assigns `voice_id` to every TTS node in the block.
+    pub fn with_voice(mut self, voice_id: &str) -> Self {
+        for node in &mut self.nodes {
+            if let ChapterBlockSubNode::TtsNode { voice_id: node_voice, .. } = node {
+                voice_id.clone_into(node_voice);
+            }
+        }
+        self
+    }
+
+    /// Returns the concatenated text of all TTS nodes.
+    pub fn text(&self) -> String {
+        self.nodes.iter().filter_map(ChapterBlockSubNode::text).collect()
+    }
 }
 
-/// A content block for chapter input.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct ChapterContentBlockInput {
-    /// Block sub-type (p, h1, h2, h3).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sub_type: Option<BlockSubType>,
-    /// TTS nodes within this block.
-    pub nodes: Vec<ChapterContentTtsNodeInput>,
-    /// Existing block ID (for updates).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub block_id: Option<String>,
+/// Chapter content as returned by the API.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChapterContentResponse {
+    /// Content blocks.
+    #[serde(default)]
+    pub blocks: Vec<ChapterBlock>,
 }
 
-/// Chapter content input for creating or updating chapter content.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Chapter content for creating or updating a chapter.
+///
+/// # Examples
+///
+/// ```
+/// use elevenlabs_sdk::types::{BlockSubType, ChapterBlock, ChapterContentInput};
+///
+/// let content = ChapterContentInput::new()
+///     .block(ChapterBlock::heading(BlockSubType::H1, "narrator", "Chapter One"))
+///     .block(ChapterBlock::paragraph("narrator", "It was a dark and stormy night."))
+///     .block(ChapterBlock::paragraph("villain", "\"Who goes there?\""));
+/// assert_eq!(content.blocks[2].voice_id(), Some("villain"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ChapterContentInput {
     /// Content blocks.
-    pub blocks: Vec<ChapterContentBlockInput>,
+    pub blocks: Vec<ChapterBlock>,
+}
+
+impl ChapterContentInput {
+    /// Creates empty content.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a block.
+    pub fn block(mut self, block: ChapterBlock) -> Self {
+        self.blocks.push(block);
+        self
+    }
+}
+
+impl From<ChapterContentResponse> for ChapterContentInput {
+    fn from(content: ChapterContentResponse) -> Self {
+        Self { blocks: content.blocks }
+    }
 }
 
 // ===========================================================================
@@ -702,16 +863,6 @@ pub struct PodcastConversationModeData {
     pub guest_voice_id: String,
 }
 
-/// Conversation podcast mode configuration.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct PodcastConversationMode {
-    /// Must be "conversation".
-    #[serde(rename = "type")]
-    pub mode_type: String,
-    /// Voice configuration for the conversation.
-    pub conversation: PodcastConversationModeData,
-}
-
 /// Bulletin mode voice data for a podcast.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct PodcastBulletinModeData {
@@ -719,34 +870,117 @@ pub struct PodcastBulletinModeData {
     pub host_voice_id: String,
 }
 
-/// Bulletin podcast mode configuration.
+/// Format of a generated podcast, sent as `mode`.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::PodcastMode;
+///
+/// let mode = PodcastMode::conversation("host_voice", "guest_voice");
+/// let json = serde_json::to_value(&mode).unwrap();
+/// assert_eq!(json["type"], "conversation");
+/// assert_eq!(json["conversation"]["guest_voice_id"], "guest_voice");
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct PodcastBulletinMode {
-    /// Must be "bulletin".
-    #[serde(rename = "type")]
-    pub mode_type: String,
-    /// Voice configuration for the bulletin.
-    pub bulletin: PodcastBulletinModeData,
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PodcastMode {
+    /// A conversation between a host and a guest.
+    Conversation {
+        /// Voices for the host and guest.
+        conversation: PodcastConversationModeData,
+    },
+    /// A single host reading a news-style bulletin.
+    Bulletin {
+        /// Voice for the host.
+        bulletin: PodcastBulletinModeData,
+    },
 }
 
-/// Text source for a podcast.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct PodcastTextSource {
-    /// Must be "text".
-    #[serde(rename = "type")]
-    pub source_type: String,
-    /// The text content to generate a podcast from.
-    pub text: String,
+impl PodcastMode {
+    /// A conversation between `host_voice_id` and `guest_voice_id`.
+    pub fn conversation(
+        host_voice_id: impl Into<String>,
+        guest_voice_id: impl Into<String>,
+    ) -> Self {
+        Self::Conversation {
+            conversation: PodcastConversationModeData {
+                host_voice_id: host_voice_id.into(),
+                guest_voice_id: guest_voice_id.into(),
+            },
+        }
+    }
+
+    /// A bulletin read by `host_voice_id`.
+    pub fn bulletin(host_voice_id: impl Into<String>) -> Self {
+        Self::Bulletin { bulletin: PodcastBulletinModeData { host_voice_id: host_voice_id.into() } }
+    }
 }
 
-/// URL source for a podcast.
+/// One document a podcast is generated from.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct PodcastUrlSource {
-    /// Must be "url".
-    #[serde(rename = "type")]
-    pub source_type: String,
-    /// The URL to generate a podcast from.
-    pub url: String,
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PodcastDocument {
+    /// Inline text.
+    Text {
+        /// The text content.
+        text: String,
+    },
+    /// A web page fetched by the API.
+    Url {
+        /// The page URL.
+        url: String,
+    },
+}
+
+/// Content a podcast is generated from, sent as `source`.
+///
+/// The API takes either a single document or a list; [`Url`](Self::Url) and
+/// [`Document`](Self::Document) send a list unless they hold exactly one
+/// entry.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::PodcastSource;
+///
+/// let json = serde_json::to_value(PodcastSource::Text("Today in AI...".into())).unwrap();
+/// assert_eq!(json, serde_json::json!({"type": "text", "text": "Today in AI..."}));
+///
+/// let urls = PodcastSource::Url(vec!["https://a.example".into(), "https://b.example".into()]);
+/// let json = serde_json::to_value(urls).unwrap();
+/// assert_eq!(json[1], serde_json::json!({"type": "url", "url": "https://b.example"}));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PodcastSource {
+    /// Inline text.
+    Text(String),
+    /// One or more web pages.
+    Url(Vec<String>),
+    /// Any mix of text and web pages.
+    Document(Vec<PodcastDocument>),
+}
+
+impl PodcastSource {
+    /// Returns the source as the list of documents it sends.
+    pub fn documents(&self) -> Vec<PodcastDocument> {
+        match self {
+            Self::Text(text) => vec![PodcastDocument::Text { text: text.clone() }],
+            Self::Url(urls) => {
+                urls.iter().map(|url| PodcastDocument::Url { url: url.clone() }).collect()
+            }
+            Self::Document(documents) => documents.clone(),
+        }
+    }
+}
+
+impl Serialize for PodcastSource {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.documents().as_slice() {
+            [single] => single.serialize(serializer),
+            documents => documents.serialize(serializer),
+        }
+    }
 }
 
 // ===========================================================================
@@ -759,10 +993,13 @@ pub struct PodcastUrlSource {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProjectVideoThumbnailSheet {
     /// Index of the first thumbnail on this sheet.
+    #[serde(default)]
     pub start_thumbnail_index: i64,
     /// Number of thumbnails on this sheet.
+    #[serde(default)]
     pub thumbnail_count: i64,
     /// Signed URL for the thumbnail sheet image.
+    #[serde(default)]
     pub signed_cloud_url: String,
 }
 
@@ -770,12 +1007,16 @@ pub struct ProjectVideoThumbnailSheet {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReadMetadataChapter {
     /// Chapter name.
+    #[serde(default)]
     pub chapter_name: String,
     /// Word count.
+    #[serde(default)]
     pub word_count: i64,
     /// Character count.
+    #[serde(default)]
     pub char_count: i64,
     /// Starting character offset in the full text.
+    #[serde(default)]
     pub starting_char_offset: i64,
     /// Whether HTML has been parsed.
     #[serde(default)]
@@ -936,7 +1177,7 @@ mod tests {
         }"#;
         let snap: ProjectSnapshotResponse = serde_json::from_str(json).unwrap();
         assert_eq!(snap.project_snapshot_id, "snap_1");
-        assert_eq!(snap.created_at_unix, 1714204800);
+        assert_eq!(snap.created_at_unix, 1_714_204_800);
     }
 
     // -- ProjectSnapshotsResponse -------------------------------------------
@@ -1060,7 +1301,7 @@ mod tests {
         let ch: ChapterWithContentResponse = serde_json::from_str(json).unwrap();
         assert_eq!(ch.chapter_id, "ch_1");
         assert_eq!(ch.content.blocks.len(), 1);
-        assert_eq!(ch.content.blocks[0].block_id, "b1");
+        assert_eq!(ch.content.blocks[0].block_id.as_deref(), Some("b1"));
     }
 
     // -- GetChaptersResponse ------------------------------------------------
@@ -1137,30 +1378,27 @@ mod tests {
                 "block_id": "b1",
                 "nodes": [
                     {"type": "tts_node", "voice_id": "v1", "text": "Hello"},
-                    {"type": "_other"}
+                    {"type": "_other"},
+                    {"type": "image_node", "url": "https://example.com/a.png"}
                 ]
             }]
         }"#;
         let content: ChapterContentResponse = serde_json::from_str(json).unwrap();
         assert_eq!(content.blocks.len(), 1);
-        assert_eq!(content.blocks[0].nodes.len(), 2);
+        let block = &content.blocks[0];
+        assert_eq!(block.block_id.as_deref(), Some("b1"));
+        assert_eq!(block.nodes.len(), 3);
+        assert_eq!(block.nodes[0], ChapterBlockSubNode::tts("v1", "Hello"));
+        assert_eq!(block.nodes[1], ChapterBlockSubNode::Other);
+        assert_eq!(block.nodes[2], ChapterBlockSubNode::Other);
+        assert_eq!(block.voice_id(), Some("v1"));
     }
 
     // -- ChapterContentInput (request) --------------------------------------
 
     #[test]
     fn chapter_content_input_serialize() {
-        let input = ChapterContentInput {
-            blocks: vec![ChapterContentBlockInput {
-                sub_type: Some(BlockSubType::P),
-                nodes: vec![ChapterContentTtsNodeInput {
-                    node_type: "tts_node".into(),
-                    text: "Hello world".into(),
-                    voice_id: "v1".into(),
-                }],
-                block_id: None,
-            }],
-        };
+        let input = ChapterContentInput::new().block(ChapterBlock::paragraph("v1", "Hello world"));
         let json = serde_json::to_string(&input).unwrap();
         assert!(json.contains("\"sub_type\":\"p\""));
         assert!(json.contains("\"type\":\"tts_node\""));
@@ -1168,17 +1406,64 @@ mod tests {
         assert!(!json.contains("block_id"));
     }
 
+    #[test]
+    fn chapter_content_input_round_trip() {
+        let input = ChapterContentInput::new()
+            .block(ChapterBlock::heading(BlockSubType::H2, "narrator", "Part I"))
+            .block(ChapterBlock {
+                block_id: Some("b7".into()),
+                sub_type: Some(BlockSubType::P),
+                nodes: vec![
+                    ChapterBlockSubNode::tts("narrator", "She said, "),
+                    ChapterBlockSubNode::tts("alice", "\"hello\"."),
+                ],
+            });
+        let json = serde_json::to_value(&input).unwrap();
+        assert_eq!(json["blocks"][0]["nodes"][0]["type"], "tts_node");
+        assert_eq!(json["blocks"][1]["block_id"], "b7");
+
+        let back: ChapterContentInput = serde_json::from_value(json).unwrap();
+        assert_eq!(back, input);
+    }
+
+    #[test]
+    fn chapter_block_voice_per_block() {
+        let mixed = ChapterBlock {
+            block_id: None,
+            sub_type: None,
+            nodes: vec![
+                ChapterBlockSubNode::tts("a", "One. "),
+                ChapterBlockSubNode::Other,
+                ChapterBlockSubNode::tts("b", "Two."),
+            ],
+        };
+        assert_eq!(mixed.voice_id(), None);
+        assert_eq!(mixed.text(), "One. Two.");
+
+        let unified = mixed.with_voice("c");
+        assert_eq!(unified.voice_id(), Some("c"));
+        assert_eq!(unified.nodes[1], ChapterBlockSubNode::Other);
+    }
+
+    #[test]
+    fn chapter_content_response_converts_to_input() {
+        let json = r#"{"blocks": [{"block_id": "b1", "nodes": [{"type": "tts_node", "voice_id": "v1", "text": "Hi"}]}]}"#;
+        let content: ChapterContentResponse = serde_json::from_str(json).unwrap();
+        let input = ChapterContentInput::from(content);
+        let sent = serde_json::to_value(&input).unwrap();
+        assert_eq!(
+            sent,
+            serde_json::json!({
+                "blocks": [{"block_id": "b1", "nodes": [{"type": "tts_node", "voice_id": "v1", "text": "Hi"}]}]
+            })
+        );
+    }
+
     // -- Podcast types ------------------------------------------------------
 
     #[test]
     fn podcast_conversation_mode_serialize() {
-        let mode = PodcastConversationMode {
-            mode_type: "conversation".into(),
-            conversation: PodcastConversationModeData {
-                host_voice_id: "host_v".into(),
-                guest_voice_id: "guest_v".into(),
-            },
-        };
+        let mode = PodcastMode::conversation("host_v", "guest_v");
         let json = serde_json::to_string(&mode).unwrap();
         assert!(json.contains("\"type\":\"conversation\""));
         assert!(json.contains("\"host_voice_id\":\"host_v\""));
@@ -1187,17 +1472,17 @@ mod tests {
 
     #[test]
     fn podcast_bulletin_mode_serialize() {
-        let mode = PodcastBulletinMode {
-            mode_type: "bulletin".into(),
-            bulletin: PodcastBulletinModeData { host_voice_id: "host_v".into() },
-        };
-        let json = serde_json::to_string(&mode).unwrap();
-        assert!(json.contains("\"type\":\"bulletin\""));
+        let mode = PodcastMode::bulletin("host_v");
+        let json = serde_json::to_value(&mode).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "bulletin", "bulletin": {"host_voice_id": "host_v"}})
+        );
     }
 
     #[test]
     fn podcast_text_source_serialize() {
-        let src = PodcastTextSource { source_type: "text".into(), text: "Hello podcast".into() };
+        let src = PodcastSource::Text("Hello podcast".into());
         let json = serde_json::to_string(&src).unwrap();
         assert!(json.contains("\"type\":\"text\""));
         assert!(json.contains("\"text\":\"Hello podcast\""));
@@ -1205,12 +1490,27 @@ mod tests {
 
     #[test]
     fn podcast_url_source_serialize() {
-        let src = PodcastUrlSource { source_type: "url".into(), url: "https://example.com".into() };
+        let src = PodcastSource::Url(vec!["https://example.com".into()]);
         let json = serde_json::to_string(&src).unwrap();
         assert!(json.contains("\"type\":\"url\""));
         assert!(json.contains("\"url\":\"https://example.com\""));
     }
 
+    #[test]
+    fn podcast_document_source_serializes_list() {
+        let src = PodcastSource::Document(vec![
+            PodcastDocument::Text { text: "Intro notes".into() },
+            PodcastDocument::Url { url: "https://example.com".into() },
+        ]);
+        assert_eq!(
+            serde_json::to_value(&src).unwrap(),
+            serde_json::json!([
+                {"type": "text", "text": "Intro notes"},
+                {"type": "url", "url": "https://example.com"}
+            ])
+        );
+    }
+
     // -- PodcastProjectResponse ---------------------------------------------
 
     #[test]
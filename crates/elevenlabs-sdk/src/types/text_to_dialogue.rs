@@ -8,13 +8,13 @@
 //!
 //! All four endpoints share the same request body shape.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use super::{
-    common::VoiceSettings,
-    text_to_speech::{
-        CharacterAlignment, PronunciationDictionaryVersionLocator, TextNormalization,
-    },
+    common::{TextNormalization, VoiceSettings},
+    text_to_speech::{CharacterAlignment, PronunciationDictionaryVersionLocator},
 };
 
 // ---------------------------------------------------------------------------
@@ -32,15 +32,22 @@ pub struct DialogueInput {
     pub voice_id: String,
 }
 
-/// A dialogue input line as returned by the API in history items.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct DialogueInputResponse {
-    /// The text of the dialogue input line.
-    pub text: String,
-    /// The ID of the voice used for this line.
-    pub voice_id: String,
-    /// The name of the voice used for this line.
-    pub voice_name: String,
+impl DialogueInput {
+    /// Creates a line spoken by `voice_id`.
+    pub fn new(text: impl Into<String>, voice_id: impl Into<String>) -> Self {
+        Self { text: text.into(), voice_id: voice_id.into() }
+    }
+}
+
+/// Error returned by [`TextToDialogueRequest::from_script`] when a line's
+/// speaker has no voice in the map.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("no voice assigned to speaker `{speaker}` (line {line})")]
+pub struct UnknownSpeakerError {
+    /// Name of the speaker without a voice.
+    pub speaker: String,
+    /// 1-based index of the first line spoken by `speaker`.
+    pub line: usize,
 }
 
 // ---------------------------------------------------------------------------
@@ -49,20 +56,20 @@ pub struct DialogueInputResponse {
 
 /// Request body for all four text-to-dialogue endpoints.
 ///
-/// Only `inputs` is required; all other fields are optional.
+/// Only `inputs` is required; all other fields are optional. The model,
+/// seed and settings apply to every line of the dialogue.
 ///
 /// # Example
 ///
 /// ```
 /// use elevenlabs_sdk::types::{DialogueInput, TextToDialogueRequest};
 ///
-/// let req = TextToDialogueRequest {
-///     inputs: vec![
-///         DialogueInput { text: "Hello!".into(), voice_id: "voice1".into() },
-///         DialogueInput { text: "Hi there!".into(), voice_id: "voice2".into() },
-///     ],
-///     ..Default::default()
-/// };
+/// let req = TextToDialogueRequest::new(vec![
+///     DialogueInput::new("Hello!", "voice1"),
+///     DialogueInput::new("Hi there!", "voice2"),
+/// ])
+/// .model_id("eleven_v3")
+/// .seed(42);
 /// assert_eq!(req.inputs.len(), 2);
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
@@ -95,6 +102,93 @@ pub struct TextToDialogueRequest {
     pub apply_text_normalization: Option<TextNormalization>,
 }
 
+impl TextToDialogueRequest {
+    /// Creates a request for `inputs` with every other field unset.
+    pub fn new(inputs: Vec<DialogueInput>) -> Self {
+        Self { inputs, ..Default::default() }
+    }
+
+    /// Builds a request from a script of `(speaker, text)` lines, looking up
+    /// each speaker's voice ID in `voices`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownSpeakerError`] for the first line whose speaker is
+    /// not in `voices`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use elevenlabs_sdk::types::TextToDialogueRequest;
+    ///
+    /// let script = "ALICE: Did you hear that?\nBOB: Just the wind.";
+    /// let voices = HashMap::from([
+    ///     ("ALICE".to_owned(), "voice-alice".to_owned()),
+    ///     ("BOB".to_owned(), "voice-bob".to_owned()),
+    /// ]);
+    ///
+    /// let lines = script.lines().filter_map(|line| line.split_once(':'));
+    /// let req = TextToDialogueRequest::from_script(lines, &voices)?;
+    /// assert_eq!(req.inputs[1].voice_id, "voice-bob");
+    /// assert_eq!(req.inputs[1].text, "Just the wind.");
+    /// # Ok::<(), elevenlabs_sdk::types::UnknownSpeakerError>(())
+    /// ```
+    pub fn from_script<I, S, T>(
+        lines: I,
+        voices: &HashMap<String, String>,
+    ) -> Result<Self, UnknownSpeakerError>
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: AsRef<str>,
+        T: AsRef<str>,
+    {
+        let inputs = lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, (speaker, text))| {
+                let speaker = speaker.as_ref().trim();
+                voices
+                    .get(speaker)
+                    .map(|voice_id| DialogueInput::new(text.as_ref().trim(), voice_id.as_str()))
+                    .ok_or_else(|| UnknownSpeakerError { speaker: speaker.to_owned(), line: i + 1 })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(inputs))
+    }
+
+    /// Appends a line spoken by `voice_id`.
+    pub fn line(mut self, text: impl Into<String>, voice_id: impl Into<String>) -> Self {
+        self.inputs.push(DialogueInput::new(text, voice_id));
+        self
+    }
+
+    /// Sets the model used for every line.
+    pub fn model_id(mut self, model_id: impl Into<String>) -> Self {
+        self.model_id = Some(model_id.into());
+        self
+    }
+
+    /// Sets the language code enforced for every line.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+
+    /// Sets the voice settings used for every line.
+    pub const fn settings(mut self, settings: VoiceSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Sets the seed for best-effort deterministic generation.
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.seed = Some(i64::from(seed));
+        self
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Voice Segment
 // ---------------------------------------------------------------------------
@@ -108,14 +202,19 @@ pub struct VoiceSegment {
     /// The voice ID used for this segment.
     pub voice_id: String,
     /// Start time of this voice segment in seconds.
+    #[serde(default)]
     pub start_time_seconds: f64,
     /// End time of this voice segment in seconds.
+    #[serde(default)]
     pub end_time_seconds: f64,
     /// Start index in the characters array.
+    #[serde(default)]
     pub character_start_index: i64,
     /// End index in the characters array (exclusive).
+    #[serde(default)]
     pub character_end_index: i64,
     /// Line of the dialogue (script) that this segment is part of.
+    #[serde(default)]
     pub dialogue_input_index: i64,
 }
 
@@ -130,12 +229,14 @@ pub struct VoiceSegment {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioWithTimestampsAndVoiceSegmentsResponse {
     /// Base64-encoded audio data.
+    #[serde(default)]
     pub audio_base64: String,
     /// Character-level alignment for the original text.
     pub alignment: Option<CharacterAlignment>,
     /// Character-level alignment for the normalized text.
     pub normalized_alignment: Option<CharacterAlignment>,
     /// Voice segments describing which voice produced each portion.
+    #[serde(default)]
     pub voice_segments: Vec<VoiceSegment>,
 }
 
@@ -147,12 +248,14 @@ pub struct AudioWithTimestampsAndVoiceSegmentsResponse {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamingAudioChunkWithTimestampsAndVoiceSegments {
     /// Base64-encoded audio data for this chunk.
+    #[serde(default)]
     pub audio_base64: String,
     /// Character-level alignment for the original text in this chunk.
     pub alignment: Option<CharacterAlignment>,
     /// Character-level alignment for the normalized text in this chunk.
     pub normalized_alignment: Option<CharacterAlignment>,
     /// Voice segments for this chunk.
+    #[serde(default)]
     pub voice_segments: Vec<VoiceSegment>,
 }
 
@@ -173,18 +276,6 @@ mod tests {
         assert!(json.contains("\"voice_id\":\"v1\""));
     }
 
-    #[test]
-    fn dialogue_input_response_deserialize() {
-        let json = r#"{
-            "text": "Hello!",
-            "voice_id": "v1",
-            "voice_name": "Rachel"
-        }"#;
-        let r: DialogueInputResponse = serde_json::from_str(json).unwrap();
-        assert_eq!(r.text, "Hello!");
-        assert_eq!(r.voice_name, "Rachel");
-    }
-
     #[test]
     fn text_to_dialogue_request_serialize() {
         let req = TextToDialogueRequest {
@@ -201,6 +292,57 @@ mod tests {
         assert!(!json.contains("language_code"));
     }
 
+    #[test]
+    fn text_to_dialogue_request_builder_serialize() {
+        let req = TextToDialogueRequest::new(vec![DialogueInput::new("Hello", "v1")])
+            .line("Hi", "v2")
+            .model_id("eleven_v3")
+            .language_code("en")
+            .seed(7);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            json["inputs"],
+            serde_json::json!([
+                {"text": "Hello", "voice_id": "v1"},
+                {"text": "Hi", "voice_id": "v2"}
+            ])
+        );
+        assert_eq!(json["model_id"], "eleven_v3");
+        assert_eq!(json["language_code"], "en");
+        assert_eq!(json["seed"], 7);
+    }
+
+    #[test]
+    fn from_script_assigns_voices_by_speaker() {
+        let voices = HashMap::from([
+            ("Alice".to_owned(), "v-alice".to_owned()),
+            ("Bob".to_owned(), "v-bob".to_owned()),
+        ]);
+        let req = TextToDialogueRequest::from_script(
+            [("Alice", " Hi Bob. "), (" Bob", "Hi."), ("Alice", "Bye.")],
+            &voices,
+        )
+        .unwrap();
+        assert_eq!(
+            req.inputs,
+            vec![
+                DialogueInput::new("Hi Bob.", "v-alice"),
+                DialogueInput::new("Hi.", "v-bob"),
+                DialogueInput::new("Bye.", "v-alice"),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_script_reports_unknown_speaker() {
+        let voices = HashMap::from([("Alice".to_owned(), "v-alice".to_owned())]);
+        let err =
+            TextToDialogueRequest::from_script([("Alice", "Hi."), ("Carol", "Hey.")], &voices)
+                .unwrap_err();
+        assert_eq!(err, UnknownSpeakerError { speaker: "Carol".into(), line: 2 });
+        assert_eq!(err.to_string(), "no voice assigned to speaker `Carol` (line 2)");
+    }
+
     #[test]
     fn text_to_dialogue_request_omits_none_fields() {
         let req = TextToDialogueRequest { inputs: vec![], ..Default::default() };
@@ -8,31 +8,24 @@
 //!
 //! All four endpoints share the same request body shape; only the response
 //! differs (audio bytes vs. JSON with alignment data).
+//!
+//! [`CostEstimator`] forecasts the credits a batch of requests will use
+//! before it is sent.
 
-use serde::{Deserialize, Serialize};
-
-use super::common::VoiceSettings;
+use std::{
+    collections::{BTreeMap, HashMap},
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
 
-// ---------------------------------------------------------------------------
-// Text Normalization
-// ---------------------------------------------------------------------------
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
 
-/// Controls how text normalization is applied before synthesis.
-///
-/// When set to `Auto` (the default), the system automatically decides whether
-/// to apply text normalization (e.g., spelling out numbers). `On` forces
-/// normalization on every request; `Off` skips it entirely.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum TextNormalization {
-    /// System decides automatically.
-    #[default]
-    Auto,
-    /// Always apply text normalization.
-    On,
-    /// Never apply text normalization.
-    Off,
-}
+use super::{
+    common::{Model, TextNormalization, VoiceSettings},
+    voices::VoicePreset,
+};
+use crate::error::{ElevenLabsError, Result};
 
 // ---------------------------------------------------------------------------
 // Pronunciation Dictionary Locator
@@ -131,6 +124,11 @@ pub struct TextToSpeechRequest {
     /// latency. Currently only supported for Japanese.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub apply_language_text_normalization: Option<bool>,
+
+    /// Uses the instant-clone version of a professional voice clone, which
+    /// has lower latency. Only meaningful for PVC voices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_pvc_as_ivc: Option<bool>,
 }
 
 impl TextToSpeechRequest {
@@ -151,8 +149,40 @@ impl TextToSpeechRequest {
             next_request_ids: None,
             apply_text_normalization: None,
             apply_language_text_normalization: None,
+            use_pvc_as_ivc: None,
         }
     }
+
+    /// Sets the ISO 639-1 language code enforced for the model and text
+    /// normalization.
+    pub fn language_code(mut self, language_code: impl Into<String>) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+
+    /// Sets the seed for best-effort deterministic generation.
+    pub const fn seed(mut self, seed: u32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the text normalization mode.
+    pub const fn apply_text_normalization(mut self, mode: TextNormalization) -> Self {
+        self.apply_text_normalization = Some(mode);
+        self
+    }
+
+    /// Uses the instant-clone version of a professional voice clone.
+    pub const fn use_pvc_as_ivc(mut self, enabled: bool) -> Self {
+        self.use_pvc_as_ivc = Some(enabled);
+        self
+    }
+
+    /// Sets the voice settings from a built-in [`VoicePreset`].
+    pub const fn with_preset(mut self, preset: VoicePreset) -> Self {
+        self.voice_settings = Some(preset.settings());
+        self
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -166,10 +196,13 @@ impl TextToSpeechRequest {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CharacterAlignment {
     /// Individual characters (may include spaces, punctuation, etc.).
+    #[serde(default)]
     pub characters: Vec<String>,
     /// Start time in seconds for each character.
+    #[serde(default)]
     pub character_start_times_seconds: Vec<f64>,
     /// End time in seconds for each character.
+    #[serde(default)]
     pub character_end_times_seconds: Vec<f64>,
 }
 
@@ -199,6 +232,7 @@ pub struct CharacterAlignment {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioWithTimestampsResponse {
     /// Base64-encoded audio data.
+    #[serde(default)]
     pub audio_base64: String,
     /// Character-level alignment for the original text.
     pub alignment: Option<CharacterAlignment>,
@@ -206,6 +240,24 @@ pub struct AudioWithTimestampsResponse {
     pub normalized_alignment: Option<CharacterAlignment>,
 }
 
+/// Audio returned by
+/// [`TextToSpeechService::convert_with_metadata`](crate::services::TextToSpeechService::convert_with_metadata),
+/// together with the response headers that identify it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextToSpeechAudio {
+    /// The generated audio.
+    pub audio: Bytes,
+    /// ID of the request, from the `request-id` header. Quote it when
+    /// contacting support.
+    pub request_id: Option<String>,
+    /// ID of the history item created for this generation, from the
+    /// `history-item-id` header. `None` when history is disabled for the
+    /// request.
+    pub history_item_id: Option<String>,
+    /// Characters billed for the request, from the `character-cost` header.
+    pub character_cost: Option<u64>,
+}
+
 /// A single chunk from `POST /v1/text-to-speech/{voice_id}/stream/with-timestamps`.
 ///
 /// The streaming-with-timestamps endpoint delivers multiple chunks, each
@@ -213,6 +265,7 @@ pub struct AudioWithTimestampsResponse {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamingAudioChunkWithTimestamps {
     /// Base64-encoded audio data for this chunk.
+    #[serde(default)]
     pub audio_base64: String,
     /// Character-level alignment for the original text in this chunk.
     pub alignment: Option<CharacterAlignment>,
@@ -220,6 +273,359 @@ pub struct StreamingAudioChunkWithTimestamps {
     pub normalized_alignment: Option<CharacterAlignment>,
 }
 
+/// A decoded chunk yielded by
+/// [`TextToSpeechService::convert_stream_with_timestamps`](crate::services::TextToSpeechService::convert_stream_with_timestamps).
+///
+/// Alignment times are measured from the start of the stream, not the
+/// start of the chunk, so they can be compared directly against the
+/// playback position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimestampedAudioChunk {
+    /// Audio bytes for this chunk, already base64-decoded.
+    pub audio: Bytes,
+    /// Alignment of the original text spoken in this chunk.
+    pub alignment: Option<CharacterAlignment>,
+    /// Alignment of the normalized text spoken in this chunk.
+    pub normalized_alignment: Option<CharacterAlignment>,
+}
+
+impl TimestampedAudioChunk {
+    /// Returns the original text spoken in this chunk, or an empty string
+    /// when the chunk carries no alignment.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.alignment.as_ref().map(|a| a.characters.concat()).unwrap_or_default()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Word events
+// ---------------------------------------------------------------------------
+
+/// A word and the span of audio in which it is spoken.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordSpoken {
+    /// The word, without surrounding whitespace.
+    #[serde(default)]
+    pub word: String,
+    /// Offset from the start of the audio at which the word begins.
+    #[serde(default)]
+    pub start_ms: u64,
+    /// Offset from the start of the audio at which the word ends.
+    #[serde(default)]
+    pub end_ms: u64,
+}
+
+/// An event from
+/// [`convert_stream_with_words`](crate::services::TextToSpeechService::convert_stream_with_words).
+///
+/// Each decoded audio chunk is followed by the words whose last character
+/// falls within it, so a player can highlight a word once playback reaches
+/// its `start_ms`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TtsStreamEvent {
+    /// A chunk of decoded audio.
+    Audio(Bytes),
+    /// A word whose audio has been fully emitted.
+    WordSpoken(WordSpoken),
+}
+
+/// Groups character-level alignment into words across streamed chunks.
+///
+/// A word may span several chunks, so it is only emitted once a following
+/// whitespace character (or [`finish`](Self::finish)) closes it.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{CharacterAlignment, WordAligner};
+///
+/// let chunk = |chars: &[&str], start: f64| CharacterAlignment {
+///     characters: chars.iter().map(|c| (*c).to_owned()).collect(),
+///     character_start_times_seconds: (0..chars.len())
+///         .map(|i| (i as f64).mul_add(0.1, start))
+///         .collect(),
+///     character_end_times_seconds: (1..=chars.len())
+///         .map(|i| (i as f64).mul_add(0.1, start))
+///         .collect(),
+/// };
+///
+/// let mut aligner = WordAligner::new();
+/// assert!(aligner.push(&chunk(&["H", "i"], 0.0)).is_empty());
+/// let words = aligner.push(&chunk(&[" ", "y", "o"], 0.2));
+/// assert_eq!(words[0].word, "Hi");
+/// assert_eq!((words[0].start_ms, words[0].end_ms), (0, 200));
+/// assert_eq!(aligner.finish().unwrap().word, "yo");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WordAligner {
+    word: String,
+    start_ms: u64,
+    end_ms: u64,
+}
+
+impl WordAligner {
+    /// Creates an aligner with no pending word.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { word: String::new(), start_ms: 0, end_ms: 0 }
+    }
+
+    /// Consumes the next chunk of alignment and returns the words it
+    /// completes.
+    pub fn push(&mut self, alignment: &CharacterAlignment) -> Vec<WordSpoken> {
+        let mut words = Vec::new();
+        let timed = alignment
+            .characters
+            .iter()
+            .zip(&alignment.character_start_times_seconds)
+            .zip(&alignment.character_end_times_seconds);
+        for ((character, &start), &end) in timed {
+            if character.chars().all(char::is_whitespace) {
+                words.extend(self.finish());
+                continue;
+            }
+            if self.word.is_empty() {
+                self.start_ms = seconds_to_ms(start);
+            }
+            self.word.push_str(character);
+            self.end_ms = seconds_to_ms(end);
+        }
+        words
+    }
+
+    /// Returns the pending word, if any, once no more alignment will arrive.
+    pub fn finish(&mut self) -> Option<WordSpoken> {
+        if self.word.is_empty() {
+            return None;
+        }
+        Some(WordSpoken {
+            word: std::mem::take(&mut self.word),
+            start_ms: self.start_ms,
+            end_ms: self.end_ms,
+        })
+    }
+}
+
+impl CharacterAlignment {
+    /// Groups the characters into words with millisecond timings.
+    #[must_use]
+    pub fn words(&self) -> Vec<WordSpoken> {
+        let mut aligner = WordAligner::new();
+        let mut words = aligner.push(self);
+        words.extend(aligner.finish());
+        words
+    }
+}
+
+/// Converts an alignment timestamp to whole milliseconds.
+fn seconds_to_ms(seconds: f64) -> u64 {
+    (seconds * 1000.0).round() as u64
+}
+
+// ---------------------------------------------------------------------------
+// Cost estimation
+// ---------------------------------------------------------------------------
+
+/// Model the API uses when a request does not name one.
+pub const DEFAULT_TTS_MODEL_ID: &str = "eleven_multilingual_v2";
+
+/// Forecast character count and credit spend of one or more requests.
+///
+/// An estimate only: the API's own count can differ slightly, and the
+/// actual charge is reported in [`TextToSpeechAudio::character_cost`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CostEstimate {
+    /// Billable characters of the request text.
+    pub characters: u64,
+    /// Credits the characters cost with the model's multiplier applied,
+    /// rounded up per request.
+    pub credits: u64,
+}
+
+impl Add for CostEstimate {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            characters: self.characters + other.characters,
+            credits: self.credits + other.credits,
+        }
+    }
+}
+
+impl AddAssign for CostEstimate {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sum for CostEstimate {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl TextToSpeechRequest {
+    /// Number of characters of `text` that are billed.
+    ///
+    /// Leading and trailing whitespace is not counted and each run of
+    /// whitespace counts as a single character. `previous_text` and
+    /// `next_text` are context only and not billed.
+    pub fn billable_characters(&self) -> u64 {
+        let mut count = 0_u64;
+        let mut in_space = false;
+        for c in self.text.trim().chars() {
+            if c.is_whitespace() {
+                if in_space {
+                    continue;
+                }
+                in_space = true;
+            } else {
+                in_space = false;
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Estimates what this request costs with `model`, whatever the
+    /// request's own `model_id`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elevenlabs_sdk::types::{Model, TextToSpeechRequest};
+    ///
+    /// let flash: Model = serde_json::from_value(serde_json::json!({
+    ///     "model_id": "eleven_flash_v2_5",
+    ///     "model_rates": { "character_cost_multiplier": 0.5 }
+    /// }))
+    /// .unwrap();
+    /// let estimate = TextToSpeechRequest::new("Hello,   world!").estimate_cost(&flash);
+    /// assert_eq!(estimate.characters, 13);
+    /// assert_eq!(estimate.credits, 7);
+    /// ```
+    pub fn estimate_cost(&self, model: &Model) -> CostEstimate {
+        let characters = self.billable_characters();
+        CostEstimate {
+            characters,
+            credits: credits(characters, model.model_rates.character_cost_multiplier),
+        }
+    }
+}
+
+/// Credits for `characters` at `multiplier`, rounded up.
+fn credits(characters: u64, multiplier: f64) -> u64 {
+    (characters as f64 * multiplier).ceil() as u64
+}
+
+/// Aggregates cost estimates across a batch of requests, per model.
+///
+/// Built from the model list returned by
+/// [`ModelsService::list`](crate::services::ModelsService::list), so the
+/// current multipliers are used. Requests without a `model_id` are priced
+/// with [`DEFAULT_TTS_MODEL_ID`] unless
+/// [`default_model`](Self::default_model) says otherwise.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{CostEstimator, Model, TextToSpeechRequest};
+///
+/// let models: Vec<Model> = serde_json::from_value(serde_json::json!([
+///     { "model_id": "eleven_multilingual_v2" },
+///     { "model_id": "eleven_flash_v2_5", "model_rates": { "character_cost_multiplier": 0.5 } }
+/// ]))
+/// .unwrap();
+///
+/// let mut estimator = CostEstimator::new(&models);
+/// estimator.add(&TextToSpeechRequest::new("Chapter one."))?;
+/// let mut fast = TextToSpeechRequest::new("Chapter two.");
+/// fast.model_id = Some("eleven_flash_v2_5".to_owned());
+/// estimator.add(&fast)?;
+///
+/// assert_eq!(estimator.total().characters, 24);
+/// assert_eq!(estimator.total().credits, 18);
+/// assert_eq!(estimator.by_model()["eleven_flash_v2_5"].credits, 6);
+/// # Ok::<(), elevenlabs_sdk::ElevenLabsError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct CostEstimator {
+    multipliers: HashMap<String, f64>,
+    default_model_id: String,
+    by_model: BTreeMap<String, CostEstimate>,
+    requests: usize,
+}
+
+impl CostEstimator {
+    /// Creates an estimator pricing requests with `models`.
+    pub fn new<'a>(models: impl IntoIterator<Item = &'a Model>) -> Self {
+        Self {
+            multipliers: models
+                .into_iter()
+                .map(|m| (m.model_id.clone(), m.model_rates.character_cost_multiplier))
+                .collect(),
+            default_model_id: DEFAULT_TTS_MODEL_ID.to_owned(),
+            by_model: BTreeMap::new(),
+            requests: 0,
+        }
+    }
+
+    /// Prices requests without a `model_id` with `model_id`.
+    pub fn default_model(mut self, model_id: impl Into<String>) -> Self {
+        self.default_model_id = model_id.into();
+        self
+    }
+
+    /// Estimates `request` and adds it to the totals.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the request's model is not
+    /// in the model list; nothing is added in that case.
+    pub fn add(&mut self, request: &TextToSpeechRequest) -> Result<CostEstimate> {
+        let model_id = request.model_id.as_deref().unwrap_or(&self.default_model_id);
+        let multiplier = self.multipliers.get(model_id).copied().ok_or_else(|| {
+            ElevenLabsError::Validation(format!("no pricing for unknown model {model_id:?}"))
+        })?;
+        let characters = request.billable_characters();
+        let estimate = CostEstimate { characters, credits: credits(characters, multiplier) };
+        *self.by_model.entry(model_id.to_owned()).or_default() += estimate;
+        self.requests += 1;
+        Ok(estimate)
+    }
+
+    /// Estimates every request in `requests` and adds them to the totals,
+    /// returning their combined estimate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] at the first request whose
+    /// model is not in the model list; the requests before it stay added.
+    pub fn add_all<'a>(
+        &mut self,
+        requests: impl IntoIterator<Item = &'a TextToSpeechRequest>,
+    ) -> Result<CostEstimate> {
+        requests.into_iter().map(|request| self.add(request)).sum()
+    }
+
+    /// Combined estimate of every request added so far.
+    pub fn total(&self) -> CostEstimate {
+        self.by_model.values().copied().sum()
+    }
+
+    /// Estimates added so far, keyed by model ID.
+    pub const fn by_model(&self) -> &BTreeMap<String, CostEstimate> {
+        &self.by_model
+    }
+
+    /// Number of requests added so far.
+    pub const fn requests(&self) -> usize {
+        self.requests
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -229,6 +635,65 @@ pub struct StreamingAudioChunkWithTimestamps {
 mod tests {
     use super::*;
 
+    // -- Cost estimation ----------------------------------------------------
+
+    fn priced_model(id: &str, multiplier: f64) -> Model {
+        serde_json::from_value(serde_json::json!({
+            "model_id": id,
+            "model_rates": {"character_cost_multiplier": multiplier}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn billable_characters_collapse_whitespace() {
+        assert_eq!(TextToSpeechRequest::new("  Hello \n\n world  ").billable_characters(), 11);
+        assert_eq!(TextToSpeechRequest::new("こんにちは").billable_characters(), 5);
+        assert_eq!(TextToSpeechRequest::new(" \t ").billable_characters(), 0);
+
+        let mut req = TextToSpeechRequest::new("Hi");
+        req.previous_text = Some("Context that is not billed.".into());
+        assert_eq!(req.billable_characters(), 2);
+    }
+
+    #[test]
+    fn estimate_cost_applies_multiplier_and_rounds_up() {
+        let req = TextToSpeechRequest::new("abc");
+        assert_eq!(
+            req.estimate_cost(&priced_model("flash", 0.5)),
+            CostEstimate { characters: 3, credits: 2 }
+        );
+        assert_eq!(req.estimate_cost(&priced_model("v3", 2.0)).credits, 6);
+    }
+
+    #[test]
+    fn cost_estimator_aggregates_per_model() {
+        let models = [priced_model("eleven_multilingual_v2", 1.0), priced_model("flash", 0.5)];
+        let mut estimator = CostEstimator::new(&models);
+
+        let mut flash = TextToSpeechRequest::new("abcd");
+        flash.model_id = Some("flash".into());
+        let batch = [TextToSpeechRequest::new("abc"), flash.clone(), flash];
+        let combined = estimator.add_all(&batch).unwrap();
+
+        assert_eq!(combined, CostEstimate { characters: 11, credits: 7 });
+        assert_eq!(estimator.total(), combined);
+        assert_eq!(estimator.requests(), 3);
+        assert_eq!(estimator.by_model()["flash"], CostEstimate { characters: 8, credits: 4 });
+    }
+
+    #[test]
+    fn cost_estimator_rejects_unknown_models() {
+        let models = [priced_model("flash", 0.5)];
+        let mut estimator = CostEstimator::new(&models);
+        let err = estimator.add(&TextToSpeechRequest::new("abc")).unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+        assert_eq!(estimator.requests(), 0);
+
+        let mut estimator = CostEstimator::new(&models).default_model("flash");
+        assert_eq!(estimator.add(&TextToSpeechRequest::new("abc")).unwrap().credits, 2);
+    }
+
     // -- TextNormalization ---------------------------------------------------
 
     #[test]
@@ -327,6 +792,7 @@ mod tests {
             next_request_ids: Some(vec!["req3".into()]),
             apply_text_normalization: Some(TextNormalization::Auto),
             apply_language_text_normalization: Some(false),
+            use_pvc_as_ivc: Some(true),
         };
         let json = serde_json::to_string_pretty(&req).unwrap();
         // Verify key fields are present.
@@ -341,6 +807,7 @@ mod tests {
         assert!(json.contains("\"next_request_ids\""));
         assert!(json.contains("\"apply_text_normalization\""));
         assert!(json.contains("\"apply_language_text_normalization\""));
+        assert!(json.contains("\"use_pvc_as_ivc\""));
 
         // Verify the JSON deserializes as a valid object.
         let v: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -350,6 +817,32 @@ mod tests {
         assert_eq!(v["apply_text_normalization"], "auto");
     }
 
+    #[test]
+    fn tts_request_builders_set_typed_fields() {
+        let req = TextToSpeechRequest::new("Dr. Smith paid $5.")
+            .language_code("en")
+            .seed(42)
+            .apply_text_normalization(TextNormalization::On)
+            .use_pvc_as_ivc(false);
+        let v = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            v,
+            serde_json::json!({
+                "text": "Dr. Smith paid $5.",
+                "language_code": "en",
+                "seed": 42,
+                "apply_text_normalization": "on",
+                "use_pvc_as_ivc": false
+            })
+        );
+    }
+
+    #[test]
+    fn tts_request_with_preset_sets_voice_settings() {
+        let req = TextToSpeechRequest::new("Once upon a time").with_preset(VoicePreset::Narration);
+        assert_eq!(req.voice_settings, Some(VoicePreset::Narration.settings()));
+    }
+
     // -- CharacterAlignment --------------------------------------------------
 
     #[test]
@@ -366,6 +859,47 @@ mod tests {
         assert!((alignment.character_end_times_seconds[4] - 0.5).abs() < f64::EPSILON);
     }
 
+    // -- Word alignment ------------------------------------------------------
+
+    fn alignment(chars: &str, start: f64) -> CharacterAlignment {
+        CharacterAlignment {
+            characters: chars.chars().map(String::from).collect(),
+            character_start_times_seconds: (0..chars.len())
+                .map(|i| (i as f64).mul_add(0.1, start))
+                .collect(),
+            character_end_times_seconds: (1..=chars.len())
+                .map(|i| (i as f64).mul_add(0.1, start))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn character_alignment_words() {
+        let words = alignment("Hi  there.", 0.0).words();
+        assert_eq!(
+            words,
+            [
+                WordSpoken { word: "Hi".into(), start_ms: 0, end_ms: 200 },
+                WordSpoken { word: "there.".into(), start_ms: 400, end_ms: 1000 },
+            ]
+        );
+        assert!(alignment("   ", 0.0).words().is_empty());
+    }
+
+    #[test]
+    fn word_aligner_joins_words_across_chunks() {
+        let mut aligner = WordAligner::new();
+        assert!(aligner.push(&alignment("Hel", 0.0)).is_empty());
+        let words = aligner.push(&alignment("lo wor", 0.3));
+        assert_eq!(words, [WordSpoken { word: "Hello".into(), start_ms: 0, end_ms: 500 }]);
+        assert!(aligner.push(&alignment("ld", 0.9)).is_empty());
+        assert_eq!(
+            aligner.finish(),
+            Some(WordSpoken { word: "world".into(), start_ms: 600, end_ms: 1100 })
+        );
+        assert_eq!(aligner.finish(), None);
+    }
+
     // -- AudioWithTimestampsResponse -----------------------------------------
 
     #[test]
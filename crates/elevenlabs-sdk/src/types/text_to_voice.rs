@@ -9,8 +9,16 @@
 
 use std::collections::HashMap;
 
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
+/// Shortest preview text the voice design endpoints accept, in characters.
+pub const MIN_PREVIEW_TEXT_CHARS: usize = 100;
+
+/// Longest preview text the voice design endpoints accept, in characters.
+pub const MAX_PREVIEW_TEXT_CHARS: usize = 1000;
+
 // ---------------------------------------------------------------------------
 // Voice Design Model
 // ---------------------------------------------------------------------------
@@ -39,8 +47,9 @@ pub struct VoicePreviewsRequest {
     /// A text description of the desired voice characteristics.
     pub voice_description: String,
 
-    /// Optional text to speak in the preview. If omitted, text is
-    /// auto-generated.
+    /// Optional text to speak in the preview
+    /// ([`MIN_PREVIEW_TEXT_CHARS`]–[`MAX_PREVIEW_TEXT_CHARS`] characters).
+    /// If omitted, text is auto-generated.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
@@ -82,7 +91,8 @@ pub struct VoiceDesignRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub model_id: Option<VoiceDesignModel>,
 
-    /// Optional text to speak in the preview.
+    /// Optional text to speak in the preview
+    /// ([`MIN_PREVIEW_TEXT_CHARS`]–[`MAX_PREVIEW_TEXT_CHARS`] characters).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
@@ -198,30 +208,71 @@ pub struct VoiceRemixRequest {
 // Responses
 // ---------------------------------------------------------------------------
 
-/// A single voice preview returned by the API.
+/// A single voice preview returned by the voice design endpoints.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::VoiceDesignPreview;
+///
+/// let json = r#"{"audio_base_64": "SGVsbG8=", "generated_voice_id": "gen1"}"#;
+/// let preview: VoiceDesignPreview = serde_json::from_str(json).unwrap();
+/// assert_eq!(preview.audio().unwrap().as_ref(), b"Hello");
+/// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct VoicePreviewResponse {
+pub struct VoiceDesignPreview {
     /// Base64-encoded audio data of the preview.
+    #[serde(default)]
     pub audio_base_64: String,
     /// Generated voice ID that can be used to create a permanent voice.
     pub generated_voice_id: String,
     /// Media type of the audio (e.g. `"audio/mpeg"`).
+    #[serde(default)]
     pub media_type: String,
     /// Duration of the preview in seconds.
+    #[serde(default)]
     pub duration_secs: f64,
     /// Language of the generated voice.
-    pub language: serde_json::Value,
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
+impl VoiceDesignPreview {
+    /// Decodes the base64 preview audio.
+    ///
+    /// Returns empty bytes when the previews were requested with
+    /// `stream_previews`, in which case the audio is fetched with
+    /// [`TextToVoiceService::stream_preview`](crate::services::TextToVoiceService::stream_preview).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audio is not valid base64.
+    pub fn audio(&self) -> Result<Bytes, base64::DecodeError> {
+        STANDARD.decode(&self.audio_base_64).map(Bytes::from)
+    }
+}
+
+/// Former name of [`VoiceDesignPreview`].
+pub type VoicePreviewResponse = VoiceDesignPreview;
+
 /// Response from `POST /v1/text-to-voice/create-previews`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VoicePreviewsResponse {
     /// The generated voice previews.
-    pub previews: Vec<VoicePreviewResponse>,
+    #[serde(default)]
+    pub previews: Vec<VoiceDesignPreview>,
     /// The text used to preview the voices.
+    #[serde(default)]
     pub text: String,
 }
 
+impl VoicePreviewsResponse {
+    /// Returns the preview with the given generated voice ID.
+    pub fn preview(&self, generated_voice_id: &str) -> Option<&VoiceDesignPreview> {
+        self.previews.iter().find(|p| p.generated_voice_id == generated_voice_id)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -292,9 +343,26 @@ mod tests {
         let resp: VoicePreviewsResponse = serde_json::from_str(json).unwrap();
         assert_eq!(resp.previews.len(), 1);
         assert_eq!(resp.previews[0].generated_voice_id, "gen1");
+        assert_eq!(resp.previews[0].language.as_deref(), Some("en"));
         assert_eq!(resp.text, "Hello world");
     }
 
+    #[test]
+    fn voice_design_preview_decodes_audio() {
+        let json = r#"{
+            "previews": [
+                {"audio_base_64": "SGVsbG8=", "generated_voice_id": "gen1"},
+                {"audio_base_64": "", "generated_voice_id": "gen2"},
+                {"audio_base_64": "not base64!", "generated_voice_id": "gen3"}
+            ]
+        }"#;
+        let resp: VoicePreviewsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.preview("gen1").unwrap().audio().unwrap().as_ref(), b"Hello");
+        assert!(resp.preview("gen2").unwrap().audio().unwrap().is_empty());
+        assert!(resp.preview("gen3").unwrap().audio().is_err());
+        assert!(resp.preview("missing").is_none());
+    }
+
     #[test]
     fn voice_design_request_serialize() {
         let req = VoiceDesignRequest {
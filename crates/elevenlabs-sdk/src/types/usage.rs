@@ -0,0 +1,260 @@
+//! Types for the ElevenLabs usage analytics endpoints.
+//!
+//! Covers:
+//! - `GET /v1/usage/character-stats` — character usage as a time series, optionally broken down by
+//!   voice, model, API key and so on
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+// ---------------------------------------------------------------------------
+// Query
+// ---------------------------------------------------------------------------
+
+/// Dimension to split character usage by.
+///
+/// Each breakdown value becomes its own series in [`UsageTimeSeries`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum UsageBreakdown {
+    /// A single series with the total usage.
+    None,
+    /// One series per voice.
+    Voice,
+    /// One series per model.
+    Model,
+    /// One series per API key.
+    #[serde(rename = "api_keys")]
+    ApiKey,
+    /// One series per workspace member.
+    User,
+    /// One series per product (TTS, dubbing, ...).
+    ProductType,
+}
+
+impl fmt::Display for UsageBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Voice => f.write_str("voice"),
+            Self::Model => f.write_str("model"),
+            Self::ApiKey => f.write_str("api_keys"),
+            Self::User => f.write_str("user"),
+            Self::ProductType => f.write_str("product_type"),
+        }
+    }
+}
+
+/// Width of each time bucket in a [`UsageTimeSeries`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum UsageInterval {
+    /// One bucket per hour.
+    Hour,
+    /// One bucket per day.
+    Day,
+    /// One bucket per week.
+    Week,
+    /// One bucket per month.
+    Month,
+    /// A single bucket covering the whole range.
+    Cumulative,
+}
+
+impl fmt::Display for UsageInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hour => f.write_str("hour"),
+            Self::Day => f.write_str("day"),
+            Self::Week => f.write_str("week"),
+            Self::Month => f.write_str("month"),
+            Self::Cumulative => f.write_str("cumulative"),
+        }
+    }
+}
+
+/// Query for `GET /v1/usage/character-stats`.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{CharacterStatsQuery, UsageBreakdown, UsageInterval};
+///
+/// let query = CharacterStatsQuery::new(1_700_000_000_000, 1_702_592_000_000)
+///     .breakdown(UsageBreakdown::Voice)
+///     .interval(UsageInterval::Day);
+/// assert_eq!(query.breakdown, Some(UsageBreakdown::Voice));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharacterStatsQuery {
+    /// Start of the range as a Unix timestamp in milliseconds.
+    pub start_unix: i64,
+    /// End of the range as a Unix timestamp in milliseconds.
+    pub end_unix: i64,
+    /// Include usage from the whole workspace rather than only the caller.
+    pub include_workspace_metrics: bool,
+    /// Dimension to split usage by.
+    pub breakdown: Option<UsageBreakdown>,
+    /// Width of each time bucket.
+    pub interval: Option<UsageInterval>,
+    /// Custom bucket width in seconds. Takes precedence over `interval` on
+    /// the server.
+    pub bucket_size_secs: Option<u32>,
+}
+
+impl CharacterStatsQuery {
+    /// Creates a query for the given time range.
+    pub const fn new(start_unix: i64, end_unix: i64) -> Self {
+        Self {
+            start_unix,
+            end_unix,
+            include_workspace_metrics: false,
+            breakdown: None,
+            interval: None,
+            bucket_size_secs: None,
+        }
+    }
+
+    /// Includes usage from the whole workspace.
+    pub const fn include_workspace_metrics(mut self, include: bool) -> Self {
+        self.include_workspace_metrics = include;
+        self
+    }
+
+    /// Splits usage by `breakdown`.
+    pub const fn breakdown(mut self, breakdown: UsageBreakdown) -> Self {
+        self.breakdown = Some(breakdown);
+        self
+    }
+
+    /// Sets the width of each time bucket.
+    pub const fn interval(mut self, interval: UsageInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Sets a custom bucket width in seconds.
+    pub const fn bucket_size_secs(mut self, secs: u32) -> Self {
+        self.bucket_size_secs = Some(secs);
+        self
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Response
+// ---------------------------------------------------------------------------
+
+/// Character usage over time, returned by
+/// [`UsageService::character_stats`](crate::services::UsageService::character_stats).
+///
+/// Every series in `usage` has one value per entry in `time`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct UsageTimeSeries {
+    /// Start of each bucket as a Unix timestamp in milliseconds.
+    #[serde(default)]
+    pub time: Vec<i64>,
+    /// Usage per series, keyed by breakdown value (voice ID, model ID, ...)
+    /// or `"All"` without a breakdown.
+    #[serde(default)]
+    pub usage: BTreeMap<String, Vec<f64>>,
+}
+
+impl UsageTimeSeries {
+    /// Returns the series names in sorted order.
+    pub fn series_names(&self) -> impl Iterator<Item = &str> {
+        self.usage.keys().map(String::as_str)
+    }
+
+    /// Returns `(timestamp, value)` pairs for `series`, or `None` if there is
+    /// no such series.
+    pub fn points(&self, series: &str) -> Option<impl Iterator<Item = (i64, f64)> + '_> {
+        let values = self.usage.get(series)?;
+        Some(self.time.iter().copied().zip(values.iter().copied()))
+    }
+
+    /// Returns the sum of `series` over the whole range, or `None` if there
+    /// is no such series.
+    pub fn total(&self, series: &str) -> Option<f64> {
+        self.usage.get(series).map(|values| values.iter().sum())
+    }
+
+    /// Returns the sum of every series over the whole range.
+    pub fn totals(&self) -> BTreeMap<&str, f64> {
+        self.usage.iter().map(|(name, values)| (name.as_str(), values.iter().sum())).collect()
+    }
+
+    /// Returns the sum of all series over the whole range.
+    pub fn grand_total(&self) -> f64 {
+        self.usage.values().flatten().sum()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakdown_display_matches_serde() {
+        for breakdown in [
+            UsageBreakdown::None,
+            UsageBreakdown::Voice,
+            UsageBreakdown::Model,
+            UsageBreakdown::ApiKey,
+            UsageBreakdown::User,
+            UsageBreakdown::ProductType,
+        ] {
+            let json = serde_json::to_string(&breakdown).unwrap();
+            assert_eq!(json.trim_matches('"'), breakdown.to_string());
+        }
+    }
+
+    #[test]
+    fn interval_display_matches_serde() {
+        for interval in [
+            UsageInterval::Hour,
+            UsageInterval::Day,
+            UsageInterval::Week,
+            UsageInterval::Month,
+            UsageInterval::Cumulative,
+        ] {
+            let json = serde_json::to_string(&interval).unwrap();
+            assert_eq!(json.trim_matches('"'), interval.to_string());
+        }
+    }
+
+    #[test]
+    fn time_series_aggregates() {
+        let series: UsageTimeSeries = serde_json::from_str(
+            r#"{
+                "time": [1700000000, 1700086400, 1700172800],
+                "usage": {"voice_a": [100, 50, 0], "voice_b": [10.5, 0, 4.5]}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(series.series_names().collect::<Vec<_>>(), ["voice_a", "voice_b"]);
+        assert_eq!(series.total("voice_a"), Some(150.0));
+        assert_eq!(series.total("missing"), None);
+        assert_eq!(series.totals().get("voice_b"), Some(&15.0));
+        assert!((series.grand_total() - 165.0).abs() < f64::EPSILON);
+
+        let points: Vec<_> = series.points("voice_a").unwrap().collect();
+        assert_eq!(points, [(1_700_000_000, 100.0), (1_700_086_400, 50.0), (1_700_172_800, 0.0)]);
+    }
+
+    #[test]
+    fn time_series_defaults_when_empty() {
+        let series: UsageTimeSeries = serde_json::from_str("{}").unwrap();
+        assert!(series.time.is_empty());
+        assert!(series.points("All").is_none());
+        assert!(series.grand_total().abs() < f64::EPSILON);
+    }
+}
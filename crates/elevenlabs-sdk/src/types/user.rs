@@ -17,21 +17,26 @@ use super::common::Subscription;
 ///
 /// Contains user profile information and subscription details.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct UserResponse {
     /// Unique user identifier.
     pub user_id: String,
     /// The user's subscription details.
     pub subscription: Subscription,
     /// Whether this is a new user (deprecated — use `created_at` instead).
+    #[serde(default)]
     pub is_new_user: bool,
     /// The user's API key (only returned on certain requests).
     #[serde(default)]
     pub xi_api_key: Option<String>,
     /// Whether the user can use delayed payment methods (deprecated).
+    #[serde(default)]
     pub can_use_delayed_payment_methods: bool,
     /// Whether onboarding has been completed.
+    #[serde(default)]
     pub is_onboarding_completed: bool,
     /// Whether the onboarding checklist has been completed.
+    #[serde(default)]
     pub is_onboarding_checklist_completed: bool,
     /// Whether to show compliance terms during onboarding.
     #[serde(default)]
@@ -52,6 +57,7 @@ pub struct UserResponse {
     #[serde(default)]
     pub partnerstack_partner_default_link: Option<String>,
     /// Unix timestamp of user creation.
+    #[serde(default)]
     pub created_at: i64,
 }
 
@@ -67,33 +73,46 @@ pub struct UserResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExtendedSubscriptionResponse {
     /// Subscription tier name (e.g. `"trial"`, `"creator"`).
+    #[serde(default)]
     pub tier: String,
     /// Number of characters used in the current billing period.
+    #[serde(default)]
     pub character_count: i64,
     /// Maximum characters allowed in the current billing period.
+    #[serde(default)]
     pub character_limit: i64,
     /// Whether the user can extend their character limit.
+    #[serde(default)]
     pub can_extend_character_limit: bool,
     /// Whether the user is allowed to extend their character limit.
+    #[serde(default)]
     pub allowed_to_extend_character_limit: bool,
     /// Unix timestamp of next character count reset.
     #[serde(default)]
     pub next_character_count_reset_unix: Option<i64>,
     /// Number of voice slots in use.
+    #[serde(default)]
     pub voice_slots_used: i64,
     /// Number of professional voice slots in use.
+    #[serde(default)]
     pub professional_voice_slots_used: i64,
     /// Maximum number of voice slots allowed.
+    #[serde(default)]
     pub voice_limit: i64,
     /// Number of voice add/edit operations performed.
+    #[serde(default)]
     pub voice_add_edit_counter: i64,
     /// Maximum number of professional voices allowed.
+    #[serde(default)]
     pub professional_voice_limit: i64,
     /// Whether the user can extend their voice limit.
+    #[serde(default)]
     pub can_extend_voice_limit: bool,
     /// Whether the user can use instant voice cloning.
+    #[serde(default)]
     pub can_use_instant_voice_cloning: bool,
     /// Whether the user can use professional voice cloning.
+    #[serde(default)]
     pub can_use_professional_voice_cloning: bool,
     /// Current subscription status.
     #[serde(default)]
@@ -112,6 +131,59 @@ pub struct ExtendedSubscriptionResponse {
     pub pending_change: Option<serde_json::Value>,
 }
 
+impl ExtendedSubscriptionResponse {
+    /// Returns the characters left in the current billing period and when
+    /// the count resets.
+    pub fn remaining_quota(&self) -> RemainingQuota {
+        RemainingQuota {
+            character_count: self.character_count,
+            character_limit: self.character_limit,
+            characters_remaining: (self.character_limit - self.character_count).max(0),
+            next_reset_unix: self.next_character_count_reset_unix,
+            can_extend: self.can_extend_character_limit && self.allowed_to_extend_character_limit,
+        }
+    }
+}
+
+/// Character quota for the current billing period.
+///
+/// Returned by [`UserService::remaining_quota`](crate::services::UserService::remaining_quota).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemainingQuota {
+    /// Characters used so far.
+    pub character_count: i64,
+    /// Characters allowed in the period.
+    pub character_limit: i64,
+    /// Characters left, never negative.
+    pub characters_remaining: i64,
+    /// Unix timestamp (seconds) at which the count resets, if known.
+    pub next_reset_unix: Option<i64>,
+    /// Whether usage past the limit is billed instead of refused.
+    pub can_extend: bool,
+}
+
+impl RemainingQuota {
+    /// Returns `true` if no characters are left.
+    pub const fn is_exhausted(&self) -> bool {
+        self.characters_remaining == 0
+    }
+
+    /// Returns the fraction of the limit used, from `0.0` upwards. Returns
+    /// `1.0` when the limit is zero.
+    pub fn fraction_used(&self) -> f64 {
+        if self.character_limit <= 0 {
+            return 1.0;
+        }
+        self.character_count as f64 / self.character_limit as f64
+    }
+
+    /// Returns the seconds until the count resets, measured from `now_unix`.
+    /// Returns `None` if the reset time is unknown and zero if it has passed.
+    pub fn seconds_until_reset(&self, now_unix: i64) -> Option<i64> {
+        self.next_reset_unix.map(|reset| (reset - now_unix).max(0))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Usage / Character Stats
 // ---------------------------------------------------------------------------
@@ -122,9 +194,11 @@ pub struct ExtendedSubscriptionResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UsageCharactersResponse {
     /// Unix timestamps for each data point.
+    #[serde(default)]
     pub time: Vec<i64>,
     /// Usage breakdown by category. Keys are metric names, values are
     /// arrays of counts aligned to the `time` vector.
+    #[serde(default)]
     pub usage: serde_json::Value,
 }
 
@@ -167,7 +241,7 @@ mod tests {
         assert_eq!(user.user_id, "user123");
         assert!(!user.is_new_user);
         assert!(user.is_onboarding_completed);
-        assert_eq!(user.created_at, 1700000000);
+        assert_eq!(user.created_at, 1_700_000_000);
     }
 
     #[test]
@@ -203,4 +277,33 @@ mod tests {
         assert_eq!(user.xi_api_key, Some("xi_key_123".into()));
         assert_eq!(user.first_name, Some("John".into()));
     }
+
+    #[test]
+    fn remaining_quota_from_subscription() {
+        let sub: ExtendedSubscriptionResponse = serde_json::from_str(
+            r#"{
+                "character_count": 7500,
+                "character_limit": 10000,
+                "next_character_count_reset_unix": 1700086400
+            }"#,
+        )
+        .unwrap();
+        let quota = sub.remaining_quota();
+        assert_eq!(quota.characters_remaining, 2500);
+        assert!(!quota.is_exhausted());
+        assert!((quota.fraction_used() - 0.75).abs() < f64::EPSILON);
+        assert_eq!(quota.seconds_until_reset(1_700_000_000), Some(86_400));
+        assert_eq!(quota.seconds_until_reset(1_800_000_000), Some(0));
+    }
+
+    #[test]
+    fn remaining_quota_never_negative() {
+        let sub: ExtendedSubscriptionResponse =
+            serde_json::from_str(r#"{"character_count": 12000, "character_limit": 10000}"#)
+                .unwrap();
+        let quota = sub.remaining_quota();
+        assert_eq!(quota.characters_remaining, 0);
+        assert!(quota.is_exhausted());
+        assert_eq!(quota.seconds_until_reset(0), None);
+    }
 }
@@ -2,11 +2,12 @@
 //!
 //! Covers voice generation and design operations:
 //! - `GET /v1/voice-generation/generate-voice/parameters` — list generation parameters
-//! - `POST /v1/voice-generation/generate-voice` — generate a random voice
+//! - `POST /v1/voice-generation/generate-voice` — generate a random voice preview
 //! - `POST /v1/voice-generation/create-voice` — create a voice from a generated preview
 
 use std::collections::HashMap;
 
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -20,8 +21,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VoiceGenerationParameterOption {
     /// Human-readable name (e.g. `"Female"`, `"British"`).
+    #[serde(default)]
     pub name: String,
     /// Machine-readable code (e.g. `"female"`, `"british"`).
+    #[serde(default)]
     pub code: String,
 }
 
@@ -32,18 +35,25 @@ pub struct VoiceGenerationParameterOption {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VoiceGenerationParameters {
     /// Available gender options.
+    #[serde(default)]
     pub genders: Vec<VoiceGenerationParameterOption>,
     /// Available accent options.
+    #[serde(default)]
     pub accents: Vec<VoiceGenerationParameterOption>,
     /// Available age options.
+    #[serde(default)]
     pub ages: Vec<VoiceGenerationParameterOption>,
     /// Minimum number of characters in the generation text.
+    #[serde(default)]
     pub minimum_characters: i64,
     /// Maximum number of characters in the generation text.
+    #[serde(default)]
     pub maximum_characters: i64,
     /// Minimum accent strength value.
+    #[serde(default)]
     pub minimum_accent_strength: f64,
     /// Maximum accent strength value.
+    #[serde(default)]
     pub maximum_accent_strength: f64,
 }
 
@@ -86,13 +96,14 @@ pub enum GenerateVoiceAge {
 ///     GenerateRandomVoiceRequest, GenerateVoiceAge, GenerateVoiceGender,
 /// };
 ///
-/// let req = GenerateRandomVoiceRequest {
-///     gender: GenerateVoiceGender::Female,
-///     accent: "british".into(),
-///     age: GenerateVoiceAge::Young,
-///     accent_strength: 1.0,
-///     text: "Every act of kindness carries value and can make a difference.".repeat(2),
-/// };
+/// let req = GenerateRandomVoiceRequest::new(
+///     GenerateVoiceGender::Female,
+///     "british",
+///     GenerateVoiceAge::Young,
+///     "Every act of kindness carries value and can make a difference.".repeat(2),
+/// )
+/// .accent_strength(1.4);
+/// assert_eq!(req.accent_strength, 1.4);
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GenerateRandomVoiceRequest {
@@ -108,6 +119,47 @@ pub struct GenerateRandomVoiceRequest {
     pub text: String,
 }
 
+impl GenerateRandomVoiceRequest {
+    /// Minimum length of [`text`](Self::text), in characters.
+    pub const MIN_TEXT_CHARS: usize = 100;
+    /// Maximum length of [`text`](Self::text), in characters.
+    pub const MAX_TEXT_CHARS: usize = 1000;
+    /// Smallest allowed [`accent_strength`](Self::accent_strength).
+    pub const MIN_ACCENT_STRENGTH: f64 = 0.3;
+    /// Largest allowed [`accent_strength`](Self::accent_strength).
+    pub const MAX_ACCENT_STRENGTH: f64 = 2.0;
+
+    /// Creates a request with an accent strength of `1.0`.
+    pub fn new(
+        gender: GenerateVoiceGender,
+        accent: impl Into<String>,
+        age: GenerateVoiceAge,
+        text: impl Into<String>,
+    ) -> Self {
+        Self { gender, accent: accent.into(), age, accent_strength: 1.0, text: text.into() }
+    }
+
+    /// Sets the accent strength.
+    pub const fn accent_strength(mut self, accent_strength: f64) -> Self {
+        self.accent_strength = accent_strength;
+        self
+    }
+}
+
+/// A generated voice preview returned by
+/// [`VoiceGenerationService::generate_preview`](crate::services::VoiceGenerationService::generate_preview).
+///
+/// Keep the preview to save the voice later with
+/// [`create_from_preview`](crate::services::VoiceGenerationService::create_from_preview).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoicePreview {
+    /// ID of the generated voice, from the `generated_voice_id` response
+    /// header.
+    pub generated_voice_id: String,
+    /// Preview audio (MP3).
+    pub audio: Bytes,
+}
+
 // ---------------------------------------------------------------------------
 // Create Voice from Generated Preview (request)
 // ---------------------------------------------------------------------------
@@ -207,6 +259,18 @@ mod tests {
         assert_eq!(serde_json::to_string(&GenerateVoiceAge::Old).unwrap(), "\"old\"");
     }
 
+    #[test]
+    fn generate_random_voice_request_builder_defaults() {
+        let req = GenerateRandomVoiceRequest::new(
+            GenerateVoiceGender::Male,
+            "american",
+            GenerateVoiceAge::Old,
+            "x".repeat(100),
+        );
+        assert_eq!(req.accent, "american");
+        assert!((req.accent_strength - 1.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn create_generated_voice_request_serialize() {
         let req = CreateGeneratedVoiceRequest {
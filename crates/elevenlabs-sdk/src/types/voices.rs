@@ -14,7 +14,7 @@
 //! Related sub-resources (samples, fine-tuning, sharing, verification) are
 //! included as embedded response types.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
@@ -49,14 +49,19 @@ pub enum FineTuningState {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FineTuning {
     /// Whether the user is allowed to fine-tune this voice.
+    #[serde(default)]
     pub is_allowed_to_fine_tune: bool,
     /// Per-model fine-tuning state (e.g. `{"eleven_multilingual_v2": "fine_tuned"}`).
+    #[serde(default)]
     pub state: HashMap<String, FineTuningState>,
     /// List of verification failure descriptions.
+    #[serde(default)]
     pub verification_failures: Vec<String>,
     /// Number of verification attempts made.
+    #[serde(default)]
     pub verification_attempts_count: i64,
     /// Whether manual verification was requested.
+    #[serde(default)]
     pub manual_verification_requested: bool,
     /// Language of the fine-tuning process.
     pub language: Option<String>,
@@ -88,12 +93,16 @@ pub struct VoiceSample {
     /// Unique sample identifier.
     pub sample_id: String,
     /// Original filename of the uploaded sample.
+    #[serde(default)]
     pub file_name: String,
     /// MIME type (e.g. `"audio/mpeg"`).
+    #[serde(default)]
     pub mime_type: String,
     /// File size in bytes.
+    #[serde(default)]
     pub size_bytes: i64,
     /// Content hash.
+    #[serde(default)]
     pub hash: String,
     /// Duration in seconds.
     pub duration_secs: Option<f64>,
@@ -121,12 +130,16 @@ pub struct Recording {
     /// Unique recording identifier.
     pub recording_id: String,
     /// MIME type (e.g. `"audio/mpeg"`).
+    #[serde(default)]
     pub mime_type: String,
     /// Recording size in bytes.
+    #[serde(default)]
     pub size_bytes: i64,
     /// Unix timestamp of the upload date.
+    #[serde(default)]
     pub upload_date_unix: i64,
     /// Transcription of the recording content.
+    #[serde(default)]
     pub transcription: String,
 }
 
@@ -134,14 +147,19 @@ pub struct Recording {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VerificationAttempt {
     /// The text that was read for verification.
+    #[serde(default)]
     pub text: String,
     /// Unix timestamp of the attempt.
+    #[serde(default)]
     pub date_unix: i64,
     /// Whether the attempt was accepted.
+    #[serde(default)]
     pub accepted: bool,
     /// Similarity score of the attempt.
+    #[serde(default)]
     pub similarity: f64,
     /// Levenshtein distance of the transcription.
+    #[serde(default)]
     pub levenshtein_distance: f64,
     /// Recording submitted for this attempt.
     pub recording: Option<Recording>,
@@ -151,12 +169,16 @@ pub struct VerificationAttempt {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VoiceVerification {
     /// Whether the voice requires verification before use.
+    #[serde(default)]
     pub requires_verification: bool,
     /// Whether the voice has been verified.
+    #[serde(default)]
     pub is_verified: bool,
     /// List of verification failure descriptions.
+    #[serde(default)]
     pub verification_failures: Vec<String>,
     /// Total number of verification attempts.
+    #[serde(default)]
     pub verification_attempts_count: i64,
     /// Language used for verification.
     pub language: Option<String>,
@@ -251,32 +273,41 @@ pub struct VoiceSharing {
     /// History item sample ID used for sharing preview.
     pub history_item_sample_id: Option<String>,
     /// Unix timestamp when sharing was enabled.
+    #[serde(default)]
     pub date_unix: i64,
     /// Emails allowed to use this shared voice.
+    #[serde(default)]
     pub whitelisted_emails: Vec<String>,
     /// Public owner identifier.
     pub public_owner_id: String,
     /// ID of the original voice this was shared from.
     pub original_voice_id: String,
     /// Whether financial rewards are enabled for this voice.
+    #[serde(default)]
     pub financial_rewards_enabled: bool,
     /// Whether free-tier users can access this voice.
+    #[serde(default)]
     pub free_users_allowed: bool,
     /// Whether live moderation is enabled.
+    #[serde(default)]
     pub live_moderation_enabled: bool,
     /// Revenue rate for the voice.
     pub rate: Option<f64>,
     /// Fiat rate (USD per 1000 credits).
     pub fiat_rate: Option<f64>,
     /// Notice period in days before disabling.
+    #[serde(default)]
     pub notice_period: i64,
     /// Unix timestamp when the voice will be disabled.
     pub disable_at_unix: Option<i64>,
     /// Whether voice mixing is allowed.
+    #[serde(default)]
     pub voice_mixing_allowed: bool,
     /// Whether the voice is featured in the library.
+    #[serde(default)]
     pub featured: bool,
     /// Voice category in the library (e.g. `"professional"`).
+    #[serde(default)]
     pub category: String,
     /// Whether the reader app is enabled.
     pub reader_app_enabled: Option<bool>,
@@ -285,20 +316,25 @@ pub struct VoiceSharing {
     /// Reason the voice was banned (if applicable).
     pub ban_reason: Option<String>,
     /// Number of users who liked this voice.
+    #[serde(default)]
     pub liked_by_count: i64,
     /// Number of users who cloned this voice.
+    #[serde(default)]
     pub cloned_by_count: i64,
     /// Display name of the shared voice.
+    #[serde(default)]
     pub name: String,
     /// Description of the shared voice.
     pub description: Option<String>,
     /// Labels associated with the voice.
+    #[serde(default)]
     pub labels: HashMap<String, String>,
     /// Current review status.
     pub review_status: ReviewStatus,
     /// Message from the reviewer.
     pub review_message: Option<String>,
     /// Whether the voice is enabled in the library.
+    #[serde(default)]
     pub enabled_in_library: bool,
     /// Instagram username of the voice owner.
     pub instagram_username: Option<String>,
@@ -341,18 +377,23 @@ pub struct VoiceSharing {
 /// assert_eq!(voice.name, "Rachel");
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct Voice {
     /// Unique voice identifier.
     pub voice_id: String,
     /// Display name of the voice.
+    #[serde(default)]
     pub name: String,
     /// Voice category.
     pub category: VoiceCategory,
     /// Key-value labels (e.g. `{"accent": "American", "gender": "female"}`).
+    #[serde(default)]
     pub labels: HashMap<String, String>,
     /// Subscription tiers this voice is available for.
+    #[serde(default)]
     pub available_for_tiers: Vec<String>,
     /// Base model IDs that support high-quality output with this voice.
+    #[serde(default)]
     pub high_quality_base_model_ids: Vec<String>,
     /// Audio samples associated with this voice.
     pub samples: Option<Vec<VoiceSample>>,
@@ -390,6 +431,173 @@ pub struct Voice {
     pub created_at_unix: Option<i64>,
 }
 
+impl Voice {
+    /// Returns the languages this voice speaks, merged from its
+    /// `verified_languages`, its fine-tuning state and its `language` label.
+    ///
+    /// Verified languages come first, in the order the API lists them, with
+    /// one entry per language and locale. A fine-tuned language that is not
+    /// verified, and a labelled language that appears nowhere else, are
+    /// appended. Voices with no language data return an empty list.
+    #[must_use]
+    pub fn languages(&self) -> Vec<VoiceLanguage> {
+        let mut languages: Vec<VoiceLanguage> = Vec::new();
+        for verified in self.verified_languages.iter().flatten() {
+            let language = primary_language(&verified.language);
+            let locale = verified.locale.as_deref().map(normalize_locale);
+            let existing =
+                languages.iter().position(|l| l.language == language && l.locale == locale);
+            let i = existing.unwrap_or_else(|| {
+                languages.push(VoiceLanguage { language, locale, ..VoiceLanguage::default() });
+                languages.len() - 1
+            });
+            let entry = &mut languages[i];
+            if !entry.verified_model_ids.contains(&verified.model_id) {
+                entry.verified_model_ids.push(verified.model_id.clone());
+            }
+            entry.accent = entry.accent.take().or_else(|| verified.accent.clone());
+            entry.preview_url = entry.preview_url.take().or_else(|| verified.preview_url.clone());
+        }
+
+        if let Some(fine_tuning) = &self.fine_tuning &&
+            let Some(language) = fine_tuning.language.as_deref().map(primary_language)
+        {
+            let mut models: Vec<String> = fine_tuning
+                .state
+                .iter()
+                .filter(|(_, state)| **state == FineTuningState::FineTuned)
+                .map(|(model_id, _)| model_id.clone())
+                .collect();
+            models.sort();
+            if !models.is_empty() {
+                if !languages.iter().any(|l| l.language == language) {
+                    languages.push(VoiceLanguage {
+                        language: language.clone(),
+                        ..VoiceLanguage::default()
+                    });
+                }
+                for entry in languages.iter_mut().filter(|l| l.language == language) {
+                    entry.fine_tuned_model_ids.clone_from(&models);
+                }
+            }
+        }
+
+        if let Some(language) =
+            self.labels.get("language").map(String::as_str).map(primary_language) &&
+            !language.is_empty() &&
+            !languages.iter().any(|l| l.language == language)
+        {
+            languages.push(VoiceLanguage {
+                language,
+                accent: self.labels.get("accent").cloned(),
+                ..VoiceLanguage::default()
+            });
+        }
+        languages
+    }
+
+    /// Returns how this voice speaks `code`, if it does.
+    ///
+    /// See [`VoiceLanguage::matches`] for how codes are compared. When
+    /// several locales match, the first from [`languages`](Self::languages)
+    /// is returned.
+    #[must_use]
+    pub fn language(&self, code: &str) -> Option<VoiceLanguage> {
+        self.languages().into_iter().find(|language| language.matches(code))
+    }
+}
+
+/// A language spoken by a [`Voice`], merged from the places the API reports
+/// it. Returned by [`Voice::languages`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoiceLanguage {
+    /// Lowercase language code without a region (e.g. `"en"`).
+    pub language: String,
+    /// Locale with a region, normalized to `language-REGION` (e.g.
+    /// `"en-US"`).
+    pub locale: Option<String>,
+    /// Accent descriptor (e.g. `"american"`).
+    pub accent: Option<String>,
+    /// Models the voice is verified for in this language.
+    #[serde(default)]
+    pub verified_model_ids: Vec<String>,
+    /// Models the voice is fine-tuned for in this language.
+    #[serde(default)]
+    pub fine_tuned_model_ids: Vec<String>,
+    /// URL of a preview clip in this language.
+    pub preview_url: Option<String>,
+}
+
+impl VoiceLanguage {
+    /// Returns `true` if ElevenLabs has verified the voice in this language
+    /// for at least one model. Languages known only from labels or
+    /// fine-tuning are unverified.
+    pub const fn is_verified(&self) -> bool {
+        !self.verified_model_ids.is_empty()
+    }
+
+    /// Returns `true` if the voice is verified or fine-tuned for
+    /// `model_id` in this language.
+    pub fn supports_model(&self, model_id: &str) -> bool {
+        self.verified_model_ids.iter().chain(&self.fine_tuned_model_ids).any(|m| m == model_id)
+    }
+
+    /// Returns `true` if this language matches `code`, case-insensitively
+    /// and accepting `_` for `-`.
+    ///
+    /// A bare language code such as `"en"` matches every locale of it. A
+    /// code with a region such as `"en-GB"` matches that locale, and
+    /// languages whose locale is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elevenlabs_sdk::types::VoiceLanguage;
+    ///
+    /// let language = VoiceLanguage {
+    ///     language: "en".into(),
+    ///     locale: Some("en-US".into()),
+    ///     ..VoiceLanguage::default()
+    /// };
+    /// assert!(language.matches("EN"));
+    /// assert!(language.matches("en_us"));
+    /// assert!(!language.matches("en-GB"));
+    /// ```
+    pub fn matches(&self, code: &str) -> bool {
+        let code = normalize_locale(code);
+        if primary_language(&code) != self.language {
+            return false;
+        }
+        !code.contains('-') || self.locale.as_ref().is_none_or(|locale| *locale == code)
+    }
+}
+
+/// A voice that speaks a requested language, returned by
+/// [`VoicesService::list_by_language`](crate::services::VoicesService::list_by_language).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalizedVoice {
+    /// How the voice speaks the language.
+    pub language: VoiceLanguage,
+    /// The voice.
+    pub voice: Voice,
+}
+
+/// Returns the lowercase language subtag of a code such as `"en-US"`.
+fn primary_language(code: &str) -> String {
+    code.trim().split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase()
+}
+
+/// Normalizes a locale such as `"en_us"` to `"en-US"`.
+fn normalize_locale(code: &str) -> String {
+    let mut parts = code.trim().split(['-', '_']);
+    let mut locale = parts.next().unwrap_or_default().to_ascii_lowercase();
+    for part in parts {
+        locale.push('-');
+        locale.push_str(&part.to_ascii_uppercase());
+    }
+    locale
+}
+
 // ---------------------------------------------------------------------------
 // List / CRUD Responses
 // ---------------------------------------------------------------------------
@@ -398,8 +606,10 @@ pub struct Voice {
 ///
 /// Contains a list of all voices available to the authenticated user.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct GetVoicesResponse {
     /// List of available voices.
+    #[serde(default)]
     pub voices: Vec<Voice>,
 }
 
@@ -416,6 +626,7 @@ pub struct AddVoiceResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EditVoiceResponse {
     /// Status message (typically `"ok"`).
+    #[serde(default)]
     pub status: String,
 }
 
@@ -423,6 +634,7 @@ pub struct EditVoiceResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeleteVoiceResponse {
     /// Status message (typically `"ok"`).
+    #[serde(default)]
     pub status: String,
 }
 
@@ -430,6 +642,7 @@ pub struct DeleteVoiceResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EditVoiceSettingsResponse {
     /// Status message (typically `"ok"`).
+    #[serde(default)]
     pub status: String,
 }
 
@@ -437,6 +650,7 @@ pub struct EditVoiceSettingsResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeleteVoiceSampleResponse {
     /// Status message (typically `"ok"`).
+    #[serde(default)]
     pub status: String,
 }
 
@@ -477,6 +691,178 @@ pub struct EditVoiceRequest {
     pub labels: Option<HashMap<String, String>>,
 }
 
+/// How remote samples reach `POST /v1/voices/add`.
+///
+/// The endpoint only accepts uploaded files, so samples given by URL are
+/// always fetched by the SDK; this controls whether they are held in memory
+/// first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SampleTransfer {
+    /// Download each sample in full, then check its format and duration
+    /// before uploading.
+    #[default]
+    Buffered,
+    /// Pipe each download into the upload as it arrives. Only the
+    /// `Content-Type` and size are checked, and the total duration is not.
+    Streamed,
+}
+
+/// Options for
+/// [`VoicesService::add_voice_from_urls_with`](crate::services::VoicesService::add_voice_from_urls_with).
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use elevenlabs_sdk::types::{RemoteSampleOptions, SampleTransfer};
+///
+/// let options = RemoteSampleOptions {
+///     transfer: SampleTransfer::Streamed,
+///     min_total_duration: Some(Duration::from_secs(60)),
+///     ..Default::default()
+/// };
+/// assert_eq!(options.max_sample_bytes, 10 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSampleOptions {
+    /// Whether samples are buffered or streamed through.
+    pub transfer: SampleTransfer,
+    /// Description of the new voice.
+    pub description: Option<String>,
+    /// Largest accepted sample, in bytes (default 10 MiB).
+    pub max_sample_bytes: u64,
+    /// Shortest accepted total duration of the samples whose duration can
+    /// be measured (WAV and MP3) (default 10 s). Ignored when streaming.
+    pub min_total_duration: Option<Duration>,
+}
+
+impl Default for RemoteSampleOptions {
+    fn default() -> Self {
+        Self {
+            transfer: SampleTransfer::default(),
+            description: None,
+            max_sample_bytes: 10 * 1024 * 1024,
+            min_total_duration: Some(Duration::from_secs(10)),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Voice Presets
+// ---------------------------------------------------------------------------
+
+/// A built-in set of [`VoiceSettings`] tuned for a style of speech.
+///
+/// Apply one to a request with
+/// [`TextToSpeechRequest::with_preset`](super::TextToSpeechRequest::with_preset),
+/// or look presets up by name in a [`VoicePresets`] registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoicePreset {
+    /// Steady delivery for long-form reading such as audiobooks.
+    Narration,
+    /// Natural, slightly livelier delivery for assistants and dialogue.
+    Conversational,
+    /// Wide emotional range for characters and storytelling.
+    Expressive,
+}
+
+impl VoicePreset {
+    /// All built-in presets.
+    pub const ALL: [Self; 3] = [Self::Narration, Self::Conversational, Self::Expressive];
+
+    /// Returns the preset's registry name.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Narration => "narration",
+            Self::Conversational => "conversational",
+            Self::Expressive => "expressive",
+        }
+    }
+
+    /// Returns the voice settings for this preset.
+    pub const fn settings(self) -> VoiceSettings {
+        let (stability, similarity_boost, style, speed) = match self {
+            Self::Narration => (0.7, 0.75, 0.0, 1.0),
+            Self::Conversational => (0.45, 0.8, 0.15, 1.05),
+            Self::Expressive => (0.3, 0.75, 0.6, 1.0),
+        };
+        VoiceSettings {
+            stability: Some(stability),
+            similarity_boost: Some(similarity_boost),
+            style: Some(style),
+            use_speaker_boost: Some(true),
+            speed: Some(speed),
+        }
+    }
+}
+
+/// Named [`VoiceSettings`] shared across apps.
+///
+/// The default registry holds the [`VoicePreset`]s under their names; teams
+/// can add their own presets or override the built-in ones. Serializes as a
+/// plain map from name to settings, so a registry can be loaded from a
+/// config file.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{VoicePresets, VoiceSettings};
+///
+/// let presets = VoicePresets::default()
+///     .with("podcast", VoiceSettings { stability: Some(0.55), ..VoiceSettings::default() });
+/// assert!(presets.get("narration").is_some());
+/// assert_eq!(presets.get("podcast").unwrap().stability, Some(0.55));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct VoicePresets {
+    presets: HashMap<String, VoiceSettings>,
+}
+
+impl Default for VoicePresets {
+    fn default() -> Self {
+        let presets =
+            VoicePreset::ALL.iter().map(|p| (p.name().to_owned(), p.settings())).collect();
+        Self { presets }
+    }
+}
+
+impl VoicePresets {
+    /// Creates an empty registry, without the built-in presets.
+    pub fn empty() -> Self {
+        Self { presets: HashMap::new() }
+    }
+
+    /// Adds or replaces the preset called `name`.
+    pub fn with(mut self, name: impl Into<String>, settings: VoiceSettings) -> Self {
+        self.insert(name, settings);
+        self
+    }
+
+    /// Adds or replaces the preset called `name`, returning the settings it
+    /// replaced.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        settings: VoiceSettings,
+    ) -> Option<VoiceSettings> {
+        self.presets.insert(name.into(), settings)
+    }
+
+    /// Returns the preset called `name`.
+    pub fn get(&self, name: &str) -> Option<&VoiceSettings> {
+        self.presets.get(name)
+    }
+
+    /// Returns the names of all presets, sorted.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.presets.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Library Voices (Shared)
 // ---------------------------------------------------------------------------
@@ -492,20 +878,28 @@ pub struct LibraryVoice {
     /// Unique voice identifier.
     pub voice_id: String,
     /// Unix timestamp of when the voice was added to the library.
+    #[serde(default)]
     pub date_unix: i64,
     /// Display name of the voice.
+    #[serde(default)]
     pub name: String,
     /// Accent descriptor (e.g. `"American"`, `"British"`).
+    #[serde(default)]
     pub accent: String,
     /// Gender descriptor (e.g. `"male"`, `"female"`).
+    #[serde(default)]
     pub gender: String,
     /// Age descriptor (e.g. `"young"`, `"middle_aged"`).
+    #[serde(default)]
     pub age: String,
     /// Descriptive adjective (e.g. `"warm"`, `"raspy"`).
+    #[serde(default)]
     pub descriptive: String,
     /// Intended use case (e.g. `"narration"`, `"conversational"`).
+    #[serde(default)]
     pub use_case: String,
     /// Voice category in the library.
+    #[serde(default)]
     pub category: String,
     /// Language of the voice.
     #[serde(default)]
@@ -520,12 +914,16 @@ pub struct LibraryVoice {
     #[serde(default)]
     pub preview_url: Option<String>,
     /// Character usage count over the last year.
+    #[serde(default)]
     pub usage_character_count_1y: i64,
     /// Character usage count over the last 7 days.
+    #[serde(default)]
     pub usage_character_count_7d: i64,
     /// Play API character usage count over the last year.
+    #[serde(default)]
     pub play_api_usage_character_count_1y: i64,
     /// Number of users who cloned this voice.
+    #[serde(default)]
     pub cloned_by_count: i64,
     /// Revenue rate for the voice.
     #[serde(default)]
@@ -534,10 +932,13 @@ pub struct LibraryVoice {
     #[serde(default)]
     pub fiat_rate: Option<f64>,
     /// Whether free-tier users can access this voice.
+    #[serde(default)]
     pub free_users_allowed: bool,
     /// Whether live moderation is enabled.
+    #[serde(default)]
     pub live_moderation_enabled: bool,
     /// Whether the voice is featured in the library.
+    #[serde(default)]
     pub featured: bool,
     /// Languages verified for this voice.
     #[serde(default)]
@@ -569,8 +970,10 @@ pub struct LibraryVoice {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GetLibraryVoicesResponse {
     /// Library voices on this page.
+    #[serde(default)]
     pub voices: Vec<LibraryVoice>,
     /// Whether more items are available.
+    #[serde(default)]
     pub has_more: bool,
     /// Sort ID of the last item (for pagination).
     #[serde(default)]
@@ -583,14 +986,36 @@ pub struct GetLibraryVoicesResponse {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GetSimilarVoicesResponse {
     /// Similar library voices.
+    #[serde(default)]
     pub voices: Vec<LibraryVoice>,
     /// Whether more items are available.
+    #[serde(default)]
     pub has_more: bool,
     /// Sort ID of the last item (for pagination).
     #[serde(default)]
     pub last_sort_id: Option<String>,
 }
 
+/// A library voice ranked by how closely it matches an audio sample.
+///
+/// Returned by
+/// [`VoicesService::find_similar`](crate::services::VoicesService::find_similar)
+/// closest match first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimilarLibraryVoice {
+    /// Position among the matches, starting at 1 for the closest.
+    #[serde(default)]
+    pub rank: usize,
+    /// Similarity score for the match. The API currently ranks matches
+    /// without reporting a score, so this is usually `None`; use
+    /// [`rank`](Self::rank) to compare matches.
+    #[serde(default)]
+    pub similarity_score: Option<f64>,
+    /// The matching library voice.
+    #[serde(flatten)]
+    pub voice: LibraryVoice,
+}
+
 // ---------------------------------------------------------------------------
 // Voices v2
 // ---------------------------------------------------------------------------
@@ -601,10 +1026,13 @@ pub struct GetSimilarVoicesResponse {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GetVoicesV2Response {
     /// Voices on this page.
+    #[serde(default)]
     pub voices: Vec<Voice>,
     /// Whether more items are available.
+    #[serde(default)]
     pub has_more: bool,
     /// Total number of voices matching the query.
+    #[serde(default)]
     pub total_count: i64,
     /// Opaque token to fetch the next page.
     #[serde(default)]
@@ -620,6 +1048,24 @@ pub struct GetVoicesV2Response {
 mod tests {
     use super::*;
 
+    #[test]
+    fn similar_voice_flattens_library_voice() {
+        let json = r#"{
+            "public_owner_id": "owner_1",
+            "voice_id": "lib_1",
+            "name": "Narrator",
+            "similarity_score": 0.42
+        }"#;
+        let similar: SimilarLibraryVoice = serde_json::from_str(json).unwrap();
+        assert_eq!(similar.voice.voice_id, "lib_1");
+        assert_eq!(similar.similarity_score, Some(0.42));
+        assert_eq!(similar.rank, 0);
+
+        let value = serde_json::to_value(&similar).unwrap();
+        assert_eq!(value["name"], "Narrator");
+        assert_eq!(value["similarity_score"], 0.42);
+    }
+
     #[test]
     fn voice_deserialize_minimal() {
         let json = r#"{
@@ -770,7 +1216,7 @@ mod tests {
         let attempts = vv.verification_attempts.unwrap();
         assert_eq!(attempts.len(), 1);
         assert!(attempts[0].accepted);
-        assert_eq!(attempts[0].similarity, 0.95);
+        assert!((attempts[0].similarity - 0.95).abs() < f64::EPSILON);
         let recording = attempts[0].recording.as_ref().unwrap();
         assert_eq!(recording.recording_id, "CwhRBWXzGAHq8TQ4Fs17");
         assert_eq!(recording.transcription, "Hello, how are you?");
@@ -783,7 +1229,51 @@ mod tests {
         // Safety control
         assert_eq!(voice.safety_control, Some(SafetyControl::None));
         assert!(!voice.is_legacy);
-        assert_eq!(voice.created_at_unix, Some(1714204800));
+        assert_eq!(voice.created_at_unix, Some(1_714_204_800));
+    }
+
+    #[test]
+    fn voice_languages_merge_sources() {
+        let json = r#"{
+            "voice_id": "v1",
+            "name": "Ana",
+            "category": "professional",
+            "labels": {"language": "fr", "accent": "parisian"},
+            "fine_tuning": {
+                "language": "es",
+                "state": {"eleven_multilingual_v2": "fine_tuned", "eleven_turbo_v2_5": "not_started"}
+            },
+            "verified_languages": [
+                {"language": "en", "model_id": "eleven_multilingual_v2", "locale": "en-US", "accent": "american"},
+                {"language": "en", "model_id": "eleven_turbo_v2_5", "locale": "en-US"},
+                {"language": "en", "model_id": "eleven_multilingual_v2", "locale": "en_gb"}
+            ]
+        }"#;
+        let voice: Voice = serde_json::from_str(json).unwrap();
+        let languages = voice.languages();
+        assert_eq!(languages.len(), 4);
+
+        assert_eq!(languages[0].locale.as_deref(), Some("en-US"));
+        assert_eq!(languages[0].accent.as_deref(), Some("american"));
+        assert_eq!(
+            languages[0].verified_model_ids,
+            ["eleven_multilingual_v2", "eleven_turbo_v2_5"]
+        );
+        assert_eq!(languages[1].locale.as_deref(), Some("en-GB"));
+
+        assert_eq!(languages[2].language, "es");
+        assert!(!languages[2].is_verified());
+        assert!(languages[2].supports_model("eleven_multilingual_v2"));
+        assert!(!languages[2].supports_model("eleven_turbo_v2_5"));
+
+        assert_eq!(languages[3].language, "fr");
+        assert_eq!(languages[3].accent.as_deref(), Some("parisian"));
+
+        assert_eq!(voice.language("en-GB").unwrap().locale.as_deref(), Some("en-GB"));
+        assert_eq!(voice.language("EN").unwrap().locale.as_deref(), Some("en-US"));
+        assert!(voice.language("es-MX").is_some());
+        assert!(voice.language("en-AU").is_none());
+        assert!(voice.language("de").is_none());
     }
 
     #[test]
@@ -948,7 +1438,7 @@ mod tests {
         let sample: VoiceSample = serde_json::from_str(json).unwrap();
         assert_eq!(sample.sample_id, "DCwhRBWXzGAHq8TQ4Fs18");
         assert_eq!(sample.file_name, "sample.mp3");
-        assert_eq!(sample.size_bytes, 1000000);
+        assert_eq!(sample.size_bytes, 1_000_000);
         assert!(sample.duration_secs.is_none());
     }
 
@@ -992,7 +1482,7 @@ mod tests {
             "captcha_checks": [0.99]
         }"#;
         let check: ModerationCheck = serde_json::from_str(json).unwrap();
-        assert_eq!(check.date_checked_unix, Some(1714204800));
+        assert_eq!(check.date_checked_unix, Some(1_714_204_800));
         assert_eq!(check.name_check, Some(true));
         assert_eq!(check.sample_checks.as_ref().unwrap().len(), 2);
     }
@@ -1038,4 +1528,22 @@ mod tests {
         assert_eq!(rec.recording_id, "rec1");
         assert_eq!(rec.transcription, "Hello world");
     }
+
+    #[test]
+    fn voice_presets_default_to_built_ins() {
+        let presets = VoicePresets::default();
+        assert_eq!(presets.names(), ["conversational", "expressive", "narration"]);
+        assert_eq!(presets.get("narration"), Some(&VoicePreset::Narration.settings()));
+        assert!(VoicePresets::empty().get("narration").is_none());
+    }
+
+    #[test]
+    fn voice_presets_serde_round_trip() {
+        let custom = VoiceSettings { stability: Some(0.9), ..VoiceSettings::default() };
+        let presets = VoicePresets::empty().with("calm", custom.clone());
+        let json = serde_json::to_value(&presets).unwrap();
+        assert_eq!(json["calm"]["stability"], 0.9);
+        let back: VoicePresets = serde_json::from_value(json).unwrap();
+        assert_eq!(back.get("calm"), Some(&custom));
+    }
 }
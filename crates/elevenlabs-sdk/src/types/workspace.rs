@@ -3,12 +3,30 @@
 //! Covers workspace management: groups, invites, members, service accounts,
 //! API keys, webhooks, and resource sharing.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::error::ElevenLabsError;
+
 // ---------------------------------------------------------------------------
 // Enums
 // ---------------------------------------------------------------------------
 
+/// Role of a user in relation to a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceRole {
+    /// Full administrative access.
+    Admin,
+    /// Can edit the resource.
+    Editor,
+    /// Can comment on the resource.
+    Commenter,
+    /// Read-only access.
+    Viewer,
+}
+
 /// Permission that can be granted to a workspace group.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -131,6 +149,61 @@ pub enum WorkspaceResourceType {
     ConvaiAgentDrafts,
 }
 
+impl WorkspaceResourceType {
+    /// Returns the wire name of the resource type, as used in query strings.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Voice => "voice",
+            Self::VoiceCollection => "voice_collection",
+            Self::PronunciationDictionary => "pronunciation_dictionary",
+            Self::Dubbing => "dubbing",
+            Self::Project => "project",
+            Self::ConvaiAgents => "convai_agents",
+            Self::ConvaiKnowledgeBaseDocuments => "convai_knowledge_base_documents",
+            Self::ConvaiTools => "convai_tools",
+            Self::ConvaiSettings => "convai_settings",
+            Self::ConvaiSecrets => "convai_secrets",
+            Self::WorkspaceAuthConnections => "workspace_auth_connections",
+            Self::ConvaiPhoneNumbers => "convai_phone_numbers",
+            Self::ConvaiMcpServers => "convai_mcp_servers",
+            Self::ConvaiApiIntegrationConnections => "convai_api_integration_connections",
+            Self::ConvaiApiIntegrationTriggerConnections => {
+                "convai_api_integration_trigger_connections"
+            }
+            Self::ConvaiBatchCalls => "convai_batch_calls",
+            Self::ConvaiAgentResponseTests => "convai_agent_response_tests",
+            Self::ConvaiTestSuiteInvocations => "convai_test_suite_invocations",
+            Self::ConvaiCrawlJobs => "convai_crawl_jobs",
+            Self::ConvaiCrawlTasks => "convai_crawl_tasks",
+            Self::ConvaiWhatsappAccounts => "convai_whatsapp_accounts",
+            Self::ConvaiAgentVersions => "convai_agent_versions",
+            Self::ConvaiAgentBranches => "convai_agent_branches",
+            Self::ConvaiAgentVersionsDeployments => "convai_agent_versions_deployments",
+            Self::Dashboard => "dashboard",
+            Self::DashboardConfiguration => "dashboard_configuration",
+            Self::ConvaiAgentDrafts => "convai_agent_drafts",
+        }
+    }
+}
+
+impl std::fmt::Display for WorkspaceResourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Kind of principal a resource can be shared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SharePrincipalType {
+    /// A workspace member.
+    User,
+    /// A workspace group.
+    Group,
+    /// A service account, identified by its workspace API key.
+    Key,
+}
+
 /// Authentication method type for webhooks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -172,6 +245,142 @@ pub enum PermissionLevel {
     Viewer,
 }
 
+/// Permission that can be granted to a service account API key.
+///
+/// Permissions this SDK does not know yet are kept as
+/// [`Unknown`](Self::Unknown) so they are sent back unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyPermission {
+    /// Text-to-speech.
+    TextToSpeech,
+    /// Speech-to-speech.
+    SpeechToSpeech,
+    /// Speech-to-text.
+    SpeechToText,
+    /// Read models.
+    ModelsRead,
+    /// Manage models.
+    ModelsWrite,
+    /// Read voices.
+    VoicesRead,
+    /// Manage voices.
+    VoicesWrite,
+    /// Read speech history.
+    SpeechHistoryRead,
+    /// Manage speech history.
+    SpeechHistoryWrite,
+    /// Sound effects generation.
+    SoundGeneration,
+    /// Audio isolation.
+    AudioIsolation,
+    /// Voice generation.
+    VoiceGeneration,
+    /// Read dubbing projects.
+    DubbingRead,
+    /// Manage dubbing projects.
+    DubbingWrite,
+    /// Read pronunciation dictionaries.
+    PronunciationDictionariesRead,
+    /// Manage pronunciation dictionaries.
+    PronunciationDictionariesWrite,
+    /// Read user information.
+    UserRead,
+    /// Manage user information.
+    UserWrite,
+    /// Read Studio projects.
+    ProjectsRead,
+    /// Manage Studio projects.
+    ProjectsWrite,
+    /// Read Audio Native projects.
+    AudioNativeRead,
+    /// Manage Audio Native projects.
+    AudioNativeWrite,
+    /// Read workspace settings.
+    WorkspaceRead,
+    /// Manage workspace settings.
+    WorkspaceWrite,
+    /// Forced alignment.
+    ForcedAlignment,
+    /// Read conversational AI resources.
+    ConvaiRead,
+    /// Manage conversational AI resources.
+    ConvaiWrite,
+    /// Music generation.
+    MusicGeneration,
+    /// Add voices from the voice library.
+    AddVoiceFromVoiceLibrary,
+    /// Create instant voice clones.
+    CreateInstantVoiceClone,
+    /// Create professional voice clones.
+    CreateProfessionalVoiceClone,
+    /// Publish voices to the voice library.
+    PublishVoiceToVoiceLibrary,
+    /// Share voices externally.
+    ShareVoiceExternally,
+    /// Create user API keys.
+    CreateUserApiKey,
+    /// Full read access to workspace analytics.
+    WorkspaceAnalyticsFullRead,
+    /// Manage webhooks.
+    WebhooksWrite,
+    /// Manage service accounts.
+    ServiceAccountWrite,
+    /// Manage group members.
+    GroupMembersManage,
+    /// Read workspace members.
+    WorkspaceMembersRead,
+    /// Invite workspace members.
+    WorkspaceMembersInvite,
+    /// Remove workspace members.
+    WorkspaceMembersRemove,
+    /// Accept terms of service.
+    TermsOfServiceAccept,
+    /// A permission not known to this SDK, kept as sent by the API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Set of permissions granted to a service account API key.
+///
+/// Serialized either as the string `"all"` or as a list of
+/// [`ApiKeyPermission`] values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiKeyPermissions {
+    /// Every permission, including ones added in the future.
+    All,
+    /// Only the listed permissions.
+    Only(Vec<ApiKeyPermission>),
+}
+
+impl Serialize for ApiKeyPermissions {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::All => serializer.serialize_str("all"),
+            Self::Only(permissions) => permissions.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiKeyPermissions {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Named(String),
+            List(Vec<ApiKeyPermission>),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Named(name) if name == "all" => Ok(Self::All),
+            Repr::Named(name) => {
+                Err(serde::de::Error::custom(format!("unknown permission set `{name}`")))
+            }
+            Repr::List(permissions) => Ok(Self::Only(permissions)),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Groups
 // ---------------------------------------------------------------------------
@@ -180,12 +389,15 @@ pub enum PermissionLevel {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkspaceGroup {
     /// Group name.
+    #[serde(default)]
     pub name: String,
     /// Group unique identifier.
     pub id: String,
     /// User IDs of group members.
+    #[serde(default)]
     pub members: Vec<String>,
     /// Permissions granted to the group.
+    #[serde(default)]
     pub permissions: serde_json::Value,
     /// Usage limit for the group.
     #[serde(default)]
@@ -199,10 +411,12 @@ pub struct WorkspaceGroup {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkspaceGroupByName {
     /// Group name.
+    #[serde(default)]
     pub name: String,
     /// Group unique identifier.
     pub id: String,
     /// Emails of the group members.
+    #[serde(default)]
     pub members_emails: Vec<String>,
 }
 
@@ -214,30 +428,33 @@ pub struct WorkspaceGroupByName {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkspaceApiKey {
     /// Display name of the API key.
+    #[serde(default)]
     pub name: String,
     /// Hint (masked key preview).
+    #[serde(default)]
     pub hint: String,
     /// Unique key identifier.
     pub key_id: String,
     /// Service account user ID this key belongs to.
     pub service_account_user_id: String,
     /// Hashed API key value.
+    #[serde(default)]
     pub hashed_xi_api_key: String,
     /// Unix timestamp of creation.
     #[serde(default)]
     pub created_at_unix: Option<i64>,
     /// Whether the key is disabled.
     #[serde(default)]
-    pub is_disabled: Option<bool>,
+    pub is_disabled: bool,
     /// Permissions associated with this key.
     #[serde(default)]
-    pub permissions: Option<serde_json::Value>,
-    /// Character limit for this key.
+    pub permissions: Option<ApiKeyPermissions>,
+    /// Character limit for this key (`None` means unlimited).
     #[serde(default)]
-    pub character_limit: Option<serde_json::Value>,
+    pub character_limit: Option<i64>,
     /// Character count used by this key.
     #[serde(default)]
-    pub character_count: Option<serde_json::Value>,
+    pub character_count: Option<i64>,
 }
 
 /// A workspace service account.
@@ -246,12 +463,14 @@ pub struct WorkspaceServiceAccount {
     /// Service account user identifier.
     pub service_account_user_id: String,
     /// Service account display name.
+    #[serde(default)]
     pub name: String,
     /// Unix timestamp of creation.
     #[serde(default)]
     pub created_at_unix: Option<i64>,
     /// API keys associated with this service account.
     #[serde(rename = "api-keys")]
+    #[serde(default)]
     pub api_keys: Vec<WorkspaceApiKey>,
     /// Default sharing groups configuration.
     #[serde(default)]
@@ -263,6 +482,7 @@ pub struct WorkspaceServiceAccount {
 pub struct WorkspaceServiceAccountList {
     /// List of service accounts.
     #[serde(rename = "service-accounts")]
+    #[serde(default)]
     pub service_accounts: Vec<WorkspaceServiceAccount>,
 }
 
@@ -271,6 +491,7 @@ pub struct WorkspaceServiceAccountList {
 pub struct WorkspaceApiKeyList {
     /// List of API keys.
     #[serde(rename = "api-keys")]
+    #[serde(default)]
     pub api_keys: Vec<WorkspaceApiKey>,
 }
 
@@ -279,6 +500,7 @@ pub struct WorkspaceApiKeyList {
 pub struct WorkspaceCreateApiKeyResponse {
     /// The newly created API key value.
     #[serde(rename = "xi-api-key")]
+    #[serde(default)]
     pub xi_api_key: String,
     /// Key identifier.
     pub key_id: String,
@@ -292,16 +514,21 @@ pub struct WorkspaceCreateApiKeyResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkspaceWebhook {
     /// Display name for this webhook.
+    #[serde(default)]
     pub name: String,
     /// Unique webhook identifier.
     pub webhook_id: String,
     /// HTTPS callback URL.
+    #[serde(default)]
     pub webhook_url: String,
     /// Whether the webhook has been manually disabled.
+    #[serde(default)]
     pub is_disabled: bool,
     /// Whether the webhook has been auto-disabled due to failures.
+    #[serde(default)]
     pub is_auto_disabled: bool,
     /// Unix timestamp of creation.
+    #[serde(default)]
     pub created_at_unix: i64,
     /// Authentication method type.
     pub auth_type: WebhookAuthMethod,
@@ -327,6 +554,7 @@ pub struct WorkspaceWebhookUsage {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkspaceWebhookList {
     /// List of configured webhooks.
+    #[serde(default)]
     pub webhooks: Vec<WorkspaceWebhook>,
 }
 
@@ -419,16 +647,35 @@ pub struct UpdateWorkspaceMemberRequest {
 }
 
 /// Request body for sharing a workspace resource.
+///
+/// Exactly one of `user_email`, `group_id` and `workspace_api_key_id` should
+/// be set; the constructors take care of that.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::types::{
+///     ResourceRole, ShareWorkspaceResourceRequest, WorkspaceResourceType,
+/// };
+///
+/// let request = ShareWorkspaceResourceRequest::with_group(
+///     ResourceRole::Editor,
+///     WorkspaceResourceType::Voice,
+///     "group_123",
+/// );
+/// assert_eq!(request.group_id.as_deref(), Some("group_123"));
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ShareWorkspaceResourceRequest {
-    /// Role to grant (e.g. `"editor"`, `"viewer"`).
-    pub role: String,
+    /// Role to grant.
+    pub role: ResourceRole,
     /// Type of resource to share.
     pub resource_type: WorkspaceResourceType,
     /// Email of the user to share with.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_email: Option<String>,
-    /// Group ID to share with.
+    /// Group ID to share with. `"default"` targets the access every member
+    /// has by default.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_id: Option<String>,
     /// Workspace API key ID to share with.
@@ -436,6 +683,45 @@ pub struct ShareWorkspaceResourceRequest {
     pub workspace_api_key_id: Option<String>,
 }
 
+impl ShareWorkspaceResourceRequest {
+    const fn new(role: ResourceRole, resource_type: WorkspaceResourceType) -> Self {
+        Self { role, resource_type, user_email: None, group_id: None, workspace_api_key_id: None }
+    }
+
+    /// Shares the resource with the workspace member or service account
+    /// with `email`.
+    pub fn with_user(
+        role: ResourceRole,
+        resource_type: WorkspaceResourceType,
+        email: impl Into<String>,
+    ) -> Self {
+        Self { user_email: Some(email.into()), ..Self::new(role, resource_type) }
+    }
+
+    /// Shares the resource with the group `group_id`.
+    pub fn with_group(
+        role: ResourceRole,
+        resource_type: WorkspaceResourceType,
+        group_id: impl Into<String>,
+    ) -> Self {
+        Self { group_id: Some(group_id.into()), ..Self::new(role, resource_type) }
+    }
+
+    /// Sets the access every workspace member has by default.
+    pub fn with_default_access(role: ResourceRole, resource_type: WorkspaceResourceType) -> Self {
+        Self::with_group(role, resource_type, DEFAULT_SHARE_GROUP)
+    }
+
+    /// Shares the resource with the workspace API key `key_id`.
+    pub fn with_api_key(
+        role: ResourceRole,
+        resource_type: WorkspaceResourceType,
+        key_id: impl Into<String>,
+    ) -> Self {
+        Self { workspace_api_key_id: Some(key_id.into()), ..Self::new(role, resource_type) }
+    }
+}
+
 /// Request body for unsharing a workspace resource.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct UnshareWorkspaceResourceRequest {
@@ -452,6 +738,31 @@ pub struct UnshareWorkspaceResourceRequest {
     pub workspace_api_key_id: Option<String>,
 }
 
+impl UnshareWorkspaceResourceRequest {
+    const fn new(resource_type: WorkspaceResourceType) -> Self {
+        Self { resource_type, user_email: None, group_id: None, workspace_api_key_id: None }
+    }
+
+    /// Revokes the access of the workspace member or service account with
+    /// `email`.
+    pub fn with_user(resource_type: WorkspaceResourceType, email: impl Into<String>) -> Self {
+        Self { user_email: Some(email.into()), ..Self::new(resource_type) }
+    }
+
+    /// Revokes the access of the group `group_id`.
+    pub fn with_group(resource_type: WorkspaceResourceType, group_id: impl Into<String>) -> Self {
+        Self { group_id: Some(group_id.into()), ..Self::new(resource_type) }
+    }
+
+    /// Revokes the access of the workspace API key `key_id`.
+    pub fn with_api_key(resource_type: WorkspaceResourceType, key_id: impl Into<String>) -> Self {
+        Self { workspace_api_key_id: Some(key_id.into()), ..Self::new(resource_type) }
+    }
+}
+
+/// Group ID that targets the access workspace members have by default.
+pub const DEFAULT_SHARE_GROUP: &str = "default";
+
 /// Request body for adding a member to a workspace group.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AddGroupMemberRequest {
@@ -459,6 +770,13 @@ pub struct AddGroupMemberRequest {
     pub email: String,
 }
 
+impl AddGroupMemberRequest {
+    /// Creates a request adding the member with `email`.
+    pub fn new(email: impl Into<String>) -> Self {
+        Self { email: email.into() }
+    }
+}
+
 /// Request body for removing a member from a workspace group.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct RemoveGroupMemberRequest {
@@ -466,13 +784,20 @@ pub struct RemoveGroupMemberRequest {
     pub email: String,
 }
 
+impl RemoveGroupMemberRequest {
+    /// Creates a request removing the member with `email`.
+    pub fn new(email: impl Into<String>) -> Self {
+        Self { email: email.into() }
+    }
+}
+
 /// Request body for creating a service account API key.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct CreateServiceAccountApiKeyRequest {
     /// Display name for the API key.
     pub name: String,
-    /// Permissions to grant (list of permission types or `"all"`).
-    pub permissions: serde_json::Value,
+    /// Permissions to grant.
+    pub permissions: ApiKeyPermissions,
     /// Optional character limit.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub character_limit: Option<i64>,
@@ -485,13 +810,37 @@ pub struct EditServiceAccountApiKeyRequest {
     pub is_enabled: bool,
     /// Display name for the API key.
     pub name: String,
-    /// Permissions to grant (list of permission types or `"all"`).
-    pub permissions: serde_json::Value,
+    /// Permissions to grant.
+    pub permissions: ApiKeyPermissions,
     /// Optional character limit.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub character_limit: Option<i64>,
 }
 
+impl EditServiceAccountApiKeyRequest {
+    /// Builds an edit request that keeps the key's current name, permissions
+    /// and character limit, changing only whether it is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the key was reported
+    /// without permissions, since sending an empty list would revoke them.
+    pub fn from_key(key: &WorkspaceApiKey, is_enabled: bool) -> crate::error::Result<Self> {
+        let permissions = key.permissions.clone().ok_or_else(|| {
+            ElevenLabsError::Validation(format!(
+                "API key `{}` has no reported permissions; edit it with explicit permissions",
+                key.key_id
+            ))
+        })?;
+        Ok(Self {
+            is_enabled,
+            name: key.name.clone(),
+            permissions,
+            character_limit: key.character_limit,
+        })
+    }
+}
+
 /// Response from `GET /v1/workspace/groups/search`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SearchGroupsResponse(pub Vec<WorkspaceGroupByName>);
@@ -509,15 +858,45 @@ pub struct ResourceMetadataResponse {
     /// Creator user identifier.
     #[serde(default)]
     pub creator_user_id: Option<String>,
-    /// Anonymous access level override.
+    /// Access level for anonymous users; `None` if the resource is not
+    /// shared publicly.
     #[serde(default)]
-    pub anonymous_access_level_override: Option<String>,
-    /// Mapping of roles to group IDs.
+    pub anonymous_access_level_override: Option<ResourceRole>,
+    /// Group IDs holding each role. A resource shared with a single user
+    /// lists the user's ID.
     #[serde(default)]
-    pub role_to_group_ids: Option<serde_json::Value>,
-    /// Sharing options for this resource.
+    pub role_to_group_ids: HashMap<ResourceRole, Vec<String>>,
+    /// Principals the resource can still be shared with.
     #[serde(default)]
-    pub share_options: Option<Vec<serde_json::Value>>,
+    pub share_options: Vec<ShareOption>,
+}
+
+impl ResourceMetadataResponse {
+    /// Returns the highest role held by `group_id`, if any.
+    pub fn role_of(&self, group_id: &str) -> Option<ResourceRole> {
+        self.role_to_group_ids
+            .iter()
+            .filter(|(_, ids)| ids.iter().any(|id| id == group_id))
+            .map(|(role, _)| *role)
+            .max_by_key(|role| match role {
+                ResourceRole::Viewer => 0,
+                ResourceRole::Commenter => 1,
+                ResourceRole::Editor => 2,
+                ResourceRole::Admin => 3,
+            })
+    }
+}
+
+/// A principal a resource can be shared with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareOption {
+    /// Name of the principal.
+    pub name: String,
+    /// ID of the principal.
+    pub id: String,
+    /// Kind of principal.
+    #[serde(rename = "type")]
+    pub principal_type: SharePrincipalType,
 }
 
 // ---------------------------------------------------------------------------
@@ -528,6 +907,7 @@ pub struct ResourceMetadataResponse {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkspaceStatusResponse {
     /// Status string, typically `"ok"`.
+    #[serde(default)]
     pub status: String,
 }
 
@@ -538,7 +918,9 @@ pub struct WorkspaceStatusResponse {
 #[cfg(test)]
 #[expect(clippy::unwrap_used, reason = "tests use unwrap")]
 mod tests {
-    use super::{super::agents::WebhookEventType, *};
+    #[cfg(feature = "convai")]
+    use super::super::agents::WebhookEventType;
+    use super::*;
 
     fn round_trip<T>(value: &T)
     where
@@ -574,6 +956,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn workspace_resource_type_as_str_matches_serde() {
+        let variants = [
+            WorkspaceResourceType::VoiceCollection,
+            WorkspaceResourceType::ConvaiApiIntegrationTriggerConnections,
+            WorkspaceResourceType::ConvaiAgentVersionsDeployments,
+            WorkspaceResourceType::DashboardConfiguration,
+        ];
+        for v in variants {
+            assert_eq!(serde_json::to_value(v).unwrap(), v.as_str());
+        }
+    }
+
+    #[test]
+    fn share_request_targets_one_principal() {
+        let request = ShareWorkspaceResourceRequest::with_default_access(
+            ResourceRole::Viewer,
+            WorkspaceResourceType::Project,
+        );
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({"role": "viewer", "resource_type": "project", "group_id": "default"})
+        );
+    }
+
     #[test]
     fn webhook_auth_method_round_trip() {
         let variants =
@@ -596,6 +1003,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "convai")]
     #[test]
     fn webhook_event_type_round_trip() {
         let variants = [
@@ -645,7 +1053,75 @@ mod tests {
         }"#;
         let key: WorkspaceApiKey = serde_json::from_str(json).unwrap();
         assert_eq!(key.name, "Prod Key");
-        assert_eq!(key.is_disabled, Some(false));
+        assert!(!key.is_disabled);
+    }
+
+    #[test]
+    fn api_key_permissions_round_trip() {
+        round_trip(&ApiKeyPermissions::All);
+        round_trip(&ApiKeyPermissions::Only(vec![
+            ApiKeyPermission::TextToSpeech,
+            ApiKeyPermission::ConvaiWrite,
+        ]));
+        assert_eq!(serde_json::to_string(&ApiKeyPermissions::All).unwrap(), "\"all\"");
+        assert!(serde_json::from_str::<ApiKeyPermissions>("\"some\"").is_err());
+    }
+
+    #[test]
+    fn api_key_permissions_keep_unknown_values() {
+        let perms: ApiKeyPermissions =
+            serde_json::from_str(r#"["text_to_speech", "agents_manage"]"#).unwrap();
+        assert_eq!(
+            perms,
+            ApiKeyPermissions::Only(vec![
+                ApiKeyPermission::TextToSpeech,
+                ApiKeyPermission::Unknown("agents_manage".into()),
+            ])
+        );
+        assert_eq!(
+            serde_json::to_value(&perms).unwrap(),
+            serde_json::json!(["text_to_speech", "agents_manage"])
+        );
+    }
+
+    #[test]
+    fn edit_api_key_request_from_key_keeps_settings() {
+        let json = r#"{
+            "name": "Scoped",
+            "hint": "xi_...abc",
+            "key_id": "key1",
+            "service_account_user_id": "sa1",
+            "hashed_xi_api_key": "hash123",
+            "permissions": ["speech_to_text"],
+            "character_limit": 5000,
+            "character_count": 12
+        }"#;
+        let key: WorkspaceApiKey = serde_json::from_str(json).unwrap();
+        let req = EditServiceAccountApiKeyRequest::from_key(&key, false).unwrap();
+        let value = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "is_enabled": false,
+                "name": "Scoped",
+                "permissions": ["speech_to_text"],
+                "character_limit": 5000
+            })
+        );
+    }
+
+    #[test]
+    fn edit_api_key_request_from_key_requires_permissions() {
+        let json = r#"{
+            "name": "Legacy",
+            "hint": "xi_...abc",
+            "key_id": "key1",
+            "service_account_user_id": "sa1",
+            "hashed_xi_api_key": "hash123"
+        }"#;
+        let key: WorkspaceApiKey = serde_json::from_str(json).unwrap();
+        let err = EditServiceAccountApiKeyRequest::from_key(&key, true).unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
     }
 
     #[test]
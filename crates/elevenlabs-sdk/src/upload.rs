@@ -0,0 +1,388 @@
+//! Streaming uploads from disk or readers.
+//!
+//! Endpoints that take an audio file as `multipart/form-data` normally want
+//! the whole file as a byte slice. [`FileInput`] lets them read it from a
+//! path or any [`AsyncRead`] instead, so the file is sent as it is read and
+//! never has to fit in memory.
+//!
+//! Use the `*_file` methods on
+//! [`AudioIsolationService`](crate::services::AudioIsolationService), or
+//! [`ForcedAlignmentService::align`](crate::services::ForcedAlignmentService::align).
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use bytes::Bytes;
+use futures_util::{StreamExt, stream, stream::BoxStream};
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+use crate::error::{ElevenLabsError, Result};
+
+/// Content type used when none is given and none can be guessed.
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Content types guessed from file extensions by [`FileInput::from_path`].
+pub(crate) const CONTENT_TYPES: &[(&str, &str)] = &[
+    ("mp3", "audio/mpeg"),
+    ("wav", "audio/wav"),
+    ("m4a", "audio/mp4"),
+    ("flac", "audio/flac"),
+    ("ogg", "audio/ogg"),
+    ("opus", "audio/opus"),
+    ("webm", "audio/webm"),
+    ("aac", "audio/aac"),
+    ("mp4", "video/mp4"),
+    ("mov", "video/quicktime"),
+];
+
+/// Where the contents of a [`FileInput`] come from.
+enum Source {
+    Bytes(Bytes),
+    Path(PathBuf),
+    Reader(Box<dyn AsyncRead + Send + Unpin>),
+}
+
+/// A file to upload as one part of a `multipart/form-data` request.
+///
+/// # Example
+///
+/// ```
+/// use elevenlabs_sdk::FileInput;
+///
+/// let input = FileInput::from_path("recordings/interview.wav");
+/// assert_eq!(input.filename(), "interview.wav");
+/// assert_eq!(input.content_type(), "audio/wav");
+///
+/// let input = FileInput::from_reader(tokio::io::empty(), "live.mp3", "audio/mpeg");
+/// assert_eq!(input.filename(), "live.mp3");
+/// ```
+pub struct FileInput {
+    filename: String,
+    content_type: String,
+    source: Source,
+}
+
+impl fmt::Debug for FileInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("FileInput");
+        debug.field("filename", &self.filename).field("content_type", &self.content_type);
+        match &self.source {
+            Source::Bytes(bytes) => debug.field("bytes", &bytes.len()).finish(),
+            Source::Path(path) => debug.field("path", path).finish(),
+            Source::Reader(_) => debug.finish_non_exhaustive(),
+        }
+    }
+}
+
+impl FileInput {
+    /// Uploads `data`, already in memory.
+    pub fn from_bytes(
+        data: impl Into<Bytes>,
+        filename: impl Into<String>,
+        content_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            source: Source::Bytes(data.into()),
+        }
+    }
+
+    /// Uploads the file at `path`, read as the request is sent.
+    ///
+    /// The filename is taken from `path` and the content type is guessed from
+    /// its extension, falling back to `application/octet-stream`. Use
+    /// [`with_filename`](Self::with_filename) and
+    /// [`with_content_type`](Self::with_content_type) to override them.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let filename =
+            path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        Self {
+            filename,
+            content_type: guess_content_type(&path).to_owned(),
+            source: Source::Path(path),
+        }
+    }
+
+    /// Uploads everything `reader` yields, read as the request is sent.
+    pub fn from_reader<R>(
+        reader: R,
+        filename: impl Into<String>,
+        content_type: impl Into<String>,
+    ) -> Self
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        Self {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            source: Source::Reader(Box::new(reader)),
+        }
+    }
+
+    /// Overrides the filename sent with the part.
+    #[must_use]
+    pub fn with_filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = filename.into();
+        self
+    }
+
+    /// Overrides the content type sent with the part.
+    #[must_use]
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = content_type.into();
+        self
+    }
+
+    /// Returns the filename sent with the part.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Returns the content type sent with the part.
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    /// Returns the size of the contents, or `None` for a reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if a file at a path cannot be
+    /// found.
+    pub(crate) async fn size(&self) -> Result<Option<u64>> {
+        match &self.source {
+            Source::Bytes(bytes) => Ok(Some(bytes.len() as u64)),
+            Source::Path(path) => {
+                let metadata = tokio::fs::metadata(path).await.map_err(|e| {
+                    ElevenLabsError::Validation(format!(
+                        "cannot open upload file {}: {e}",
+                        path.display()
+                    ))
+                })?;
+                Ok(Some(metadata.len()))
+            }
+            Source::Reader(_) => Ok(None),
+        }
+    }
+}
+
+/// Returns the content type for `path`'s extension.
+fn guess_content_type(path: &Path) -> &'static str {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| {
+            CONTENT_TYPES
+                .iter()
+                .find(|(known, _)| known.eq_ignore_ascii_case(ext))
+                .map(|(_, content_type)| *content_type)
+        })
+        .unwrap_or(DEFAULT_CONTENT_TYPE)
+}
+
+/// A request body and its size in bytes, if known up front.
+pub(crate) struct UploadBody {
+    pub(crate) body: hpx::Body,
+    pub(crate) len: Option<u64>,
+}
+
+impl From<Vec<u8>> for UploadBody {
+    fn from(body: Vec<u8>) -> Self {
+        let len = Some(body.len() as u64);
+        Self { body: body.into(), len }
+    }
+}
+
+/// Builds a streaming `multipart/form-data` body with `input` as the part
+/// named `field_name`, followed by the text `fields`.
+///
+/// A file at a path is opened here, so a missing file fails before any
+/// request is sent.
+pub(crate) async fn multipart_body(
+    boundary: &str,
+    field_name: &str,
+    input: FileInput,
+    fields: &[(&str, String)],
+) -> Result<UploadBody> {
+    let (head, tail) = envelope(boundary, field_name, &input, fields);
+    let (file, file_len) = match input.source {
+        Source::Bytes(bytes) => {
+            let len = bytes.len() as u64;
+            (stream::once(async move { Ok(bytes) }).boxed(), Some(len))
+        }
+        Source::Path(path) => {
+            let file = tokio::fs::File::open(&path).await.map_err(|e| {
+                ElevenLabsError::Validation(format!(
+                    "cannot open upload file {}: {e}",
+                    path.display()
+                ))
+            })?;
+            let len = file.metadata().await?.len();
+            (ReaderStream::new(file).boxed(), Some(len))
+        }
+        Source::Reader(reader) => (ReaderStream::new(reader).boxed(), None),
+    };
+
+    let len = file_len.map(|file_len| head.len() as u64 + file_len + tail.len() as u64);
+    let body = stream::once(async move { Ok(Bytes::from(head)) })
+        .chain(file)
+        .chain(stream::once(async move { Ok::<_, std::io::Error>(Bytes::from(tail)) }));
+    Ok(UploadBody { body: hpx::Body::wrap_stream(body), len })
+}
+
+/// A file part whose contents arrive as a stream of chunks.
+pub(crate) struct StreamedPart {
+    pub(crate) filename: String,
+    pub(crate) content_type: String,
+    pub(crate) body: BoxStream<'static, std::io::Result<Bytes>>,
+}
+
+/// Builds a streaming `multipart/form-data` body with the text `fields`
+/// followed by `parts`, each named `field_name`.
+pub(crate) fn multipart_parts_body(
+    boundary: &str,
+    field_name: &str,
+    fields: &[(&str, String)],
+    parts: Vec<StreamedPart>,
+) -> UploadBody {
+    let body = parts_stream(boundary, field_name, fields, parts);
+    UploadBody { body: hpx::Body::wrap_stream(body), len: None }
+}
+
+/// Returns the chunks of a [`multipart_parts_body`].
+fn parts_stream(
+    boundary: &str,
+    field_name: &str,
+    fields: &[(&str, String)],
+    parts: Vec<StreamedPart>,
+) -> BoxStream<'static, std::io::Result<Bytes>> {
+    let mut head = String::new();
+    for (name, value) in fields {
+        head.push_str(&format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+        ));
+    }
+    let mut body = stream::once(async move { Ok(Bytes::from(head)) }).boxed();
+    for part in parts {
+        let part_head = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{field_name}\"; \
+             filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+            part.filename, part.content_type
+        );
+        body = body
+            .chain(stream::once(async move { Ok(Bytes::from(part_head)) }))
+            .chain(part.body)
+            .chain(stream::once(async { Ok(Bytes::from_static(b"\r\n")) }))
+            .boxed();
+    }
+    let tail = format!("--{boundary}--\r\n");
+    body.chain(stream::once(async move { Ok(Bytes::from(tail)) })).boxed()
+}
+
+/// Returns the parts of the body before and after the file contents.
+fn envelope(
+    boundary: &str,
+    field_name: &str,
+    input: &FileInput,
+    fields: &[(&str, String)],
+) -> (String, String) {
+    let head = format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"{field_name}\"; \
+         filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+        input.filename, input.content_type
+    );
+    let mut tail = String::from("\r\n");
+    for (name, value) in fields {
+        tail.push_str(&format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+        ));
+    }
+    tail.push_str(&format!("--{boundary}--\r\n"));
+    (head, tail)
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_path_guesses_filename_and_content_type() {
+        let input = FileInput::from_path("/tmp/clips/Take 1.FLAC");
+        assert_eq!(input.filename(), "Take 1.FLAC");
+        assert_eq!(input.content_type(), "audio/flac");
+
+        let input = FileInput::from_path("notes.bin").with_content_type("audio/mpeg");
+        assert_eq!(input.content_type(), "audio/mpeg");
+        assert_eq!(FileInput::from_path("noext").content_type(), DEFAULT_CONTENT_TYPE);
+    }
+
+    #[test]
+    fn envelope_wraps_file_and_fields() {
+        let input = FileInput::from_bytes(&b"RIFF"[..], "a.wav", "audio/wav");
+        let fields = [("file_format", "other".to_owned())];
+        let (head, tail) = envelope("b", "audio", &input, &fields);
+        assert_eq!(
+            head,
+            "--b\r\nContent-Disposition: form-data; name=\"audio\"; filename=\"a.wav\"\r\n\
+             Content-Type: audio/wav\r\n\r\n"
+        );
+        assert_eq!(
+            tail,
+            "\r\n--b\r\nContent-Disposition: form-data; name=\"file_format\"\r\n\r\nother\r\n\
+             --b--\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn multipart_body_length_is_known_for_paths() {
+        let path =
+            std::env::temp_dir().join(format!("elevenlabs-upload-{}.wav", std::process::id()));
+        std::fs::write(&path, b"RIFF").unwrap();
+
+        let input = FileInput::from_path(&path);
+        let (head, tail) = envelope("b", "audio", &input, &[]);
+        let body = multipart_body("b", "audio", input, &[]).await.unwrap();
+        assert_eq!(body.len, Some((head.len() + 4 + tail.len()) as u64));
+
+        let reader = FileInput::from_reader(tokio::io::empty(), "a.mp3", "audio/mpeg");
+        assert_eq!(multipart_body("b", "audio", reader, &[]).await.unwrap().len, None);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn parts_stream_chains_fields_and_parts() {
+        let parts = ["one", "two"]
+            .into_iter()
+            .map(|data| StreamedPart {
+                filename: format!("{data}.mp3"),
+                content_type: "audio/mpeg".to_owned(),
+                body: stream::iter([Ok(Bytes::from(data))]).boxed(),
+            })
+            .collect();
+        let fields = [("name", "V".to_owned())];
+        let chunks: Vec<_> = parts_stream("b", "files", &fields, parts).collect().await;
+        let bytes: Vec<u8> = chunks.into_iter().flat_map(|chunk| chunk.unwrap()).collect();
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            "--b\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\nV\r\n\
+             --b\r\nContent-Disposition: form-data; name=\"files\"; filename=\"one.mp3\"\r\n\
+             Content-Type: audio/mpeg\r\n\r\none\r\n\
+             --b\r\nContent-Disposition: form-data; name=\"files\"; filename=\"two.mp3\"\r\n\
+             Content-Type: audio/mpeg\r\n\r\ntwo\r\n\
+             --b--\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn multipart_body_rejects_missing_file() {
+        let input = FileInput::from_path("/definitely/not/here.mp3");
+        let err = multipart_body("b", "audio", input, &[]).await.err().unwrap();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+    }
+}
@@ -0,0 +1,410 @@
+//! Webhook signature verification and typed webhook payloads.
+//!
+//! ElevenLabs signs every webhook request (post-call transcription and audio,
+//! call initiation failures, asynchronous speech-to-text results) with an
+//! HMAC-SHA256 signature sent in the `ElevenLabs-Signature` header:
+//!
+//! ```text
+//! ElevenLabs-Signature: t=1739537297,v0=<hex-encoded HMAC>
+//! ```
+//!
+//! The HMAC is computed over `"{timestamp}.{raw_body}"` using the webhook
+//! secret shown in the ElevenLabs dashboard. [`verify_signature`] checks both
+//! the signature and the timestamp freshness, and [`construct_event`]
+//! additionally parses the body into a [`WebhookEvent`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use elevenlabs_sdk::webhooks::{self, WebhookEvent};
+//!
+//! # fn handle(headers: &hpx::header::HeaderMap, body: &[u8]) -> elevenlabs_sdk::Result<()> {
+//! match webhooks::construct_event("whsec_...", headers, body)? {
+//!     WebhookEvent::PostCallTranscription { data, .. } => {
+//!         println!("conversation {} finished", data.conversation_id);
+//!     }
+//!     WebhookEvent::SpeechToTextCompleted { data, .. } => {
+//!         println!("transcript: {}", data.transcription.text);
+//!     }
+//!     _ => {}
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use hpx::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+#[cfg(feature = "convai")]
+use crate::types::GetConversationResponse;
+use crate::{
+    error::{ElevenLabsError, Result},
+    types::SpeechToTextChunkResponse,
+};
+
+/// HTTP header carrying the webhook signature.
+pub const SIGNATURE_HEADER: &str = "elevenlabs-signature";
+
+/// Default maximum age of a webhook timestamp (30 minutes).
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_mins(30);
+
+type HmacSha256 = Hmac<Sha256>;
+
+// ---------------------------------------------------------------------------
+// Signature verification
+// ---------------------------------------------------------------------------
+
+/// Verifies the `ElevenLabs-Signature` header of an incoming webhook.
+///
+/// `body` must be the raw, unmodified request body. The timestamp embedded in
+/// the header must be within [`DEFAULT_TOLERANCE`] of the current time.
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::WebhookSignature`] if the header is missing or
+/// malformed, the timestamp is outside the tolerance window, or no signature
+/// matches.
+pub fn verify_signature(secret: &str, headers: &HeaderMap, body: &[u8]) -> Result<()> {
+    let header = headers
+        .get(SIGNATURE_HEADER)
+        .ok_or_else(|| ElevenLabsError::WebhookSignature("missing signature header".to_owned()))?
+        .to_str()
+        .map_err(|_| {
+            ElevenLabsError::WebhookSignature("signature header is not ASCII".to_owned())
+        })?;
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    verify_signature_header(secret, header, body, now, DEFAULT_TOLERANCE)
+}
+
+/// Verifies a raw signature header value against `body`.
+///
+/// This is the lower-level form of [`verify_signature`] that takes the header
+/// value directly, the current Unix time, and a custom tolerance window.
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::WebhookSignature`] if the header is malformed,
+/// the timestamp is outside `tolerance`, or no signature matches.
+pub fn verify_signature_header(
+    secret: &str,
+    header: &str,
+    body: &[u8],
+    now_unix_secs: u64,
+    tolerance: Duration,
+) -> Result<()> {
+    let mut timestamp: Option<&str> = None;
+    let mut signatures: Vec<&str> = Vec::new();
+
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = Some(value),
+            Some(("v0", value)) => signatures.push(value),
+            _ => {}
+        }
+    }
+
+    let timestamp = timestamp
+        .ok_or_else(|| ElevenLabsError::WebhookSignature("missing timestamp".to_owned()))?;
+    let timestamp_secs: u64 = timestamp
+        .parse()
+        .map_err(|_| ElevenLabsError::WebhookSignature("invalid timestamp".to_owned()))?;
+
+    if now_unix_secs.abs_diff(timestamp_secs) > tolerance.as_secs() {
+        return Err(ElevenLabsError::WebhookSignature(
+            "timestamp outside tolerance window".to_owned(),
+        ));
+    }
+
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+        .map_err(|e| ElevenLabsError::WebhookSignature(format!("invalid secret: {e}")))?;
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    let matched = signatures
+        .iter()
+        .filter_map(|sig| decode_hex(sig))
+        .any(|sig| mac.clone().verify_slice(&sig).is_ok());
+
+    if matched {
+        Ok(())
+    } else {
+        Err(ElevenLabsError::WebhookSignature("signature mismatch".to_owned()))
+    }
+}
+
+/// Verifies the webhook signature and parses the body into a
+/// [`WebhookEvent`].
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::WebhookSignature`] if verification fails, or
+/// [`ElevenLabsError::Deserialization`] if the body is not a valid event.
+pub fn construct_event(secret: &str, headers: &HeaderMap, body: &[u8]) -> Result<WebhookEvent> {
+    verify_signature(secret, headers, body)?;
+    parse_event(body)
+}
+
+/// Parses a webhook body into a [`WebhookEvent`] without verifying it.
+///
+/// Only use this for payloads that were already verified (e.g. by a gateway).
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::Deserialization`] if the body is not a valid
+/// event.
+pub fn parse_event(body: &[u8]) -> Result<WebhookEvent> {
+    Ok(serde_json::from_slice(body)?)
+}
+
+/// Decodes a lowercase or uppercase hex string into bytes.
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok()).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Payloads
+// ---------------------------------------------------------------------------
+
+/// A webhook event delivered by ElevenLabs.
+///
+/// The variant is selected by the `type` field of the payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// A conversation finished and its transcript and analysis are available.
+    ///
+    /// Without the `convai` feature these events parse as
+    /// [`Unknown`](Self::Unknown).
+    #[cfg(feature = "convai")]
+    PostCallTranscription {
+        /// Unix timestamp (seconds) at which the event was emitted.
+        event_timestamp: i64,
+        /// Full conversation details, boxed since they dwarf the other
+        /// payloads.
+        data: Box<GetConversationResponse>,
+    },
+
+    /// A conversation finished and its full audio is available.
+    PostCallAudio {
+        /// Unix timestamp (seconds) at which the event was emitted.
+        event_timestamp: i64,
+        /// Conversation audio payload.
+        data: PostCallAudioData,
+    },
+
+    /// An outbound call could not be initiated.
+    CallInitiationFailure {
+        /// Unix timestamp (seconds) at which the event was emitted.
+        event_timestamp: i64,
+        /// Failure details.
+        data: CallInitiationFailureData,
+    },
+
+    /// An asynchronous speech-to-text transcription finished.
+    #[serde(rename = "speech_to_text_transcription")]
+    SpeechToTextCompleted {
+        /// Unix timestamp (seconds) at which the event was emitted.
+        event_timestamp: i64,
+        /// Transcription result.
+        data: SpeechToTextCompletedData,
+    },
+
+    /// An event type not yet modelled by this SDK.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Payload of a [`WebhookEvent::PostCallAudio`] event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostCallAudioData {
+    /// Agent that handled the conversation.
+    pub agent_id: String,
+    /// Conversation identifier.
+    pub conversation_id: String,
+    /// Base64-encoded full conversation audio (MP3).
+    pub full_audio: String,
+}
+
+/// Payload of a [`WebhookEvent::CallInitiationFailure`] event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallInitiationFailureData {
+    /// Agent that was supposed to handle the call.
+    pub agent_id: String,
+    /// Conversation identifier.
+    pub conversation_id: String,
+    /// Reason reported by the telephony provider (e.g. `"busy"`, `"no-answer"`).
+    pub failure_reason: Option<String>,
+    /// Provider-specific failure metadata.
+    #[serde(default)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// Payload of a [`WebhookEvent::SpeechToTextCompleted`] event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeechToTextCompletedData {
+    /// Request ID returned when the transcription was submitted.
+    pub request_id: Option<String>,
+    /// The completed transcription.
+    pub transcription: SpeechToTextChunkResponse,
+    /// Metadata supplied via `webhook_metadata` at submission time.
+    #[serde(default)]
+    pub webhook_metadata: Option<serde_json::Value>,
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, clippy::panic, reason = "tests use unwrap and panic")]
+mod tests {
+    use hpx::header::HeaderValue;
+
+    use super::*;
+    use crate::download::hex;
+
+    const SECRET: &str = "whsec_test";
+
+    fn sign(secret: &str, timestamp: u64, body: &[u8]) -> String {
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("{timestamp}.").as_bytes());
+        mac.update(body);
+        format!("t={timestamp},v0={}", hex(&mac.finalize().into_bytes()))
+    }
+
+    fn now() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    #[test]
+    fn verify_signature_accepts_valid_header() {
+        let body = br#"{"type":"post_call_audio"}"#;
+        let mut headers = HeaderMap::new();
+        headers
+            .insert(SIGNATURE_HEADER, HeaderValue::from_str(&sign(SECRET, now(), body)).unwrap());
+
+        assert!(verify_signature(SECRET, &headers, body).is_ok());
+    }
+
+    #[test]
+    fn verify_signature_rejects_wrong_secret() {
+        let body = b"{}";
+        let header = sign("other-secret", 1_000, body);
+        let err =
+            verify_signature_header(SECRET, &header, body, 1_000, DEFAULT_TOLERANCE).unwrap_err();
+        assert!(matches!(err, ElevenLabsError::WebhookSignature(_)));
+    }
+
+    #[test]
+    fn verify_signature_rejects_tampered_body() {
+        let header = sign(SECRET, 1_000, b"{\"a\":1}");
+        let result =
+            verify_signature_header(SECRET, &header, b"{\"a\":2}", 1_000, DEFAULT_TOLERANCE);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn verify_signature_rejects_stale_timestamp() {
+        let body = b"{}";
+        let header = sign(SECRET, 1_000, body);
+        let result = verify_signature_header(
+            SECRET,
+            &header,
+            body,
+            1_000 + DEFAULT_TOLERANCE.as_secs() + 1,
+            DEFAULT_TOLERANCE,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn verify_signature_rejects_missing_header() {
+        let err = verify_signature(SECRET, &HeaderMap::new(), b"{}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Webhook signature verification failed: missing signature header"
+        );
+    }
+
+    #[test]
+    fn verify_signature_rejects_malformed_header() {
+        let result = verify_signature_header(SECRET, "garbage", b"{}", 0, DEFAULT_TOLERANCE);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_post_call_audio_event() {
+        let body = br#"{
+            "type": "post_call_audio",
+            "event_timestamp": 1739537297,
+            "data": {"agent_id": "a1", "conversation_id": "c1", "full_audio": "SGVsbG8="}
+        }"#;
+        match parse_event(body).unwrap() {
+            WebhookEvent::PostCallAudio { event_timestamp, data } => {
+                assert_eq!(event_timestamp, 1_739_537_297);
+                assert_eq!(data.conversation_id, "c1");
+            }
+            other => panic!("expected PostCallAudio, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_speech_to_text_event() {
+        let body = br#"{
+            "type": "speech_to_text_transcription",
+            "event_timestamp": 1739537297,
+            "data": {
+                "request_id": "req-1",
+                "transcription": {
+                    "language_code": "eng",
+                    "language_probability": 0.99,
+                    "text": "Hello",
+                    "words": []
+                }
+            }
+        }"#;
+        match parse_event(body).unwrap() {
+            WebhookEvent::SpeechToTextCompleted { data, .. } => {
+                assert_eq!(data.transcription.text, "Hello");
+                assert_eq!(data.request_id.as_deref(), Some("req-1"));
+            }
+            other => panic!("expected SpeechToTextCompleted, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_call_initiation_failure_event() {
+        let body = br#"{
+            "type": "call_initiation_failure",
+            "event_timestamp": 1,
+            "data": {"agent_id": "a1", "conversation_id": "c1", "failure_reason": "busy"}
+        }"#;
+        let event = parse_event(body).unwrap();
+        assert!(matches!(
+            event,
+            WebhookEvent::CallInitiationFailure { ref data, .. } if data.failure_reason.as_deref() == Some("busy")
+        ));
+    }
+
+    #[test]
+    fn parse_unknown_event() {
+        let event = parse_event(br#"{"type": "future_event", "data": {}}"#).unwrap();
+        assert_eq!(event, WebhookEvent::Unknown);
+    }
+
+    #[test]
+    fn decode_hex_round_trip() {
+        assert_eq!(decode_hex("00ff10"), Some(vec![0x00, 0xff, 0x10]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+}
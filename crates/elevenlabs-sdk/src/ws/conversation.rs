@@ -9,69 +9,149 @@
 //! 1. Obtain a signed URL via
 //!    [`AgentsService::get_conversation_signed_url`](crate::services::AgentsService::get_conversation_signed_url).
 //! 2. Connect to the signed URL with [`ConversationWebSocket::connect`].
-//! 3. Send audio via [`ConversationWebSocket::send_audio`].
-//! 4. Receive events via [`ConversationWebSocket::recv`].
-//! 5. Respond to [`ConversationEvent::Ping`] with [`ConversationWebSocket::send_pong`] to keep the
-//!    connection alive.
+//! 3. Optionally set dynamic variables with [`ConversationWebSocket::update_dynamic_variables`]
+//!    before sending anything else.
+//! 4. Send audio via [`ConversationWebSocket::send_audio`], or text via
+//!    [`ConversationWebSocket::send_user_message`]. Background information the agent should know
+//!    without replying to it goes through [`ConversationWebSocket::send_contextual_update`].
+//! 5. Receive events via [`ConversationWebSocket::recv`].
+//! 6. Respond to [`ConversationEvent::Ping`] with [`ConversationWebSocket::send_pong`] to keep the
+//!    connection alive, or enable [`ConversationWebSocket::with_auto_pong`].
+//!
+//! # Reconnection
+//!
+//! With [`ConversationWebSocket::with_reconnect`], when the socket drops,
+//! [`ConversationWebSocket::recv`] opens a new socket to the same agent
+//! instead of returning `None`. The last dynamic variables are sent again
+//! and [`ConversationEvent::Reconnected`] is returned before any events from
+//! the new socket.
+//!
+//! This replaces the conversation rather than resuming it. The
+//! Conversational AI WebSocket has no way to rejoin a conversation by its
+//! ID, so the server starts a new one with a new ID and the agent does not
+//! remember what was said on the dropped socket. Audio or messages in
+//! flight when the socket dropped are lost. A conversation that ended is not reopened: the socket
+//! is left closed after a normal close (code 1000), such as one started by
+//! [`ConversationWebSocket::close`], and after the agent ends the call with
+//! the `end_call` tool.
 
-use base64::Engine;
-use hpx_transport::websocket::{
-    Connection, ConnectionHandle, ConnectionStream, Event, WsConfig, WsMessage,
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
 };
+
+use base64::Engine;
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use tracing::{Instrument, debug, warn};
+use url::Url;
 
 use crate::{
     client::ElevenLabsClient,
+    config::{ClientConfig, NetworkConfig, WsTransportConfig},
+    deadline,
     error::{ElevenLabsError, Result},
-    ws::conversation_handler::ConversationProtocolHandler,
+    middleware,
+    types::SingleUseToken,
+    ws::{
+        WsObserver, build_ws_url, client_headers, connect_deadline, token_query_param,
+        transport::{self, Incoming, WsHandle, WsStream},
+    },
 };
 
+/// Close code of a normal closure, which ends the conversation instead of
+/// triggering a reconnect.
+const NORMAL_CLOSURE: u16 = 1000;
+
+/// Tool the agent runs to end the call.
+const END_CALL_TOOL: &str = "end_call";
+
+/// Default base delay between reconnection attempts.
+pub const DEFAULT_RECONNECT_BACKOFF: Duration = Duration::from_millis(500);
+
 /// Events received from the Conversational AI WebSocket.
 ///
 /// Each variant corresponds to a server-sent event type identified by the
-/// `"type"` field in the JSON payload.
+/// `"type"` field in the JSON payload, with the payload in a field named
+/// like the frame's event object.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "type")]
 pub enum ConversationEvent {
     /// Initial metadata sent when the conversation begins.
     #[serde(rename = "conversation_initiation_metadata")]
     InitiationMetadata {
-        /// Raw metadata payload.
-        #[serde(flatten)]
-        metadata: serde_json::Value,
+        /// Conversation ID and audio formats.
+        #[serde(rename = "conversation_initiation_metadata_event")]
+        metadata: ConversationMetadata,
     },
 
-    /// An audio chunk from the agent (base64-encoded).
+    /// An audio chunk from the agent.
     #[serde(rename = "audio")]
     Audio {
-        /// Base64-encoded audio data.
-        audio: AudioEvent,
+        /// The audio chunk.
+        audio_event: AudioEvent,
     },
 
     /// A text response from the agent.
     #[serde(rename = "agent_response")]
     AgentResponse {
-        /// The agent's response text.
-        agent_response_text: String,
+        /// The agent's response.
+        agent_response_event: AgentResponseEvent,
+    },
+
+    /// The agent's last response was cut short by an interruption; the
+    /// correction holds the part the user actually heard.
+    #[serde(rename = "agent_response_correction")]
+    AgentResponseCorrection {
+        /// The original and corrected response.
+        agent_response_correction_event: AgentResponseCorrectionEvent,
+    },
+
+    /// A preliminary agent response, sent while the final one is generated.
+    #[serde(rename = "internal_tentative_agent_response")]
+    InternalTentativeAgentResponse {
+        /// The tentative response.
+        tentative_agent_response_internal_event: TentativeAgentResponseEvent,
     },
 
     /// A transcript of the user's speech.
     #[serde(rename = "user_transcript")]
     UserTranscript {
-        /// The transcribed user text.
-        user_transcript_text: String,
+        /// The transcribed user speech.
+        user_transcription_event: UserTranscriptEvent,
+    },
+
+    /// Voice activity detection score for the user's audio.
+    #[serde(rename = "vad_score")]
+    VadScore {
+        /// The score.
+        vad_score_event: VadScoreEvent,
     },
 
     /// The agent was interrupted by the user.
     #[serde(rename = "interruption")]
     Interruption {
-        /// Raw interruption payload.
-        #[serde(flatten)]
-        data: serde_json::Value,
+        /// The interruption.
+        interruption_event: InterruptionEvent,
+    },
+
+    /// The agent asks the client to run a client tool.
+    #[serde(rename = "client_tool_call")]
+    ClientToolCall {
+        /// The tool call.
+        client_tool_call: ClientToolCallEvent,
+    },
+
+    /// The agent ran a server-side tool.
+    #[serde(rename = "agent_tool_response")]
+    AgentToolResponse {
+        /// The tool's outcome.
+        agent_tool_response: AgentToolResponseEvent,
     },
 
-    /// A keep-alive ping from the server. Respond with [`ConversationWebSocket::send_pong`].
+    /// A keep-alive ping from the server. Respond with
+    /// [`ConversationWebSocket::send_pong`], or let
+    /// [`ConversationWebSocket::with_auto_pong`] answer it.
     #[serde(rename = "ping")]
     Ping {
         /// The ping event payload containing an event ID.
@@ -86,16 +166,139 @@ pub enum ConversationEvent {
         data: serde_json::Value,
     },
 
-    /// An event type not yet modelled by this SDK.
-    #[serde(other)]
-    Unknown,
+    /// The socket dropped and a new conversation was started on a new
+    /// socket under the [`ReconnectPolicy`]. Produced by the client, never
+    /// sent by the server.
+    ///
+    /// The dropped conversation is over; the API cannot resume it. A fresh
+    /// [`InitiationMetadata`](Self::InitiationMetadata) with a new
+    /// conversation ID follows. Use `previous_conversation_id` to link the
+    /// two transcripts, and [`ConversationWebSocket::send_contextual_update`]
+    /// to tell the agent what was said before the drop.
+    #[serde(skip_deserializing)]
+    Reconnected {
+        /// Which attempt succeeded, starting at 1.
+        attempt: u32,
+        /// ID of the conversation on the dropped socket, if it was known.
+        previous_conversation_id: Option<String>,
+    },
+
+    /// A frame this SDK does not model, or a known frame whose payload did
+    /// not match, passed through as received.
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+/// Payload of a conversation initiation metadata event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConversationMetadata {
+    /// ID of the conversation.
+    pub conversation_id: String,
+    /// Format of the agent's audio (e.g. `"pcm_16000"`).
+    #[serde(default)]
+    pub agent_output_audio_format: Option<String>,
+    /// Format the agent expects user audio in.
+    #[serde(default)]
+    pub user_input_audio_format: Option<String>,
+    /// Other metadata sent by the server.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 /// Payload of an audio event from the server.
 #[derive(Debug, Clone, Deserialize)]
 pub struct AudioEvent {
     /// Base64-encoded audio chunk.
-    pub chunk: Option<String>,
+    pub audio_base_64: String,
+    /// ID of the event, matched by later interruptions.
+    #[serde(default)]
+    pub event_id: i64,
+}
+
+impl AudioEvent {
+    /// Decodes the audio chunk.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the chunk is not valid
+    /// base64.
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        base64::engine::general_purpose::STANDARD
+            .decode(&self.audio_base_64)
+            .map_err(|e| ElevenLabsError::Validation(format!("invalid audio chunk: {e}")))
+    }
+}
+
+/// Payload of an agent response event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AgentResponseEvent {
+    /// The agent's response text.
+    pub agent_response: String,
+}
+
+/// Payload of an agent response correction event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AgentResponseCorrectionEvent {
+    /// The response as generated.
+    pub original_agent_response: String,
+    /// The part of the response spoken before the interruption.
+    pub corrected_agent_response: String,
+}
+
+/// Payload of a tentative agent response event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TentativeAgentResponseEvent {
+    /// The preliminary response text.
+    pub tentative_agent_response: String,
+}
+
+/// Payload of a user transcript event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserTranscriptEvent {
+    /// The transcribed user text.
+    pub user_transcript: String,
+}
+
+/// Payload of a voice activity detection event.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct VadScoreEvent {
+    /// Probability that the user is speaking, from 0 to 1.
+    pub vad_score: f64,
+}
+
+/// Payload of an interruption event.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct InterruptionEvent {
+    /// ID of the audio event the interruption applies to.
+    #[serde(default)]
+    pub event_id: i64,
+}
+
+/// Payload of a client tool call event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClientToolCallEvent {
+    /// Name of the tool to run.
+    pub tool_name: String,
+    /// ID of the call.
+    pub tool_call_id: String,
+    /// Arguments for the tool.
+    #[serde(default)]
+    pub parameters: serde_json::Value,
+}
+
+/// Payload of an agent tool response event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AgentToolResponseEvent {
+    /// Name of the tool that ran.
+    pub tool_name: String,
+    /// ID of the call.
+    pub tool_call_id: String,
+    /// Kind of tool (e.g. `"system"` or `"webhook"`).
+    #[serde(default)]
+    pub tool_type: Option<String>,
+    /// Whether the tool failed.
+    #[serde(default)]
+    pub is_error: bool,
 }
 
 /// Payload of a ping event from the server.
@@ -103,6 +306,9 @@ pub struct AudioEvent {
 pub struct PingEvent {
     /// The event ID to echo back in a pong response.
     pub event_id: i64,
+    /// Measured round-trip latency in milliseconds, if reported.
+    #[serde(default)]
+    pub ping_ms: Option<u64>,
 }
 
 // -- Client messages ----------------------------------------------------------
@@ -124,12 +330,84 @@ enum ClientMessage {
         /// The event ID from the original ping.
         event_id: i64,
     },
+
+    /// Information for the agent that does not call for a reply.
+    #[serde(rename = "contextual_update")]
+    ContextualUpdate {
+        /// The context to add.
+        text: String,
+    },
+
+    /// A text message from the user, answered like speech.
+    #[serde(rename = "user_message")]
+    UserMessage {
+        /// The message text.
+        text: String,
+    },
+
+    /// Client data for the conversation, sent before any other message.
+    #[serde(rename = "conversation_initiation_client_data")]
+    InitiationClientData {
+        /// Values for the agent's dynamic variables.
+        dynamic_variables: HashMap<String, serde_json::Value>,
+    },
+}
+
+/// When and how often [`ConversationWebSocket`] re-opens a dropped socket.
+///
+/// Attempts are spaced with exponential backoff starting at `backoff`,
+/// capped at 30 seconds.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use elevenlabs_sdk::ws::conversation::ReconnectPolicy;
+///
+/// let policy = ReconnectPolicy::new(5).backoff(Duration::from_secs(1));
+/// assert_eq!(policy.max_attempts, 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Attempts made before [`ConversationWebSocket::recv`] gives up.
+    pub max_attempts: u32,
+    /// Delay before the first attempt; doubles for each further attempt.
+    pub backoff: Duration,
+}
+
+impl ReconnectPolicy {
+    /// Creates a policy making up to `max_attempts` attempts, starting with
+    /// [`DEFAULT_RECONNECT_BACKOFF`].
+    pub const fn new(max_attempts: u32) -> Self {
+        Self { max_attempts, backoff: DEFAULT_RECONNECT_BACKOFF }
+    }
+
+    /// Sets the delay before the first attempt.
+    pub const fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Returns the delay before `attempt` (starting at 1).
+    fn delay(&self, attempt: u32) -> Duration {
+        middleware::compute_delay(attempt.saturating_sub(1), self.backoff, None)
+    }
+}
+
+/// Where a dropped conversation reconnects to.
+enum ReconnectTarget {
+    /// The signed URL the socket was opened with.
+    SignedUrl(String),
+    /// A fresh signed URL for the agent, fetched with the client.
+    Agent { client: Box<ElevenLabsClient>, agent_id: String },
 }
 
 /// Conversational AI WebSocket client for real-time agent interaction.
 ///
 /// Supports sending audio frames and receiving typed conversation events
-/// (transcripts, agent responses, audio, pings, etc.).
+/// (transcripts, agent responses, audio, pings, etc.). To send from one
+/// task and receive in another, [`split`](Self::split) it.
 ///
 /// # Example
 ///
@@ -144,8 +422,8 @@ enum ClientMessage {
 ///
 /// while let Some(event) = conv.recv().await? {
 ///     match event {
-///         elevenlabs_sdk::ConversationEvent::AgentResponse { agent_response_text } => {
-///             println!("Agent: {agent_response_text}");
+///         elevenlabs_sdk::ConversationEvent::AgentResponse { agent_response_event } => {
+///             println!("Agent: {}", agent_response_event.agent_response);
 ///         }
 ///         elevenlabs_sdk::ConversationEvent::Ping { ping_event } => {
 ///             conv.send_pong(ping_event.event_id).await?;
@@ -156,14 +434,87 @@ enum ClientMessage {
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Debug)]
 pub struct ConversationWebSocket {
-    handle: ConnectionHandle,
-    stream: ConnectionStream,
+    sender: ConversationSender,
+    receiver: ConversationReceiver,
+}
+
+/// Sending half of a [`ConversationWebSocket`], returned by
+/// [`ConversationWebSocket::split`].
+///
+/// Clones share the connection. After a reconnect made by the
+/// [`ConversationReceiver`], frames go to the new socket.
+#[derive(Clone)]
+pub struct ConversationSender {
+    shared: Arc<ConversationShared>,
+}
+
+/// Receiving half of a [`ConversationWebSocket`], returned by
+/// [`ConversationWebSocket::split`].
+///
+/// Keeps the reconnect policy and automatic pongs of the client it was
+/// split from.
+pub struct ConversationReceiver {
+    stream: WsStream,
+    shared: Arc<ConversationShared>,
+    config: Option<ClientConfig>,
+    target: ReconnectTarget,
+    reconnect: Option<ReconnectPolicy>,
+    auto_pong: bool,
+    conversation_id: Option<String>,
+    /// Whether the agent ended the call, so the next close is final.
+    ended: bool,
+}
+
+/// State shared by the halves of a conversation.
+struct ConversationShared {
+    /// Current socket, replaced on reconnect.
+    link: Mutex<(WsHandle, Arc<WsObserver>)>,
+    /// Last `conversation_initiation_client_data` frame, replayed on
+    /// reconnect.
+    initiation: Mutex<Option<String>>,
+}
+
+impl ConversationShared {
+    fn new(handle: WsHandle, observer: WsObserver) -> Self {
+        Self { link: Mutex::new((handle, Arc::new(observer))), initiation: Mutex::new(None) }
+    }
+
+    /// Returns the current socket.
+    fn link(&self) -> (WsHandle, Arc<WsObserver>) {
+        let link = self.link.lock().unwrap_or_else(PoisonError::into_inner);
+        (link.0.clone(), Arc::clone(&link.1))
+    }
+
+    /// Sends one JSON text frame and records it; `action` prefixes the
+    /// error message.
+    async fn send_frame(&self, json: String, action: &str) -> Result<()> {
+        let (handle, observer) = self.link();
+        let bytes = json.len();
+        handle
+            .send_text(json)
+            .instrument(observer.span().clone())
+            .await
+            .map_err(|e| ElevenLabsError::WebSocket(format!("{action} failed: {e}")))?;
+        observer.sent(bytes);
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for ConversationSender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConversationSender").finish_non_exhaustive()
+    }
 }
 
-impl std::fmt::Debug for ConversationWebSocket {
+impl std::fmt::Debug for ConversationReceiver {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConversationWebSocket").finish_non_exhaustive()
+        f.debug_struct("ConversationReceiver")
+            .field("reconnect", &self.reconnect)
+            .field("auto_pong", &self.auto_pong)
+            .field("conversation_id", &self.conversation_id)
+            .finish_non_exhaustive()
     }
 }
 
@@ -178,18 +529,39 @@ impl ConversationWebSocket {
     /// Returns [`ElevenLabsError::WebSocket`] if the connection or upgrade
     /// fails.
     pub async fn connect(signed_url: &str) -> Result<Self> {
-        debug!(url = %signed_url, "connecting to Conversational AI WebSocket");
-
-        let handler = ConversationProtocolHandler;
-        let transport_config =
-            WsConfig::new(signed_url).reconnect_max_attempts(Some(0)).use_websocket_ping(true);
+        Self::connect_observed(signed_url, None).await
+    }
 
-        let (handle, stream) = Connection::connect(transport_config, handler)
-            .await
-            .map_err(|e| ElevenLabsError::WebSocket(format!("connection failed: {e}")))?;
+    /// Connect using a pre-obtained signed URL, with spans, metrics and
+    /// network settings following [`ClientConfig::tracing_spans`],
+    /// [`ClientConfig::metrics_sink`] and [`ClientConfig::network`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the connection or upgrade
+    /// fails.
+    pub async fn connect_with_config(signed_url: &str, config: &ClientConfig) -> Result<Self> {
+        Self::connect_observed(signed_url, Some(config)).await
+    }
 
-        debug!("Conversational AI WebSocket connected");
-        Ok(Self { handle, stream })
+    /// Shared implementation of [`connect`](Self::connect) and
+    /// [`connect_with_config`](Self::connect_with_config).
+    async fn connect_observed(signed_url: &str, config: Option<&ClientConfig>) -> Result<Self> {
+        let (handle, stream, observer) = open(signed_url, config).await?;
+        let shared = Arc::new(ConversationShared::new(handle, observer));
+        Ok(Self {
+            sender: ConversationSender { shared: Arc::clone(&shared) },
+            receiver: ConversationReceiver {
+                stream,
+                shared,
+                config: config.cloned(),
+                target: ReconnectTarget::SignedUrl(signed_url.to_owned()),
+                reconnect: None,
+                auto_pong: false,
+                conversation_id: None,
+                ended: false,
+            },
+        })
     }
 
     /// Connect by agent ID.
@@ -203,7 +575,104 @@ impl ConversationWebSocket {
     pub async fn connect_with_agent(client: &ElevenLabsClient, agent_id: &str) -> Result<Self> {
         debug!(agent_id, "fetching signed URL for conversation");
         let resp = client.agents().get_conversation_signed_url(agent_id).await?;
-        Self::connect(&resp.signed_url).await
+        let mut ws = Self::connect_with_config(&resp.signed_url, client.config()).await?;
+        ws.receiver.target = ReconnectTarget::Agent {
+            client: Box::new(client.with_options(client.options().clone())),
+            agent_id: agent_id.to_owned(),
+        };
+        Ok(ws)
+    }
+
+    /// Connect to an agent with a single-use token instead of a signed URL.
+    ///
+    /// Lets an untrusted client join a conversation without holding the API
+    /// key; only the base URL and network settings of `config` are used.
+    /// The token is consumed by this connection, so a reconnect configured
+    /// with [`with_reconnect`](Self::with_reconnect) will be rejected by the
+    /// server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the token has expired, or
+    /// [`ElevenLabsError::WebSocket`] if the connection or upgrade fails.
+    pub async fn connect_with_token(
+        config: &ClientConfig,
+        agent_id: &str,
+        token: &SingleUseToken,
+    ) -> Result<Self> {
+        let (name, value) = token_query_param(token, None)?;
+        let url = build_ws_url(
+            &config.region,
+            "/v1/convai/conversation",
+            &[("agent_id", agent_id), (name, &value)],
+        )?;
+        Self::connect_observed(url.as_str(), Some(config)).await
+    }
+
+    /// Start a new conversation on a new socket from [`recv`](Self::recv)
+    /// when the socket drops, following `policy`.
+    ///
+    /// Connections made with [`connect_with_agent`](Self::connect_with_agent)
+    /// fetch a fresh signed URL for each attempt. Connections made from a
+    /// signed URL reuse it, which fails once the URL has expired; those made
+    /// with [`connect_with_token`](Self::connect_with_token) cannot reuse
+    /// their token and fail to reconnect.
+    ///
+    /// The dropped conversation is not resumed, since the API cannot rejoin a
+    /// conversation by its ID; see the [module docs](self#reconnection).
+    /// Normal closes and calls the agent ended with `end_call` are not
+    /// reconnected.
+    #[must_use]
+    pub const fn with_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.receiver.reconnect = Some(policy);
+        self
+    }
+
+    /// Answer every [`ConversationEvent::Ping`] from [`recv`](Self::recv)
+    /// with a pong before returning it, so callers need not call
+    /// [`send_pong`](Self::send_pong).
+    #[must_use]
+    pub const fn with_auto_pong(mut self) -> Self {
+        self.receiver.auto_pong = true;
+        self
+    }
+
+    /// Splits the client into a sender, which can be cloned and moved to
+    /// other tasks, and a receiver.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use elevenlabs_sdk::{ClientConfig, ConversationWebSocket, ElevenLabsClient};
+    ///
+    /// # async fn example(
+    /// #     mut microphone: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    /// # ) -> elevenlabs_sdk::Result<()> {
+    /// let client = ElevenLabsClient::new(ClientConfig::builder("your-api-key").build())?;
+    /// let conv = ConversationWebSocket::connect_with_agent(&client, "agent-id").await?;
+    /// let (sender, mut receiver) = conv.with_auto_pong().split();
+    ///
+    /// tokio::spawn(async move {
+    ///     while let Some(frame) = microphone.recv().await {
+    ///         sender.send_audio(&frame).await?;
+    ///     }
+    ///     elevenlabs_sdk::Result::Ok(())
+    /// });
+    ///
+    /// while let Some(event) = receiver.recv().await? {
+    ///     println!("{event:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split(self) -> (ConversationSender, ConversationReceiver) {
+        (self.sender, self.receiver)
+    }
+
+    /// Returns the ID of the current conversation, once the server has sent
+    /// [`ConversationEvent::InitiationMetadata`].
+    pub fn conversation_id(&self) -> Option<&str> {
+        self.receiver.conversation_id()
     }
 
     /// Send an audio chunk (raw PCM bytes) to the agent.
@@ -214,40 +683,63 @@ impl ConversationWebSocket {
     ///
     /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
     pub async fn send_audio(&mut self, audio: &[u8]) -> Result<()> {
-        let encoded = base64::engine::general_purpose::STANDARD.encode(audio);
-        let msg = ClientMessage::UserAudioChunk { user_audio_chunk: encoded };
-        let json = serde_json::to_string(&msg)?;
-        self.handle
-            .send(WsMessage::text(json))
-            .await
-            .map_err(|e| ElevenLabsError::WebSocket(format!("send_audio failed: {e}")))?;
-        Ok(())
+        self.sender.send_audio(audio).await
+    }
+
+    /// Tell the agent something without asking for a reply, e.g. that the
+    /// user opened a page or an order changed state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn send_contextual_update(&mut self, text: &str) -> Result<()> {
+        self.sender.send_contextual_update(text).await
+    }
+
+    /// Send a text message as the user. The agent replies as it would to
+    /// speech.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn send_user_message(&mut self, text: &str) -> Result<()> {
+        self.sender.send_user_message(text).await
+    }
+
+    /// Set values for the agent's dynamic variables (`{{name}}` in its
+    /// prompt and first message).
+    ///
+    /// Sent as `conversation_initiation_client_data`, which the server only
+    /// reads at the start of a conversation, so call this right after
+    /// connecting and before sending audio or messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn update_dynamic_variables(
+        &mut self,
+        variables: &HashMap<String, serde_json::Value>,
+    ) -> Result<()> {
+        self.sender.update_dynamic_variables(variables).await
     }
 
     /// Receive the next conversation event from the server.
     ///
-    /// Returns `Ok(None)` when the connection is closed.
+    /// Returns `Ok(None)` when the connection is closed. With a
+    /// [`ReconnectPolicy`] set, a dropped socket is re-opened instead and
+    /// [`ConversationEvent::Reconnected`] is returned, unless the
+    /// conversation ended with a normal close or an `end_call`.
     ///
     /// # Errors
     ///
-    /// Returns [`ElevenLabsError::WebSocket`] on transport errors or
-    /// [`ElevenLabsError::Deserialization`] if the JSON payload is malformed.
+    /// Returns [`ElevenLabsError::WebSocket`] on transport errors, when an
+    /// automatic pong cannot be sent, or when every reconnection attempt
+    /// fails, [`ElevenLabsError::Timeout`] if the caller's
+    /// [`deadline`](crate::deadline) would pass before the next reconnection
+    /// attempt, or [`ElevenLabsError::Deserialization`] if a frame is not
+    /// JSON.
     pub async fn recv(&mut self) -> Result<Option<ConversationEvent>> {
-        loop {
-            match self.stream.next().await {
-                Some(Event::Message(incoming)) => {
-                    if let Some(text) = incoming.text {
-                        let event: ConversationEvent = serde_json::from_str(&text)?;
-                        return Ok(Some(event));
-                    }
-                    // Binary message without decodable text — keep receiving.
-                }
-                Some(Event::Connected { .. }) => {
-                    // Connection lifecycle event — keep receiving.
-                }
-                Some(Event::Disconnected { .. }) | None => return Ok(None),
-            }
-        }
+        self.receiver.recv().await
     }
 
     /// Send a pong response to keep the connection alive.
@@ -258,13 +750,7 @@ impl ConversationWebSocket {
     ///
     /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
     pub async fn send_pong(&mut self, event_id: i64) -> Result<()> {
-        let msg = ClientMessage::Pong { event_id };
-        let json = serde_json::to_string(&msg)?;
-        self.handle
-            .send(WsMessage::text(json))
-            .await
-            .map_err(|e| ElevenLabsError::WebSocket(format!("send_pong failed: {e}")))?;
-        Ok(())
+        self.sender.send_pong(event_id).await
     }
 
     /// Close the conversation.
@@ -273,41 +759,261 @@ impl ConversationWebSocket {
     ///
     /// Returns [`ElevenLabsError::WebSocket`] if the close handshake fails.
     pub async fn close(self) -> Result<()> {
-        self.handle
+        self.sender.close().await
+    }
+}
+
+impl ConversationSender {
+    /// Send an audio chunk (raw PCM bytes) to the agent. See
+    /// [`ConversationWebSocket::send_audio`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn send_audio(&self, audio: &[u8]) -> Result<()> {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(audio);
+        let msg = ClientMessage::UserAudioChunk { user_audio_chunk: encoded };
+        self.shared.send_frame(serde_json::to_string(&msg)?, "send_audio").await
+    }
+
+    /// Tell the agent something without asking for a reply. See
+    /// [`ConversationWebSocket::send_contextual_update`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn send_contextual_update(&self, text: &str) -> Result<()> {
+        let msg = ClientMessage::ContextualUpdate { text: text.to_owned() };
+        self.shared.send_frame(serde_json::to_string(&msg)?, "send_contextual_update").await
+    }
+
+    /// Send a text message as the user. See
+    /// [`ConversationWebSocket::send_user_message`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn send_user_message(&self, text: &str) -> Result<()> {
+        let msg = ClientMessage::UserMessage { text: text.to_owned() };
+        self.shared.send_frame(serde_json::to_string(&msg)?, "send_user_message").await
+    }
+
+    /// Set values for the agent's dynamic variables. See
+    /// [`ConversationWebSocket::update_dynamic_variables`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn update_dynamic_variables(
+        &self,
+        variables: &HashMap<String, serde_json::Value>,
+    ) -> Result<()> {
+        let msg = ClientMessage::InitiationClientData { dynamic_variables: variables.clone() };
+        let json = serde_json::to_string(&msg)?;
+        *self.shared.initiation.lock().unwrap_or_else(PoisonError::into_inner) = Some(json.clone());
+        self.shared.send_frame(json, "update_dynamic_variables").await
+    }
+
+    /// Send a pong response to keep the connection alive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn send_pong(&self, event_id: i64) -> Result<()> {
+        let msg = ClientMessage::Pong { event_id };
+        self.shared.send_frame(serde_json::to_string(&msg)?, "send_pong").await
+    }
+
+    /// Close the conversation for every clone and the receiver.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the close handshake fails.
+    pub async fn close(&self) -> Result<()> {
+        let (handle, observer) = self.shared.link();
+        handle
             .close()
+            .instrument(observer.span().clone())
             .await
             .map_err(|e| ElevenLabsError::WebSocket(format!("close failed: {e}")))?;
-        debug!("Conversational AI WebSocket closed");
+        observer.closed();
+        debug!(parent: observer.span(), "Conversational AI WebSocket closed");
         Ok(())
     }
 }
 
+impl ConversationReceiver {
+    /// Returns the ID of the current conversation, once the server has sent
+    /// [`ConversationEvent::InitiationMetadata`].
+    pub fn conversation_id(&self) -> Option<&str> {
+        self.conversation_id.as_deref()
+    }
+
+    /// Receive the next conversation event from the server. See
+    /// [`ConversationWebSocket::recv`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] on transport errors, when an
+    /// automatic pong cannot be sent, or when every reconnection attempt
+    /// fails, [`ElevenLabsError::Timeout`] if the caller's
+    /// [`deadline`](crate::deadline) would pass before the next reconnection
+    /// attempt, or [`ElevenLabsError::Deserialization`] if a frame is not
+    /// JSON.
+    pub async fn recv(&mut self) -> Result<Option<ConversationEvent>> {
+        let text = match self.stream.next().await {
+            Some(Incoming::Text(text)) => text,
+            closed => {
+                self.shared.link().1.closed();
+                let ended = self.ended ||
+                    matches!(closed, Some(Incoming::Closed { code: Some(NORMAL_CLOSURE) }));
+                return match self.reconnect {
+                    Some(policy) if !ended => self.reconnect_with(policy).await.map(Some),
+                    _ => Ok(None),
+                };
+            }
+        };
+        self.shared.link().1.received(text.len());
+        let event: ConversationEvent = serde_json::from_str(&text)?;
+        match &event {
+            ConversationEvent::InitiationMetadata { metadata } => {
+                self.conversation_id = Some(metadata.conversation_id.clone());
+            }
+            ConversationEvent::AgentToolResponse { agent_tool_response }
+                if agent_tool_response.tool_name == END_CALL_TOOL &&
+                    !agent_tool_response.is_error =>
+            {
+                self.ended = true;
+            }
+            ConversationEvent::Ping { ping_event } if self.auto_pong => {
+                let msg = ClientMessage::Pong { event_id: ping_event.event_id };
+                self.shared.send_frame(serde_json::to_string(&msg)?, "send_pong").await?;
+            }
+            _ => {}
+        }
+        Ok(Some(event))
+    }
+
+    /// Opens a new socket following `policy` and replays the initiation
+    /// data, starting a new conversation.
+    async fn reconnect_with(&mut self, policy: ReconnectPolicy) -> Result<ConversationEvent> {
+        let previous_conversation_id = self.conversation_id.take();
+        let mut last_error = None;
+        for attempt in 1..=policy.max_attempts {
+            let delay = policy.delay(attempt);
+            if !deadline::leaves_time(deadline::current(), delay) {
+                warn!(attempt, "not reconnecting, deadline would pass during backoff");
+                return Err(ElevenLabsError::Timeout);
+            }
+            tokio::time::sleep(delay).await;
+            match self.reopen().await {
+                Ok(()) => {
+                    debug!(parent: self.shared.link().1.span(), attempt, "Conversational AI WebSocket reconnected");
+                    return Ok(ConversationEvent::Reconnected { attempt, previous_conversation_id });
+                }
+                Err(e) => {
+                    warn!(attempt, error = %e, "Conversational AI WebSocket reconnect failed");
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(ElevenLabsError::WebSocket(match last_error {
+            Some(e) => format!("reconnect failed after {} attempts: {e}", policy.max_attempts),
+            None => "connection lost".to_owned(),
+        }))
+    }
+
+    /// Opens a new socket to the reconnect target, hands it to the senders
+    /// and replays the initiation data on it.
+    async fn reopen(&mut self) -> Result<()> {
+        let signed_url = match &self.target {
+            ReconnectTarget::SignedUrl(url) => url.clone(),
+            ReconnectTarget::Agent { client, agent_id } => {
+                client.agents().get_conversation_signed_url(agent_id).await?.signed_url
+            }
+        };
+        let (handle, stream, observer) = open(&signed_url, self.config.as_ref()).await?;
+        *self.shared.link.lock().unwrap_or_else(PoisonError::into_inner) =
+            (handle, Arc::new(observer));
+        self.stream = stream;
+        let initiation =
+            self.shared.initiation.lock().unwrap_or_else(PoisonError::into_inner).clone();
+        if let Some(initiation) = initiation {
+            self.shared.send_frame(initiation, "replay initiation data").await?;
+        }
+        Ok(())
+    }
+}
+
+/// Opens a socket to `signed_url`.
+async fn open(
+    signed_url: &str,
+    config: Option<&ClientConfig>,
+) -> Result<(WsHandle, WsStream, WsObserver)> {
+    let url = Url::parse(signed_url)?;
+    // The signed URL carries a token in its query, so only the path is used
+    // as the endpoint label.
+    let observer = WsObserver::new(config, url.path());
+    debug!(parent: observer.span(), url = %signed_url, "connecting to Conversational AI WebSocket");
+
+    let default_network = NetworkConfig::default();
+    let default_transport = WsTransportConfig::default();
+    let network = config.map_or(&default_network, |config| &config.network);
+    let ws_transport = config.map_or(&default_transport, |config| &config.ws_transport);
+
+    let headers = client_headers(config);
+    let connecting = transport::connect(&url, network, &headers, ws_transport);
+    let (handle, stream) = deadline::within(connect_deadline(ws_transport), connecting)
+        .instrument(observer.span().clone())
+        .await?;
+    observer.connected();
+
+    debug!(parent: observer.span(), "Conversational AI WebSocket connected");
+    Ok((handle, stream, observer))
+}
+
 #[cfg(test)]
-#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+#[expect(clippy::unwrap_used, clippy::panic, reason = "tests use unwrap and panic")]
 mod tests {
+    use futures_util::{SinkExt, StreamExt};
+    use hpx_yawc::{close::CloseCode, frame::Frame};
+    use tokio::sync::mpsc;
+
     use super::*;
+    use crate::ws::transport::test_server::{ServerSocket, TestServer};
 
     #[test]
     fn deserialize_initiation_metadata() {
         let json = r#"{
             "type": "conversation_initiation_metadata",
-            "conversation_id": "conv-123",
-            "agent_output_audio_format": "pcm_16000"
+            "conversation_initiation_metadata_event": {
+                "conversation_id": "conv-123",
+                "agent_output_audio_format": "pcm_16000",
+                "user_input_audio_format": "pcm_16000"
+            }
         }"#;
         let event: ConversationEvent = serde_json::from_str(json).unwrap();
-        assert!(matches!(event, ConversationEvent::InitiationMetadata { .. }));
+        match event {
+            ConversationEvent::InitiationMetadata { metadata } => {
+                assert_eq!(metadata.conversation_id, "conv-123");
+                assert_eq!(metadata.agent_output_audio_format.as_deref(), Some("pcm_16000"));
+                assert_eq!(metadata.user_input_audio_format.as_deref(), Some("pcm_16000"));
+            }
+            _ => panic!("expected InitiationMetadata event"),
+        }
     }
 
     #[test]
     fn deserialize_audio_event() {
         let json = r#"{
             "type": "audio",
-            "audio": {"chunk": "SGVsbG8="}
+            "audio_event": {"audio_base_64": "SGVsbG8=", "event_id": 7}
         }"#;
         let event: ConversationEvent = serde_json::from_str(json).unwrap();
         match event {
-            ConversationEvent::Audio { audio } => {
-                assert_eq!(audio.chunk.as_deref(), Some("SGVsbG8="));
+            ConversationEvent::Audio { audio_event } => {
+                assert_eq!(audio_event.event_id, 7);
+                assert_eq!(audio_event.decode().unwrap(), b"Hello");
             }
             _ => panic!("expected Audio event"),
         }
@@ -317,49 +1023,146 @@ mod tests {
     fn deserialize_agent_response() {
         let json = r#"{
             "type": "agent_response",
-            "agent_response_text": "Hello! How can I help?"
+            "agent_response_event": {"agent_response": "Hello! How can I help?"}
         }"#;
         let event: ConversationEvent = serde_json::from_str(json).unwrap();
         match event {
-            ConversationEvent::AgentResponse { agent_response_text } => {
-                assert_eq!(agent_response_text, "Hello! How can I help?");
+            ConversationEvent::AgentResponse { agent_response_event } => {
+                assert_eq!(agent_response_event.agent_response, "Hello! How can I help?");
             }
             _ => panic!("expected AgentResponse event"),
         }
     }
 
+    #[test]
+    fn deserialize_agent_response_correction() {
+        let json = r#"{
+            "type": "agent_response_correction",
+            "agent_response_correction_event": {
+                "original_agent_response": "Our opening hours are nine to five",
+                "corrected_agent_response": "Our opening hours"
+            }
+        }"#;
+        let event: ConversationEvent = serde_json::from_str(json).unwrap();
+        match event {
+            ConversationEvent::AgentResponseCorrection { agent_response_correction_event } => {
+                assert_eq!(
+                    agent_response_correction_event.corrected_agent_response,
+                    "Our opening hours"
+                );
+            }
+            _ => panic!("expected AgentResponseCorrection event"),
+        }
+    }
+
+    #[test]
+    fn deserialize_tentative_agent_response() {
+        let json = r#"{
+            "type": "internal_tentative_agent_response",
+            "tentative_agent_response_internal_event": {"tentative_agent_response": "Let me"}
+        }"#;
+        let event: ConversationEvent = serde_json::from_str(json).unwrap();
+        match event {
+            ConversationEvent::InternalTentativeAgentResponse {
+                tentative_agent_response_internal_event,
+            } => {
+                assert_eq!(
+                    tentative_agent_response_internal_event.tentative_agent_response,
+                    "Let me"
+                );
+            }
+            _ => panic!("expected InternalTentativeAgentResponse event"),
+        }
+    }
+
     #[test]
     fn deserialize_user_transcript() {
         let json = r#"{
             "type": "user_transcript",
-            "user_transcript_text": "Hi there"
+            "user_transcription_event": {"user_transcript": "Hi there"}
         }"#;
         let event: ConversationEvent = serde_json::from_str(json).unwrap();
         match event {
-            ConversationEvent::UserTranscript { user_transcript_text } => {
-                assert_eq!(user_transcript_text, "Hi there");
+            ConversationEvent::UserTranscript { user_transcription_event } => {
+                assert_eq!(user_transcription_event.user_transcript, "Hi there");
             }
             _ => panic!("expected UserTranscript event"),
         }
     }
 
+    #[test]
+    fn deserialize_vad_score() {
+        let json = r#"{"type": "vad_score", "vad_score_event": {"vad_score": 0.95}}"#;
+        let event: ConversationEvent = serde_json::from_str(json).unwrap();
+        match event {
+            ConversationEvent::VadScore { vad_score_event } => {
+                assert!((vad_score_event.vad_score - 0.95).abs() < f64::EPSILON);
+            }
+            _ => panic!("expected VadScore event"),
+        }
+    }
+
     #[test]
     fn deserialize_interruption() {
-        let json = r#"{"type": "interruption"}"#;
+        let json = r#"{"type": "interruption", "interruption_event": {"event_id": 7}}"#;
         let event: ConversationEvent = serde_json::from_str(json).unwrap();
-        assert!(matches!(event, ConversationEvent::Interruption { .. }));
+        match event {
+            ConversationEvent::Interruption { interruption_event } => {
+                assert_eq!(interruption_event.event_id, 7);
+            }
+            _ => panic!("expected Interruption event"),
+        }
+    }
+
+    #[test]
+    fn deserialize_tool_events() {
+        let json = r#"{
+            "type": "client_tool_call",
+            "client_tool_call": {
+                "tool_name": "open_page",
+                "tool_call_id": "call_1",
+                "parameters": {"page": "pricing"}
+            }
+        }"#;
+        let event: ConversationEvent = serde_json::from_str(json).unwrap();
+        match event {
+            ConversationEvent::ClientToolCall { client_tool_call } => {
+                assert_eq!(client_tool_call.tool_name, "open_page");
+                assert_eq!(client_tool_call.parameters["page"], "pricing");
+            }
+            _ => panic!("expected ClientToolCall event"),
+        }
+
+        let json = r#"{
+            "type": "agent_tool_response",
+            "agent_tool_response": {
+                "tool_name": "end_call",
+                "tool_call_id": "call_2",
+                "tool_type": "system",
+                "is_error": false
+            }
+        }"#;
+        let event: ConversationEvent = serde_json::from_str(json).unwrap();
+        match event {
+            ConversationEvent::AgentToolResponse { agent_tool_response } => {
+                assert_eq!(agent_tool_response.tool_type.as_deref(), Some("system"));
+                assert!(!agent_tool_response.is_error);
+            }
+            _ => panic!("expected AgentToolResponse event"),
+        }
     }
 
     #[test]
     fn deserialize_ping() {
         let json = r#"{
             "type": "ping",
-            "ping_event": {"event_id": 42}
+            "ping_event": {"event_id": 42, "ping_ms": 50}
         }"#;
         let event: ConversationEvent = serde_json::from_str(json).unwrap();
         match event {
             ConversationEvent::Ping { ping_event } => {
                 assert_eq!(ping_event.event_id, 42);
+                assert_eq!(ping_event.ping_ms, Some(50));
             }
             _ => panic!("expected Ping event"),
         }
@@ -376,7 +1179,18 @@ mod tests {
     fn deserialize_unknown_event() {
         let json = r#"{"type": "some_future_event", "data": 123}"#;
         let event: ConversationEvent = serde_json::from_str(json).unwrap();
-        assert!(matches!(event, ConversationEvent::Unknown));
+        match event {
+            ConversationEvent::Unknown(frame) => {
+                assert_eq!(frame["type"], "some_future_event");
+                assert_eq!(frame["data"], 123);
+            }
+            _ => panic!("expected Unknown event"),
+        }
+
+        // A known type with an unexpected payload is passed through too.
+        let json = r#"{"type": "vad_score", "vad_score_event": {}}"#;
+        let event: ConversationEvent = serde_json::from_str(json).unwrap();
+        assert!(matches!(event, ConversationEvent::Unknown(_)));
     }
 
     #[test]
@@ -394,4 +1208,131 @@ mod tests {
         assert!(json.contains("\"type\":\"pong\""));
         assert!(json.contains("\"event_id\":42"));
     }
+
+    #[test]
+    fn serialize_text_messages() {
+        let msg =
+            ClientMessage::ContextualUpdate { text: "User opened the pricing page".to_owned() };
+        assert_eq!(
+            serde_json::to_value(&msg).unwrap(),
+            serde_json::json!({"type": "contextual_update", "text": "User opened the pricing page"})
+        );
+
+        let msg = ClientMessage::UserMessage { text: "Hello".to_owned() };
+        assert_eq!(
+            serde_json::to_value(&msg).unwrap(),
+            serde_json::json!({"type": "user_message", "text": "Hello"})
+        );
+    }
+
+    #[test]
+    fn serialize_dynamic_variables() {
+        let dynamic_variables = HashMap::from([
+            ("user_name".to_owned(), serde_json::json!("Ada")),
+            ("visits".to_owned(), serde_json::json!(3)),
+        ]);
+        let msg = ClientMessage::InitiationClientData { dynamic_variables };
+        assert_eq!(
+            serde_json::to_value(&msg).unwrap(),
+            serde_json::json!({
+                "type": "conversation_initiation_client_data",
+                "dynamic_variables": {"user_name": "Ada", "visits": 3}
+            })
+        );
+    }
+
+    #[test]
+    fn reconnected_is_never_deserialized() {
+        let json = r#"{"type": "Reconnected", "attempt": 1}"#;
+        let event: ConversationEvent = serde_json::from_str(json).unwrap();
+        assert!(matches!(event, ConversationEvent::Unknown(_)));
+    }
+
+    #[test]
+    fn reconnect_policy_backs_off_exponentially() {
+        let policy = ReconnectPolicy::new(4).backoff(Duration::from_millis(100));
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(4), Duration::from_millis(800));
+        assert_eq!(ReconnectPolicy::new(1).backoff, DEFAULT_RECONNECT_BACKOFF);
+    }
+
+    fn conversation_url(server: &TestServer) -> String {
+        format!("ws://{}/v1/convai/conversation?agent_id=agent", server.addr)
+    }
+
+    fn quick_reconnect() -> ReconnectPolicy {
+        ReconnectPolicy::new(2).backoff(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn reconnects_and_replays_initiation_data_after_a_drop() {
+        let (frames_tx, mut frames) = mpsc::unbounded_channel();
+        // Records the first frame of each connection, then drops the first
+        // socket without a close frame.
+        let server = TestServer::start(move |index, mut socket: ServerSocket| {
+            let frames_tx = frames_tx.clone();
+            async move {
+                if let Some(frame) = socket.next().await {
+                    frames_tx.send((index, frame.as_str().to_owned())).unwrap();
+                }
+                if index > 0 {
+                    while socket.next().await.is_some() {}
+                }
+            }
+        })
+        .await;
+        let mut ws = ConversationWebSocket::connect(&conversation_url(&server))
+            .await
+            .unwrap()
+            .with_reconnect(quick_reconnect());
+        let variables = HashMap::from([("user_name".to_owned(), serde_json::json!("Ada"))]);
+        ws.update_dynamic_variables(&variables).await.unwrap();
+
+        let event = ws.recv().await.unwrap();
+
+        assert!(matches!(event, Some(ConversationEvent::Reconnected { attempt: 1, .. })));
+        let (first, initiation) = frames.recv().await.unwrap();
+        let (second, replayed) = frames.recv().await.unwrap();
+        assert_eq!((first, second), (0, 1));
+        assert!(initiation.contains("\"user_name\":\"Ada\""));
+        assert_eq!(replayed, initiation);
+    }
+
+    #[tokio::test]
+    async fn normal_close_is_not_reconnected() {
+        let server = TestServer::start(|_, mut socket: ServerSocket| async move {
+            let _ = socket.send(Frame::close(CloseCode::Normal, b"")).await;
+            while socket.next().await.is_some() {}
+        })
+        .await;
+        let mut ws = ConversationWebSocket::connect(&conversation_url(&server))
+            .await
+            .unwrap()
+            .with_reconnect(quick_reconnect());
+
+        assert!(ws.recv().await.unwrap().is_none());
+        assert_eq!(server.handshakes().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn ended_call_is_not_reconnected() {
+        let server = TestServer::start(|_, mut socket: ServerSocket| async move {
+            let end_call = serde_json::json!({
+                "type": "agent_tool_response",
+                "agent_tool_response": {"tool_name": "end_call", "tool_call_id": "call_1"}
+            });
+            let _ = socket.send(Frame::text(end_call.to_string())).await;
+        })
+        .await;
+        let mut ws = ConversationWebSocket::connect(&conversation_url(&server))
+            .await
+            .unwrap()
+            .with_reconnect(quick_reconnect());
+
+        let event = ws.recv().await.unwrap();
+        assert!(matches!(event, Some(ConversationEvent::AgentToolResponse { .. })));
+        assert!(ws.recv().await.unwrap().is_none());
+        assert_eq!(server.handshakes().len(), 1);
+    }
 }
@@ -4,30 +4,67 @@
 //!
 //! - **Text-to-Speech** ([`tts`]) — stream text and receive audio chunks in real time via the
 //!   input-streaming TTS endpoint.
-//! - **Conversational AI** ([`conversation`]) — bidirectional audio/text communication with an
-//!   ElevenLabs conversational agent.
+//! - **Conversational AI** ([`conversation`], with the `convai` feature) — bidirectional audio/text
+//!   communication with an ElevenLabs conversational agent.
 //!
-//! Both clients are built on top of [`hpx_transport::websocket`] for managed
-//! WebSocket connections with automatic reconnection and protocol handling.
+//! Both clients run on a [`hpx_yawc`] socket that follows the proxy, TLS and
+//! DNS settings in [`ClientConfig::network`], like HTTP requests, and the
+//! connect timeout, ping interval, message size limit, headers and TLS
+//! options in [`ClientConfig::ws_transport`]. Handshakes carry the same
+//! `User-Agent` and [`ClientConfig::default_headers`] as HTTP requests.
+//! Inside a
+//! [`deadline::scope`](crate::deadline::scope), connects and reconnects fail with
+//! [`ElevenLabsError::Timeout`](crate::ElevenLabsError::Timeout) once the
+//! deadline passes.
+//!
+//! Both clients can be split into a clonable sender and a receiver
+//! ([`TtsWebSocket::split`](tts::TtsWebSocket::split),
+//! [`ConversationWebSocket::split`](conversation::ConversationWebSocket::split)),
+//! so one task can send while another receives.
+//!
+//! Each connection runs under a `websocket` tracing span that tracks frames
+//! and bytes in both directions, and reports [`WsEvent`]s to the
+//! [`MetricsSink`] configured on the [`ClientConfig`].
 
+#[cfg(feature = "convai")]
 pub mod conversation;
-pub(crate) mod conversation_handler;
+pub(crate) mod transport;
 pub mod tts;
-pub(crate) mod tts_handler;
 
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Instant,
+};
+
+use tokio::time::Instant as Deadline;
 use url::Url;
 
-use crate::error::Result;
+use crate::{
+    config::{ClientConfig, Region, SDK_USER_AGENT, WsTransportConfig},
+    deadline,
+    error::{ElevenLabsError, Result},
+    middleware::metrics::{MetricsSink, WsEvent},
+    types::{SingleUseToken, TokenPurpose},
+};
 
-/// Builds a WebSocket URL by appending query parameters to a base path.
+/// Builds a WebSocket URL for `path` in `region`, appending query
+/// parameters.
 ///
 /// # Errors
 ///
-/// Returns [`ElevenLabsError::InvalidUrl`] if the resulting URL cannot be
-/// parsed.
-pub(crate) fn build_ws_url(base_url: &str, path: &str, params: &[(&str, &str)]) -> Result<Url> {
-    // Replace https:// with wss:// (and http:// with ws://)
-    let ws_base = base_url.replace("https://", "wss://").replace("http://", "ws://");
+/// Returns [`ElevenLabsError::Validation`] if the region does not offer the
+/// endpoint, or [`ElevenLabsError::InvalidUrl`] if the resulting URL cannot
+/// be parsed.
+pub(crate) fn build_ws_url(region: &Region, path: &str, params: &[(&str, &str)]) -> Result<Url> {
+    if !region.supports(path) {
+        return Err(ElevenLabsError::Validation(format!(
+            "{path} is not available in the {region} region"
+        )));
+    }
+    let ws_base = region.ws_base_url();
 
     let mut url = Url::parse(&format!("{ws_base}{path}"))?;
 
@@ -41,6 +78,141 @@ pub(crate) fn build_ws_url(base_url: &str, path: &str, params: &[(&str, &str)])
     Ok(url)
 }
 
+/// Returns the query parameter that authenticates with `token`, for
+/// [`build_ws_url`].
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::Validation`] if the token has expired or was
+/// issued for a purpose other than `expected`.
+pub(crate) fn token_query_param(
+    token: &SingleUseToken,
+    expected: Option<TokenPurpose>,
+) -> Result<(&'static str, String)> {
+    if let Some(expected) = expected.filter(|&p| p != token.purpose) {
+        return Err(ElevenLabsError::Validation(format!(
+            "single-use token was issued for {}, not {expected}",
+            token.purpose
+        )));
+    }
+    if token.is_expired() {
+        return Err(ElevenLabsError::Validation("single-use token has expired".to_owned()));
+    }
+    let (name, value) = token.ws_query_param();
+    Ok((name, value.to_owned()))
+}
+
+/// Returns the headers every handshake carries: the `User-Agent` and the
+/// client's default headers, or only the SDK's `User-Agent` without a
+/// config.
+pub(crate) fn client_headers(config: Option<&ClientConfig>) -> Vec<(String, String)> {
+    let user_agent =
+        config.map_or_else(|| SDK_USER_AGENT.to_owned(), ClientConfig::user_agent_header);
+    let mut headers = vec![("user-agent".to_owned(), user_agent)];
+    if let Some(config) = config {
+        headers.extend(config.default_headers.iter().cloned());
+    }
+    headers
+}
+
+/// Returns the deadline for opening a connection: the earlier of the
+/// caller's deadline and the configured connect timeout.
+pub(crate) fn connect_deadline(ws: &WsTransportConfig) -> Option<Deadline> {
+    let timeout = ws.connect_timeout.map(|timeout| Deadline::now() + timeout);
+    deadline::earliest(deadline::current(), timeout)
+}
+
+/// Connection-wide span and metrics reporting shared by the WebSocket
+/// clients. Counters are atomic so the halves of a split client can share
+/// one observer.
+#[derive(Debug)]
+pub(crate) struct WsObserver {
+    endpoint: String,
+    span: tracing::Span,
+    sink: Option<Arc<dyn MetricsSink>>,
+    started: Instant,
+    frames_sent: AtomicU64,
+    frames_received: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    closed: AtomicBool,
+}
+
+impl WsObserver {
+    /// Starts observing a connection to `endpoint`. Without a config, spans
+    /// are enabled and no metrics are reported.
+    pub(crate) fn new(config: Option<&ClientConfig>, endpoint: impl Into<String>) -> Self {
+        let endpoint = endpoint.into();
+        let span = if config.is_none_or(|c| c.tracing_spans) {
+            tracing::info_span!(
+                "websocket",
+                endpoint = %endpoint,
+                connect_ms = tracing::field::Empty,
+                frames_sent = 0_u64,
+                frames_received = 0_u64,
+                bytes_sent = 0_u64,
+                bytes_received = 0_u64,
+            )
+        } else {
+            tracing::Span::none()
+        };
+        Self {
+            endpoint,
+            span,
+            sink: config.and_then(|c| c.metrics_sink.clone()),
+            started: Instant::now(),
+            frames_sent: AtomicU64::new(0),
+            frames_received: AtomicU64::new(0),
+            bytes_sent: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the connection span.
+    pub(crate) const fn span(&self) -> &tracing::Span {
+        &self.span
+    }
+
+    /// Records that the connection was established.
+    pub(crate) fn connected(&self) {
+        let latency = self.started.elapsed();
+        self.span.record("connect_ms", latency.as_millis() as u64);
+        self.report(&WsEvent::Connected { latency });
+    }
+
+    /// Records an outgoing frame of `bytes` bytes.
+    pub(crate) fn sent(&self, bytes: usize) {
+        let frames = self.frames_sent.fetch_add(1, Ordering::Relaxed) + 1;
+        let total = self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed) + bytes as u64;
+        self.span.record("frames_sent", frames);
+        self.span.record("bytes_sent", total);
+        self.report(&WsEvent::MessageSent { bytes: bytes as u64 });
+    }
+
+    /// Records an incoming frame of `bytes` bytes.
+    pub(crate) fn received(&self, bytes: usize) {
+        let frames = self.frames_received.fetch_add(1, Ordering::Relaxed) + 1;
+        let total = self.bytes_received.fetch_add(bytes as u64, Ordering::Relaxed) + bytes as u64;
+        self.span.record("frames_received", frames);
+        self.span.record("bytes_received", total);
+        self.report(&WsEvent::MessageReceived { bytes: bytes as u64 });
+    }
+
+    /// Records that the connection closed. Only the first call is reported.
+    pub(crate) fn closed(&self) {
+        if !self.closed.swap(true, Ordering::Relaxed) {
+            self.report(&WsEvent::Closed);
+        }
+    }
+
+    fn report(&self, event: &WsEvent) {
+        if let Some(sink) = &self.sink {
+            sink.record_ws_event(&self.endpoint, event);
+        }
+    }
+}
+
 #[cfg(test)]
 #[expect(clippy::unwrap_used, reason = "tests use unwrap")]
 mod tests {
@@ -49,7 +221,7 @@ mod tests {
     #[test]
     fn build_ws_url_basic() {
         let url = build_ws_url(
-            "https://api.elevenlabs.io",
+            &Region::Global,
             "/v1/text-to-speech/voice123/stream-input",
             &[("model_id", "eleven_turbo_v2"), ("xi_api_key", "sk-test")],
         )
@@ -64,7 +236,7 @@ mod tests {
 
     #[test]
     fn build_ws_url_empty_params() {
-        let url = build_ws_url("https://api.elevenlabs.io", "/v1/ws", &[]).unwrap();
+        let url = build_ws_url(&Region::Global, "/v1/ws", &[]).unwrap();
 
         assert_eq!(url.scheme(), "wss");
         assert!(url.query().is_none() || url.query() == Some(""));
@@ -72,21 +244,81 @@ mod tests {
 
     #[test]
     fn build_ws_url_http_to_ws() {
-        let url = build_ws_url("http://localhost:8080", "/ws", &[]).unwrap();
+        let url =
+            build_ws_url(&Region::Custom("http://localhost:8080".to_owned()), "/ws", &[]).unwrap();
         assert_eq!(url.scheme(), "ws");
     }
 
+    #[test]
+    fn build_ws_url_follows_region() {
+        let url = build_ws_url(&Region::EuResidency, "/v1/convai/conversation", &[]).unwrap();
+        assert_eq!(url.scheme(), "wss");
+        assert_eq!(url.host_str(), Some("api.eu.residency.elevenlabs.io"));
+
+        let err = build_ws_url(&Region::EuResidency, "/v1/shared-voices", &[]).unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+    }
+
     #[test]
     fn build_ws_url_special_chars() {
-        let url = build_ws_url(
-            "https://api.elevenlabs.io",
-            "/v1/ws",
-            &[("key", "value with spaces & symbols=!")],
-        )
-        .unwrap();
+        let url =
+            build_ws_url(&Region::Global, "/v1/ws", &[("key", "value with spaces & symbols=!")])
+                .unwrap();
 
         // URL-encodes special characters
         let query = url.query().unwrap();
         assert!(query.contains("key="));
     }
+
+    #[test]
+    fn token_query_param_checks_purpose_and_expiry() {
+        let mut token = SingleUseToken::new("sutkn_1", TokenPurpose::TtsWebsocket);
+        assert_eq!(
+            token_query_param(&token, Some(TokenPurpose::TtsWebsocket)).unwrap(),
+            ("single_use_token", "sutkn_1".to_owned())
+        );
+        assert!(token_query_param(&token, None).is_ok());
+
+        let err = token_query_param(&token, Some(TokenPurpose::RealtimeScribe)).unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(_)));
+
+        token.expires_at = 0;
+        let err = token_query_param(&token, None).unwrap_err();
+        assert!(matches!(err, ElevenLabsError::Validation(msg) if msg.contains("expired")));
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingSink(std::sync::Mutex<Vec<(String, WsEvent)>>);
+
+    impl MetricsSink for RecordingSink {
+        fn record_request(&self, _metrics: &crate::RequestMetrics) {}
+
+        fn record_ws_event(&self, endpoint: &str, event: &WsEvent) {
+            self.0.lock().unwrap().push((endpoint.to_owned(), *event));
+        }
+    }
+
+    #[test]
+    fn observer_reports_frames_and_closes_once() {
+        let sink = Arc::new(RecordingSink::default());
+        let config = ClientConfig::builder("key").metrics_sink(sink.clone()).build();
+        let observer = WsObserver::new(Some(&config), "/v1/ws");
+
+        observer.sent(10);
+        observer.received(25);
+        observer.closed();
+        observer.closed();
+
+        let events = sink.0.lock().unwrap();
+        assert!(events.iter().all(|(endpoint, _)| endpoint == "/v1/ws"));
+        let events: Vec<WsEvent> = events.iter().map(|(_, event)| *event).collect();
+        assert_eq!(
+            events,
+            vec![
+                WsEvent::MessageSent { bytes: 10 },
+                WsEvent::MessageReceived { bytes: 25 },
+                WsEvent::Closed,
+            ]
+        );
+    }
 }
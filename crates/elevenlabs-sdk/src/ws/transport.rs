@@ -0,0 +1,878 @@
+//! WebSocket connections for the streaming clients.
+//!
+//! The socket is opened here rather than by an HTTP client so that it
+//! follows [`NetworkConfig`] like HTTP requests do: it is tunnelled through
+//! the configured proxy, trusts the extra root certificates (or any
+//! certificate with `danger_accept_invalid_certs`) and connects to the
+//! resolve overrides instead of looking the host up. Once the handshake is
+//! done, a spawned task owns the socket: it answers pings, sends keepalive
+//! pings and passes frames between the socket and a [`WsHandle`] /
+//! [`WsStream`] pair.
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use base64::Engine;
+use futures_util::{SinkExt, StreamExt};
+use hpx::header::{HeaderName, HeaderValue};
+use hpx_yawc::{
+    HttpRequestBuilder, Options, WebSocket, WebSocketError,
+    close::CloseCode,
+    frame::{Frame, OpCode},
+};
+use percent_encoding::percent_decode_str;
+use rustls::{
+    ClientConfig as TlsConfig, DigitallySignedStruct, RootCertStore, SignatureScheme,
+    SupportedProtocolVersion,
+    client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    crypto::CryptoProvider,
+    pki_types::{CertificateDer, ServerName, UnixTime, pem::PemObject},
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+    sync::{mpsc, oneshot},
+};
+use tokio_rustls::TlsConnector;
+use url::Url;
+
+use crate::{
+    client::crypto_provider,
+    config::{NetworkConfig, TlsVersion, WsTransportConfig},
+    error::{ElevenLabsError, Result},
+};
+
+/// Largest message accepted when [`WsTransportConfig::max_message_size`]
+/// is unset.
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Ping interval used when [`WsTransportConfig::ping_interval`] is unset.
+const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Largest proxy response header accepted while opening a tunnel.
+const MAX_PROXY_RESPONSE: usize = 8 * 1024;
+
+/// Number of frames buffered between the socket task and its handles.
+const CHANNEL_CAPACITY: usize = 64;
+
+/// Byte stream the handshake runs over: plain TCP, TLS, or either tunnelled
+/// through a proxy.
+trait Io: AsyncRead + AsyncWrite + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin> Io for T {}
+
+type BoxIo = Box<dyn Io>;
+
+/// Frame received from the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Incoming {
+    /// A text frame, or a binary frame holding UTF-8.
+    Text(String),
+    /// The server sent a close frame, with its status code if it had one.
+    Closed {
+        /// Close status code, e.g. `1000` for a normal closure.
+        code: Option<u16>,
+    },
+}
+
+/// Request from a [`WsHandle`] to the socket task.
+enum Command {
+    Send(Frame, oneshot::Sender<std::result::Result<(), WebSocketError>>),
+    Close(oneshot::Sender<std::result::Result<(), WebSocketError>>),
+}
+
+/// Sends frames on a connection. Clones share the connection.
+#[derive(Clone)]
+pub(crate) struct WsHandle {
+    commands: mpsc::Sender<Command>,
+}
+
+/// Receives frames from a connection. Ends once the socket is closed.
+#[derive(Debug)]
+pub(crate) struct WsStream {
+    frames: mpsc::Receiver<Incoming>,
+}
+
+impl std::fmt::Debug for WsHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WsHandle").field("closed", &self.commands.is_closed()).finish()
+    }
+}
+
+impl WsHandle {
+    /// Sends a text frame once the socket has accepted it.
+    pub(crate) async fn send_text(&self, text: String) -> std::result::Result<(), WebSocketError> {
+        self.request(|reply| Command::Send(Frame::text(text), reply)).await
+    }
+
+    /// Starts the close handshake. The [`WsStream`] ends once the server
+    /// answers or drops the socket.
+    pub(crate) async fn close(&self) -> std::result::Result<(), WebSocketError> {
+        self.request(Command::Close).await
+    }
+
+    async fn request(
+        &self,
+        command: impl FnOnce(oneshot::Sender<std::result::Result<(), WebSocketError>>) -> Command,
+    ) -> std::result::Result<(), WebSocketError> {
+        let (reply, done) = oneshot::channel();
+        self.commands.send(command(reply)).await.map_err(|_| WebSocketError::ConnectionClosed)?;
+        done.await.unwrap_or(Err(WebSocketError::ConnectionClosed))
+    }
+}
+
+impl WsStream {
+    /// Returns the next frame, or `None` once the socket is gone.
+    pub(crate) async fn next(&mut self) -> Option<Incoming> {
+        self.frames.recv().await
+    }
+}
+
+/// Opens a WebSocket to `url` following `network` and `ws`, and spawns the
+/// task that drives it.
+///
+/// The handshake carries `client_headers` (see
+/// [`client_headers`](super::client_headers)) and then the headers in
+/// `ws`; a later header replaces an earlier one with the same name.
+///
+/// # Errors
+///
+/// Returns [`ElevenLabsError::Validation`] if the proxy URL, a root
+/// certificate or a handshake header is invalid, or [`ElevenLabsError::WebSocket`] if the
+/// connection, the TLS handshake or the WebSocket upgrade fails.
+pub(crate) async fn connect(
+    url: &Url,
+    network: &NetworkConfig,
+    client_headers: &[(String, String)],
+    ws: &WsTransportConfig,
+) -> Result<(WsHandle, WsStream)> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| ElevenLabsError::Validation(format!("{url} has no host")))?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_owned();
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| ElevenLabsError::Validation(format!("{url} has no port")))?;
+    let tls = match url.scheme() {
+        "wss" => Some(tls_connector(network, ws)?),
+        "ws" => None,
+        scheme => {
+            return Err(ElevenLabsError::Validation(format!(
+                "unsupported WebSocket scheme {scheme:?}"
+            )));
+        }
+    };
+
+    let request = handshake_request(client_headers.iter().chain(&ws.headers))?;
+
+    let io = if let Some(proxy) = &network.proxy {
+        tunnel(proxy, &host, port, network).await?
+    } else {
+        let addr = network
+            .resolve_overrides
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&host))
+            .map(|(_, addr)| SocketAddr::new(addr.ip(), port));
+        Box::new(tcp_connect(addr, &host, port).await?)
+    };
+    let io = match tls {
+        Some(tls) => tls_handshake(&tls, &host, io).await?,
+        None => io,
+    };
+
+    let options = Options::default()
+        .with_max_payload_read(ws.max_message_size.unwrap_or(DEFAULT_MAX_MESSAGE_SIZE));
+    let socket = WebSocket::handshake_with_request(url.clone(), io, options, request)
+        .await
+        .map_err(|e| ElevenLabsError::WebSocket(format!("handshake failed: {e}")))?;
+
+    let (commands, command_rx) = mpsc::channel(CHANNEL_CAPACITY);
+    let (frame_tx, frames) = mpsc::channel(CHANNEL_CAPACITY);
+    let ping_interval = ws.ping_interval.unwrap_or(DEFAULT_PING_INTERVAL);
+    tokio::spawn(drive(socket, command_rx, frame_tx, ping_interval));
+    Ok((WsHandle { commands }, WsStream { frames }))
+}
+
+/// Owns the socket: forwards commands to it and frames from it, and sends
+/// a ping every `ping_interval` until either side closes.
+async fn drive(
+    mut socket: WebSocket<BoxIo>,
+    mut commands: mpsc::Receiver<Command>,
+    frames: mpsc::Sender<Incoming>,
+    ping_interval: Duration,
+) {
+    let mut ping =
+        tokio::time::interval_at(tokio::time::Instant::now() + ping_interval, ping_interval);
+    ping.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut handles_dropped = false;
+    loop {
+        tokio::select! {
+            command = commands.recv(), if !handles_dropped => match command {
+                Some(Command::Send(frame, reply)) => {
+                    let _ = reply.send(socket.send(frame).await);
+                }
+                Some(Command::Close(reply)) => {
+                    let _ = reply.send(socket.send(Frame::close(CloseCode::Normal, [])).await);
+                }
+                None => {
+                    handles_dropped = true;
+                    if socket.send(Frame::close(CloseCode::Normal, [])).await.is_err() {
+                        break;
+                    }
+                }
+            },
+            frame = socket.next() => {
+                let Some(frame) = frame else { break };
+                let incoming = match frame.opcode() {
+                    OpCode::Text => Incoming::Text(frame.as_str().to_owned()),
+                    OpCode::Binary => match String::from_utf8(frame.into_payload().to_vec()) {
+                        Ok(text) => Incoming::Text(text),
+                        Err(_) => continue,
+                    },
+                    OpCode::Close => Incoming::Closed { code: frame.close_code().map(u16::from) },
+                    _ => continue,
+                };
+                // A receiver that has gone away still lets the close
+                // handshake finish.
+                let _ = frames.send(incoming).await;
+            }
+            _ = ping.tick() => {
+                if socket.send(Frame::ping(Vec::new())).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Connects to `addr` if given, or looks up `host`.
+async fn tcp_connect(addr: Option<SocketAddr>, host: &str, port: u16) -> Result<TcpStream> {
+    let stream = match addr {
+        Some(addr) => TcpStream::connect(addr).await,
+        None => TcpStream::connect((host, port)).await,
+    }
+    .map_err(|e| ElevenLabsError::WebSocket(format!("connection failed: {e}")))?;
+    let _ = stream.set_nodelay(true);
+    Ok(stream)
+}
+
+/// Opens a tunnel to `host:port` through the proxy at `proxy`.
+async fn tunnel(proxy: &str, host: &str, port: u16, network: &NetworkConfig) -> Result<BoxIo> {
+    let proxy_url = Url::parse(proxy).map_err(|e| {
+        ElevenLabsError::Validation(format!(
+            "invalid proxy URL {}: {e}",
+            network.redacted_proxy().unwrap_or_default()
+        ))
+    })?;
+    let proxy_host = proxy_url
+        .host_str()
+        .ok_or_else(|| ElevenLabsError::Validation("proxy URL has no host".to_owned()))?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_owned();
+    let credentials = (!proxy_url.username().is_empty()).then(|| {
+        let decode = |part: &str| percent_decode_str(part).decode_utf8_lossy().into_owned();
+        (decode(proxy_url.username()), decode(proxy_url.password().unwrap_or_default()))
+    });
+    let proxy_error =
+        |e: std::io::Error| ElevenLabsError::WebSocket(format!("proxy tunnel failed: {e}"));
+
+    match proxy_url.scheme() {
+        scheme @ ("http" | "https") => {
+            let proxy_port = proxy_url.port_or_known_default().unwrap_or(80);
+            let mut io: BoxIo = Box::new(tcp_connect(None, &proxy_host, proxy_port).await?);
+            if scheme == "https" {
+                // The proxy is reached like any HTTP endpoint, so only the
+                // client-wide TLS settings apply to it.
+                let tls = tls_connector(network, &WsTransportConfig::default())?;
+                io = tls_handshake(&tls, &proxy_host, io).await?;
+            }
+            http_connect(&mut io, host, port, credentials.as_ref()).await.map_err(proxy_error)?;
+            Ok(io)
+        }
+        "socks5" | "socks5h" => {
+            let proxy_port = proxy_url.port().unwrap_or(1080);
+            let mut io: BoxIo = Box::new(tcp_connect(None, &proxy_host, proxy_port).await?);
+            socks5_connect(&mut io, host, port, credentials.as_ref()).await.map_err(proxy_error)?;
+            Ok(io)
+        }
+        scheme => Err(ElevenLabsError::Validation(format!("unsupported proxy scheme {scheme:?}"))),
+    }
+}
+
+/// Asks an HTTP proxy to open a tunnel to `host:port` with `CONNECT`.
+async fn http_connect(
+    io: &mut BoxIo,
+    host: &str,
+    port: u16,
+    credentials: Option<&(String, String)>,
+) -> std::io::Result<()> {
+    let authority =
+        if host.contains(':') { format!("[{host}]:{port}") } else { format!("{host}:{port}") };
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if let Some((user, password)) = credentials {
+        let token = base64::engine::general_purpose::STANDARD.encode(format!("{user}:{password}"));
+        request.push_str(&format!("Proxy-Authorization: Basic {token}\r\n"));
+    }
+    request.push_str("\r\n");
+    io.write_all(request.as_bytes()).await?;
+    io.flush().await?;
+
+    // Read the response header byte by byte so nothing from the tunnelled
+    // stream is consumed.
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_PROXY_RESPONSE {
+            return Err(std::io::Error::other("proxy response header too large"));
+        }
+        response.push(io.read_u8().await?);
+    }
+    let status_line = response.split(|&b| b == b'\r').next().unwrap_or_default();
+    let status_line = String::from_utf8_lossy(status_line);
+    match status_line.split_whitespace().nth(1) {
+        Some(status) if status.starts_with('2') => Ok(()),
+        _ => Err(std::io::Error::other(format!("proxy refused CONNECT: {status_line}"))),
+    }
+}
+
+/// Asks a SOCKS5 proxy to open a tunnel to `host:port` (RFC 1928), logging
+/// in with `credentials` if given (RFC 1929).
+async fn socks5_connect(
+    io: &mut BoxIo,
+    host: &str,
+    port: u16,
+    credentials: Option<&(String, String)>,
+) -> std::io::Result<()> {
+    const NO_AUTH: u8 = 0x00;
+    const USER_PASSWORD: u8 = 0x02;
+
+    let method = if credentials.is_some() { USER_PASSWORD } else { NO_AUTH };
+    io.write_all(&[0x05, 1, method]).await?;
+    let mut reply = [0_u8; 2];
+    io.read_exact(&mut reply).await?;
+    if reply != [0x05, method] {
+        return Err(std::io::Error::other("SOCKS5 proxy rejected the authentication method"));
+    }
+    if let Some((user, password)) = credentials {
+        let (user, password) = (user.as_bytes(), password.as_bytes());
+        let too_long = || std::io::Error::other("SOCKS5 user name or password too long");
+        let mut login = vec![0x01, u8::try_from(user.len()).map_err(|_| too_long())?];
+        login.extend_from_slice(user);
+        login.push(u8::try_from(password.len()).map_err(|_| too_long())?);
+        login.extend_from_slice(password);
+        io.write_all(&login).await?;
+        io.read_exact(&mut reply).await?;
+        if reply[1] != 0 {
+            return Err(std::io::Error::other("SOCKS5 proxy rejected the credentials"));
+        }
+    }
+
+    let mut request = vec![0x05, 0x01, 0x00];
+    match host.parse::<std::net::IpAddr>() {
+        Ok(std::net::IpAddr::V4(ip)) => {
+            request.push(0x01);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(std::net::IpAddr::V6(ip)) => {
+            request.push(0x04);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            request.push(0x03);
+            request.push(
+                u8::try_from(host.len())
+                    .map_err(|_| std::io::Error::other("host name too long for SOCKS5"))?,
+            );
+            request.extend_from_slice(host.as_bytes());
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    io.write_all(&request).await?;
+
+    let mut head = [0_u8; 4];
+    io.read_exact(&mut head).await?;
+    if head[1] != 0 {
+        return Err(std::io::Error::other(format!(
+            "SOCKS5 proxy refused CONNECT (reply {})",
+            head[1]
+        )));
+    }
+    // Skip the bound address and port.
+    let address_len = match head[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => usize::from(io.read_u8().await?),
+        other => return Err(std::io::Error::other(format!("unknown SOCKS5 address type {other}"))),
+    };
+    let mut bound = vec![0_u8; address_len + 2];
+    io.read_exact(&mut bound).await?;
+    Ok(())
+}
+
+/// Runs the TLS handshake for `host` over `io`.
+async fn tls_handshake(tls: &TlsConnector, host: &str, io: BoxIo) -> Result<BoxIo> {
+    let name = ServerName::try_from(host.to_owned()).map_err(|e| {
+        ElevenLabsError::Validation(format!("invalid TLS server name {host:?}: {e}"))
+    })?;
+    let stream = tls
+        .connect(name, io)
+        .await
+        .map_err(|e| ElevenLabsError::WebSocket(format!("TLS handshake failed: {e}")))?;
+    Ok(Box::new(stream))
+}
+
+/// Builds the handshake request carrying `headers`, later ones replacing
+/// earlier ones with the same name.
+///
+/// The headers are checked here because the handshake panics on a request
+/// builder holding an invalid header.
+fn handshake_request<'a>(
+    headers: impl IntoIterator<Item = &'a (String, String)>,
+) -> Result<HttpRequestBuilder> {
+    let mut request = HttpRequestBuilder::new();
+    for (name, value) in headers {
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+            ElevenLabsError::Validation(format!("invalid WebSocket header name {name:?}: {e}"))
+        })?;
+        let value = HeaderValue::from_str(value).map_err(|e| {
+            ElevenLabsError::Validation(format!("invalid value for WebSocket header {name}: {e}"))
+        })?;
+        if let Some(headers) = request.headers_mut() {
+            headers.insert(name, value);
+        }
+    }
+    Ok(request)
+}
+
+/// Builds the TLS connector: the built-in roots plus the extra roots in
+/// `network` and `ws`, or no verification at all with
+/// `danger_accept_invalid_certs`.
+fn tls_connector(network: &NetworkConfig, ws: &WsTransportConfig) -> Result<TlsConnector> {
+    let provider = crypto_provider();
+    let versions: &[&SupportedProtocolVersion] = match ws.min_tls_version {
+        Some(TlsVersion::Tls13) => &[&rustls::version::TLS13],
+        Some(TlsVersion::Tls12) | None => rustls::DEFAULT_VERSIONS,
+    };
+    let builder = TlsConfig::builder_with_provider(Arc::clone(&provider))
+        .with_protocol_versions(versions)
+        .map_err(|e| ElevenLabsError::WebSocket(format!("TLS setup failed: {e}")))?;
+
+    let mut config = if network.danger_accept_invalid_certs {
+        builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(provider)))
+            .with_no_client_auth()
+    } else {
+        let mut roots = RootCertStore::empty();
+        roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        for pem in network.root_certificates.iter().chain(&ws.root_certificates) {
+            for cert in CertificateDer::pem_slice_iter(pem) {
+                let cert = cert.map_err(|e| {
+                    ElevenLabsError::Validation(format!("invalid root certificate: {e}"))
+                })?;
+                roots.add(cert).map_err(|e| {
+                    ElevenLabsError::Validation(format!("invalid root certificate: {e}"))
+                })?;
+            }
+        }
+        builder.with_root_certificates(roots).with_no_client_auth()
+    };
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    Ok(TlsConnector::from(Arc::new(config)))
+}
+
+/// Certificate verifier for `danger_accept_invalid_certs`: accepts any
+/// certificate but still checks handshake signatures.
+#[derive(Debug)]
+struct AcceptAnyCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// In-process WebSocket server for the client tests.
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+pub(crate) mod test_server {
+    use std::{
+        convert::Infallible,
+        future::Future,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+
+    use hpx_yawc::{HttpStream, WebSocket};
+    use hyper::{HeaderMap, Request, body::Incoming, server::conn::http1, service::service_fn};
+    use hyper_util::rt::TokioIo;
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject};
+    use tokio::net::TcpListener;
+    use tokio_rustls::TlsAcceptor;
+
+    /// Root certificate the TLS server's certificate chains to.
+    pub(crate) const CA_PEM: &[u8] = include_bytes!("../../tests/tls/ca.pem");
+
+    /// Host name in the TLS server's certificate.
+    pub(crate) const TLS_HOST: &str = "elevenlabs.test";
+
+    /// Socket handed to a connection handler.
+    pub(crate) type ServerSocket = WebSocket<HttpStream>;
+
+    /// Handshake request received by the server.
+    #[derive(Debug, Clone)]
+    pub(crate) struct Handshake {
+        /// Path and query of the request.
+        pub(crate) path: String,
+        /// Request headers.
+        pub(crate) headers: HeaderMap,
+    }
+
+    /// Running server; stops when the test's runtime shuts down.
+    #[derive(Debug)]
+    pub(crate) struct TestServer {
+        /// Address the server listens on.
+        pub(crate) addr: SocketAddr,
+        handshakes: Arc<Mutex<Vec<Handshake>>>,
+    }
+
+    impl TestServer {
+        /// Starts a plain `ws://` server that calls `handler` with the
+        /// index of each connection and its socket.
+        pub(crate) async fn start<F, Fut>(handler: F) -> Self
+        where
+            F: Fn(usize, ServerSocket) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = ()> + Send + 'static,
+        {
+            Self::serve(None, handler).await
+        }
+
+        /// Starts a `wss://` server with a certificate for [`TLS_HOST`]
+        /// issued by [`CA_PEM`].
+        pub(crate) async fn start_tls<F, Fut>(handler: F) -> Self
+        where
+            F: Fn(usize, ServerSocket) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = ()> + Send + 'static,
+        {
+            let certs =
+                CertificateDer::pem_slice_iter(include_bytes!("../../tests/tls/server.pem"))
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+            let key = PrivateKeyDer::from_pem_slice(include_bytes!("../../tests/tls/server.key"))
+                .unwrap();
+            let config = rustls::ServerConfig::builder_with_provider(Arc::new(
+                rustls::crypto::ring::default_provider(),
+            ))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_no_client_auth()
+            .with_single_cert(certs, key)
+            .unwrap();
+            Self::serve(Some(TlsAcceptor::from(Arc::new(config))), handler).await
+        }
+
+        async fn serve<F, Fut>(tls: Option<TlsAcceptor>, handler: F) -> Self
+        where
+            F: Fn(usize, ServerSocket) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = ()> + Send + 'static,
+        {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let handshakes = Arc::new(Mutex::new(Vec::new()));
+            let handler = Arc::new(handler);
+            let recorded = Arc::clone(&handshakes);
+            tokio::spawn(async move {
+                for index in 0.. {
+                    let Ok((tcp, _)) = listener.accept().await else { return };
+                    let (tls, handler, recorded) =
+                        (tls.clone(), Arc::clone(&handler), Arc::clone(&recorded));
+                    tokio::spawn(async move {
+                        let service = service_fn(move |mut request: Request<Incoming>| {
+                            recorded.lock().unwrap().push(Handshake {
+                                path: request.uri().to_string(),
+                                headers: request.headers().clone(),
+                            });
+                            let (response, upgrade) = WebSocket::upgrade(&mut request).unwrap();
+                            let handler = Arc::clone(&handler);
+                            tokio::spawn(async move {
+                                if let Ok(socket) = upgrade.await {
+                                    handler(index, socket).await;
+                                }
+                            });
+                            async move { Ok::<_, Infallible>(response) }
+                        });
+                        let connection = http1::Builder::new();
+                        let _ = match tls {
+                            Some(tls) => {
+                                let Ok(stream) = tls.accept(tcp).await else { return };
+                                connection
+                                    .serve_connection(TokioIo::new(stream), service)
+                                    .with_upgrades()
+                                    .await
+                            }
+                            None => {
+                                connection
+                                    .serve_connection(TokioIo::new(tcp), service)
+                                    .with_upgrades()
+                                    .await
+                            }
+                        };
+                    });
+                }
+            });
+            Self { addr, handshakes }
+        }
+
+        /// Returns the handshakes received so far.
+        pub(crate) fn handshakes(&self) -> Vec<Handshake> {
+            self.handshakes.lock().unwrap().clone()
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "tests use unwrap")]
+mod tests {
+    use std::sync::Mutex;
+
+    use tokio::{io::copy_bidirectional, net::TcpListener};
+
+    use super::{
+        test_server::{CA_PEM, ServerSocket, TLS_HOST, TestServer},
+        *,
+    };
+    use crate::config::ClientConfig;
+
+    /// Echoes text frames until the client closes.
+    async fn echo(_index: usize, mut socket: ServerSocket) {
+        while let Some(frame) = socket.next().await {
+            if frame.opcode() == OpCode::Text {
+                let _ = socket.send(frame).await;
+            }
+        }
+    }
+
+    async fn round_trip(url: &str, network: &NetworkConfig) -> Result<Option<Incoming>> {
+        round_trip_with(url, network, &WsTransportConfig::default()).await
+    }
+
+    async fn round_trip_with(
+        url: &str,
+        network: &NetworkConfig,
+        ws: &WsTransportConfig,
+    ) -> Result<Option<Incoming>> {
+        let url = Url::parse(url).unwrap();
+        let (handle, mut stream) = connect(&url, network, &[], ws).await?;
+        handle.send_text("hello".to_owned()).await.unwrap();
+        Ok(stream.next().await)
+    }
+
+    #[tokio::test]
+    async fn sends_and_receives_text() {
+        let server = TestServer::start(echo).await;
+        let url = format!("ws://{}/v1/ws?model_id=m", server.addr);
+
+        let reply = round_trip(&url, &NetworkConfig::default()).await.unwrap();
+
+        assert_eq!(reply, Some(Incoming::Text("hello".to_owned())));
+        assert_eq!(server.handshakes()[0].path, "/v1/ws?model_id=m");
+    }
+
+    #[tokio::test]
+    async fn reports_the_close_code_and_ends() {
+        let server = TestServer::start(|_, mut socket: ServerSocket| async move {
+            let _ = socket.send(Frame::close(CloseCode::Normal, b"done")).await;
+            while socket.next().await.is_some() {}
+        })
+        .await;
+        let url = Url::parse(&format!("ws://{}/", server.addr)).unwrap();
+
+        let (_handle, mut stream) =
+            connect(&url, &NetworkConfig::default(), &[], &WsTransportConfig::default())
+                .await
+                .unwrap();
+
+        assert_eq!(stream.next().await, Some(Incoming::Closed { code: Some(1000) }));
+        assert_eq!(stream.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn connects_to_resolve_override() {
+        let server = TestServer::start(echo).await;
+        let config = ClientConfig::builder("key")
+            .resolve("api.elevenlabs.test", "127.0.0.1:1".parse().unwrap())
+            .build();
+        let url = format!("ws://api.elevenlabs.test:{}/v1/ws", server.addr.port());
+
+        let reply = round_trip(&url, &config.network).await.unwrap();
+
+        assert_eq!(reply, Some(Incoming::Text("hello".to_owned())));
+        let host = server.handshakes()[0].headers["host"].to_str().unwrap().to_owned();
+        assert_eq!(host, format!("api.elevenlabs.test:{}", server.addr.port()));
+    }
+
+    #[tokio::test]
+    async fn tunnels_through_http_proxy() {
+        let server = TestServer::start(echo).await;
+        let proxy = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_addr = proxy.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        tokio::spawn(async move {
+            let (mut client, _) = proxy.accept().await.unwrap();
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(client.read_u8().await.unwrap());
+            }
+            recorded.lock().unwrap().push(String::from_utf8(head).unwrap());
+            let mut upstream = TcpStream::connect(server.addr).await.unwrap();
+            client.write_all(b"HTTP/1.1 200 Connection established\r\n\r\n").await.unwrap();
+            let _ = copy_bidirectional(&mut client, &mut upstream).await;
+        });
+        let config =
+            ClientConfig::builder("key").proxy(format!("http://user:p%40ss@{proxy_addr}")).build();
+
+        let reply = round_trip("ws://api.elevenlabs.test:8080/v1/ws", &config.network).await;
+
+        assert_eq!(reply.unwrap(), Some(Incoming::Text("hello".to_owned())));
+        let request = requests.lock().unwrap()[0].clone();
+        assert!(request.starts_with("CONNECT api.elevenlabs.test:8080 HTTP/1.1\r\n"));
+        let credentials = base64::engine::general_purpose::STANDARD.encode("user:p@ss");
+        assert!(request.contains(&format!("Proxy-Authorization: Basic {credentials}\r\n")));
+    }
+
+    #[tokio::test]
+    async fn reports_refused_proxy_tunnel() {
+        let proxy = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_addr = proxy.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut client, _) = proxy.accept().await.unwrap();
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(client.read_u8().await.unwrap());
+            }
+            client.write_all(b"HTTP/1.1 403 Forbidden\r\n\r\n").await.unwrap();
+        });
+        let config = ClientConfig::builder("key").proxy(format!("http://{proxy_addr}")).build();
+
+        let err = round_trip("ws://api.elevenlabs.test/v1/ws", &config.network).await.unwrap_err();
+
+        assert!(matches!(err, ElevenLabsError::WebSocket(msg) if msg.contains("403")));
+    }
+
+    #[tokio::test]
+    async fn trusts_added_root_certificate() {
+        let server = TestServer::start_tls(echo).await;
+        let url = format!("wss://{TLS_HOST}:{}/v1/ws", server.addr.port());
+        let builder = || ClientConfig::builder("key").resolve(TLS_HOST, server.addr);
+
+        let err = round_trip(&url, &builder().build().network).await.unwrap_err();
+        assert!(matches!(err, ElevenLabsError::WebSocket(msg) if msg.contains("TLS")));
+
+        let config = builder().add_root_certificate(CA_PEM).build();
+        let reply = round_trip(&url, &config.network).await.unwrap();
+        assert_eq!(reply, Some(Incoming::Text("hello".to_owned())));
+    }
+
+    #[tokio::test]
+    async fn accepts_invalid_certificate_when_asked() {
+        let server = TestServer::start_tls(echo).await;
+        let url = format!("wss://{TLS_HOST}:{}/v1/ws", server.addr.port());
+        let config = ClientConfig::builder("key")
+            .resolve(TLS_HOST, server.addr)
+            .danger_accept_invalid_certs(true)
+            .build();
+
+        let reply = round_trip(&url, &config.network).await.unwrap();
+
+        assert_eq!(reply, Some(Incoming::Text("hello".to_owned())));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_root_certificate() {
+        let config = ClientConfig::builder("key")
+            .add_root_certificate(b"-----BEGIN CERTIFICATE-----\nnot base64\n".as_slice())
+            .build();
+
+        let err = round_trip("wss://api.elevenlabs.test/v1/ws", &config.network).await.unwrap_err();
+
+        assert!(
+            matches!(err, ElevenLabsError::Validation(msg) if msg.contains("root certificate"))
+        );
+    }
+
+    #[tokio::test]
+    async fn sends_configured_headers() {
+        let server = TestServer::start(echo).await;
+        let ws = WsTransportConfig::new().header("x-route", "eu-gateway-7");
+
+        let url = format!("ws://{}/v1/ws", server.addr);
+        round_trip_with(&url, &NetworkConfig::default(), &ws).await.unwrap();
+
+        assert_eq!(server.handshakes()[0].headers["x-route"], "eu-gateway-7");
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_header() {
+        let ws = WsTransportConfig::new().header("x-route", "line\nbreak");
+        let url = "ws://127.0.0.1:1/v1/ws";
+
+        let err = round_trip_with(url, &NetworkConfig::default(), &ws).await.unwrap_err();
+
+        assert!(matches!(err, ElevenLabsError::Validation(msg) if msg.contains("x-route")));
+    }
+
+    #[tokio::test]
+    async fn applies_transport_root_certificate_and_min_tls_version() {
+        let server = TestServer::start_tls(echo).await;
+        let url = format!("wss://{TLS_HOST}:{}/v1/ws", server.addr.port());
+        let config = ClientConfig::builder("key").resolve(TLS_HOST, server.addr).build();
+        let ws = WsTransportConfig::new()
+            .add_root_certificate(CA_PEM)
+            .min_tls_version(TlsVersion::Tls13);
+
+        let reply = round_trip_with(&url, &config.network, &ws).await.unwrap();
+
+        assert_eq!(reply, Some(Incoming::Text("hello".to_owned())));
+    }
+}
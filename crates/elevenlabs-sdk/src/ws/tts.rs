@@ -1,8 +1,8 @@
 //! TTS WebSocket client for real-time text-to-speech streaming.
 //!
 //! Connects to the ElevenLabs input-streaming TTS endpoint and allows
-//! sending text chunks incrementally while receiving base64-encoded audio
-//! responses in real time.
+//! sending text chunks incrementally while receiving audio responses in
+//! real time. The server's base64 audio is decoded on arrival.
 //!
 //! # Protocol
 //!
@@ -10,22 +10,71 @@
 //! 2. Send a **BOS** (beginning-of-stream) message with voice settings and generation config.
 //! 3. Send text chunks via [`TtsWebSocket::send_text`].
 //! 4. Optionally flush with [`TtsWebSocket::flush`].
-//! 5. Receive [`TtsWsResponse`] messages containing base64 audio.
-//! 6. Close with [`TtsWebSocket::close`] (sends an EOS message).
+//! 5. Send an **EOS** (end-of-stream) message with [`TtsWebSocket::end_input`] once all text is
+//!    sent.
+//! 6. Receive [`TtsWsResponse`] messages containing audio, or take the audio alone from
+//!    [`TtsWebSocket::audio_stream`].
+//! 7. Close with [`TtsWebSocket::close`], or with [`TtsWebSocket::close_gracefully`] to collect the
+//!    audio still in flight first.
+//!
+//! # Generation
+//!
+//! By default the server buffers text until the next length in
+//! [`TtsWsGenerationConfig::chunk_length_schedule`] is reached before
+//! generating audio. [`TtsWsConfig::auto_mode`] instead generates as soon as
+//! a sentence is complete, which suits text that arrives in whole sentences.
+//!
+//! # Inactivity
+//!
+//! The server closes the stream when no text arrives for
+//! [`TtsWsConfig::inactivity_timeout`] (20 seconds by default). With
+//! [`TtsWsConfig::auto_keepalive`] enabled, [`TtsWebSocket::recv`] sends a
+//! single-space keepalive whenever half the timeout passes without any text
+//! being sent. If the server does time the stream out, `recv` yields
+//! [`TtsWsResponse::TimedOut`] instead of a transport error.
+//!
+//! # Pacing
+//!
+//! Text sent much faster than it can be spoken piles up in the server's
+//! buffer. With [`TtsWsConfig::pacing`] set, [`TtsWebSocket::send_text`]
+//! waits until the [`TtsWsPacing`] budget allows the chunk, so a fast
+//! producer such as an LLM token stream is slowed to a steady rate simply by
+//! awaiting each send.
 
-use hpx_transport::websocket::{
-    Connection, ConnectionHandle, ConnectionStream, Event, WsConfig, WsMessage,
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc, Mutex, PoisonError,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::Duration,
 };
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bytes::Bytes;
+use futures_core::Stream;
+use futures_util::stream;
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use tokio::{sync::Notify, time::Instant};
+use tracing::{Instrument, debug};
 
 use crate::{
-    config::ClientConfig,
+    config::{ClientConfig, WsTransportConfig},
+    deadline,
     error::{ElevenLabsError, Result},
-    types::{OutputFormat, VoiceSettings},
-    ws::{build_ws_url, tts_handler::TtsProtocolHandler},
+    types::{OutputFormat, SingleUseToken, TextNormalization, TokenPurpose, VoiceSettings},
+    ws::{
+        WsObserver, build_ws_url, client_headers, connect_deadline, token_query_param,
+        transport::{self, Incoming, WsHandle, WsStream},
+    },
 };
 
+/// Inactivity timeout the server applies when none is configured.
+pub const DEFAULT_TTS_WS_INACTIVITY_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Error code the server reports when it closes an idle stream.
+const INPUT_TIMEOUT_ERROR: &str = "input_timeout_exceeded";
+
 /// Configuration for a TTS WebSocket connection.
 #[derive(Debug, Clone)]
 pub struct TtsWsConfig {
@@ -39,51 +88,392 @@ pub struct TtsWsConfig {
     pub generation_config: Option<TtsWsGenerationConfig>,
     /// Optional output format override.
     pub output_format: Option<OutputFormat>,
+    /// How long the server waits for new text before closing the stream.
+    /// Sent in whole seconds; the server accepts up to 180. Defaults to
+    /// [`DEFAULT_TTS_WS_INACTIVITY_TIMEOUT`] when `None`.
+    pub inactivity_timeout: Option<Duration>,
+    /// Send keepalive frames from [`TtsWebSocket::recv`] while no text is
+    /// being sent, so the stream outlives the inactivity timeout.
+    pub auto_keepalive: bool,
+    /// Generate audio at the end of each sentence instead of following the
+    /// chunk length schedule, which is then ignored.
+    pub auto_mode: Option<bool>,
+    /// Whether numbers, dates and the like are spelled out before synthesis.
+    pub apply_text_normalization: Option<TextNormalization>,
+    /// Deliver each audio chunk together with its alignment instead of
+    /// sending alignment as soon as it is known.
+    pub sync_alignment: Option<bool>,
+    /// Limits how fast [`TtsWebSocket::send_text`] sends text. Unpaced when
+    /// `None`.
+    pub pacing: Option<TtsWsPacing>,
+    /// Connect timeout, keepalive and message size limit for this stream. Falls
+    /// back to [`ClientConfig::ws_transport`] when `None`.
+    pub transport: Option<WsTransportConfig>,
+}
+
+impl TtsWsConfig {
+    /// Creates a configuration with only the voice and model set.
+    pub fn new(voice_id: impl Into<String>, model_id: impl Into<String>) -> Self {
+        Self {
+            voice_id: voice_id.into(),
+            model_id: model_id.into(),
+            voice_settings: None,
+            generation_config: None,
+            output_format: None,
+            inactivity_timeout: None,
+            auto_keepalive: false,
+            auto_mode: None,
+            apply_text_normalization: None,
+            sync_alignment: None,
+            pacing: None,
+            transport: None,
+        }
+    }
+
+    /// Overrides the client's WebSocket transport settings for this stream,
+    /// for example to accept larger PCM messages.
+    #[must_use]
+    pub fn transport(mut self, transport: WsTransportConfig) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets the chunk length schedule, keeping any other generation
+    /// settings.
+    #[must_use]
+    pub fn chunk_length_schedule(mut self, schedule: impl Into<Vec<u32>>) -> Self {
+        self.generation_config
+            .get_or_insert_with(TtsWsGenerationConfig::default)
+            .chunk_length_schedule = schedule.into();
+        self
+    }
+
+    /// Returns the query parameters of the connection URL, other than
+    /// authentication.
+    ///
+    /// Fails if the chunk length schedule is invalid, so that a bad config is
+    /// reported before connecting.
+    fn query_params(&self) -> Result<Vec<(&'static str, String)>> {
+        if let Some(generation) = &self.generation_config {
+            generation.validate()?;
+        }
+        if let Some(pacing) = &self.pacing {
+            pacing.validate()?;
+        }
+        let mut params = vec![("model_id", self.model_id.clone())];
+        if let Some(fmt) = self.output_format {
+            params.push(("output_format", fmt.to_string()));
+        }
+        if let Some(timeout) = self.inactivity_timeout {
+            params.push(("inactivity_timeout", timeout.as_secs().max(1).to_string()));
+        }
+        if let Some(auto_mode) = self.auto_mode {
+            params.push(("auto_mode", auto_mode.to_string()));
+        }
+        if let Some(normalization) = self.apply_text_normalization {
+            let value = match normalization {
+                TextNormalization::Auto => "auto",
+                TextNormalization::On => "on",
+                TextNormalization::Off => "off",
+            };
+            params.push(("apply_text_normalization", value.to_owned()));
+        }
+        if let Some(sync_alignment) = self.sync_alignment {
+            params.push(("sync_alignment", sync_alignment.to_string()));
+        }
+        Ok(params)
+    }
 }
 
+/// Shortest chunk length the server accepts in a schedule.
+pub const MIN_CHUNK_LENGTH: u32 = 50;
+
+/// Longest chunk length the server accepts in a schedule.
+pub const MAX_CHUNK_LENGTH: u32 = 500;
+
 /// Generation configuration for TTS WebSocket streaming.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TtsWsGenerationConfig {
     /// Schedule of chunk lengths in characters. The server uses progressively
-    /// larger chunks as more text is buffered.
+    /// larger chunks as more text is buffered. Each length must lie between
+    /// [`MIN_CHUNK_LENGTH`] and [`MAX_CHUNK_LENGTH`].
     pub chunk_length_schedule: Vec<u32>,
 }
 
+impl TtsWsGenerationConfig {
+    /// Checks the chunk length schedule against the server's limits.
+    fn validate(&self) -> Result<()> {
+        if self.chunk_length_schedule.is_empty() {
+            return Err(ElevenLabsError::Validation("chunk length schedule is empty".to_owned()));
+        }
+        match self
+            .chunk_length_schedule
+            .iter()
+            .find(|&&len| !(MIN_CHUNK_LENGTH..=MAX_CHUNK_LENGTH).contains(&len))
+        {
+            Some(len) => Err(ElevenLabsError::Validation(format!(
+                "chunk length {len} is outside {MIN_CHUNK_LENGTH}..={MAX_CHUNK_LENGTH}"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
 impl Default for TtsWsGenerationConfig {
     fn default() -> Self {
         Self { chunk_length_schedule: vec![120, 160, 250, 290] }
     }
 }
 
+/// Sending budget for [`TtsWebSocket::send_text`].
+///
+/// Text is metered by a token bucket: up to `burst_chars` characters go out
+/// at once, after which sends are spaced to `chars_per_second`. Optionally,
+/// at most `max_in_flight_chunks` chunks are sent before the server answers
+/// with audio; reaching the limit flushes the buffered text and waits for
+/// it, so a send never waits on audio the chunk length schedule holds back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TtsWsPacing {
+    /// Sustained sending rate in characters per second.
+    pub chars_per_second: u32,
+    /// Characters that may be sent at once after a pause.
+    pub burst_chars: u32,
+    /// Text chunks that may be sent since audio last arrived. Unlimited when
+    /// `None`.
+    pub max_in_flight_chunks: Option<usize>,
+}
+
+impl TtsWsPacing {
+    /// Paces sending to `chars_per_second`, with a burst of one second's
+    /// worth of text and no in-flight limit.
+    pub const fn new(chars_per_second: u32) -> Self {
+        Self { chars_per_second, burst_chars: chars_per_second, max_in_flight_chunks: None }
+    }
+
+    /// Sets the number of characters that may be sent at once.
+    #[must_use]
+    pub const fn burst_chars(mut self, chars: u32) -> Self {
+        self.burst_chars = chars;
+        self
+    }
+
+    /// Limits the text chunks sent since audio last arrived.
+    #[must_use]
+    pub const fn max_in_flight_chunks(mut self, chunks: usize) -> Self {
+        self.max_in_flight_chunks = Some(chunks);
+        self
+    }
+
+    /// Checks that the budget lets text through at all.
+    fn validate(&self) -> Result<()> {
+        if self.chars_per_second == 0 || self.burst_chars == 0 {
+            return Err(ElevenLabsError::Validation(
+                "pacing rate and burst must be at least one character".to_owned(),
+            ));
+        }
+        if self.max_in_flight_chunks == Some(0) {
+            return Err(ElevenLabsError::Validation(
+                "pacing must allow at least one chunk in flight".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Token bucket metering characters for [`TtsWsPacing`].
+#[derive(Debug)]
+struct CharBudget {
+    rate: f64,
+    capacity: f64,
+    available: f64,
+    updated: Instant,
+}
+
+impl CharBudget {
+    /// Creates a full bucket.
+    fn new(pacing: &TtsWsPacing, now: Instant) -> Self {
+        let capacity = f64::from(pacing.burst_chars);
+        Self {
+            rate: f64::from(pacing.chars_per_second),
+            capacity,
+            available: capacity,
+            updated: now,
+        }
+    }
+
+    /// Takes `chars` from the bucket and returns how long to wait before
+    /// sending them. A chunk larger than the bucket waits for the shortfall.
+    fn reserve(&mut self, chars: usize, now: Instant) -> Duration {
+        let refill = now.saturating_duration_since(self.updated).as_secs_f64() * self.rate;
+        self.available = (self.available + refill).min(self.capacity) - chars as f64;
+        self.updated = self.updated.max(now);
+        if self.available >= 0.0 {
+            return Duration::ZERO;
+        }
+        // The shortfall is paid off by waiting, after which the bucket is
+        // empty.
+        let ready = self.updated + Duration::from_secs_f64(-self.available / self.rate);
+        self.available = 0.0;
+        self.updated = ready;
+        ready - now
+    }
+}
+
 /// Response from the TTS WebSocket.
+#[derive(Debug, Clone)]
+pub enum TtsWsResponse {
+    /// An audio chunk, alignment data, or the final marker of a generation.
+    Audio(TtsWsAudioChunk),
+    /// The server closed the stream because no text arrived within the
+    /// inactivity timeout. No further responses follow.
+    TimedOut {
+        /// Explanation from the server, if it sent one.
+        message: String,
+    },
+}
+
+impl TtsWsResponse {
+    /// Returns the audio chunk, if this is an [`Audio`](Self::Audio)
+    /// response.
+    pub const fn audio(&self) -> Option<&TtsWsAudioChunk> {
+        match self {
+            Self::Audio(chunk) => Some(chunk),
+            Self::TimedOut { .. } => None,
+        }
+    }
+
+    /// Returns `true` if no further audio follows: either the final marker
+    /// of a generation or a timeout.
+    pub fn is_final(&self) -> bool {
+        match self {
+            Self::Audio(chunk) => chunk.is_final == Some(true),
+            Self::TimedOut { .. } => true,
+        }
+    }
+}
+
+/// An audio message from the TTS WebSocket.
 ///
-/// Each message may contain a base64-encoded audio chunk, alignment data,
-/// or a final marker.
+/// Each message may contain an audio chunk, alignment data, or a final
+/// marker. Audio is decoded from base64 during deserialization.
 #[derive(Debug, Clone, Deserialize)]
-pub struct TtsWsResponse {
-    /// Base64-encoded audio data. `None` on the final acknowledgement.
-    pub audio: Option<String>,
+#[serde(try_from = "RawTtsWsAudioChunk")]
+pub struct TtsWsAudioChunk {
+    /// Decoded audio data. `None` on the final acknowledgement.
+    pub audio: Option<Bytes>,
     /// Whether this is the final response for the current generation.
-    #[serde(rename = "isFinal")]
     pub is_final: Option<bool>,
     /// Character-level alignment information.
     pub alignment: Option<TtsWsAlignment>,
     /// Normalised character-level alignment information.
-    #[serde(rename = "normalizedAlignment")]
     pub normalized_alignment: Option<TtsWsAlignment>,
+    audio_base64: Option<String>,
+}
+
+impl TtsWsAudioChunk {
+    /// Returns the audio as the base64 string the server sent.
+    pub fn audio_base64(&self) -> Option<&str> {
+        self.audio_base64.as_deref()
+    }
+}
+
+/// Wire form of [`TtsWsAudioChunk`], before the audio is decoded.
+#[derive(Deserialize)]
+struct RawTtsWsAudioChunk {
+    audio: Option<String>,
+    #[serde(rename = "isFinal")]
+    is_final: Option<bool>,
+    alignment: Option<TtsWsAlignment>,
+    #[serde(rename = "normalizedAlignment")]
+    normalized_alignment: Option<TtsWsAlignment>,
+}
+
+impl TryFrom<RawTtsWsAudioChunk> for TtsWsAudioChunk {
+    type Error = base64::DecodeError;
+
+    fn try_from(raw: RawTtsWsAudioChunk) -> std::result::Result<Self, Self::Error> {
+        let audio = raw.audio.as_deref().map(|b64| STANDARD.decode(b64)).transpose()?;
+        Ok(Self {
+            audio: audio.map(Bytes::from),
+            is_final: raw.is_final,
+            alignment: raw.alignment,
+            normalized_alignment: raw.normalized_alignment,
+            audio_base64: raw.audio,
+        })
+    }
 }
 
 /// Character-level alignment data returned alongside audio chunks.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// The three lists run in parallel; [`characters`](Self::characters) pairs
+/// them up. Times are relative to the start of the generation, not of the
+/// chunk.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
 pub struct TtsWsAlignment {
     /// The characters that were synthesised.
-    pub chars: Option<Vec<String>>,
+    #[serde(default)]
+    pub chars: Vec<String>,
     /// Start time in milliseconds for each character.
-    #[serde(rename = "charStartTimesMs")]
-    pub char_start_times_ms: Option<Vec<f64>>,
+    #[serde(rename = "charStartTimesMs", default)]
+    pub char_start_times_ms: Vec<f64>,
     /// Duration in milliseconds for each character.
-    #[serde(rename = "charDurationsMs")]
-    pub char_durations_ms: Option<Vec<f64>>,
+    #[serde(rename = "charDurationsMs", default)]
+    pub char_durations_ms: Vec<f64>,
+}
+
+impl TtsWsAlignment {
+    /// Returns each character with its timing. Characters without a start
+    /// time or duration are skipped.
+    pub fn characters(&self) -> impl Iterator<Item = TtsWsCharTiming<'_>> {
+        self.chars.iter().zip(&self.char_start_times_ms).zip(&self.char_durations_ms).map(
+            |((character, &start_ms), &duration_ms)| TtsWsCharTiming {
+                character,
+                start: ms_duration(start_ms),
+                duration: ms_duration(duration_ms),
+            },
+        )
+    }
+}
+
+/// One character of a [`TtsWsAlignment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TtsWsCharTiming<'a> {
+    /// The character, which may be whitespace or punctuation.
+    pub character: &'a str,
+    /// When the character starts being spoken.
+    pub start: Duration,
+    /// How long the character is spoken for.
+    pub duration: Duration,
+}
+
+impl TtsWsCharTiming<'_> {
+    /// Returns when the character stops being spoken.
+    pub fn end(&self) -> Duration {
+        self.start + self.duration
+    }
+}
+
+/// Converts milliseconds from the wire, clamping negative values to zero.
+fn ms_duration(ms: f64) -> Duration {
+    Duration::try_from_secs_f64(ms / 1000.0).unwrap_or_default()
+}
+
+// -- Internal message types received from the server --------------------------
+
+/// Any message the server sends.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IncomingMessage {
+    /// An error report, sent before the server closes the stream.
+    Error {
+        error: String,
+        #[serde(default)]
+        message: Option<String>,
+    },
+    /// An audio message. Every field is optional, so this matches anything
+    /// that is not an error. Decoded separately so that bad base64 is
+    /// reported as such rather than as an unrecognised message.
+    Audio(RawTtsWsAudioChunk),
 }
 
 // -- Internal message types sent to the server --------------------------------
@@ -114,16 +504,21 @@ struct FlushMessage<'a> {
     flush: bool,
 }
 
-/// EOS (end-of-stream) message.
+/// EOS (end-of-stream) message. Also used, with a single space, as a
+/// keepalive.
 #[derive(Serialize)]
 struct EosMessage<'a> {
     text: &'a str,
 }
 
+/// Text of a keepalive message. An empty string would end the stream.
+const KEEPALIVE_TEXT: &str = " ";
+
 /// TTS WebSocket client for real-time text-to-speech streaming.
 ///
-/// Wraps an `hpx_transport` managed connection, providing typed methods for
-/// the ElevenLabs input-streaming TTS protocol.
+/// Wraps a WebSocket connection, providing typed methods for the ElevenLabs
+/// input-streaming TTS protocol. To send text from one task
+/// and receive audio in another, [`split`](Self::split) it.
 ///
 /// # Example
 ///
@@ -132,20 +527,18 @@ struct EosMessage<'a> {
 ///
 /// # async fn example() -> elevenlabs_sdk::Result<()> {
 /// let config = ClientConfig::builder("your-api-key").build();
-/// let ws_config = TtsWsConfig {
-///     voice_id: "voice123".into(),
-///     model_id: "eleven_turbo_v2".into(),
-///     voice_settings: None,
-///     generation_config: None,
-///     output_format: None,
-/// };
+/// let ws_config = TtsWsConfig::new("voice123", "eleven_turbo_v2");
 ///
 /// let mut ws = TtsWebSocket::connect(&config, &ws_config).await?;
 /// ws.send_text("Hello, world!").await?;
-/// ws.flush().await?;
+/// ws.end_input().await?;
 ///
+/// let mut audio = Vec::new();
 /// while let Some(resp) = ws.recv().await? {
-///     if resp.is_final == Some(true) {
+///     if let Some(chunk) = resp.audio().and_then(|c| c.audio.as_ref()) {
+///         audio.extend_from_slice(chunk);
+///     }
+///     if resp.is_final() {
 ///         break;
 ///     }
 /// }
@@ -154,14 +547,80 @@ struct EosMessage<'a> {
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Debug)]
 pub struct TtsWebSocket {
-    handle: ConnectionHandle,
-    stream: ConnectionStream,
+    sender: TtsWsSender,
+    receiver: TtsWsReceiver,
 }
 
-impl std::fmt::Debug for TtsWebSocket {
+/// Sending half of a [`TtsWebSocket`], returned by
+/// [`TtsWebSocket::split`].
+///
+/// Clones share the connection and the [`TtsWsPacing`] budget, so several
+/// tasks can feed text into one stream.
+#[derive(Clone)]
+pub struct TtsWsSender {
+    handle: WsHandle,
+    shared: Arc<TtsWsShared>,
+}
+
+/// Receiving half of a [`TtsWebSocket`], returned by
+/// [`TtsWebSocket::split`].
+///
+/// Sends keepalives while waiting, like [`TtsWebSocket::recv`].
+pub struct TtsWsReceiver {
+    handle: WsHandle,
+    stream: WsStream,
+    shared: Arc<TtsWsShared>,
+    inactivity_timeout: Duration,
+    auto_keepalive: bool,
+    timed_out: bool,
+    pending: VecDeque<TtsWsResponse>,
+}
+
+/// State shared by the halves of a connection.
+#[derive(Debug)]
+struct TtsWsShared {
+    observer: WsObserver,
+    last_sent: Mutex<Instant>,
+    input_ended: AtomicBool,
+    closed: AtomicBool,
+    budget: Mutex<Option<CharBudget>>,
+    max_in_flight_chunks: Option<usize>,
+    in_flight: AtomicUsize,
+    /// Woken when audio arrives or the connection closes, for senders
+    /// waiting at the in-flight limit.
+    audio_arrived: Notify,
+}
+
+impl TtsWsShared {
+    /// Returns the time the last frame was sent.
+    fn last_sent(&self) -> Instant {
+        *self.last_sent.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the in-flight limit if it has been reached.
+    fn in_flight_limit_reached(&self) -> Option<usize> {
+        self.max_in_flight_chunks.filter(|&max| self.in_flight.load(Ordering::Acquire) >= max)
+    }
+}
+
+impl std::fmt::Debug for TtsWsSender {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("TtsWebSocket").finish_non_exhaustive()
+        f.debug_struct("TtsWsSender")
+            .field("max_in_flight_chunks", &self.shared.max_in_flight_chunks)
+            .field("in_flight", &self.in_flight_chunks())
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Debug for TtsWsReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TtsWsReceiver")
+            .field("inactivity_timeout", &self.inactivity_timeout)
+            .field("auto_keepalive", &self.auto_keepalive)
+            .field("pending", &self.pending.len())
+            .finish_non_exhaustive()
     }
 }
 
@@ -169,50 +628,153 @@ impl TtsWebSocket {
     /// Connect to the TTS WebSocket endpoint.
     ///
     /// Establishes the connection and sends the BOS (beginning-of-stream)
-    /// message automatically.
+    /// message automatically. Spans, metrics and network settings follow
+    /// [`ClientConfig::tracing_spans`], [`ClientConfig::metrics_sink`] and
+    /// [`ClientConfig::network`].
     ///
     /// # Errors
     ///
     /// Returns [`ElevenLabsError::WebSocket`] if the connection or the BOS
-    /// handshake fails.
+    /// handshake fails, or the provider's error if a single-use token
+    /// cannot be fetched.
     pub async fn connect(client_config: &ClientConfig, ws_config: &TtsWsConfig) -> Result<Self> {
+        // Token strategies authenticate through the URL instead of the BOS
+        // frame.
+        let auth_param = client_config.auth.ws_query_param().await?;
+        Self::connect_authenticated(client_config, ws_config, auth_param).await
+    }
+
+    /// Connect to the TTS WebSocket endpoint with a single-use token instead
+    /// of the API key.
+    ///
+    /// Lets an untrusted client, such as a browser or mobile app, stream
+    /// speech without holding the API key: the backend creates the token
+    /// with
+    /// [`SingleUseTokenService::create`](crate::services::SingleUseTokenService::create)
+    /// and hands it over. The API key in `client_config` is never sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::Validation`] if the token has expired or
+    /// was not issued for [`TokenPurpose::TtsWebsocket`], or
+    /// [`ElevenLabsError::WebSocket`] if the connection or the BOS handshake
+    /// fails.
+    pub async fn connect_with_token(
+        client_config: &ClientConfig,
+        ws_config: &TtsWsConfig,
+        token: &SingleUseToken,
+    ) -> Result<Self> {
+        let auth_param = token_query_param(token, Some(TokenPurpose::TtsWebsocket))?;
+        Self::connect_authenticated(client_config, ws_config, Some(auth_param)).await
+    }
+
+    /// Shared implementation of [`connect`](Self::connect) and
+    /// [`connect_with_token`](Self::connect_with_token). Without an
+    /// `auth_param`, the API key is sent in the BOS frame.
+    async fn connect_authenticated(
+        client_config: &ClientConfig,
+        ws_config: &TtsWsConfig,
+        auth_param: Option<(&'static str, String)>,
+    ) -> Result<Self> {
         let path = format!("/v1/text-to-speech/{}/stream-input", ws_config.voice_id);
 
-        let mut params: Vec<(&str, String)> = vec![("model_id", ws_config.model_id.clone())];
+        let mut params = ws_config.query_params()?;
 
-        if let Some(ref fmt) = ws_config.output_format {
-            params.push(("output_format", fmt.to_string()));
+        if let Some((name, token)) = &auth_param {
+            params.push((name, token.clone()));
         }
 
         // Build param refs for the URL builder.
         let param_refs: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
-        let url = build_ws_url(&client_config.base_url, &path, &param_refs)?;
-        debug!(url = %url, "connecting to TTS WebSocket");
+        let url = build_ws_url(&client_config.region, &path, &param_refs)?;
+        let observer = WsObserver::new(Some(client_config), url.path());
+        debug!(parent: observer.span(), path = url.path(), "connecting to TTS WebSocket");
 
-        let handler = TtsProtocolHandler;
-        let transport_config =
-            WsConfig::new(url.to_string()).reconnect_max_attempts(Some(0)).use_websocket_ping(true);
+        let ws_transport = ws_config.transport.as_ref().unwrap_or(&client_config.ws_transport);
+        let headers = client_headers(Some(client_config));
+        let connecting = transport::connect(&url, &client_config.network, &headers, ws_transport);
+        let (handle, stream) = deadline::within(connect_deadline(ws_transport), connecting)
+            .instrument(observer.span().clone())
+            .await?;
+        observer.connected();
 
-        let (handle, stream) = Connection::connect(transport_config, handler)
-            .await
-            .map_err(|e| ElevenLabsError::WebSocket(format!("connection failed: {e}")))?;
+        let shared = Arc::new(TtsWsShared {
+            observer,
+            last_sent: Mutex::new(Instant::now()),
+            input_ended: AtomicBool::new(false),
+            closed: AtomicBool::new(false),
+            budget: Mutex::new(
+                ws_config.pacing.as_ref().map(|pacing| CharBudget::new(pacing, Instant::now())),
+            ),
+            max_in_flight_chunks: ws_config.pacing.and_then(|pacing| pacing.max_in_flight_chunks),
+            in_flight: AtomicUsize::new(0),
+            audio_arrived: Notify::new(),
+        });
+        let ws = Self {
+            sender: TtsWsSender { handle: handle.clone(), shared: Arc::clone(&shared) },
+            receiver: TtsWsReceiver {
+                handle,
+                stream,
+                shared,
+                inactivity_timeout: ws_config
+                    .inactivity_timeout
+                    .map_or(DEFAULT_TTS_WS_INACTIVITY_TIMEOUT, |t| t.max(Duration::from_secs(1))),
+                auto_keepalive: ws_config.auto_keepalive,
+                timed_out: false,
+                pending: VecDeque::new(),
+            },
+        };
 
         // Send BOS message.
         let bos = BosMessage {
             text: " ",
             voice_settings: ws_config.voice_settings.as_ref(),
             generation_config: ws_config.generation_config.as_ref(),
-            xi_api_key: Some(client_config.api_key.as_str()),
+            xi_api_key: auth_param.is_none().then(|| client_config.api_key.as_str()),
         };
-        let bos_json = serde_json::to_string(&bos)?;
-        handle
-            .send(WsMessage::text(bos_json))
-            .await
-            .map_err(|e| ElevenLabsError::WebSocket(format!("BOS send failed: {e}")))?;
+        ws.sender.send_frame(serde_json::to_string(&bos)?, "BOS send").await?;
 
-        debug!("TTS WebSocket connected and BOS sent");
-        Ok(Self { handle, stream })
+        debug!(parent: ws.sender.shared.observer.span(), "TTS WebSocket connected and BOS sent");
+        Ok(ws)
+    }
+
+    /// Splits the connection into a sender, which can be cloned and moved
+    /// to other tasks, and a receiver.
+    ///
+    /// With an in-flight limit set through [`TtsWsConfig::pacing`], a
+    /// sender at the limit waits for the receiver to take delivery of
+    /// audio, so keep receiving while sending.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use elevenlabs_sdk::{ClientConfig, TtsWebSocket, TtsWsConfig};
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> elevenlabs_sdk::Result<()> {
+    /// let config = ClientConfig::builder("your-api-key").build();
+    /// let ws =
+    ///     TtsWebSocket::connect(&config, &TtsWsConfig::new("voice123", "eleven_turbo_v2")).await?;
+    /// let (sender, mut receiver) = ws.split();
+    ///
+    /// let producer = tokio::spawn(async move {
+    ///     for sentence in ["Hello there. ", "How are you today?"] {
+    ///         sender.send_text(sentence).await?;
+    ///     }
+    ///     sender.end_input().await
+    /// });
+    ///
+    /// let mut audio = std::pin::pin!(receiver.audio_stream());
+    /// while let Some(chunk) = audio.next().await {
+    ///     println!("{} bytes", chunk?.len());
+    /// }
+    /// producer.await.expect("producer panicked")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split(self) -> (TtsWsSender, TtsWsReceiver) {
+        (self.sender, self.receiver)
     }
 
     /// Send a text chunk for conversion.
@@ -220,91 +782,467 @@ impl TtsWebSocket {
     /// The text is queued on the server side and synthesis is triggered
     /// according to the generation config's chunk schedule.
     ///
+    /// With [`TtsWsConfig::pacing`] set, this waits until the pacing budget
+    /// allows the chunk. Responses that arrive while waiting for audio are
+    /// kept and returned by [`recv`](Self::recv) in order.
+    ///
     /// # Errors
     ///
-    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails or the
+    /// connection closes while waiting for audio.
     pub async fn send_text(&mut self, text: &str) -> Result<()> {
-        let msg = TextChunkMessage { text, try_trigger_generation: true };
-        let json = serde_json::to_string(&msg)?;
-        self.handle
-            .send(WsMessage::text(json))
-            .await
-            .map_err(|e| ElevenLabsError::WebSocket(format!("send_text failed: {e}")))?;
-        Ok(())
+        self.send_text_with_trigger(text, true).await
+    }
+
+    /// Send a text chunk, choosing whether it may trigger generation.
+    ///
+    /// With `try_trigger_generation` set to `false` the text is only
+    /// buffered, and audio is generated once later text triggers it or
+    /// [`flush`](Self::flush) is called.
+    ///
+    /// Paced like [`send_text`](Self::send_text).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails or the
+    /// connection closes while waiting for audio.
+    pub async fn send_text_with_trigger(
+        &mut self,
+        text: &str,
+        try_trigger_generation: bool,
+    ) -> Result<()> {
+        // At the in-flight limit the buffered text is flushed, so that audio
+        // is generated even if the chunk length schedule is not yet met.
+        if let Some(max) = self.sender.shared.in_flight_limit_reached() {
+            self.sender.flush().await?;
+            while self.sender.in_flight_chunks() >= max {
+                let Some(response) = self.receiver.recv_from_server().await? else {
+                    return Err(ElevenLabsError::WebSocket(
+                        "connection closed while waiting for audio".to_owned(),
+                    ));
+                };
+                self.receiver.pending.push_back(response);
+            }
+        }
+        self.sender.send_text_paced(text, try_trigger_generation).await
+    }
+
+    /// Returns the number of text chunks sent since audio last arrived.
+    pub fn in_flight_chunks(&self) -> usize {
+        self.sender.in_flight_chunks()
     }
 
     /// Flush the current audio generation buffer.
     ///
-    /// Forces the server to synthesise any buffered text immediately.
+    /// Forces the server to synthesise any buffered text immediately,
+    /// regardless of the chunk length schedule, without ending the stream.
+    /// Use it at the end of a conversational turn.
     ///
     /// # Errors
     ///
     /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
     pub async fn flush(&mut self) -> Result<()> {
-        let msg = FlushMessage { text: " ", flush: true };
-        let json = serde_json::to_string(&msg)?;
-        self.handle
-            .send(WsMessage::text(json))
-            .await
-            .map_err(|e| ElevenLabsError::WebSocket(format!("flush failed: {e}")))?;
-        Ok(())
+        self.sender.flush().await
+    }
+
+    /// Tell the server that no more text follows.
+    ///
+    /// Sends the EOS (end-of-stream) message while keeping the connection
+    /// open, so the remaining audio and the final marker can still be
+    /// received. [`close`](Self::close) does not send EOS again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn end_input(&mut self) -> Result<()> {
+        self.sender.end_input().await
+    }
+
+    /// Returns a stream of decoded audio chunks.
+    ///
+    /// The stream ends after the final marker of the generation or when the
+    /// connection closes; call [`end_input`](Self::end_input) first so the
+    /// server sends the final marker. Empty chunks and alignment-only
+    /// messages are skipped. Use [`recv`](Self::recv) instead to see
+    /// alignment data.
+    ///
+    /// # Errors
+    ///
+    /// Yields the first error from [`recv`](Self::recv), or
+    /// [`ElevenLabsError::WebSocket`] if the server times the stream out,
+    /// and then ends.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use elevenlabs_sdk::{ClientConfig, TtsWebSocket, TtsWsConfig};
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> elevenlabs_sdk::Result<()> {
+    /// let config = ClientConfig::builder("your-api-key").build();
+    /// let mut ws =
+    ///     TtsWebSocket::connect(&config, &TtsWsConfig::new("voice123", "eleven_turbo_v2")).await?;
+    /// ws.send_text("Hello, world!").await?;
+    /// ws.end_input().await?;
+    ///
+    /// let mut file = Vec::new();
+    /// {
+    ///     let mut audio = std::pin::pin!(ws.audio_stream());
+    ///     while let Some(chunk) = audio.next().await {
+    ///         file.extend_from_slice(&chunk?);
+    ///     }
+    /// }
+    /// ws.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn audio_stream(&mut self) -> impl Stream<Item = Result<Bytes>> + '_ {
+        self.receiver.audio_stream()
     }
 
     /// Receive the next audio response from the server.
     ///
-    /// Returns `Ok(None)` when the connection is closed.
+    /// Returns `Ok(None)` when the connection is closed. If the server closed
+    /// it for inactivity, [`TtsWsResponse::TimedOut`] is returned first.
+    ///
+    /// With [`TtsWsConfig::auto_keepalive`] enabled, keepalive frames are
+    /// sent while waiting here, so an application that waits for audio
+    /// without sending text does not hit the inactivity timeout.
     ///
     /// # Errors
     ///
-    /// Returns [`ElevenLabsError::WebSocket`] on transport errors or
-    /// [`ElevenLabsError::Deserialization`] if the JSON payload is malformed.
+    /// Returns [`ElevenLabsError::WebSocket`] on transport errors or server
+    /// error reports, or [`ElevenLabsError::Deserialization`] if the JSON
+    /// payload is malformed.
     pub async fn recv(&mut self) -> Result<Option<TtsWsResponse>> {
-        loop {
-            match self.stream.next().await {
-                Some(Event::Message(incoming)) => {
-                    if let Some(text) = incoming.text {
-                        let resp: TtsWsResponse = serde_json::from_str(&text)?;
-                        return Ok(Some(resp));
-                    }
-                    // Binary message without decodable text — keep receiving.
-                }
-                Some(Event::Connected { .. }) => {
-                    // Connection lifecycle event — keep receiving.
-                }
-                Some(Event::Disconnected { .. }) | None => return Ok(None),
+        self.receiver.recv().await
+    }
+
+    /// End the input, wait for the server to finish generating, and close
+    /// the connection.
+    ///
+    /// Returns the audio chunks that arrived after the call, up to and
+    /// including the final marker, so no audio or alignment still in flight
+    /// is lost. A server timeout also ends the wait.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if a send, a receive or the
+    /// close handshake fails.
+    pub async fn close_gracefully(mut self) -> Result<Vec<TtsWsAudioChunk>> {
+        self.end_input().await?;
+        let mut chunks = Vec::new();
+        while let Some(response) = self.recv().await? {
+            let is_final = response.is_final();
+            if let TtsWsResponse::Audio(chunk) = response {
+                chunks.push(chunk);
+            }
+            if is_final {
+                break;
             }
         }
+        self.close().await?;
+        Ok(chunks)
     }
 
-    /// Send EOS (end-of-stream) and close the connection.
+    /// Send EOS (end-of-stream), unless [`end_input`](Self::end_input)
+    /// already did, and close the connection.
+    ///
+    /// Audio not yet received is discarded; use
+    /// [`close_gracefully`](Self::close_gracefully) to wait for it.
     ///
     /// # Errors
     ///
     /// Returns [`ElevenLabsError::WebSocket`] if the close handshake fails.
     pub async fn close(self) -> Result<()> {
-        // Send EOS message.
+        self.sender.close().await
+    }
+}
+
+impl TtsWsSender {
+    /// Send a text chunk for conversion. See [`TtsWebSocket::send_text`].
+    ///
+    /// At the [`TtsWsPacing`] in-flight limit this flushes and then waits
+    /// until the [`TtsWsReceiver`] receives audio.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails or the
+    /// connection closes while waiting for audio.
+    pub async fn send_text(&self, text: &str) -> Result<()> {
+        self.send_text_with_trigger(text, true).await
+    }
+
+    /// Send a text chunk, choosing whether it may trigger generation. See
+    /// [`TtsWebSocket::send_text_with_trigger`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails or the
+    /// connection closes while waiting for audio.
+    pub async fn send_text_with_trigger(
+        &self,
+        text: &str,
+        try_trigger_generation: bool,
+    ) -> Result<()> {
+        if self.shared.in_flight_limit_reached().is_some() {
+            self.flush().await?;
+            loop {
+                let arrived = self.shared.audio_arrived.notified();
+                tokio::pin!(arrived);
+                arrived.as_mut().enable();
+                if self.shared.closed.load(Ordering::Acquire) {
+                    return Err(ElevenLabsError::WebSocket(
+                        "connection closed while waiting for audio".to_owned(),
+                    ));
+                }
+                if self.shared.in_flight_limit_reached().is_none() {
+                    break;
+                }
+                arrived.await;
+            }
+        }
+        self.send_text_paced(text, try_trigger_generation).await
+    }
+
+    /// Returns the number of text chunks sent since audio last arrived.
+    pub fn in_flight_chunks(&self) -> usize {
+        self.shared.in_flight.load(Ordering::Acquire)
+    }
+
+    /// Flush the current audio generation buffer. See
+    /// [`TtsWebSocket::flush`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn flush(&self) -> Result<()> {
+        let msg = FlushMessage { text: " ", flush: true };
+        self.send_frame(serde_json::to_string(&msg)?, "flush").await
+    }
+
+    /// Tell the server that no more text follows. See
+    /// [`TtsWebSocket::end_input`]. Only the first call on any clone sends
+    /// EOS.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the send fails.
+    pub async fn end_input(&self) -> Result<()> {
+        if self.shared.input_ended.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
         let eos = EosMessage { text: "" };
-        let json = serde_json::to_string(&eos)?;
-        self.handle
-            .send(WsMessage::text(json))
-            .await
-            .map_err(|e| ElevenLabsError::WebSocket(format!("EOS send failed: {e}")))?;
+        if let Err(e) = self.send_frame(serde_json::to_string(&eos)?, "EOS send").await {
+            self.shared.input_ended.store(false, Ordering::Release);
+            return Err(e);
+        }
+        Ok(())
+    }
 
-        // Close the managed connection.
+    /// Send EOS, unless it was already sent, and close the connection for
+    /// every clone and the receiver.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] if the close handshake fails.
+    pub async fn close(&self) -> Result<()> {
+        self.end_input().await?;
+
+        let observer = &self.shared.observer;
         self.handle
             .close()
+            .instrument(observer.span().clone())
             .await
             .map_err(|e| ElevenLabsError::WebSocket(format!("close failed: {e}")))?;
+        observer.closed();
+
+        debug!(parent: observer.span(), "TTS WebSocket closed");
+        Ok(())
+    }
+
+    /// Waits for the pacing budget, then sends a text chunk.
+    async fn send_text_paced(&self, text: &str, try_trigger_generation: bool) -> Result<()> {
+        let wait = self
+            .shared
+            .budget
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+            .map_or(Duration::ZERO, |budget| budget.reserve(text.chars().count(), Instant::now()));
+        if !wait.is_zero() {
+            debug!(parent: self.shared.observer.span(), wait_ms = wait.as_millis(), "pacing TTS text");
+            tokio::time::sleep(wait).await;
+        }
+        let msg = TextChunkMessage { text, try_trigger_generation };
+        self.send_frame(serde_json::to_string(&msg)?, "send_text").await?;
+        self.shared.in_flight.fetch_add(1, Ordering::AcqRel);
+        Ok(())
+    }
 
-        debug!("TTS WebSocket closed");
+    /// Sends one JSON text frame and records it; `action` prefixes the
+    /// error message.
+    async fn send_frame(&self, json: String, action: &str) -> Result<()> {
+        send_frame(&self.handle, &self.shared, json, action).await
+    }
+}
+
+impl TtsWsReceiver {
+    /// Receive the next audio response from the server. See
+    /// [`TtsWebSocket::recv`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElevenLabsError::WebSocket`] on transport errors or server
+    /// error reports, or [`ElevenLabsError::Deserialization`] if the JSON
+    /// payload is malformed.
+    pub async fn recv(&mut self) -> Result<Option<TtsWsResponse>> {
+        if let Some(response) = self.pending.pop_front() {
+            return Ok(Some(response));
+        }
+        self.recv_from_server().await
+    }
+
+    /// Returns a stream of decoded audio chunks. See
+    /// [`TtsWebSocket::audio_stream`].
+    ///
+    /// # Errors
+    ///
+    /// Yields the first error from [`recv`](Self::recv), or
+    /// [`ElevenLabsError::WebSocket`] if the server times the stream out,
+    /// and then ends.
+    pub fn audio_stream(&mut self) -> impl Stream<Item = Result<Bytes>> + '_ {
+        stream::unfold(Some(self), |rx| async move {
+            let rx = rx?;
+            loop {
+                match rx.recv().await {
+                    Ok(Some(TtsWsResponse::Audio(chunk))) => {
+                        let is_final = chunk.is_final == Some(true);
+                        match chunk.audio.filter(|audio| !audio.is_empty()) {
+                            Some(audio) => return Some((Ok(audio), (!is_final).then_some(rx))),
+                            None if is_final => return None,
+                            None => {}
+                        }
+                    }
+                    Ok(Some(TtsWsResponse::TimedOut { message })) => {
+                        let error =
+                            ElevenLabsError::WebSocket(format!("stream timed out: {message}"));
+                        return Some((Err(error), None));
+                    }
+                    Ok(None) => return None,
+                    Err(e) => return Some((Err(e), None)),
+                }
+            }
+        })
+    }
+
+    /// Receives the next response from the connection, bypassing responses
+    /// kept while pacing.
+    async fn recv_from_server(&mut self) -> Result<Option<TtsWsResponse>> {
+        loop {
+            let input_ended = self.shared.input_ended.load(Ordering::Acquire);
+            let event = if self.auto_keepalive && !input_ended {
+                let keepalive_at = self.shared.last_sent() + self.inactivity_timeout / 2;
+                if let Ok(event) = tokio::time::timeout_at(keepalive_at, self.stream.next()).await {
+                    event
+                } else {
+                    self.send_keepalive().await?;
+                    continue;
+                }
+            } else {
+                self.stream.next().await
+            };
+
+            match event {
+                Some(Incoming::Text(text)) => {
+                    self.shared.observer.received(text.len());
+                    return self.parse_response(&text).map(Some);
+                }
+                Some(Incoming::Closed { .. }) | None => {
+                    self.shared.observer.closed();
+                    self.shared.closed.store(true, Ordering::Release);
+                    self.shared.audio_arrived.notify_waiters();
+                    // The server may close an idle stream without an error
+                    // message; infer the timeout from our own send history.
+                    if !self.timed_out &&
+                        self.shared.last_sent().elapsed() >= self.inactivity_timeout
+                    {
+                        self.timed_out = true;
+                        return Ok(Some(TtsWsResponse::TimedOut {
+                            message: format!(
+                                "no text received for {} seconds",
+                                self.inactivity_timeout.as_secs()
+                            ),
+                        }));
+                    }
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    /// Decodes one text frame from the server.
+    fn parse_response(&mut self, text: &str) -> Result<TtsWsResponse> {
+        match serde_json::from_str(text)? {
+            IncomingMessage::Audio(raw) => {
+                let chunk = TtsWsAudioChunk::try_from(raw).map_err(|e| {
+                    ElevenLabsError::Deserialization(serde::de::Error::custom(format!(
+                        "invalid audio base64: {e}"
+                    )))
+                })?;
+                if chunk.is_final == Some(true) ||
+                    chunk.audio.as_ref().is_some_and(|a| !a.is_empty())
+                {
+                    self.shared.in_flight.store(0, Ordering::Release);
+                    self.shared.audio_arrived.notify_waiters();
+                }
+                Ok(TtsWsResponse::Audio(chunk))
+            }
+            IncomingMessage::Error { error, message } if error == INPUT_TIMEOUT_ERROR => {
+                self.timed_out = true;
+                Ok(TtsWsResponse::TimedOut { message: message.unwrap_or(error) })
+            }
+            IncomingMessage::Error { error, message } => Err(ElevenLabsError::WebSocket(
+                message.map_or_else(|| error.clone(), |m| format!("{error}: {m}")),
+            )),
+        }
+    }
+
+    /// Sends a keepalive frame, resetting the server's inactivity timer.
+    async fn send_keepalive(&self) -> Result<()> {
+        let json = serde_json::to_string(&EosMessage { text: KEEPALIVE_TEXT })?;
+        send_frame(&self.handle, &self.shared, json, "keepalive").await?;
+        debug!(parent: self.shared.observer.span(), "sent TTS WebSocket keepalive");
         Ok(())
     }
 }
 
+/// Sends one JSON text frame on `handle` and records it; `action` prefixes
+/// the error message.
+async fn send_frame(
+    handle: &WsHandle,
+    shared: &TtsWsShared,
+    json: String,
+    action: &str,
+) -> Result<()> {
+    let bytes = json.len();
+    handle
+        .send_text(json)
+        .instrument(shared.observer.span().clone())
+        .await
+        .map_err(|e| ElevenLabsError::WebSocket(format!("{action} failed: {e}")))?;
+    shared.observer.sent(bytes);
+    *shared.last_sent.lock().unwrap_or_else(PoisonError::into_inner) = Instant::now();
+    Ok(())
+}
+
 #[cfg(test)]
 #[expect(clippy::unwrap_used, reason = "tests use unwrap")]
 mod tests {
+    use futures_util::StreamExt;
+
     use super::*;
+    use crate::ws::transport::test_server::{ServerSocket, TestServer};
 
     #[test]
     fn deserialize_tts_response_with_audio() {
@@ -318,20 +1256,22 @@ mod tests {
             }
         }"#;
 
-        let resp: TtsWsResponse = serde_json::from_str(json).unwrap();
-        assert_eq!(resp.audio.as_deref(), Some("SGVsbG8gV29ybGQ="));
+        let resp: TtsWsAudioChunk = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.audio.as_deref(), Some(&b"Hello World"[..]));
+        assert_eq!(resp.audio_base64(), Some("SGVsbG8gV29ybGQ="));
         assert_eq!(resp.is_final, Some(false));
         let alignment = resp.alignment.unwrap();
-        assert_eq!(alignment.chars.as_ref().unwrap().len(), 5);
-        assert_eq!(alignment.char_start_times_ms.as_ref().unwrap().len(), 5);
-        assert_eq!(alignment.char_durations_ms.as_ref().unwrap().len(), 5);
+        assert_eq!(alignment.chars.len(), 5);
+        assert_eq!(alignment.char_start_times_ms.len(), 5);
+        assert_eq!(alignment.char_durations_ms.len(), 5);
     }
 
     #[test]
     fn deserialize_tts_response_final() {
         let json = r#"{"audio": null, "isFinal": true}"#;
-        let resp: TtsWsResponse = serde_json::from_str(json).unwrap();
+        let resp: TtsWsAudioChunk = serde_json::from_str(json).unwrap();
         assert!(resp.audio.is_none());
+        assert!(resp.audio_base64().is_none());
         assert_eq!(resp.is_final, Some(true));
         assert!(resp.alignment.is_none());
     }
@@ -339,11 +1279,18 @@ mod tests {
     #[test]
     fn deserialize_tts_response_empty_audio() {
         let json = r#"{"audio": ""}"#;
-        let resp: TtsWsResponse = serde_json::from_str(json).unwrap();
-        assert_eq!(resp.audio.as_deref(), Some(""));
+        let resp: TtsWsAudioChunk = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.audio, Some(Bytes::new()));
+        assert_eq!(resp.audio_base64(), Some(""));
         assert!(resp.is_final.is_none());
     }
 
+    #[test]
+    fn deserialize_tts_response_rejects_invalid_base64() {
+        let result = serde_json::from_str::<TtsWsAudioChunk>(r#"{"audio": "not base64!"}"#);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn deserialize_alignment() {
         let json = r#"{
@@ -352,7 +1299,15 @@ mod tests {
             "charDurationsMs": [100.0, 200.0]
         }"#;
         let alignment: TtsWsAlignment = serde_json::from_str(json).unwrap();
-        assert_eq!(alignment.chars.as_ref().unwrap().len(), 2);
+        assert_eq!(alignment.chars.len(), 2);
+
+        let timings: Vec<_> = alignment.characters().collect();
+        assert_eq!(timings[1].character, "b");
+        assert_eq!(timings[1].start, Duration::from_millis(100));
+        assert_eq!(timings[1].end(), Duration::from_millis(300));
+
+        let empty: TtsWsAlignment = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty.characters().count(), 0);
     }
 
     #[test]
@@ -415,8 +1370,124 @@ mod tests {
                 "charDurationsMs": [50.0, 50.0]
             }
         }"#;
-        let resp: TtsWsResponse = serde_json::from_str(json).unwrap();
+        let resp: TtsWsAudioChunk = serde_json::from_str(json).unwrap();
         assert!(resp.normalized_alignment.is_some());
         assert!(resp.alignment.is_none());
     }
+
+    #[test]
+    fn incoming_timeout_error_is_recognised() {
+        let json = r#"{"message":"Have not received new text for 20 seconds.","error":"input_timeout_exceeded","code":1008}"#;
+        let msg: IncomingMessage = serde_json::from_str(json).unwrap();
+        assert!(
+            matches!(msg, IncomingMessage::Error { ref error, .. } if error == INPUT_TIMEOUT_ERROR)
+        );
+
+        let msg: IncomingMessage = serde_json::from_str(r#"{"audio":"AAAA"}"#).unwrap();
+        assert!(matches!(msg, IncomingMessage::Audio(_)));
+    }
+
+    #[test]
+    fn response_is_final() {
+        let chunk: TtsWsAudioChunk = serde_json::from_str(r#"{"isFinal": true}"#).unwrap();
+        assert!(TtsWsResponse::Audio(chunk).is_final());
+        let chunk: TtsWsAudioChunk = serde_json::from_str(r#"{"audio": "AAAA"}"#).unwrap();
+        assert!(!TtsWsResponse::Audio(chunk).is_final());
+        assert!(TtsWsResponse::TimedOut { message: String::new() }.is_final());
+    }
+
+    #[test]
+    fn keepalive_is_not_eos() {
+        let json = serde_json::to_string(&EosMessage { text: KEEPALIVE_TEXT }).unwrap();
+        assert_eq!(json, r#"{"text":" "}"#);
+    }
+
+    #[test]
+    fn config_query_params() {
+        let mut config = TtsWsConfig::new("voice123", "eleven_flash_v2_5");
+        assert_eq!(config.query_params().unwrap(), [("model_id", "eleven_flash_v2_5".to_owned())]);
+
+        config.auto_mode = Some(true);
+        config.apply_text_normalization = Some(TextNormalization::Off);
+        config.sync_alignment = Some(true);
+        let params = config.query_params().unwrap();
+        assert!(params.contains(&("auto_mode", "true".to_owned())));
+        assert!(params.contains(&("apply_text_normalization", "off".to_owned())));
+        assert!(params.contains(&("sync_alignment", "true".to_owned())));
+    }
+
+    #[test]
+    fn config_validates_chunk_length_schedule() {
+        let config =
+            TtsWsConfig::new("voice123", "eleven_turbo_v2").chunk_length_schedule([50, 500]);
+        assert_eq!(config.generation_config.as_ref().unwrap().chunk_length_schedule, [50, 500]);
+        assert!(config.query_params().is_ok());
+
+        for schedule in [vec![], vec![49], vec![120, 501]] {
+            let config =
+                TtsWsConfig::new("voice123", "eleven_turbo_v2").chunk_length_schedule(schedule);
+            assert!(matches!(config.query_params(), Err(ElevenLabsError::Validation(_))));
+        }
+    }
+
+    #[test]
+    fn config_validates_pacing() {
+        let mut config = TtsWsConfig::new("voice123", "eleven_turbo_v2");
+        config.pacing = Some(TtsWsPacing::new(40).max_in_flight_chunks(2));
+        assert!(config.query_params().is_ok());
+
+        for pacing in [
+            TtsWsPacing::new(0),
+            TtsWsPacing::new(40).burst_chars(0),
+            TtsWsPacing::new(40).max_in_flight_chunks(0),
+        ] {
+            config.pacing = Some(pacing);
+            assert!(matches!(config.query_params(), Err(ElevenLabsError::Validation(_))));
+        }
+    }
+
+    #[test]
+    fn char_budget_spaces_sends_to_rate() {
+        let start = Instant::now();
+        let mut budget = CharBudget::new(&TtsWsPacing::new(10).burst_chars(20), start);
+
+        assert_eq!(budget.reserve(15, start), Duration::ZERO);
+        assert_eq!(budget.reserve(5, start), Duration::ZERO);
+        assert_eq!(budget.reserve(5, start), Duration::from_millis(500));
+        // A second send before the first wait is over queues behind it.
+        assert_eq!(budget.reserve(10, start), Duration::from_millis(1500));
+        // After the wait, the bucket refills at the rate up to the burst.
+        let later = start + Duration::from_secs(10);
+        assert_eq!(budget.reserve(20, later), Duration::ZERO);
+        assert_eq!(budget.reserve(1, later), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn config_new_defaults() {
+        let config = TtsWsConfig::new("voice123", "eleven_turbo_v2");
+        assert_eq!(config.voice_id, "voice123");
+        assert!(config.inactivity_timeout.is_none());
+        assert!(!config.auto_keepalive);
+    }
+
+    #[tokio::test]
+    async fn handshake_carries_user_agent_and_headers() {
+        let server = TestServer::start(|_, mut socket: ServerSocket| async move {
+            while socket.next().await.is_some() {}
+        })
+        .await;
+        let config = ClientConfig::builder("key")
+            .base_url(format!("http://{}", server.addr))
+            .user_agent("audiobook-studio/2.1")
+            .default_headers([("xi-source", "audiobook-studio"), ("x-route", "default")])
+            .ws_transport(WsTransportConfig::new().header("x-route", "eu-gateway-7"))
+            .build();
+
+        TtsWebSocket::connect(&config, &TtsWsConfig::new("voice", "model")).await.unwrap();
+
+        let headers = &server.handshakes()[0].headers;
+        assert_eq!(headers["user-agent"], config.user_agent_header());
+        assert_eq!(headers["xi-source"], "audiobook-studio");
+        assert_eq!(headers.get_all("x-route").iter().collect::<Vec<_>>(), ["eu-gateway-7"]);
+    }
 }
@@ -0,0 +1,12 @@
+//! Helpers shared by the payload tests.
+
+use serde::de::DeserializeOwned;
+
+/// Deserialization check for one payload.
+pub(crate) type Check = fn(&str) -> Result<(), String>;
+
+/// Attempts to deserialize `json` as `T`, returning the error message on
+/// failure.
+pub(crate) fn check<T: DeserializeOwned>(json: &str) -> Result<(), String> {
+    serde_json::from_str::<T>(json).map(drop).map_err(|e| e.to_string())
+}
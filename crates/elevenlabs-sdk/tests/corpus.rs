@@ -0,0 +1,84 @@
+//! Deserializes API payloads into the SDK response types.
+//!
+//! Each subdirectory of `tests/corpus/` holds JSON payloads for one endpoint;
+//! every file in it must deserialize into the matching SDK type. Run with the
+//! `strict-serde` feature to also reject payloads carrying fields the SDK
+//! types don't model:
+//!
+//! ```bash
+//! cargo test -p elevenlabs-sdk --features strict-serde --test corpus
+//! ```
+//!
+//! The bundled payloads are synthetic: they were written by hand from the
+//! API reference, not recorded from the live API, so they only show that the
+//! types match the documented shapes. To catch drift against the live API,
+//! set `ELEVENLABS_CORPUS_DIR` to a directory of recorded production
+//! payloads laid out the same way.
+
+mod common;
+
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{Check, check};
+use elevenlabs_sdk::types::{
+    ForcedAlignmentResponse, GetModelsResponse, GetSpeechHistoryResponse, GetVoicesResponse,
+    SpeechToTextChunkResponse, UserResponse,
+};
+
+/// Maps a corpus subdirectory to the type its payloads must deserialize into.
+fn checker(endpoint: &str) -> Option<Check> {
+    let check: Check = match endpoint {
+        "forced_alignment" => check::<ForcedAlignmentResponse>,
+        "history" => check::<GetSpeechHistoryResponse>,
+        "models" => check::<GetModelsResponse>,
+        "speech_to_text" => check::<SpeechToTextChunkResponse>,
+        "user" => check::<UserResponse>,
+        "voices" => check::<GetVoicesResponse>,
+        _ => return None,
+    };
+    Some(check)
+}
+
+/// Returns the corpus root, honouring `ELEVENLABS_CORPUS_DIR`.
+fn corpus_root() -> PathBuf {
+    std::env::var_os("ELEVENLABS_CORPUS_DIR")
+        .map_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus"), PathBuf::from)
+}
+
+#[test]
+fn corpus_payloads_deserialize() -> Result<(), Box<dyn Error>> {
+    let root = corpus_root();
+    let mut checked = 0_usize;
+    let mut failures = Vec::new();
+
+    for endpoint_dir in fs::read_dir(&root)? {
+        let endpoint_dir = endpoint_dir?.path();
+        if !endpoint_dir.is_dir() {
+            continue;
+        }
+        let endpoint = endpoint_dir.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        let Some(check) = checker(endpoint) else {
+            failures.push(format!("{}: no SDK type registered for corpus", endpoint_dir.display()));
+            continue;
+        };
+
+        for file in fs::read_dir(&endpoint_dir)? {
+            let file = file?.path();
+            if file.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            if let Err(error) = check(&fs::read_to_string(&file)?) {
+                failures.push(format!("{}: {error}", file.display()));
+            }
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 0, "no corpus payloads found under {}", root.display());
+    assert!(failures.is_empty(), "corpus drift detected:\n{}", failures.join("\n"));
+    Ok(())
+}
@@ -90,7 +90,7 @@ mod prism {
     #[ignore = "requires Prism mock server on port 4010"]
     async fn test_history_list() {
         let client = integration_client();
-        let result = client.history().list(None, None, None).await;
+        let result = client.history().list(None, None, None, None).await;
         assert!(result.is_ok(), "history().list() failed: {result:?}");
     }
 
@@ -0,0 +1,320 @@
+//! Checks that response types tolerate the API omitting non-essential fields.
+//!
+//! Every payload below carries only identifiers and the enum fields a type
+//! cannot sensibly default; everything else must fall back to its default
+//! (see the field policy in `elevenlabs_sdk::types`). A failure here means a
+//! field was added as required and would break deserialization the moment the
+//! API stops sending it.
+//!
+//! The table covers every deserializable `*Response` struct under
+//! `src/types`: the sources are scanned for them, so a new response type
+//! fails the suite until it gets a payload here.
+
+mod common;
+
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{Check, check};
+use elevenlabs_sdk::types::{
+    AddChapterResponse, AddKnowledgeBaseResponse, AddProjectResponse,
+    AddPronunciationDictionaryResponse, AddVoiceResponse, AdditionalFormatResponse,
+    AgentBranchResponse, AgentDeploymentResponse, AgentLinkResponse, AgentWidgetResponse,
+    AudioNativeCreateProjectResponse, AudioNativeEditContentResponse,
+    AudioWithTimestampsAndVoiceSegmentsResponse, AudioWithTimestampsResponse, BatchCallResponse,
+    ChapterContentResponse, ChapterResponse, ChapterSnapshotExtendedResponse,
+    ChapterSnapshotResponse, ChapterSnapshotsResponse, ChapterWithContentResponse,
+    ConversationTokenResponse, ConvertChapterResponse, ConvertProjectResponse,
+    CreatePhoneNumberResponse, CreatePronunciationDictionaryResponse, DeleteChapterResponse,
+    DeleteDubbingResponse, DeleteHistoryItemResponse, DeleteProjectResponse,
+    DeletePvcSampleResponse, DeleteSampleResponse, DeleteVoiceResponse, DeleteVoiceSampleResponse,
+    DetailedMusicResponse, DialogueInputResponse, DoDubbingResponse, DubbingMetadataPageResponse,
+    DubbingMetadataResponse, DubbingRenderResponse, DubbingTranscriptResponse,
+    DubbingTranscriptsResponse, EditChapterResponse, EditProjectResponse, EditVoiceResponse,
+    EditVoiceSettingsResponse, ExtendedSubscriptionResponse, FeedbackResponse,
+    ForcedAlignmentResponse, GetAgentBranchesResponse, GetAgentResponse, GetAgentSummariesResponse,
+    GetAgentsResponse, GetAudioNativeProjectSettingsResponse, GetChaptersResponse,
+    GetConvAiSettingsResponse, GetConversationResponse, GetConversationUsersResponse,
+    GetConversationsResponse, GetDocumentRagIndexesResponse, GetKnowledgeBaseListResponse,
+    GetLibraryVoicesResponse, GetModelsResponse, GetProjectsResponse,
+    GetPronunciationDictionariesResponse, GetPvcCaptchaResponse, GetSecretsResponse,
+    GetSimilarVoicesResponse, GetSpeechHistoryResponse, GetToolDependentAgentsResponse,
+    GetToolsResponse, GetVoicesResponse, GetVoicesV2Response, KnowledgeBaseDocumentSummary,
+    LanguageAddedResponse, ListPhoneNumbersResponse, ListWhatsAppAccountsResponse,
+    LiveCountResponse, McpServerResponse, McpServerToolsResponse, McpServersResponse, Model,
+    MultichannelSpeechToTextResponse, PodcastProjectResponse, ProjectExtendedResponse,
+    ProjectMutedTracksResponse, ProjectResponse, ProjectSnapshotExtendedResponse,
+    ProjectSnapshotResponse, ProjectSnapshotsResponse, ProjectVoiceResponse,
+    PronunciationAliasRuleResponse, PronunciationDictionaryMetadata,
+    PronunciationDictionaryRulesResponse, PronunciationPhonemeRuleResponse,
+    RequestPvcManualVerificationResponse, ResourceMetadataResponse, SampleResponse,
+    SearchGroupsResponse, SegmentCreateResponse, SegmentDeleteResponse, SegmentDubResponse,
+    SegmentMigrationResponse, SegmentTranscriptionResponse, SegmentTranslationResponse,
+    SegmentUpdateResponse, SignedUrlResponse, SimilarVoicesForSpeakerResponse,
+    SingleUseTokenResponse, SpeakerAudioResponse, SpeakerCreatedResponse, SpeakerResponse,
+    SpeakerSeparationResponse, SpeakerUpdatedResponse, SpeechHistoryItem,
+    SpeechToTextChunkResponse, SpeechToTextWebhookResponse, StartPvcTrainingResponse,
+    StartSpeakerSeparationResponse, Subscription, ToolResponse, TwilioOutboundCallResponse,
+    UsageCharactersResponse, UsageTimeSeries, UserResponse, UtteranceResponse,
+    VerifyPvcCaptchaResponse, Voice, VoicePreviewsResponse, VoiceSamplePreviewResponse,
+    VoiceSampleWaveformResponse, WorkspaceApiKey, WorkspaceBatchCallsResponse,
+    WorkspaceCreateApiKeyResponse, WorkspaceCreateWebhookResponse, WorkspaceStatusResponse,
+};
+
+/// Builds the payload table from `Type => json` pairs, naming each entry
+/// after its type.
+macro_rules! minimal_payloads {
+    ($($ty:ident => $json:expr),* $(,)?) => {
+        &[$((stringify!($ty), check::<$ty> as Check, $json)),*]
+    };
+}
+
+/// Minimal payloads paired with the type they must deserialize into.
+const MINIMAL_PAYLOADS: &[(&str, Check, &str)] = minimal_payloads! {
+    Model => r#"{"model_id": "eleven_v3"}"#,
+    GetModelsResponse => r#"[{"model_id": "eleven_v3"}]"#,
+    Voice => r#"{"voice_id": "v1", "category": "premade"}"#,
+    GetVoicesResponse => "{}",
+    SpeechHistoryItem => r#"{"history_item_id": "h1", "state": "created"}"#,
+    GetSpeechHistoryResponse => "{}",
+    Subscription => r#"{"status": "free"}"#,
+    ExtendedSubscriptionResponse => "{}",
+    UsageTimeSeries => "{}",
+    UserResponse => r#"{"user_id": "u1", "subscription": {"status": "active"}}"#,
+    SpeechToTextChunkResponse => "{}",
+    ForcedAlignmentResponse => "{}",
+    AudioWithTimestampsResponse => "{}",
+    GetAgentsResponse => "{}",
+    GetAgentResponse => r#"{"agent_id": "a1"}"#,
+    GetConversationResponse => r#"{"agent_id": "a1", "conversation_id": "c1", "status": "done"}"#,
+    GetToolsResponse => "{}",
+    ToolResponse => r#"{"id": "t1", "access_info": {"role": "admin"}}"#,
+    GetKnowledgeBaseListResponse => "{}",
+    KnowledgeBaseDocumentSummary => r#"{"id": "d1", "access_info": {"role": "viewer"}}"#,
+    AddKnowledgeBaseResponse => r#"{"id": "d1"}"#,
+    DubbingMetadataResponse => r#"{"dubbing_id": "dub1"}"#,
+    GetProjectsResponse => "{}",
+    ProjectResponse => r#"{
+            "project_id": "p1",
+            "default_title_voice_id": "v1",
+            "default_paragraph_voice_id": "v1",
+            "default_model_id": "eleven_v3",
+            "state": "default"
+        }"#,
+    GetPronunciationDictionariesResponse => "{}",
+    PronunciationDictionaryMetadata => r#"{"id": "pd1", "latest_version_id": "pv1"}"#,
+    DetailedMusicResponse => "{}",
+    WorkspaceApiKey => r#"{"key_id": "k1", "service_account_user_id": "sa1"}"#,
+    GetAgentBranchesResponse => "{}",
+    AgentBranchResponse => r#"{"id": "br1", "agent_id": "a1"}"#,
+    AgentDeploymentResponse => "{}",
+    GetConversationsResponse => "{}",
+    GetDocumentRagIndexesResponse => "{}",
+    CreatePhoneNumberResponse => r#"{"phone_number_id": "ph1"}"#,
+    McpServerToolsResponse => r#"{"success": true}"#,
+    McpServerResponse => r#"{"id": "mcp1"}"#,
+    McpServersResponse => "{}",
+    BatchCallResponse => r#"{"id": "b1", "agent_id": "a1", "status": "pending"}"#,
+    WorkspaceBatchCallsResponse => "{}",
+    GetConvAiSettingsResponse => "{}",
+    GetAgentSummariesResponse => "{}",
+    AgentLinkResponse => "{}",
+    AgentWidgetResponse => r#"{"agent_id": "a1", "widget_config": {}}"#,
+    SignedUrlResponse => "{}",
+    ConversationTokenResponse => "{}",
+    ListPhoneNumbersResponse => "{}",
+    ListWhatsAppAccountsResponse => "{}",
+    LiveCountResponse => "{}",
+    GetSecretsResponse => "{}",
+    TwilioOutboundCallResponse => "{}",
+    GetConversationUsersResponse => "{}",
+    GetToolDependentAgentsResponse => "{}",
+    AudioNativeCreateProjectResponse => r#"{"project_id": "p1"}"#,
+    AudioNativeEditContentResponse => r#"{"project_id": "p1"}"#,
+    GetAudioNativeProjectSettingsResponse => "{}",
+    DubbingMetadataPageResponse => "{}",
+    DoDubbingResponse => r#"{"dubbing_id": "dub1"}"#,
+    DeleteDubbingResponse => "{}",
+    DubbingTranscriptResponse => "{}",
+    DubbingTranscriptsResponse => r#"{"transcript_format": "srt"}"#,
+    UtteranceResponse => "{}",
+    SpeakerResponse => r#"{"speaker_id": "s1"}"#,
+    SpeakerAudioResponse => "{}",
+    SpeakerCreatedResponse => r#"{"speaker_id": "s1"}"#,
+    SpeakerUpdatedResponse => "{}",
+    SimilarVoicesForSpeakerResponse => "{}",
+    SegmentCreateResponse => "{}",
+    SegmentUpdateResponse => "{}",
+    SegmentDeleteResponse => "{}",
+    SegmentDubResponse => "{}",
+    SegmentMigrationResponse => "{}",
+    SegmentTranscriptionResponse => "{}",
+    SegmentTranslationResponse => "{}",
+    DubbingRenderResponse => r#"{"render_id": "r1"}"#,
+    LanguageAddedResponse => "{}",
+    FeedbackResponse => "{}",
+    DialogueInputResponse => r#"{"voice_id": "v1"}"#,
+    DeleteHistoryItemResponse => "{}",
+    PronunciationAliasRuleResponse => "{}",
+    PronunciationPhonemeRuleResponse => "{}",
+    CreatePronunciationDictionaryResponse => "{}",
+    AddPronunciationDictionaryResponse => r#"{"id": "pd1", "version_id": "pv1"}"#,
+    PronunciationDictionaryRulesResponse => r#"{"id": "pd1", "version_id": "pv1"}"#,
+    VoiceSamplePreviewResponse => r#"{"voice_id": "v1", "sample_id": "sm1"}"#,
+    VoiceSampleWaveformResponse => r#"{"sample_id": "sm1"}"#,
+    VerifyPvcCaptchaResponse => "{}",
+    RequestPvcManualVerificationResponse => "{}",
+    StartPvcTrainingResponse => "{}",
+    DeletePvcSampleResponse => "{}",
+    SpeakerSeparationResponse => r#"{
+            "voice_id": "v1",
+            "sample_id": "sm1",
+            "status": "not_started"
+        }"#,
+    StartSpeakerSeparationResponse => "{}",
+    GetPvcCaptchaResponse => "{}",
+    SampleResponse => r#"{"sample_id": "sm1"}"#,
+    DeleteSampleResponse => "{}",
+    SingleUseTokenResponse => "{}",
+    AdditionalFormatResponse => "{}",
+    MultichannelSpeechToTextResponse => "{}",
+    SpeechToTextWebhookResponse => r#"{"request_id": "req1"}"#,
+    ProjectExtendedResponse => r#"{
+            "project_id": "p1",
+            "default_title_voice_id": "v1",
+            "default_paragraph_voice_id": "v1",
+            "default_model_id": "eleven_v3",
+            "state": "default"
+        }"#,
+    AddProjectResponse => r#"{
+            "project": {
+                "project_id": "p1",
+                "default_title_voice_id": "v1",
+                "default_paragraph_voice_id": "v1",
+                "default_model_id": "eleven_v3",
+                "state": "default"
+            }
+        }"#,
+    EditProjectResponse => r#"{
+            "project": {
+                "project_id": "p1",
+                "default_title_voice_id": "v1",
+                "default_paragraph_voice_id": "v1",
+                "default_model_id": "eleven_v3",
+                "state": "default"
+            }
+        }"#,
+    DeleteProjectResponse => "{}",
+    ConvertProjectResponse => "{}",
+    ProjectSnapshotResponse => r#"{"project_snapshot_id": "ps1", "project_id": "p1"}"#,
+    ProjectSnapshotExtendedResponse => r#"{"project_snapshot_id": "ps1", "project_id": "p1"}"#,
+    ProjectSnapshotsResponse => "{}",
+    ProjectMutedTracksResponse => "{}",
+    ProjectVoiceResponse => r#"{"voice_id": "v1"}"#,
+    ChapterResponse => r#"{"chapter_id": "ch1", "state": "default"}"#,
+    ChapterWithContentResponse => r#"{"chapter_id": "ch1", "state": "default"}"#,
+    GetChaptersResponse => "{}",
+    AddChapterResponse => r#"{"chapter": {"chapter_id": "ch1", "state": "default"}}"#,
+    EditChapterResponse => r#"{"chapter": {"chapter_id": "ch1", "state": "default"}}"#,
+    DeleteChapterResponse => "{}",
+    ConvertChapterResponse => "{}",
+    ChapterSnapshotResponse => r#"{
+            "chapter_snapshot_id": "cs1",
+            "project_id": "p1",
+            "chapter_id": "ch1"
+        }"#,
+    ChapterSnapshotExtendedResponse => r#"{
+            "chapter_snapshot_id": "cs1",
+            "project_id": "p1",
+            "chapter_id": "ch1"
+        }"#,
+    ChapterSnapshotsResponse => "{}",
+    ChapterContentResponse => "{}",
+    PodcastProjectResponse => r#"{
+            "project": {
+                "project_id": "p1",
+                "default_title_voice_id": "v1",
+                "default_paragraph_voice_id": "v1",
+                "default_model_id": "eleven_v3",
+                "state": "default"
+            }
+        }"#,
+    AudioWithTimestampsAndVoiceSegmentsResponse => "{}",
+    VoicePreviewsResponse => "{}",
+    UsageCharactersResponse => "{}",
+    AddVoiceResponse => r#"{"voice_id": "v1"}"#,
+    EditVoiceResponse => "{}",
+    DeleteVoiceResponse => "{}",
+    EditVoiceSettingsResponse => "{}",
+    DeleteVoiceSampleResponse => "{}",
+    GetLibraryVoicesResponse => "{}",
+    GetSimilarVoicesResponse => "{}",
+    GetVoicesV2Response => "{}",
+    WorkspaceCreateApiKeyResponse => r#"{"key_id": "k1"}"#,
+    WorkspaceCreateWebhookResponse => r#"{"webhook_id": "wh1"}"#,
+    SearchGroupsResponse => "[]",
+    ResourceMetadataResponse => r#"{"resource_id": "v1", "resource_type": "voice"}"#,
+    WorkspaceStatusResponse => "{}",
+};
+
+/// Returns the names of the structs under `src/types` that derive
+/// `Deserialize` and end in `Response`.
+fn declared_response_types() -> Result<Vec<String>, Box<dyn Error>> {
+    let mut names = Vec::new();
+    let mut dirs = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("src/types")];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path: PathBuf = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            // Attributes and docs since the previous item, to find the derive
+            // of the next struct.
+            let mut preamble = String::new();
+            for line in fs::read_to_string(&path)?.lines() {
+                if let Some(rest) = line.strip_prefix("pub struct ") {
+                    let name: String =
+                        rest.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+                    if name.ends_with("Response") && preamble.contains("Deserialize") {
+                        names.push(name);
+                    }
+                }
+                if line.is_empty() || line.starts_with('}') || line.starts_with("pub ") {
+                    preamble.clear();
+                } else {
+                    preamble.push_str(line);
+                }
+            }
+        }
+    }
+    Ok(names)
+}
+
+#[test]
+fn response_types_accept_minimal_payloads() {
+    let failures: Vec<String> = MINIMAL_PAYLOADS
+        .iter()
+        .filter_map(|(name, check, json)| check(json).err().map(|error| format!("{name}: {error}")))
+        .collect();
+    assert!(failures.is_empty(), "minimal payloads rejected:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn every_response_type_has_a_minimal_payload() -> Result<(), Box<dyn Error>> {
+    let declared = declared_response_types()?;
+    assert!(!declared.is_empty(), "no response types found under src/types");
+    let missing: Vec<String> = declared
+        .into_iter()
+        .filter(|declared| !MINIMAL_PAYLOADS.iter().any(|(name, ..)| name == declared))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "response types without a minimal payload:\n{}",
+        missing.join("\n")
+    );
+    Ok(())
+}